@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CacheStats } from "./CacheStats";
 
 /**
  * Operational capabilities of an adapter plus its default schema reference.
@@ -8,7 +9,16 @@ export type AdapterCapabilities = {
  * Human-readable adapter name, e.g. `"markdown"`, `"github"`, `"ado"`.
  */
 name: string, supports_create: boolean, supports_update: boolean, supports_delete: boolean, supports_search: boolean, supports_webhooks: boolean, 
+/**
+ * Whether the comment operations ([`Adapter::list_comments`] and
+ * friends) are implemented.
+ */
+supports_comments: boolean, 
 /**
  * The schema id documents returned by this adapter conform to by default.
  */
-default_schema: string, };
+default_schema: string, 
+/**
+ * Hit/miss statistics when the adapter is wrapped in a [`CachingAdapter`].
+ */
+cache: CacheStats | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * New comment on a spec
+ */
+export type AddCommentRequest = { body: string, 
+/**
+ * Id of the comment this one replies to
+ */
+inReplyTo: string | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API token as listed by `/api/tokens` (never includes the secret)
+ */
+export type ApiTokenInfo = { name: string, 
+/**
+ * viewer, editor or admin
+ */
+role: string, 
+/**
+ * Project ids the token is limited to; empty means every project
+ */
+projects: Array<string>, createdAt: string | null, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorkloadSpec } from "./WorkloadSpec";
+
+export type AssigneeWorkload = { assignee: string, inProgress: number, planned: number, 
+/**
+ * Total tokens of the open specs, a rough measure of their size
+ */
+tokens: number, wipLimit: number | null, 
+/**
+ * More specs in progress than the WIP limit allows
+ */
+overCapacity: boolean, 
+/**
+ * Open specs past their due date
+ */
+overdue: number, 
+/**
+ * Open specs due within `due_soon_days`
+ */
+dueSoon: number, 
+/**
+ * In-progress specs first, then by due date
+ */
+specs: Array<WorkloadSpec>, };
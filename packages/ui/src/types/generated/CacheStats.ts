@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Counters reported through [`AdapterCapabilities::cache`].
+ */
+export type CacheStats = { 
+/**
+ * Served from memory without contacting the backend.
+ */
+hits: number, 
+/**
+ * No entry was cached; fetched in full.
+ */
+misses: number, 
+/**
+ * Expired entries the backend confirmed unchanged or patched with a delta.
+ */
+revalidated: number, 
+/**
+ * Expired entries the backend replaced with a full result.
+ */
+refetched: number, 
+/**
+ * Entries dropped because of writes made through the cache.
+ */
+invalidations: number, 
+/**
+ * Entries currently held.
+ */
+entries: number, ttl_secs: number, schema_ttl_secs: number, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A comment in a spec document's discussion.
+ */
+export type Comment = { 
+/**
+ * Adapter-native comment id.
+ */
+id: string, author: string | null, 
+/**
+ * Comment body in markdown.
+ */
+body: string, created_at: string | null, updated_at: string | null, 
+/**
+ * Resolved comments are kept but no longer need attention.
+ */
+resolved: boolean, 
+/**
+ * Id of the comment this one replies to.
+ */
+in_reply_to: string | null, url: string | null, };
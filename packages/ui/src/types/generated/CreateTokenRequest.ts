@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request body for creating an API token
+ */
+export type CreateTokenRequest = { name: string, 
+/**
+ * viewer, editor or admin
+ */
+role: string, projects: Array<string>, };
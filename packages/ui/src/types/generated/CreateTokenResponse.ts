@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ApiTokenInfo } from "./ApiTokenInfo";
+
+/**
+ * Response for a newly created API token. `token` is only returned here.
+ */
+export type CreateTokenResponse = { token: string, info: ApiTokenInfo, };
@@ -4,6 +4,10 @@
  * Metadata update request
  */
 export type MetadataUpdate = { status: string | null, priority: string | null, tags: Array<string> | null, assignee: string | null, addDependsOn: Array<string> | null, removeDependsOn: Array<string> | null, parent: string | null | null, expectedContentHash: string | null, 
+/**
+ * Version token from a prior read; `If-Match` takes precedence
+ */
+expectedVersion: string | null, 
 /**
  * Skip completion verification when setting status to complete
  */
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to rename or renumber a spec
+ */
+export type MoveSpecRequest = { 
+/**
+ * New name, with or without a number prefix
+ */
+newName: string | null, number: number | null, dryRun: boolean, };
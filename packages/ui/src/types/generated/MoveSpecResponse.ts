@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MovedFile } from "./MovedFile";
+
+/**
+ * Response from a spec move (or its dry-run preview)
+ */
+export type MoveSpecResponse = { from: string, to: string, dryRun: boolean, files: Array<MovedFile>, };
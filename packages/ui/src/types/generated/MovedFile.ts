@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MovedLine } from "./MovedLine";
+
+/**
+ * A file whose references a move rewrites
+ */
+export type MovedFile = { 
+/**
+ * Path relative to the specs directory
+ */
+path: string, changes: Array<MovedLine>, };
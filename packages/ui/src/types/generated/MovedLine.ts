@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One rewritten line in a moved file
+ */
+export type MovedLine = { line: number, before: string | null, after: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Reviewers to ask for a review of a spec
+ */
+export type RequestReviewRequest = { reviewers: Array<string>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReviewDecision } from "./ReviewDecision";
+
+/**
+ * A recorded review decision.
+ */
+export type ReviewRecord = { reviewer: string, decision: ReviewDecision, at: string, 
+/**
+ * Hash of the spec body the decision was made against
+ */
+contentHash: string, comment: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A request for someone to review a spec.
+ */
+export type ReviewRequest = { reviewer: string, requestedAt: string, requestedBy: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Overall review state of a spec.
+ */
+export type ReviewState = "not-requested" | "pending" | "approved" | "changes-requested";
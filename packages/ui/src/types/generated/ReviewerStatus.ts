@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReviewDecision } from "./ReviewDecision";
+
+/**
+ * Where one reviewer stands on the current content.
+ */
+export type ReviewerStatus = { reviewer: string, 
+/**
+ * Explicitly requested, as opposed to the frontmatter `reviewer` or
+ * someone who reviewed unasked
+ */
+requested: boolean, 
+/**
+ * The frontmatter `reviewer` or explicitly requested, so their decision
+ * counts toward the review state
+ */
+assigned: boolean, 
+/**
+ * Latest decision, current or not
+ */
+decision: ReviewDecision | null, decidedAt: string | null, 
+/**
+ * The latest decision was made against different content
+ */
+stale: boolean, };
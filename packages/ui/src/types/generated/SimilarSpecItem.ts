@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A spec that resembles the candidate
+ */
+export type SimilarSpecItem = { id: string, title: string, score: number, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request body for finding specs similar to a draft
+ */
+export type SimilarSpecsRequest = { title: string, content: string | null, limit: number | null, threshold: number | null, 
+/**
+ * Spec id to leave out of the comparison (e.g. the spec being edited)
+ */
+exclude: string | null, };
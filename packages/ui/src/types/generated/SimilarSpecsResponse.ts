@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SimilarSpecItem } from "./SimilarSpecItem";
+
+/**
+ * Response for similar spec lookup
+ */
+export type SimilarSpecsResponse = { results: Array<SimilarSpecItem>, threshold: number, projectId: string | null, };
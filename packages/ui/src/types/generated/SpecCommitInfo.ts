@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A commit linked to a spec via a `spec:` trailer
+ */
+export type SpecCommitInfo = { sha: string, shortSha: string, author: string, date: string, subject: string, };
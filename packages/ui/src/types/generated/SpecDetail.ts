@@ -1,8 +1,18 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SpecCommitInfo } from "./SpecCommitInfo";
+import type { SpecLink } from "./SpecLink";
 import type { SpecRelationships } from "./SpecRelationships";
 import type { SubSpec } from "./SubSpec";
 
 /**
  * Full spec detail for view
  */
-export type SpecDetail = { projectId: string | null, id: string, specNumber: number | null, specName: string, title: string | null, status: string, priority: string | null, tags: Array<string>, assignee: string | null, contentMd: string, createdAt: string | null, updatedAt: string | null, completedAt: string | null, filePath: string, dependsOn: Array<string>, parent: string | null, children: Array<string>, requiredBy: Array<string>, contentHash: string | null, tokenCount: number | null, tokenStatus: string | null, validationStatus: string | null, relationships: SpecRelationships | null, subSpecs: Array<SubSpec> | null, };
+export type SpecDetail = { projectId: string | null, id: string, specNumber: number | null, specName: string, title: string | null, status: string, priority: string | null, tags: Array<string>, assignee: string | null, contentMd: string, createdAt: string | null, updatedAt: string | null, completedAt: string | null, filePath: string, dependsOn: Array<string>, parent: string | null, children: Array<string>, requiredBy: Array<string>, 
+/**
+ * Every typed link of the spec, declared on it or on the other end
+ */
+links: Array<SpecLink>, contentHash: string | null, version: string | null, tokenCount: number | null, tokenStatus: string | null, validationStatus: string | null, relationships: SpecRelationships | null, subSpecs: Array<SubSpec> | null, 
+/**
+ * Commits linked to this spec via `spec:` trailers
+ */
+commits: Array<SpecCommitInfo> | null, };
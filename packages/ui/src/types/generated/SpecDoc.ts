@@ -20,4 +20,10 @@ schema_id: string,
 /**
  * All field values, keyed by [`FieldDef::key`].
  */
-fields: { [key in string]?: FieldValue }, links: Array<ItemLink>, created_at: string | null, updated_at: string | null, url: string | null, raw: unknown | null, };
+fields: { [key in string]?: FieldValue }, links: Array<ItemLink>, created_at: string | null, updated_at: string | null, url: string | null, raw: unknown | null, 
+/**
+ * Opaque concurrency token that changes whenever the document does.
+ * Pass it back as [`UpdateRequest::expected_version`] to reject updates
+ * based on a stale read.
+ */
+version: string | null, };
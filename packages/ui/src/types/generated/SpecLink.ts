@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A typed link as seen from one spec
+ */
+export type SpecLink = { 
+/**
+ * Link key from this spec's side, e.g. `supersedes` or `superseded_by`
+ */
+linkType: string, label: string, targetId: string, targetTitle: string, };
@@ -3,4 +3,4 @@
 /**
  * Metadata for a single spec (tokens + validation)
  */
-export type SpecMetadata = { tokenCount: number, tokenStatus: string, validationStatus: string, };
+export type SpecMetadata = { tokenCount: number, tokenStatus: string, validationStatus: string, version: string | null, };
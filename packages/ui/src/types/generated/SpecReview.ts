@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReviewRecord } from "./ReviewRecord";
+import type { ReviewRequest } from "./ReviewRequest";
+import type { ReviewState } from "./ReviewState";
+import type { ReviewerStatus } from "./ReviewerStatus";
+
+/**
+ * Review state of a spec, computed against its current content.
+ */
+export type SpecReview = { spec: string, state: ReviewState, contentHash: string, 
+/**
+ * Assigned reviewers whose latest decision approves the current content
+ */
+approvals: number, reviewers: Array<ReviewerStatus>, requests: Array<ReviewRequest>, 
+/**
+ * Every decision, oldest first
+ */
+history: Array<ReviewRecord>, };
@@ -4,4 +4,8 @@ import type { SpecRelationships } from "./SpecRelationships";
 /**
  * Lightweight spec for list views
  */
-export type SpecSummary = { projectId: string | null, id: string, specNumber: number | null, specName: string, title: string | null, status: string, priority: string | null, tags: Array<string>, assignee: string | null, createdAt: string | null, updatedAt: string | null, completedAt: string | null, filePath: string, dependsOn: Array<string>, parent: string | null, children: Array<string>, requiredBy: Array<string>, contentHash: string | null, tokenCount: number | null, tokenStatus: string | null, validationStatus: string | null, relationships: SpecRelationships | null, };
+export type SpecSummary = { projectId: string | null, id: string, specNumber: number | null, specName: string, title: string | null, status: string, priority: string | null, tags: Array<string>, assignee: string | null, createdAt: string | null, updatedAt: string | null, completedAt: string | null, filePath: string, dependsOn: Array<string>, parent: string | null, children: Array<string>, requiredBy: Array<string>, contentHash: string | null, 
+/**
+ * Opaque version token to send back as `If-Match` on writes
+ */
+version: string | null, tokenCount: number | null, tokenStatus: string | null, validationStatus: string | null, relationships: SpecRelationships | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReviewDecision } from "../../../../../crate/packages/ui/src/types/generated/ReviewDecision";
+
+/**
+ * A review decision on the current content of a spec
+ */
+export type SubmitReviewRequest = { decision: ReviewDecision, 
+/**
+ * Reviewer name; ignored when the request is made with an API token,
+ * whose name is used instead
+ */
+reviewer: string | null, comment: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Comment edit: new body text, resolved state, or both
+ */
+export type UpdateCommentRequest = { body: string | null, resolved: boolean | null, };
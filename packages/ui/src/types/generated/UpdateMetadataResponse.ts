@@ -4,4 +4,4 @@ import type { FrontmatterResponse } from "./FrontmatterResponse";
 /**
  * Metadata update response
  */
-export type UpdateMetadataResponse = { success: boolean, specId: string, frontmatter: FrontmatterResponse, };
+export type UpdateMetadataResponse = { success: boolean, specId: string, frontmatter: FrontmatterResponse, version: string | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Query parameters for project validation
+ */
+export type ValidateProjectQuery = { 
+/**
+ * Report format for spec validation results: `sarif` or `junit`.
+ * When omitted, only the project structure is checked.
+ */
+format: string | null, };
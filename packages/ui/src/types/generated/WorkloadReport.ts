@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssigneeWorkload } from "./AssigneeWorkload";
+import type { WorkloadSpec } from "./WorkloadSpec";
+
+/**
+ * Open work per assignee.
+ */
+export type WorkloadReport = { 
+/**
+ * One entry per assignee with open specs or a configured WIP limit,
+ * sorted by name
+ */
+assignees: Array<AssigneeWorkload>, 
+/**
+ * Open specs without an assignee
+ */
+unassigned: Array<WorkloadSpec>, };
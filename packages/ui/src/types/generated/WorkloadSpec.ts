@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SpecStatus } from "./SpecStatus";
+
+export type WorkloadSpec = { path: string, title: string, status: SpecStatus, tokens: number, due: string | null, 
+/**
+ * Days until the due date, negative when overdue
+ */
+daysUntilDue: bigint | null, };
@@ -1,3 +1,6 @@
+export type { AddCommentRequest } from "./AddCommentRequest";
+export type { ApiTokenInfo } from "./ApiTokenInfo";
+export type { AssigneeWorkload } from "./AssigneeWorkload";
 export type { BatchMetadataRequest } from "./BatchMetadataRequest";
 export type { BatchMetadataResponse } from "./BatchMetadataResponse";
 export type { ChecklistToggledResult } from "./ChecklistToggledResult";
@@ -8,6 +11,8 @@ export type { ConfigFeatures } from "./ConfigFeatures";
 export type { ConfigStructure } from "./ConfigStructure";
 export type { ContextFile } from "./ContextFile";
 export type { CreateSpecRequest } from "./CreateSpecRequest";
+export type { CreateTokenRequest } from "./CreateTokenRequest";
+export type { CreateTokenResponse } from "./CreateTokenResponse";
 export type { DependencyEdge } from "./DependencyEdge";
 export type { DependencyGraphResponse } from "./DependencyGraphResponse";
 export type { DependencyNode } from "./DependencyNode";
@@ -23,11 +28,21 @@ export type { LeanSpecConfig } from "./LeanSpecConfig";
 export type { ListSpecsQuery } from "./ListSpecsQuery";
 export type { ListSpecsResponse } from "./ListSpecsResponse";
 export type { MetadataUpdate } from "./MetadataUpdate";
+export type { MovedFile } from "./MovedFile";
+export type { MovedLine } from "./MovedLine";
+export type { MoveSpecRequest } from "./MoveSpecRequest";
+export type { MoveSpecResponse } from "./MoveSpecResponse";
 export type { PriorityCountItem } from "./PriorityCountItem";
 export type { ProjectConfigResponse } from "./ProjectConfigResponse";
 export type { ProjectContextResponse } from "./ProjectContextResponse";
 export type { ProjectValidationResponse } from "./ProjectValidationResponse";
 export type { ProjectValidationSummary } from "./ProjectValidationSummary";
+export type { RequestReviewRequest } from "./RequestReviewRequest";
+export type { ReviewDecision } from "./ReviewDecision";
+export type { ReviewerStatus } from "./ReviewerStatus";
+export type { ReviewRecord } from "./ReviewRecord";
+export type { ReviewRequest } from "./ReviewRequest";
+export type { ReviewState } from "./ReviewState";
 export type { RunnerDefinition } from "./RunnerDefinition";
 export type { SearchFilters } from "./SearchFilters";
 export type { SearchRequest } from "./SearchRequest";
@@ -37,12 +52,18 @@ export type { Session } from "./Session";
 export type { SessionConfig } from "./SessionConfig";
 export type { SessionMode } from "./SessionMode";
 export type { SessionStatus } from "./SessionStatus";
+export type { SimilarSpecItem } from "./SimilarSpecItem";
+export type { SimilarSpecsRequest } from "./SimilarSpecsRequest";
+export type { SimilarSpecsResponse } from "./SimilarSpecsResponse";
+export type { SpecCommitInfo } from "./SpecCommitInfo";
 export type { SpecDetail } from "./SpecDetail";
+export type { SpecLink } from "./SpecLink";
 export type { SpecMetadata } from "./SpecMetadata";
 export type { SpecPriority } from "./SpecPriority";
 export type { SpecRawResponse } from "./SpecRawResponse";
 export type { SpecRawUpdateRequest } from "./SpecRawUpdateRequest";
 export type { SpecRelationships } from "./SpecRelationships";
+export type { SpecReview } from "./SpecReview";
 export type { SpecStatus } from "./SpecStatus";
 export type { SpecSummary } from "./SpecSummary";
 export type { SpecTokenResponse } from "./SpecTokenResponse";
@@ -51,8 +72,12 @@ export type { SpecValidationResponse } from "./SpecValidationResponse";
 export type { StatsResponse } from "./StatsResponse";
 export type { StatusCountItem } from "./StatusCountItem";
 export type { StatusTransition } from "./StatusTransition";
+export type { SubmitReviewRequest } from "./SubmitReviewRequest";
 export type { SubSpec } from "./SubSpec";
 export type { TokenBreakdown } from "./TokenBreakdown";
+export type { UpdateCommentRequest } from "./UpdateCommentRequest";
 export type { UpdateMetadataResponse } from "./UpdateMetadataResponse";
 export type { ValidationError } from "./ValidationError";
 export type { ValidationResponse } from "./ValidationResponse";
+export type { WorkloadReport } from "./WorkloadReport";
+export type { WorkloadSpec } from "./WorkloadSpec";
@@ -16,7 +16,7 @@ pub(crate) struct Cli {
     #[arg(short = 'd', long, global = true)]
     pub(crate) specs_dir: Option<String>,
 
-    /// Output format: text, json
+    /// Output format: text, json (validate also supports sarif, junit)
     #[arg(short = 'o', long, global = true, default_value = "text")]
     pub(crate) output: String,
 
@@ -101,6 +101,18 @@ pub(crate) enum Commands {
         spec: String,
     },
 
+    /// List and write comments on a spec
+    ///
+    /// Examples:
+    ///   lean-spec comment list 257
+    ///   lean-spec comment add 257 "Needs a rollback plan"
+    ///   lean-spec comment add 257 "Added one" --reply-to 3
+    ///   lean-spec comment resolve 257 3
+    Comment {
+        #[command(subcommand)]
+        action: CommentSubcommand,
+    },
+
     /// Remove specified line ranges from spec
     Compact {
         /// Spec to compact
@@ -168,15 +180,32 @@ pub(crate) enum Commands {
     /// List example projects
     Examples,
 
+    /// Export every spec as a portable archive, or to other formats
+    #[command(args_conflicts_with_subcommands = true)]
+    Export {
+        #[command(subcommand)]
+        action: Option<ExportSubcommand>,
+
+        /// Archive format: ndjson, json
+        #[arg(long, default_value = "ndjson")]
+        format: String,
+
+        /// Write to a file instead of stdout
+        #[arg(long)]
+        out: Option<String>,
+    },
+
     /// Manage spec relationships (hierarchy and dependencies)
     ///
     /// Use parent/child for hierarchy and depends-on for blockers.
-    /// Never use both for the same spec pair.
+    /// Never use both for the same spec pair. Other link types
+    /// (relates_to, supersedes, duplicates, implements) go through --link.
     ///
     /// Examples:
     ///   lean-spec rel add 257 --parent 250
     ///   lean-spec rel add 257 --depends-on 254
     ///   lean-spec rel rm 257 --depends-on 254
+    ///   lean-spec rel add 257 --link supersedes=042
     Rel {
         /// Arguments: <spec> or <action> <spec>
         #[arg(required = true, num_args = 1..=2)]
@@ -193,6 +222,10 @@ pub(crate) enum Commands {
         /// Add or remove dependency relationships
         #[arg(long = "depends-on", num_args = 1..)]
         depends_on: Vec<String>,
+
+        /// Add or remove links of other types, as <type>=<spec>
+        #[arg(long = "link", num_args = 1..)]
+        link: Vec<String>,
     },
 
     /// List files in a spec directory
@@ -218,6 +251,49 @@ pub(crate) enum Commands {
         status: Option<String>,
     },
 
+    /// Manage git hooks (pre-commit validation, commit-to-spec linking)
+    Hooks {
+        #[command(subcommand)]
+        action: HooksSubcommand,
+    },
+
+    /// Show which started downstream specs a change to a spec's acceptance
+    /// criteria or design affects
+    ///
+    /// Examples:
+    ///   lean-spec impact 042
+    ///   lean-spec impact 042 --since main --mark
+    Impact {
+        /// Spec path or number
+        spec: String,
+
+        /// Git revision to compare the spec against
+        #[arg(long, default_value = "HEAD")]
+        since: String,
+
+        /// Maximum depth of downstream specs to check
+        #[arg(short = 'D', long, default_value = "3")]
+        depth: usize,
+
+        /// Tag affected specs with `needs-review`
+        #[arg(long)]
+        mark: bool,
+    },
+
+    /// Import an archive written by `export` into the active adapter
+    Import {
+        /// Archive file (`-` reads stdin)
+        file: String,
+
+        /// YAML file mapping source values to target values per field
+        #[arg(long)]
+        mapping: Option<String>,
+
+        /// Show what would be imported without creating anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Initialize LeanSpec in current directory
     Init {
         /// Skip prompts and use defaults
@@ -299,6 +375,17 @@ pub(crate) enum Commands {
         downstream: bool,
     },
 
+    /// Find clusters of duplicate or near-duplicate specs
+    Duplicates {
+        /// Minimum similarity score (0.0-1.0)
+        #[arg(short = 'T', long, default_value = "0.5")]
+        threshold: f64,
+
+        /// Include archived specs
+        #[arg(long)]
+        include_archived: bool,
+    },
+
     /// Migrate specs from other SDD tools
     Migrate {
         /// Path to directory containing specs to migrate
@@ -329,6 +416,24 @@ pub(crate) enum Commands {
         backfill: bool,
     },
 
+    /// Rename or renumber a spec and rewrite every reference to it
+    Mv {
+        /// Spec path or number
+        spec: String,
+
+        /// New name, with or without a number prefix
+        #[arg(required_unless_present = "number")]
+        new_name: Option<String>,
+
+        /// New spec number
+        #[arg(long)]
+        number: Option<u32>,
+
+        /// Show the changes without applying them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Open spec in editor
     Open {
         /// Spec path or number
@@ -339,6 +444,18 @@ pub(crate) enum Commands {
         editor: Option<String>,
     },
 
+    /// Request and record reviews of a spec
+    ///
+    /// Examples:
+    ///   lean-spec review status 257
+    ///   lean-spec review request 257 alice bob
+    ///   lean-spec review approve 257
+    ///   lean-spec review request-changes 257 -m "Needs a rollback plan"
+    Review {
+        #[command(subcommand)]
+        action: ReviewSubcommand,
+    },
+
     /// Search specs
     Search {
         /// Search query (supports AND/OR/NOT, field filters, phrases, fuzzy)
@@ -513,6 +630,10 @@ pub(crate) enum Commands {
         /// Expected content hash for optimistic concurrency (fails if content changed)
         #[arg(long = "expected-hash")]
         expected_hash: Option<String>,
+
+        /// Expected version token (from `view --json`); fails if the spec changed in any way
+        #[arg(long = "expected-version")]
+        expected_version: Option<String>,
     },
 
     /// Validate specs for issues
@@ -531,6 +652,10 @@ pub(crate) enum Commands {
         /// Only show warnings (exit 0)
         #[arg(long)]
         warnings_only: bool,
+
+        /// Run project-wide consistency checks across specs
+        #[arg(long)]
+        project: bool,
     },
 
     /// View a spec's details
@@ -542,6 +667,20 @@ pub(crate) enum Commands {
         #[arg(long)]
         raw: bool,
     },
+
+    /// Show open work per assignee against their WIP limits
+    ///
+    /// Limits come from `workload.default_wip_limit` and
+    /// `workload.wip_limits` in the project config.
+    ///
+    /// Examples:
+    ///   lean-spec workload
+    ///   lean-spec workload --assignee alice
+    Workload {
+        /// Only show this assignee
+        #[arg(short, long)]
+        assignee: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -570,3 +709,135 @@ pub(crate) enum GitSubcommand {
         name: Option<String>,
     },
 }
+
+#[derive(Subcommand)]
+pub(crate) enum CommentSubcommand {
+    /// List the comments on a spec
+    List {
+        /// Spec path or number
+        spec: String,
+    },
+
+    /// Add a comment, or a reply with --reply-to
+    Add {
+        /// Spec path or number
+        spec: String,
+
+        /// Comment text (markdown)
+        body: String,
+
+        /// Id of the comment to reply to
+        #[arg(long)]
+        reply_to: Option<String>,
+
+        /// Author name (default: git user.name)
+        #[arg(long)]
+        author: Option<String>,
+    },
+
+    /// Replace the text of a comment
+    Edit {
+        /// Spec path or number
+        spec: String,
+
+        /// Comment id
+        id: String,
+
+        /// New comment text (markdown)
+        body: String,
+    },
+
+    /// Mark a comment resolved
+    Resolve {
+        /// Spec path or number
+        spec: String,
+
+        /// Comment id
+        id: String,
+
+        /// Reopen a resolved comment instead
+        #[arg(long)]
+        reopen: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum ReviewSubcommand {
+    /// Show reviewers, decisions and whether they are current
+    Status {
+        /// Spec path or number
+        spec: String,
+    },
+
+    /// Ask one or more people to review a spec
+    Request {
+        /// Spec path or number
+        spec: String,
+
+        /// Reviewer names
+        #[arg(required = true)]
+        reviewers: Vec<String>,
+    },
+
+    /// Approve the current content of a spec
+    Approve {
+        /// Spec path or number
+        spec: String,
+
+        /// Reviewer name (default: git user.name)
+        #[arg(long)]
+        reviewer: Option<String>,
+
+        /// Review comment
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+
+    /// Request changes to the current content of a spec
+    RequestChanges {
+        /// Spec path or number
+        spec: String,
+
+        /// Reviewer name (default: git user.name)
+        #[arg(long)]
+        reviewer: Option<String>,
+
+        /// Review comment
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum ExportSubcommand {
+    /// Render every spec to a static HTML site that works offline
+    Site {
+        /// Output directory
+        #[arg(long)]
+        out: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum HooksSubcommand {
+    /// Install pre-commit and commit-msg hooks into the current repository
+    Install {
+        /// Overwrite existing hooks not managed by lean-spec
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Remove hooks installed by lean-spec
+    Uninstall,
+
+    /// Validate specs touched by staged files (run by the pre-commit hook)
+    #[command(hide = true)]
+    PreCommit,
+
+    /// Check `spec:` trailers in a commit message (run by the commit-msg hook)
+    #[command(hide = true)]
+    CommitMsg {
+        /// Path to the commit message file
+        file: String,
+    },
+}
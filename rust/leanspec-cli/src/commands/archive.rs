@@ -3,7 +3,8 @@
 //! Archives spec(s) by setting status to archived (no file move).
 
 use colored::Colorize;
-use leanspec_core::{SpecArchiver, SpecLoader, SpecStatus};
+use leanspec_core::adapters::markdown::Changeset;
+use leanspec_core::{SpecLoader, SpecStatus};
 use std::error::Error;
 
 pub fn run(specs_dir: &str, specs: &[String], dry_run: bool) -> Result<(), Box<dyn Error>> {
@@ -12,7 +13,6 @@ pub fn run(specs_dir: &str, specs: &[String], dry_run: bool) -> Result<(), Box<d
     }
 
     let loader = SpecLoader::new(specs_dir);
-    let archiver = SpecArchiver::new(specs_dir);
 
     // Collect all specs to archive with validation
     let mut specs_to_archive = Vec::new();
@@ -59,17 +59,11 @@ pub fn run(specs_dir: &str, specs: &[String], dry_run: bool) -> Result<(), Box<d
         return Err("No valid specs to archive".into());
     }
 
-    // Process each spec
-    let mut archived_count = 0;
-
     if dry_run {
         println!();
         println!("{}", "Dry run - no changes will be made".yellow());
         println!();
-    }
-
-    for spec_info in specs_to_archive {
-        if dry_run {
+        for spec_info in &specs_to_archive {
             println!("Would archive: {}", spec_info.path.cyan());
             println!(
                 "  Status: {} → {}",
@@ -77,32 +71,29 @@ pub fn run(specs_dir: &str, specs: &[String], dry_run: bool) -> Result<(), Box<d
                 "archived".green()
             );
             println!();
-            continue;
         }
-
-        // Archive by setting status (no file move)
-        match archiver.archive(&spec_info.path) {
-            Ok(()) => {
-                println!("{} Archived: {}", "✓".green(), spec_info.path.cyan());
-                println!(
-                    "  Status: {} → {}",
-                    spec_info.frontmatter.status.to_string().dimmed(),
-                    "archived".green()
-                );
-                println!();
-                archived_count += 1;
-            }
-            Err(e) => {
-                eprintln!("{} Failed to archive {}: {}", "✗".red(), spec_info.path, e);
-            }
+    } else {
+        // Archive all specs in one changeset so a failure part-way through
+        // leaves none of them archived.
+        let mut changeset = Changeset::new(specs_dir);
+        for spec_info in &specs_to_archive {
+            changeset.archive(&spec_info.path)?;
         }
-    }
+        changeset.commit()?;
 
-    if !dry_run {
+        for spec_info in &specs_to_archive {
+            println!("{} Archived: {}", "✓".green(), spec_info.path.cyan());
+            println!(
+                "  Status: {} → {}",
+                spec_info.frontmatter.status.to_string().dimmed(),
+                "archived".green()
+            );
+            println!();
+        }
         println!(
             "{} Successfully archived {} spec(s)",
             "✓".green(),
-            archived_count
+            specs_to_archive.len()
         );
     }
 
@@ -48,13 +48,14 @@ pub fn run(params: CapabilitiesParams) -> Result<(), Box<dyn Error>> {
 
     println!("{} {}", "Adapter:".bold(), caps.name.cyan());
     println!(
-        "  {} create={} update={} delete={} search={} webhooks={}",
+        "  {} create={} update={} delete={} search={} webhooks={} comments={}",
         "Operations:".bold(),
         yesno(caps.supports_create),
         yesno(caps.supports_update),
         yesno(caps.supports_delete),
         yesno(caps.supports_search),
         yesno(caps.supports_webhooks),
+        yesno(caps.supports_comments),
     );
     println!(
         "  {} {}",
@@ -3,6 +3,7 @@
 //! Checks for sequence conflicts in spec numbering.
 
 use colored::Colorize;
+use leanspec_core::adapters::markdown::Changeset;
 use leanspec_core::SpecLoader;
 use std::collections::HashMap;
 use std::error::Error;
@@ -83,6 +84,7 @@ pub fn run(specs_dir: &str, fix: bool, output_format: &str) -> Result<(), Box<dy
     println!();
 
     // Report conflicts
+    let mut fixed = false;
     if conflicts.is_empty() {
         println!("{} No sequence conflicts found", "✓".green());
     } else {
@@ -104,25 +106,23 @@ pub fn run(specs_dir: &str, fix: bool, output_format: &str) -> Result<(), Box<dy
             println!();
             println!("{}", "Fixing conflicts...".cyan());
 
-            // Renumber conflicting specs
-            for (num, paths) in &conflicts {
-                // Keep the first one, renumber others
+            // Keep the first spec of each number and move the others past
+            // the current maximum, rewriting references to them,
+            // all-or-nothing.
+            let mut changeset = Changeset::new(specs_dir);
+            for (_, paths) in &conflicts {
                 for path in paths.iter().skip(1) {
-                    let new_num = max_number + 1;
                     max_number += 1;
-
-                    // Actually rename (just show what would happen)
-                    let new_path =
-                        path.replacen(&format!("{:03}", num), &format!("{:03}", new_num), 1);
-                    println!("  Would rename: {} → {}", path.yellow(), new_path.green());
-
-                    // Note: In production, we'd actually do the rename:
-                    // std::fs::rename(old_path, new_path)?;
+                    let rest = path.split_once('-').map(|(_, rest)| rest).unwrap_or("");
+                    let new_name = format!("{:03}-{}", max_number, rest);
+                    changeset.move_spec(path, &new_name)?;
                 }
             }
-
-            println!();
-            println!("{}", "Run without --fix to see changes only".dimmed());
+            let summary = changeset.commit()?;
+            for (from, to) in &summary.renamed {
+                println!("  Renamed: {} → {}", from.yellow(), to.green());
+            }
+            fixed = true;
         }
     }
 
@@ -159,7 +159,7 @@ pub fn run(specs_dir: &str, fix: bool, output_format: &str) -> Result<(), Box<dy
 
     println!();
 
-    if !conflicts.is_empty() {
+    if !conflicts.is_empty() && !fixed {
         return Err("Sequence conflicts found".into());
     }
 
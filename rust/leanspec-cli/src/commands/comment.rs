@@ -0,0 +1,144 @@
+//! `comment` command — discussion threads on a spec
+//!
+//! Comments go through the active adapter: a `comments.yaml` sidecar for
+//! markdown specs, issue comments for GitHub and Jira.
+
+use super::export::resolve_adapter;
+use colored::Colorize;
+use leanspec_core::adapters::{Comment, CommentRequest};
+use std::error::Error;
+use std::process::Command;
+
+pub enum CommentCommand {
+    List {
+        spec: String,
+    },
+    Add {
+        spec: String,
+        body: String,
+        reply_to: Option<String>,
+        author: Option<String>,
+    },
+    Edit {
+        spec: String,
+        id: String,
+        body: String,
+    },
+    Resolve {
+        spec: String,
+        id: String,
+        resolved: bool,
+    },
+}
+
+pub fn run(
+    specs_dir: Option<&str>,
+    cmd: CommentCommand,
+    output_format: &str,
+) -> Result<(), Box<dyn Error>> {
+    let adapter = resolve_adapter(specs_dir)?;
+
+    let (comment, verb) = match cmd {
+        CommentCommand::List { spec } => {
+            let comments = adapter.list_comments(&spec)?;
+            if output_format == "json" {
+                println!("{}", serde_json::to_string_pretty(&comments)?);
+            } else {
+                print_threads(&spec, &comments);
+            }
+            return Ok(());
+        }
+        CommentCommand::Add {
+            spec,
+            body,
+            reply_to,
+            author,
+        } => {
+            let req = CommentRequest {
+                body,
+                in_reply_to: reply_to,
+                author: author.or_else(git_user_name),
+            };
+            (adapter.add_comment(&spec, &req)?, "Added")
+        }
+        CommentCommand::Edit { spec, id, body } => {
+            (adapter.edit_comment(&spec, &id, &body)?, "Updated")
+        }
+        CommentCommand::Resolve { spec, id, resolved } => {
+            let verb = if resolved { "Resolved" } else { "Reopened" };
+            (adapter.resolve_comment(&spec, &id, resolved)?, verb)
+        }
+    };
+
+    if output_format == "json" {
+        println!("{}", serde_json::to_string_pretty(&comment)?);
+    } else {
+        println!("{} {} comment {}", "✓".green(), verb, comment.id.cyan());
+    }
+    Ok(())
+}
+
+/// Print top-level comments with their replies indented beneath them.
+fn print_threads(spec: &str, comments: &[Comment]) {
+    if comments.is_empty() {
+        println!("No comments on {}", spec);
+        return;
+    }
+    let is_root = |c: &Comment| {
+        c.in_reply_to
+            .as_deref()
+            .map_or(true, |parent| !comments.iter().any(|p| p.id == parent))
+    };
+    for root in comments.iter().filter(|c| is_root(c)) {
+        print_comment(root, 0);
+        print_replies(comments, &root.id, 1);
+    }
+}
+
+fn print_replies(comments: &[Comment], parent: &str, depth: usize) {
+    for reply in comments
+        .iter()
+        .filter(|c| c.in_reply_to.as_deref() == Some(parent))
+    {
+        print_comment(reply, depth);
+        print_replies(comments, &reply.id, depth + 1);
+    }
+}
+
+fn print_comment(comment: &Comment, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let author = comment.author.as_deref().unwrap_or("unknown");
+    let when = comment
+        .created_at
+        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default();
+    let resolved = if comment.resolved {
+        format!(" {}", "[resolved]".green())
+    } else {
+        String::new()
+    };
+    println!(
+        "{}{} {} {}{}",
+        indent,
+        format!("#{}", comment.id).cyan(),
+        author.bold(),
+        when.dimmed(),
+        resolved
+    );
+    for line in comment.body.lines() {
+        println!("{}  {}", indent, line);
+    }
+    println!();
+}
+
+pub(crate) fn git_user_name() -> Option<String> {
+    let output = Command::new("git")
+        .args(["config", "user.name"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!name.is_empty()).then_some(name)
+}
@@ -4,6 +4,7 @@ use chrono::Utc;
 use colored::Colorize;
 use leanspec_core::io::TemplateLoader;
 use leanspec_core::types::LeanSpecConfig;
+use leanspec_core::{SimilarityIndex, SpecLoader, SpecStatus, DEFAULT_SIMILARITY_THRESHOLD};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::error::Error;
@@ -179,9 +180,51 @@ pub fn run(params: CreateParams) -> Result<(), Box<dyn Error>> {
         readme_path: &readme_path,
     });
 
+    // 9. Warn about near-duplicates (best effort; never fails the create)
+    warn_similar_specs(specs_dir, &spec_name);
+
     Ok(())
 }
 
+/// Print a warning when the new spec resembles existing active specs.
+fn warn_similar_specs(specs_dir: &str, spec_name: &str) {
+    let Ok(specs) = SpecLoader::new(specs_dir).load_all() else {
+        return;
+    };
+    let Some(new_spec) = specs.iter().find(|s| s.path == spec_name) else {
+        return;
+    };
+    let candidates: Vec<_> = specs
+        .iter()
+        .filter(|s| s.path != spec_name && s.frontmatter.status != SpecStatus::Archived)
+        .cloned()
+        .collect();
+
+    let similar = SimilarityIndex::from_specs(&candidates).find_similar(
+        &new_spec.title,
+        &new_spec.content,
+        DEFAULT_SIMILARITY_THRESHOLD,
+        &[],
+    );
+    if similar.is_empty() {
+        return;
+    }
+
+    println!();
+    println!(
+        "{} {}",
+        "⚠️".yellow(),
+        "This spec looks similar to existing specs:".yellow()
+    );
+    for spec in similar.iter().take(5) {
+        println!("  {:.2}  {} - {}", spec.score, spec.id.cyan(), spec.title);
+    }
+    println!(
+        "  {}",
+        "Consider updating an existing spec instead, or link them with `lean-spec rel`.".dimmed()
+    );
+}
+
 fn apply_variables(
     template: &str,
     title: &str,
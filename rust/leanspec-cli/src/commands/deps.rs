@@ -1,97 +1,120 @@
 //! Deps command implementation
+//!
+//! Walks `depends_on` links through the active adapter, so the same view
+//! works for markdown specs and remote trackers.
 
+use super::export::resolve_adapter;
 use colored::Colorize;
-use leanspec_core::{DependencyGraph, SpecLoader};
+use leanspec_core::model::{SpecDoc, SpecSchema};
+use leanspec_core::{semantic, ListFilter, Reached, SpecGraph, SpecStatus};
 use std::error::Error;
 
+/// Dependency link key shared by the built-in adapters.
+const DEPENDS_ON: &str = "depends_on";
+
 pub fn run(
-    specs_dir: &str,
+    specs_dir: Option<&str>,
     spec: &str,
     depth: usize,
     upstream: bool,
     downstream: bool,
     output_format: &str,
 ) -> Result<(), Box<dyn Error>> {
-    let loader = SpecLoader::new(specs_dir);
-    let root = loader
-        .load(spec)?
-        .ok_or_else(|| format!("Spec not found: {}", spec))?;
+    let adapter = resolve_adapter(specs_dir)?;
+    let root = adapter
+        .get(spec)
+        .map_err(|_| format!("Spec not found: {}", spec))?;
 
-    let all_specs = loader.load_all()?;
-    let graph = DependencyGraph::new(&all_specs);
+    let docs = adapter.list(&ListFilter {
+        include_archived: true,
+        ..Default::default()
+    })?;
+    let schema = adapter.schema();
+    let graph = SpecGraph::new(docs, &schema.link_types);
+    let impact = graph
+        .impact(&root.id, DEPENDS_ON, depth)
+        .ok_or_else(|| format!("Spec not found: {}", spec))?;
 
     let upstream_specs = if downstream {
         Vec::new()
     } else {
-        graph.get_upstream(&root.path, depth)
+        impact.upstream
     };
 
     let downstream_specs = if upstream {
         Vec::new()
     } else {
-        graph.get_downstream(&root.path, depth)
+        impact.downstream
     };
 
     if output_format == "json" {
+        let entry = |doc: &SpecDoc| {
+            serde_json::json!({
+                "path": doc.id,
+                "title": doc.title,
+                "status": status(doc, schema),
+            })
+        };
         let output = serde_json::json!({
-            "spec": {
-                "path": root.path,
-                "title": root.title,
-                "status": root.frontmatter.status.to_string(),
-            },
+            "spec": entry(impact.doc),
             "depth": depth,
-            "upstream": upstream_specs.iter().map(|s| {
-                serde_json::json!({
-                    "path": s.path,
-                    "title": s.title,
-                    "status": s.frontmatter.status.to_string(),
-                })
-            }).collect::<Vec<_>>(),
-            "downstream": downstream_specs.iter().map(|s| {
-                serde_json::json!({
-                    "path": s.path,
-                    "title": s.title,
-                    "status": s.frontmatter.status.to_string(),
-                })
-            }).collect::<Vec<_>>(),
+            "upstream": upstream_specs.iter().map(|r| entry(r.doc)).collect::<Vec<_>>(),
+            "downstream": downstream_specs.iter().map(|r| entry(r.doc)).collect::<Vec<_>>(),
         });
         println!("{}", serde_json::to_string_pretty(&output)?);
         return Ok(());
     }
 
-    println!("\n{} {}", "Dependency graph for".bold(), root.path.cyan());
+    println!(
+        "\n{} {}",
+        "Dependency graph for".bold(),
+        impact.doc.id.cyan()
+    );
 
     if !downstream {
-        if upstream_specs.is_empty() {
-            println!("{}", "Upstream: (none)".dimmed());
-        } else {
-            println!("{}", "Upstream:".bold());
-            for s in upstream_specs {
-                println!(
-                    "  {} {} - {}",
-                    s.frontmatter.status_emoji(),
-                    s.path.cyan(),
-                    s.title
-                );
-            }
-        }
+        print_section("Upstream", &upstream_specs, schema);
     }
-
     if !upstream {
-        if downstream_specs.is_empty() {
-            println!("{}", "Downstream: (none)".dimmed());
-        } else {
-            println!("{}", "Downstream:".bold());
-            for s in downstream_specs {
-                println!(
-                    "  {} {} - {}",
-                    s.frontmatter.status_emoji(),
-                    s.path.cyan(),
-                    s.title
-                );
-            }
-        }
+        print_section("Downstream", &downstream_specs, schema);
     }
 
     Ok(())
 }
+
+fn print_section(heading: &str, specs: &[Reached<'_>], schema: &SpecSchema) {
+    if specs.is_empty() {
+        println!("{}", format!("{}: (none)", heading).dimmed());
+        return;
+    }
+    println!("{}", format!("{}:", heading).bold());
+    for reached in specs {
+        let doc = reached.doc;
+        println!(
+            "  {}{} {} - {}",
+            "  ".repeat(reached.depth - 1),
+            status_emoji(status(doc, schema)),
+            doc.id.cyan(),
+            doc.title
+        );
+    }
+}
+
+fn status<'a>(doc: &'a SpecDoc, schema: &SpecSchema) -> &'a str {
+    schema
+        .key_for_semantic(semantic::STATUS)
+        .and_then(|key| doc.field_str(key))
+        .unwrap_or_default()
+}
+
+/// Emoji for a status value; statuses LeanSpec doesn't define get a
+/// neutral marker.
+fn status_emoji(status: &str) -> &'static str {
+    match status.parse::<SpecStatus>().ok() {
+        Some(SpecStatus::Draft) => "📝",
+        Some(SpecStatus::Planned) => "📅",
+        Some(SpecStatus::InProgress) => "⏳",
+        Some(SpecStatus::Complete) => "✅",
+        Some(SpecStatus::Archived) => "📦",
+        None => "•",
+    }
+}
@@ -0,0 +1,77 @@
+//! Duplicates command implementation
+//!
+//! Groups specs whose titles and sections resemble each other closely enough
+//! to be likely duplicates.
+
+use colored::Colorize;
+use leanspec_core::{SimilarityIndex, SpecLoader, SpecStatus};
+use std::error::Error;
+
+pub fn run(
+    specs_dir: &str,
+    threshold: f64,
+    include_archived: bool,
+    output_format: &str,
+) -> Result<(), Box<dyn Error>> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(format!("Threshold must be between 0.0 and 1.0, got {}", threshold).into());
+    }
+
+    let loader = SpecLoader::new(specs_dir);
+    let specs: Vec<_> = loader
+        .load_all()?
+        .into_iter()
+        .filter(|s| include_archived || s.frontmatter.status != SpecStatus::Archived)
+        .collect();
+
+    let clusters = SimilarityIndex::from_specs(&specs).duplicate_clusters(threshold);
+
+    if output_format == "json" {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "threshold": threshold,
+                "clusters": clusters,
+            }))?
+        );
+        return Ok(());
+    }
+
+    if clusters.is_empty() {
+        println!(
+            "{} No duplicate specs found (threshold {:.2})",
+            "✓".green(),
+            threshold
+        );
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "{} duplicate cluster(s) found (threshold {:.2}):",
+        clusters.len().to_string().yellow(),
+        threshold
+    );
+    println!();
+
+    for (i, cluster) in clusters.iter().enumerate() {
+        println!(
+            "{} Cluster {} — {} specs, max score {:.2}",
+            "⚠️".yellow(),
+            i + 1,
+            cluster.specs.len(),
+            cluster.score
+        );
+        for pair in &cluster.pairs {
+            println!(
+                "   {:.2}  {} ↔ {}",
+                pair.score,
+                pair.left.cyan(),
+                pair.right.cyan()
+            );
+        }
+        println!();
+    }
+
+    Ok(())
+}
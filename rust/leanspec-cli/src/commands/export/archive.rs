@@ -0,0 +1,57 @@
+//! `export --format ndjson|json` — write the project as a portable archive
+//!
+//! The archive holds every spec with its fields and links plus the source
+//! schema, and can be replayed into any adapter with `lean-spec import`.
+
+use colored::Colorize;
+use leanspec_core::adapters::Archive;
+use std::error::Error;
+use std::fs;
+
+pub fn run(
+    specs_dir: Option<&str>,
+    format: &str,
+    out: Option<&str>,
+    output_format: &str,
+) -> Result<(), Box<dyn Error>> {
+    if format != "ndjson" && format != "json" {
+        return Err(format!(
+            "Unknown export format '{}' (expected ndjson or json)",
+            format
+        )
+        .into());
+    }
+
+    let adapter = super::resolve_adapter(specs_dir)?;
+    let archive = Archive::export(adapter.as_ref())?;
+    let content = if format == "json" {
+        archive.to_json()? + "\n"
+    } else {
+        archive.to_ndjson()?
+    };
+
+    let Some(out) = out else {
+        print!("{}", content);
+        return Ok(());
+    };
+    fs::write(out, content)?;
+
+    if output_format == "json" {
+        let output = serde_json::json!({
+            "out": out,
+            "format": format,
+            "adapter": archive.adapter,
+            "specs": archive.specs.len(),
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!(
+            "{} Exported {} specs from {} to {}",
+            "✓".green(),
+            archive.specs.len(),
+            archive.adapter,
+            out.cyan()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,239 @@
+//! Markdown to HTML, driven by `pulldown-cmark` events
+//!
+//! The workspace builds `pulldown-cmark` without its `html` feature, so the
+//! site renders the event stream itself. Specs may come from issue trackers
+//! where anyone can write them, so raw HTML is shown as text (comments are
+//! dropped) and only http(s) and relative URLs are kept in links and images.
+
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+/// Escape text for use in HTML content and attribute values
+pub(super) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Whether `url` is relative or uses http(s). Browsers ignore whitespace and
+/// control characters inside a scheme, so those are skipped when reading it.
+pub(super) fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+        .collect();
+    let Some((scheme, _)) = url.split_once(':') else {
+        return true;
+    };
+    if scheme.contains(['/', '?', '#']) {
+        return true;
+    }
+    scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+}
+
+/// Raw HTML as text, without HTML comments. `in_comment` carries an open
+/// comment over to the next chunk.
+fn raw_html_as_text(raw: &str, in_comment: &mut bool) -> String {
+    let mut text = String::new();
+    let mut rest = raw;
+    loop {
+        if *in_comment {
+            match rest.find("-->") {
+                Some(end) => {
+                    rest = &rest[end + 3..];
+                    *in_comment = false;
+                }
+                None => break,
+            }
+        } else {
+            match rest.find("<!--") {
+                Some(start) => {
+                    text.push_str(&rest[..start]);
+                    rest = &rest[start + 4..];
+                    *in_comment = true;
+                }
+                None => {
+                    text.push_str(rest);
+                    break;
+                }
+            }
+        }
+    }
+    escape(&text)
+}
+
+/// Render `markdown` to HTML. `rewrite_link` may map a link destination to
+/// a page inside the site; other destinations are kept as written.
+pub(super) fn render_markdown(
+    markdown: &str,
+    rewrite_link: &dyn Fn(&str) -> Option<String>,
+) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+
+    let mut html = String::new();
+    let mut alignments: Vec<Alignment> = Vec::new();
+    let mut cell = 0;
+    let mut in_head = false;
+    let mut in_comment = false;
+    // Alt text of the image being rendered, if any
+    let mut image_alt: Option<String> = None;
+
+    for event in Parser::new_ext(markdown, options) {
+        if let Some(alt) = image_alt.as_mut() {
+            match event {
+                Event::End(TagEnd::Image) => {
+                    html.push_str(&format!("{}\" />", escape(alt)));
+                    image_alt = None;
+                }
+                Event::Text(text) | Event::Code(text) => alt.push_str(&text),
+                _ => {}
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Paragraph => html.push_str("<p>"),
+                Tag::Heading { level, .. } => html.push_str(&format!("<{}>", heading(level))),
+                Tag::BlockQuote(_) => html.push_str("<blockquote>\n"),
+                Tag::CodeBlock(CodeBlockKind::Fenced(lang)) if !lang.is_empty() => {
+                    let lang = lang.split_whitespace().next().unwrap_or_default();
+                    html.push_str(&format!("<pre><code class=\"language-{}\">", escape(lang)));
+                }
+                Tag::CodeBlock(_) => html.push_str("<pre><code>"),
+                Tag::List(Some(1)) => html.push_str("<ol>\n"),
+                Tag::List(Some(start)) => html.push_str(&format!("<ol start=\"{}\">\n", start)),
+                Tag::List(None) => html.push_str("<ul>\n"),
+                Tag::Item => html.push_str("<li>"),
+                Tag::FootnoteDefinition(name) => html.push_str(&format!(
+                    "<div class=\"footnote\" id=\"fn-{}\"><sup>{}</sup> ",
+                    escape(&name),
+                    escape(&name)
+                )),
+                Tag::Table(aligns) => {
+                    alignments = aligns;
+                    html.push_str("<table>\n");
+                }
+                Tag::TableHead => {
+                    in_head = true;
+                    cell = 0;
+                    html.push_str("<thead><tr>");
+                }
+                Tag::TableRow => {
+                    cell = 0;
+                    html.push_str("<tr>");
+                }
+                Tag::TableCell => {
+                    let name = if in_head { "th" } else { "td" };
+                    match alignments.get(cell) {
+                        Some(Alignment::Left) => {
+                            html.push_str(&format!("<{} style=\"text-align:left\">", name))
+                        }
+                        Some(Alignment::Center) => {
+                            html.push_str(&format!("<{} style=\"text-align:center\">", name))
+                        }
+                        Some(Alignment::Right) => {
+                            html.push_str(&format!("<{} style=\"text-align:right\">", name))
+                        }
+                        _ => html.push_str(&format!("<{}>", name)),
+                    }
+                }
+                Tag::Emphasis => html.push_str("<em>"),
+                Tag::Strong => html.push_str("<strong>"),
+                Tag::Strikethrough => html.push_str("<del>"),
+                Tag::Link {
+                    dest_url, title, ..
+                } => {
+                    let href = rewrite_link(&dest_url).unwrap_or_else(|| dest_url.to_string());
+                    if is_safe_url(&href) {
+                        html.push_str(&format!("<a href=\"{}\"", escape(&href)));
+                    } else {
+                        html.push_str("<a");
+                    }
+                    if !title.is_empty() {
+                        html.push_str(&format!(" title=\"{}\"", escape(&title)));
+                    }
+                    html.push('>');
+                }
+                Tag::Image { dest_url, .. } => {
+                    let src = if is_safe_url(&dest_url) {
+                        &dest_url
+                    } else {
+                        ""
+                    };
+                    html.push_str(&format!("<img src=\"{}\" alt=\"", escape(src)));
+                    image_alt = Some(String::new());
+                }
+                _ => {}
+            },
+            Event::End(tag) => match tag {
+                TagEnd::Paragraph => html.push_str("</p>\n"),
+                TagEnd::Heading(level) => html.push_str(&format!("</{}>\n", heading(level))),
+                TagEnd::BlockQuote(_) => html.push_str("</blockquote>\n"),
+                TagEnd::CodeBlock => html.push_str("</code></pre>\n"),
+                TagEnd::List(true) => html.push_str("</ol>\n"),
+                TagEnd::List(false) => html.push_str("</ul>\n"),
+                TagEnd::Item => html.push_str("</li>\n"),
+                TagEnd::FootnoteDefinition => html.push_str("</div>\n"),
+                TagEnd::Table => html.push_str("</tbody></table>\n"),
+                TagEnd::TableHead => {
+                    in_head = false;
+                    html.push_str("</tr></thead><tbody>\n");
+                }
+                TagEnd::TableRow => html.push_str("</tr>\n"),
+                TagEnd::TableCell => {
+                    html.push_str(if in_head { "</th>" } else { "</td>" });
+                    cell += 1;
+                }
+                TagEnd::Emphasis => html.push_str("</em>"),
+                TagEnd::Strong => html.push_str("</strong>"),
+                TagEnd::Strikethrough => html.push_str("</del>"),
+                TagEnd::Link => html.push_str("</a>"),
+                _ => {}
+            },
+            Event::Text(text) => html.push_str(&escape(&text)),
+            Event::Code(code) => html.push_str(&format!("<code>{}</code>", escape(&code))),
+            Event::Html(raw) | Event::InlineHtml(raw) => {
+                html.push_str(&raw_html_as_text(&raw, &mut in_comment))
+            }
+            Event::FootnoteReference(name) => html.push_str(&format!(
+                "<sup><a href=\"#fn-{}\">{}</a></sup>",
+                escape(&name),
+                escape(&name)
+            )),
+            Event::SoftBreak => html.push('\n'),
+            Event::HardBreak => html.push_str("<br />\n"),
+            Event::Rule => html.push_str("<hr />\n"),
+            Event::TaskListMarker(checked) => html.push_str(if checked {
+                "<input type=\"checkbox\" disabled checked /> "
+            } else {
+                "<input type=\"checkbox\" disabled /> "
+            }),
+            _ => {}
+        }
+    }
+
+    html
+}
+
+fn heading(level: HeadingLevel) -> &'static str {
+    match level {
+        HeadingLevel::H1 => "h1",
+        HeadingLevel::H2 => "h2",
+        HeadingLevel::H3 => "h3",
+        HeadingLevel::H4 => "h4",
+        HeadingLevel::H5 => "h5",
+        HeadingLevel::H6 => "h6",
+    }
+}
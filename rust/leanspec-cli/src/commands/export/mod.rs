@@ -0,0 +1,27 @@
+//! `export` commands — write specs out of the active adapter
+//!
+//! Exporters only see [`leanspec_core::SpecDoc`]s and the adapter schema,
+//! so they work the same for markdown, GitHub, Jira or any other backend.
+
+pub mod archive;
+mod html;
+pub mod site;
+
+use leanspec_core::adapters::{Adapter, AdapterConfig, AdapterRegistry};
+use std::error::Error;
+
+/// Resolve the adapter the same way `capabilities` does: an explicit
+/// `--specs-dir` forces markdown at that directory, otherwise the project's
+/// adapter configuration decides.
+pub(crate) fn resolve_adapter(specs_dir: Option<&str>) -> Result<Box<dyn Adapter>, Box<dyn Error>> {
+    Ok(match specs_dir {
+        Some(dir) => {
+            let config = AdapterConfig {
+                adapter: "markdown".into(),
+                settings: serde_json::json!({ "directory": dir }),
+            };
+            AdapterRegistry::create(&config)?
+        }
+        None => AdapterRegistry::from_project()?,
+    })
+}
@@ -0,0 +1,670 @@
+//! `export site` — render every spec to a static HTML site
+//!
+//! Pages are built from [`SpecDoc`]s and the adapter's [`SpecSchema`] only,
+//! so any adapter can be exported. All assets, including the search index,
+//! are written next to the pages and loaded with relative `<script>` tags,
+//! which keeps the site usable straight from `file://` without a server.
+
+use super::html::{escape, is_safe_url, render_markdown};
+use colored::Colorize;
+use leanspec_core::model::{FieldDisplay, FieldKind, FieldValue, SpecDoc, SpecSchema};
+use leanspec_core::{semantic, ListFilter};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+pub fn run(specs_dir: Option<&str>, out: &str, output_format: &str) -> Result<(), Box<dyn Error>> {
+    let adapter = super::resolve_adapter(specs_dir)?;
+    let filter = ListFilter {
+        include_archived: true,
+        ..Default::default()
+    };
+    let mut docs = adapter.list(&filter)?;
+    docs.sort_by_key(|doc| sort_key(&doc.id));
+
+    let site = Site::new(adapter.schema(), &docs);
+    let out_dir = Path::new(out);
+    fs::create_dir_all(out_dir.join("specs"))?;
+    fs::create_dir_all(out_dir.join("tags"))?;
+    fs::create_dir_all(out_dir.join("assets"))?;
+
+    let mut pages = 0;
+    let mut write = |path: &str, content: String| -> std::io::Result<()> {
+        pages += 1;
+        fs::write(out_dir.join(path), content)
+    };
+
+    write("index.html", site.index_page())?;
+    write("board.html", site.board_page())?;
+    write("tags/index.html", site.tags_page())?;
+    for (tag, ids) in &site.tags {
+        write(
+            &format!("tags/{}.html", site.tag_slugs[tag]),
+            site.tag_page(tag, ids),
+        )?;
+    }
+    for doc in &docs {
+        write(
+            &format!("specs/{}.html", site.slugs[&doc.id]),
+            site.spec_page(doc),
+        )?;
+    }
+    fs::write(out_dir.join("assets/style.css"), STYLE)?;
+    fs::write(out_dir.join("assets/search.js"), SEARCH_JS)?;
+    fs::write(out_dir.join("assets/search-index.js"), site.search_index()?)?;
+
+    if output_format == "json" {
+        let output = serde_json::json!({
+            "out": out,
+            "specs": docs.len(),
+            "tags": site.tags.len(),
+            "pages": pages,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!(
+            "{} Exported {} specs ({} pages) to {}",
+            "✓".green(),
+            docs.len(),
+            pages,
+            out.cyan()
+        );
+        println!(
+            "  Open {} in a browser",
+            out_dir.join("index.html").display()
+        );
+    }
+    Ok(())
+}
+
+/// Order by the first number in the id, so "9" < "10", "009-x" < "010-y"
+/// and "PROJ-9" < "PROJ-10"
+fn sort_key(id: &str) -> (u64, String) {
+    let digits: String = id
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    let number = digits.parse().unwrap_or(u64::MAX);
+    (number, id.to_string())
+}
+
+/// File-safe page name for an id or tag
+fn slugify(value: &str) -> String {
+    let slug: String = value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let slug = slug.trim_matches(['-', '.']).to_string();
+    if slug.is_empty() {
+        "spec".to_string()
+    } else {
+        slug
+    }
+}
+
+/// A distinct slug per key, numbering later keys whose slugs collide
+fn unique_slugs<'k>(keys: impl IntoIterator<Item = &'k String>) -> HashMap<String, String> {
+    let mut slugs = HashMap::new();
+    let mut used = HashSet::new();
+    for key in keys {
+        let base = slugify(key);
+        let mut slug = base.clone();
+        let mut n = 2;
+        while !used.insert(slug.clone()) {
+            slug = format!("{}-{}", base, n);
+            n += 1;
+        }
+        slugs.insert(key.clone(), slug);
+    }
+    slugs
+}
+
+struct Site<'a> {
+    schema: &'a SpecSchema,
+    docs: &'a [SpecDoc],
+    slugs: HashMap<String, String>,
+    /// Tag → page name, distinct even for tags like `API` and `api`
+    tag_slugs: HashMap<String, String>,
+    status_key: Option<&'a str>,
+    tags_key: Option<&'a str>,
+    /// Tag → ids of the specs carrying it
+    tags: BTreeMap<String, Vec<String>>,
+}
+
+impl<'a> Site<'a> {
+    fn new(schema: &'a SpecSchema, docs: &'a [SpecDoc]) -> Self {
+        let slugs = unique_slugs(docs.iter().map(|doc| &doc.id));
+
+        let tags_key = schema.key_for_semantic(semantic::TAGS);
+        let mut tags: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        if let Some(key) = tags_key {
+            for doc in docs {
+                for tag in doc.field(key).and_then(|v| v.as_strings()).unwrap_or(&[]) {
+                    tags.entry(tag.clone()).or_default().push(doc.id.clone());
+                }
+            }
+        }
+
+        let tag_slugs = unique_slugs(tags.keys());
+        Self {
+            schema,
+            docs,
+            slugs,
+            tag_slugs,
+            status_key: schema.key_for_semantic(semantic::STATUS),
+            tags_key,
+            tags,
+        }
+    }
+
+    fn doc(&self, id: &str) -> Option<&SpecDoc> {
+        self.docs.iter().find(|d| d.id == id)
+    }
+
+    /// Link to a spec page from a page `depth` directories deep
+    fn spec_link(&self, id: &str, label: &str, depth: usize) -> String {
+        match self.slugs.get(id) {
+            Some(slug) => format!(
+                "<a href=\"{}specs/{}.html\">{}</a>",
+                "../".repeat(depth),
+                slug,
+                escape(label)
+            ),
+            None => escape(label),
+        }
+    }
+
+    fn tag_link(&self, tag: &str, depth: usize) -> String {
+        format!(
+            "<a class=\"tag\" href=\"{}tags/{}.html\">{}</a>",
+            "../".repeat(depth),
+            self.tag_slugs[tag],
+            escape(tag)
+        )
+    }
+
+    fn status(&self, doc: &SpecDoc) -> Option<String> {
+        let key = self.status_key?;
+        match doc.field(key)? {
+            FieldValue::String(value) => Some(value.clone()),
+            FieldValue::Strings(values) => values.first().cloned(),
+            _ => None,
+        }
+    }
+
+    /// Display label for an enum value of `key`
+    fn option_label(&self, key: &str, value: &str) -> String {
+        match self.schema.field(key).map(|f| &f.kind) {
+            Some(FieldKind::Enum { options, .. }) => options
+                .iter()
+                .find(|o| o.value == value)
+                .map(|o| o.label.clone())
+                .unwrap_or_else(|| value.to_string()),
+            _ => value.to_string(),
+        }
+    }
+
+    fn status_badge(&self, doc: &SpecDoc) -> String {
+        match (self.status_key, self.status(doc)) {
+            (Some(key), Some(status)) => format!(
+                "<span class=\"status status-{}\">{}</span>",
+                slugify(&status),
+                escape(&self.option_label(key, &status))
+            ),
+            _ => String::new(),
+        }
+    }
+
+    fn doc_tags<'d>(&self, doc: &'d SpecDoc) -> &'d [String] {
+        self.tags_key
+            .and_then(|key| doc.field(key))
+            .and_then(|v| v.as_strings())
+            .unwrap_or(&[])
+    }
+
+    fn spec_table(&self, ids: &[String], depth: usize) -> String {
+        let mut rows = String::new();
+        for id in ids {
+            let Some(doc) = self.doc(id) else { continue };
+            let tags: Vec<String> = self
+                .doc_tags(doc)
+                .iter()
+                .map(|t| self.tag_link(t, depth))
+                .collect();
+            rows.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape(&doc.id),
+                self.spec_link(&doc.id, &doc.title, depth),
+                self.status_badge(doc),
+                tags.join(" ")
+            ));
+        }
+        format!(
+            "<table class=\"specs\">\n<thead><tr><th>ID</th><th>Title</th><th>Status</th><th>Tags</th></tr></thead>\n<tbody>\n{}</tbody>\n</table>\n",
+            rows
+        )
+    }
+
+    fn index_page(&self) -> String {
+        let ids: Vec<String> = self.docs.iter().map(|d| d.id.clone()).collect();
+        let body = format!(
+            "<h1>Specs</h1>\n\
+             <input id=\"search\" type=\"search\" placeholder=\"Search specs…\" autofocus />\n\
+             <ul id=\"results\"></ul>\n\
+             {}\
+             <script src=\"assets/search-index.js\"></script>\n\
+             <script src=\"assets/search.js\"></script>\n",
+            self.spec_table(&ids, 0)
+        );
+        layout("Specs", 0, &body)
+    }
+
+    fn board_page(&self) -> String {
+        let mut columns: Vec<(String, String)> = match self
+            .status_key
+            .and_then(|key| self.schema.field(key))
+            .map(|f| &f.kind)
+        {
+            Some(FieldKind::Enum { options, .. }) => options
+                .iter()
+                .map(|o| (o.value.clone(), o.label.clone()))
+                .collect(),
+            _ => Vec::new(),
+        };
+        for doc in self.docs {
+            if let Some(status) = self.status(doc) {
+                if !columns.iter().any(|(value, _)| *value == status) {
+                    columns.push((status.clone(), status));
+                }
+            }
+        }
+
+        let mut body = String::from("<h1>Board</h1>\n<div class=\"board\">\n");
+        for (value, label) in &columns {
+            let cards: Vec<String> = self
+                .docs
+                .iter()
+                .filter(|d| self.status(d).as_deref() == Some(value.as_str()))
+                .map(|d| {
+                    format!(
+                        "<li>{}<br /><small>{}</small></li>",
+                        self.spec_link(&d.id, &d.title, 0),
+                        escape(&d.id)
+                    )
+                })
+                .collect();
+            body.push_str(&format!(
+                "<section class=\"column\"><h2>{} <small>({})</small></h2>\n<ul>\n{}\n</ul></section>\n",
+                escape(label),
+                cards.len(),
+                cards.join("\n")
+            ));
+        }
+        let unset: Vec<String> = self
+            .docs
+            .iter()
+            .filter(|d| self.status(d).is_none())
+            .map(|d| format!("<li>{}</li>", self.spec_link(&d.id, &d.title, 0)))
+            .collect();
+        if !unset.is_empty() {
+            body.push_str(&format!(
+                "<section class=\"column\"><h2>No status <small>({})</small></h2>\n<ul>\n{}\n</ul></section>\n",
+                unset.len(),
+                unset.join("\n")
+            ));
+        }
+        body.push_str("</div>\n");
+        layout("Board", 0, &body)
+    }
+
+    fn tags_page(&self) -> String {
+        let items: Vec<String> = self
+            .tags
+            .iter()
+            .map(|(tag, ids)| {
+                format!(
+                    "<li><a href=\"{}.html\">{}</a> ({})</li>",
+                    self.tag_slugs[tag],
+                    escape(tag),
+                    ids.len()
+                )
+            })
+            .collect();
+        let body = if items.is_empty() {
+            "<h1>Tags</h1>\n<p>No tags.</p>\n".to_string()
+        } else {
+            format!("<h1>Tags</h1>\n<ul>\n{}\n</ul>\n", items.join("\n"))
+        };
+        layout("Tags", 1, &body)
+    }
+
+    fn tag_page(&self, tag: &str, ids: &[String]) -> String {
+        let body = format!("<h1>Tag: {}</h1>\n{}", escape(tag), self.spec_table(ids, 1));
+        layout(&format!("Tag: {}", tag), 1, &body)
+    }
+
+    fn spec_page(&self, doc: &SpecDoc) -> String {
+        let mut body = format!(
+            "<h1>{} {}</h1>\n<p class=\"id\">{}</p>\n",
+            escape(&doc.title),
+            self.status_badge(doc),
+            escape(&doc.id)
+        );
+
+        // Metadata: inline schema fields, then fields the schema doesn't know
+        let mut meta = Vec::new();
+        for field in &self.schema.fields {
+            if field.display != FieldDisplay::Inline {
+                continue;
+            }
+            if let Some(value) = doc.field(&field.key) {
+                meta.push((field.label.clone(), self.render_inline(&field.key, value)));
+            }
+        }
+        let mut unknown: Vec<&String> = doc
+            .fields
+            .keys()
+            .filter(|key| self.schema.field(key).is_none())
+            .collect();
+        unknown.sort();
+        for key in unknown {
+            meta.push((key.clone(), self.render_inline(key, &doc.fields[key])));
+        }
+        // Skip adapter timestamps the schema already shows as a field
+        let shown = |meta: &[(String, String)], prefix: &str| {
+            meta.iter().any(|(label, _)| label.starts_with(prefix))
+        };
+        if let Some(created) = doc.created_at.filter(|_| !shown(&meta, "Created")) {
+            meta.push(("Created at".into(), created.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(updated) = doc.updated_at.filter(|_| !shown(&meta, "Updated")) {
+            meta.push(("Updated at".into(), updated.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(url) = doc.url.as_ref().filter(|url| is_safe_url(url)) {
+            meta.push((
+                "Source".into(),
+                format!("<a href=\"{}\">{}</a>", escape(url), escape(url)),
+            ));
+        }
+        if !meta.is_empty() {
+            body.push_str("<dl class=\"meta\">\n");
+            for (label, value) in meta {
+                body.push_str(&format!("<dt>{}</dt><dd>{}</dd>\n", escape(&label), value));
+            }
+            body.push_str("</dl>\n");
+        }
+
+        body.push_str(&self.render_links(doc));
+
+        let sections: Vec<_> = self
+            .schema
+            .fields
+            .iter()
+            .filter(|f| f.display == FieldDisplay::Section)
+            .filter_map(|f| doc.field(&f.key).map(|v| (f, v)))
+            .collect();
+        let single = sections.len() == 1;
+        for (field, value) in sections {
+            body.push_str("<section class=\"field\">\n");
+            if !single {
+                body.push_str(&format!("<h2>{}</h2>\n", escape(&field.label)));
+            }
+            body.push_str(&self.render_section(value, &doc.title));
+            body.push_str("</section>\n");
+        }
+
+        layout(&doc.title, 1, &body)
+    }
+
+    fn render_inline(&self, key: &str, value: &FieldValue) -> String {
+        match value {
+            FieldValue::String(s) => escape(&self.option_label(key, s)),
+            FieldValue::Number(n) => n.to_string(),
+            FieldValue::Bool(b) => if *b { "yes" } else { "no" }.to_string(),
+            FieldValue::Timestamp(t) => t.format("%Y-%m-%d").to_string(),
+            FieldValue::Strings(values) if Some(key) == self.tags_key => values
+                .iter()
+                .map(|t| self.tag_link(t, 1))
+                .collect::<Vec<_>>()
+                .join(" "),
+            FieldValue::Strings(values) => values
+                .iter()
+                .map(|v| escape(&self.option_label(key, v)))
+                .collect::<Vec<_>>()
+                .join(", "),
+            FieldValue::Checklist(items) => {
+                let done = items.iter().filter(|i| i.checked).count();
+                format!("{}/{} done", done, items.len())
+            }
+            FieldValue::References(refs) => refs
+                .iter()
+                .map(|r| self.spec_link(&r.id, r.title.as_deref().unwrap_or(&r.id), 1))
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    fn render_section(&self, value: &FieldValue, title: &str) -> String {
+        match value {
+            FieldValue::String(markdown) => {
+                render_markdown(strip_title(markdown, title), &|dest| {
+                    self.rewrite_link(dest)
+                })
+            }
+            FieldValue::Checklist(items) => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|item| {
+                        format!(
+                            "<li><input type=\"checkbox\" disabled{} /> {}</li>",
+                            if item.checked { " checked" } else { "" },
+                            escape(&item.text)
+                        )
+                    })
+                    .collect();
+                format!("<ul class=\"checklist\">\n{}\n</ul>\n", items.join("\n"))
+            }
+            other => format!("<p>{}</p>\n", self.render_inline("", other)),
+        }
+    }
+
+    /// Point relative links at another spec's README to its page
+    fn rewrite_link(&self, dest: &str) -> Option<String> {
+        if dest.contains("://") || dest.starts_with('#') {
+            return None;
+        }
+        let path = dest.split('#').next().unwrap_or(dest);
+        let mut segments = path
+            .trim_end_matches('/')
+            .split('/')
+            .filter(|s| !s.is_empty() && *s != "." && *s != "..");
+        let target = match segments.next_back()? {
+            "README.md" => segments.next_back()?,
+            last => last,
+        };
+        self.slugs.get(target).map(|slug| format!("{}.html", slug))
+    }
+
+    fn render_links(&self, doc: &SpecDoc) -> String {
+        let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let outgoing: HashSet<(&str, &str)> = doc
+            .links
+            .iter()
+            .map(|l| (l.link_type.as_str(), l.target_id.as_str()))
+            .collect();
+
+        for link in &doc.links {
+            let label = self.link_label(&link.link_type);
+            let title = link
+                .target_title
+                .clone()
+                .or_else(|| self.doc(&link.target_id).map(|d| d.title.clone()))
+                .unwrap_or_else(|| link.target_id.clone());
+            groups
+                .entry(label)
+                .or_default()
+                .push(self.spec_link(&link.target_id, &title, 1));
+        }
+
+        // Incoming links shown under the link type's inverse label
+        for other in self.docs {
+            for link in other.links.iter().filter(|l| l.target_id == doc.id) {
+                let Some(def) = self
+                    .schema
+                    .link_types
+                    .iter()
+                    .find(|t| t.key == link.link_type)
+                else {
+                    continue;
+                };
+                let (Some(inverse), Some(label)) = (&def.inverse_key, &def.inverse_label) else {
+                    continue;
+                };
+                if outgoing.contains(&(inverse.as_str(), other.id.as_str())) {
+                    continue;
+                }
+                groups
+                    .entry(label.clone())
+                    .or_default()
+                    .push(self.spec_link(&other.id, &other.title, 1));
+            }
+        }
+
+        if groups.is_empty() {
+            return String::new();
+        }
+        let mut html = String::from("<dl class=\"links\">\n");
+        for (label, links) in groups {
+            html.push_str(&format!(
+                "<dt>{}</dt><dd>{}</dd>\n",
+                escape(&label),
+                links.join(", ")
+            ));
+        }
+        html.push_str("</dl>\n");
+        html
+    }
+
+    fn link_label(&self, link_type: &str) -> String {
+        self.schema
+            .link_types
+            .iter()
+            .find(|t| t.key == link_type)
+            .map(|t| t.label.clone())
+            .unwrap_or_else(|| link_type.to_string())
+    }
+
+    /// `window.LEANSPEC_SEARCH_INDEX = [...]`, loadable from `file://`
+    fn search_index(&self) -> Result<String, serde_json::Error> {
+        let entries: Vec<serde_json::Value> = self
+            .docs
+            .iter()
+            .map(|doc| {
+                let text: Vec<&str> = doc.fields.values().filter_map(|v| v.as_str()).collect();
+                serde_json::json!({
+                    "id": doc.id,
+                    "title": doc.title,
+                    "url": format!("specs/{}.html", self.slugs[&doc.id]),
+                    "status": self.status(doc),
+                    "tags": self.doc_tags(doc),
+                    "text": text.join("\n"),
+                })
+            })
+            .collect();
+        Ok(format!(
+            "window.LEANSPEC_SEARCH_INDEX = {};\n",
+            serde_json::to_string(&entries)?
+        ))
+    }
+}
+
+/// Drop a leading `# Title` that repeats the page heading
+fn strip_title<'s>(markdown: &'s str, title: &str) -> &'s str {
+    let trimmed = markdown.trim_start();
+    match trimmed.split_once('\n') {
+        Some((first, rest)) if first.trim() == format!("# {}", title) => rest,
+        None if trimmed.trim() == format!("# {}", title) => "",
+        _ => markdown,
+    }
+}
+
+fn layout(title: &str, depth: usize, body: &str) -> String {
+    let root = "../".repeat(depth);
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\" />\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\" />\n\
+         <title>{title}</title>\n<link rel=\"stylesheet\" href=\"{root}assets/style.css\" />\n\
+         </head>\n<body>\n<nav><a href=\"{root}index.html\">Specs</a> \
+         <a href=\"{root}board.html\">Board</a> <a href=\"{root}tags/index.html\">Tags</a></nav>\n\
+         <main>\n{body}</main>\n</body>\n</html>\n",
+        title = escape(title),
+        root = root,
+        body = body
+    )
+}
+
+const STYLE: &str = r#"body { font-family: system-ui, sans-serif; margin: 0; color: #1f2937; }
+nav { background: #111827; padding: 0.75rem 1.5rem; }
+nav a { color: #f9fafb; margin-right: 1rem; text-decoration: none; font-weight: 600; }
+main { max-width: 60rem; margin: 0 auto; padding: 1.5rem; }
+a { color: #2563eb; }
+table { border-collapse: collapse; width: 100%; margin: 1rem 0; }
+th, td { border-bottom: 1px solid #e5e7eb; padding: 0.4rem 0.6rem; text-align: left; vertical-align: top; }
+pre { background: #f3f4f6; padding: 0.75rem; overflow-x: auto; }
+code { font-family: ui-monospace, monospace; font-size: 0.9em; }
+blockquote { border-left: 4px solid #d1d5db; margin-left: 0; padding-left: 1rem; color: #4b5563; }
+.id { color: #6b7280; margin-top: -0.5rem; }
+.status { font-size: 0.75rem; padding: 0.15rem 0.5rem; border-radius: 999px; background: #e5e7eb; vertical-align: middle; }
+.status-complete { background: #d1fae5; }
+.status-in-progress { background: #fef3c7; }
+.status-planned { background: #dbeafe; }
+.status-archived { background: #f3f4f6; color: #6b7280; }
+.tag { background: #eef2ff; border-radius: 4px; padding: 0 0.35rem; text-decoration: none; font-size: 0.85em; }
+dl.meta, dl.links { display: grid; grid-template-columns: max-content 1fr; gap: 0.25rem 1rem; }
+dt { font-weight: 600; }
+dd { margin: 0; }
+.board { display: flex; gap: 1rem; overflow-x: auto; align-items: flex-start; }
+.column { flex: 1; min-width: 12rem; background: #f9fafb; border-radius: 6px; padding: 0 0.75rem; }
+.column ul { list-style: none; padding: 0; }
+.column li { background: #fff; border: 1px solid #e5e7eb; border-radius: 4px; padding: 0.5rem; margin-bottom: 0.5rem; }
+ul.checklist, li:has(> input[type=checkbox]) { list-style: none; }
+#search { width: 100%; padding: 0.5rem; font-size: 1rem; box-sizing: border-box; }
+#results:empty { display: none; }
+"#;
+
+const SEARCH_JS: &str = r#"(function () {
+  var index = window.LEANSPEC_SEARCH_INDEX || [];
+  var input = document.getElementById("search");
+  var results = document.getElementById("results");
+  if (!input || !results) return;
+  input.addEventListener("input", function () {
+    var terms = input.value.toLowerCase().split(/\s+/).filter(Boolean);
+    results.innerHTML = "";
+    if (!terms.length) return;
+    index
+      .filter(function (entry) {
+        var haystack = [entry.id, entry.title, entry.status || "", entry.tags.join(" "), entry.text]
+          .join("\n")
+          .toLowerCase();
+        return terms.every(function (term) { return haystack.indexOf(term) !== -1; });
+      })
+      .slice(0, 50)
+      .forEach(function (entry) {
+        var item = document.createElement("li");
+        var link = document.createElement("a");
+        link.href = entry.url;
+        link.textContent = entry.title + " (" + entry.id + ")";
+        item.appendChild(link);
+        results.appendChild(item);
+      });
+  });
+})();
+"#;
@@ -2,49 +2,86 @@
 //!
 //! Shows timeline with dependencies in Gantt-style format.
 
+use super::export::resolve_adapter;
 use colored::Colorize;
-use leanspec_core::{DependencyGraph, SpecLoader, SpecStatus};
+use leanspec_core::model::SpecDoc;
+use leanspec_core::{semantic, ListFilter, SpecGraph, SpecStatus};
 use std::error::Error;
 
+/// Dependency link key shared by the built-in adapters.
+const DEPENDS_ON: &str = "depends_on";
+
 pub fn run(
-    specs_dir: &str,
+    specs_dir: Option<&str>,
     filter_status: Option<String>,
     output_format: &str,
 ) -> Result<(), Box<dyn Error>> {
-    let loader = SpecLoader::new(specs_dir);
-    let specs = loader.load_all()?;
-
-    let dep_graph = DependencyGraph::new(&specs);
+    let adapter = resolve_adapter(specs_dir)?;
+    let docs = adapter.list(&ListFilter {
+        include_archived: true,
+        ..Default::default()
+    })?;
+    let status_key = adapter
+        .schema()
+        .key_for_semantic(semantic::STATUS)
+        .map(String::from);
+    let graph = SpecGraph::new(docs, &adapter.schema().link_types);
+
+    let raw_status = |doc: &SpecDoc| -> String {
+        status_key
+            .as_deref()
+            .and_then(|key| doc.field_str(key))
+            .unwrap_or_default()
+            .to_string()
+    };
+    let status = |doc: &SpecDoc| raw_status(doc).parse::<SpecStatus>().ok();
+    let depends_on = |doc: &SpecDoc| -> Vec<String> {
+        graph
+            .related(&doc.id, DEPENDS_ON)
+            .iter()
+            .map(|dep| dep.id.clone())
+            .collect()
+    };
+    // Dependencies that are not complete yet
+    let blocked_by = |doc: &SpecDoc| -> Vec<String> {
+        graph
+            .related(&doc.id, DEPENDS_ON)
+            .iter()
+            .filter(|dep| status(dep) != Some(SpecStatus::Complete))
+            .map(|dep| dep.id.clone())
+            .collect()
+    };
 
     // Filter specs
-    let filtered: Vec<_> = specs
+    let filtered: Vec<&SpecDoc> = graph
+        .docs()
         .iter()
         .filter(|s| {
-            if let Some(ref status) = filter_status {
-                s.frontmatter.status.to_string() == *status
+            if let Some(ref wanted) = filter_status {
+                raw_status(s) == *wanted
             } else {
                 // Default: show planned and in-progress
                 matches!(
-                    s.frontmatter.status,
-                    SpecStatus::Planned | SpecStatus::InProgress
+                    status(s),
+                    Some(SpecStatus::Planned | SpecStatus::InProgress)
                 )
             }
         })
         .collect();
 
     // Get topological order if possible
-    let ordered_paths: Vec<String> = match dep_graph.topological_sort() {
-        Some(order) => order.iter().map(|s| s.path.clone()).collect(),
-        None => filtered.iter().map(|s| s.path.clone()).collect(),
+    let ordered_ids: Vec<&str> = match graph.topological_order(DEPENDS_ON) {
+        Ok(order) => order.iter().map(|s| s.id.as_str()).collect(),
+        Err(_) => filtered.iter().map(|s| s.id.as_str()).collect(),
     };
 
     // Reorder filtered specs according to topological order
     let mut sorted_specs: Vec<_> = filtered
         .iter()
         .filter_map(|s| {
-            ordered_paths
+            ordered_ids
                 .iter()
-                .position(|p| p == &s.path)
+                .position(|id| *id == s.id)
                 .map(|pos| (pos, *s))
         })
         .collect();
@@ -71,33 +108,16 @@ pub fn run(
             specs: sorted_specs
                 .iter()
                 .enumerate()
-                .map(|(i, (_, s))| {
-                    // Find incomplete dependencies that block this spec
-                    let blocked_by: Vec<_> = s
-                        .frontmatter
-                        .depends_on
-                        .iter()
-                        .filter(|dep| {
-                            specs
-                                .iter()
-                                .find(|sp| &sp.path == *dep)
-                                .map(|sp| sp.frontmatter.status != SpecStatus::Complete)
-                                .unwrap_or(false)
-                        })
-                        .cloned()
-                        .collect();
-
-                    GanttSpec {
-                        path: s.path.clone(),
-                        title: s.title.clone(),
-                        status: s.frontmatter.status.to_string(),
-                        depends_on: s.frontmatter.depends_on.clone(),
-                        blocked_by,
-                        order: i + 1,
-                    }
+                .map(|(i, (_, s))| GanttSpec {
+                    path: s.id.clone(),
+                    title: s.title.clone(),
+                    status: raw_status(s),
+                    depends_on: depends_on(s),
+                    blocked_by: blocked_by(s),
+                    order: i + 1,
                 })
                 .collect(),
-            execution_order: sorted_specs.iter().map(|(_, s)| s.path.clone()).collect(),
+            execution_order: sorted_specs.iter().map(|(_, s)| s.id.clone()).collect(),
         };
 
         println!("{}", serde_json::to_string_pretty(&output)?);
@@ -126,28 +146,17 @@ pub fn run(
         let order_num = format!("{:2}.", i + 1);
 
         // Status indicator
-        let status_icon = spec.frontmatter.status_emoji();
-        let status_bar = match spec.frontmatter.status {
-            SpecStatus::Complete => "████████".green(),
-            SpecStatus::InProgress => "████░░░░".yellow(),
-            SpecStatus::Planned => "░░░░░░░░".blue(),
-            SpecStatus::Draft => "░░░░░░░░".dimmed(),
-            SpecStatus::Archived => "--------".dimmed(),
+        let (status_icon, status_bar) = match status(spec) {
+            Some(SpecStatus::Complete) => ("✅", "████████".green()),
+            Some(SpecStatus::InProgress) => ("⏳", "████░░░░".yellow()),
+            Some(SpecStatus::Planned) => ("📅", "░░░░░░░░".blue()),
+            Some(SpecStatus::Draft) => ("📝", "░░░░░░░░".dimmed()),
+            Some(SpecStatus::Archived) => ("📦", "--------".dimmed()),
+            None => ("•", "░░░░░░░░".dimmed()),
         };
 
         // Check if blocked
-        let blocked_by: Vec<_> = spec
-            .frontmatter
-            .depends_on
-            .iter()
-            .filter(|dep| {
-                specs
-                    .iter()
-                    .find(|s| &s.path == *dep)
-                    .map(|s| s.frontmatter.status != SpecStatus::Complete)
-                    .unwrap_or(false)
-            })
-            .collect();
+        let blocked_by = blocked_by(spec);
 
         let blocked_indicator = if !blocked_by.is_empty() {
             format!(" {} blocked by {}", "⚠".red(), blocked_by.len())
@@ -172,10 +181,9 @@ pub fn run(
         );
 
         // Show dependencies line if any
-        if !spec.frontmatter.depends_on.is_empty() {
-            let deps_str = spec
-                .frontmatter
-                .depends_on
+        let deps = depends_on(spec);
+        if !deps.is_empty() {
+            let deps_str = deps
                 .iter()
                 .map(|d: &String| {
                     // Extract number from dependency path
@@ -204,27 +212,19 @@ pub fn run(
     // Summary
     let complete = sorted_specs
         .iter()
-        .filter(|(_, s)| s.frontmatter.status == SpecStatus::Complete)
+        .filter(|(_, s)| status(s) == Some(SpecStatus::Complete))
         .count();
     let in_progress = sorted_specs
         .iter()
-        .filter(|(_, s)| s.frontmatter.status == SpecStatus::InProgress)
+        .filter(|(_, s)| status(s) == Some(SpecStatus::InProgress))
         .count();
     let planned = sorted_specs
         .iter()
-        .filter(|(_, s)| s.frontmatter.status == SpecStatus::Planned)
+        .filter(|(_, s)| status(s) == Some(SpecStatus::Planned))
         .count();
     let blocked: usize = sorted_specs
         .iter()
-        .filter(|(_, s)| {
-            s.frontmatter.depends_on.iter().any(|dep| {
-                specs
-                    .iter()
-                    .find(|sp| &sp.path == dep)
-                    .map(|sp| sp.frontmatter.status != SpecStatus::Complete)
-                    .unwrap_or(false)
-            })
-        })
+        .filter(|(_, s)| !blocked_by(s).is_empty())
         .count();
 
     println!(
@@ -0,0 +1,290 @@
+//! Git hooks command implementation
+//!
+//! `lean-spec hooks install` writes pre-commit and commit-msg hooks into the
+//! current repository. The hooks call back into `lean-spec hooks pre-commit`
+//! and `lean-spec hooks commit-msg`, which validate staged specs and check
+//! `spec:` trailers respectively.
+
+use colored::Colorize;
+use leanspec_core::git::operations::run_git;
+use leanspec_core::git::{parse_spec_trailers, spec_ref_matches};
+use leanspec_core::SpecLoader;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use super::validate;
+
+/// Marker identifying hooks written by `lean-spec hooks install`.
+const HOOK_MARKER: &str = "# lean-spec managed hook";
+
+const HOOKS: &[&str] = &["pre-commit", "commit-msg"];
+
+pub enum HooksCommand {
+    Install { force: bool },
+    Uninstall,
+    PreCommit,
+    CommitMsg { file: String },
+}
+
+pub fn run(
+    specs_dir: &str,
+    explicit_specs_dir: Option<&str>,
+    cmd: HooksCommand,
+) -> Result<(), Box<dyn Error>> {
+    match cmd {
+        HooksCommand::Install { force } => install(explicit_specs_dir, force),
+        HooksCommand::Uninstall => uninstall(),
+        HooksCommand::PreCommit => pre_commit(specs_dir),
+        HooksCommand::CommitMsg { file } => commit_msg(specs_dir, &file),
+    }
+}
+
+fn hooks_dir() -> Result<PathBuf, Box<dyn Error>> {
+    // Honors core.hooksPath and linked worktrees.
+    let path = run_git(&["rev-parse", "--git-path", "hooks"], Path::new("."))
+        .map_err(|_| "Not inside a git repository")?;
+    Ok(PathBuf::from(path))
+}
+
+fn hook_script(hook: &str, explicit_specs_dir: Option<&str>) -> String {
+    let exe = std::env::current_exe()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| "lean-spec".to_string());
+    let specs_arg = explicit_specs_dir
+        .map(|dir| format!(" --specs-dir '{}'", dir.replace('\'', "'\\''")))
+        .unwrap_or_default();
+    let args = if hook == "commit-msg" { " \"$1\"" } else { "" };
+
+    format!(
+        "#!/bin/sh\n\
+         {marker} — installed by `lean-spec hooks install`\n\
+         LEANSPEC='{exe}'\n\
+         [ -x \"$LEANSPEC\" ] || LEANSPEC=lean-spec\n\
+         exec \"$LEANSPEC\" hooks {hook}{args}{specs_arg}\n",
+        marker = HOOK_MARKER,
+        exe = exe.replace('\'', "'\\''"),
+    )
+}
+
+fn install(explicit_specs_dir: Option<&str>, force: bool) -> Result<(), Box<dyn Error>> {
+    let dir = hooks_dir()?;
+    fs::create_dir_all(&dir)?;
+
+    let mut skipped = Vec::new();
+    for hook in HOOKS {
+        let path = dir.join(hook);
+        if let Ok(existing) = fs::read_to_string(&path) {
+            if !existing.contains(HOOK_MARKER) && !force {
+                skipped.push(*hook);
+                continue;
+            }
+        }
+
+        fs::write(&path, hook_script(hook, explicit_specs_dir))?;
+        make_executable(&path)?;
+        println!("{} Installed {} hook", "✓".green(), hook.cyan());
+    }
+
+    if !skipped.is_empty() {
+        return Err(format!(
+            "Existing hook(s) not managed by lean-spec: {}. Re-run with --force to overwrite.",
+            skipped.join(", ")
+        )
+        .into());
+    }
+
+    println!(
+        "  {}",
+        "Add `spec: <number>` trailers to commit messages to link commits to specs.".dimmed()
+    );
+    Ok(())
+}
+
+fn uninstall() -> Result<(), Box<dyn Error>> {
+    let dir = hooks_dir()?;
+    for hook in HOOKS {
+        let path = dir.join(hook);
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing.contains(HOOK_MARKER) => {
+                fs::remove_file(&path)?;
+                println!("{} Removed {} hook", "✓".green(), hook.cyan());
+            }
+            Ok(_) => println!(
+                "{} Left {} hook in place (not managed by lean-spec)",
+                "ℹ️".cyan(),
+                hook
+            ),
+            Err(_) => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<(), Box<dyn Error>> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = fs::metadata(path)?.permissions();
+    perms.set_mode(0o755);
+    fs::set_permissions(path, perms)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<(), Box<dyn Error>> {
+    Ok(())
+}
+
+/// Validate only the specs touched by staged files.
+fn pre_commit(specs_dir: &str) -> Result<(), Box<dyn Error>> {
+    let cwd = Path::new(".");
+    let repo_root = PathBuf::from(run_git(&["rev-parse", "--show-toplevel"], cwd)?);
+    let staged = run_git(
+        &["diff", "--cached", "--name-only", "--diff-filter=ACMR"],
+        cwd,
+    )?;
+
+    let specs_root = match Path::new(specs_dir).canonicalize() {
+        Ok(path) => path,
+        // No specs directory means nothing to validate.
+        Err(_) => return Ok(()),
+    };
+    let repo_root = repo_root.canonicalize()?;
+    let Ok(specs_prefix) = specs_root.strip_prefix(&repo_root) else {
+        return Ok(());
+    };
+
+    let changed = changed_specs(staged.lines(), specs_prefix);
+    if changed.is_empty() {
+        return Ok(());
+    }
+
+    // Validate the staged versions rather than the working tree, so partially
+    // staged files are checked as they will be committed.
+    let index = tempfile::tempdir()?;
+    checkout_index(&repo_root, specs_prefix, index.path())?;
+    let index_specs_dir = index.path().join(specs_prefix);
+    let index_specs_dir = index_specs_dir.to_string_lossy();
+
+    let loader = SpecLoader::new(index_specs_dir.as_ref());
+    let mut specs = Vec::new();
+    for name in changed {
+        if loader.load(&name)?.is_some() {
+            specs.push(name);
+        }
+    }
+    if specs.is_empty() {
+        return Ok(());
+    }
+
+    println!(
+        "{} Validating {} staged spec(s): {}",
+        "→".cyan(),
+        specs.len(),
+        specs.join(", ")
+    );
+    validate::run(&index_specs_dir, specs, false, false, false, false, "text")
+}
+
+/// Write the index versions of the files under `prefix` into `dest`.
+fn checkout_index(repo_root: &Path, prefix: &Path, dest: &Path) -> Result<(), Box<dyn Error>> {
+    let pathspec = match prefix.to_string_lossy() {
+        p if p.is_empty() => ".".to_string(),
+        p => p.to_string(),
+    };
+    let files = run_git(&["ls-files", "-z", "--", &pathspec], repo_root)?;
+    let files: Vec<&str> = files.split('\0').filter(|f| !f.is_empty()).collect();
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    let dest_prefix = format!("--prefix={}/", dest.display());
+    let mut args = vec!["checkout-index", dest_prefix.as_str(), "--"];
+    args.extend(files);
+    run_git(&args, repo_root)?;
+    Ok(())
+}
+
+/// Spec directory names for staged markdown files under `specs_prefix`.
+fn changed_specs<'a>(staged: impl Iterator<Item = &'a str>, specs_prefix: &Path) -> Vec<String> {
+    let mut specs = BTreeSet::new();
+    for file in staged {
+        let path = Path::new(file);
+        if path.extension().and_then(|e| e.to_str()) != Some("md") {
+            continue;
+        }
+        let Ok(relative) = path.strip_prefix(specs_prefix) else {
+            continue;
+        };
+        let mut dirs = relative.components().filter_map(|c| match c {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        });
+        let first = dirs.next();
+        let spec = match first {
+            Some("archived") => dirs.next(),
+            other => other,
+        };
+        // Skip files that sit directly in the specs directory.
+        if let Some(spec) = spec.filter(|s| !s.ends_with(".md")) {
+            specs.insert(spec.to_string());
+        }
+    }
+    specs.into_iter().collect()
+}
+
+/// Check that every `spec:` trailer points at an existing spec.
+fn commit_msg(specs_dir: &str, file: &str) -> Result<(), Box<dyn Error>> {
+    let message: String = fs::read_to_string(file)?
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let references = parse_spec_trailers(&message);
+    if references.is_empty() {
+        return Ok(());
+    }
+
+    let specs = SpecLoader::new(specs_dir).load_all()?;
+    let mut linked = Vec::new();
+    let mut unknown = Vec::new();
+    for reference in &references {
+        match specs.iter().find(|s| spec_ref_matches(reference, &s.path)) {
+            Some(spec) => linked.push(spec.path.clone()),
+            None => unknown.push(reference.as_str()),
+        }
+    }
+
+    if !unknown.is_empty() {
+        return Err(format!(
+            "Commit message references unknown spec(s): {}",
+            unknown.join(", ")
+        )
+        .into());
+    }
+
+    println!("{} Linked to {}", "✓".green(), linked.join(", ").cyan());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_specs_maps_staged_files_to_spec_dirs() {
+        let staged = [
+            "specs/001-auth/README.md",
+            "specs/001-auth/DESIGN.md",
+            "specs/archived/002-old/README.md",
+            "specs/README.md",
+            "specs/003-api/diagram.png",
+            "src/main.rs",
+            "docs/004-guide/README.md",
+        ];
+        let changed = changed_specs(staged.into_iter(), Path::new("specs"));
+        assert_eq!(changed, vec!["001-auth", "002-old"]);
+    }
+}
@@ -0,0 +1,122 @@
+//! Impact command implementation
+//!
+//! Compares a spec with its version at a git revision and lists the started
+//! downstream specs that a change to its acceptance criteria or design
+//! affects, optionally tagging them for review.
+
+use colored::Colorize;
+use leanspec_core::adapters::markdown::{
+    analyze_change, mark_needs_review, ChangeImpact, SectionDigest, NEEDS_REVIEW_TAG,
+};
+use leanspec_core::git::operations::file_at_revision;
+use leanspec_core::{DependencyGraph, SpecLoader};
+use std::error::Error;
+
+pub fn run(
+    specs_dir: &str,
+    spec: &str,
+    since: &str,
+    depth: usize,
+    mark: bool,
+    output_format: &str,
+) -> Result<(), Box<dyn Error>> {
+    let loader = SpecLoader::new(specs_dir);
+    let spec_info = loader
+        .load(spec)?
+        .ok_or_else(|| format!("Spec not found: {}", spec))?;
+
+    // A spec that didn't exist at `since` counts as entirely changed.
+    let before = file_at_revision(&spec_info.file_path, since)?.unwrap_or_default();
+    let before = SectionDigest::new(&before);
+
+    let all_specs = loader.load_all()?;
+    let graph = DependencyGraph::new(&all_specs);
+    let impact = analyze_change(&graph, &spec_info, &before, depth).unwrap_or(ChangeImpact {
+        spec: spec_info.path.clone(),
+        title: spec_info.title.clone(),
+        changed_sections: Vec::new(),
+        affected: Vec::new(),
+    });
+
+    let marked = if mark {
+        mark_needs_review(specs_dir, &impact)?
+    } else {
+        Vec::new()
+    };
+
+    if output_format == "json" {
+        let output = serde_json::json!({
+            "spec": impact.spec,
+            "title": impact.title,
+            "since": since,
+            "changed_sections": impact.changed_sections,
+            "affected": impact.affected.iter().map(|a| serde_json::json!({
+                "path": a.path,
+                "title": a.title,
+                "status": a.status,
+                "assignee": a.assignee,
+            })).collect::<Vec<_>>(),
+            "marked": marked,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!(
+        "\n{} {} {} {}",
+        "Impact of changes to".bold(),
+        impact.spec.cyan(),
+        "since".bold(),
+        since
+    );
+
+    if impact.changed_sections.is_empty() {
+        println!("{}", "No acceptance criteria or design changes.".dimmed());
+        return Ok(());
+    }
+    println!("Changed: {}", impact.changed_sections.join(", "));
+
+    if impact.affected.is_empty() {
+        println!("{}", "No started downstream specs are affected.".dimmed());
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "{}",
+        format!("Affected ({}):", impact.affected.len()).bold()
+    );
+    for affected in &impact.affected {
+        let assignee = affected
+            .assignee
+            .as_deref()
+            .map(|a| format!(" @{}", a))
+            .unwrap_or_default();
+        println!(
+            "  {} - {} [{}]{}",
+            affected.path.cyan(),
+            affected.title,
+            affected.status,
+            assignee.dimmed()
+        );
+    }
+
+    if mark {
+        println!();
+        if marked.is_empty() {
+            println!(
+                "{}",
+                format!("All affected specs are already tagged {}", NEEDS_REVIEW_TAG).dimmed()
+            );
+        } else {
+            println!(
+                "{} Tagged {} spec(s) with {}",
+                "✓".green(),
+                marked.len(),
+                NEEDS_REVIEW_TAG
+            );
+        }
+    }
+
+    Ok(())
+}
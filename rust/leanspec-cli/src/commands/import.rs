@@ -0,0 +1,111 @@
+//! `import` command — replay an archive written by `export` into the
+//! active adapter
+//!
+//! Specs are created in archive order and links are rewritten to the ids
+//! the target assigns. Enum values the target schema doesn't know must be
+//! translated with `--mapping`; nothing is created until they all map.
+
+use super::export::resolve_adapter;
+use colored::Colorize;
+use leanspec_core::adapters::{Archive, ImportPlan, ValueMapping};
+use std::error::Error;
+use std::fs;
+use std::io::Read;
+
+pub fn run(
+    specs_dir: Option<&str>,
+    file: &str,
+    mapping: Option<&str>,
+    dry_run: bool,
+    output_format: &str,
+) -> Result<(), Box<dyn Error>> {
+    let input = if file == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        fs::read_to_string(file).map_err(|e| format!("Failed to read {}: {}", file, e))?
+    };
+    let archive = Archive::parse(&input)?;
+    let mapping = match mapping {
+        Some(path) => ValueMapping::from_yaml(
+            &fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?,
+        )?,
+        None => ValueMapping::default(),
+    };
+
+    let adapter = resolve_adapter(specs_dir)?;
+    let plan = ImportPlan::new(&archive, adapter.schema(), &mapping)?;
+    let target = adapter.capabilities().name.clone();
+
+    if dry_run {
+        if output_format == "json" {
+            let specs: Vec<_> = plan
+                .specs
+                .iter()
+                .map(|spec| {
+                    serde_json::json!({
+                        "from": spec.source_id,
+                        "title": spec.request.title,
+                        "links": spec.links.len(),
+                    })
+                })
+                .collect();
+            let output = serde_json::json!({
+                "dryRun": true,
+                "source": archive.adapter,
+                "target": target,
+                "specs": specs,
+                "warnings": plan.warnings,
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        } else {
+            println!(
+                "{} Would import {} specs from {} into {}",
+                "→".cyan(),
+                plan.specs.len(),
+                archive.adapter,
+                target
+            );
+            for spec in &plan.specs {
+                println!("  {} {}", spec.source_id.dimmed(), spec.request.title);
+            }
+            print_warnings(&plan.warnings);
+        }
+        return Ok(());
+    }
+
+    let report = plan.apply(adapter.as_ref())?;
+
+    if output_format == "json" {
+        let output = serde_json::json!({
+            "dryRun": false,
+            "source": archive.adapter,
+            "target": target,
+            "ids": report.ids,
+            "links": report.links,
+            "warnings": plan.warnings,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!(
+            "{} Imported {} specs ({} links) from {} into {}",
+            "✓".green(),
+            report.ids.len(),
+            report.links,
+            archive.adapter,
+            target
+        );
+        for id in &report.ids {
+            println!("  {} → {}", id.from.dimmed(), id.to.cyan());
+        }
+        print_warnings(&plan.warnings);
+    }
+    Ok(())
+}
+
+fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        println!("{} {}", "⚠".yellow(), warning);
+    }
+}
@@ -0,0 +1,546 @@
+//! Structural import shared by the spec-kit and OpenSpec importers
+//!
+//! Importers parse the source tool's files into [`ImportedSpec`]s; this
+//! module numbers them, renders LeanSpec READMEs, writes them in batches,
+//! validates each batch and reports whatever could not be mapped.
+
+use colored::Colorize;
+use leanspec_core::{
+    FrontmatterValidator, SpecLoader, SpecPriority, SpecStatus, StructureValidator,
+    TokenCountValidator, ValidationResult,
+};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A `##` (or deeper) markdown section
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Section {
+    pub heading: String,
+    pub body: String,
+}
+
+impl Section {
+    pub fn new(heading: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            heading: heading.into(),
+            body: body.into(),
+        }
+    }
+}
+
+/// A markdown file split into its H1 title, preamble and `##` sections
+#[derive(Debug, Default)]
+pub(super) struct Document {
+    pub title: Option<String>,
+    pub preamble: String,
+    pub sections: Vec<Section>,
+}
+
+impl Document {
+    pub fn parse(content: &str) -> Self {
+        let content = strip_frontmatter(content);
+        let mut title = None;
+        let mut rest = Vec::new();
+        let mut in_fence = false;
+        for line in content.lines() {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+            }
+            if !in_fence && title.is_none() && line.starts_with("# ") {
+                title = Some(line[2..].trim().to_string());
+                continue;
+            }
+            rest.push(line);
+        }
+        let (preamble, sections) = split_sections(&rest.join("\n"), 2);
+        Self {
+            title,
+            preamble,
+            sections,
+        }
+    }
+
+    /// Everything below the title, with the `##` headings restored
+    pub fn body(&self) -> String {
+        let mut body = self.preamble.clone();
+        for section in &self.sections {
+            body.push_str(&format!("\n\n## {}\n\n{}", section.heading, section.body));
+        }
+        body.trim().to_string()
+    }
+
+    /// Remove and return the first section whose heading matches
+    pub fn take(&mut self, matches: impl Fn(&str) -> bool) -> Option<Section> {
+        let index = self
+            .sections
+            .iter()
+            .position(|s| matches(&normalize_heading(&s.heading)))?;
+        Some(self.sections.remove(index))
+    }
+}
+
+/// Split `content` at headings of exactly `level`, ignoring fenced code
+pub(super) fn split_sections(content: &str, level: usize) -> (String, Vec<Section>) {
+    let marker = format!("{} ", "#".repeat(level));
+    let mut preamble = Vec::new();
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        if !in_fence && line.starts_with(&marker) {
+            sections.push((line[marker.len()..].trim().to_string(), Vec::new()));
+            continue;
+        }
+        match sections.last_mut() {
+            Some((_, body)) => body.push(line),
+            None => preamble.push(line),
+        }
+    }
+
+    (
+        preamble.join("\n").trim().to_string(),
+        sections
+            .into_iter()
+            .map(|(heading, body)| Section::new(heading, body.join("\n").trim()))
+            .collect(),
+    )
+}
+
+/// Lowercase a heading and drop decorations such as `*(mandatory)*`
+pub(super) fn normalize_heading(heading: &str) -> String {
+    let heading = match heading.find(['*', '(']) {
+        Some(index) => &heading[..index],
+        None => heading,
+    };
+    heading.trim().to_lowercase()
+}
+
+/// Push every heading outside fenced code one level down
+pub(super) fn demote_headings(content: &str) -> String {
+    let mut in_fence = false;
+    content
+        .lines()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+            }
+            if !in_fence && line.starts_with('#') {
+                format!("#{}", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `**Key**: value` metadata lines, as lowercase keys
+pub(super) fn metadata_lines(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix("**")?;
+            let (key, value) = rest.split_once("**")?;
+            let value = value.trim_start().strip_prefix(':')?;
+            Some((
+                key.trim_end_matches(':').trim().to_lowercase(),
+                value.trim().to_string(),
+            ))
+        })
+        .collect()
+}
+
+/// Whether `value` looks like a `YYYY-MM-DD` date
+pub(super) fn is_date(value: &str) -> bool {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+}
+
+/// Join non-empty blocks with blank lines
+pub(super) fn join_blocks(blocks: &[String]) -> String {
+    blocks
+        .iter()
+        .map(|b| b.trim())
+        .filter(|b| !b.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// `path` relative to `base`, for report lines
+pub(super) fn relative(base: &Path, path: &Path) -> String {
+    path.strip_prefix(base)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
+
+fn strip_frontmatter(content: &str) -> &str {
+    content
+        .strip_prefix("---\n")
+        .and_then(|rest| rest.find("\n---").map(|end| &rest[end + 4..]))
+        .unwrap_or(content)
+}
+
+/// Task lists converted to LeanSpec checklists
+#[derive(Debug, Default)]
+pub(super) struct Checklist {
+    pub body: String,
+    pub total: usize,
+    pub checked: usize,
+}
+
+impl Checklist {
+    /// Keep existing checkboxes and turn plain top-level list items into
+    /// unchecked ones; headings and prose pass through unchanged.
+    pub fn from_tasks(content: &str) -> Self {
+        let mut checklist = Self::default();
+        let mut lines = Vec::new();
+        for line in content.lines() {
+            let item = line
+                .strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .map(str::trim_start);
+            match item {
+                Some(item) if item.starts_with("[x]") || item.starts_with("[X]") => {
+                    checklist.total += 1;
+                    checklist.checked += 1;
+                    lines.push(format!("- [x]{}", &item[3..]));
+                }
+                Some(item) if item.starts_with("[ ]") => {
+                    checklist.total += 1;
+                    lines.push(format!("- [ ]{}", &item[3..]));
+                }
+                Some(item) => {
+                    checklist.total += 1;
+                    lines.push(format!("- [ ] {}", item));
+                }
+                None => lines.push(line.to_string()),
+            }
+        }
+        checklist.body = lines.join("\n").trim().to_string();
+        checklist
+    }
+
+    /// Status implied by task progress, or `fallback` without tasks
+    pub fn status(&self, fallback: SpecStatus) -> SpecStatus {
+        match (self.total, self.checked) {
+            (0, _) => fallback,
+            (total, checked) if checked == total => SpecStatus::Complete,
+            (_, 0) => SpecStatus::Planned,
+            _ => SpecStatus::InProgress,
+        }
+    }
+}
+
+/// A spec assembled from another tool's files
+#[derive(Debug)]
+pub(super) struct ImportedSpec {
+    /// Identifier other imported specs use in `depends_on`
+    pub key: String,
+    pub name: String,
+    pub title: String,
+    pub status: SpecStatus,
+    pub created: String,
+    pub priority: Option<SpecPriority>,
+    pub depends_on: Vec<String>,
+    pub sections: Vec<Section>,
+    /// Extra markdown files kept next to the README, as `(file, content)`
+    pub sub_specs: Vec<(String, String)>,
+    pub sources: Vec<PathBuf>,
+    pub unmapped: Vec<String>,
+}
+
+impl ImportedSpec {
+    pub fn new(key: impl Into<String>, name: &str, title: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            name: super::normalize_name(name),
+            title: title.into(),
+            status: SpecStatus::Planned,
+            created: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+            priority: None,
+            depends_on: Vec::new(),
+            sections: Vec::new(),
+            sub_specs: Vec::new(),
+            sources: Vec::new(),
+            unmapped: Vec::new(),
+        }
+    }
+
+    /// Add a section unless its body is empty
+    pub fn section(&mut self, heading: &str, body: impl Into<String>) {
+        let body = body.into();
+        if !body.trim().is_empty() {
+            self.sections.push(Section::new(heading, body.trim()));
+        }
+    }
+
+    /// Keep sections no importer rule covers, and report them
+    pub fn keep_unmapped(&mut self, source: &str, sections: Vec<Section>) {
+        for section in sections {
+            if section.body.trim().is_empty() {
+                continue;
+            }
+            self.unmapped.push(format!(
+                "{}: section \"{}\" kept as-is",
+                source, section.heading
+            ));
+            self.sections.push(section);
+        }
+    }
+
+    fn render(&self, dir_names: &HashMap<String, String>) -> String {
+        let mut frontmatter = serde_yaml::Mapping::new();
+        frontmatter.insert("status".into(), self.status.to_string().into());
+        frontmatter.insert("created".into(), self.created.clone().into());
+        if let Some(priority) = self.priority {
+            frontmatter.insert("priority".into(), priority.to_string().into());
+        }
+        let depends_on: Vec<serde_yaml::Value> = self
+            .depends_on
+            .iter()
+            .filter_map(|key| dir_names.get(key))
+            .map(|dir| dir.clone().into())
+            .collect();
+        if !depends_on.is_empty() {
+            frontmatter.insert("depends_on".into(), depends_on.into());
+        }
+
+        let mut content = format!(
+            "---\n{}---\n\n# {}\n",
+            serde_yaml::to_string(&frontmatter).unwrap_or_default(),
+            self.title
+        );
+        for section in &self.sections {
+            content.push_str(&format!("\n## {}\n\n{}\n", section.heading, section.body));
+        }
+        content
+    }
+}
+
+/// Everything an importer found in the source tree
+#[derive(Debug, Default)]
+pub(super) struct Import {
+    pub specs: Vec<ImportedSpec>,
+    /// Source files no spec was built from
+    pub unmapped: Vec<String>,
+}
+
+pub(super) struct ImportOptions<'a> {
+    pub specs_dir: &'a str,
+    pub format: &'a str,
+    pub dry_run: bool,
+    pub batch_size: Option<usize>,
+    pub skip_validation: bool,
+    pub output_format: &'a str,
+}
+
+/// Number, write, validate and report an import
+pub(super) fn write_import(import: Import, options: ImportOptions) -> Result<(), Box<dyn Error>> {
+    let specs_path = Path::new(options.specs_dir);
+    let first = super::get_next_spec_number(options.specs_dir)?;
+    let dir_names: HashMap<String, String> = import
+        .specs
+        .iter()
+        .zip(first..)
+        .map(|(spec, number)| (spec.key.clone(), format!("{:03}-{}", number, spec.name)))
+        .collect();
+
+    let json = options.output_format == "json";
+    if !json {
+        println!("{}", "═".repeat(70));
+        println!(
+            "{}",
+            format!("🚀 Importing {} specs", options.format)
+                .cyan()
+                .bold()
+        );
+        println!("{}", "═".repeat(70));
+        println!();
+        if options.dry_run {
+            println!("{}", "⚠️  DRY RUN - No changes will be made".yellow());
+            println!();
+        }
+    }
+
+    let batch_size = options.batch_size.unwrap_or(import.specs.len()).max(1);
+    let mut failures: Vec<ValidationResult> = Vec::new();
+
+    for (index, batch) in import.specs.chunks(batch_size).enumerate() {
+        for spec in batch {
+            let dir_name = &dir_names[&spec.key];
+            if !options.dry_run {
+                let dir = specs_path.join(dir_name);
+                fs::create_dir_all(&dir)?;
+                fs::write(dir.join("README.md"), spec.render(&dir_names))?;
+                for (file, content) in &spec.sub_specs {
+                    fs::write(dir.join(file), content)?;
+                }
+            }
+            if !json {
+                let marker = if options.dry_run {
+                    "→".cyan()
+                } else {
+                    "✓".green()
+                };
+                println!("  {} {}/ ({})", marker, dir_name, spec.status);
+            }
+        }
+
+        if options.dry_run || options.skip_validation {
+            continue;
+        }
+
+        let results = validate_batch(options.specs_dir, batch, &dir_names)?;
+        let failed = results.iter().filter(|r| r.has_errors()).count();
+        if !json {
+            println!(
+                "  {} Batch {}: {} spec(s), {} with errors",
+                if failed == 0 {
+                    "✓".green()
+                } else {
+                    "✗".red()
+                },
+                index + 1,
+                batch.len(),
+                failed
+            );
+        }
+        failures.extend(results.into_iter().filter(|r| r.has_errors()));
+    }
+
+    if json {
+        print_json_report(&import, &dir_names, &failures, options.dry_run)?;
+    } else {
+        print_report(&import, &dir_names, &failures);
+    }
+
+    if !failures.is_empty() {
+        return Err(format!("{} imported spec(s) failed validation", failures.len()).into());
+    }
+    Ok(())
+}
+
+fn validate_batch(
+    specs_dir: &str,
+    batch: &[ImportedSpec],
+    dir_names: &HashMap<String, String>,
+) -> Result<Vec<ValidationResult>, Box<dyn Error>> {
+    let loader = SpecLoader::new(specs_dir);
+    let frontmatter = FrontmatterValidator::new();
+    let structure = StructureValidator::new();
+    let tokens = TokenCountValidator::new();
+
+    let mut results = Vec::new();
+    for spec in batch {
+        let dir_name = &dir_names[&spec.key];
+        let info = loader
+            .load_exact(dir_name)?
+            .ok_or_else(|| format!("Imported spec not found: {}", dir_name))?;
+        let mut result = ValidationResult::new(&info.path);
+        result.merge(frontmatter.validate(&info));
+        result.merge(structure.validate(&info));
+        result.merge(tokens.validate(&info));
+        results.push(result);
+    }
+    Ok(results)
+}
+
+fn print_report(
+    import: &Import,
+    dir_names: &HashMap<String, String>,
+    failures: &[ValidationResult],
+) {
+    println!();
+    println!("{}", "Import report".bold());
+    println!("{}", "─".repeat(70));
+    println!("  Specs: {}", import.specs.len());
+
+    let unmapped: usize =
+        import.unmapped.len() + import.specs.iter().map(|s| s.unmapped.len()).sum::<usize>();
+    if unmapped == 0 {
+        println!("  {} Everything was mapped", "✓".green());
+    } else {
+        println!("  {} {} item(s) not mapped:", "ℹ".blue(), unmapped);
+        for item in &import.unmapped {
+            println!("    - {}", item);
+        }
+        for spec in &import.specs {
+            for item in &spec.unmapped {
+                println!("    - {}: {}", dir_names[&spec.key], item);
+            }
+        }
+    }
+
+    for result in failures {
+        println!("  {} {}", "✗".red(), result.spec_path);
+        for error in result.errors() {
+            println!("    - {}", error.message);
+        }
+    }
+
+    println!();
+    println!("Next steps:");
+    println!("  {}      # View your specs", "lean-spec board".cyan());
+    println!("  {}   # Check for issues", "lean-spec validate".cyan());
+}
+
+fn print_json_report(
+    import: &Import,
+    dir_names: &HashMap<String, String>,
+    failures: &[ValidationResult],
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let output = serde_json::json!({
+        "dry_run": dry_run,
+        "specs": import.specs.iter().map(|spec| serde_json::json!({
+            "path": dir_names[&spec.key],
+            "title": spec.title,
+            "status": spec.status.to_string(),
+            "depends_on": spec
+                .depends_on
+                .iter()
+                .filter_map(|key| dir_names.get(key))
+                .collect::<Vec<_>>(),
+            "sources": spec.sources,
+            "unmapped": spec.unmapped,
+        })).collect::<Vec<_>>(),
+        "unmapped": import.unmapped,
+        "validation_errors": failures.iter().map(|result| serde_json::json!({
+            "spec": result.spec_path,
+            "errors": result.errors().map(|e| e.message.clone()).collect::<Vec<_>>(),
+        })).collect::<Vec<_>>(),
+    });
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_sections_outside_code_fences() {
+        let doc = Document::parse(
+            "# Title\n\nIntro\n\n## One\n\n```\n## not a heading\n```\n\n## Two\nbody",
+        );
+        assert_eq!(doc.title.as_deref(), Some("Title"));
+        assert_eq!(doc.preamble, "Intro");
+        assert_eq!(doc.sections.len(), 2);
+        assert!(doc.sections[0].body.contains("## not a heading"));
+    }
+
+    #[test]
+    fn converts_tasks_to_checklist() {
+        let checklist =
+            Checklist::from_tasks("## Phase 1\n- [x] T001 Setup\n- T002 Build\n  detail");
+        assert_eq!(checklist.total, 2);
+        assert_eq!(checklist.checked, 1);
+        assert!(checklist.body.contains("- [ ] T002 Build"));
+        assert_eq!(checklist.status(SpecStatus::Draft), SpecStatus::InProgress);
+    }
+}
@@ -2,11 +2,17 @@
 //!
 //! Migrate specs from other SDD tools (OpenSpec, spec-kit, etc.)
 
+mod import;
+mod openspec;
+mod spec_kit;
+
 use colored::Colorize;
 use std::error::Error;
 use std::fs;
 use std::path::Path;
 
+use import::{write_import, ImportOptions};
+
 #[allow(clippy::too_many_arguments)]
 pub fn run(
     specs_dir: &str,
@@ -14,10 +20,10 @@ pub fn run(
     auto: bool,
     ai_provider: Option<String>,
     dry_run: bool,
-    _batch_size: Option<usize>,
-    _skip_validation: bool,
+    batch_size: Option<usize>,
+    skip_validation: bool,
     backfill: bool,
-    _output_format: &str,
+    output_format: &str,
 ) -> Result<(), Box<dyn Error>> {
     // Validate input path exists
     let input = Path::new(input_path);
@@ -25,7 +31,10 @@ pub fn run(
         return Err(format!("Path not found or not a directory: {}", input_path).into());
     }
 
-    println!("{} {}\n", "Scanning:".cyan(), input_path);
+    let quiet = output_format == "json";
+    if !quiet {
+        println!("{} {}\n", "Scanning:".cyan(), input_path);
+    }
 
     // Scan for documents
     let documents = scan_documents(input)?;
@@ -34,20 +43,39 @@ pub fn run(
         return Err(format!("No documents found in {}", input_path).into());
     }
 
-    println!(
-        "{} Found {} document{}\n",
-        "✓".green(),
-        documents.len(),
-        if documents.len() == 1 { "" } else { "s" }
-    );
-
     // Detect source format
-    let format = detect_source_format(&documents);
-    println!("{} {}\n", "Detected format:".cyan(), format);
+    let format = detect_source_format(input, &documents);
+    if !quiet {
+        println!(
+            "{} Found {} document{}\n",
+            "✓".green(),
+            documents.len(),
+            if documents.len() == 1 { "" } else { "s" }
+        );
+        println!("{} {}\n", "Detected format:".cyan(), format);
+    }
 
-    // Auto mode
+    // Auto mode: structural import for known tools, file copy otherwise
     if auto {
-        return migrate_auto(specs_dir, &documents, &format, dry_run, backfill);
+        let import = match format.as_str() {
+            "spec-kit" => spec_kit::import(input)?,
+            "openspec" => openspec::import(input)?,
+            _ => return migrate_auto(specs_dir, &documents, &format, dry_run, backfill),
+        };
+        if import.specs.is_empty() {
+            return Err(format!("No {} specs found in {}", format, input_path).into());
+        }
+        return write_import(
+            import,
+            ImportOptions {
+                specs_dir,
+                format: &format,
+                dry_run,
+                batch_size,
+                skip_validation,
+                output_format,
+            },
+        );
     }
 
     // AI-assisted mode
@@ -102,21 +130,29 @@ fn scan_recursive(dir: &Path, documents: &mut Vec<DocumentInfo>) -> Result<(), B
     Ok(())
 }
 
-fn detect_source_format(documents: &[DocumentInfo]) -> String {
+fn detect_source_format(input: &Path, documents: &[DocumentInfo]) -> String {
+    // OpenSpec also names its capability files spec.md, so check it first
+    let has_openspec = input.join("openspec").is_dir()
+        || input.file_name().is_some_and(|n| n == "openspec")
+        || documents.iter().any(|d| d.path.contains("openspec/"))
+        || (input.join("changes").is_dir() && input.join("project.md").is_file());
+    if has_openspec {
+        return "openspec".to_string();
+    }
+
     // Check for spec-kit pattern
-    let has_spec_kit = documents
-        .iter()
-        .any(|d| d.path.contains(".specify") || d.name == "spec.md");
+    let has_spec_kit = input.join(".specify").is_dir()
+        || documents.iter().any(|d| {
+            let dir = Path::new(&d.path).parent();
+            d.name == "spec.md"
+                && dir.is_some_and(|dir| {
+                    dir.join("plan.md").is_file() || dir.join("tasks.md").is_file()
+                })
+        });
     if has_spec_kit {
         return "spec-kit".to_string();
     }
 
-    // Check for OpenSpec pattern
-    let has_openspec = documents.iter().any(|d| d.path.contains("openspec/"));
-    if has_openspec {
-        return "openspec".to_string();
-    }
-
     "generic".to_string()
 }
 
@@ -0,0 +1,405 @@
+//! OpenSpec importer
+//!
+//! Every capability under `openspec/specs/` becomes a complete spec whose
+//! requirements are its acceptance criteria. Every change folder under
+//! `openspec/changes/` (including `archive/`) becomes a spec built from
+//! `proposal.md`, `design.md` and `tasks.md` that depends on the
+//! capabilities its delta specs touch.
+
+use super::import::{
+    demote_headings, is_date, join_blocks, relative, split_sections, Checklist, Document, Import,
+    ImportedSpec,
+};
+use leanspec_core::SpecStatus;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(super) fn import(input: &Path) -> Result<Import, Box<dyn Error>> {
+    let root = if input.join("openspec").is_dir() {
+        input.join("openspec")
+    } else {
+        input.to_path_buf()
+    };
+
+    let mut import = Import::default();
+    for entry in sorted_entries(&root)? {
+        let name = file_name(&entry);
+        if entry.is_file() && entry.extension().and_then(|e| e.to_str()) == Some("md") {
+            import.unmapped.push(format!(
+                "{}: project-level file, not imported",
+                relative(input, &entry)
+            ));
+        } else if entry.is_dir() && !matches!(name.as_str(), "specs" | "changes") {
+            import.unmapped.push(format!(
+                "{}/: unknown directory, not imported",
+                relative(input, &entry)
+            ));
+        }
+    }
+
+    let specs_dir = root.join("specs");
+    if specs_dir.is_dir() {
+        for dir in sorted_entries(&specs_dir)?
+            .into_iter()
+            .filter(|p| p.is_dir())
+        {
+            import.specs.push(import_capability(&dir)?);
+        }
+    }
+
+    let changes_dir = root.join("changes");
+    if changes_dir.is_dir() {
+        let archive_dir = changes_dir.join("archive");
+        let mut changes: Vec<(PathBuf, bool)> = Vec::new();
+        if archive_dir.is_dir() {
+            for dir in sorted_entries(&archive_dir)?
+                .into_iter()
+                .filter(|p| p.is_dir())
+            {
+                changes.push((dir, true));
+            }
+        }
+        for dir in sorted_entries(&changes_dir)?
+            .into_iter()
+            .filter(|p| p.is_dir() && *p != archive_dir)
+        {
+            changes.push((dir, false));
+        }
+
+        let capabilities: Vec<String> = import.specs.iter().map(|s| s.key.clone()).collect();
+        for (dir, archived) in changes {
+            import
+                .specs
+                .push(import_change(input, &dir, archived, &capabilities)?);
+        }
+    }
+
+    Ok(import)
+}
+
+fn import_capability(dir: &Path) -> Result<ImportedSpec, Box<dyn Error>> {
+    let name = file_name(dir);
+    let spec_path = dir.join("spec.md");
+    let mut doc = match fs::read_to_string(&spec_path) {
+        Ok(content) => Document::parse(&content),
+        Err(_) => Document::default(),
+    };
+    let title = doc
+        .title
+        .as_deref()
+        .map(|t| t.trim_end_matches("Specification").trim())
+        .filter(|t| !t.is_empty())
+        .unwrap_or(&name)
+        .to_string();
+
+    let mut spec = ImportedSpec::new(capability_key(&name), &name, title);
+    spec.status = SpecStatus::Complete;
+    if spec_path.is_file() {
+        spec.sources.push(spec_path);
+    } else {
+        spec.unmapped.push("spec.md missing".to_string());
+    }
+
+    let purpose = doc.take(|h| h == "purpose").map(|s| s.body);
+    spec.section(
+        "Overview",
+        join_blocks(&[doc.preamble.clone(), purpose.unwrap_or_default()]),
+    );
+    if let Some(requirements) = doc.take(|h| h == "requirements") {
+        spec.section(
+            "Acceptance Criteria",
+            requirement_checklist(&requirements.body, true),
+        );
+    }
+    let rest = std::mem::take(&mut doc.sections);
+    spec.keep_unmapped("spec.md", rest);
+
+    add_extra_files(&mut spec, dir, &["spec.md"])?;
+    Ok(spec)
+}
+
+fn import_change(
+    input: &Path,
+    dir: &Path,
+    archived: bool,
+    capabilities: &[String],
+) -> Result<ImportedSpec, Box<dyn Error>> {
+    let folder = file_name(dir);
+    // Archived changes are named `YYYY-MM-DD-<change-id>`.
+    let (date, name) = match folder.get(..11) {
+        Some(prefix) if archived && is_date(&prefix[..10]) => {
+            (Some(prefix[..10].to_string()), folder[11..].to_string())
+        }
+        _ => (None, folder.clone()),
+    };
+
+    let proposal_path = dir.join("proposal.md");
+    let mut proposal = match fs::read_to_string(&proposal_path) {
+        Ok(content) => Document::parse(&content),
+        Err(_) => Document::default(),
+    };
+    let title = proposal
+        .title
+        .as_deref()
+        .map(|t| t.trim_start_matches("Change:").trim())
+        .filter(|t| !t.is_empty())
+        .map(String::from)
+        .unwrap_or_else(|| humanize(&name));
+
+    let mut spec = ImportedSpec::new(format!("change:{}", folder), &name, title);
+    if let Some(date) = date {
+        spec.created = date;
+    }
+    if proposal_path.is_file() {
+        spec.sources.push(proposal_path);
+    } else {
+        spec.unmapped.push("proposal.md missing".to_string());
+    }
+
+    let why = proposal.take(|h| h == "why").map(|s| s.body);
+    spec.section(
+        "Overview",
+        join_blocks(&[proposal.preamble.clone(), why.unwrap_or_default()]),
+    );
+
+    let mut design = Vec::new();
+    if let Some(changes) = proposal.take(|h| h == "what changes") {
+        design.push(changes.body);
+    }
+    let design_path = dir.join("design.md");
+    if design_path.is_file() {
+        let doc = Document::parse(&fs::read_to_string(&design_path)?);
+        design.push(demote_headings(&doc.body()));
+        spec.sources.push(design_path);
+    }
+    spec.section("Design", join_blocks(&design));
+
+    let tasks_path = dir.join("tasks.md");
+    let mut status = SpecStatus::Planned;
+    if tasks_path.is_file() {
+        let tasks = Document::parse(&fs::read_to_string(&tasks_path)?);
+        let checklist = Checklist::from_tasks(&demote_headings(&tasks.body()));
+        status = checklist.status(status);
+        spec.section("Plan", checklist.body);
+        spec.sources.push(tasks_path);
+    }
+    spec.status = if archived {
+        SpecStatus::Complete
+    } else {
+        status
+    };
+
+    // Delta specs name the capabilities the change touches.
+    let mut criteria = Vec::new();
+    let deltas_dir = dir.join("specs");
+    if deltas_dir.is_dir() {
+        for cap_dir in sorted_entries(&deltas_dir)?
+            .into_iter()
+            .filter(|p| p.is_dir())
+        {
+            let capability = file_name(&cap_dir);
+            let delta_path = cap_dir.join("spec.md");
+            let Ok(content) = fs::read_to_string(&delta_path) else {
+                continue;
+            };
+            spec.sources.push(delta_path);
+            depend_on(&mut spec, &capability, capabilities);
+
+            let delta = Document::parse(&content);
+            let mut items = Vec::new();
+            for section in &delta.sections {
+                let operation = section
+                    .heading
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .to_lowercase();
+                let label = match operation.as_str() {
+                    "added" => "Add",
+                    "modified" => "Change",
+                    "removed" => "Remove",
+                    "renamed" => "Rename",
+                    _ => {
+                        spec.unmapped.push(format!(
+                            "{}: delta section \"{}\" not mapped",
+                            relative(input, &cap_dir.join("spec.md")),
+                            section.heading
+                        ));
+                        continue;
+                    }
+                };
+                items.push(prefix_items(
+                    &requirement_checklist(&section.body, archived),
+                    label,
+                ));
+            }
+            criteria.push(format!("### {}\n\n{}", capability, join_blocks(&items)));
+        }
+    }
+    spec.section("Acceptance Criteria", join_blocks(&criteria));
+
+    if let Some(impact) = proposal.take(|h| h == "impact") {
+        for line in impact.body.lines() {
+            if let Some((_, affected)) = line.split_once("Affected specs:") {
+                for capability in affected.split(',') {
+                    let capability = capability.trim().trim_matches('`');
+                    if !capability.is_empty() {
+                        depend_on(&mut spec, capability, capabilities);
+                    }
+                }
+            }
+        }
+        spec.section("Notes", format!("### Impact\n\n{}", impact.body));
+    }
+    let rest = std::mem::take(&mut proposal.sections);
+    spec.keep_unmapped("proposal.md", rest);
+
+    add_extra_files(&mut spec, dir, &["proposal.md", "design.md", "tasks.md"])?;
+    Ok(spec)
+}
+
+/// Turn `### Requirement: X` blocks into checklist items, with each
+/// `#### Scenario: Y` as a nested bullet
+fn requirement_checklist(body: &str, checked: bool) -> String {
+    let mark = if checked { "x" } else { " " };
+    let (preamble, requirements) = split_sections(body, 3);
+    let mut lines = Vec::new();
+    if !preamble.is_empty() {
+        lines.push(preamble);
+    }
+    for requirement in requirements {
+        let name = requirement
+            .heading
+            .trim_start_matches("Requirement:")
+            .trim();
+        let (statement, scenarios) = split_sections(&requirement.body, 4);
+        let statement = statement.split_whitespace().collect::<Vec<_>>().join(" ");
+        if statement.is_empty() {
+            lines.push(format!("- [{}] **{}**", mark, name));
+        } else {
+            lines.push(format!("- [{}] **{}**: {}", mark, name, statement));
+        }
+        for scenario in scenarios {
+            let steps: Vec<&str> = scenario
+                .body
+                .lines()
+                .map(|l| l.trim().trim_start_matches("- ").trim())
+                .filter(|l| !l.is_empty())
+                .collect();
+            lines.push(format!(
+                "  - {} — {}",
+                scenario.heading.trim(),
+                steps.join("; ")
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Prefix the text of every top-level checklist item with `label`
+fn prefix_items(checklist: &str, label: &str) -> String {
+    checklist
+        .lines()
+        .map(|line| match line.get(..6) {
+            Some(marker) if marker == "- [ ] " || marker == "- [x] " => {
+                format!("{}{}: {}", marker, label, &line[6..])
+            }
+            _ => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn depend_on(spec: &mut ImportedSpec, capability: &str, capabilities: &[String]) {
+    let key = capability_key(capability);
+    if spec.depends_on.contains(&key) {
+        return;
+    }
+    if capabilities.contains(&key) {
+        spec.depends_on.push(key);
+    } else {
+        spec.unmapped.push(format!(
+            "capability \"{}\" has no spec under openspec/specs, no dependency added",
+            capability
+        ));
+    }
+}
+
+/// Keep other markdown files as sub-specs and report the rest
+fn add_extra_files(
+    spec: &mut ImportedSpec,
+    dir: &Path,
+    mapped: &[&str],
+) -> Result<(), Box<dyn Error>> {
+    for entry in sorted_entries(dir)? {
+        let name = file_name(&entry);
+        if mapped.contains(&name.as_str()) || (entry.is_dir() && name == "specs") {
+            continue;
+        }
+        if entry.is_file() && entry.extension().and_then(|e| e.to_str()) == Some("md") {
+            let file = format!("{}.md", name.trim_end_matches(".md").to_uppercase());
+            spec.sub_specs.push((file, fs::read_to_string(&entry)?));
+            spec.sources.push(entry);
+        } else {
+            spec.unmapped
+                .push(format!("{}: not imported", relative(dir, &entry)));
+        }
+    }
+    Ok(())
+}
+
+fn capability_key(capability: &str) -> String {
+    format!("spec:{}", capability)
+}
+
+fn humanize(name: &str) -> String {
+    name.split(['-', '_'])
+        .filter(|w| !w.is_empty())
+        .enumerate()
+        .map(|(i, w)| {
+            if i == 0 {
+                let mut chars = w.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            } else {
+                w.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut entries = fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| !file_name(p).starts_with('.'))
+        .collect::<Vec<_>>();
+    entries.sort();
+    Ok(entries)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_requirements_and_scenarios() {
+        let body = "### Requirement: Login\nThe system SHALL\nauthenticate users.\n\n#### Scenario: Valid credentials\n- **WHEN** a user logs in\n- **THEN** a session starts";
+        let checklist = requirement_checklist(body, false);
+        assert_eq!(
+            checklist,
+            "- [ ] **Login**: The system SHALL authenticate users.\n  - Scenario: Valid credentials — **WHEN** a user logs in; **THEN** a session starts"
+        );
+        assert!(prefix_items(&checklist, "Add").starts_with("- [ ] Add: **Login**"));
+    }
+}
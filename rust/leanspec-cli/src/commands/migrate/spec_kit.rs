@@ -0,0 +1,208 @@
+//! spec-kit importer
+//!
+//! Each feature directory (`specs/NNN-feature/`) holding a `spec.md`
+//! becomes one spec: the user scenarios become the overview, functional
+//! requirements and success criteria become acceptance criteria, `plan.md`
+//! becomes the design and `tasks.md` becomes the plan checklist. Other
+//! markdown files in the feature directory are kept as sub-specs.
+
+use super::import::{
+    demote_headings, is_date, join_blocks, metadata_lines, relative, split_sections, Checklist,
+    Document, Import, ImportedSpec,
+};
+use leanspec_core::{SpecPriority, SpecStatus};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Files in a feature directory with a dedicated mapping
+const MAPPED_FILES: [&str; 3] = ["spec.md", "plan.md", "tasks.md"];
+
+pub(super) fn import(input: &Path) -> Result<Import, Box<dyn Error>> {
+    let mut import = Import::default();
+    let mut feature_dirs = Vec::new();
+    let mut other_docs = Vec::new();
+
+    for entry in WalkDir::new(input)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| e.file_name() != "node_modules" && e.file_name() != ".git")
+        .filter_map(|e| e.ok())
+    {
+        let path = entry.path();
+        if entry.file_type().is_dir() && path.join("spec.md").is_file() {
+            feature_dirs.push(path.to_path_buf());
+        } else if entry.file_type().is_file()
+            && path.extension().and_then(|e| e.to_str()) == Some("md")
+        {
+            other_docs.push(path.to_path_buf());
+        }
+    }
+
+    for doc in other_docs {
+        let in_feature = feature_dirs.iter().any(|dir| doc.starts_with(dir));
+        // Templates are spec-kit scaffolding, not project content.
+        let is_template = doc.components().any(|c| c.as_os_str() == "templates");
+        if !in_feature && !is_template {
+            import.unmapped.push(format!(
+                "{}: not part of a feature, not imported",
+                relative(input, &doc)
+            ));
+        }
+    }
+
+    for dir in &feature_dirs {
+        import.specs.push(import_feature(input, dir)?);
+    }
+
+    Ok(import)
+}
+
+fn import_feature(input: &Path, dir: &Path) -> Result<ImportedSpec, Box<dyn Error>> {
+    let dir_name = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = match dir_name.split_once('-') {
+        Some((number, rest)) if number.parse::<u32>().is_ok() => rest.to_string(),
+        _ => dir_name.clone(),
+    };
+
+    let mut spec_doc = Document::parse(&fs::read_to_string(dir.join("spec.md"))?);
+    let title = spec_doc
+        .title
+        .as_deref()
+        .map(|t| t.trim_start_matches("Feature Specification:").trim())
+        .filter(|t| !t.is_empty())
+        .unwrap_or(&name)
+        .to_string();
+
+    let mut spec = ImportedSpec::new(dir_name.clone(), &name, title);
+    spec.sources.push(dir.join("spec.md"));
+    let mut status = SpecStatus::Planned;
+    let mut overview = Vec::new();
+
+    for (key, value) in metadata_lines(&spec_doc.preamble) {
+        match key.as_str() {
+            "created" if is_date(&value) => spec.created = value,
+            "status" => match value.parse::<SpecStatus>() {
+                Ok(parsed) => status = parsed,
+                Err(_) => spec
+                    .unmapped
+                    .push(format!("spec.md: unknown status \"{}\"", value)),
+            },
+            "input" => overview.push(format!(
+                "> {}",
+                value.trim_start_matches("User description:").trim()
+            )),
+            _ => spec
+                .unmapped
+                .push(format!("spec.md: metadata \"{}\" not mapped", key)),
+        }
+    }
+
+    if let Some(scenarios) = spec_doc.take(|h| h.starts_with("user scenarios")) {
+        spec.priority = story_priority(&scenarios.body);
+        overview.push(demote_headings(&scenarios.body));
+    }
+    spec.section("Overview", overview.join("\n\n"));
+
+    let mut design = Vec::new();
+    let mut criteria = Vec::new();
+    if let Some(requirements) = spec_doc.take(|h| h == "requirements") {
+        let (preamble, subsections) = split_sections(&requirements.body, 3);
+        criteria.push(Checklist::from_tasks(&preamble).body);
+        for subsection in subsections {
+            if subsection
+                .heading
+                .to_lowercase()
+                .starts_with("key entities")
+            {
+                design.push(format!("### {}\n\n{}", subsection.heading, subsection.body));
+            } else {
+                criteria.push(format!(
+                    "### {}\n\n{}",
+                    subsection.heading,
+                    Checklist::from_tasks(&subsection.body).body
+                ));
+            }
+        }
+    }
+    if let Some(success) = spec_doc.take(|h| h == "success criteria") {
+        criteria.push(Checklist::from_tasks(&demote_headings(&success.body)).body);
+    }
+
+    let plan_path = dir.join("plan.md");
+    if plan_path.is_file() {
+        let plan = Document::parse(&fs::read_to_string(&plan_path)?);
+        design.push(demote_headings(&plan.body()));
+        spec.sources.push(plan_path);
+    }
+    spec.section("Design", join_blocks(&design));
+
+    let tasks_path = dir.join("tasks.md");
+    if tasks_path.is_file() {
+        let tasks = Document::parse(&fs::read_to_string(&tasks_path)?);
+        let checklist = Checklist::from_tasks(&demote_headings(&tasks.body()));
+        status = checklist.status(status);
+        spec.section("Plan", checklist.body);
+        spec.sources.push(tasks_path);
+    }
+    spec.status = status;
+
+    spec.section("Acceptance Criteria", join_blocks(&criteria));
+    if let Some(clarifications) = spec_doc.take(|h| h == "clarifications") {
+        spec.section("Notes", demote_headings(&clarifications.body));
+    }
+    let rest = std::mem::take(&mut spec_doc.sections);
+    spec.keep_unmapped("spec.md", rest);
+
+    for entry in WalkDir::new(dir)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+    {
+        let path = entry.path();
+        let rel = relative(dir, path);
+        if MAPPED_FILES.contains(&rel.as_str()) {
+            continue;
+        }
+        if path.extension().and_then(|e| e.to_str()) == Some("md") {
+            let file = rel
+                .replace(['/', '\\'], "-")
+                .to_uppercase()
+                .replace(".MD", ".md");
+            spec.sub_specs.push((file, fs::read_to_string(path)?));
+            spec.sources.push(path.to_path_buf());
+        } else {
+            spec.unmapped.push(format!(
+                "{}: not markdown, not imported",
+                relative(input, path)
+            ));
+        }
+    }
+
+    Ok(spec)
+}
+
+/// Highest user story priority (`(Priority: P1)` is the most urgent)
+fn story_priority(scenarios: &str) -> Option<SpecPriority> {
+    let level = scenarios
+        .match_indices("Priority: P")
+        .filter_map(|(index, marker)| {
+            scenarios[index + marker.len()..]
+                .chars()
+                .next()
+                .and_then(|c| c.to_digit(10))
+        })
+        .min()?;
+    Some(match level {
+        0 => SpecPriority::Critical,
+        1 => SpecPriority::High,
+        2 => SpecPriority::Medium,
+        _ => SpecPriority::Low,
+    })
+}
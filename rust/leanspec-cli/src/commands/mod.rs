@@ -5,6 +5,7 @@ pub mod capabilities;
 pub mod children;
 pub mod create;
 pub mod deps;
+pub mod duplicates;
 pub mod list;
 pub mod rel;
 pub mod search;
@@ -13,6 +14,7 @@ pub mod tokens;
 pub mod update;
 pub mod validate;
 pub mod view;
+pub mod workload;
 
 pub mod git_repo;
 
@@ -20,12 +22,20 @@ pub mod git_repo;
 pub mod analyze;
 pub mod archive;
 pub mod check;
+pub mod comment;
 pub mod examples;
+pub mod export;
 pub mod files;
 pub mod gantt;
+pub mod hooks;
+pub mod impact;
+pub mod import;
 pub mod init;
+pub mod mv;
 pub mod open;
 pub mod package_manager;
+pub mod project;
+pub mod review;
 pub mod timeline;
 
 // Additional commands (spec 170)
@@ -0,0 +1,119 @@
+//! Mv command implementation
+//!
+//! Renames or renumbers a spec and rewrites references to it across the project.
+
+use colored::Colorize;
+use leanspec_core::adapters::markdown::{Changeset, FileDiff};
+use leanspec_core::SpecLoader;
+use std::error::Error;
+use std::path::Path;
+
+pub fn run(
+    specs_dir: &str,
+    spec: &str,
+    new_name: Option<&str>,
+    number: Option<u32>,
+    dry_run: bool,
+    output_format: &str,
+) -> Result<(), Box<dyn Error>> {
+    if new_name.is_none() && number.is_none() {
+        return Err("Either a new name or --number is required".into());
+    }
+
+    let loader = SpecLoader::new(specs_dir);
+    let spec_info = loader
+        .load(spec)?
+        .ok_or_else(|| format!("Spec not found: {}", spec))?;
+
+    let target = spec_info.moved_name(new_name, number);
+    if target == spec_info.path {
+        return Err(format!("Spec is already named {}", target).into());
+    }
+
+    let mut changeset = Changeset::new(specs_dir);
+    changeset.move_spec(&spec_info.path, &target)?;
+    let diffs = changeset.diff();
+
+    if output_format == "json" {
+        let output = serde_json::json!({
+            "from": spec_info.path,
+            "to": target,
+            "dry_run": dry_run,
+            "files": diffs
+                .iter()
+                .map(|diff| serde_json::json!({
+                    "path": relative(specs_dir, &diff.path),
+                    "created": diff.created,
+                    "changes": diff
+                        .changes
+                        .iter()
+                        .map(|c| serde_json::json!({
+                            "line": c.line,
+                            "before": c.before,
+                            "after": c.after,
+                        }))
+                        .collect::<Vec<_>>(),
+                }))
+                .collect::<Vec<_>>(),
+        });
+        if !dry_run {
+            changeset.commit()?;
+        }
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if dry_run {
+        println!();
+        println!("{}", "Dry run - no changes will be made".yellow());
+        println!();
+        println!(
+            "Would rename: {} → {}",
+            spec_info.path.cyan(),
+            target.green()
+        );
+        println!();
+        print_diffs(specs_dir, &diffs);
+        return Ok(());
+    }
+
+    changeset.commit()?;
+
+    println!(
+        "{} Renamed: {} → {}",
+        "✓".green(),
+        spec_info.path.cyan(),
+        target.green()
+    );
+    if !diffs.is_empty() {
+        println!("  Updated references in {} file(s)", diffs.len());
+    }
+    Ok(())
+}
+
+fn print_diffs(specs_dir: &str, diffs: &[FileDiff]) {
+    if diffs.is_empty() {
+        println!("{}", "No references to update".dimmed());
+        return;
+    }
+
+    for diff in diffs {
+        println!("{}", relative(specs_dir, &diff.path).bold());
+        for change in &diff.changes {
+            if let Some(before) = &change.before {
+                println!("  {:>4} {}", change.line, format!("- {}", before).red());
+            }
+            if let Some(after) = &change.after {
+                println!("  {:>4} {}", change.line, format!("+ {}", after).green());
+            }
+        }
+        println!();
+    }
+}
+
+fn relative(specs_dir: &str, path: &Path) -> String {
+    path.strip_prefix(specs_dir)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
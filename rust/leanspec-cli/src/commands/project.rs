@@ -0,0 +1,23 @@
+//! Project lookup shared by commands
+
+use leanspec_core::types::LeanSpecConfig;
+use std::path::{Path, PathBuf};
+
+/// Configuration of the project whose specs live in `specs_dir`, or the
+/// defaults when no `.lean-spec` directory is found above it.
+pub(crate) fn project_config(specs_dir: &str) -> LeanSpecConfig {
+    project_root(specs_dir)
+        .map(|root| LeanSpecConfig::load_project(&root))
+        .unwrap_or_default()
+}
+
+fn project_root(specs_dir: &str) -> Option<PathBuf> {
+    let start = Path::new(specs_dir)
+        .canonicalize()
+        .or_else(|_| std::env::current_dir())
+        .ok()?;
+    start
+        .ancestors()
+        .find(|dir| dir.join(".lean-spec").is_dir())
+        .map(Path::to_path_buf)
+}
@@ -1,10 +1,9 @@
 //! Unified relationships command
 
 use colored::Colorize;
-use leanspec_core::{
-    validate_dependency_addition, validate_parent_assignment, DependencyGraph, FrontmatterParser,
-    SpecLoader,
-};
+use leanspec_core::adapters::markdown::{link, spec_info_to_doc, Changeset, MarkdownAdapter};
+use leanspec_core::adapters::Adapter;
+use leanspec_core::{DependencyGraph, SpecGraph, SpecInfo, SpecLoader};
 use std::collections::HashMap;
 use std::error::Error;
 
@@ -18,6 +17,8 @@ pub struct RelArgs {
     pub parent: Option<String>,
     pub children: Vec<String>,
     pub depends_on: Vec<String>,
+    /// `<type>=<spec>` pairs for the frontmatter link types
+    pub links: Vec<String>,
 }
 
 pub fn run(specs_dir: &str, rel_args: RelArgs, output_format: &str) -> Result<(), Box<dyn Error>> {
@@ -74,6 +75,8 @@ fn view_relationships(
         .filter(|s| s.frontmatter.parent.as_deref() == Some(spec_info.path.as_str()))
         .collect();
 
+    let links = frontmatter_links(specs_dir, &all_specs, &spec_info.path);
+
     let graph = DependencyGraph::new(&all_specs);
     let complete = graph.get_complete_graph(&spec_info.path);
     let depends_on = complete
@@ -106,7 +109,11 @@ fn view_relationships(
             "dependencies": {
                 "depends_on": depends_on,
                 "required_by": required_by,
-            }
+            },
+            "links": links.iter().map(|l| serde_json::json!({
+                "type": l.key,
+                "target": l.target,
+            })).collect::<Vec<_>>(),
         });
         println!("{}", serde_json::to_string_pretty(&output)?);
         return Ok(());
@@ -151,9 +158,52 @@ fn view_relationships(
         }
     }
 
+    if !links.is_empty() {
+        println!();
+        println!("{}", "Links".bold());
+        for (i, link) in links.iter().enumerate() {
+            let branch = if i + 1 == links.len() {
+                "└──"
+            } else {
+                "├──"
+            };
+            println!("{} {}: {}", branch, link.label, link.target.cyan());
+        }
+    }
+
     Ok(())
 }
 
+/// A link of one of the frontmatter link types, seen from one spec.
+pub(crate) struct FrontmatterLink {
+    /// Link key from this spec's side, e.g. `supersedes` or `superseded_by`
+    pub key: String,
+    pub label: String,
+    pub target: String,
+}
+
+/// Frontmatter links touching `spec` in both directions: the ones it
+/// declares and the inverse of those other specs declare towards it.
+pub(crate) fn frontmatter_links(
+    specs_dir: &str,
+    all_specs: &[SpecInfo],
+    spec: &str,
+) -> Vec<FrontmatterLink> {
+    let adapter = MarkdownAdapter::new(specs_dir);
+    let docs = all_specs.iter().map(spec_info_to_doc).collect();
+    let graph = SpecGraph::new(docs, &adapter.schema().link_types);
+    graph
+        .links(spec)
+        .into_iter()
+        .filter(|l| link::FRONTMATTER.contains(&l.link_type))
+        .map(|l| FrontmatterLink {
+            key: l.key.to_string(),
+            label: l.label.to_string(),
+            target: l.target.id.clone(),
+        })
+        .collect()
+}
+
 fn update_relationships(
     specs_dir: &str,
     spec: &str,
@@ -165,8 +215,10 @@ fn update_relationships(
         .load(spec)?
         .ok_or_else(|| format!("Spec not found: {}", spec))?;
 
-    let mut working_specs = loader.load_all()?;
-
+    // Every file touched below is staged and only written once the final
+    // state passes the relationship checks, so a bad child or dependency
+    // leaves the project untouched.
+    let mut changeset = Changeset::new(specs_dir);
     let mut updates: HashMap<String, serde_yaml::Value> = HashMap::new();
     let mut depends_on = spec_info.frontmatter.depends_on.clone();
 
@@ -179,17 +231,9 @@ fn update_relationships(
             let parent_info = loader
                 .load(&parent)?
                 .ok_or_else(|| format!("Parent spec not found: {}", parent))?;
-            let resolved_parent = parent_info.path.clone();
-            validate_parent_assignment(&spec_info.path, &resolved_parent, &working_specs)
-                .map_err(|e| e.to_string())?;
-            updates.insert(
-                "parent".to_string(),
-                serde_yaml::Value::String(resolved_parent.clone()),
-            );
-            set_parent_in_specs(&mut working_specs, &spec_info.path, Some(resolved_parent));
+            updates.insert("parent".to_string(), parent_value(Some(parent_info.path)));
         } else {
-            updates.insert("parent".to_string(), serde_yaml::Value::Null);
-            set_parent_in_specs(&mut working_specs, &spec_info.path, None);
+            updates.insert("parent".to_string(), parent_value(None));
         }
     }
 
@@ -197,8 +241,6 @@ fn update_relationships(
         if is_add {
             for dep in rel_args.depends_on {
                 if !depends_on.contains(&dep) {
-                    validate_dependency_addition(&spec_info.path, &dep, &working_specs)
-                        .map_err(|e| e.to_string())?;
                     depends_on.push(dep);
                 }
             }
@@ -215,40 +257,51 @@ fn update_relationships(
         );
     }
 
-    let mut updated_children = false;
-
-    if !rel_args.children.is_empty() {
-        for child in &rel_args.children {
-            let child_info = loader
-                .load(child)?
-                .ok_or_else(|| format!("Child spec not found: {}", child))?;
-            if is_add {
-                validate_parent_assignment(&child_info.path, &spec_info.path, &working_specs)
-                    .map_err(|e| e.to_string())?;
-                set_parent_for(specs_dir, &child_info.path, Some(spec_info.path.clone()))?;
-                set_parent_in_specs(
-                    &mut working_specs,
-                    &child_info.path,
-                    Some(spec_info.path.clone()),
-                );
-            } else {
-                set_parent_for(specs_dir, &child_info.path, None)?;
-                set_parent_in_specs(&mut working_specs, &child_info.path, None);
-            }
-            updated_children = true;
+    let mut link_targets: HashMap<&str, Vec<String>> = HashMap::new();
+    for (link_type, target) in parse_links(&rel_args.links)? {
+        let resolved = match loader.load(&target)? {
+            Some(info) => info.path,
+            None if is_add => return Err(format!("Spec not found: {}", target).into()),
+            None => target.clone(),
+        };
+        let targets = link_targets
+            .entry(link_type)
+            .or_insert_with(|| spec_info.frontmatter.link_targets(link_type));
+        if !is_add {
+            targets.retain(|t| *t != resolved && *t != target);
+        } else if !targets.contains(&resolved) {
+            targets.push(resolved);
         }
     }
+    for (link_type, targets) in link_targets {
+        let value = if targets.is_empty() {
+            serde_yaml::Value::Null
+        } else {
+            serde_yaml::Value::Sequence(
+                targets.into_iter().map(serde_yaml::Value::String).collect(),
+            )
+        };
+        updates.insert(link_type.to_string(), value);
+    }
+
+    for child in &rel_args.children {
+        let child_info = loader
+            .load(child)?
+            .ok_or_else(|| format!("Child spec not found: {}", child))?;
+        let parent = is_add.then(|| spec_info.path.clone());
+        let mut child_updates = HashMap::new();
+        child_updates.insert("parent".to_string(), parent_value(parent));
+        changeset.update_frontmatter(&child_info.path, &child_updates)?;
+    }
 
-    if updates.is_empty() && !updated_children {
+    if updates.is_empty() && changeset.is_empty() {
         return Err("No relationship changes specified".into());
     }
 
     if !updates.is_empty() {
-        let content = std::fs::read_to_string(&spec_info.file_path)?;
-        let parser = FrontmatterParser::new();
-        let new_content = parser.update_frontmatter(&content, &updates)?;
-        std::fs::write(&spec_info.file_path, &new_content)?;
+        changeset.update_frontmatter(&spec_info.path, &updates)?;
     }
+    changeset.commit()?;
 
     println!(
         "{} Updated relationships for {}",
@@ -258,32 +311,33 @@ fn update_relationships(
     Ok(())
 }
 
-fn set_parent_for(
-    specs_dir: &str,
-    child_spec: &str,
-    parent: Option<String>,
-) -> Result<(), Box<dyn Error>> {
-    let loader = SpecLoader::new(specs_dir);
-    let spec_info = loader
-        .load(child_spec)?
-        .ok_or_else(|| format!("Spec not found: {}", child_spec))?;
-
-    let content = std::fs::read_to_string(&spec_info.file_path)?;
-    let mut updates: HashMap<String, serde_yaml::Value> = HashMap::new();
-    if let Some(parent) = parent {
-        updates.insert("parent".to_string(), serde_yaml::Value::String(parent));
-    } else {
-        updates.insert("parent".to_string(), serde_yaml::Value::Null);
-    }
-
-    let parser = FrontmatterParser::new();
-    let new_content = parser.update_frontmatter(&content, &updates)?;
-    std::fs::write(&spec_info.file_path, &new_content)?;
-    Ok(())
+/// Split `--link <type>=<spec>` values, accepting only the link types kept
+/// in frontmatter.
+fn parse_links(links: &[String]) -> Result<Vec<(&'static str, String)>, Box<dyn Error>> {
+    links
+        .iter()
+        .map(|raw| {
+            let (key, target) = raw
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid link '{}': expected <type>=<spec>", raw))?;
+            let link_type = link::FRONTMATTER
+                .into_iter()
+                .find(|t| *t == key.trim())
+                .ok_or_else(|| {
+                    format!(
+                        "Unknown link type '{}'. Valid types: {} (use --parent/--depends-on for hierarchy and dependencies)",
+                        key,
+                        link::FRONTMATTER.join(", ")
+                    )
+                })?;
+            Ok((link_type, target.trim().to_string()))
+        })
+        .collect()
 }
 
-fn set_parent_in_specs(specs: &mut [leanspec_core::SpecInfo], spec: &str, parent: Option<String>) {
-    if let Some(target) = specs.iter_mut().find(|s| s.path == spec) {
-        target.frontmatter.parent = parent;
+fn parent_value(parent: Option<String>) -> serde_yaml::Value {
+    match parent {
+        Some(parent) => serde_yaml::Value::String(parent),
+        None => serde_yaml::Value::Null,
     }
 }
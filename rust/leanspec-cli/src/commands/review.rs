@@ -0,0 +1,132 @@
+//! `review` command — reviewers and approvals for a spec
+//!
+//! Reviews are stored in a `review.yaml` sidecar next to the spec. When the
+//! project sets `review.require_approval`, `update --status in-progress`
+//! refuses to start a planned spec until its current content is approved.
+
+use super::comment::git_user_name;
+use colored::Colorize;
+use leanspec_core::adapters::markdown::{
+    load_review, record_review, request_review, ReviewDecision, ReviewState, SpecReview,
+};
+use leanspec_core::SpecLoader;
+use std::error::Error;
+
+pub enum ReviewCommand {
+    Status {
+        spec: String,
+    },
+    Request {
+        spec: String,
+        reviewers: Vec<String>,
+    },
+    Decide {
+        spec: String,
+        decision: ReviewDecision,
+        reviewer: Option<String>,
+        comment: Option<String>,
+    },
+}
+
+pub fn run(specs_dir: &str, cmd: ReviewCommand, output_format: &str) -> Result<(), Box<dyn Error>> {
+    let loader = SpecLoader::new(specs_dir);
+    let load = |spec: &str| {
+        loader
+            .load(spec)?
+            .ok_or_else(|| -> Box<dyn Error> { format!("Spec not found: {}", spec).into() })
+    };
+
+    let (review, message) = match cmd {
+        ReviewCommand::Status { spec } => (load_review(&load(&spec)?)?, None),
+        ReviewCommand::Request { spec, reviewers } => {
+            let review = request_review(&load(&spec)?, &reviewers, git_user_name().as_deref())?;
+            let message = format!("Requested review from {}", reviewers.join(", "));
+            (review, Some(message))
+        }
+        ReviewCommand::Decide {
+            spec,
+            decision,
+            reviewer,
+            comment,
+        } => {
+            let reviewer = reviewer
+                .or_else(git_user_name)
+                .ok_or("No reviewer given and git user.name is not set. Use --reviewer.")?;
+            let review = record_review(&load(&spec)?, &reviewer, decision, comment)?;
+            let verb = match decision {
+                ReviewDecision::Approved => "approved",
+                ReviewDecision::ChangesRequested => "requested changes to",
+            };
+            let message = format!("{} {} {}", reviewer, verb, review.spec);
+            (review, Some(message))
+        }
+    };
+
+    if output_format == "json" {
+        println!("{}", serde_json::to_string_pretty(&review)?);
+        return Ok(());
+    }
+    if let Some(message) = message {
+        println!("{} {}", "✓".green(), message);
+    }
+    print_review(&review);
+    Ok(())
+}
+
+fn print_review(review: &SpecReview) {
+    let state = match review.state {
+        ReviewState::Approved => review.state.to_string().green(),
+        ReviewState::ChangesRequested => review.state.to_string().red(),
+        ReviewState::Pending => review.state.to_string().yellow(),
+        ReviewState::NotRequested => review.state.to_string().dimmed(),
+    };
+    println!(
+        "\n{} {} ({} current approval{})",
+        review.spec.cyan(),
+        state,
+        review.approvals,
+        if review.approvals == 1 { "" } else { "s" }
+    );
+
+    if review.reviewers.is_empty() {
+        println!("  No reviewers. Use `lean-spec review request` to ask for one.");
+    }
+    for reviewer in &review.reviewers {
+        let (symbol, status) = match (reviewer.decision, reviewer.stale) {
+            (None, _) => ("○".yellow(), "pending".to_string()),
+            (Some(decision), true) => ("~".dimmed(), format!("{} (stale)", decision)),
+            (Some(ReviewDecision::Approved), false) => ("✓".green(), "approved".to_string()),
+            (Some(ReviewDecision::ChangesRequested), false) => {
+                ("✗".red(), "changes-requested".to_string())
+            }
+        };
+        let when = reviewer
+            .decided_at
+            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        println!(
+            "  {} {} {} {}",
+            symbol,
+            reviewer.reviewer.bold(),
+            status,
+            when.dimmed()
+        );
+    }
+
+    let comments: Vec<_> = review
+        .history
+        .iter()
+        .filter_map(|r| r.comment.as_ref().map(|c| (r, c)))
+        .collect();
+    if !comments.is_empty() {
+        println!("\n  {}", "Comments:".dimmed());
+        for (record, comment) in comments {
+            println!(
+                "  {} {} {}",
+                record.at.format("%Y-%m-%d").to_string().dimmed(),
+                record.reviewer.bold(),
+                comment
+            );
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! Split a spec into multiple files by line ranges.
 
 use colored::Colorize;
+use leanspec_core::adapters::markdown::Changeset;
 use std::error::Error;
 use std::fs;
 use std::path::Path;
@@ -55,7 +56,7 @@ pub fn run(
     }
 
     // Execute the split
-    execute_split(&spec_path, spec, &extractions, update_refs)?;
+    execute_split(specs_dir, &spec_path, spec, &extractions, update_refs)?;
 
     Ok(())
 }
@@ -192,6 +193,7 @@ fn display_dry_run(spec: &str, extractions: &[Extraction]) -> Result<(), Box<dyn
 }
 
 fn execute_split(
+    specs_dir: &str,
     spec_path: &Path,
     spec: &str,
     extractions: &[Extraction],
@@ -200,19 +202,15 @@ fn execute_split(
     println!("{}", format!("✂️  Splitting: {}", spec).cyan().bold());
     println!();
 
+    // Stage every file so a failed write leaves the spec as it was.
+    let readme = spec_path.join("README.md");
+    let mut changeset = Changeset::new(specs_dir);
     for ext in extractions {
-        let output_path = spec_path.join(&ext.file);
-        fs::write(&output_path, &ext.content)?;
-
-        println!(
-            "{} Created {} ({} lines)",
-            "✓".green(),
-            ext.file,
-            ext.line_count
-        );
+        changeset.write(spec_path.join(&ext.file), ext.content.as_str());
     }
 
     // Update README with sub-spec links if requested
+    let mut updated_readme = false;
     if update_refs {
         let sub_specs: Vec<&str> = extractions
             .iter()
@@ -220,17 +218,28 @@ fn execute_split(
             .map(|e| e.file.as_str())
             .collect();
 
-        if !sub_specs.is_empty() {
-            let readme_path = spec_path.join("README.md");
-            if readme_path.exists() {
-                let readme_content = fs::read_to_string(&readme_path)?;
-                let updated = add_sub_spec_links(&readme_content, &sub_specs);
-                fs::write(&readme_path, updated)?;
-                println!("{} Updated README.md with sub-spec links", "✓".green());
-            }
+        if !sub_specs.is_empty() && readme.exists() {
+            let readme_content = changeset.read(&readme)?;
+            let updated = add_sub_spec_links(&readme_content, &sub_specs);
+            changeset.write(&readme, updated);
+            updated_readme = true;
         }
     }
 
+    changeset.commit()?;
+
+    for ext in extractions {
+        println!(
+            "{} Created {} ({} lines)",
+            "✓".green(),
+            ext.file,
+            ext.line_count
+        );
+    }
+    if updated_readme {
+        println!("{} Updated README.md with sub-spec links", "✓".green());
+    }
+
     println!();
     println!("{}", "Split complete!".green().bold());
     println!(
@@ -9,7 +9,8 @@ pub fn run(specs_dir: &str, detailed: bool, output_format: &str) -> Result<(), B
     let specs = loader.load_all()?;
 
     let stats = SpecStats::compute(&specs);
-    let insights = Insights::generate(&specs, &stats);
+    let config = super::project::project_config(specs_dir);
+    let insights = Insights::generate_with_config(&specs, &stats, &config);
 
     if output_format == "json" {
         #[derive(serde::Serialize)]
@@ -7,6 +7,9 @@ use ratatui::layout::Rect;
 use std::collections::HashSet;
 use std::error::Error;
 
+use super::graph::{GraphLayout, Step};
+use super::markdown;
+
 /// Per-project UI preferences persisted across sessions.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct TuiPrefs {
@@ -300,6 +303,16 @@ pub struct AppDebugState {
     pub board_groups: Vec<BoardGroupDebug>,
     pub tree_mode: bool,
     pub sidebar_collapsed: bool,
+    /// Dependency graph layout, when the dependencies view is shown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dep_graph: Option<DepGraphDebug>,
+}
+
+#[derive(serde::Serialize)]
+pub struct DepGraphDebug {
+    pub selected: Option<String>,
+    pub critical_path: Vec<String>,
+    pub layout: Vec<String>,
 }
 
 #[derive(serde::Serialize)]
@@ -339,8 +352,10 @@ pub struct App {
 
     // Detail scroll
     pub detail_scroll: u16,
-    /// Upper bound for detail_scroll estimated from content line count.
+    /// Upper bound for detail_scroll: the number of rendered content lines.
     pub detail_content_lines: u16,
+    /// Text width the detail content was last laid out at (0 = stale).
+    pub detail_layout_width: u16,
 
     // Search
     pub search_query: String,
@@ -372,6 +387,10 @@ pub struct App {
     /// Cursor position in the TOC overlay.
     pub toc_selected: usize,
 
+    // Dependency graph
+    /// Spec selected in the dependency graph; `None` means the displayed spec.
+    pub graph_selected: Option<String>,
+
     // Project management
     /// The currently active project (if loaded from registry).
     pub current_project: Option<leanspec_core::storage::Project>,
@@ -443,6 +462,7 @@ impl App {
             list_scroll_offset: 0,
             detail_scroll: 0,
             detail_content_lines: u16::MAX,
+            detail_layout_width: 0,
             search_query: String::new(),
             search_results: Vec::new(),
             sidebar_width_pct: 30,
@@ -460,6 +480,7 @@ impl App {
             tree_rows: Vec::new(),
             detail_toc: Vec::new(),
             toc_selected: 0,
+            graph_selected: None,
             current_project: initial_project,
             project_switcher: None,
             project_mgmt: None,
@@ -720,11 +741,9 @@ impl App {
             }
         };
         if let Ok(Some(full)) = self.loader.load(&path) {
-            let new_lines = full.content.lines().count() as u16;
-            self.detail_content_lines = new_lines;
-            self.detail_toc = Self::extract_headings_inner(&full.content);
-            self.detail_scroll = self.detail_scroll.min(new_lines.saturating_sub(1));
             self.selected_detail = Some(full);
+            self.detail_layout_width = 0;
+            self.layout_detail();
         } else {
             self.selected_detail = None;
             self.detail_content_lines = u16::MAX;
@@ -1063,9 +1082,10 @@ impl App {
             };
             let path = &spec.path;
             if let Ok(Some(full)) = self.loader.load(path) {
-                self.detail_content_lines = full.content.lines().count() as u16;
-                self.detail_toc = Self::extract_headings_inner(&full.content);
                 self.selected_detail = Some(full);
+                self.detail_scroll = 0;
+                self.detail_layout_width = 0;
+                self.layout_detail();
             } else {
                 self.selected_detail = None;
                 self.detail_content_lines = u16::MAX;
@@ -1078,46 +1098,30 @@ impl App {
         }
         self.detail_scroll = 0;
         self.toc_selected = 0;
+        self.graph_selected = None;
     }
 
-    /// Extract ## and ### headings from markdown content.
-    /// Returns (line_index, level, heading_text).
-    fn extract_headings_inner(content: &str) -> Vec<(usize, u8, String)> {
-        let mut headings: Vec<(usize, u8, String)> = Vec::new();
-        let mut line_idx: usize = 0;
-        let mut in_code_block = false;
-        let mut code_len: usize = 0;
-
-        for raw_line in content.lines() {
-            let trimmed = raw_line.trim_end();
-
-            if trimmed.starts_with("```") {
-                if in_code_block {
-                    // End of code block — count its rendered lines
-                    line_idx += code_len + 2;
-                    in_code_block = false;
-                    code_len = 0;
-                } else {
-                    in_code_block = true;
-                }
-                continue;
-            }
-
-            if in_code_block {
-                code_len += 1;
-                continue;
-            }
-
-            if let Some(rest) = trimmed.strip_prefix("## ") {
-                headings.push((line_idx, 2, rest.to_string()));
-            } else if let Some(rest) = trimmed.strip_prefix("### ") {
-                headings.push((line_idx, 3, rest.to_string()));
-            }
-
-            line_idx += 1;
+    /// Lay the selected spec out at the detail pane's current width, so
+    /// the scroll limit and table of contents match the rendered lines.
+    /// Does nothing when the width hasn't changed since the last layout.
+    pub fn layout_detail(&mut self) {
+        let width = match self.layout_right.width {
+            0 => 80,
+            w => w.saturating_sub(2),
+        };
+        if width == self.detail_layout_width {
+            return;
         }
-
-        headings
+        self.detail_layout_width = width;
+        let Some(spec) = &self.selected_detail else {
+            return;
+        };
+        let doc = markdown::render_document(&spec.content, width);
+        self.detail_content_lines = doc.lines.len().min(u16::MAX as usize) as u16;
+        self.detail_toc = doc.headings;
+        self.detail_scroll = self
+            .detail_scroll
+            .min(self.detail_content_lines.saturating_sub(1));
     }
 
     // -- Navigation --
@@ -1310,6 +1314,59 @@ impl App {
         };
     }
 
+    // -- Dependency graph --
+
+    /// Layout of the displayed spec's dependency graph.
+    pub fn graph_layout(&self) -> Option<GraphLayout> {
+        let spec = self.selected_detail.as_ref()?;
+        GraphLayout::build(&self.dep_graph, &spec.path)
+    }
+
+    /// Node selected in `layout`, falling back to the displayed spec.
+    pub fn graph_selected_node(&self, layout: &GraphLayout) -> Option<usize> {
+        self.graph_selected
+            .as_deref()
+            .and_then(|path| layout.node(path))
+            .or_else(|| layout.node(&self.selected_detail.as_ref()?.path))
+    }
+
+    /// Move the graph selection. Returns false when there is no node that way.
+    pub fn graph_step(&mut self, step: Step) -> bool {
+        let Some(layout) = self.graph_layout() else {
+            return false;
+        };
+        let next = self
+            .graph_selected_node(&layout)
+            .and_then(|node| layout.step(node, step));
+        match next {
+            Some(node) => {
+                self.graph_selected = Some(layout.nodes[node].path.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Open the spec selected in the graph in the content view.
+    pub fn graph_open_selected(&mut self) {
+        let Some(path) = self.graph_selected.clone() else {
+            self.detail_mode = DetailMode::Content;
+            return;
+        };
+        self.restore_selection_by_path(&path);
+        self.load_selected_detail();
+        if self
+            .selected_detail
+            .as_ref()
+            .is_some_and(|s| s.path == path)
+        {
+            self.detail_mode = DetailMode::Content;
+        } else {
+            // Hidden by the current filter: keep the graph as it was
+            self.graph_selected = Some(path);
+        }
+    }
+
     pub fn focus_left(&mut self) {
         self.focus = FocusPane::Left;
     }
@@ -1564,9 +1621,31 @@ impl App {
                 .collect(),
             tree_mode: self.tree_mode,
             sidebar_collapsed: self.sidebar_collapsed,
+            dep_graph: self.dep_graph_debug(),
         }
     }
 
+    fn dep_graph_debug(&self) -> Option<DepGraphDebug> {
+        if self.detail_mode != DetailMode::Dependencies {
+            return None;
+        }
+        let layout = self.graph_layout()?;
+        let selected = self.graph_selected_node(&layout);
+        Some(DepGraphDebug {
+            selected: selected.map(|n| layout.nodes[n].path.clone()),
+            critical_path: layout
+                .critical_path()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            layout: layout
+                .render(selected)
+                .iter()
+                .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+                .collect(),
+        })
+    }
+
     /// Create an empty App for testing (no filesystem access needed).
     #[cfg(test)]
     pub fn empty_for_test() -> Self {
@@ -1589,6 +1668,7 @@ impl App {
             list_scroll_offset: 0,
             detail_scroll: 0,
             detail_content_lines: u16::MAX,
+            detail_layout_width: 0,
             search_query: String::new(),
             search_results: Vec::new(),
             sidebar_width_pct: 30,
@@ -1606,6 +1686,7 @@ impl App {
             tree_rows: Vec::new(),
             detail_toc: Vec::new(),
             toc_selected: 0,
+            graph_selected: None,
             current_project: None,
             project_switcher: None,
             project_mgmt: None,
@@ -1,86 +1,82 @@
-//! Dependency tree widget — upstream and downstream deps for selected spec.
+//! Dependency graph widget — layered graph around the selected spec.
 
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Widget},
 };
 
-use super::app::App;
+use super::app::{App, FocusPane};
 use super::theme;
 
 pub fn render(area: Rect, buf: &mut Buffer, app: &App) {
+    let border_style = if app.focus == FocusPane::Right {
+        theme::border_focused_style()
+    } else {
+        theme::border_unfocused_style()
+    };
     let block = Block::default()
         .title(" Dependencies ")
         .borders(Borders::ALL)
-        .border_style(theme::border_unfocused_style());
+        .border_style(border_style);
     let inner = block.inner(area);
     block.render(area, buf);
 
-    let Some(spec) = &app.selected_detail else {
+    let Some(layout) = app.graph_layout() else {
         let msg =
             Paragraph::new("  Select a spec to view dependencies").style(theme::dimmed_style());
         msg.render(inner, buf);
         return;
     };
+    let selected = app.graph_selected_node(&layout);
 
-    let mut lines: Vec<Line> = Vec::new();
+    let chunks = Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).split(inner);
 
-    // Upstream (depends on)
-    let upstream = app.dep_graph.get_upstream(&spec.path, 3);
-    lines.push(Line::from(Span::styled(
-        " Upstream (depends on):",
-        theme::header_style(),
-    )));
-    if upstream.is_empty() {
-        lines.push(Line::styled("   (none)", theme::dimmed_style()));
-    } else {
-        for dep in &upstream {
-            let sym = theme::status_symbol(&dep.frontmatter.status);
-            let style = theme::status_style(&dep.frontmatter.status);
-            lines.push(Line::from(vec![
-                Span::raw("   "),
-                Span::styled(sym, style),
-                Span::raw(format!(" {} - {}", dep.path, dep.title)),
-            ]));
-        }
+    // Header: selected node and critical path
+    let mut header = Vec::new();
+    if let Some(node) = selected.map(|n| &layout.nodes[n]) {
+        header.push(Line::from(vec![
+            Span::styled(
+                format!(" {} ", theme::status_symbol(&node.status)),
+                theme::status_style(&node.status),
+            ),
+            Span::styled(node.path.clone(), theme::title_style()),
+            Span::raw(format!(" - {}", node.title)),
+        ]));
     }
+    let critical_path = layout.critical_path();
+    header.push(if layout.nodes.len() == 1 {
+        Line::styled(" No dependencies", theme::dimmed_style())
+    } else if critical_path.is_empty() {
+        Line::styled(" All dependencies complete", theme::dimmed_style())
+    } else {
+        Line::from(vec![
+            Span::styled(" Critical path: ", theme::dimmed_style()),
+            Span::styled(critical_path.join(" → "), theme::critical_path_style()),
+        ])
+    });
+    Paragraph::new(header).render(chunks[0], buf);
 
-    lines.push(Line::from(""));
+    // Graph, scrolled to keep the selected node in view
+    let graph_area = chunks[1];
+    let (width, height) = layout.size();
+    let (x, y, node_width) = selected.map_or((0, 0, 0), |n| layout.node_rect(n));
+    let scroll_x = scroll_offset(x + node_width / 2, width, graph_area.width as usize);
+    let scroll_y = scroll_offset(y + 1, height, graph_area.height as usize);
 
-    // Downstream (required by)
-    let downstream = app.dep_graph.get_downstream(&spec.path, 3);
-    lines.push(Line::from(Span::styled(
-        " Downstream (required by):",
-        theme::header_style(),
-    )));
-    if downstream.is_empty() {
-        lines.push(Line::styled("   (none)", theme::dimmed_style()));
-    } else {
-        for dep in &downstream {
-            let sym = theme::status_symbol(&dep.frontmatter.status);
-            let style = theme::status_style(&dep.frontmatter.status);
-            lines.push(Line::from(vec![
-                Span::raw("   "),
-                Span::styled(sym, style),
-                Span::raw(format!(" {} - {}", dep.path, dep.title)),
-            ]));
-        }
-    }
+    Paragraph::new(layout.render(selected))
+        .scroll((scroll_y, scroll_x))
+        .render(graph_area, buf);
+}
 
-    // Direct dependencies from frontmatter
-    if !spec.frontmatter.depends_on.is_empty() {
-        lines.push(Line::from(""));
-        lines.push(Line::from(Span::styled(
-            " Direct dependencies (frontmatter):",
-            theme::header_style(),
-        )));
-        for dep_path in &spec.frontmatter.depends_on {
-            lines.push(Line::from(format!("   -> {}", dep_path)));
-        }
+/// Offset that centres `target` in a viewport, clamped to the content.
+fn scroll_offset(target: usize, content: usize, viewport: usize) -> u16 {
+    if content <= viewport {
+        return 0;
     }
-
-    let paragraph = Paragraph::new(lines);
-    paragraph.render(inner, buf);
+    target
+        .saturating_sub(viewport / 2)
+        .min(content - viewport)
+        .min(u16::MAX as usize) as u16
 }
@@ -6,9 +6,7 @@ use ratatui::{
     prelude::StatefulWidget,
     style::Style,
     text::{Line, Span},
-    widgets::{
-        Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Widget, Wrap,
-    },
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Widget},
 };
 
 use leanspec_core::SpecInfo;
@@ -100,7 +98,12 @@ fn render_metadata(area: Rect, buf: &mut Buffer, spec: &SpecInfo, app: &App) {
     let updated_str = spec.frontmatter.updated.as_deref().unwrap_or("-");
 
     let lines = vec![
-        Line::from(vec![Span::styled(spec.title.clone(), theme::title_style())]),
+        Line::from(
+            markdown::parse_inline(&spec.title)
+                .into_iter()
+                .map(|span| Span::styled(span.content, theme::title_style().patch(span.style)))
+                .collect::<Vec<_>>(),
+        ),
         Line::from(vec![
             Span::styled(format!(" {}", spec.path), theme::dimmed_style()),
             Span::styled(deps_str, theme::dimmed_style()),
@@ -139,9 +142,9 @@ fn render_content(area: Rect, buf: &mut Buffer, spec: &SpecInfo, app: &App) {
     let total_lines = lines.len();
     let viewport_height = area.height as usize;
 
-    let paragraph = Paragraph::new(lines)
-        .wrap(Wrap { trim: false })
-        .scroll((app.detail_scroll, 0));
+    // Lines are already wrapped to the pane, so scroll offsets line up
+    // with the table of contents.
+    let paragraph = Paragraph::new(lines).scroll((app.detail_scroll, 0));
     paragraph.render(area, buf);
 
     // Scrollbar — only render when content exceeds viewport
@@ -0,0 +1,683 @@
+//! Layered layout of a spec's dependency neighbourhood for the deps pane.
+//!
+//! Specs are placed in layers with dependencies above their dependents
+//! (longest-path layering), edges spanning several layers are threaded
+//! through placeholder slots, and each layer is ordered by the barycenter of
+//! its neighbours to keep crossings down. The result is drawn with box
+//! characters onto a character canvas.
+
+use std::collections::{HashMap, HashSet};
+
+use leanspec_core::{DependencyGraph, SpecStatus};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use super::theme;
+
+/// How far upstream and downstream of the selected spec the graph reaches.
+const DEPTH: usize = 3;
+/// Widest label drawn inside a node box before truncation.
+const MAX_LABEL: usize = 24;
+const BOX_HEIGHT: usize = 3;
+/// Rows between two layers used for routing edges.
+const EDGE_ROWS: usize = 3;
+const LAYER_HEIGHT: usize = BOX_HEIGHT + EDGE_ROWS;
+const COLUMN_GAP: usize = 2;
+/// Barycenter sweeps (down and up) when ordering layers.
+const ORDER_SWEEPS: usize = 4;
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// Direction to move the graph selection in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+pub struct GraphNode {
+    pub path: String,
+    pub title: String,
+    pub status: SpecStatus,
+    pub critical: bool,
+}
+
+/// A box in a layer: a spec, or a placeholder an edge passes through.
+struct Slot {
+    node: Option<usize>,
+    layer: usize,
+    x: usize,
+    width: usize,
+}
+
+impl Slot {
+    fn center(&self) -> usize {
+        self.x + self.width / 2
+    }
+}
+
+/// Part of an edge between slots in adjacent layers.
+struct Segment {
+    from: usize,
+    to: usize,
+    critical: bool,
+}
+
+pub struct GraphLayout {
+    pub nodes: Vec<GraphNode>,
+    slots: Vec<Slot>,
+    /// Slot indices of each layer, left to right
+    layers: Vec<Vec<usize>>,
+    segments: Vec<Segment>,
+    /// Nodes on the critical path, dependencies first
+    critical_path: Vec<usize>,
+    width: usize,
+}
+
+impl GraphLayout {
+    /// Lay out `root` with its upstream and downstream dependencies.
+    pub fn build(graph: &DependencyGraph, root: &str) -> Option<Self> {
+        let mut specs = vec![graph.get_complete_graph(root)?.current];
+        specs.extend(graph.get_upstream(root, DEPTH));
+        specs.extend(graph.get_downstream(root, DEPTH));
+        let mut seen = HashSet::new();
+        specs.retain(|s| seen.insert(s.path.clone()));
+        specs.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let index: HashMap<&str, usize> = specs
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.path.as_str(), i))
+            .collect();
+        // Edges run from a dependency to the spec depending on it
+        let mut edges: Vec<(usize, usize)> = specs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, spec)| {
+                spec.frontmatter
+                    .depends_on
+                    .iter()
+                    .filter_map(|dep| index.get(dep.as_str()).copied())
+                    .filter(move |&j| j != i)
+                    .map(move |j| (j, i))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let layer = assign_layers(specs.len(), &edges);
+        // Edges closing a cycle point back up and aren't drawn
+        edges.retain(|&(from, to)| layer[to] > layer[from]);
+
+        let nodes: Vec<GraphNode> = specs
+            .into_iter()
+            .map(|spec| GraphNode {
+                path: spec.path,
+                title: spec.title,
+                status: spec.frontmatter.status,
+                critical: false,
+            })
+            .collect();
+
+        let mut layout = GraphLayout {
+            slots: nodes
+                .iter()
+                .zip(&layer)
+                .enumerate()
+                .map(|(i, (node, &layer))| Slot {
+                    node: Some(i),
+                    layer,
+                    x: 0,
+                    width: label(node).chars().count() + 4,
+                })
+                .collect(),
+            nodes,
+            layers: Vec::new(),
+            segments: Vec::new(),
+            critical_path: Vec::new(),
+            width: 0,
+        };
+        layout.mark_critical_path(&edges, &layer);
+        layout.thread_edges(&edges, &layer);
+        layout.order_layers();
+        layout.place();
+        Some(layout)
+    }
+
+    /// Index of the node for a spec path.
+    pub fn node(&self, path: &str) -> Option<usize> {
+        self.nodes.iter().position(|n| n.path == path)
+    }
+
+    /// Paths on the critical path, dependencies first.
+    pub fn critical_path(&self) -> Vec<&str> {
+        self.critical_path
+            .iter()
+            .map(|&n| self.nodes[n].path.as_str())
+            .collect()
+    }
+
+    /// Canvas size as `(width, height)`.
+    pub fn size(&self) -> (usize, usize) {
+        let height = (self.layers.len() * LAYER_HEIGHT).saturating_sub(EDGE_ROWS);
+        (self.width, height)
+    }
+
+    /// Top-left corner and width of a node's box on the canvas.
+    pub fn node_rect(&self, node: usize) -> (usize, usize, usize) {
+        let slot = &self.slots[node];
+        (slot.x, slot.layer * LAYER_HEIGHT, slot.width)
+    }
+
+    /// The node next to `node` in direction `step`: the closest node in the
+    /// layer above or below, or the neighbour in the same layer.
+    pub fn step(&self, node: usize, step: Step) -> Option<usize> {
+        let slot = &self.slots[node];
+        let layer = match step {
+            Step::Up => slot.layer.checked_sub(1)?,
+            Step::Down => slot.layer + 1,
+            Step::Left | Step::Right => {
+                let row: Vec<usize> = self.layers[slot.layer]
+                    .iter()
+                    .filter_map(|&s| self.slots[s].node)
+                    .collect();
+                let pos = row.iter().position(|&n| n == node)?;
+                return match step {
+                    Step::Left => pos.checked_sub(1).map(|p| row[p]),
+                    _ => row.get(pos + 1).copied(),
+                };
+            }
+        };
+        self.layers
+            .get(layer)?
+            .iter()
+            .filter_map(|&s| self.slots[s].node)
+            .min_by_key(|&n| self.slots[n].center().abs_diff(slot.center()))
+    }
+
+    /// Draw the graph with `selected` highlighted.
+    pub fn render(&self, selected: Option<usize>) -> Vec<Line<'static>> {
+        let (width, height) = self.size();
+        let mut canvas = Canvas::new(width, height);
+
+        for segment in &self.segments {
+            self.draw_segment(&mut canvas, segment);
+        }
+        for (i, slot) in self.slots.iter().enumerate() {
+            let top = slot.layer * LAYER_HEIGHT;
+            match slot.node {
+                Some(node) => self.draw_node(&mut canvas, node, selected == Some(node)),
+                None => {
+                    let critical = self.segments.iter().any(|s| s.to == i && s.critical);
+                    for row in top..top + BOX_HEIGHT {
+                        canvas.line(row, slot.x, UP | DOWN, edge_style(critical));
+                    }
+                }
+            }
+        }
+        canvas.into_lines()
+    }
+
+    /// Add placeholder slots so every edge only connects adjacent layers.
+    fn thread_edges(&mut self, edges: &[(usize, usize)], layer: &[usize]) {
+        for &(from, to) in edges {
+            let critical = self.nodes[from].critical
+                && self.nodes[to].critical
+                && self
+                    .critical_path
+                    .windows(2)
+                    .any(|w| w[0] == from && w[1] == to);
+            let mut previous = from;
+            for l in layer[from] + 1..layer[to] {
+                self.slots.push(Slot {
+                    node: None,
+                    layer: l,
+                    x: 0,
+                    width: 1,
+                });
+                let slot = self.slots.len() - 1;
+                self.segments.push(Segment {
+                    from: previous,
+                    to: slot,
+                    critical,
+                });
+                previous = slot;
+            }
+            self.segments.push(Segment {
+                from: previous,
+                to,
+                critical,
+            });
+        }
+
+        let count = layer.iter().max().map_or(0, |l| l + 1);
+        self.layers = vec![Vec::new(); count];
+        for (i, slot) in self.slots.iter().enumerate() {
+            self.layers[slot.layer].push(i);
+        }
+    }
+
+    /// Reorder each layer by the mean position of its neighbours in the
+    /// layer above, then below.
+    fn order_layers(&mut self) {
+        let mut position = vec![0.0; self.slots.len()];
+        let update = |layers: &[Vec<usize>], position: &mut Vec<f64>| {
+            for layer in layers {
+                for (p, &s) in layer.iter().enumerate() {
+                    position[s] = p as f64;
+                }
+            }
+        };
+        update(&self.layers, &mut position);
+
+        for _ in 0..ORDER_SWEEPS {
+            for l in 1..self.layers.len() {
+                self.sort_layer(l, &position, true);
+                update(&self.layers, &mut position);
+            }
+            for l in (0..self.layers.len().saturating_sub(1)).rev() {
+                self.sort_layer(l, &position, false);
+                update(&self.layers, &mut position);
+            }
+        }
+    }
+
+    fn sort_layer(&mut self, layer: usize, position: &[f64], by_above: bool) {
+        let barycenter = |slot: usize| {
+            let neighbours: Vec<f64> = self
+                .segments
+                .iter()
+                .filter_map(|s| match by_above {
+                    true if s.to == slot => Some(position[s.from]),
+                    false if s.from == slot => Some(position[s.to]),
+                    _ => None,
+                })
+                .collect();
+            if neighbours.is_empty() {
+                position[slot]
+            } else {
+                neighbours.iter().sum::<f64>() / neighbours.len() as f64
+            }
+        };
+        let mut keyed: Vec<(f64, usize)> = self.layers[layer]
+            .iter()
+            .map(|&s| (barycenter(s), s))
+            .collect();
+        keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.layers[layer] = keyed.into_iter().map(|(_, s)| s).collect();
+    }
+
+    /// Pack each layer left to right, centred on the widest layer.
+    fn place(&mut self) {
+        let slots = &mut self.slots;
+        let widths: Vec<usize> = self
+            .layers
+            .iter()
+            .map(|layer| {
+                layer.iter().map(|&s| slots[s].width).sum::<usize>()
+                    + COLUMN_GAP * layer.len().saturating_sub(1)
+            })
+            .collect();
+        self.width = widths.iter().copied().max().unwrap_or(0);
+        for (layer, layer_width) in self.layers.iter().zip(widths) {
+            let mut x = (self.width - layer_width) / 2;
+            for &s in layer {
+                slots[s].x = x;
+                x += slots[s].width + COLUMN_GAP;
+            }
+        }
+    }
+
+    /// Find the longest chain of dependencies, counting only unfinished
+    /// specs, and flag its nodes.
+    fn mark_critical_path(&mut self, edges: &[(usize, usize)], layer: &[usize]) {
+        let mut order: Vec<usize> = (0..self.nodes.len()).collect();
+        order.sort_by_key(|&n| layer[n]);
+
+        let mut length = vec![0usize; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        for &node in &order {
+            let best = edges
+                .iter()
+                .filter(|&&(_, to)| to == node)
+                .map(|&(from, _)| from)
+                .fold(None, |best: Option<usize>, from| match best {
+                    Some(b) if length[b] >= length[from] => Some(b),
+                    _ => Some(from),
+                });
+            let unfinished = !matches!(
+                self.nodes[node].status,
+                SpecStatus::Complete | SpecStatus::Archived
+            );
+            length[node] = best.map_or(0, |b| length[b]) + usize::from(unfinished);
+            previous[node] = best.filter(|&b| length[b] > 0);
+        }
+
+        let Some(mut node) = order.iter().copied().filter(|&n| length[n] > 0).fold(
+            None,
+            |best: Option<usize>, n| match best {
+                Some(b) if length[b] >= length[n] => Some(b),
+                _ => Some(n),
+            },
+        ) else {
+            return;
+        };
+        let mut path = vec![node];
+        while let Some(p) = previous[node] {
+            path.push(p);
+            node = p;
+        }
+        if path.len() < 2 {
+            return;
+        }
+        path.reverse();
+        for &n in &path {
+            self.nodes[n].critical = true;
+        }
+        self.critical_path = path;
+    }
+
+    fn draw_segment(&self, canvas: &mut Canvas, segment: &Segment) {
+        let from = &self.slots[segment.from];
+        let to = &self.slots[segment.to];
+        let style = edge_style(segment.critical);
+        let (a, b) = (from.center(), to.center());
+        let row = from.layer * LAYER_HEIGHT + BOX_HEIGHT;
+
+        if from.node.is_some() {
+            canvas.line(row - 1, a, DOWN, style);
+        }
+        canvas.line(row, a, UP | DOWN, style);
+        if a == b {
+            canvas.line(row + 1, a, UP | DOWN, style);
+        } else {
+            let (toward_b, toward_a) = if b > a { (RIGHT, LEFT) } else { (LEFT, RIGHT) };
+            canvas.line(row + 1, a, UP | toward_b, style);
+            for x in a.min(b) + 1..a.max(b) {
+                canvas.line(row + 1, x, LEFT | RIGHT, style);
+            }
+            canvas.line(row + 1, b, DOWN | toward_a, style);
+        }
+        match to.node {
+            Some(_) => canvas.text(row + 2, b, "▼", style),
+            None => canvas.line(row + 2, b, UP | DOWN, style),
+        }
+    }
+
+    fn draw_node(&self, canvas: &mut Canvas, node: usize, selected: bool) {
+        let slot = &self.slots[node];
+        let spec = &self.nodes[node];
+        let (x, top, width) = self.node_rect(node);
+        let right = x + width - 1;
+
+        let border = if selected {
+            theme::border_focused_style().add_modifier(Modifier::BOLD)
+        } else if spec.critical {
+            theme::critical_path_style()
+        } else {
+            theme::border_unfocused_style()
+        };
+        canvas.line(top, x, DOWN | RIGHT, border);
+        canvas.line(top, right, DOWN | LEFT, border);
+        canvas.line(top + 2, x, UP | RIGHT, border);
+        canvas.line(top + 2, right, UP | LEFT, border);
+        for col in x + 1..right {
+            canvas.line(top, col, LEFT | RIGHT, border);
+            canvas.line(top + 2, col, LEFT | RIGHT, border);
+        }
+        canvas.line(top + 1, x, UP | DOWN, border);
+        canvas.line(top + 1, right, UP | DOWN, border);
+
+        let mut text = theme::status_style(&spec.status);
+        if selected {
+            text = theme::selected_style().patch(text);
+        }
+        canvas.text(top + 1, slot.x + 1, &format!(" {} ", label(spec)), text);
+    }
+}
+
+/// Status symbol and path, truncated to fit a box.
+fn label(node: &GraphNode) -> String {
+    let text = format!("{} {}", theme::status_symbol(&node.status), node.path);
+    if text.chars().count() <= MAX_LABEL {
+        return text;
+    }
+    let mut truncated: String = text.chars().take(MAX_LABEL - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+fn edge_style(critical: bool) -> Style {
+    if critical {
+        theme::critical_path_style()
+    } else {
+        theme::dimmed_style()
+    }
+}
+
+/// Longest-path layering from the specs without dependencies. Specs in a
+/// cycle are placed one at a time, ignoring the edges that close it.
+fn assign_layers(count: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut layer = vec![0; count];
+    let mut placed = vec![false; count];
+    let mut pending = vec![0usize; count];
+    for &(_, to) in edges {
+        pending[to] += 1;
+    }
+
+    while let Some(first) = (0..count).find(|&n| !placed[n]) {
+        let mut ready: Vec<usize> = (0..count)
+            .filter(|&n| !placed[n] && pending[n] == 0)
+            .collect();
+        if ready.is_empty() {
+            ready.push(first);
+        }
+        for &node in &ready {
+            layer[node] = edges
+                .iter()
+                .filter(|&&(from, to)| to == node && placed[from])
+                .map(|&(from, _)| layer[from] + 1)
+                .max()
+                .unwrap_or(0);
+        }
+        for &node in &ready {
+            placed[node] = true;
+            for &(_, to) in edges.iter().filter(|&&(from, _)| from == node) {
+                pending[to] = pending[to].saturating_sub(1);
+            }
+        }
+    }
+    layer
+}
+
+#[derive(Clone)]
+struct Cell {
+    text: char,
+    /// Line directions through the cell, drawn as a box character
+    lines: u8,
+    style: Style,
+}
+
+struct Canvas {
+    cells: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        let blank = Cell {
+            text: ' ',
+            lines: 0,
+            style: Style::default(),
+        };
+        Self {
+            cells: vec![vec![blank; width]; height],
+        }
+    }
+
+    fn line(&mut self, row: usize, col: usize, lines: u8, style: Style) {
+        if let Some(cell) = self.cells.get_mut(row).and_then(|r| r.get_mut(col)) {
+            cell.lines |= lines;
+            cell.style = style;
+        }
+    }
+
+    fn text(&mut self, row: usize, col: usize, text: &str, style: Style) {
+        for (i, c) in text.chars().enumerate() {
+            if let Some(cell) = self.cells.get_mut(row).and_then(|r| r.get_mut(col + i)) {
+                cell.text = c;
+                cell.lines = 0;
+                cell.style = style;
+            }
+        }
+    }
+
+    fn into_lines(self) -> Vec<Line<'static>> {
+        self.cells
+            .into_iter()
+            .map(|row| {
+                let mut spans: Vec<Span<'static>> = Vec::new();
+                for cell in row {
+                    let c = match cell.lines {
+                        0 => cell.text,
+                        lines => box_char(lines),
+                    };
+                    match spans.last_mut() {
+                        Some(last) if last.style == cell.style => last.content.to_mut().push(c),
+                        _ => spans.push(Span::styled(c.to_string(), cell.style)),
+                    }
+                }
+                if let Some(last) = spans.last_mut() {
+                    let trimmed = last.content.trim_end().len();
+                    last.content.to_mut().truncate(trimmed);
+                }
+                spans.retain(|s| !s.content.is_empty());
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+fn box_char(lines: u8) -> char {
+    match lines {
+        l if l == UP | DOWN | LEFT | RIGHT => '┼',
+        l if l == UP | DOWN | RIGHT => '├',
+        l if l == UP | DOWN | LEFT => '┤',
+        l if l == DOWN | LEFT | RIGHT => '┬',
+        l if l == UP | LEFT | RIGHT => '┴',
+        l if l == DOWN | RIGHT => '┌',
+        l if l == DOWN | LEFT => '┐',
+        l if l == UP | RIGHT => '└',
+        l if l == UP | LEFT => '┘',
+        l if l & (UP | DOWN) != 0 => '│',
+        _ => '─',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leanspec_core::SpecInfo;
+
+    fn spec(path: &str, status: &str, depends_on: &[&str]) -> SpecInfo {
+        let frontmatter = format!(
+            "status: {}\ncreated: '2026-01-01'\ndepends_on: [{}]",
+            status,
+            depends_on.join(", ")
+        );
+        SpecInfo {
+            path: path.to_string(),
+            title: path.to_string(),
+            frontmatter: serde_yaml::from_str(&frontmatter).unwrap(),
+            content: String::new(),
+            file_path: Default::default(),
+            is_sub_spec: false,
+            parent_spec: None,
+        }
+    }
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    fn sample() -> DependencyGraph {
+        DependencyGraph::new(&[
+            spec("001-db", "complete", &[]),
+            spec("002-auth", "in-progress", &[]),
+            spec("003-api", "planned", &["001-db", "002-auth"]),
+            spec("004-ui", "planned", &["003-api", "001-db"]),
+        ])
+    }
+
+    #[test]
+    fn lays_out_dependencies_above_dependents() {
+        let layout = GraphLayout::build(&sample(), "003-api").unwrap();
+        assert_eq!(
+            text(&layout.render(None)),
+            vec![
+                "┌──────────┐  ┌────────────┐",
+                "│ ✓ 001-db │  │ ▶ 002-auth │",
+                "└─────┬────┘  └──────┬─────┘",
+                "      │              │",
+                "      ├────────┬─────┘",
+                "      │        ▼",
+                "      │  ┌───────────┐",
+                "      │  │ · 003-api │",
+                "      │  └─────┬─────┘",
+                "      │        │",
+                "      └───────┬┘",
+                "              ▼",
+                "        ┌──────────┐",
+                "        │ · 004-ui │",
+                "        └──────────┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn critical_path_follows_unfinished_specs() {
+        let layout = GraphLayout::build(&sample(), "004-ui").unwrap();
+        assert_eq!(
+            layout.critical_path(),
+            vec!["002-auth", "003-api", "004-ui"]
+        );
+    }
+
+    #[test]
+    fn steps_between_nodes() {
+        let layout = GraphLayout::build(&sample(), "003-api").unwrap();
+        let node = |path| layout.node(path).unwrap();
+        assert_eq!(
+            layout.step(node("001-db"), Step::Right),
+            Some(node("002-auth"))
+        );
+        assert_eq!(layout.step(node("001-db"), Step::Left), None);
+        assert_eq!(
+            layout.step(node("002-auth"), Step::Down),
+            Some(node("003-api"))
+        );
+        assert_eq!(
+            layout.step(node("003-api"), Step::Down),
+            Some(node("004-ui"))
+        );
+        assert_eq!(layout.step(node("004-ui"), Step::Down), None);
+    }
+
+    #[test]
+    fn tolerates_cycles() {
+        let graph = DependencyGraph::new(&[
+            spec("001-a", "planned", &["002-b"]),
+            spec("002-b", "planned", &["001-a"]),
+        ]);
+        let layout = GraphLayout::build(&graph, "001-a").unwrap();
+        assert_eq!(layout.size().1, LAYER_HEIGHT + BOX_HEIGHT);
+    }
+}
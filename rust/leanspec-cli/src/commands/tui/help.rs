@@ -41,6 +41,8 @@ const HELP_LINES: &[(&str, &str)] = &[
     ("", ""),
     ("Detail Pane", ""),
     ("  T", "Open table of contents (TOC)"),
+    ("  h/j/k/l", "Move between specs in the graph"),
+    ("  Enter", "Open the spec selected in the graph"),
     ("", ""),
     ("Sidebar", ""),
     ("  [ / ]", "Narrow / widen sidebar"),
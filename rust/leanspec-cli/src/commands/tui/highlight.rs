@@ -0,0 +1,463 @@
+//! Lightweight syntax highlighting for code blocks in the detail pane.
+//!
+//! A per-language table of keywords and comment/string delimiters drives a
+//! small tokenizer: enough to tell keywords, strings, comments, numbers and
+//! keys apart in the snippets specs usually contain.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+
+struct Language {
+    keywords: &'static [&'static str],
+    line_comment: Option<&'static str>,
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    case_insensitive: bool,
+    /// `key:` / `key =` at the start of a line and strings before `:` are keys
+    keys: bool,
+}
+
+const RUST: Language = Language {
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+        "true", "type", "unsafe", "use", "where", "while", "Some", "None", "Ok", "Err",
+    ],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    case_insensitive: false,
+    keys: false,
+};
+
+const TYPESCRIPT: Language = Language {
+    keywords: &[
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "else",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "if",
+        "implements",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "let",
+        "new",
+        "null",
+        "of",
+        "return",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "type",
+        "typeof",
+        "undefined",
+        "var",
+        "while",
+        "yield",
+    ],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\'', '`'],
+    case_insensitive: false,
+    keys: false,
+};
+
+const PYTHON: Language = Language {
+    keywords: &[
+        "and", "as", "async", "await", "break", "class", "continue", "def", "elif", "else",
+        "except", "False", "finally", "for", "from", "if", "import", "in", "is", "lambda", "None",
+        "not", "or", "pass", "raise", "return", "self", "True", "try", "while", "with", "yield",
+    ],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+    case_insensitive: false,
+    keys: false,
+};
+
+const GO: Language = Language {
+    keywords: &[
+        "break",
+        "case",
+        "chan",
+        "const",
+        "continue",
+        "default",
+        "defer",
+        "else",
+        "false",
+        "for",
+        "func",
+        "go",
+        "if",
+        "import",
+        "interface",
+        "map",
+        "nil",
+        "package",
+        "range",
+        "return",
+        "select",
+        "struct",
+        "switch",
+        "true",
+        "type",
+        "var",
+    ],
+    line_comment: Some("//"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '`'],
+    case_insensitive: false,
+    keys: false,
+};
+
+const SHELL: Language = Language {
+    keywords: &[
+        "case", "do", "done", "echo", "elif", "else", "esac", "export", "fi", "for", "function",
+        "if", "in", "local", "return", "then", "while",
+    ],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+    case_insensitive: false,
+    keys: false,
+};
+
+const SQL: Language = Language {
+    keywords: &[
+        "and",
+        "as",
+        "by",
+        "create",
+        "delete",
+        "from",
+        "group",
+        "index",
+        "insert",
+        "into",
+        "join",
+        "key",
+        "left",
+        "limit",
+        "not",
+        "null",
+        "on",
+        "or",
+        "order",
+        "primary",
+        "references",
+        "select",
+        "set",
+        "table",
+        "update",
+        "values",
+        "where",
+    ],
+    line_comment: Some("--"),
+    block_comment: Some(("/*", "*/")),
+    quotes: &['\''],
+    case_insensitive: true,
+    keys: false,
+};
+
+const DATA: Language = Language {
+    keywords: &["true", "false", "null", "yes", "no", "~"],
+    line_comment: Some("#"),
+    block_comment: None,
+    quotes: &['"', '\''],
+    case_insensitive: false,
+    keys: true,
+};
+
+const JSON: Language = Language {
+    keywords: &["true", "false", "null"],
+    line_comment: None,
+    block_comment: None,
+    quotes: &['"'],
+    case_insensitive: false,
+    keys: true,
+};
+
+fn language(lang: &str) -> Option<&'static Language> {
+    match lang.to_lowercase().as_str() {
+        "rust" | "rs" => Some(&RUST),
+        "typescript" | "ts" | "tsx" | "javascript" | "js" | "jsx" => Some(&TYPESCRIPT),
+        "python" | "py" => Some(&PYTHON),
+        "go" | "golang" => Some(&GO),
+        "bash" | "sh" | "shell" | "zsh" | "console" => Some(&SHELL),
+        "sql" => Some(&SQL),
+        "yaml" | "yml" | "toml" => Some(&DATA),
+        "json" | "jsonc" => Some(&JSON),
+        _ => None,
+    }
+}
+
+fn plain_style() -> Style {
+    Style::default().fg(Color::White)
+}
+
+fn keyword_style() -> Style {
+    Style::default()
+        .fg(Color::Magenta)
+        .add_modifier(Modifier::BOLD)
+}
+
+fn string_style() -> Style {
+    Style::default().fg(Color::Green)
+}
+
+fn comment_style() -> Style {
+    Style::default()
+        .fg(Color::DarkGray)
+        .add_modifier(Modifier::ITALIC)
+}
+
+fn number_style() -> Style {
+    Style::default().fg(Color::Yellow)
+}
+
+fn key_style() -> Style {
+    Style::default().fg(Color::Cyan)
+}
+
+/// Highlights the lines of one code block, carrying block comments across
+/// lines.
+pub struct Highlighter {
+    language: Option<&'static Language>,
+    in_block_comment: bool,
+}
+
+impl Highlighter {
+    pub fn new(lang: &str) -> Self {
+        Self {
+            language: language(lang),
+            in_block_comment: false,
+        }
+    }
+
+    /// Styled spans for one line. Languages without a table render in the
+    /// plain code color.
+    pub fn line(&mut self, line: &str) -> Vec<Span<'static>> {
+        let Some(lang) = self.language else {
+            return vec![Span::styled(
+                line.to_string(),
+                Style::default().fg(Color::Green),
+            )];
+        };
+
+        let mut out = Output::default();
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+
+        if lang.keys && !self.in_block_comment {
+            i = self.leading_key(&chars, &mut out);
+        }
+
+        while i < chars.len() {
+            let rest: String = chars[i..].iter().collect();
+
+            if self.in_block_comment {
+                let (_, end) = lang.block_comment.unwrap_or(("", ""));
+                match rest.find(end) {
+                    Some(pos) => {
+                        let len = rest[..pos].chars().count() + end.chars().count();
+                        out.push(&chars[i..i + len], comment_style());
+                        i += len;
+                        self.in_block_comment = false;
+                    }
+                    None => {
+                        out.push(&chars[i..], comment_style());
+                        break;
+                    }
+                }
+                continue;
+            }
+
+            if lang.line_comment.is_some_and(|c| rest.starts_with(c)) {
+                out.push(&chars[i..], comment_style());
+                break;
+            }
+
+            if let Some((start, _)) = lang.block_comment {
+                if rest.starts_with(start) {
+                    self.in_block_comment = true;
+                    let len = start.chars().count();
+                    out.push(&chars[i..i + len], comment_style());
+                    i += len;
+                    continue;
+                }
+            }
+
+            let c = chars[i];
+            if lang.quotes.contains(&c) {
+                let end = string_end(&chars, i);
+                let is_key =
+                    lang.keys && chars[end..].iter().find(|c| !c.is_whitespace()) == Some(&':');
+                let style = if is_key { key_style() } else { string_style() };
+                out.push(&chars[i..end], style);
+                i = end;
+                continue;
+            }
+
+            if c.is_ascii_digit() && (i == 0 || !is_ident(chars[i - 1])) {
+                let end = run_end(&chars, i, |c| {
+                    c.is_ascii_alphanumeric() || c == '.' || c == '_'
+                });
+                out.push(&chars[i..end], number_style());
+                i = end;
+                continue;
+            }
+
+            if is_ident(c) {
+                let end = run_end(&chars, i, is_ident);
+                let word: String = chars[i..end].iter().collect();
+                let is_keyword = if lang.case_insensitive {
+                    lang.keywords.iter().any(|k| k.eq_ignore_ascii_case(&word))
+                } else {
+                    lang.keywords.contains(&word.as_str())
+                };
+                let style = if is_keyword {
+                    keyword_style()
+                } else if !lang.keys && word.starts_with(|c: char| c.is_ascii_uppercase()) {
+                    key_style()
+                } else {
+                    plain_style()
+                };
+                out.push(&chars[i..end], style);
+                i = end;
+                continue;
+            }
+
+            out.push(&chars[i..i + 1], plain_style());
+            i += 1;
+        }
+
+        out.spans
+    }
+
+    /// Style a YAML/TOML key or TOML table header at the start of a line,
+    /// returning where the rest of the line starts.
+    fn leading_key(&self, chars: &[char], out: &mut Output) -> usize {
+        let indent = run_end(chars, 0, |c| c.is_whitespace() || c == '-');
+        let rest: String = chars[indent..].iter().collect();
+        if rest.starts_with('[') {
+            out.push(&chars[..indent], plain_style());
+            out.push(&chars[indent..], keyword_style());
+            return chars.len();
+        }
+        let key_end = run_end(chars, indent, |c| is_ident(c) || c == '-' || c == '.');
+        let after = chars[key_end..].iter().find(|c| !c.is_whitespace());
+        if key_end > indent && matches!(after, Some(':') | Some('=')) {
+            out.push(&chars[..indent], plain_style());
+            out.push(&chars[indent..key_end], key_style());
+            return key_end;
+        }
+        0
+    }
+}
+
+/// Spans being built for one line; adjacent pieces of the same style merge.
+#[derive(Default)]
+struct Output {
+    spans: Vec<Span<'static>>,
+}
+
+impl Output {
+    fn push(&mut self, chars: &[char], style: Style) {
+        if chars.is_empty() {
+            return;
+        }
+        let text: String = chars.iter().collect();
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push_str(&text),
+            _ => self.spans.push(Span::styled(text, style)),
+        }
+    }
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn run_end(chars: &[char], start: usize, pred: impl Fn(char) -> bool) -> usize {
+    chars[start..]
+        .iter()
+        .position(|c| !pred(*c))
+        .map_or(chars.len(), |offset| start + offset)
+}
+
+/// Index just past the string starting at `start`, or the end of the line
+/// when it is unterminated.
+fn string_end(chars: &[char], start: usize) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled<'a>(spans: &'a [Span<'static>], text: &str) -> &'a Span<'static> {
+        spans
+            .iter()
+            .find(|s| s.content == text)
+            .unwrap_or_else(|| panic!("no span {:?} in {:?}", text, spans))
+    }
+
+    #[test]
+    fn highlights_rust_tokens() {
+        let mut h = Highlighter::new("rust");
+        let spans = h.line("let x = \"hi\"; // note");
+        assert_eq!(styled(&spans, "let").style, keyword_style());
+        assert_eq!(styled(&spans, "\"hi\"").style, string_style());
+        assert_eq!(styled(&spans, "// note").style, comment_style());
+
+        let spans = h.line("/* start");
+        assert_eq!(spans[0].style, comment_style());
+        let spans = h.line("end */ fn");
+        assert_eq!(styled(&spans, "end */").style, comment_style());
+        assert_eq!(styled(&spans, "fn").style, keyword_style());
+    }
+
+    #[test]
+    fn highlights_yaml_keys() {
+        let mut h = Highlighter::new("yaml");
+        let spans = h.line("  status: planned # soon");
+        assert_eq!(styled(&spans, "status").style, key_style());
+        assert_eq!(styled(&spans, "# soon").style, comment_style());
+    }
+
+    #[test]
+    fn unknown_language_is_plain() {
+        let mut h = Highlighter::new("brainfuck");
+        let spans = h.line("+[-->+<]");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].style.fg, Some(Color::Green));
+    }
+}
@@ -3,7 +3,8 @@
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
 use std::path::PathBuf;
 
-use super::app::{App, AppMode, FocusPane};
+use super::app::{App, AppMode, DetailMode, FocusPane};
+use super::graph::Step;
 
 /// Expand `~` in a path to the user's home directory.
 fn expand_tilde(path: &str) -> PathBuf {
@@ -110,6 +111,12 @@ pub fn handle_key(app: &mut App, key: KeyEvent) {
 }
 
 fn handle_normal(app: &mut App, key: KeyEvent) {
+    if app.focus == FocusPane::Right
+        && app.detail_mode == DetailMode::Dependencies
+        && handle_graph(app, key)
+    {
+        return;
+    }
     let page_size = (app.layout_left.height.saturating_sub(4) as usize).max(5);
 
     match key.code {
@@ -219,6 +226,31 @@ fn handle_normal(app: &mut App, key: KeyEvent) {
     }
 }
 
+/// Graph navigation in the dependencies view. Returns false for keys it
+/// doesn't handle.
+fn handle_graph(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.graph_step(Step::Down);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.graph_step(Step::Up);
+        }
+        KeyCode::Char('h') | KeyCode::Left => {
+            // Past the leftmost node, fall back to focusing the sidebar
+            if !app.graph_step(Step::Left) {
+                app.focus_left();
+            }
+        }
+        KeyCode::Char('l') | KeyCode::Right => {
+            app.graph_step(Step::Right);
+        }
+        KeyCode::Enter => app.graph_open_selected(),
+        _ => return false,
+    }
+    true
+}
+
 /// Handle mouse events.
 pub fn handle_mouse(app: &mut App, mouse: MouseEvent) {
     use ratatui::crossterm::event::{MouseButton, MouseEventKind};
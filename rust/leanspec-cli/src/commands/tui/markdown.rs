@@ -1,326 +1,782 @@
 //! Markdown renderer for the TUI detail pane.
 //!
-//! Converts markdown text into ratatui `Line` values with styled `Span`s.
+//! Walks `pulldown-cmark` events and lays the document out as ratatui
+//! `Line`s: paragraphs and list items are word-wrapped to the pane width
+//! with hanging indents, tables get fitted columns, code blocks are
+//! highlighted, mermaid flowcharts are drawn as trees and links are numbered
+//! with their targets listed at the end.
 
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::highlight::Highlighter;
+use super::mermaid;
+
+/// Narrowest column any text is wrapped to, however deep the nesting.
+const MIN_TEXT_WIDTH: usize = 8;
+
+/// A rendered document and where its `##`/`###` headings ended up.
+pub struct RenderedMarkdown {
+    pub lines: Vec<Line<'static>>,
+    /// `(line_index, level, text)` for every level 2 and 3 heading
+    pub headings: Vec<(usize, u8, String)>,
+}
 
 /// Render markdown content into a list of styled ratatui lines.
 pub fn render_markdown(content: &str, width: u16) -> Vec<Line<'static>> {
-    let mut lines: Vec<Line<'static>> = Vec::new();
-    let mut in_code_block = false;
-    let mut code_lang = String::new();
-    let mut code_lines: Vec<String> = Vec::new();
-
-    for raw_line in content.lines() {
-        let trimmed = raw_line.trim_end();
-
-        // --- Code block handling ---
-        if trimmed.starts_with("```") {
-            if in_code_block {
-                // End of code block — emit box
-                let block_lines = flush_code_block(&code_lang, &code_lines, width);
-                lines.extend(block_lines);
-                in_code_block = false;
-                code_lang.clear();
-                code_lines.clear();
-            } else {
-                // Start of code block
-                in_code_block = true;
-                code_lang = trimmed.trim_start_matches('`').trim().to_string();
-            }
-            continue;
-        }
-
-        if in_code_block {
-            code_lines.push(trimmed.to_string());
-            continue;
-        }
+    render_document(content, width).lines
+}
 
-        // --- Normal markdown elements ---
-        let line = parse_line(trimmed, width);
-        lines.push(line);
+/// Render markdown content for a pane `width` columns wide.
+pub fn render_document(content: &str, width: u16) -> RenderedMarkdown {
+    let mut renderer = Renderer::new(width.saturating_sub(2) as usize, true);
+    for event in Parser::new_ext(content, options()) {
+        renderer.event(event);
     }
+    renderer.finish()
+}
 
-    // Unclosed code block — flush remaining
-    if in_code_block && !code_lines.is_empty() {
-        let block_lines = flush_code_block(&code_lang, &code_lines, width);
-        lines.extend(block_lines);
+/// Parse inline markdown elements (bold, code, links) into `Span`s.
+pub fn parse_inline(text: &str) -> Vec<Span<'static>> {
+    let mut renderer = Renderer::new(usize::MAX, false);
+    for event in Parser::new_ext(text, options()) {
+        match event {
+            Event::Start(Tag::Paragraph) | Event::End(TagEnd::Paragraph) => {}
+            event => renderer.event(event),
+        }
     }
+    renderer.inline
+}
 
-    lines
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS
+}
+
+fn dim() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
+
+fn inline_code_style() -> Style {
+    Style::default().fg(Color::Green).bg(Color::DarkGray)
+}
+
+struct ListLevel {
+    /// Number of the next item in an ordered list
+    next: Option<u64>,
+    /// Width of the current item's marker, which its continuation lines
+    /// are indented by
+    indent: usize,
+}
+
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<Vec<Span<'static>>>>,
+    header_rows: usize,
+}
+
+struct CodeBlock {
+    lang: String,
+    text: String,
+}
+
+struct Renderer {
+    /// Columns available to the document
+    width: usize,
+    /// Number links and list their targets at the end
+    footnotes: bool,
+    lines: Vec<Line<'static>>,
+    headings: Vec<(usize, u8, String)>,
+    /// Inline content of the block being built
+    inline: Vec<Span<'static>>,
+    styles: Vec<Style>,
+    /// Open links: destination and where their text starts in `inline`
+    open_links: Vec<(String, usize)>,
+    links: Vec<String>,
+    quote_depth: usize,
+    lists: Vec<ListLevel>,
+    /// Marker of the current list item until its first line is written
+    item_marker: Option<Span<'static>>,
+    heading: Option<HeadingLevel>,
+    code: Option<CodeBlock>,
+    table: Option<Table>,
+    in_html_comment: bool,
+    /// Whether the next top-level block is separated by a blank line
+    needs_gap: bool,
 }
 
-/// Parse a single non-code-block line into a `Line<'static>`.
-fn parse_line(text: &str, width: u16) -> Line<'static> {
-    // Horizontal rule
-    if text == "---" || text == "***" || text == "___" {
-        let rule = "─".repeat(width.saturating_sub(2) as usize);
-        return Line::from(Span::styled(rule, Style::default().fg(Color::DarkGray)));
-    }
-
-    // H1
-    if let Some(rest) = text.strip_prefix("# ") {
-        let header_text = format!(" ═ {}", rest);
-        return Line::from(Span::styled(
-            header_text,
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ));
-    }
-
-    // H2
-    if let Some(rest) = text.strip_prefix("## ") {
-        let header_text = format!(" — {}", rest);
-        return Line::from(Span::styled(
-            header_text,
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ));
-    }
-
-    // H3
-    if let Some(rest) = text.strip_prefix("### ") {
-        let header_text = format!("   {}", rest);
-        return Line::from(Span::styled(
-            header_text,
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        ));
-    }
-
-    // Blockquote
-    if let Some(rest) = text.strip_prefix("> ") {
-        let mut spans = vec![Span::styled(
-            " │ ".to_string(),
-            Style::default().fg(Color::DarkGray),
-        )];
-        spans.extend(parse_inline(rest));
-        return Line::from(spans);
-    }
-    if text == ">" {
-        return Line::from(Span::styled(
-            " │ ".to_string(),
-            Style::default().fg(Color::DarkGray),
-        ));
-    }
-
-    // Task list items
-    if text.starts_with("- [ ] ") || text.starts_with("* [ ] ") {
-        let rest = &text[6..];
-        let mut spans = vec![Span::styled(
-            " ○ ".to_string(),
-            Style::default().fg(Color::DarkGray),
-        )];
-        spans.extend(parse_inline(rest));
-        return Line::from(spans);
-    }
-    if text.starts_with("- [x] ")
-        || text.starts_with("- [X] ")
-        || text.starts_with("* [x] ")
-        || text.starts_with("* [X] ")
-    {
-        let rest = &text[6..];
-        let mut spans = vec![Span::styled(
-            " ✓ ".to_string(),
-            Style::default().fg(Color::Green),
-        )];
-        spans.extend(parse_inline(rest));
-        return Line::from(spans);
-    }
-
-    // Bullet list items
-    if let Some(rest) = text.strip_prefix("- ").or_else(|| text.strip_prefix("* ")) {
-        let mut spans = vec![Span::raw(" • ".to_string())];
-        spans.extend(parse_inline(rest));
-        return Line::from(spans);
-    }
-
-    // Table rows (contain | characters)
-    if text.contains('|') && text.trim_start().starts_with('|') {
-        // Separator row: |---|---|
-        let is_separator = text
-            .split('|')
-            .filter(|s| !s.trim().is_empty())
-            .all(|cell| cell.trim().chars().all(|c| c == '-' || c == ':'));
-        if is_separator {
-            let rule = "─".repeat(text.chars().count());
-            return Line::from(Span::styled(rule, Style::default().fg(Color::DarkGray)));
+impl Renderer {
+    fn new(width: usize, footnotes: bool) -> Self {
+        Self {
+            width,
+            footnotes,
+            lines: Vec::new(),
+            headings: Vec::new(),
+            inline: Vec::new(),
+            styles: Vec::new(),
+            open_links: Vec::new(),
+            links: Vec::new(),
+            quote_depth: 0,
+            lists: Vec::new(),
+            item_marker: None,
+            heading: None,
+            code: None,
+            table: None,
+            in_html_comment: false,
+            needs_gap: false,
         }
+    }
 
-        // Regular table row
-        let spans: Vec<Span<'static>> = text
-            .split('|')
-            .enumerate()
-            .flat_map(|(i, cell)| {
-                if i == 0 && cell.trim().is_empty() {
-                    // Leading pipe — skip
-                    return vec![];
-                }
-                if cell.trim().is_empty() && i > 0 {
-                    return vec![];
-                }
-                let mut cell_spans = parse_inline(cell.trim());
-                cell_spans.push(Span::raw(" │ ".to_string()));
-                cell_spans
-            })
-            .collect();
-        return Line::from(spans);
-    }
-
-    // Default: inline parsing
-    let spans = parse_inline(text);
-    if spans.is_empty() {
-        Line::from("")
-    } else {
-        Line::from(spans)
+    fn style(&self) -> Style {
+        self.styles
+            .iter()
+            .fold(Style::default(), |style, s| style.patch(*s))
     }
-}
 
-/// Parse inline markdown elements (bold, code, links) into `Span`s.
-pub fn parse_inline(text: &str) -> Vec<Span<'static>> {
-    let mut spans: Vec<Span<'static>> = Vec::new();
-    let chars: Vec<char> = text.chars().collect();
-    let len = chars.len();
-    let mut i = 0;
-    let mut current = String::new();
-
-    while i < len {
-        // Inline code: `...`
-        if chars[i] == '`' {
-            if !current.is_empty() {
-                spans.push(Span::raw(current.clone()));
-                current.clear();
-            }
-            i += 1;
-            let start = i;
-            while i < len && chars[i] != '`' {
-                i += 1;
-            }
-            let code: String = chars[start..i].iter().collect();
-            spans.push(Span::styled(
-                code,
-                Style::default().fg(Color::Green).bg(Color::DarkGray),
-            ));
-            if i < len {
-                i += 1; // skip closing `
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => match &mut self.code {
+                Some(code) => code.text.push_str(&text),
+                None => self
+                    .inline
+                    .push(Span::styled(text.into_string(), self.style())),
+            },
+            Event::Code(code) => self
+                .inline
+                .push(Span::styled(code.into_string(), inline_code_style())),
+            Event::SoftBreak => self.inline.push(Span::styled(" ", self.style())),
+            Event::HardBreak => self.flush_inline(Vec::new()),
+            Event::Rule => {
+                self.start_block();
+                let prefix = self.prefix(true);
+                let rule = "─".repeat(self.width.saturating_sub(spans_width(&prefix)));
+                self.push_line(prefix, vec![Span::styled(rule, dim())]);
+                self.needs_gap = true;
             }
-            continue;
+            Event::TaskListMarker(checked) => {
+                self.item_marker = Some(if checked {
+                    Span::styled(" ✓ ", Style::default().fg(Color::Green))
+                } else {
+                    Span::styled(" ○ ", dim())
+                });
+            }
+            Event::Html(html) | Event::InlineHtml(html) => self.html(&html),
+            _ => {}
         }
+    }
 
-        // Bold: **...**
-        if i + 1 < len && chars[i] == '*' && chars[i + 1] == '*' {
-            if !current.is_empty() {
-                spans.push(Span::raw(current.clone()));
-                current.clear();
-            }
-            i += 2;
-            let start = i;
-            while i + 1 < len && !(chars[i] == '*' && chars[i + 1] == '*') {
-                i += 1;
-            }
-            let bold_text: String = chars[start..i].iter().collect();
-            spans.push(Span::styled(
-                bold_text,
-                Style::default().add_modifier(Modifier::BOLD),
-            ));
-            if i + 1 < len {
-                i += 2; // skip closing **
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { level, .. } => {
+                self.start_block();
+                self.heading = Some(level);
             }
-            continue;
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.start_block();
+                let lang = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().unwrap_or("").to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code = Some(CodeBlock {
+                    lang,
+                    text: String::new(),
+                });
+            }
+            Tag::List(first) => {
+                if self.lists.is_empty() {
+                    self.start_block();
+                } else {
+                    // Text of the parent item goes above the nested list
+                    self.flush_inline(Vec::new());
+                    self.flush_marker();
+                }
+                self.lists.push(ListLevel {
+                    next: first,
+                    indent: 0,
+                });
+            }
+            Tag::Item => {
+                let Some(level) = self.lists.last_mut() else {
+                    return;
+                };
+                let marker = match level.next.as_mut() {
+                    Some(n) => {
+                        let marker = format!(" {}. ", n);
+                        *n += 1;
+                        Span::styled(marker, Style::default().fg(Color::Yellow))
+                    }
+                    None => Span::raw(" • "),
+                };
+                level.indent = marker.width();
+                self.item_marker = Some(marker);
+            }
+            Tag::Table(alignments) => {
+                self.start_block();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    header_rows: 0,
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => self.inline.clear(),
+            Tag::Emphasis => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { dest_url, .. } => {
+                self.styles.push(Style::default().fg(Color::Cyan));
+                self.open_links
+                    .push((dest_url.into_string(), self.inline.len()));
+            }
+            Tag::Image { dest_url, .. } => {
+                self.styles.push(dim().add_modifier(Modifier::ITALIC));
+                self.inline.push(Span::styled("▣ ", self.style()));
+                self.open_links
+                    .push((dest_url.into_string(), self.inline.len()));
+            }
+            _ => {}
         }
+    }
 
-        // Link: [text](url)
-        if chars[i] == '[' {
-            // Find closing ]
-            let bracket_start = i + 1;
-            let mut j = bracket_start;
-            while j < len && chars[j] != ']' {
-                j += 1;
-            }
-            if j < len && j + 1 < len && chars[j + 1] == '(' {
-                // Found [text]( — now find )
-                let paren_start = j + 2;
-                let mut k = paren_start;
-                while k < len && chars[k] != ')' {
-                    k += 1;
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.flush_inline(Vec::new()),
+            TagEnd::Heading(level) => {
+                self.heading = None;
+                let (lead, style) = match level {
+                    HeadingLevel::H1 => (
+                        " ═ ",
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    HeadingLevel::H2 => (
+                        " — ",
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    _ => (
+                        "   ",
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                };
+                let level = match level {
+                    HeadingLevel::H1 => 1,
+                    HeadingLevel::H2 => 2,
+                    HeadingLevel::H3 => 3,
+                    _ => 4,
+                };
+                if matches!(level, 2 | 3) {
+                    let text = spans_text(&self.inline);
+                    self.headings.push((self.lines.len(), level, text));
+                }
+                self.inline = std::mem::take(&mut self.inline)
+                    .into_iter()
+                    .map(|span| span.patch_style(style))
+                    .collect();
+                self.flush_inline(vec![Span::styled(lead, style)]);
+            }
+            TagEnd::BlockQuote(_) => {
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+                self.needs_gap = true;
+            }
+            TagEnd::CodeBlock => {
+                if let Some(code) = self.code.take() {
+                    self.code_block(&code);
+                }
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+                self.needs_gap = true;
+            }
+            TagEnd::Item => {
+                self.flush_inline(Vec::new());
+                self.flush_marker();
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.header_rows = table.rows.len();
+                }
+            }
+            TagEnd::TableCell => {
+                let cell = std::mem::take(&mut self.inline);
+                if let Some(row) = self.table.as_mut().and_then(|t| t.rows.last_mut()) {
+                    row.push(cell);
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.render_table(table);
                 }
-                if k < len {
-                    // Valid link
-                    if !current.is_empty() {
-                        spans.push(Span::raw(current.clone()));
-                        current.clear();
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link | TagEnd::Image => {
+                self.styles.pop();
+                if let Some((dest, start)) = self.open_links.pop() {
+                    let text = spans_text(&self.inline[start.min(self.inline.len())..]);
+                    if self.footnotes && !dest.is_empty() && dest != text {
+                        let number = match self.links.iter().position(|l| *l == dest) {
+                            Some(index) => index + 1,
+                            None => {
+                                self.links.push(dest);
+                                self.links.len()
+                            }
+                        };
+                        self.inline
+                            .push(Span::styled(format!("[{}]", number), dim()));
                     }
-                    let link_text: String = chars[bracket_start..j].iter().collect();
-                    spans.push(Span::styled(link_text, Style::default().fg(Color::Cyan)));
-                    i = k + 1; // skip past )
-                    continue;
                 }
             }
+            _ => {}
         }
+    }
 
-        current.push(chars[i]);
-        i += 1;
+    /// HTML passes through dimmed, except comments which are hidden.
+    fn html(&mut self, html: &str) {
+        if self.in_html_comment || html.trim_start().starts_with("<!--") {
+            self.in_html_comment = !html.contains("-->");
+            return;
+        }
+        self.inline
+            .push(Span::styled(html.trim_end_matches('\n').to_string(), dim()));
+        if html.ends_with('\n') {
+            self.start_block();
+            self.flush_inline(Vec::new());
+        }
     }
 
-    if !current.is_empty() {
-        spans.push(Span::raw(current));
+    /// Separate a new top-level block from the previous one.
+    fn start_block(&mut self) {
+        if self.needs_gap && self.lists.is_empty() && !self.lines.is_empty() {
+            let prefix = self.prefix(false);
+            self.lines.push(Line::from(prefix));
+        }
+        self.needs_gap = false;
     }
 
-    spans
+    /// Quote bars and list indentation for the next line. The first line of
+    /// a list item carries its marker.
+    fn prefix(&mut self, first: bool) -> Vec<Span<'static>> {
+        let mut spans = Vec::new();
+        if self.quote_depth > 0 {
+            spans.push(Span::styled(" │ ".repeat(self.quote_depth), dim()));
+        }
+        let Some((current, outer)) = self.lists.split_last() else {
+            return spans;
+        };
+        let outer: usize = outer.iter().map(|l| l.indent).sum();
+        let marker = if first { self.item_marker.take() } else { None };
+        match marker {
+            Some(marker) => {
+                if outer > 0 {
+                    spans.push(Span::raw(" ".repeat(outer)));
+                }
+                spans.push(marker);
+            }
+            None => spans.push(Span::raw(" ".repeat(outer + current.indent))),
+        }
+        spans
+    }
+
+    fn push_line(&mut self, prefix: Vec<Span<'static>>, content: Vec<Span<'static>>) {
+        let mut spans = prefix;
+        spans.extend(content);
+        spans.retain(|s| !s.content.is_empty());
+        self.lines.push(Line::from(spans));
+    }
+
+    /// Word-wrap the pending inline content under the current prefix, with
+    /// `lead` before the first line and its width indenting the rest.
+    fn flush_inline(&mut self, lead: Vec<Span<'static>>) {
+        if self.inline.iter().all(|s| s.content.trim().is_empty()) {
+            self.inline.clear();
+            return;
+        }
+        let spans = std::mem::take(&mut self.inline);
+        let mut first = self.prefix(true);
+        let lead_width = spans_width(&lead);
+        first.extend(lead);
+        let mut rest = self.prefix(false);
+        if lead_width > 0 {
+            rest.push(Span::raw(" ".repeat(lead_width)));
+        }
+
+        let available = self
+            .width
+            .saturating_sub(spans_width(&first))
+            .max(MIN_TEXT_WIDTH);
+        for (i, line) in wrap_spans(&spans, available).into_iter().enumerate() {
+            let prefix = if i == 0 { first.clone() } else { rest.clone() };
+            self.push_line(prefix, line);
+        }
+        self.needs_gap = true;
+    }
+
+    /// Write the marker of an item that had no text of its own.
+    fn flush_marker(&mut self) {
+        if self.item_marker.is_some() {
+            let prefix = self.prefix(true);
+            self.push_line(prefix, Vec::new());
+        }
+    }
+
+    fn code_block(&mut self, code: &CodeBlock) {
+        let flowchart = (code.lang == "mermaid")
+            .then(|| mermaid::render_flowchart(&code.text))
+            .flatten();
+        let (label, body) = match flowchart {
+            Some(lines) => (
+                "mermaid flowchart".to_string(),
+                lines.into_iter().map(|l| l.spans).collect(),
+            ),
+            None => {
+                let mut highlighter = Highlighter::new(&code.lang);
+                let body = code
+                    .text
+                    .trim_end_matches('\n')
+                    .split('\n')
+                    .map(|line| highlighter.line(line))
+                    .collect();
+                (code.lang.clone(), body)
+            }
+        };
+
+        let mut first = true;
+        let prefix_width = {
+            let prefix = self.prefix(false);
+            spans_width(&prefix)
+        };
+        let box_width = self
+            .width
+            .saturating_sub(prefix_width)
+            .max(MIN_TEXT_WIDTH + 4);
+        for line in boxed(&label, body, box_width) {
+            let prefix = self.prefix(first);
+            first = false;
+            self.push_line(prefix, line);
+        }
+        self.needs_gap = true;
+    }
+
+    fn render_table(&mut self, table: Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        let mut widths = vec![1; columns];
+        for row in &table.rows {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(spans_width(cell));
+            }
+        }
+        let prefix_width = {
+            let prefix = self.prefix(false);
+            spans_width(&prefix)
+        };
+        let available = self
+            .width
+            .saturating_sub(prefix_width + 1 + 3 * (columns - 1));
+        fit_columns(&mut widths, available);
+
+        let separator = || Span::styled(" │ ", dim());
+        for (r, row) in table.rows.iter().enumerate() {
+            let header = r < table.header_rows;
+            let cells: Vec<Vec<Vec<Span<'static>>>> = (0..columns)
+                .map(|c| {
+                    let cell = row.get(c).cloned().unwrap_or_default();
+                    let cell = if header {
+                        cell.into_iter()
+                            .map(|s| s.patch_style(Style::default().add_modifier(Modifier::BOLD)))
+                            .collect()
+                    } else {
+                        cell
+                    };
+                    wrap_spans(&cell, widths[c])
+                })
+                .collect();
+            let height = cells.iter().map(Vec::len).max().unwrap_or(0).max(1);
+
+            for k in 0..height {
+                let mut spans = vec![Span::raw(" ")];
+                for (c, cell) in cells.iter().enumerate() {
+                    if c > 0 {
+                        spans.push(separator());
+                    }
+                    let content = cell.get(k).cloned().unwrap_or_default();
+                    let alignment = table.alignments.get(c).copied().unwrap_or(Alignment::None);
+                    spans.extend(pad(content, widths[c], alignment));
+                }
+                let prefix = self.prefix(false);
+                self.push_line(prefix, spans);
+            }
+
+            if header && r + 1 == table.header_rows {
+                let rule = widths
+                    .iter()
+                    .map(|w| "─".repeat(*w))
+                    .collect::<Vec<_>>()
+                    .join("─┼─");
+                let prefix = self.prefix(false);
+                self.push_line(prefix, vec![Span::styled(format!(" {}", rule), dim())]);
+            }
+        }
+        self.needs_gap = true;
+    }
+
+    fn finish(mut self) -> RenderedMarkdown {
+        self.flush_inline(Vec::new());
+        if !self.links.is_empty() {
+            self.start_block();
+            if !self.lines.is_empty() {
+                self.lines.push(Line::from(""));
+            }
+            self.lines.push(Line::from(Span::styled(
+                " Links",
+                dim().add_modifier(Modifier::BOLD),
+            )));
+            for (i, link) in self.links.iter().enumerate() {
+                let label = format!(" [{}] ", i + 1);
+                let available = self.width.saturating_sub(label.width()).max(MIN_TEXT_WIDTH);
+                let url = vec![Span::styled(link.clone(), dim())];
+                for (j, part) in split_width(&url, available).into_iter().enumerate() {
+                    let lead = if j == 0 {
+                        label.clone()
+                    } else {
+                        " ".repeat(label.width())
+                    };
+                    let mut spans = vec![Span::styled(lead, dim())];
+                    spans.extend(part);
+                    self.lines.push(Line::from(spans));
+                }
+            }
+        }
+        RenderedMarkdown {
+            lines: self.lines,
+            headings: self.headings,
+        }
+    }
 }
 
-/// Flush a collected code block into bordered box lines.
-fn flush_code_block(lang: &str, code_lines: &[String], width: u16) -> Vec<Line<'static>> {
-    let inner_width = width.saturating_sub(4) as usize;
-    let mut result: Vec<Line<'static>> = Vec::new();
+/// Frame `body` in a box `width` columns wide, with `label` in the top
+/// border. Lines longer than the box are broken.
+fn boxed(label: &str, body: Vec<Vec<Span<'static>>>, width: usize) -> Vec<Vec<Span<'static>>> {
+    let inner_width = width.saturating_sub(2);
+    let content_width = inner_width.saturating_sub(2);
 
-    // Top border: ┌─ lang ─┐
-    let lang_label = if lang.is_empty() {
+    let label = if label.is_empty() {
         String::new()
     } else {
-        format!(" {} ", lang)
+        format!(" {} ", label)
     };
-    let dashes_total = inner_width.saturating_sub(lang_label.chars().count());
-    let dashes_left = dashes_total / 2;
-    let dashes_right = dashes_total - dashes_left;
+    let dashes = inner_width.saturating_sub(label.width());
     let top = format!(
         "┌{}{}{}┐",
-        "─".repeat(dashes_left),
-        lang_label,
-        "─".repeat(dashes_right)
+        "─".repeat(dashes / 2),
+        label,
+        "─".repeat(dashes - dashes / 2)
     );
-    result.push(Line::from(Span::styled(
-        top,
-        Style::default().fg(Color::DarkGray),
-    )));
-
-    // Code lines
-    for line in code_lines {
-        let padded = format!(
-            "│ {:<width$} │",
-            line,
-            width = inner_width.saturating_sub(2)
-        );
-        result.push(Line::from(Span::styled(
-            padded,
-            Style::default().fg(Color::Green),
-        )));
+
+    let mut result = vec![vec![Span::styled(top, dim())]];
+    for line in body {
+        for part in split_width(&line, content_width) {
+            let mut spans = vec![Span::styled("│ ", dim())];
+            spans.extend(pad(part, content_width, Alignment::Left));
+            spans.push(Span::styled(" │", dim()));
+            result.push(spans);
+        }
     }
+    result.push(vec![Span::styled(
+        format!("└{}┘", "─".repeat(inner_width)),
+        dim(),
+    )]);
+    result
+}
 
-    // Bottom border: └──────┘
-    let bottom = format!("└{}┘", "─".repeat(inner_width));
-    result.push(Line::from(Span::styled(
-        bottom,
-        Style::default().fg(Color::DarkGray),
-    )));
+/// Shrink the widest columns until the table fits `available` columns.
+fn fit_columns(widths: &mut [usize], available: usize) {
+    const MIN_COLUMN: usize = 3;
+    while widths.iter().sum::<usize>() > available {
+        let Some(widest) = widths
+            .iter_mut()
+            .filter(|w| **w > MIN_COLUMN)
+            .max_by_key(|w| **w)
+        else {
+            break;
+        };
+        *widest -= 1;
+    }
+}
 
-    result
+/// Pad a line of spans to `width` columns.
+fn pad(mut spans: Vec<Span<'static>>, width: usize, alignment: Alignment) -> Vec<Span<'static>> {
+    let fill = width.saturating_sub(spans_width(&spans));
+    let (left, right) = match alignment {
+        Alignment::Right => (fill, 0),
+        Alignment::Center => (fill / 2, fill - fill / 2),
+        _ => (0, fill),
+    };
+    if left > 0 {
+        spans.insert(0, Span::raw(" ".repeat(left)));
+    }
+    if right > 0 {
+        spans.push(Span::raw(" ".repeat(right)));
+    }
+    spans
+}
+
+fn spans_width(spans: &[Span]) -> usize {
+    spans.iter().map(|s| s.content.width()).sum()
+}
+
+fn spans_text(spans: &[Span]) -> String {
+    spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+/// Append `text` to a line, merging it into the last span when the style
+/// matches.
+fn push_text(line: &mut Vec<Span<'static>>, text: &str, style: Style) {
+    match line.last_mut() {
+        Some(last) if last.style == style => last.content.to_mut().push_str(text),
+        _ => line.push(Span::styled(text.to_string(), style)),
+    }
+}
+
+/// Whitespace between words and its style.
+type Space = (String, Style);
+
+/// Greedy word wrap of styled spans into lines of at most `width` columns.
+/// Lines only break at whitespace, so a word made of several styles (like
+/// `**Tags**:`) stays together; words wider than a line are broken.
+fn wrap_spans(spans: &[Span<'static>], width: usize) -> Vec<Vec<Span<'static>>> {
+    let width = width.max(1);
+
+    // Words as runs of styled pieces, each with the whitespace before it
+    let mut words: Vec<(Option<Space>, Vec<Span<'static>>)> = Vec::new();
+    let mut space: Option<Space> = None;
+    let mut in_word = false;
+    for span in spans {
+        for (text, is_space) in split_words(&span.content) {
+            if is_space {
+                space = Some((text.to_string(), span.style));
+                in_word = false;
+            } else if in_word {
+                if let Some((_, pieces)) = words.last_mut() {
+                    push_text(pieces, text, span.style);
+                }
+            } else {
+                words.push((
+                    space.take(),
+                    vec![Span::styled(text.to_string(), span.style)],
+                ));
+                in_word = true;
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut line: Vec<Span<'static>> = Vec::new();
+    let mut line_width = 0;
+    for (space, word) in words {
+        let word_width = spans_width(&word);
+        let space_width = space.as_ref().map_or(0, |(s, _)| s.width());
+        if line_width > 0 && line_width + space_width + word_width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if line_width > 0 {
+            if let Some((text, style)) = space {
+                push_text(&mut line, &text, style);
+                line_width += space_width;
+            }
+        }
+
+        if line_width + word_width <= width {
+            for piece in word {
+                push_text(&mut line, &piece.content, piece.style);
+            }
+            line_width += word_width;
+            continue;
+        }
+        for piece in word {
+            for c in piece.content.chars() {
+                let char_width = c.width().unwrap_or(0);
+                if line_width + char_width > width && line_width > 0 {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0;
+                }
+                push_text(&mut line, c.encode_utf8(&mut [0; 4]), piece.style);
+                line_width += char_width;
+            }
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Break a line of spans every `width` columns, keeping whitespace.
+fn split_width(spans: &[Span<'static>], width: usize) -> Vec<Vec<Span<'static>>> {
+    let width = width.max(1);
+    let mut lines = vec![Vec::new()];
+    let mut line_width = 0;
+    for span in spans {
+        for c in span.content.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if line_width + char_width > width && line_width > 0 {
+                lines.push(Vec::new());
+                line_width = 0;
+            }
+            if let Some(line) = lines.last_mut() {
+                push_text(line, c.encode_utf8(&mut [0; 4]), span.style);
+            }
+            line_width += char_width;
+        }
+    }
+    lines
+}
+
+/// Split text into alternating runs of whitespace and non-whitespace.
+fn split_words(text: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let is_space = first.is_whitespace();
+        let end = rest
+            .find(|c: char| c.is_whitespace() != is_space)
+            .unwrap_or(rest.len());
+        let (word, tail) = rest.split_at(end);
+        rest = tail;
+        Some((word, is_space))
+    })
 }
 
 #[cfg(test)]
@@ -462,10 +918,10 @@ mod tests {
 
     #[test]
     fn test_table_separator_row() {
-        let md = "| --- | --- |";
+        let md = "| A | B |\n| --- | --- |\n| 1 | 2 |";
         let lines = render_markdown(md, 40);
-        let text = spans_text(&lines[0].spans);
-        assert!(text.chars().all(|c| c == '─'));
+        let text = spans_text(&lines[1].spans);
+        assert!(text.trim_start().chars().all(|c| c == '─' || c == '┼'));
     }
 
     #[test]
@@ -514,4 +970,62 @@ mod tests {
         let text = spans_text(&spans);
         assert_eq!(text, "Hello bold and code end");
     }
+
+    #[test]
+    fn test_nested_list_wraps_with_hanging_indent() {
+        let md = "- parent item\n  - child item that is long enough to wrap";
+        let lines = render_markdown(md, 24);
+        let text: Vec<String> = lines.iter().map(|l| spans_text(&l.spans)).collect();
+        assert_eq!(
+            text,
+            vec![
+                " • parent item",
+                "    • child item that",
+                "      is long enough",
+                "      to wrap",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_columns_are_aligned() {
+        let md = "| Name | Count |\n| :-- | --: |\n| apples | 3 |\n| kiwi | 12 |";
+        let lines = render_markdown(md, 40);
+        let text: Vec<String> = lines.iter().map(|l| spans_text(&l.spans)).collect();
+        assert_eq!(
+            text,
+            vec![
+                " Name   │ Count",
+                " ───────┼──────",
+                " apples │     3",
+                " kiwi   │    12",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_links_are_listed_as_footnotes() {
+        let md = "See [docs](https://a.dev) and [again](https://a.dev).";
+        let text = lines_text(&render_markdown(md, 80));
+        assert!(text.contains("See docs[1] and again[1]."));
+        assert!(text.ends_with(" Links\n [1] https://a.dev"));
+    }
+
+    #[test]
+    fn test_mermaid_flowchart_renders_as_tree() {
+        let md = "```mermaid\ngraph TD\n  A --> B\n```";
+        let text = lines_text(&render_markdown(md, 40));
+        assert!(text.contains("mermaid flowchart"));
+        assert!(text.contains("└─▶ [B]"));
+    }
+
+    #[test]
+    fn test_heading_positions_follow_rendered_lines() {
+        let md = "Intro text that wraps over two lines\n\n## Design\n\n### Data";
+        let doc = render_document(md, 24);
+        assert_eq!(
+            doc.headings,
+            vec![(3, 2, "Design".to_string()), (5, 3, "Data".to_string())]
+        );
+    }
 }
@@ -0,0 +1,344 @@
+//! ASCII rendering of mermaid flowcharts for the detail pane.
+//!
+//! A terminal can't lay out a graph the way the web UI does, so flowcharts
+//! are drawn as a tree that follows the edges from each root node. Nodes
+//! reached a second time (joins and cycles) are shown once more with `↺`
+//! instead of being expanded again.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use std::collections::HashSet;
+
+struct Node {
+    id: String,
+    label: String,
+    /// Opening and closing bracket for the node shape
+    shape: (char, char),
+}
+
+struct Edge {
+    from: usize,
+    to: usize,
+    label: Option<String>,
+    directed: bool,
+}
+
+#[derive(Default)]
+struct Flowchart {
+    direction: String,
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+/// Render a mermaid source block, or `None` when it isn't a flowchart.
+pub fn render_flowchart(source: &str) -> Option<Vec<Line<'static>>> {
+    let chart = parse(source)?;
+    let mut lines = vec![Line::from(Span::styled(
+        format!("flowchart {}", chart.direction)
+            .trim_end()
+            .to_string(),
+        Style::default().fg(Color::DarkGray),
+    ))];
+
+    let mut has_incoming = vec![false; chart.nodes.len()];
+    for edge in &chart.edges {
+        if edge.from != edge.to {
+            has_incoming[edge.to] = true;
+        }
+    }
+
+    let mut visited = HashSet::new();
+    let roots = (0..chart.nodes.len()).filter(|n| !has_incoming[*n]);
+    // Nodes only reachable through a cycle get drawn from their first
+    // declaration once the real roots are done.
+    let leftovers: Vec<usize> = (0..chart.nodes.len()).collect();
+    for node in roots.chain(leftovers) {
+        if visited.contains(&node) {
+            continue;
+        }
+        visited.insert(node);
+        lines.push(Line::from(node_span(&chart.nodes[node])));
+        chart.walk(node, String::new(), &mut visited, &mut lines);
+    }
+
+    Some(lines)
+}
+
+impl Flowchart {
+    fn walk(
+        &self,
+        node: usize,
+        prefix: String,
+        visited: &mut HashSet<usize>,
+        lines: &mut Vec<Line<'static>>,
+    ) {
+        let edges: Vec<&Edge> = self.edges.iter().filter(|e| e.from == node).collect();
+        for (i, edge) in edges.iter().enumerate() {
+            let last = i + 1 == edges.len();
+            let branch = if last { "└─" } else { "├─" };
+            let arrow = match (&edge.label, edge.directed) {
+                (Some(label), true) => format!(" {} ─▶ ", label),
+                (Some(label), false) => format!(" {} ── ", label),
+                (None, true) => "▶ ".to_string(),
+                (None, false) => "─ ".to_string(),
+            };
+            let target = &self.nodes[edge.to];
+            let mut spans = vec![
+                Span::styled(
+                    format!("{}{}", prefix, branch),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(arrow, Style::default().fg(Color::DarkGray)),
+                node_span(target),
+            ];
+
+            let seen = !visited.insert(edge.to);
+            if seen {
+                spans.push(Span::styled(" ↺", Style::default().fg(Color::DarkGray)));
+            }
+            lines.push(Line::from(spans));
+
+            if !seen {
+                let child_prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
+                self.walk(edge.to, child_prefix, visited, lines);
+            }
+        }
+    }
+
+    fn node(&mut self, id: &str, label: Option<Shape>) -> usize {
+        let index = match self.nodes.iter().position(|n| n.id == id) {
+            Some(index) => index,
+            None => {
+                self.nodes.push(Node {
+                    id: id.to_string(),
+                    label: id.to_string(),
+                    shape: ('[', ']'),
+                });
+                self.nodes.len() - 1
+            }
+        };
+        if let Some((label, shape)) = label {
+            self.nodes[index].label = label;
+            self.nodes[index].shape = shape;
+        }
+        index
+    }
+}
+
+fn node_span(node: &Node) -> Span<'static> {
+    let style = match node.shape.0 {
+        '{' => Style::default().fg(Color::Yellow),
+        _ => Style::default().fg(Color::Cyan),
+    };
+    Span::styled(
+        format!("{}{}{}", node.shape.0, node.label, node.shape.1),
+        style.add_modifier(Modifier::BOLD),
+    )
+}
+
+fn parse(source: &str) -> Option<Flowchart> {
+    let mut statements = source
+        .lines()
+        .map(|l| l.split("%%").next().unwrap_or(""))
+        .flat_map(|l| l.split(';'))
+        .map(str::trim)
+        .filter(|l| !l.is_empty());
+
+    let header = statements.next()?;
+    let mut words = header.split_whitespace();
+    if !matches!(words.next(), Some("graph") | Some("flowchart")) {
+        return None;
+    }
+    let mut chart = Flowchart {
+        direction: words.next().unwrap_or("").to_string(),
+        ..Default::default()
+    };
+
+    const IGNORED: [&str; 7] = [
+        "subgraph ",
+        "classDef ",
+        "class ",
+        "style ",
+        "linkStyle ",
+        "click ",
+        "direction ",
+    ];
+    for statement in statements {
+        if statement == "end" || IGNORED.iter().any(|p| statement.starts_with(p)) {
+            continue;
+        }
+        parse_statement(statement, &mut chart);
+    }
+    Some(chart)
+}
+
+/// `A[Start] --> B{Valid?} -->|yes| C & D`
+fn parse_statement(statement: &str, chart: &mut Flowchart) {
+    let mut rest = statement;
+    let mut previous: Vec<usize> = Vec::new();
+    let mut pending: Option<(Option<String>, bool)> = None;
+
+    loop {
+        let mut group = Vec::new();
+        loop {
+            let Some((id, label, after)) = parse_node(rest) else {
+                return;
+            };
+            group.push(chart.node(id, label));
+            rest = after.trim_start();
+            match rest.strip_prefix('&') {
+                Some(after) => rest = after.trim_start(),
+                None => break,
+            }
+        }
+
+        if let Some((label, directed)) = pending.take() {
+            for &from in &previous {
+                for &to in &group {
+                    chart.edges.push(Edge {
+                        from,
+                        to,
+                        label: label.clone(),
+                        directed,
+                    });
+                }
+            }
+        }
+
+        match parse_link(rest) {
+            Some((label, directed, after)) => {
+                pending = Some((label, directed));
+                previous = group;
+                rest = after.trim_start();
+            }
+            None => return,
+        }
+    }
+}
+
+/// Label and brackets given to a node where it is declared.
+type Shape = (String, (char, char));
+
+/// Node id with an optional shape, e.g. `B{Is valid?}` or `db[(Store)]`.
+fn parse_node(text: &str) -> Option<(&str, Option<Shape>, &str)> {
+    let id_end = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+    if id_end == 0 {
+        return None;
+    }
+    let (id, rest) = text.split_at(id_end);
+
+    let open_len = rest
+        .find(|c: char| !"[({>/\\".contains(c))
+        .unwrap_or(rest.len());
+    if open_len == 0 {
+        return Some((id, None, rest));
+    }
+    let open = &rest[..open_len];
+    let close: String = open
+        .chars()
+        .rev()
+        .map(|c| match c {
+            '[' | '>' => ']',
+            '(' => ')',
+            '{' => '}',
+            other => other,
+        })
+        .collect();
+    let body = &rest[open_len..];
+    let end = body.find(close.as_str())?;
+    let label = body[..end].trim().trim_matches('"').to_string();
+    let shape = match open.chars().next() {
+        Some('(') => ('(', ')'),
+        Some('{') => ('{', '}'),
+        _ => ('[', ']'),
+    };
+    Some((id, Some((label, shape)), &body[end + close.len()..]))
+}
+
+/// Link between nodes: `-->`, `---`, `-.->`, `==>`, `-->|label|` or
+/// `-- label -->`. Returns the label, whether it has an arrowhead and the
+/// text after it.
+fn parse_link(text: &str) -> Option<(Option<String>, bool, &str)> {
+    let arrow_len = text
+        .find(|c: char| !"-=.<>".contains(c))
+        .unwrap_or(text.len());
+    let arrow = &text[..arrow_len];
+    if arrow.len() < 2 || !arrow.starts_with(['-', '=', '<']) {
+        return None;
+    }
+    let rest = &text[arrow_len..];
+
+    // `-- label -->`: the first run only opens the link
+    if matches!(arrow, "--" | "==" | "-.") {
+        let close = ["-->", "---", "==>", "===", ".->", "-.-"]
+            .iter()
+            .filter_map(|c| rest.find(c).map(|pos| (pos, *c)))
+            .min_by_key(|(pos, _)| *pos)?;
+        let label = rest[..close.0].trim().to_string();
+        let after = rest[close.0..].trim_start_matches(|c: char| "-=.<>".contains(c));
+        return Some((Some(label), close.1.ends_with('>'), after));
+    }
+
+    let directed = arrow.ends_with('>');
+    let rest = rest.trim_start();
+    if let Some(labelled) = rest.strip_prefix('|') {
+        let end = labelled.find('|')?;
+        let label = labelled[..end].trim().to_string();
+        return Some((Some(label), directed, &labelled[end + 1..]));
+    }
+    Some((None, directed, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn renders_flowchart_as_tree() {
+        let source = "flowchart TD\n  A[Start] --> B{Valid?}\n  B -->|yes| C(Save)\n  B -- no --> D[Show error]\n  C --> E[Done]\n  D --> E";
+        let lines = render_flowchart(source).unwrap();
+        assert_eq!(
+            text(&lines),
+            vec![
+                "flowchart TD",
+                "[Start]",
+                "└─▶ {Valid?}",
+                "    ├─ yes ─▶ (Save)",
+                "    │   └─▶ [Done]",
+                "    └─ no ─▶ [Show error]",
+                "        └─▶ [Done] ↺",
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_chains_groups_and_cycles() {
+        let source = "graph LR; A --> B & C --- D; D --> A";
+        assert_eq!(
+            text(&render_flowchart(source).unwrap()),
+            vec![
+                "flowchart LR",
+                "[A]",
+                "├─▶ [B]",
+                "│   └── [D]",
+                "│       └─▶ [A] ↺",
+                "└─▶ [C]",
+                "    └── [D] ↺",
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_other_diagrams() {
+        assert!(render_flowchart("sequenceDiagram\n  A->>B: hi").is_none());
+    }
+}
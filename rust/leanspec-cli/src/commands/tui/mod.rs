@@ -8,11 +8,14 @@ mod board;
 mod deps;
 mod detail;
 mod filter;
+mod graph;
 mod headless;
 mod help;
+mod highlight;
 mod keybindings;
 mod list;
 mod markdown;
+mod mermaid;
 mod project_switcher;
 mod projects;
 mod search;
@@ -270,6 +273,7 @@ fn draw_split_pane(frame: &mut Frame, area: Rect, app: &mut App) {
     let chunks = Layout::horizontal([left_constraint, Constraint::Min(1)]).split(area);
     app.layout_left = chunks[0];
     app.layout_right = chunks[1];
+    app.layout_detail();
 
     // Left pane: Board or List
     if !app.sidebar_collapsed {
@@ -291,6 +295,7 @@ fn draw_single_pane(frame: &mut Frame, area: Rect, app: &mut App) {
     app.last_frame_height = area.height;
     app.layout_left = area;
     app.layout_right = area;
+    app.layout_detail();
 
     match app.focus {
         app::FocusPane::Left => match app.primary_view {
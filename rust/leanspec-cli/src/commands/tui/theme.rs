@@ -140,6 +140,13 @@ pub fn favorite_style() -> Style {
     Style::default().fg(Color::Yellow)
 }
 
+/// Nodes and edges on the critical path in the dependency graph.
+pub fn critical_path_style() -> Style {
+    Style::default()
+        .fg(rgb(255, 120, 80, Color::LightRed))
+        .add_modifier(Modifier::BOLD)
+}
+
 pub fn error_style() -> Style {
     Style::default().fg(Color::Red)
 }
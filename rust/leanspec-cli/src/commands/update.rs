@@ -1,6 +1,7 @@
 //! Update command implementation
 
 use colored::Colorize;
+use leanspec_core::adapters::markdown::{approval_blocker, load_review, spec_version};
 use leanspec_core::{
     apply_checklist_toggles, apply_replacements, apply_section_updates, preserve_title_heading,
     rebuild_content, split_frontmatter, ChecklistToggle, CompletionVerifier, FrontmatterParser,
@@ -30,6 +31,7 @@ pub fn run(
     content_override: Option<String>,
     force: bool,
     expected_hash: Option<String>,
+    expected_version: Option<String>,
 ) -> Result<(), Box<dyn Error>> {
     if specs.is_empty() {
         return Err("At least one spec path is required".into());
@@ -53,6 +55,7 @@ pub fn run(
 
     let loader = SpecLoader::new(specs_dir);
     let parser = FrontmatterParser::new();
+    let review_config = super::project::project_config(specs_dir).review;
     let mut updated_count = 0;
     let mut errors = Vec::new();
 
@@ -97,6 +100,20 @@ pub fn run(
             }
         }
 
+        // Validate expected version (covers frontmatter as well as body)
+        if let Some(ref expected) = expected_version {
+            let current = spec_version(&spec_info);
+            if current.as_deref() != Some(expected.as_str()) {
+                errors.push(format!(
+                    "Version conflict for {} (expected {}, current {}). The spec has been modified since you last read it.",
+                    spec_info.path,
+                    expected,
+                    current.as_deref().unwrap_or("none")
+                ));
+                continue;
+            }
+        }
+
         // Build updates
         let mut updates: HashMap<String, serde_yaml::Value> = HashMap::new();
         let mut fields_updated = Vec::new();
@@ -229,6 +246,22 @@ pub fn run(
                 ));
                 continue;
             }
+            if current_status == SpecStatus::Planned && new_status == "in-progress" && !force {
+                let blocker = match load_review(&spec_info) {
+                    Ok(review) => approval_blocker(&review, &review_config),
+                    Err(e) => {
+                        errors.push(format!("Error loading review of {}: {}", spec_info.path, e));
+                        continue;
+                    }
+                };
+                if let Some(reason) = blocker {
+                    errors.push(format!(
+                        "Cannot start {} before its review is approved: {}. Use --force to override.",
+                        spec_info.path, reason
+                    ));
+                    continue;
+                }
+            }
             if new_status == "complete" && !force {
                 let verification = match CompletionVerifier::verify_content(&new_content) {
                     Ok(result) => result,
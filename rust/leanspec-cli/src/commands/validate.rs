@@ -1,30 +1,37 @@
 //! Validate command implementation
 
 use colored::Colorize;
+use leanspec_core::compute::{check_consistency, ConsistencyIssueKind};
 use leanspec_core::{
-    DependencyGraph, FrontmatterValidator, SpecLoader, StructureValidator, TokenCountValidator,
-    ValidationResult,
+    body_line_offset, DependencyGraph, FrontmatterValidator, ReportFormat, SpecLoader,
+    StructureValidator, TokenCountValidator, ValidationReport, ValidationResult,
 };
 use std::error::Error;
 
+/// Validate `specs` (every spec when empty).
 pub fn run(
     specs_dir: &str,
-    spec: Option<String>,
+    specs: Vec<String>,
     check_deps: bool,
     strict: bool,
     warnings_only: bool,
+    project: bool,
     output_format: &str,
 ) -> Result<(), Box<dyn Error>> {
     let loader = SpecLoader::new(specs_dir);
     let all_specs = loader.load_all()?;
 
-    let specs_to_validate = if let Some(spec_path) = spec {
-        let spec = loader
-            .load(&spec_path)?
-            .ok_or_else(|| format!("Spec not found: {}", spec_path))?;
-        vec![spec]
-    } else {
+    let specs_to_validate = if specs.is_empty() {
         all_specs.clone()
+    } else {
+        specs
+            .iter()
+            .map(|spec_path| {
+                loader
+                    .load(spec_path)?
+                    .ok_or_else(|| format!("Spec not found: {}", spec_path).into())
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?
     };
 
     let fm_validator = FrontmatterValidator::new();
@@ -44,6 +51,24 @@ pub fn run(
         }
     }
 
+    let mut consistency_issues: std::collections::HashMap<String, Vec<_>> =
+        std::collections::HashMap::new();
+    if project {
+        for issue in check_consistency(&all_specs) {
+            // Open children of a complete parent are already reported by the
+            // per-spec parent checks below.
+            if issue.kind == ConsistencyIssueKind::UmbrellaOpenChildren {
+                continue;
+            }
+            consistency_issues
+                .entry(issue.spec.clone())
+                .or_default()
+                .push(issue);
+        }
+    }
+
+    let report_format = output_format.parse::<ReportFormat>().ok();
+    let mut report = ValidationReport::new();
     let mut all_results: Vec<ValidationResult> = Vec::new();
     let mut error_count = 0;
     let mut warning_count = 0;
@@ -63,17 +88,35 @@ pub fn run(
             validate_dependencies(spec, &all_specs, &mut result);
         }
 
+        if let Some(issues) = consistency_issues.get(&spec.path) {
+            for issue in issues {
+                result.add(issue.to_validation_error());
+            }
+        }
+
         // Count issues
         error_count += result.errors().count();
         warning_count += result.warnings().count();
 
-        if result.has_errors() || result.has_warnings() {
+        if report_format.is_some() {
+            // Reports list every validated spec so passing ones show up as
+            // passing test cases, not just the failures.
+            let raw = std::fs::read_to_string(&spec.file_path).unwrap_or_default();
+            let line_offset = body_line_offset(&raw, &spec.content);
+            report.push(
+                result,
+                spec.file_path.to_string_lossy().to_string(),
+                line_offset,
+            );
+        } else if result.has_errors() || result.has_warnings() {
             all_results.push(result);
         }
     }
 
     // Output results
-    if output_format == "json" {
+    if let Some(format) = report_format {
+        println!("{}", report.render(format));
+    } else if output_format == "json" {
         print_json(&all_results)?;
     } else {
         print_text(&all_results, specs_to_validate.len());
@@ -1,8 +1,14 @@
 //! View command implementation
 
 use colored::Colorize;
+use leanspec_core::adapters::markdown::spec_version;
+use leanspec_core::git::{commits_for_spec, SpecCommit};
 use leanspec_core::SpecLoader;
 use std::error::Error;
+use std::path::Path;
+
+/// How many trailer-carrying commits to scan for linked commits.
+const COMMIT_SCAN_LIMIT: usize = 1000;
 
 pub fn run(
     specs_dir: &str,
@@ -25,6 +31,12 @@ pub fn run(
         .iter()
         .filter(|s| s.frontmatter.depends_on.contains(&spec_info.path))
         .collect();
+    let links = super::rel::frontmatter_links(specs_dir, &all_specs, &spec_info.path);
+    let commits = if raw {
+        Vec::new()
+    } else {
+        commits_for_spec(Path::new(specs_dir), &spec_info.path, COMMIT_SCAN_LIMIT)
+    };
 
     if output_format == "json" {
         #[derive(serde::Serialize)]
@@ -40,9 +52,20 @@ pub fn run(
             assignee: Option<String>,
             parent: Option<String>,
             children: Vec<String>,
+            links: Vec<LinkOutput>,
+            commits: Vec<SpecCommit>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            version: Option<String>,
             content: String,
         }
 
+        #[derive(serde::Serialize)]
+        struct LinkOutput {
+            #[serde(rename = "type")]
+            link_type: String,
+            target: String,
+        }
+
         let output = SpecOutput {
             path: spec_info.path.clone(),
             title: spec_info.title.clone(),
@@ -55,6 +78,15 @@ pub fn run(
             assignee: spec_info.frontmatter.assignee.clone(),
             parent: spec_info.frontmatter.parent.clone(),
             children: children.iter().map(|s| s.path.clone()).collect(),
+            links: links
+                .into_iter()
+                .map(|l| LinkOutput {
+                    link_type: l.key,
+                    target: l.target,
+                })
+                .collect(),
+            commits,
+            version: spec_version(&spec_info),
             content: spec_info.content.clone(),
         };
 
@@ -149,6 +181,28 @@ pub fn run(
         println!("{}: (none)", "Depends on".bold());
     }
 
+    for link in &links {
+        println!("{}: {}", link.label.bold(), link.target);
+    }
+
+    if !commits.is_empty() {
+        println!();
+        println!("{}", "Commits".bold());
+        for commit in &commits {
+            println!(
+                "  {} {} {}",
+                commit.short_sha.yellow(),
+                commit.subject,
+                format!(
+                    "({}, {})",
+                    commit.author,
+                    commit.date.get(..10).unwrap_or(&commit.date)
+                )
+                .dimmed()
+            );
+        }
+    }
+
     println!();
     println!("{}", "─".repeat(60).dimmed());
     println!();
@@ -0,0 +1,117 @@
+//! Workload command implementation
+//!
+//! Open specs per assignee with token-weighted size and due-date pressure,
+//! checked against the WIP limits in the project config.
+
+use chrono::Utc;
+use colored::Colorize;
+use leanspec_core::{AssigneeWorkload, SpecLoader, SpecStatus, WorkloadReport, WorkloadSpec};
+use std::error::Error;
+
+pub fn run(
+    specs_dir: &str,
+    assignee: Option<&str>,
+    output_format: &str,
+) -> Result<(), Box<dyn Error>> {
+    let loader = SpecLoader::new(specs_dir);
+    let specs = loader.load_all()?;
+    let config = super::project::project_config(specs_dir).workload;
+
+    let mut report = WorkloadReport::compute(&specs, &config, Utc::now().date_naive());
+    if let Some(name) = assignee {
+        report.assignees.retain(|a| a.assignee == name);
+        report.unassigned.clear();
+        if report.assignees.is_empty() {
+            return Err(format!("No open specs or WIP limit for assignee: {}", name).into());
+        }
+    }
+
+    if output_format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", "═".repeat(60).dimmed());
+    println!("{}", " WORKLOAD ".bold().cyan());
+    println!("{}", "═".repeat(60).dimmed());
+
+    if report.assignees.is_empty() && report.unassigned.is_empty() {
+        println!();
+        println!("  No planned or in-progress specs");
+    }
+    for workload in &report.assignees {
+        print_assignee(workload, config.due_soon_days);
+    }
+    if !report.unassigned.is_empty() {
+        println!();
+        println!("👤 {} ({})", "Unassigned".bold(), report.unassigned.len());
+        println!("{}", "─".repeat(40).dimmed());
+        for spec in &report.unassigned {
+            print_spec(spec, config.due_soon_days);
+        }
+    }
+    println!();
+    Ok(())
+}
+
+fn print_assignee(workload: &AssigneeWorkload, due_soon_days: i64) {
+    let in_progress = match workload.wip_limit {
+        Some(limit) => format!("{}/{}", workload.in_progress, limit),
+        None => workload.in_progress.to_string(),
+    };
+    let in_progress = if workload.over_capacity {
+        in_progress.red().bold()
+    } else {
+        in_progress.normal()
+    };
+
+    println!();
+    print!("👤 {}", workload.assignee.bold());
+    if workload.over_capacity {
+        print!("  {}", "over WIP limit".red());
+    }
+    println!();
+    println!("{}", "─".repeat(40).dimmed());
+    println!(
+        "  In progress: {}  Planned: {}  Tokens: {}",
+        in_progress, workload.planned, workload.tokens
+    );
+    if workload.overdue > 0 || workload.due_soon > 0 {
+        println!(
+            "  Overdue: {}  Due within {} days: {}",
+            if workload.overdue > 0 {
+                workload.overdue.to_string().red()
+            } else {
+                "0".normal()
+            },
+            due_soon_days,
+            workload.due_soon.to_string().yellow()
+        );
+    }
+    for spec in &workload.specs {
+        print_spec(spec, due_soon_days);
+    }
+}
+
+fn print_spec(spec: &WorkloadSpec, due_soon_days: i64) {
+    let emoji = if spec.status == SpecStatus::InProgress {
+        "⏳"
+    } else {
+        "📅"
+    };
+    let due = match (&spec.due, spec.days_until_due) {
+        (Some(date), Some(days)) if days < 0 => format!(" due {} (overdue)", date).red(),
+        (Some(date), _) if spec.is_due_within(due_soon_days) => format!(" due {}", date).yellow(),
+        (Some(date), _) => format!(" due {}", date).dimmed(),
+        (None, _) => "".normal(),
+    };
+    println!(
+        "  {} {} - {} {}{}",
+        emoji,
+        spec.path.cyan(),
+        spec.title.dimmed(),
+        format!("({} tokens)", spec.tokens).dimmed(),
+        due
+    );
+}
@@ -7,7 +7,10 @@ use clap::Parser;
 use colored::Colorize;
 use std::process::ExitCode;
 
-use crate::cli_args::{Cli, Commands, GitSubcommand};
+use crate::cli_args::{
+    Cli, Commands, CommentSubcommand, ExportSubcommand, GitSubcommand, HooksSubcommand,
+    ReviewSubcommand,
+};
 
 fn main() -> ExitCode {
     let cli = Cli::parse();
@@ -49,6 +52,30 @@ fn main() -> ExitCode {
         }
         Commands::Check { fix } => commands::check::run(&specs_dir, fix, &cli.output),
         Commands::Children { spec } => commands::children::run(&specs_dir, &spec, &cli.output),
+        Commands::Comment { action } => {
+            use commands::comment::CommentCommand as Cmd;
+            let cmd = match action {
+                CommentSubcommand::List { spec } => Cmd::List { spec },
+                CommentSubcommand::Add {
+                    spec,
+                    body,
+                    reply_to,
+                    author,
+                } => Cmd::Add {
+                    spec,
+                    body,
+                    reply_to,
+                    author,
+                },
+                CommentSubcommand::Edit { spec, id, body } => Cmd::Edit { spec, id, body },
+                CommentSubcommand::Resolve { spec, id, reopen } => Cmd::Resolve {
+                    spec,
+                    id,
+                    resolved: !reopen,
+                },
+            };
+            commands::comment::run(cli.specs_dir.as_deref(), cmd, &cli.output)
+        }
         Commands::Compact {
             spec,
             removes,
@@ -87,6 +114,7 @@ fn main() -> ExitCode {
             parent,
             child,
             depends_on,
+            link,
         } => commands::rel::run(
             &specs_dir,
             commands::rel::RelArgs {
@@ -94,16 +122,43 @@ fn main() -> ExitCode {
                 parent,
                 children: child,
                 depends_on,
+                links: link,
             },
             &cli.output,
         ),
         Commands::Examples => commands::examples::run(&cli.output),
+        Commands::Export {
+            action,
+            format,
+            out,
+        } => match action {
+            Some(ExportSubcommand::Site { out }) => {
+                commands::export::site::run(cli.specs_dir.as_deref(), &out, &cli.output)
+            }
+            None => commands::export::archive::run(
+                cli.specs_dir.as_deref(),
+                &format,
+                out.as_deref(),
+                &cli.output,
+            ),
+        },
         Commands::Deps {
             spec,
             depth,
             upstream,
             downstream,
-        } => commands::deps::run(&specs_dir, &spec, depth, upstream, downstream, &cli.output),
+        } => commands::deps::run(
+            cli.specs_dir.as_deref(),
+            &spec,
+            depth,
+            upstream,
+            downstream,
+            &cli.output,
+        ),
+        Commands::Duplicates {
+            threshold,
+            include_archived,
+        } => commands::duplicates::run(&specs_dir, threshold, include_archived, &cli.output),
         Commands::Files { spec, size } => {
             commands::files::run(&specs_dir, &spec, size, &cli.output)
         }
@@ -115,7 +170,36 @@ fn main() -> ExitCode {
             };
             commands::git_repo::run(cmd, &cli.output)
         }
-        Commands::Gantt { status } => commands::gantt::run(&specs_dir, status, &cli.output),
+        Commands::Gantt { status } => {
+            commands::gantt::run(cli.specs_dir.as_deref(), status, &cli.output)
+        }
+        Commands::Hooks { action } => {
+            use commands::hooks::HooksCommand as Cmd;
+            let cmd = match action {
+                HooksSubcommand::Install { force } => Cmd::Install { force },
+                HooksSubcommand::Uninstall => Cmd::Uninstall,
+                HooksSubcommand::PreCommit => Cmd::PreCommit,
+                HooksSubcommand::CommitMsg { file } => Cmd::CommitMsg { file },
+            };
+            commands::hooks::run(&specs_dir, cli.specs_dir.as_deref(), cmd)
+        }
+        Commands::Impact {
+            spec,
+            since,
+            depth,
+            mark,
+        } => commands::impact::run(&specs_dir, &spec, &since, depth, mark, &cli.output),
+        Commands::Import {
+            file,
+            mapping,
+            dry_run,
+        } => commands::import::run(
+            cli.specs_dir.as_deref(),
+            &file,
+            mapping.as_deref(),
+            dry_run,
+            &cli.output,
+        ),
         Commands::Init {
             yes,
             example,
@@ -174,7 +258,49 @@ fn main() -> ExitCode {
             backfill,
             &cli.output,
         ),
+        Commands::Mv {
+            spec,
+            new_name,
+            number,
+            dry_run,
+        } => commands::mv::run(
+            &specs_dir,
+            &spec,
+            new_name.as_deref(),
+            number,
+            dry_run,
+            &cli.output,
+        ),
         Commands::Open { spec, editor } => commands::open::run(&specs_dir, &spec, editor),
+        Commands::Review { action } => {
+            use commands::review::ReviewCommand as Cmd;
+            use leanspec_core::adapters::markdown::ReviewDecision;
+            let cmd = match action {
+                ReviewSubcommand::Status { spec } => Cmd::Status { spec },
+                ReviewSubcommand::Request { spec, reviewers } => Cmd::Request { spec, reviewers },
+                ReviewSubcommand::Approve {
+                    spec,
+                    reviewer,
+                    message,
+                } => Cmd::Decide {
+                    spec,
+                    decision: ReviewDecision::Approved,
+                    reviewer,
+                    comment: message,
+                },
+                ReviewSubcommand::RequestChanges {
+                    spec,
+                    reviewer,
+                    message,
+                } => Cmd::Decide {
+                    spec,
+                    decision: ReviewDecision::ChangesRequested,
+                    reviewer,
+                    comment: message,
+                },
+            };
+            commands::review::run(&specs_dir, cmd, &cli.output)
+        }
         Commands::Search { query, limit } => {
             commands::search::run(&specs_dir, &query, limit, &cli.output)
         }
@@ -236,6 +362,7 @@ fn main() -> ExitCode {
             content,
             force,
             expected_hash,
+            expected_version,
         } => commands::update::run(
             &specs_dir,
             &specs,
@@ -256,21 +383,27 @@ fn main() -> ExitCode {
             content,
             force,
             expected_hash,
+            expected_version,
         ),
         Commands::Validate {
             spec,
             check_deps,
             strict,
             warnings_only,
+            project,
         } => commands::validate::run(
             &specs_dir,
-            spec,
+            spec.into_iter().collect(),
             check_deps,
             strict,
             warnings_only,
+            project,
             &cli.output,
         ),
         Commands::View { spec, raw } => commands::view::run(&specs_dir, &spec, raw, &cli.output),
+        Commands::Workload { assignee } => {
+            commands::workload::run(&specs_dir, assignee.as_deref(), &cli.output)
+        }
     };
 
     match result {
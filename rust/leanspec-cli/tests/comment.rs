@@ -0,0 +1,65 @@
+//! E2E Tests: comment command
+//!
+//! Tests comment threads stored next to markdown specs
+
+mod common;
+use common::*;
+
+#[test]
+fn test_comment_thread_lifecycle() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+    create_spec(cwd, "database");
+
+    let added = exec_cli(
+        &[
+            "comment",
+            "add",
+            "001",
+            "Why not SQLite?",
+            "--author",
+            "alice",
+            "-o",
+            "json",
+        ],
+        cwd,
+    );
+    assert!(added.success, "{}\n{}", added.stdout, added.stderr);
+    let first: serde_json::Value = serde_json::from_str(&added.stdout).unwrap();
+    assert_eq!(first["id"], "1");
+    assert_eq!(first["author"], "alice");
+
+    let reply = exec_cli(
+        &[
+            "comment",
+            "add",
+            "001",
+            "It is SQLite.",
+            "--reply-to",
+            "1",
+            "--author",
+            "bob",
+        ],
+        cwd,
+    );
+    assert!(reply.success, "{}", reply.stderr);
+    assert!(exec_cli(&["comment", "resolve", "001", "1"], cwd).success);
+    assert!(exec_cli(&["comment", "edit", "001", "2", "It is *SQLite*."], cwd).success);
+
+    let listed = exec_cli(&["comment", "list", "001-database", "-o", "json"], cwd);
+    assert!(listed.success, "{}", listed.stderr);
+    let comments: serde_json::Value = serde_json::from_str(&listed.stdout).unwrap();
+    assert_eq!(comments[0]["resolved"], true);
+    assert_eq!(comments[1]["in_reply_to"], "1");
+    assert_eq!(comments[1]["body"], "It is *SQLite*.");
+
+    let text = exec_cli(&["comment", "list", "001"], cwd);
+    assert!(text.stdout.contains("[resolved]"), "{}", text.stdout);
+    assert!(file_exists(
+        &cwd.join("specs").join("001-database").join("comments.yaml")
+    ));
+
+    let missing = exec_cli(&["comment", "resolve", "001", "9"], cwd);
+    assert!(!missing.success);
+}
@@ -0,0 +1,71 @@
+//! E2E Tests: deps and gantt commands
+//!
+//! Tests dependency traversal and execution order over `depends_on` links
+
+mod common;
+use common::*;
+
+fn setup_chain(cwd: &std::path::Path) {
+    init_project(cwd, true);
+    for name in ["database", "api", "frontend"] {
+        create_spec(cwd, name);
+    }
+    assert!(link_specs(cwd, "002-api", "001-database").success);
+    assert!(link_specs(cwd, "003-frontend", "002-api").success);
+    for spec in ["001", "002", "003"] {
+        update_spec(cwd, spec, &[("status", "planned")]);
+    }
+}
+
+fn ids(value: &serde_json::Value) -> Vec<&str> {
+    value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["path"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_deps_walks_upstream_and_downstream() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup_chain(cwd);
+
+    let result = exec_cli(&["deps", "002", "-o", "json"], cwd);
+    assert!(result.success, "{}", result.stderr);
+    let json: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    assert_eq!(json["spec"]["path"], "002-api");
+    assert_eq!(json["spec"]["status"], "planned");
+    assert_eq!(ids(&json["upstream"]), ["001-database"]);
+    assert_eq!(ids(&json["downstream"]), ["003-frontend"]);
+
+    let result = exec_cli(
+        &["deps", "001", "--downstream", "--depth", "1", "-o", "json"],
+        cwd,
+    );
+    let json: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    assert_eq!(ids(&json["downstream"]), ["002-api"]);
+    assert!(json["upstream"].as_array().unwrap().is_empty());
+
+    assert!(!exec_cli(&["deps", "999"], cwd).success);
+}
+
+#[test]
+fn test_gantt_orders_dependencies_first() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup_chain(cwd);
+
+    let result = exec_cli(&["gantt", "-o", "json"], cwd);
+    assert!(result.success, "{}", result.stderr);
+    let json: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    assert_eq!(
+        json["execution_order"],
+        serde_json::json!(["001-database", "002-api", "003-frontend"])
+    );
+    assert_eq!(
+        json["specs"][2]["blocked_by"],
+        serde_json::json!(["002-api"])
+    );
+}
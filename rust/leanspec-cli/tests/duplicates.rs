@@ -0,0 +1,110 @@
+//! E2E Tests: duplicate detection
+//!
+//! Tests similarity detection:
+//! - `duplicates` lists clusters with scores
+//! - `create` warns when a new spec resembles an existing one
+//! - Unrelated specs are not flagged
+
+mod common;
+use common::*;
+
+const AUTH_BODY: &str =
+    "## Overview\n\nUsers login with OAuth providers and refresh session tokens automatically.";
+
+#[test]
+fn test_duplicates_lists_clusters() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec_with_options(
+        cwd,
+        "oauth-login",
+        &[("title", "OAuth Login Sessions"), ("content", AUTH_BODY)],
+    );
+    create_spec_with_options(
+        cwd,
+        "oauth-sessions",
+        &[
+            ("title", "OAuth Login Session Refresh"),
+            ("content", AUTH_BODY),
+        ],
+    );
+    create_spec_with_options(cwd, "billing", &[("title", "Monthly Invoice Billing")]);
+
+    let result = exec_cli(&["duplicates", "-o", "json"], cwd);
+    assert!(result.success, "stderr: {}", result.stderr);
+
+    let json: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    let clusters = json["clusters"].as_array().unwrap();
+    assert_eq!(clusters.len(), 1);
+    let members = clusters[0]["specs"].as_array().unwrap();
+    assert_eq!(members.len(), 2);
+    assert!(members
+        .iter()
+        .all(|m| m.as_str().unwrap().contains("oauth")));
+    assert!(clusters[0]["score"].as_f64().unwrap() >= 0.5);
+}
+
+#[test]
+fn test_duplicates_none_found() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec_with_options(cwd, "billing", &[("title", "Monthly Invoice Billing")]);
+    create_spec_with_options(cwd, "dark-mode", &[("title", "Dark Mode Theme")]);
+
+    let result = exec_cli(&["duplicates"], cwd);
+    assert!(result.success);
+    assert!(result.stdout.contains("No duplicate specs found"));
+}
+
+#[test]
+fn test_duplicates_rejects_invalid_threshold() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+
+    let result = exec_cli(&["duplicates", "--threshold", "1.5"], cwd);
+    assert!(!result.success);
+}
+
+#[test]
+fn test_create_warns_about_similar_spec() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec_with_options(
+        cwd,
+        "oauth-login",
+        &[("title", "OAuth Login Sessions"), ("content", AUTH_BODY)],
+    );
+
+    let result = create_spec_with_options(
+        cwd,
+        "oauth-sessions",
+        &[
+            ("title", "OAuth Login Session Refresh"),
+            ("content", AUTH_BODY),
+        ],
+    );
+    assert!(result.success);
+    assert!(result.stdout.contains("looks similar to existing specs"));
+    assert!(result.stdout.contains("001-oauth-login"));
+}
+
+#[test]
+fn test_create_without_similar_specs_has_no_warning() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec_with_options(cwd, "billing", &[("title", "Monthly Invoice Billing")]);
+
+    let result = create_spec_with_options(cwd, "dark-mode", &[("title", "Dark Mode Theme")]);
+    assert!(result.success);
+    assert!(!result.stdout.contains("looks similar"));
+}
@@ -0,0 +1,173 @@
+//! E2E Tests: export command
+//!
+//! Tests static site export
+
+mod common;
+use common::*;
+
+#[test]
+fn test_export_site_renders_pages() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+
+    create_spec_with_options(cwd, "database", &[("tags", "backend,storage")]);
+    create_spec_with_options(cwd, "api", &[("tags", "backend")]);
+    assert!(link_specs(cwd, "002-api", "001-database").success);
+
+    let readme = cwd.join("specs").join("002-api").join("README.md");
+    let content = read_file(&readme);
+    write_file(
+        &readme,
+        &format!(
+            "{}\n## Notes\n\nSee [the schema](../001-database/README.md) and `<b>`.\n\n\
+             <!-- hidden -->\n<script>alert(1)</script>\n\n\
+             [Click](javascript:alert(1)) ![pic](JaVaScRiPt:alert(2))\n",
+            content
+        ),
+    );
+
+    let result = exec_cli(&["export", "site", "--out", "site", "-o", "json"], cwd);
+    assert!(result.success, "{}\n{}", result.stdout, result.stderr);
+    let report: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    assert_eq!(report["specs"], 2);
+    assert_eq!(report["tags"], 2);
+
+    let site = cwd.join("site");
+    let index = read_file(&site.join("index.html"));
+    assert!(index.contains("href=\"specs/001-database.html\""));
+    assert!(index.contains("assets/search-index.js"));
+    assert!(read_file(&site.join("board.html")).contains("Planned"));
+
+    let api = read_file(&site.join("specs").join("002-api.html"));
+    assert!(api.contains("Depends on"), "{}", api);
+    assert!(api.contains("href=\"../specs/001-database.html\""));
+    assert!(api.contains("<a href=\"001-database.html\">the schema</a>"));
+    assert!(api.contains("<code>&lt;b&gt;</code>"));
+    // Raw HTML is shown as text and unsafe URLs are dropped
+    assert!(
+        api.contains("&lt;script&gt;alert(1)&lt;/script&gt;"),
+        "{}",
+        api
+    );
+    assert!(!api.contains("<script>alert") && !api.contains("hidden"));
+    assert!(!api.to_lowercase().contains("javascript:"), "{}", api);
+    assert!(api.contains("<a>Click</a>"));
+    assert!(api.contains("href=\"../tags/backend.html\""));
+
+    let database = read_file(&site.join("specs").join("001-database.html"));
+    assert!(database.contains("Blocked by"), "{}", database);
+
+    let tag = read_file(&site.join("tags").join("storage.html"));
+    assert!(tag.contains("001-database") && !tag.contains("002-api"));
+
+    let search = read_file(&site.join("assets").join("search-index.js"));
+    assert!(search.starts_with("window.LEANSPEC_SEARCH_INDEX = ["));
+    assert!(search.contains("\"url\":\"specs/002-api.html\""));
+}
+
+#[test]
+fn test_export_site_keeps_tags_with_colliding_slugs_apart() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+    create_spec_with_options(cwd, "upper", &[("tags", "API")]);
+    create_spec_with_options(cwd, "lower", &[("tags", "api")]);
+
+    let result = exec_cli(&["export", "site", "--out", "site", "-o", "json"], cwd);
+    assert!(result.success, "{}\n{}", result.stdout, result.stderr);
+
+    let site = cwd.join("site");
+    let tags = read_file(&site.join("tags").join("index.html"));
+    assert!(tags.contains("href=\"api.html\">API<"), "{}", tags);
+    assert!(tags.contains("href=\"api-2.html\">api<"), "{}", tags);
+    let upper = read_file(&site.join("tags").join("api.html"));
+    let lower = read_file(&site.join("tags").join("api-2.html"));
+    assert!(upper.contains("001-upper") && !upper.contains("002-lower"));
+    assert!(lower.contains("002-lower") && !lower.contains("001-upper"));
+    let spec = read_file(&site.join("specs").join("002-lower.html"));
+    assert!(spec.contains("href=\"../tags/api-2.html\""), "{}", spec);
+}
+
+#[test]
+fn test_export_import_round_trip() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+    create_spec(cwd, "database");
+    create_spec(cwd, "api");
+    assert!(link_specs(cwd, "002-api", "001-database").success);
+
+    let result = exec_cli(&["export", "--out", "project.ndjson"], cwd);
+    assert!(result.success, "{}\n{}", result.stdout, result.stderr);
+    let archive = read_file(&cwd.join("project.ndjson"));
+    assert_eq!(archive.lines().count(), 3);
+    assert!(archive.starts_with("{\"type\":\"header\""));
+
+    let target = cwd.join("target");
+    std::fs::create_dir_all(&target).unwrap();
+    init_project(&target, true);
+    create_spec(&target, "existing");
+
+    let archive_path = cwd.join("project.ndjson");
+    let result = exec_cli(
+        &["import", archive_path.to_str().unwrap(), "-o", "json"],
+        &target,
+    );
+    assert!(result.success, "{}\n{}", result.stdout, result.stderr);
+    let report: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    assert_eq!(report["ids"][1]["from"], "002-api");
+    assert_eq!(report["ids"][1]["to"], "003-api");
+
+    let api = read_file(&target.join("specs").join("003-api").join("README.md"));
+    assert_eq!(
+        parse_frontmatter(&api)["depends_on"][0].as_str(),
+        Some("002-database")
+    );
+    assert_eq!(api.matches("# Api").count(), 1, "{}", api);
+}
+
+#[test]
+fn test_import_maps_values_from_other_adapters() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+
+    let archive = r###"{"type":"header","version":1,"adapter":"github","exported_at":"2026-01-01T00:00:00Z","schema":{"id":"leanspec:github","name":"GitHub Issue","fields":[{"key":"status","label":"Status","kind":{"kind":"enum","options":[{"value":"open","label":"Open"},{"value":"closed","label":"Closed"}]},"semantic":"status"},{"key":"content","label":"Body","kind":{"kind":"long_text"},"display":"section"}],"link_types":[{"key":"depends_on","label":"Depends on","inverse_key":"blocked_by","inverse_label":"Blocked by"}]}}
+{"type":"spec","id":"12","title":"Login","schema_id":"leanspec:github","fields":{"status":{"kind":"string","value":"closed"},"content":{"kind":"string","value":"## Overview\n\nSign in."}},"links":[]}
+{"type":"spec","id":"15","title":"Two factor","schema_id":"leanspec:github","fields":{"status":{"kind":"string","value":"open"}},"links":[{"link_type":"depends_on","target_id":"12"},{"link_type":"depends_on","target_id":"99"}]}
+"###;
+    write_file(&cwd.join("issues.ndjson"), archive);
+
+    let result = exec_cli(&["import", "issues.ndjson"], cwd);
+    assert!(!result.success);
+    assert!(
+        result.stderr.contains("status: closed, open"),
+        "{}",
+        result.stderr
+    );
+    assert!(!cwd.join("specs").join("001-login").exists());
+
+    write_file(
+        &cwd.join("mapping.yaml"),
+        "status:\n  open: planned\n  closed: complete\n",
+    );
+    let result = exec_cli(
+        &["import", "issues.ndjson", "--mapping", "mapping.yaml"],
+        cwd,
+    );
+    assert!(result.success, "{}\n{}", result.stdout, result.stderr);
+    assert!(result.stdout.contains("outside the archive"));
+
+    let login = read_file(&cwd.join("specs").join("001-login").join("README.md"));
+    assert_eq!(
+        parse_frontmatter(&login)["status"].as_str(),
+        Some("complete")
+    );
+    assert!(login.contains("Sign in."));
+
+    let two_factor = read_file(&cwd.join("specs").join("002-two-factor").join("README.md"));
+    let frontmatter = parse_frontmatter(&two_factor);
+    assert_eq!(frontmatter["status"].as_str(), Some("planned"));
+    assert_eq!(frontmatter["depends_on"][0].as_str(), Some("001-login"));
+}
@@ -0,0 +1,222 @@
+//! E2E Tests: git hooks
+//!
+//! Tests hook integration:
+//! - `hooks install` / `hooks uninstall`
+//! - pre-commit validates only staged specs, as staged
+//! - commit-msg checks `spec:` trailers
+//! - `view` lists commits linked via trailers
+
+mod common;
+use common::*;
+use std::path::Path;
+use std::process::Command;
+
+fn git_commit(cwd: &Path, message: &str) -> std::process::Output {
+    Command::new("git")
+        .args(["add", "."])
+        .current_dir(cwd)
+        .status()
+        .expect("git add failed");
+    Command::new("git")
+        .args(["commit", "-m", message])
+        .current_dir(cwd)
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("Failed to execute git commit")
+}
+
+fn setup_repo(cwd: &Path) {
+    init_project(cwd, true);
+    create_spec_with_options(cwd, "auth", &[("title", "User Authentication")]);
+    init_git_repo(cwd);
+}
+
+#[test]
+fn test_hooks_install_and_uninstall() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup_repo(cwd);
+
+    let result = exec_cli(&["hooks", "install"], cwd);
+    assert!(result.success, "stderr: {}", result.stderr);
+
+    let pre_commit = cwd.join(".git/hooks/pre-commit");
+    let commit_msg = cwd.join(".git/hooks/commit-msg");
+    assert!(read_file(&pre_commit).contains("hooks pre-commit"));
+    assert!(read_file(&commit_msg).contains("hooks commit-msg \"$1\""));
+
+    // Re-installing over managed hooks is fine
+    assert!(exec_cli(&["hooks", "install"], cwd).success);
+
+    let result = exec_cli(&["hooks", "uninstall"], cwd);
+    assert!(result.success);
+    assert!(!file_exists(&pre_commit));
+    assert!(!file_exists(&commit_msg));
+}
+
+#[test]
+fn test_hooks_install_keeps_foreign_hooks_without_force() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup_repo(cwd);
+
+    let pre_commit = cwd.join(".git/hooks/pre-commit");
+    write_file(&pre_commit, "#!/bin/sh\necho custom\n");
+
+    let result = exec_cli(&["hooks", "install"], cwd);
+    assert!(!result.success);
+    assert!(result.stderr.contains("--force"));
+    assert_eq!(read_file(&pre_commit), "#!/bin/sh\necho custom\n");
+
+    let result = exec_cli(&["hooks", "install", "--force"], cwd);
+    assert!(result.success);
+    assert!(read_file(&pre_commit).contains("hooks pre-commit"));
+}
+
+#[test]
+fn test_pre_commit_validates_only_staged_specs() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup_repo(cwd);
+    assert!(exec_cli(&["hooks", "install"], cwd).success);
+
+    // Break a spec's frontmatter and stage it: the commit is rejected
+    let readme = cwd.join("specs/001-auth/README.md");
+    let original = read_file(&readme);
+    write_file(
+        &readme,
+        &original.replace("status: planned", "status: bogus"),
+    );
+    let output = git_commit(cwd, "break spec");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("specs/001-auth/README.md"));
+
+    // Unstaged breakage elsewhere does not block unrelated commits
+    Command::new("git")
+        .args(["reset", "-q"])
+        .current_dir(cwd)
+        .status()
+        .unwrap();
+    write_file(&cwd.join("notes.txt"), "unrelated");
+    Command::new("git")
+        .args(["add", "notes.txt"])
+        .current_dir(cwd)
+        .status()
+        .unwrap();
+    let output = Command::new("git")
+        .args(["commit", "-m", "add notes"])
+        .current_dir(cwd)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Fixing the spec lets it through
+    write_file(
+        &readme,
+        &original.replace("## Overview", "## Overview\n\nFixed."),
+    );
+    let output = git_commit(cwd, "fix spec");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_pre_commit_validates_staged_content() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup_repo(cwd);
+    assert!(exec_cli(&["hooks", "install"], cwd).success);
+
+    let readme = cwd.join("specs/001-auth/README.md");
+    let original = read_file(&readme);
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(cwd)
+            .output()
+            .expect("git failed")
+    };
+
+    // Broken content staged, fixed only in the working tree: rejected
+    write_file(
+        &readme,
+        &original.replace("status: planned", "status: bogus"),
+    );
+    git(&["add", "specs"]);
+    write_file(&readme, &original);
+    let output = git(&["commit", "-m", "staged breakage"]);
+    assert!(!output.status.success());
+
+    // Valid content staged, broken only in the working tree: accepted
+    let fixed = original.replace("## Overview", "## Overview\n\nStaged.");
+    write_file(&readme, &fixed);
+    git(&["add", "specs"]);
+    write_file(&readme, &fixed.replace("status: planned", "status: bogus"));
+    let output = git(&["commit", "-m", "staged fix"]);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_commit_msg_checks_spec_trailers() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup_repo(cwd);
+    assert!(exec_cli(&["hooks", "install"], cwd).success);
+
+    write_file(&cwd.join("a.txt"), "a");
+    let output = git_commit(cwd, "feat: something\n\nspec: 999");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown spec"));
+
+    let output = git_commit(cwd, "feat: login form\n\nspec: 001");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // A `Spec:` subject is not a trailer
+    write_file(&cwd.join("b.txt"), "b");
+    let output = git_commit(cwd, "Spec: add auth flow\n\nDescribe the flow.");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_view_lists_linked_commits() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup_repo(cwd);
+
+    write_file(&cwd.join("login.rs"), "fn login() {}");
+    commit_all(cwd, "feat: login form\n\nspec: 001");
+    write_file(&cwd.join("other.rs"), "fn other() {}");
+    commit_all(cwd, "chore: unrelated");
+
+    let result = view_spec(cwd, "001");
+    assert!(result.success);
+    assert!(result.stdout.contains("Commits"));
+    assert!(result.stdout.contains("feat: login form"));
+    assert!(!result.stdout.contains("chore: unrelated"));
+
+    let result = exec_cli(&["view", "001", "-o", "json"], cwd);
+    let json: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    let commits = json["commits"].as_array().unwrap();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0]["subject"], "feat: login form");
+    assert_eq!(commits[0]["specs"][0], "001");
+}
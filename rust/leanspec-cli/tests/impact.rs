@@ -0,0 +1,53 @@
+//! E2E Tests: impact command
+//!
+//! Tests that acceptance criteria and design changes since a git revision
+//! flag the started specs depending on the changed spec
+
+mod common;
+use common::*;
+
+#[test]
+fn test_impact_flags_started_downstream_specs() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    for name in ["database", "api", "frontend"] {
+        create_spec(cwd, name);
+    }
+    assert!(link_specs(cwd, "002-api", "001-database").success);
+    assert!(link_specs(cwd, "003-frontend", "001-database").success);
+    update_spec(cwd, "002", &[("status", "in-progress")]);
+
+    let readme = cwd.join("specs").join("001-database").join("README.md");
+    write_file(
+        &readme,
+        &format!("{}\n## Design\n\nUse Postgres.\n", read_file(&readme)),
+    );
+    init_git_repo(cwd);
+
+    let result = exec_cli(&["impact", "001", "-o", "json"], cwd);
+    assert!(result.success, "{}", result.stderr);
+    let json: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    assert!(json["changed_sections"].as_array().unwrap().is_empty());
+
+    write_file(
+        &readme,
+        &read_file(&readme).replace("Use Postgres.", "Use SQLite."),
+    );
+    let result = exec_cli(&["impact", "001", "--mark", "-o", "json"], cwd);
+    assert!(result.success, "{}", result.stderr);
+    let json: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    assert_eq!(json["changed_sections"], serde_json::json!(["Design"]));
+    assert_eq!(json["affected"][0]["path"], "002-api");
+    assert_eq!(json["affected"].as_array().unwrap().len(), 1);
+    assert_eq!(json["marked"], serde_json::json!(["002-api"]));
+
+    let api = parse_frontmatter(&read_file(
+        &cwd.join("specs").join("002-api").join("README.md"),
+    ));
+    let tags = api["tags"].as_sequence().unwrap();
+    assert!(tags.iter().any(|t| t.as_str() == Some("needs-review")));
+
+    assert!(!exec_cli(&["impact", "001", "--since", "no-such-rev"], cwd).success);
+}
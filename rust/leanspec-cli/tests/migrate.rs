@@ -0,0 +1,203 @@
+//! E2E Tests: migrate command
+//!
+//! Tests structural import from spec-kit and OpenSpec layouts
+
+mod common;
+use common::*;
+use std::fs;
+use std::path::Path;
+
+fn write_source(root: &Path, file: &str, content: &str) {
+    let path = root.join(file);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    write_file(&path, content);
+}
+
+#[test]
+fn test_migrate_spec_kit_maps_structure() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+
+    let source = cwd.join("source");
+    write_source(
+        &source,
+        ".specify/memory/constitution.md",
+        "# Constitution\n",
+    );
+    write_source(
+        &source,
+        "specs/001-photo-albums/spec.md",
+        r#"# Feature Specification: Photo Albums
+
+**Feature Branch**: `001-photo-albums`
+**Created**: 2025-03-01
+**Status**: Draft
+**Input**: User description: "Organize photos into albums"
+
+## User Scenarios & Testing *(mandatory)*
+
+### User Story 1 - Create album (Priority: P1)
+
+A user groups photos by date.
+
+## Requirements *(mandatory)*
+
+### Functional Requirements
+
+- **FR-001**: System MUST let users create albums
+- **FR-002**: System MUST let users reorder albums
+
+### Key Entities
+
+- **Album**: a named group of photos
+
+## Success Criteria *(mandatory)*
+
+- **SC-001**: Users create an album in under a minute
+
+## Review Checklist
+
+Reviewed by the team.
+"#,
+    );
+    write_source(
+        &source,
+        "specs/001-photo-albums/plan.md",
+        "# Implementation Plan: Photo Albums\n\n## Summary\n\nVite app with SQLite.\n",
+    );
+    write_source(
+        &source,
+        "specs/001-photo-albums/tasks.md",
+        "# Tasks: Photo Albums\n\n## Phase 1: Setup\n\n- [x] T001 Create project\n- [ ] T002 [P] Add album model\n",
+    );
+    write_source(
+        &source,
+        "specs/001-photo-albums/research.md",
+        "# Research\n\nSQLite is enough.\n",
+    );
+
+    let result = exec_cli(&["migrate", "source", "--auto"], cwd);
+    assert!(result.success, "{}\n{}", result.stdout, result.stderr);
+    assert!(result.stdout.contains("constitution.md"));
+    assert!(result.stdout.contains("Review Checklist"));
+
+    let spec_dir = cwd.join("specs").join("001-photo-albums");
+    let readme = read_file(&spec_dir.join("README.md"));
+    let frontmatter = parse_frontmatter(&readme);
+    assert_eq!(frontmatter["status"].as_str(), Some("in-progress"));
+    assert_eq!(frontmatter["created"].as_str(), Some("2025-03-01"));
+    assert_eq!(frontmatter["priority"].as_str(), Some("high"));
+    assert!(readme.contains("# Photo Albums"));
+    assert!(readme.contains("## Acceptance Criteria"));
+    assert!(readme.contains("- [ ] **FR-001**: System MUST let users create albums"));
+    assert!(readme.contains("- [ ] **SC-001**"));
+    assert!(readme.contains("## Plan"));
+    assert!(readme.contains("- [x] T001 Create project"));
+    assert!(readme.contains("### Key Entities"));
+    assert!(file_exists(&spec_dir.join("RESEARCH.md")));
+}
+
+#[test]
+fn test_migrate_openspec_links_changes_to_capabilities() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+
+    let source = cwd.join("source");
+    write_source(&source, "openspec/project.md", "# Project\n");
+    write_source(
+        &source,
+        "openspec/specs/auth/spec.md",
+        r#"# Auth Specification
+
+## Purpose
+
+Authenticate users.
+
+## Requirements
+
+### Requirement: Login
+The system SHALL authenticate users with a password.
+
+#### Scenario: Valid credentials
+- **WHEN** a user submits valid credentials
+- **THEN** a session is created
+"#,
+    );
+    write_source(
+        &source,
+        "openspec/changes/add-2fa/proposal.md",
+        r#"# Change: Add two-factor authentication
+
+## Why
+
+Passwords alone are weak.
+
+## What Changes
+
+- Add an OTP step after login
+
+## Impact
+
+- Affected specs: auth
+"#,
+    );
+    write_source(
+        &source,
+        "openspec/changes/add-2fa/tasks.md",
+        "## 1. Implementation\n\n- [ ] 1.1 Add OTP table\n- [ ] 1.2 Add OTP check\n",
+    );
+    write_source(
+        &source,
+        "openspec/changes/add-2fa/specs/auth/spec.md",
+        "## ADDED Requirements\n\n### Requirement: Two-Factor\nThe system SHALL ask for an OTP.\n",
+    );
+
+    let dry_run = exec_cli(&["migrate", "source", "--auto", "--dry-run"], cwd);
+    assert!(dry_run.success, "{}\n{}", dry_run.stdout, dry_run.stderr);
+    assert!(!cwd.join("specs").join("001-auth").exists());
+
+    let result = exec_cli(
+        &[
+            "migrate",
+            "source",
+            "--auto",
+            "--batch-size",
+            "1",
+            "-o",
+            "json",
+        ],
+        cwd,
+    );
+    assert!(result.success, "{}\n{}", result.stdout, result.stderr);
+    let report: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    assert_eq!(report["specs"].as_array().unwrap().len(), 2);
+    assert!(report["unmapped"][0]
+        .as_str()
+        .unwrap()
+        .contains("project.md"));
+
+    let capability = read_file(&cwd.join("specs").join("001-auth").join("README.md"));
+    assert_eq!(
+        parse_frontmatter(&capability)["status"].as_str(),
+        Some("complete")
+    );
+    assert!(capability.contains("- [x] **Login**: The system SHALL authenticate users"));
+    assert!(capability.contains("Scenario: Valid credentials"));
+
+    let change = read_file(&cwd.join("specs").join("002-add-2fa").join("README.md"));
+    let frontmatter = parse_frontmatter(&change);
+    assert_eq!(frontmatter["status"].as_str(), Some("planned"));
+    assert_eq!(
+        frontmatter["depends_on"][0].as_str(),
+        Some("001-auth"),
+        "{}",
+        change
+    );
+    assert!(change.contains("# Add two-factor authentication"));
+    assert!(change.contains("- [ ] Add: **Two-Factor**: The system SHALL ask for an OTP."));
+    assert!(change.contains("- [ ] 1.1 Add OTP table"));
+
+    assert!(validate_specs(cwd).success);
+}
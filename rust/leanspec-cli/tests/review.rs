@@ -0,0 +1,103 @@
+//! E2E Tests: review command
+//!
+//! Tests review requests, approvals and the start gate
+
+mod common;
+use common::*;
+
+fn review_json(cwd: &std::path::Path, args: &[&str]) -> serde_json::Value {
+    let mut full = vec!["review"];
+    full.extend_from_slice(args);
+    full.extend_from_slice(&["-o", "json"]);
+    let result = exec_cli(&full, cwd);
+    assert!(result.success, "{}\n{}", result.stdout, result.stderr);
+    serde_json::from_str(&result.stdout).unwrap()
+}
+
+#[test]
+fn test_review_gates_starting_work() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+    create_spec(cwd, "database");
+
+    let config_path = cwd.join(".lean-spec").join("config.json");
+    let mut config: serde_json::Value = serde_json::from_str(&read_file(&config_path)).unwrap();
+    config["review"] = serde_json::json!({ "requireApproval": true });
+    write_file(&config_path, &config.to_string());
+
+    let blocked = update_spec(cwd, "001", &[("status", "in-progress")]);
+    assert!(!blocked.success);
+    assert!(
+        blocked.stdout.contains("no review has been requested"),
+        "{}",
+        blocked.stdout
+    );
+
+    let requested = review_json(cwd, &["request", "001", "alice"]);
+    assert_eq!(requested["state"], "pending");
+
+    let approved = review_json(
+        cwd,
+        &["approve", "001", "--reviewer", "alice", "-m", "Ship it"],
+    );
+    assert_eq!(approved["state"], "approved");
+    assert_eq!(approved["history"][0]["comment"], "Ship it");
+
+    // Editing the body invalidates the approval
+    let readme = cwd.join("specs").join("001-database").join("README.md");
+    write_file(
+        &readme,
+        &format!("{}\nOne more thing.\n", read_file(&readme)),
+    );
+    let status = review_json(cwd, &["status", "001"]);
+    assert_eq!(status["state"], "pending");
+    assert_eq!(status["reviewers"][0]["stale"], true);
+    assert!(!update_spec(cwd, "001", &[("status", "in-progress")]).success);
+
+    review_json(cwd, &["approve", "001", "--reviewer", "alice"]);
+    let started = update_spec(cwd, "001", &[("status", "in-progress")]);
+    assert!(started.success, "{}", started.stderr);
+    let content = read_file(&readme);
+    assert_eq!(
+        parse_frontmatter(&content)["status"].as_str(),
+        Some("in-progress")
+    );
+}
+
+#[test]
+fn test_review_changes_requested() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    init_project(cwd, true);
+    create_spec(cwd, "database");
+
+    review_json(cwd, &["request", "001", "alice", "bob"]);
+    review_json(cwd, &["approve", "001", "--reviewer", "alice"]);
+    let review = review_json(
+        cwd,
+        &[
+            "request-changes",
+            "001",
+            "--reviewer",
+            "bob",
+            "-m",
+            "Needs a rollback plan",
+        ],
+    );
+    assert_eq!(review["state"], "changes-requested");
+    assert_eq!(review["approvals"], 1);
+
+    let text = exec_cli(&["review", "status", "001"], cwd);
+    assert!(text.success, "{}", text.stderr);
+    assert!(text.stdout.contains("changes-requested"), "{}", text.stdout);
+    assert!(
+        text.stdout.contains("Needs a rollback plan"),
+        "{}",
+        text.stdout
+    );
+
+    // Without require_approval the gate is off
+    let started = update_spec(cwd, "001", &[("status", "in-progress")]);
+    assert!(started.success, "{}", started.stderr);
+}
@@ -265,6 +265,137 @@ fn test_batch_update_and_link_workflow() {
     }
 }
 
+#[test]
+fn test_rel_children_is_all_or_nothing() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec(cwd, "umbrella");
+    create_spec(cwd, "child");
+    create_spec(cwd, "grandchild");
+    create_spec(cwd, "library");
+
+    assert!(exec_cli(&["rel", "add", "001-umbrella", "--child", "002-child"], cwd).success);
+    assert!(
+        exec_cli(
+            &["rel", "add", "002-child", "--child", "003-grandchild"],
+            cwd
+        )
+        .success
+    );
+
+    let grandchild_path = cwd.join("specs").join("003-grandchild").join("README.md");
+    let umbrella_path = cwd.join("specs").join("001-umbrella").join("README.md");
+    let grandchild_before = read_file(&grandchild_path);
+    let umbrella_before = read_file(&umbrella_path);
+
+    // Adopting the umbrella would close a parent cycle, so the valid
+    // dependency staged alongside it must not be written either.
+    let result = exec_cli(
+        &[
+            "rel",
+            "add",
+            "003-grandchild",
+            "--depends-on",
+            "004-library",
+            "--child",
+            "001-umbrella",
+        ],
+        cwd,
+    );
+    assert!(!result.success);
+    assert!(result.stderr.contains("cycle"));
+    assert_eq!(read_file(&grandchild_path), grandchild_before);
+    assert_eq!(read_file(&umbrella_path), umbrella_before);
+}
+
+#[test]
+fn test_rel_frontmatter_links() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec(cwd, "old-auth");
+    create_spec(cwd, "new-auth");
+
+    // Superseding a spec that is still active is rejected
+    let result = exec_cli(&["rel", "add", "002", "--link", "supersedes=001"], cwd);
+    assert!(!result.success);
+    assert!(result.stderr.contains("Archive"));
+    assert!(!exec_cli(&["rel", "add", "002", "--link", "blocks=001"], cwd).success);
+
+    assert!(archive_spec(cwd, "001").success);
+    let result = exec_cli(&["rel", "add", "002", "--link", "supersedes=001"], cwd);
+    assert!(result.success, "{}", result.stderr);
+
+    let readme = cwd.join("specs").join("002-new-auth").join("README.md");
+    let fm = parse_frontmatter(&read_file(&readme));
+    assert_eq!(
+        fm.get("supersedes"),
+        Some(&serde_yaml::Value::Sequence(vec!["001-old-auth".into()]))
+    );
+
+    let view = exec_cli(&["view", "001", "-o", "json"], cwd);
+    let json: serde_json::Value = serde_json::from_str(&view.stdout).unwrap();
+    assert_eq!(
+        json["links"],
+        serde_json::json!([{ "type": "superseded_by", "target": "002-new-auth" }])
+    );
+
+    assert!(exec_cli(&["rel", "rm", "002", "--link", "supersedes=001"], cwd).success);
+    let fm = parse_frontmatter(&read_file(&readme));
+    assert!(!fm.contains_key("supersedes"));
+}
+
+#[test]
+fn test_mv_renumbers_and_rewrites_references() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec(cwd, "base");
+    create_spec(cwd, "feature");
+    assert!(
+        exec_cli(
+            &["rel", "add", "002-feature", "--depends-on", "001-base"],
+            cwd
+        )
+        .success
+    );
+
+    let feature_readme = cwd.join("specs").join("002-feature").join("README.md");
+    let before = read_file(&feature_readme);
+
+    let result = exec_cli(&["mv", "001-base", "--number", "10", "--dry-run"], cwd);
+    assert!(result.success, "{}", result.stderr);
+    assert!(result.stdout.contains("010-base"));
+    assert!(result.stdout.contains("002-feature/README.md"));
+    assert!(cwd.join("specs").join("001-base").exists());
+    assert_eq!(read_file(&feature_readme), before);
+
+    let result = exec_cli(&["mv", "001-base", "foundation"], cwd);
+    assert!(result.success, "{}", result.stderr);
+    assert!(!cwd.join("specs").join("001-base").exists());
+    assert!(cwd
+        .join("specs")
+        .join("001-foundation")
+        .join("README.md")
+        .exists());
+
+    let frontmatter = parse_frontmatter(&read_file(&feature_readme));
+    let depends_on = frontmatter
+        .get("depends_on")
+        .unwrap()
+        .as_sequence()
+        .unwrap();
+    assert_eq!(depends_on[0].as_str(), Some("001-foundation"));
+
+    let result = exec_cli(&["mv", "001-foundation", "--number", "2"], cwd);
+    assert!(!result.success);
+    assert!(cwd.join("specs").join("001-foundation").exists());
+}
+
 #[test]
 fn test_list_specs_by_status() {
     let ctx = TestContext::new();
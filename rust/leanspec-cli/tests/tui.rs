@@ -37,3 +37,51 @@ fn test_tui_accepts_view_flag() {
         result.stdout
     );
 }
+
+#[test]
+fn test_tui_headless_dependency_graph() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    for name in ["database", "auth", "api", "ui"] {
+        create_spec(cwd, name);
+    }
+    assert!(link_specs(cwd, "003-api", "001-database").success);
+    assert!(link_specs(cwd, "003-api", "002-auth").success);
+    assert!(link_specs(cwd, "004-ui", "003-api").success);
+
+    let headless = |keys: &str| {
+        let result = exec_cli(&["tui", "--headless", keys, "--specs-dir", "specs"], cwd);
+        assert!(result.success, "{}", result.stderr);
+        serde_json::from_str::<serde_json::Value>(&result.stdout).unwrap()
+    };
+
+    // Content view doesn't dump the graph
+    let state = headless("");
+    assert_eq!(state["selected_path"], "004-ui");
+    assert!(state.get("dep_graph").is_none());
+
+    // d: dependencies view, l: focus it, k/k/l: walk up to 002-auth
+    let state = headless("dlkkl");
+    let graph = &state["dep_graph"];
+    assert_eq!(graph["selected"], "002-auth");
+    assert_eq!(
+        graph["critical_path"],
+        serde_json::json!(["001-database", "003-api", "004-ui"])
+    );
+    let layout: Vec<&str> = graph["layout"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|l| l.as_str().unwrap())
+        .collect();
+    assert!(layout[1].contains("001-database") && layout[1].contains("002-auth"));
+    assert!(layout.iter().any(|l| l.contains("003-api")));
+    assert_eq!(layout.iter().filter(|l| l.contains('▼')).count(), 2);
+
+    // Enter opens the selected spec
+    let state = headless("dlkkl\\n");
+    assert_eq!(state["selected_path"], "002-auth");
+    assert!(state.get("dep_graph").is_none());
+}
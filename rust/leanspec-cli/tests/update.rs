@@ -357,3 +357,37 @@ fn test_update_section_append() {
     let content = read_file(&cwd.join("specs").join("001-my-spec").join("README.md"));
     assert!(content.contains("Appended details."));
 }
+
+#[test]
+fn test_update_rejects_stale_expected_version() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec(cwd, "my-spec");
+
+    let view = exec_cli(&["view", "001-my-spec", "-o", "json"], cwd);
+    assert!(view.success);
+    let json: serde_json::Value = serde_json::from_str(&view.stdout).unwrap();
+    let version = json["version"].as_str().unwrap().to_string();
+
+    let result = update_spec(
+        cwd,
+        "001-my-spec",
+        &[("priority", "high"), ("expected-version", &version)],
+    );
+    assert!(result.success);
+
+    // The first write moved the version on; replaying the old token fails.
+    let result = update_spec(
+        cwd,
+        "001-my-spec",
+        &[("priority", "low"), ("expected-version", &version)],
+    );
+    assert!(!result.success);
+    assert!(result.stdout.contains("Version conflict"));
+
+    let content = read_file(&cwd.join("specs").join("001-my-spec").join("README.md"));
+    let fm = parse_frontmatter(&content);
+    assert_eq!(fm.get("priority").and_then(|v| v.as_str()), Some("high"));
+}
@@ -6,6 +6,8 @@
 //! - Dependency alignment check
 //! - Strict mode
 //! - Warnings only mode
+//! - SARIF and JUnit report output
+//! - Project-wide consistency checks
 
 mod common;
 use common::*;
@@ -105,3 +107,56 @@ fn test_validate_empty_project() {
     let result = validate_specs(cwd);
     assert!(result.exit_code >= 0, "should handle empty project");
 }
+
+#[test]
+fn test_validate_sarif_output() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec(cwd, "sarif-spec");
+
+    let result = exec_cli(&["validate", "--output", "sarif"], cwd);
+    let sarif: serde_json::Value =
+        serde_json::from_str(&result.stdout).expect("stdout should be SARIF JSON");
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(
+        sarif["runs"][0]["artifacts"][0]["location"]["uri"],
+        "specs/001-sarif-spec/README.md"
+    );
+}
+
+#[test]
+fn test_validate_junit_output() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec(cwd, "junit-spec");
+
+    let result = exec_cli(&["validate", "--output", "junit"], cwd);
+    assert!(result.stdout.starts_with("<?xml"));
+    assert!(result.stdout.contains("name=\"001-junit-spec\""));
+    assert!(result
+        .stdout
+        .contains("file=\"specs/001-junit-spec/README.md\""));
+}
+
+#[test]
+fn test_validate_project_consistency() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+
+    init_project(cwd, true);
+    create_spec(cwd, "old-spec");
+    create_spec(cwd, "new-spec");
+    link_specs(cwd, "002-new-spec", "001-old-spec");
+    archive_spec(cwd, "001-old-spec");
+
+    let result = exec_cli(&["validate", "--project", "--output", "json"], cwd);
+    assert!(result.stdout.contains("\"category\": \"consistency\""));
+    assert!(result.stdout.contains("archived spec 001-old-spec"));
+
+    let result = exec_cli(&["validate", "--output", "json"], cwd);
+    assert!(!result.stdout.contains("consistency"));
+}
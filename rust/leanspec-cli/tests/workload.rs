@@ -0,0 +1,88 @@
+//! E2E Tests: workload command
+//!
+//! Tests per-assignee workload and WIP limits
+
+mod common;
+use common::*;
+
+fn setup(cwd: &std::path::Path) {
+    init_project(cwd, true);
+    for name in ["api", "cache", "docs", "search"] {
+        create_spec(cwd, name);
+    }
+    update_spec(
+        cwd,
+        "001",
+        &[("status", "in-progress"), ("assignee", "alice")],
+    );
+    update_spec(
+        cwd,
+        "002",
+        &[("status", "in-progress"), ("assignee", "alice")],
+    );
+    update_spec(cwd, "003", &[("assignee", "alice")]);
+
+    // Past due
+    let readme = cwd.join("specs").join("003-docs").join("README.md");
+    let content = read_file(&readme).replacen("status:", "due: '2000-01-01'\nstatus:", 1);
+    write_file(&readme, &content);
+
+    let config_path = cwd.join(".lean-spec").join("config.json");
+    let mut config: serde_json::Value = serde_json::from_str(&read_file(&config_path)).unwrap();
+    config["workload"] = serde_json::json!({ "wipLimits": { "alice": 1, "bob": 2 } });
+    write_file(&config_path, &config.to_string());
+}
+
+#[test]
+fn test_workload_report() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup(cwd);
+
+    let result = exec_cli(&["workload", "-o", "json"], cwd);
+    assert!(result.success, "{}", result.stderr);
+    let report: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+
+    let alice = &report["assignees"][0];
+    assert_eq!(alice["assignee"], "alice");
+    assert_eq!(alice["inProgress"], 2);
+    assert_eq!(alice["planned"], 1);
+    assert_eq!(alice["wipLimit"], 1);
+    assert_eq!(alice["overCapacity"], true);
+    assert_eq!(alice["overdue"], 1);
+    assert!(alice["tokens"].as_u64().unwrap() > 0);
+
+    let bob = &report["assignees"][1];
+    assert_eq!(bob["assignee"], "bob");
+    assert_eq!(bob["inProgress"], 0);
+    assert_eq!(bob["overCapacity"], false);
+
+    assert_eq!(report["unassigned"][0]["path"], "004-search");
+
+    let text = exec_cli(&["workload", "--assignee", "alice"], cwd);
+    assert!(text.success, "{}", text.stderr);
+    assert!(text.stdout.contains("over WIP limit"), "{}", text.stdout);
+    assert!(text.stdout.contains("2/1"), "{}", text.stdout);
+    assert!(text.stdout.contains("overdue"), "{}", text.stdout);
+    assert!(!text.stdout.contains("Unassigned"), "{}", text.stdout);
+
+    assert!(!exec_cli(&["workload", "--assignee", "nobody"], cwd).success);
+}
+
+#[test]
+fn test_stats_reports_over_capacity() {
+    let ctx = TestContext::new();
+    let cwd = ctx.path();
+    setup(cwd);
+
+    let result = exec_cli(&["stats", "-o", "json"], cwd);
+    assert!(result.success, "{}", result.stderr);
+    let stats: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+    let insight = stats["insights"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|i| i["message"].as_str().unwrap().contains("WIP limit"))
+        .expect("over capacity insight");
+    assert!(insight["message"].as_str().unwrap().contains("alice (2/1)"));
+}
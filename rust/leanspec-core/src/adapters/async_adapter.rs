@@ -0,0 +1,391 @@
+//! Async adapter interface
+//!
+//! [`AsyncAdapter`] is the non-blocking twin of [`Adapter`] used by async
+//! callers such as the HTTP server. Remote backends (GitHub, Jira) implement
+//! it natively on top of async `reqwest`; file-backed adapters are lifted into
+//! it with [`BlockingBridge`], which runs each call on Tokio's blocking pool so
+//! disk I/O never stalls a worker thread.
+//!
+//! The methods return boxed futures rather than using `async fn` so the trait
+//! stays object-safe and can be held as `Arc<dyn AsyncAdapter>`.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use super::{
+    comments_not_supported, Adapter, AdapterCapabilities, AdapterError, CacheStats, Comment,
+    CommentRequest, CreateRequest, ItemLink, ListFilter, Revalidated, SearchHit, SearchOptions,
+    SpecDoc, SpecSchema, UpdateRequest, Validator,
+};
+
+/// Boxed future returned by every [`AsyncAdapter`] operation.
+pub type AdapterFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, AdapterError>> + Send + 'a>>;
+
+/// Non-blocking counterpart of [`Adapter`].
+pub trait AsyncAdapter: Send + Sync {
+    /// Operational capabilities and default schema id.
+    fn capabilities(&self) -> &AdapterCapabilities;
+
+    /// The schema documents returned by this adapter conform to.
+    fn schema(&self) -> &SpecSchema;
+
+    /// Populate dynamic enum options into a schema. See
+    /// [`Adapter::resolve_schema`].
+    fn resolve_schema<'a>(&'a self, _schema: &'a mut SpecSchema) -> AdapterFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn list<'a>(&'a self, filter: &'a ListFilter) -> AdapterFuture<'a, Vec<SpecDoc>>;
+    fn get<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, SpecDoc>;
+    fn create<'a>(&'a self, req: &'a CreateRequest) -> AdapterFuture<'a, SpecDoc>;
+    fn update<'a>(&'a self, id: &'a str, req: &'a UpdateRequest) -> AdapterFuture<'a, SpecDoc>;
+    fn delete<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, ()>;
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        opts: &'a SearchOptions,
+    ) -> AdapterFuture<'a, Vec<SearchHit>>;
+
+    fn get_links<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, Vec<ItemLink>> {
+        Box::pin(async move { Ok(self.get(id).await?.links) })
+    }
+
+    /// See [`Adapter::list_conditional`].
+    fn list_conditional<'a>(
+        &'a self,
+        filter: &'a ListFilter,
+        _previous: Option<&'a Validator>,
+    ) -> AdapterFuture<'a, Revalidated<Vec<SpecDoc>>> {
+        Box::pin(async move {
+            Ok(Revalidated::Replaced(
+                self.list(filter).await?,
+                Validator::now(),
+            ))
+        })
+    }
+
+    /// See [`Adapter::get_conditional`].
+    fn get_conditional<'a>(
+        &'a self,
+        id: &'a str,
+        _previous: Option<&'a Validator>,
+    ) -> AdapterFuture<'a, Revalidated<SpecDoc>> {
+        Box::pin(async move { Ok(Revalidated::Replaced(self.get(id).await?, Validator::now())) })
+    }
+
+    /// See [`Adapter::cache_stats`].
+    fn cache_stats(&self) -> Option<CacheStats> {
+        None
+    }
+
+    /// See [`Adapter::list_comments`].
+    fn list_comments<'a>(&'a self, _id: &'a str) -> AdapterFuture<'a, Vec<Comment>> {
+        Box::pin(async move {
+            Err(comments_not_supported(
+                AsyncAdapter::capabilities(self),
+                "list_comments",
+            ))
+        })
+    }
+
+    /// See [`Adapter::add_comment`].
+    fn add_comment<'a>(
+        &'a self,
+        _id: &'a str,
+        _req: &'a CommentRequest,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(async move {
+            Err(comments_not_supported(
+                AsyncAdapter::capabilities(self),
+                "add_comment",
+            ))
+        })
+    }
+
+    /// See [`Adapter::edit_comment`].
+    fn edit_comment<'a>(
+        &'a self,
+        _id: &'a str,
+        _comment_id: &'a str,
+        _body: &'a str,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(async move {
+            Err(comments_not_supported(
+                AsyncAdapter::capabilities(self),
+                "edit_comment",
+            ))
+        })
+    }
+
+    /// See [`Adapter::resolve_comment`].
+    fn resolve_comment<'a>(
+        &'a self,
+        _id: &'a str,
+        _comment_id: &'a str,
+        _resolved: bool,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(async move {
+            Err(comments_not_supported(
+                AsyncAdapter::capabilities(self),
+                "resolve_comment",
+            ))
+        })
+    }
+}
+
+impl fmt::Debug for dyn AsyncAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AsyncAdapter({})", self.capabilities().name)
+    }
+}
+
+/// Lifts a blocking [`Adapter`] into [`AsyncAdapter`] by running every call
+/// on Tokio's blocking thread pool.
+///
+/// Must be awaited from within a Tokio runtime.
+#[derive(Clone)]
+pub struct BlockingBridge {
+    inner: Arc<dyn Adapter>,
+}
+
+impl BlockingBridge {
+    pub fn new(adapter: impl Adapter + 'static) -> Self {
+        Self {
+            inner: Arc::new(adapter),
+        }
+    }
+
+    pub fn from_boxed(adapter: Box<dyn Adapter>) -> Self {
+        Self {
+            inner: Arc::from(adapter),
+        }
+    }
+
+    /// The wrapped blocking adapter.
+    pub fn inner(&self) -> &dyn Adapter {
+        self.inner.as_ref()
+    }
+
+    /// Run `f` against the wrapped adapter on the blocking pool.
+    async fn run<T, F>(&self, f: F) -> Result<T, AdapterError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&dyn Adapter) -> Result<T, AdapterError> + Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        let name = inner.capabilities().name.clone();
+        tokio::task::spawn_blocking(move || f(inner.as_ref()))
+            .await
+            .map_err(|e| AdapterError::BackendError {
+                adapter: name,
+                reason: format!("blocking task failed: {e}"),
+            })?
+    }
+}
+
+impl AsyncAdapter for BlockingBridge {
+    fn capabilities(&self) -> &AdapterCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn schema(&self) -> &SpecSchema {
+        self.inner.schema()
+    }
+
+    fn resolve_schema<'a>(&'a self, schema: &'a mut SpecSchema) -> AdapterFuture<'a, ()> {
+        Box::pin(async move {
+            let mut owned = schema.clone();
+            owned = self
+                .run(move |a| a.resolve_schema(&mut owned).map(|_| owned))
+                .await?;
+            *schema = owned;
+            Ok(())
+        })
+    }
+
+    fn list<'a>(&'a self, filter: &'a ListFilter) -> AdapterFuture<'a, Vec<SpecDoc>> {
+        let filter = filter.clone();
+        Box::pin(self.run(move |a| a.list(&filter)))
+    }
+
+    fn get<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, SpecDoc> {
+        let id = id.to_string();
+        Box::pin(self.run(move |a| a.get(&id)))
+    }
+
+    fn create<'a>(&'a self, req: &'a CreateRequest) -> AdapterFuture<'a, SpecDoc> {
+        let req = req.clone();
+        Box::pin(self.run(move |a| a.create(&req)))
+    }
+
+    fn update<'a>(&'a self, id: &'a str, req: &'a UpdateRequest) -> AdapterFuture<'a, SpecDoc> {
+        let id = id.to_string();
+        let req = req.clone();
+        Box::pin(self.run(move |a| a.update(&id, &req)))
+    }
+
+    fn delete<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, ()> {
+        let id = id.to_string();
+        Box::pin(self.run(move |a| a.delete(&id)))
+    }
+
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        opts: &'a SearchOptions,
+    ) -> AdapterFuture<'a, Vec<SearchHit>> {
+        let query = query.to_string();
+        let opts = opts.clone();
+        Box::pin(self.run(move |a| a.search(&query, &opts)))
+    }
+
+    fn get_links<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, Vec<ItemLink>> {
+        let id = id.to_string();
+        Box::pin(self.run(move |a| a.get_links(&id)))
+    }
+
+    fn list_conditional<'a>(
+        &'a self,
+        filter: &'a ListFilter,
+        previous: Option<&'a Validator>,
+    ) -> AdapterFuture<'a, Revalidated<Vec<SpecDoc>>> {
+        let filter = filter.clone();
+        let previous = previous.cloned();
+        Box::pin(self.run(move |a| a.list_conditional(&filter, previous.as_ref())))
+    }
+
+    fn get_conditional<'a>(
+        &'a self,
+        id: &'a str,
+        previous: Option<&'a Validator>,
+    ) -> AdapterFuture<'a, Revalidated<SpecDoc>> {
+        let id = id.to_string();
+        let previous = previous.cloned();
+        Box::pin(self.run(move |a| a.get_conditional(&id, previous.as_ref())))
+    }
+
+    fn cache_stats(&self) -> Option<CacheStats> {
+        self.inner.cache_stats()
+    }
+
+    fn list_comments<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, Vec<Comment>> {
+        let id = id.to_string();
+        Box::pin(self.run(move |a| a.list_comments(&id)))
+    }
+
+    fn add_comment<'a>(
+        &'a self,
+        id: &'a str,
+        req: &'a CommentRequest,
+    ) -> AdapterFuture<'a, Comment> {
+        let id = id.to_string();
+        let req = req.clone();
+        Box::pin(self.run(move |a| a.add_comment(&id, &req)))
+    }
+
+    fn edit_comment<'a>(
+        &'a self,
+        id: &'a str,
+        comment_id: &'a str,
+        body: &'a str,
+    ) -> AdapterFuture<'a, Comment> {
+        let (id, comment_id, body) = (id.to_string(), comment_id.to_string(), body.to_string());
+        Box::pin(self.run(move |a| a.edit_comment(&id, &comment_id, &body)))
+    }
+
+    fn resolve_comment<'a>(
+        &'a self,
+        id: &'a str,
+        comment_id: &'a str,
+        resolved: bool,
+    ) -> AdapterFuture<'a, Comment> {
+        let (id, comment_id) = (id.to_string(), comment_id.to_string());
+        Box::pin(self.run(move |a| a.resolve_comment(&id, &comment_id, resolved)))
+    }
+}
+
+/// Runtime that drives natively-async adapters when they are called through
+/// the blocking [`Adapter`] interface (CLI, tests).
+#[cfg(any(test, feature = "github", feature = "jira"))]
+static BRIDGE_RUNTIME: std::sync::LazyLock<tokio::runtime::Runtime> =
+    std::sync::LazyLock::new(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("leanspec-adapter")
+            .enable_all()
+            .build()
+            .expect("failed to start adapter runtime")
+    });
+
+/// Drive an adapter future to completion from synchronous code.
+///
+/// When called from inside a Tokio runtime (e.g. a blocking adapter call
+/// made on a worker thread) the future runs on a scoped helper thread, since
+/// nesting `block_on` inside a runtime panics.
+#[cfg(any(test, feature = "github", feature = "jira"))]
+pub(crate) fn block_on<F>(future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    if tokio::runtime::Handle::try_current().is_ok() {
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| BRIDGE_RUNTIME.block_on(future))
+                .join()
+                .expect("adapter bridge thread panicked")
+        })
+    } else {
+        BRIDGE_RUNTIME.block_on(future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::markdown::MarkdownAdapter;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn markdown_fixture() -> TempDir {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("001-first");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("README.md"),
+            "---\nstatus: planned\ncreated: '2025-01-01'\n---\n\n# First\n\nBody.\n",
+        )
+        .unwrap();
+        tmp
+    }
+
+    #[tokio::test]
+    async fn blocking_bridge_runs_sync_adapter() {
+        let tmp = markdown_fixture();
+        let bridge: Arc<dyn AsyncAdapter> =
+            Arc::new(BlockingBridge::new(MarkdownAdapter::new(tmp.path())));
+
+        assert_eq!(bridge.capabilities().name, "markdown");
+        let docs = bridge.list(&ListFilter::default()).await.unwrap();
+        assert_eq!(docs.len(), 1);
+        let doc = bridge.get("001-first").await.unwrap();
+        assert_eq!(doc.title, "First");
+        assert!(matches!(
+            bridge.get("999-missing").await,
+            Err(AdapterError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn block_on_works_inside_and_outside_a_runtime() {
+        assert_eq!(block_on(async { 1 + 1 }), 2);
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let value = rt.block_on(async { block_on(async { 21 * 2 }) });
+        assert_eq!(value, 42);
+    }
+}
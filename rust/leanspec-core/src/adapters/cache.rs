@@ -0,0 +1,829 @@
+//! Caching adapter wrapper
+//!
+//! [`CachingAdapter`] sits in front of any [`Adapter`] (or, with the `async`
+//! feature, any [`AsyncAdapter`](super::AsyncAdapter)) and keeps `list`,
+//! `get` and `resolve_schema` results in memory for a configurable TTL.
+//!
+//! Once an entry expires it is not simply refetched: the wrapper hands the
+//! [`Validator`] captured on the previous fetch to the backend's
+//! `list_conditional` / `get_conditional` hook. GitHub answers with
+//! `If-None-Match` against the stored ETag, Jira with an `updated >=` JQL
+//! delta, and adapters without a cheap revalidation path fall back to a full
+//! fetch. Local writes through the wrapper invalidate affected entries.
+//!
+//! Configure it under the `cache:` key of `leanspec.adapter.yaml`:
+//!
+//! ```yaml
+//! adapter: github
+//! repo: owner/name
+//! cache:
+//!   ttl_secs: 60
+//!   schema_ttl_secs: 3600
+//! ```
+//!
+//! `cache: false` disables the wrapper.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::{
+    Adapter, AdapterCapabilities, AdapterError, Comment, CommentRequest, CreateRequest, ListFilter,
+    SearchHit, SearchOptions, SpecDoc, SpecSchema, UpdateRequest,
+};
+
+/// Default TTL for cached `list` and `get` results.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+/// Default TTL for the resolved schema (enum options change rarely).
+pub const DEFAULT_SCHEMA_TTL: Duration = Duration::from_secs(3600);
+
+/// Revalidation token captured when a result was fetched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Validator {
+    /// HTTP entity tag for `If-None-Match` revalidation, when the backend
+    /// provides one.
+    pub etag: Option<String>,
+    /// When the backend last confirmed the result.
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl Validator {
+    pub fn now() -> Self {
+        Self::with_etag(None)
+    }
+
+    pub fn with_etag(etag: Option<String>) -> Self {
+        Self {
+            etag,
+            fetched_at: Utc::now(),
+        }
+    }
+}
+
+/// Outcome of a conditional fetch.
+#[derive(Debug)]
+pub enum Revalidated<T> {
+    /// The previous result is still current.
+    Unchanged(Validator),
+    /// A complete new result.
+    Replaced(T, Validator),
+    /// A delta against the previous listing: documents to insert or replace
+    /// by id, and ids that no longer match the filter.
+    Changed {
+        upserted: Vec<SpecDoc>,
+        removed: Vec<String>,
+        validator: Validator,
+    },
+}
+
+/// Cache tuning, read from the adapter settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheConfig {
+    pub ttl: Duration,
+    pub schema_ttl: Duration,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: DEFAULT_TTL,
+            schema_ttl: DEFAULT_SCHEMA_TTL,
+        }
+    }
+}
+
+impl CacheConfig {
+    /// Parse the `cache:` key of an adapter's settings.
+    ///
+    /// Returns `None` when caching is disabled with `cache: false`. A missing
+    /// key or `cache: true` yields the defaults.
+    pub fn from_settings(settings: &serde_json::Value) -> Result<Option<Self>, AdapterError> {
+        let Some(value) = settings.get("cache") else {
+            return Ok(Some(Self::default()));
+        };
+        match value {
+            serde_json::Value::Bool(false) => Ok(None),
+            serde_json::Value::Bool(true) | serde_json::Value::Null => Ok(Some(Self::default())),
+            serde_json::Value::Object(map) => {
+                let secs = |key: &str, default: Duration| match map.get(key) {
+                    None => Ok(default),
+                    Some(v) => v.as_u64().map(Duration::from_secs).ok_or_else(|| {
+                        AdapterError::ConfigError(format!(
+                            "cache.{key} must be a non-negative integer"
+                        ))
+                    }),
+                };
+                Ok(Some(Self {
+                    ttl: secs("ttl_secs", DEFAULT_TTL)?,
+                    schema_ttl: secs("schema_ttl_secs", DEFAULT_SCHEMA_TTL)?,
+                }))
+            }
+            _ => Err(AdapterError::ConfigError(
+                "cache must be a boolean or a mapping".into(),
+            )),
+        }
+    }
+}
+
+/// Counters reported through [`AdapterCapabilities::cache`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+pub struct CacheStats {
+    /// Served from memory without contacting the backend.
+    pub hits: usize,
+    /// No entry was cached; fetched in full.
+    pub misses: usize,
+    /// Expired entries the backend confirmed unchanged or patched with a delta.
+    pub revalidated: usize,
+    /// Expired entries the backend replaced with a full result.
+    pub refetched: usize,
+    /// Entries dropped because of writes made through the cache.
+    pub invalidations: usize,
+    /// Entries currently held.
+    pub entries: usize,
+    pub ttl_secs: usize,
+    pub schema_ttl_secs: usize,
+}
+
+struct Entry<T> {
+    value: T,
+    validator: Validator,
+    stored_at: Instant,
+}
+
+#[derive(Default)]
+struct State {
+    lists: HashMap<String, Entry<Vec<SpecDoc>>>,
+    docs: HashMap<String, Entry<SpecDoc>>,
+    schema: Option<(SpecSchema, Instant)>,
+    stats: CacheStats,
+}
+
+/// Result of looking a key up before touching the backend.
+enum Lookup<T> {
+    Fresh(T),
+    Stale(T, Validator),
+    Missing,
+}
+
+/// TTL cache in front of another adapter. See the [module docs](self).
+pub struct CachingAdapter<A> {
+    inner: A,
+    config: CacheConfig,
+    capabilities: AdapterCapabilities,
+    state: Mutex<State>,
+}
+
+impl<A> CachingAdapter<A> {
+    /// Wrap `inner`, copying `capabilities` from it.
+    pub fn new(inner: A, capabilities: AdapterCapabilities, config: CacheConfig) -> Self {
+        Self {
+            inner,
+            config,
+            capabilities,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// The wrapped adapter.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Drop every cached entry.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        let dropped = state.lists.len() + state.docs.len() + usize::from(state.schema.is_some());
+        state.lists.clear();
+        state.docs.clear();
+        state.schema = None;
+        state.stats.invalidations += dropped;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // A panic while holding the lock leaves plain data behind; keep using it.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn stats(&self) -> CacheStats {
+        let state = self.lock();
+        CacheStats {
+            entries: (state.lists.len() + state.docs.len() + usize::from(state.schema.is_some())),
+            ttl_secs: self.config.ttl.as_secs() as usize,
+            schema_ttl_secs: self.config.schema_ttl.as_secs() as usize,
+            ..state.stats.clone()
+        }
+    }
+
+    fn lookup_list(&self, key: &str) -> Lookup<Vec<SpecDoc>> {
+        let mut state = self.lock();
+        let lookup = match state.lists.get(key) {
+            Some(e) if e.stored_at.elapsed() < self.config.ttl => Lookup::Fresh(e.value.clone()),
+            Some(e) => Lookup::Stale(e.value.clone(), e.validator.clone()),
+            None => Lookup::Missing,
+        };
+        record_lookup(&mut state.stats, &lookup);
+        lookup
+    }
+
+    fn store_list(
+        &self,
+        key: String,
+        previous: Option<Vec<SpecDoc>>,
+        result: Revalidated<Vec<SpecDoc>>,
+    ) -> Vec<SpecDoc> {
+        let (value, validator) = match (result, previous) {
+            (Revalidated::Unchanged(validator), Some(previous)) => (previous, validator),
+            (
+                Revalidated::Changed {
+                    upserted,
+                    removed,
+                    validator,
+                },
+                Some(previous),
+            ) => (apply_delta(previous, upserted, &removed), validator),
+            (Revalidated::Replaced(value, validator), _) => (value, validator),
+            // A backend may only answer `Unchanged`/`Changed` when given a
+            // validator, and we only pass one alongside a previous value.
+            (Revalidated::Unchanged(validator), None) => (Vec::new(), validator),
+            (
+                Revalidated::Changed {
+                    upserted,
+                    validator,
+                    ..
+                },
+                None,
+            ) => (upserted, validator),
+        };
+        let mut state = self.lock();
+        state.lists.insert(
+            key,
+            Entry {
+                value: value.clone(),
+                validator,
+                stored_at: Instant::now(),
+            },
+        );
+        value
+    }
+
+    fn lookup_doc(&self, id: &str) -> Lookup<SpecDoc> {
+        let mut state = self.lock();
+        let lookup = match state.docs.get(id) {
+            Some(e) if e.stored_at.elapsed() < self.config.ttl => Lookup::Fresh(e.value.clone()),
+            Some(e) => Lookup::Stale(e.value.clone(), e.validator.clone()),
+            None => Lookup::Missing,
+        };
+        record_lookup(&mut state.stats, &lookup);
+        lookup
+    }
+
+    fn store_doc(
+        &self,
+        id: &str,
+        previous: Option<SpecDoc>,
+        result: Revalidated<SpecDoc>,
+    ) -> Result<SpecDoc, AdapterError> {
+        let (value, validator) = match result {
+            Revalidated::Replaced(value, validator) => (value, validator),
+            Revalidated::Unchanged(validator) => match previous {
+                Some(previous) => (previous, validator),
+                None => return Err(unexpected_unchanged(&self.capabilities.name, id)),
+            },
+            Revalidated::Changed {
+                mut upserted,
+                validator,
+                ..
+            } => match upserted.pop() {
+                Some(doc) => (doc, validator),
+                None => return Err(AdapterError::NotFound(id.to_string())),
+            },
+        };
+        self.lock().docs.insert(
+            id.to_string(),
+            Entry {
+                value: value.clone(),
+                validator,
+                stored_at: Instant::now(),
+            },
+        );
+        Ok(value)
+    }
+
+    fn cached_schema(&self) -> Option<SpecSchema> {
+        let mut state = self.lock();
+        let cached = match &state.schema {
+            Some((schema, at)) if at.elapsed() < self.config.schema_ttl => Some(schema.clone()),
+            _ => None,
+        };
+        if cached.is_some() {
+            state.stats.hits += 1;
+        } else {
+            state.stats.misses += 1;
+        }
+        cached
+    }
+
+    fn store_schema(&self, schema: &SpecSchema) {
+        self.lock().schema = Some((schema.clone(), Instant::now()));
+    }
+
+    /// Creating a document can change any listing.
+    fn invalidate_lists(&self, state: &mut State) {
+        state.stats.invalidations += state.lists.len();
+        state.lists.clear();
+    }
+
+    fn after_create(&self) {
+        let mut state = self.lock();
+        self.invalidate_lists(&mut state);
+    }
+
+    fn after_update(&self, doc: &SpecDoc) {
+        let mut state = self.lock();
+        self.invalidate_lists(&mut state);
+        state.docs.insert(
+            doc.id.clone(),
+            Entry {
+                value: doc.clone(),
+                validator: Validator::now(),
+                stored_at: Instant::now(),
+            },
+        );
+    }
+
+    fn after_delete(&self, id: &str) {
+        let mut state = self.lock();
+        self.invalidate_lists(&mut state);
+        if state.docs.remove(id).is_some() {
+            state.stats.invalidations += 1;
+        }
+    }
+
+    /// Remote backends bump a document's `updated_at`, and so its version,
+    /// when it is commented on. Drop the cached copies like a delete does.
+    fn after_comment(&self, id: &str) {
+        self.after_delete(id);
+    }
+}
+
+fn record_lookup<T>(stats: &mut CacheStats, lookup: &Lookup<T>) {
+    match lookup {
+        Lookup::Fresh(_) => stats.hits += 1,
+        Lookup::Missing => stats.misses += 1,
+        Lookup::Stale(..) => {}
+    }
+}
+
+fn record_refresh<T>(state: &Mutex<State>, result: &Revalidated<T>) {
+    let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+    match result {
+        Revalidated::Replaced(..) => state.stats.refetched += 1,
+        _ => state.stats.revalidated += 1,
+    }
+}
+
+fn unexpected_unchanged(adapter: &str, id: &str) -> AdapterError {
+    AdapterError::BackendError {
+        adapter: adapter.to_string(),
+        reason: format!("backend reported {id} unchanged without a cached copy"),
+    }
+}
+
+/// Merge a listing delta into the previous listing, keeping its order.
+fn apply_delta(previous: Vec<SpecDoc>, upserted: Vec<SpecDoc>, removed: &[String]) -> Vec<SpecDoc> {
+    let mut upserted: HashMap<String, SpecDoc> =
+        upserted.into_iter().map(|d| (d.id.clone(), d)).collect();
+    let mut merged: Vec<SpecDoc> = previous
+        .into_iter()
+        .filter(|d| !removed.contains(&d.id))
+        .map(|d| upserted.remove(&d.id).unwrap_or(d))
+        .collect();
+    let mut added: Vec<SpecDoc> = upserted.into_values().collect();
+    added.sort_by(|a, b| a.id.cmp(&b.id));
+    merged.extend(added);
+    merged
+}
+
+/// Stable cache key for a [`ListFilter`].
+fn filter_key(filter: &ListFilter) -> String {
+    let mut fields: Vec<_> = filter.fields.iter().collect();
+    fields.sort();
+    serde_json::json!({
+        "fields": fields,
+        "text": filter.text,
+        "include_archived": filter.include_archived,
+        "raw": filter.raw,
+    })
+    .to_string()
+}
+
+impl<A: Adapter> CachingAdapter<A> {
+    /// Wrap a blocking adapter, using its own capabilities.
+    pub fn wrap(inner: A, config: CacheConfig) -> Self {
+        let capabilities = inner.capabilities().clone();
+        Self::new(inner, capabilities, config)
+    }
+}
+
+impl<A: Adapter> Adapter for CachingAdapter<A> {
+    fn capabilities(&self) -> &AdapterCapabilities {
+        &self.capabilities
+    }
+
+    fn schema(&self) -> &SpecSchema {
+        self.inner.schema()
+    }
+
+    fn resolve_schema(&self, schema: &mut SpecSchema) -> Result<(), AdapterError> {
+        if let Some(cached) = self.cached_schema() {
+            *schema = cached;
+            return Ok(());
+        }
+        self.inner.resolve_schema(schema)?;
+        self.store_schema(schema);
+        Ok(())
+    }
+
+    fn list(&self, filter: &ListFilter) -> Result<Vec<SpecDoc>, AdapterError> {
+        let key = filter_key(filter);
+        match self.lookup_list(&key) {
+            Lookup::Fresh(docs) => Ok(docs),
+            Lookup::Stale(previous, validator) => {
+                let result = self.inner.list_conditional(filter, Some(&validator))?;
+                record_refresh(&self.state, &result);
+                Ok(self.store_list(key, Some(previous), result))
+            }
+            Lookup::Missing => {
+                let result = self.inner.list_conditional(filter, None)?;
+                Ok(self.store_list(key, None, result))
+            }
+        }
+    }
+
+    fn get(&self, id: &str) -> Result<SpecDoc, AdapterError> {
+        let lookup = self.lookup_doc(id);
+        match lookup {
+            Lookup::Fresh(doc) => Ok(doc),
+            Lookup::Stale(previous, validator) => {
+                let result = self.inner.get_conditional(id, Some(&validator))?;
+                record_refresh(&self.state, &result);
+                self.store_doc(id, Some(previous), result)
+            }
+            Lookup::Missing => {
+                let result = self.inner.get_conditional(id, None)?;
+                self.store_doc(id, None, result)
+            }
+        }
+    }
+
+    fn create(&self, req: &CreateRequest) -> Result<SpecDoc, AdapterError> {
+        let doc = self.inner.create(req)?;
+        self.after_create();
+        Ok(doc)
+    }
+
+    fn update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
+        let doc = self.inner.update(id, req)?;
+        self.after_update(&doc);
+        Ok(doc)
+    }
+
+    fn delete(&self, id: &str) -> Result<(), AdapterError> {
+        self.inner.delete(id)?;
+        self.after_delete(id);
+        Ok(())
+    }
+
+    fn search(&self, query: &str, opts: &SearchOptions) -> Result<Vec<SearchHit>, AdapterError> {
+        self.inner.search(query, opts)
+    }
+
+    fn cache_stats(&self) -> Option<CacheStats> {
+        Some(self.stats())
+    }
+
+    fn list_comments(&self, id: &str) -> Result<Vec<Comment>, AdapterError> {
+        self.inner.list_comments(id)
+    }
+
+    fn add_comment(&self, id: &str, req: &CommentRequest) -> Result<Comment, AdapterError> {
+        let comment = self.inner.add_comment(id, req)?;
+        self.after_comment(id);
+        Ok(comment)
+    }
+
+    fn edit_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment, AdapterError> {
+        let comment = self.inner.edit_comment(id, comment_id, body)?;
+        self.after_comment(id);
+        Ok(comment)
+    }
+
+    fn resolve_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        resolved: bool,
+    ) -> Result<Comment, AdapterError> {
+        let comment = self.inner.resolve_comment(id, comment_id, resolved)?;
+        self.after_comment(id);
+        Ok(comment)
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_impl {
+    use super::*;
+    use crate::adapters::{AdapterFuture, AsyncAdapter};
+
+    impl<A: AsyncAdapter> CachingAdapter<A> {
+        /// Wrap an async adapter, using its own capabilities.
+        pub fn wrap_async(inner: A, config: CacheConfig) -> Self {
+            let capabilities = inner.capabilities().clone();
+            Self::new(inner, capabilities, config)
+        }
+    }
+
+    impl<A: AsyncAdapter> AsyncAdapter for CachingAdapter<A> {
+        fn capabilities(&self) -> &AdapterCapabilities {
+            &self.capabilities
+        }
+
+        fn schema(&self) -> &SpecSchema {
+            self.inner.schema()
+        }
+
+        fn resolve_schema<'a>(&'a self, schema: &'a mut SpecSchema) -> AdapterFuture<'a, ()> {
+            Box::pin(async move {
+                if let Some(cached) = self.cached_schema() {
+                    *schema = cached;
+                    return Ok(());
+                }
+                self.inner.resolve_schema(schema).await?;
+                self.store_schema(schema);
+                Ok(())
+            })
+        }
+
+        fn list<'a>(&'a self, filter: &'a ListFilter) -> AdapterFuture<'a, Vec<SpecDoc>> {
+            Box::pin(async move {
+                let key = filter_key(filter);
+                match self.lookup_list(&key) {
+                    Lookup::Fresh(docs) => Ok(docs),
+                    Lookup::Stale(previous, validator) => {
+                        let result = self
+                            .inner
+                            .list_conditional(filter, Some(&validator))
+                            .await?;
+                        record_refresh(&self.state, &result);
+                        Ok(self.store_list(key, Some(previous), result))
+                    }
+                    Lookup::Missing => {
+                        let result = self.inner.list_conditional(filter, None).await?;
+                        Ok(self.store_list(key, None, result))
+                    }
+                }
+            })
+        }
+
+        fn get<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, SpecDoc> {
+            Box::pin(async move {
+                match self.lookup_doc(id) {
+                    Lookup::Fresh(doc) => Ok(doc),
+                    Lookup::Stale(previous, validator) => {
+                        let result = self.inner.get_conditional(id, Some(&validator)).await?;
+                        record_refresh(&self.state, &result);
+                        self.store_doc(id, Some(previous), result)
+                    }
+                    Lookup::Missing => {
+                        let result = self.inner.get_conditional(id, None).await?;
+                        self.store_doc(id, None, result)
+                    }
+                }
+            })
+        }
+
+        fn create<'a>(&'a self, req: &'a CreateRequest) -> AdapterFuture<'a, SpecDoc> {
+            Box::pin(async move {
+                let doc = self.inner.create(req).await?;
+                self.after_create();
+                Ok(doc)
+            })
+        }
+
+        fn update<'a>(&'a self, id: &'a str, req: &'a UpdateRequest) -> AdapterFuture<'a, SpecDoc> {
+            Box::pin(async move {
+                let doc = self.inner.update(id, req).await?;
+                self.after_update(&doc);
+                Ok(doc)
+            })
+        }
+
+        fn delete<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, ()> {
+            Box::pin(async move {
+                self.inner.delete(id).await?;
+                self.after_delete(id);
+                Ok(())
+            })
+        }
+
+        fn search<'a>(
+            &'a self,
+            query: &'a str,
+            opts: &'a SearchOptions,
+        ) -> AdapterFuture<'a, Vec<SearchHit>> {
+            self.inner.search(query, opts)
+        }
+
+        fn cache_stats(&self) -> Option<CacheStats> {
+            Some(self.stats())
+        }
+
+        fn list_comments<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, Vec<Comment>> {
+            self.inner.list_comments(id)
+        }
+
+        fn add_comment<'a>(
+            &'a self,
+            id: &'a str,
+            req: &'a CommentRequest,
+        ) -> AdapterFuture<'a, Comment> {
+            Box::pin(async move {
+                let comment = self.inner.add_comment(id, req).await?;
+                self.after_comment(id);
+                Ok(comment)
+            })
+        }
+
+        fn edit_comment<'a>(
+            &'a self,
+            id: &'a str,
+            comment_id: &'a str,
+            body: &'a str,
+        ) -> AdapterFuture<'a, Comment> {
+            Box::pin(async move {
+                let comment = self.inner.edit_comment(id, comment_id, body).await?;
+                self.after_comment(id);
+                Ok(comment)
+            })
+        }
+
+        fn resolve_comment<'a>(
+            &'a self,
+            id: &'a str,
+            comment_id: &'a str,
+            resolved: bool,
+        ) -> AdapterFuture<'a, Comment> {
+            Box::pin(async move {
+                let comment = self.inner.resolve_comment(id, comment_id, resolved).await?;
+                self.after_comment(id);
+                Ok(comment)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::markdown::MarkdownAdapter;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn fixture() -> TempDir {
+        let tmp = TempDir::new().unwrap();
+        for (name, title) in [("001-first", "First"), ("002-second", "Second")] {
+            let dir = tmp.path().join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join("README.md"),
+                format!("---\nstatus: planned\ncreated: '2025-01-01'\n---\n\n# {title}\n\nBody.\n"),
+            )
+            .unwrap();
+        }
+        tmp
+    }
+
+    fn doc(id: &str) -> SpecDoc {
+        SpecDoc {
+            id: id.into(),
+            title: id.into(),
+            schema_id: "test".into(),
+            fields: HashMap::new(),
+            links: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            url: None,
+            raw: None,
+            version: None,
+        }
+    }
+
+    #[test]
+    fn list_is_served_from_cache_within_ttl() {
+        let tmp = fixture();
+        let cache = CachingAdapter::wrap(MarkdownAdapter::new(tmp.path()), CacheConfig::default());
+
+        assert_eq!(cache.list(&ListFilter::default()).unwrap().len(), 2);
+        // Out-of-band change is not visible until the entry expires.
+        fs::remove_dir_all(tmp.path().join("002-second")).unwrap();
+        assert_eq!(cache.list(&ListFilter::default()).unwrap().len(), 2);
+
+        let stats = cache.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        assert_eq!(stats.entries, 1);
+    }
+
+    #[test]
+    fn expired_entries_are_refetched() {
+        let tmp = fixture();
+        let config = CacheConfig {
+            ttl: Duration::ZERO,
+            ..CacheConfig::default()
+        };
+        let cache = CachingAdapter::wrap(MarkdownAdapter::new(tmp.path()), config);
+
+        assert_eq!(cache.list(&ListFilter::default()).unwrap().len(), 2);
+        fs::remove_dir_all(tmp.path().join("002-second")).unwrap();
+        assert_eq!(cache.list(&ListFilter::default()).unwrap().len(), 1);
+        assert_eq!(cache.cache_stats().unwrap().refetched, 1);
+    }
+
+    #[test]
+    fn writes_invalidate_listings_and_refresh_the_document() {
+        let tmp = fixture();
+        let cache = CachingAdapter::wrap(MarkdownAdapter::new(tmp.path()), CacheConfig::default());
+
+        cache.list(&ListFilter::default()).unwrap();
+        cache.get("001-first").unwrap();
+        let mut req = UpdateRequest::default();
+        req.fields.insert(
+            "status".into(),
+            crate::model::FieldValue::String("in-progress".into()),
+        );
+        cache.update("001-first", &req).unwrap();
+
+        let doc = cache.get("001-first").unwrap();
+        assert_eq!(doc.field_str("status"), Some("in-progress"));
+        let stats = cache.cache_stats().unwrap();
+        assert_eq!(stats.invalidations, 1);
+        // The document lookup after the update is a hit on the refreshed entry.
+        assert_eq!(stats.hits, 1);
+
+        cache.delete("002-second").unwrap();
+        assert_eq!(cache.cache_stats().unwrap().entries, 1);
+    }
+
+    #[test]
+    fn filters_get_separate_entries() {
+        let mut a = ListFilter::default();
+        a.fields.insert("status".into(), vec!["planned".into()]);
+        a.fields.insert("priority".into(), vec!["high".into()]);
+        let mut b = ListFilter::default();
+        b.fields.insert("priority".into(), vec!["high".into()]);
+        b.fields.insert("status".into(), vec!["planned".into()]);
+        assert_eq!(filter_key(&a), filter_key(&b));
+        assert_ne!(filter_key(&a), filter_key(&ListFilter::default()));
+    }
+
+    #[test]
+    fn delta_replaces_in_place_and_appends_new_documents() {
+        let mut changed = doc("002");
+        changed.title = "Changed".into();
+        let merged = apply_delta(
+            vec![doc("001"), doc("002"), doc("003")],
+            vec![doc("004"), changed],
+            &["001".to_string()],
+        );
+        let ids: Vec<_> = merged.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, ["002", "003", "004"]);
+        assert_eq!(merged[0].title, "Changed");
+    }
+
+    #[test]
+    fn config_parses_settings() {
+        let parse = |v| CacheConfig::from_settings(&serde_json::json!({ "cache": v }));
+        assert_eq!(parse(serde_json::json!(false)).unwrap(), None);
+        assert_eq!(
+            parse(serde_json::json!({ "ttl_secs": 5 })).unwrap(),
+            Some(CacheConfig {
+                ttl: Duration::from_secs(5),
+                schema_ttl: DEFAULT_SCHEMA_TTL,
+            })
+        );
+        assert!(parse(serde_json::json!("soon")).is_err());
+        assert_eq!(
+            CacheConfig::from_settings(&serde_json::json!({})).unwrap(),
+            Some(CacheConfig::default())
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Issue comments as spec [`Comment`]s.
+//!
+//! Comments are read and written through the REST issue comment endpoints;
+//! the comment id is GitHub's numeric comment id. GitHub has no reply or
+//! resolve state on issue comments, so:
+//!
+//! - a reply carries a hidden `<!-- leanspec:in-reply-to N -->` marker at the
+//!   end of its body, stripped again on read;
+//! - resolving minimizes the comment with the `RESOLVED` reason through the
+//!   GraphQL API, which collapses it in GitHub's UI. The minimized state is
+//!   read back in one GraphQL query per listing; when that query fails
+//!   (older GitHub Enterprise servers), comments are reported unresolved.
+
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde_json::{json, Value};
+
+use super::links::{graphql_url, is_unsupported};
+use super::{send, with_not_found_id, GitHubAdapter, ADAPTER_NAME, DEFAULT_LIST_LIMIT};
+use crate::adapters::AdapterError;
+use crate::model::{Comment, CommentRequest};
+
+/// Comment node ids per GraphQL minimized-state query.
+const STATE_BATCH: usize = 100;
+
+const REPLY_MARKER: &str = "<!-- leanspec:in-reply-to ";
+
+/// Append the reply marker for `parent` to `body`.
+fn with_reply_marker(body: &str, parent: Option<&str>) -> String {
+    match parent {
+        Some(parent) => format!("{}\n\n{REPLY_MARKER}{parent} -->", body.trim_end()),
+        None => body.to_string(),
+    }
+}
+
+/// Split a stored body into the visible text and the reply target.
+fn split_reply_marker(body: &str) -> (String, Option<String>) {
+    let trimmed = body.trim_end();
+    if let Some(start) = trimmed.rfind(REPLY_MARKER) {
+        let marker = &trimmed[start + REPLY_MARKER.len()..];
+        if let Some(parent) = marker.strip_suffix("-->").map(str::trim) {
+            if !parent.is_empty() && parent.chars().all(|c| c.is_ascii_digit()) {
+                let text = trimmed[..start].trim_end().to_string();
+                return (text, Some(parent.to_string()));
+            }
+        }
+    }
+    (body.to_string(), None)
+}
+
+fn parse_time(value: Option<&Value>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|v| v.as_str())
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// Project a REST issue comment onto a [`Comment`].
+fn comment_from_json(value: &Value) -> Comment {
+    let raw_body = value.get("body").and_then(|v| v.as_str()).unwrap_or("");
+    let (body, in_reply_to) = split_reply_marker(raw_body);
+    let created_at = parse_time(value.get("created_at"));
+    let updated_at = parse_time(value.get("updated_at")).filter(|u| Some(*u) != created_at);
+    Comment {
+        id: value
+            .get("id")
+            .and_then(|v| v.as_u64())
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        author: value
+            .pointer("/user/login")
+            .and_then(|v| v.as_str())
+            .map(String::from),
+        body,
+        created_at,
+        updated_at,
+        resolved: false,
+        in_reply_to,
+        url: value
+            .get("html_url")
+            .and_then(|v| v.as_str())
+            .map(String::from),
+    }
+}
+
+fn node_id(value: &Value) -> Option<&str> {
+    value.get("node_id").and_then(|v| v.as_str())
+}
+
+fn invalid(reason: String) -> AdapterError {
+    AdapterError::InvalidField {
+        adapter: ADAPTER_NAME.into(),
+        reason,
+    }
+}
+
+fn check_body(body: &str) -> Result<(), AdapterError> {
+    if body.trim().is_empty() {
+        return Err(invalid("comment body must not be empty".into()));
+    }
+    Ok(())
+}
+
+impl GitHubAdapter {
+    fn comment_url(&self, comment_id: &str) -> String {
+        self.url(&format!("{}/comments/{comment_id}", self.issues_path()))
+    }
+
+    /// Fetch comment `comment_id`, checking that it belongs to issue
+    /// `number`.
+    async fn fetch_comment(&self, number: &str, comment_id: &str) -> Result<Value, AdapterError> {
+        let not_found = || AdapterError::NotFound(format!("comment {comment_id} on #{number}"));
+        if comment_id.is_empty() || !comment_id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(not_found());
+        }
+        let resp = send(self.request(Method::GET, &self.comment_url(comment_id)))
+            .await
+            .map_err(|e| match e {
+                AdapterError::NotFound(_) => not_found(),
+                other => other,
+            })?;
+        let value = Self::parse_json(resp).await?;
+        let issue_url = value
+            .get("issue_url")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        if !issue_url.ends_with(&format!("/issues/{number}")) {
+            return Err(not_found());
+        }
+        Ok(value)
+    }
+
+    /// Whether each comment is minimized as resolved, keyed by node id.
+    async fn resolved_states(
+        &self,
+        node_ids: &[&str],
+    ) -> Result<std::collections::HashMap<String, bool>, AdapterError> {
+        let url = graphql_url(&self.base_url);
+        let mut out = std::collections::HashMap::new();
+        for batch in node_ids.chunks(STATE_BATCH) {
+            let body = json!({
+                "query": "query($ids: [ID!]!) { nodes(ids: $ids) { \
+                          ... on IssueComment { id isMinimized minimizedReason } } }",
+                "variables": { "ids": batch },
+            });
+            let resp = send(self.request(Method::POST, &url).json(&body)).await?;
+            let value = Self::parse_json(resp).await?;
+            let nodes = value
+                .pointer("/data/nodes")
+                .and_then(|n| n.as_array())
+                .ok_or_else(|| AdapterError::BackendError {
+                    adapter: ADAPTER_NAME.into(),
+                    reason: "no nodes in GraphQL comment state response".into(),
+                })?;
+            for node in nodes {
+                let Some(id) = node.get("id").and_then(|v| v.as_str()) else {
+                    continue;
+                };
+                let minimized = node.get("isMinimized").and_then(|v| v.as_bool()) == Some(true);
+                let reason = node
+                    .get("minimizedReason")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("");
+                out.insert(
+                    id.to_string(),
+                    minimized && reason.eq_ignore_ascii_case("resolved"),
+                );
+            }
+        }
+        Ok(out)
+    }
+
+    pub(super) async fn list_issue_comments(
+        &self,
+        number: &str,
+    ) -> Result<Vec<Comment>, AdapterError> {
+        let url = self.url(&format!(
+            "{}/{number}/comments?per_page=100",
+            self.issues_path()
+        ));
+        let raw = self
+            .paginate_issues(&url, DEFAULT_LIST_LIMIT)
+            .await
+            .map_err(|e| with_not_found_id(e, number))?;
+        let mut comments: Vec<Comment> = raw.iter().map(comment_from_json).collect();
+
+        let node_ids: Vec<&str> = raw.iter().filter_map(node_id).collect();
+        if !node_ids.is_empty() {
+            match self.resolved_states(&node_ids).await {
+                Ok(states) => {
+                    for (comment, value) in comments.iter_mut().zip(&raw) {
+                        comment.resolved = node_id(value)
+                            .and_then(|id| states.get(id))
+                            .copied()
+                            .unwrap_or(false);
+                    }
+                }
+                Err(e) if is_unsupported(&e) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(comments)
+    }
+
+    pub(super) async fn add_issue_comment(
+        &self,
+        number: &str,
+        req: &CommentRequest,
+    ) -> Result<Comment, AdapterError> {
+        check_body(&req.body)?;
+        if let Some(parent) = &req.in_reply_to {
+            self.fetch_comment(number, parent)
+                .await
+                .map_err(|_| invalid(format!("no comment '{parent}' to reply to on #{number}")))?;
+        }
+        let url = self.url(&format!("{}/{number}/comments", self.issues_path()));
+        let body = with_reply_marker(&req.body, req.in_reply_to.as_deref());
+        let resp = send(
+            self.request(Method::POST, &url)
+                .json(&json!({ "body": body })),
+        )
+        .await
+        .map_err(|e| with_not_found_id(e, number))?;
+        Ok(comment_from_json(&Self::parse_json(resp).await?))
+    }
+
+    pub(super) async fn edit_issue_comment(
+        &self,
+        number: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment, AdapterError> {
+        check_body(body)?;
+        let current = self.fetch_comment(number, comment_id).await?;
+        let (_, in_reply_to) =
+            split_reply_marker(current.get("body").and_then(|v| v.as_str()).unwrap_or(""));
+        let resp = send(
+            self.request(Method::PATCH, &self.comment_url(comment_id))
+                .json(&json!({ "body": with_reply_marker(body, in_reply_to.as_deref()) })),
+        )
+        .await?;
+        Ok(comment_from_json(&Self::parse_json(resp).await?))
+    }
+
+    pub(super) async fn resolve_issue_comment(
+        &self,
+        number: &str,
+        comment_id: &str,
+        resolved: bool,
+    ) -> Result<Comment, AdapterError> {
+        let current = self.fetch_comment(number, comment_id).await?;
+        let subject = node_id(&current).ok_or_else(|| AdapterError::ParseError {
+            path: format!("github comment {comment_id}"),
+            reason: "missing 'node_id' field".into(),
+        })?;
+        let query = if resolved {
+            "mutation($id: ID!) { minimizeComment(input: { subjectId: $id, classifier: RESOLVED }) \
+             { minimizedComment { isMinimized } } }"
+        } else {
+            "mutation($id: ID!) { unminimizeComment(input: { subjectId: $id }) \
+             { unminimizedComment { isMinimized } } }"
+        };
+        let body = json!({ "query": query, "variables": { "id": subject } });
+        let resp = send(
+            self.request(Method::POST, &graphql_url(&self.base_url))
+                .json(&body),
+        )
+        .await?;
+        let value = Self::parse_json(resp).await?;
+        if let Some(message) = value.pointer("/errors/0/message").and_then(|m| m.as_str()) {
+            return Err(AdapterError::BackendError {
+                adapter: ADAPTER_NAME.into(),
+                reason: format!("could not update comment {comment_id}: {message}"),
+            });
+        }
+        let mut comment = comment_from_json(&current);
+        comment.resolved = resolved;
+        Ok(comment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reply_marker_round_trips() {
+        let stored = with_reply_marker("Agreed.\n", Some("42"));
+        assert_eq!(stored, "Agreed.\n\n<!-- leanspec:in-reply-to 42 -->");
+        assert_eq!(
+            split_reply_marker(&stored),
+            ("Agreed.".to_string(), Some("42".to_string()))
+        );
+        let plain = "Mentions <!-- leanspec:in-reply-to x --> inline";
+        assert_eq!(split_reply_marker(plain), (plain.to_string(), None));
+    }
+}
@@ -0,0 +1,543 @@
+//! Issue relationships as [`ItemLink`]s.
+//!
+//! GitHub models hierarchy as sub-issues and ordering as issue dependencies
+//! ("blocked by" / "blocking"). Both are read in bulk through the GraphQL
+//! API — one query covers up to [`RELATIONS_BATCH`] issues — and written
+//! through the REST endpoints, which address issues by database id:
+//!
+//! | Link | Read from | Written with |
+//! |------|-----------|--------------|
+//! | `parent` | `Issue.parent` | `POST /issues/{parent}/sub_issues` |
+//! | `child` | `Issue.subIssues` | `POST /issues/{n}/sub_issues` |
+//! | `depends_on` | `Issue.blockedBy` | `POST /issues/{n}/dependencies/blocked_by` |
+//!
+//! A document reports its `parent`, its sub-issues as `child` links and its
+//! `depends_on` links, so replacing a document's links with an edited copy
+//! of them keeps whatever was not edited. "Blocking" is the inverse of
+//! `depends_on` and shows up on the other issue. A `Depends on #N` line in
+//! the issue body is read as a `depends_on` link too, for repositories that
+//! predate native dependencies. Those links are read-only: a replace that
+//! drops one is rejected, and the body has to be edited instead.
+//!
+//! Relationship reads are best effort. When the GraphQL endpoint is missing
+//! or failing (older GitHub Enterprise servers), documents keep the links
+//! found in the body; authentication and rate-limit errors still surface.
+
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+
+use super::{link, send, with_not_found_id, GitHubAdapter, ADAPTER_NAME};
+use crate::adapters::AdapterError;
+use crate::model::{ItemLink, SpecDoc};
+
+/// Issues per GraphQL relationship query.
+const RELATIONS_BATCH: usize = 100;
+
+/// Relationship entries fetched per issue and kind.
+const RELATIONS_PER_ISSUE: usize = 50;
+
+/// An issue on the other end of a relationship.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct LinkedIssue {
+    pub number: String,
+    pub title: Option<String>,
+    pub database_id: Option<u64>,
+}
+
+/// Native relationships of one issue.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct Relations {
+    pub parent: Option<LinkedIssue>,
+    pub sub_issues: Vec<LinkedIssue>,
+    pub blocked_by: Vec<LinkedIssue>,
+}
+
+impl Relations {
+    /// Links reported on the issue itself: its parent, its sub-issues and
+    /// what blocks it.
+    fn links(&self) -> Vec<ItemLink> {
+        let parent = self.parent.iter().map(|p| (link::PARENT, p));
+        let children = self.sub_issues.iter().map(|s| (link::CHILD, s));
+        let blocked_by = self.blocked_by.iter().map(|b| (link::DEPENDS_ON, b));
+        parent
+            .chain(children)
+            .chain(blocked_by)
+            .map(|(link_type, issue)| ItemLink {
+                link_type: link_type.into(),
+                target_id: issue.number.clone(),
+                target_title: issue.title.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Issue numbers referenced by `Depends on #N` lines in an issue body,
+/// e.g. `Depends on #12, #15 and #20`.
+pub(super) fn body_dependencies(body: &str) -> Vec<String> {
+    const MARKER: &str = "depends on";
+    let mut out: Vec<String> = Vec::new();
+    for line in body.lines() {
+        // Match on the line itself: lowercasing can change byte lengths, so
+        // offsets into a lowercased copy may not fall on `line`'s boundaries.
+        let Some(start) = line
+            .as_bytes()
+            .windows(MARKER.len())
+            .position(|window| window.eq_ignore_ascii_case(MARKER.as_bytes()))
+        else {
+            continue;
+        };
+        let rest = &line[start + MARKER.len()..];
+        for token in rest.split(|c: char| c.is_whitespace() || c == ',') {
+            let token = token.trim_matches(|c: char| matches!(c, '.' | ';' | ':' | '*'));
+            if token.is_empty() || token.eq_ignore_ascii_case("and") {
+                continue;
+            }
+            match token.strip_prefix('#') {
+                Some(n) if !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()) => {
+                    if !out.iter().any(|seen| seen == n) {
+                        out.push(n.to_string());
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+    out
+}
+
+/// Links derived from the issue body, before native relationships are known.
+pub(super) fn body_links(body: Option<&str>) -> Vec<ItemLink> {
+    body.map(body_dependencies)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|target_id| ItemLink {
+            link_type: link::DEPENDS_ON.into(),
+            target_id,
+            target_title: None,
+        })
+        .collect()
+}
+
+/// Merge native relationships into `doc.links`, keeping body-convention
+/// links and filling in titles where the native data has them.
+fn merge_links(doc: &mut SpecDoc, relations: &Relations) {
+    let mut links = relations.links();
+    for existing in doc.links.drain(..) {
+        if !links
+            .iter()
+            .any(|l| l.link_type == existing.link_type && l.target_id == existing.target_id)
+        {
+            links.push(existing);
+        }
+    }
+    doc.links = links;
+}
+
+/// GraphQL endpoint for a REST base URL: `https://api.github.com/graphql`,
+/// or `https://host/api/graphql` for GitHub Enterprise's `/api/v3`.
+pub(super) fn graphql_url(base_url: &str) -> String {
+    let base = base_url.trim_end_matches('/');
+    match base.strip_suffix("/api/v3") {
+        Some(host) => format!("{host}/api/graphql"),
+        None => format!("{base}/graphql"),
+    }
+}
+
+/// One aliased `issue(number:)` selection per number.
+fn relations_query(numbers: &[&str]) -> String {
+    let linked = "number title databaseId";
+    let selections: String = numbers
+        .iter()
+        .map(|n| {
+            format!(
+                "i{n}: issue(number: {n}) {{ parent {{ {linked} }} \
+                 subIssues(first: {RELATIONS_PER_ISSUE}) {{ nodes {{ {linked} }} }} \
+                 blockedBy(first: {RELATIONS_PER_ISSUE}) {{ nodes {{ {linked} }} }} }} "
+            )
+        })
+        .collect();
+    format!(
+        "query($owner: String!, $name: String!) {{ \
+         repository(owner: $owner, name: $name) {{ {selections}}} }}"
+    )
+}
+
+fn parse_linked(node: &Value) -> Option<LinkedIssue> {
+    Some(LinkedIssue {
+        number: node.get("number")?.as_u64()?.to_string(),
+        title: node.get("title").and_then(|v| v.as_str()).map(String::from),
+        database_id: node.get("databaseId").and_then(|v| v.as_u64()),
+    })
+}
+
+fn parse_nodes(connection: Option<&Value>) -> Vec<LinkedIssue> {
+    connection
+        .and_then(|c| c.get("nodes"))
+        .and_then(|n| n.as_array())
+        .map(|nodes| nodes.iter().filter_map(parse_linked).collect())
+        .unwrap_or_default()
+}
+
+/// Read the relationships of each requested issue out of a GraphQL
+/// response. Issues GitHub could not resolve are left out.
+fn parse_relations(response: &Value, numbers: &[&str]) -> HashMap<String, Relations> {
+    let repository = response.pointer("/data/repository");
+    numbers
+        .iter()
+        .filter_map(|n| {
+            let issue = repository?.get(format!("i{n}"))?;
+            if issue.is_null() {
+                return None;
+            }
+            let relations = Relations {
+                parent: issue.get("parent").and_then(parse_linked),
+                sub_issues: parse_nodes(issue.get("subIssues")),
+                blocked_by: parse_nodes(issue.get("blockedBy")),
+            };
+            Some((n.to_string(), relations))
+        })
+        .collect()
+}
+
+/// Errors that mean "this server can't tell us about relationships" rather
+/// than "this request is broken".
+pub(super) fn is_unsupported(err: &AdapterError) -> bool {
+    matches!(
+        err,
+        AdapterError::Unavailable { .. }
+            | AdapterError::BackendError { .. }
+            | AdapterError::NotFound(_)
+            | AdapterError::ParseError { .. }
+    )
+}
+
+fn invalid_link(reason: String) -> AdapterError {
+    AdapterError::InvalidField {
+        adapter: ADAPTER_NAME.into(),
+        reason,
+    }
+}
+
+/// Links requested for one issue, validated and grouped by kind before
+/// anything is written.
+#[derive(Debug, Default)]
+pub(super) struct LinkPlan<'a> {
+    parent: Option<&'a str>,
+    children: Vec<&'a str>,
+    depends_on: Vec<&'a str>,
+}
+
+impl<'a> LinkPlan<'a> {
+    /// Validate `links` for issue `number` (empty when the issue is about to
+    /// be created).
+    pub(super) fn new(number: &str, links: &'a [ItemLink]) -> Result<Self, AdapterError> {
+        let mut plan = LinkPlan::default();
+        for l in links {
+            let target = l.target_id.as_str();
+            if target.is_empty() || !target.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid_link(format!(
+                    "link target must be an issue number, got '{target}'"
+                )));
+            }
+            if target == number {
+                return Err(invalid_link(format!(
+                    "issue #{number} cannot link to itself"
+                )));
+            }
+            let bucket = match l.link_type.as_str() {
+                link::PARENT => {
+                    if plan.parent.is_some_and(|p| p != target) {
+                        return Err(invalid_link("an issue can only have one parent".into()));
+                    }
+                    plan.parent = Some(target);
+                    continue;
+                }
+                link::CHILD => &mut plan.children,
+                link::DEPENDS_ON => &mut plan.depends_on,
+                other => {
+                    return Err(invalid_link(format!(
+                        "unknown link type '{other}' — expected {}, {} or {}",
+                        link::PARENT,
+                        link::CHILD,
+                        link::DEPENDS_ON
+                    )))
+                }
+            };
+            if !bucket.contains(&target) {
+                bucket.push(target);
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Reject a plan for issue `number` that drops a dependency declared in
+    /// its `body`, which [`GitHubAdapter::apply_links`] cannot remove.
+    pub(super) fn check_body(&self, number: &str, body: &str) -> Result<(), AdapterError> {
+        let dropped: Vec<String> = body_dependencies(body)
+            .into_iter()
+            .filter(|n| !self.depends_on.contains(&n.as_str()))
+            .map(|n| format!("#{n}"))
+            .collect();
+        if dropped.is_empty() {
+            return Ok(());
+        }
+        Err(invalid_link(format!(
+            "issue #{number} depends on {} through a 'Depends on' line in its body; \
+             edit the body to remove the dependency",
+            dropped.join(", ")
+        )))
+    }
+}
+
+impl GitHubAdapter {
+    /// Fetch native relationships for `numbers`, keyed by issue number.
+    pub(super) async fn fetch_relations(
+        &self,
+        numbers: &[&str],
+    ) -> Result<HashMap<String, Relations>, AdapterError> {
+        let url = graphql_url(&self.base_url);
+        let mut out = HashMap::new();
+        for batch in numbers.chunks(RELATIONS_BATCH) {
+            let body = json!({
+                "query": relations_query(batch),
+                "variables": { "owner": self.owner, "name": self.repo },
+            });
+            let resp = send(self.request(Method::POST, &url).json(&body)).await?;
+            let value = Self::parse_json(resp).await?;
+            if value
+                .pointer("/data/repository")
+                .map_or(true, Value::is_null)
+            {
+                let reason = value
+                    .pointer("/errors/0/message")
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("no repository data in GraphQL response");
+                return Err(AdapterError::BackendError {
+                    adapter: ADAPTER_NAME.into(),
+                    reason: format!("relationship query failed: {reason}"),
+                });
+            }
+            out.extend(parse_relations(&value, batch));
+        }
+        Ok(out)
+    }
+
+    /// Add native relationships to `docs`. See the module docs for when
+    /// failures are tolerated.
+    pub(super) async fn attach_links(&self, docs: &mut [SpecDoc]) -> Result<(), AdapterError> {
+        let numbers: Vec<&str> = docs
+            .iter()
+            .map(|d| d.id.as_str())
+            .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+            .collect();
+        if numbers.is_empty() {
+            return Ok(());
+        }
+        let relations = match self.fetch_relations(&numbers).await {
+            Ok(relations) => relations,
+            Err(e) if is_unsupported(&e) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        for doc in docs.iter_mut() {
+            if let Some(r) = relations.get(&doc.id) {
+                merge_links(doc, r);
+            }
+        }
+        Ok(())
+    }
+
+    async fn fetch_issue(&self, number: &str) -> Result<Value, AdapterError> {
+        let url = self.url(&format!("{}/{}", self.issues_path(), number));
+        let resp = send(self.request(Method::GET, &url))
+            .await
+            .map_err(|e| with_not_found_id(e, number))?;
+        Self::parse_json(resp).await
+    }
+
+    /// Current body of an issue, empty when it has none.
+    pub(super) async fn issue_body(&self, number: &str) -> Result<String, AdapterError> {
+        let value = self.fetch_issue(number).await?;
+        Ok(value
+            .get("body")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string())
+    }
+
+    /// Database id of an issue, which the sub-issue and dependency
+    /// endpoints take instead of the issue number.
+    async fn issue_database_id(&self, number: &str) -> Result<u64, AdapterError> {
+        let value = self.fetch_issue(number).await?;
+        value
+            .get("id")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| AdapterError::ParseError {
+                path: format!("github issue {number}"),
+                reason: "missing 'id' field".into(),
+            })
+    }
+
+    async fn linked_database_id(&self, issue: &LinkedIssue) -> Result<u64, AdapterError> {
+        match issue.database_id {
+            Some(id) => Ok(id),
+            None => self.issue_database_id(&issue.number).await,
+        }
+    }
+
+    /// Make issue `number`'s relationships match `plan`.
+    ///
+    /// The parent, sub-issues and native dependencies are replaced. Body
+    /// dependencies are left alone; see [`LinkPlan::check_body`].
+    pub(super) async fn apply_links(
+        &self,
+        number: &str,
+        plan: &LinkPlan<'_>,
+    ) -> Result<(), AdapterError> {
+        let LinkPlan {
+            parent,
+            children,
+            depends_on,
+        } = plan;
+        let parent = *parent;
+
+        let current = self
+            .fetch_relations(&[number])
+            .await?
+            .remove(number)
+            .ok_or_else(|| AdapterError::NotFound(number.to_string()))?;
+        let own_id = self.issue_database_id(number).await?;
+        let issue_path =
+            |n: &str, rest: &str| self.url(&format!("{}/{n}/{rest}", self.issues_path()));
+
+        let current_parent = current.parent.as_ref().map(|p| p.number.as_str());
+        if current_parent != parent {
+            match (parent, current_parent) {
+                (Some(new), _) => {
+                    let body = json!({ "sub_issue_id": own_id, "replace_parent": true });
+                    send(
+                        self.request(Method::POST, &issue_path(new, "sub_issues"))
+                            .json(&body),
+                    )
+                    .await
+                    .map_err(|e| with_not_found_id(e, new))?;
+                }
+                (None, Some(old)) => {
+                    let body = json!({ "sub_issue_id": own_id });
+                    send(
+                        self.request(Method::DELETE, &issue_path(old, "sub_issue"))
+                            .json(&body),
+                    )
+                    .await?;
+                }
+                (None, None) => {}
+            }
+        }
+
+        for child in children {
+            if current.sub_issues.iter().any(|s| s.number == *child) {
+                continue;
+            }
+            let child_id = self.issue_database_id(child).await?;
+            let body = json!({ "sub_issue_id": child_id, "replace_parent": true });
+            send(
+                self.request(Method::POST, &issue_path(number, "sub_issues"))
+                    .json(&body),
+            )
+            .await?;
+        }
+        for sub_issue in &current.sub_issues {
+            if children.contains(&sub_issue.number.as_str()) {
+                continue;
+            }
+            let sub_issue_id = self.linked_database_id(sub_issue).await?;
+            send(
+                self.request(Method::DELETE, &issue_path(number, "sub_issue"))
+                    .json(&json!({ "sub_issue_id": sub_issue_id })),
+            )
+            .await?;
+        }
+
+        for target in depends_on {
+            if current.blocked_by.iter().any(|b| b.number == *target) {
+                continue;
+            }
+            let target_id = self.issue_database_id(target).await?;
+            send(
+                self.request(Method::POST, &issue_path(number, "dependencies/blocked_by"))
+                    .json(&json!({ "issue_id": target_id })),
+            )
+            .await?;
+        }
+        for blocker in &current.blocked_by {
+            if depends_on.contains(&blocker.number.as_str()) {
+                continue;
+            }
+            let blocker_id = self.linked_database_id(blocker).await?;
+            send(self.request(
+                Method::DELETE,
+                &issue_path(number, &format!("dependencies/blocked_by/{blocker_id}")),
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn body_dependencies_reads_issue_references() {
+        let body =
+            "Intro\n\nDepends on #12, #15 and #20.\n**Depends on:** #15\ndepends on the API (#99)";
+        assert_eq!(body_dependencies(body), vec!["12", "15", "20"]);
+        assert!(body_dependencies("Nothing here").is_empty());
+    }
+
+    #[test]
+    fn body_dependencies_handles_non_ascii_lines() {
+        assert_eq!(body_dependencies("İİİİ depends on #7"), vec!["7"]);
+        assert_eq!(
+            body_dependencies("Ünïcödé DEPENDS ON #8 and #9"),
+            vec!["8", "9"]
+        );
+        assert!(body_dependencies("İİİİİİİİİİİİ depends").is_empty());
+    }
+
+    #[test]
+    fn graphql_url_handles_enterprise_hosts() {
+        assert_eq!(
+            graphql_url("https://api.github.com"),
+            "https://api.github.com/graphql"
+        );
+        assert_eq!(
+            graphql_url("https://github.acme.corp/api/v3/"),
+            "https://github.acme.corp/api/graphql"
+        );
+    }
+
+    #[test]
+    fn parse_relations_skips_unresolved_issues() {
+        let response = json!({
+            "data": { "repository": {
+                "i1": {
+                    "parent": { "number": 9, "title": "Epic", "databaseId": 900 },
+                    "subIssues": { "nodes": [] },
+                    "blockedBy": { "nodes": [{ "number": 3, "title": "Schema", "databaseId": 300 }] }
+                },
+                "i2": null
+            }}
+        });
+        let relations = parse_relations(&response, &["1", "2"]);
+        assert_eq!(relations.len(), 1);
+        let links = relations["1"].links();
+        assert_eq!(links[0].link_type, link::PARENT);
+        assert_eq!(links[0].target_id, "9");
+        assert_eq!(links[1].link_type, link::DEPENDS_ON);
+        assert_eq!(links[1].target_title.as_deref(), Some("Schema"));
+    }
+}
@@ -19,6 +19,28 @@
 //! enum options. Labels prefixed with `priority:` (e.g. `priority:high`) feed
 //! the `priority` field; all other labels become `tags` options.
 //!
+//! ## Async
+//!
+//! The adapter is natively async: [`AsyncAdapter`](super::AsyncAdapter) calls
+//! share one pooled `reqwest` client and fetch listing pages concurrently.
+//! The blocking [`Adapter`] impl drives the same code on a small internal
+//! runtime.
+//!
+//! ## Conditional requests
+//!
+//! `list_conditional` / `get_conditional` send `If-None-Match` with the ETag
+//! captured on the previous fetch. A listing is revalidated with a one-item
+//! probe of the most recently updated issue: any create, edit, close or
+//! relabel changes it. GitHub does not count `304 Not Modified` responses
+//! against the rate limit, so a [`CachingAdapter`](super::CachingAdapter)
+//! in front of this adapter polls cheaply.
+//!
+//! ## Links
+//!
+//! Sub-issues map to `parent` links and issue dependencies ("blocked by") to
+//! `depends_on` links, with a `Depends on #N` body convention as a fallback.
+//! See [`links`] for how they are read and written.
+//!
 //! ## Delete semantics
 //!
 //! GitHub has no hard-delete for issues. [`GitHubAdapter::delete`] closes the
@@ -29,15 +51,26 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use chrono::{DateTime, TimeZone, Utc};
-use reqwest::blocking::{Client, RequestBuilder, Response};
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, LINK, USER_AGENT};
-use reqwest::{Method, StatusCode};
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, ETAG, IF_NONE_MATCH, LINK, USER_AGENT,
+};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode, Url};
 use serde_json::{json, Value};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+mod comments;
+mod links;
 
-use super::{Adapter, AdapterCapabilities, AdapterError, ListFilter, SearchHit, SearchOptions};
+use super::async_adapter::{block_on, AdapterFuture};
+use super::retry::parse_retry_after;
+use super::{
+    Adapter, AdapterCapabilities, AdapterError, ListFilter, Revalidated, SearchHit, SearchOptions,
+    Validator,
+};
 use crate::model::{
-    semantic, CreateRequest, EnumOption, FieldDef, FieldDisplay, FieldKind, FieldValue,
-    LinkTypeDef, SpecDoc, SpecSchema, UpdateRequest,
+    semantic, Comment, CommentRequest, CreateRequest, EnumOption, FieldDef, FieldDisplay,
+    FieldKind, FieldValue, LinkTypeDef, SpecDoc, SpecSchema, UpdateRequest,
 };
 
 /// Adapter name used in errors and capabilities.
@@ -56,6 +89,10 @@ const DEFAULT_PAGE_SIZE: u32 = 100;
 /// capped by the caller.
 const DEFAULT_LIST_LIMIT: usize = 1000;
 
+/// Maximum number of issue pages fetched in parallel. Kept low so large
+/// listings don't trip GitHub's secondary rate limits.
+const PAGE_CONCURRENCY: usize = 4;
+
 /// Metadata field keys declared by the GitHub adapter schema.
 pub mod field {
     pub const STATUS: &str = "status";
@@ -68,6 +105,8 @@ pub mod field {
 
 /// Link type keys declared by the GitHub adapter schema.
 pub mod link {
+    pub const PARENT: &str = "parent";
+    pub const CHILD: &str = "child";
     pub const DEPENDS_ON: &str = "depends_on";
 }
 
@@ -177,12 +216,26 @@ fn build_schema() -> SpecSchema {
                 placeholder: None,
             },
         ],
-        link_types: vec![LinkTypeDef {
-            key: link::DEPENDS_ON.into(),
-            label: "Depends on".into(),
-            inverse_key: Some("blocked_by".into()),
-            inverse_label: Some("Blocked by".into()),
-        }],
+        link_types: vec![
+            LinkTypeDef {
+                key: link::PARENT.into(),
+                label: "Parent".into(),
+                inverse_key: Some(link::CHILD.into()),
+                inverse_label: Some("Sub-issue".into()),
+            },
+            LinkTypeDef {
+                key: link::CHILD.into(),
+                label: "Sub-issue".into(),
+                inverse_key: Some(link::PARENT.into()),
+                inverse_label: Some("Parent".into()),
+            },
+            LinkTypeDef {
+                key: link::DEPENDS_ON.into(),
+                label: "Depends on".into(),
+                inverse_key: Some("blocked_by".into()),
+                inverse_label: Some("Blocked by".into()),
+            },
+        ],
     }
 }
 
@@ -195,7 +248,9 @@ fn build_capabilities() -> AdapterCapabilities {
         supports_delete: true,
         supports_search: true,
         supports_webhooks: false,
+        supports_comments: true,
         default_schema: SCHEMA_ID.into(),
+        cache: None,
     }
 }
 
@@ -290,10 +345,16 @@ impl GitHubAdapter {
     /// own schema. Invoked by [`AdapterRegistry::create`] so callers that only
     /// call `adapter.schema()` see the dynamically resolved options.
     pub fn resolve_inline(&mut self) -> Result<(), AdapterError> {
+        block_on(self.resolve_inline_async())
+    }
+
+    /// Async form of [`Self::resolve_inline`], used by
+    /// [`AdapterRegistry::create_async`].
+    pub async fn resolve_inline_async(&mut self) -> Result<(), AdapterError> {
         let mut schema = std::mem::replace(&mut self.schema, build_schema());
-        self.resolve_schema(&mut schema)?;
+        let resolved = super::AsyncAdapter::resolve_schema(&*self, &mut schema).await;
         self.schema = schema;
-        Ok(())
+        resolved
     }
 
     fn auth_headers(&self) -> HeaderMap {
@@ -323,58 +384,150 @@ impl GitHubAdapter {
             .headers(self.auth_headers())
     }
 
-    /// Send a request and map HTTP errors onto [`AdapterError`].
-    fn send(&self, req: RequestBuilder) -> Result<Response, AdapterError> {
-        let resp = req.send().map_err(|e| AdapterError::BackendError {
-            adapter: ADAPTER_NAME.into(),
-            reason: format!("network: {e}"),
-        })?;
-
-        let status = resp.status();
-        if status.is_success() {
-            return Ok(resp);
-        }
-
-        // Capture body and headers before consuming the response.
-        let headers = resp.headers().clone();
-        let body = resp.text().unwrap_or_default();
-        Err(map_error(status, &headers, &body))
-    }
-
-    fn parse_json(resp: Response) -> Result<Value, AdapterError> {
-        resp.json().map_err(|e| AdapterError::ParseError {
+    async fn parse_json(resp: Response) -> Result<Value, AdapterError> {
+        resp.json().await.map_err(|e| AdapterError::ParseError {
             path: "github response".into(),
             reason: e.to_string(),
         })
     }
 
-    /// Fetch every page reachable via the `Link: <…>; rel="next"` header,
-    /// stopping when `limit` items have been collected.
-    fn paginate_issues(&self, url: &str, limit: usize) -> Result<Vec<Value>, AdapterError> {
+    /// Fetch every page of an issue listing, stopping when `limit` items have
+    /// been collected.
+    ///
+    /// When the first response advertises a `rel="last"` page, the remaining
+    /// pages are fetched concurrently (at most [`PAGE_CONCURRENCY`] at a
+    /// time); otherwise the `rel="next"` chain is followed sequentially.
+    async fn paginate_issues(&self, url: &str, limit: usize) -> Result<Vec<Value>, AdapterError> {
         let mut out: Vec<Value> = Vec::new();
-        let mut next: Option<String> = Some(url.to_string());
+        let resp = send(self.request(Method::GET, url)).await?;
+        let link = resp.headers().get(LINK).cloned();
+        let first = Self::parse_json(resp).await?;
+        if push_issues(&mut out, first, limit) {
+            return Ok(out);
+        }
+
+        let remaining = parse_last_page(link.as_ref()).and_then(|(template, last)| {
+            page_urls(&template, last, limit.saturating_sub(out.len()))
+        });
+        if let Some(urls) = remaining {
+            for page in self.fetch_pages(urls).await? {
+                if push_issues(&mut out, page, limit) {
+                    break;
+                }
+            }
+            return Ok(out);
+        }
 
+        let mut next = parse_next_link(link.as_ref());
         while let Some(u) = next.take() {
-            let resp = self.send(self.request(Method::GET, &u))?;
+            let resp = send(self.request(Method::GET, &u)).await?;
             let link_next = parse_next_link(resp.headers().get(LINK));
-            let value: Value = Self::parse_json(resp)?;
-            let arr = value.as_array().cloned().unwrap_or_default();
-            for item in arr {
-                if out.len() >= limit {
-                    return Ok(out);
-                }
-                // `/repos/.../issues` returns both issues and pull requests.
-                // Pull requests carry a `pull_request` key — skip them.
-                if item.get("pull_request").is_some() {
-                    continue;
-                }
-                out.push(item);
+            let value = Self::parse_json(resp).await?;
+            if push_issues(&mut out, value, limit) {
+                break;
             }
             next = link_next;
         }
 
         Ok(out)
     }
+
+    /// Fetch `urls` concurrently over the shared connection pool, returning
+    /// the decoded pages in request order.
+    async fn fetch_pages(&self, urls: Vec<String>) -> Result<Vec<Value>, AdapterError> {
+        let permits = std::sync::Arc::new(Semaphore::new(PAGE_CONCURRENCY));
+        let mut tasks = JoinSet::new();
+        for (index, url) in urls.into_iter().enumerate() {
+            let req = self.request(Method::GET, &url);
+            let permits = permits.clone();
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                let resp = send(req).await?;
+                Ok::<_, AdapterError>((index, Self::parse_json(resp).await?))
+            });
+        }
+
+        let mut pages: Vec<(usize, Value)> = Vec::with_capacity(tasks.len());
+        while let Some(joined) = tasks.join_next().await {
+            let page = joined.map_err(|e| AdapterError::BackendError {
+                adapter: ADAPTER_NAME.into(),
+                reason: format!("page fetch failed: {e}"),
+            })??;
+            pages.push(page);
+        }
+        pages.sort_by_key(|(index, _)| *index);
+        Ok(pages.into_iter().map(|(_, page)| page).collect())
+    }
+}
+
+/// Send a request and map HTTP errors onto [`AdapterError`].
+async fn send(req: RequestBuilder) -> Result<Response, AdapterError> {
+    let resp = req.send().await.map_err(network_error)?;
+    check_status(resp).await
+}
+
+/// Send a request with `If-None-Match: etag`. Returns `None` on
+/// `304 Not Modified`.
+async fn send_conditional(
+    req: RequestBuilder,
+    etag: Option<&str>,
+) -> Result<Option<Response>, AdapterError> {
+    let req = match etag {
+        Some(etag) => req.header(IF_NONE_MATCH, etag),
+        None => req,
+    };
+    let resp = req.send().await.map_err(network_error)?;
+    if resp.status() == StatusCode::NOT_MODIFIED {
+        return Ok(None);
+    }
+    check_status(resp).await.map(Some)
+}
+
+fn network_error(err: reqwest::Error) -> AdapterError {
+    AdapterError::Unavailable {
+        adapter: ADAPTER_NAME.into(),
+        reason: format!("network: {err}"),
+        retry_after: None,
+    }
+}
+
+fn etag_of(resp: &Response) -> Option<String> {
+    resp.headers()
+        .get(ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+async fn check_status(resp: Response) -> Result<Response, AdapterError> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+
+    // Capture body and headers before consuming the response.
+    let headers = resp.headers().clone();
+    let body = resp.text().await.unwrap_or_default();
+    Err(map_error(status, &headers, &body))
+}
+
+/// Append the issues from one listing page to `out`. Returns `true` once
+/// `limit` items have been collected.
+fn push_issues(out: &mut Vec<Value>, page: Value, limit: usize) -> bool {
+    let Value::Array(items) = page else {
+        return out.len() >= limit;
+    };
+    for item in items {
+        if out.len() >= limit {
+            return true;
+        }
+        // `/repos/.../issues` returns both issues and pull requests.
+        // Pull requests carry a `pull_request` key — skip them.
+        if item.get("pull_request").is_some() {
+            continue;
+        }
+        out.push(item);
+    }
+    out.len() >= limit
 }
 
 impl Adapter for GitHubAdapter {
@@ -387,326 +540,549 @@ impl Adapter for GitHubAdapter {
     }
 
     fn resolve_schema(&self, schema: &mut SpecSchema) -> Result<(), AdapterError> {
-        let url = self.url(&format!(
-            "/repos/{}/{}/labels?per_page={}",
-            self.owner, self.repo, DEFAULT_PAGE_SIZE
-        ));
-        let mut tag_options: Vec<EnumOption> = Vec::new();
-        let mut priority_options: Vec<EnumOption> = Vec::new();
-        let mut next = Some(url);
+        block_on(super::AsyncAdapter::resolve_schema(self, schema))
+    }
 
-        while let Some(u) = next.take() {
-            let resp = self.send(self.request(Method::GET, &u))?;
-            let link_next = parse_next_link(resp.headers().get(LINK));
-            let value: Value = Self::parse_json(resp)?;
-            for item in value.as_array().cloned().unwrap_or_default() {
-                let Some(name) = item.get("name").and_then(|v| v.as_str()) else {
-                    continue;
-                };
-                let color = item
-                    .get("color")
-                    .and_then(|v| v.as_str())
-                    .map(|c| format!("#{c}"));
-                let description = item
-                    .get("description")
-                    .and_then(|v| v.as_str())
-                    .map(String::from);
-
-                if let Some(rest) = name.strip_prefix(PRIORITY_LABEL_PREFIX) {
-                    priority_options.push(EnumOption {
-                        value: rest.to_string(),
-                        label: rest.to_string(),
-                        color: color.clone(),
-                        icon: None,
-                        description: description.clone(),
-                    });
-                } else {
-                    tag_options.push(EnumOption {
-                        value: name.to_string(),
-                        label: name.to_string(),
-                        color: color.clone(),
-                        icon: None,
-                        description: description.clone(),
-                    });
+    fn list(&self, filter: &ListFilter) -> Result<Vec<SpecDoc>, AdapterError> {
+        block_on(super::AsyncAdapter::list(self, filter))
+    }
+
+    fn get(&self, id: &str) -> Result<SpecDoc, AdapterError> {
+        block_on(super::AsyncAdapter::get(self, id))
+    }
+
+    fn create(&self, req: &CreateRequest) -> Result<SpecDoc, AdapterError> {
+        block_on(super::AsyncAdapter::create(self, req))
+    }
+
+    fn update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
+        block_on(super::AsyncAdapter::update(self, id, req))
+    }
+
+    fn delete(&self, id: &str) -> Result<(), AdapterError> {
+        block_on(super::AsyncAdapter::delete(self, id))
+    }
+
+    fn search(&self, query: &str, opts: &SearchOptions) -> Result<Vec<SearchHit>, AdapterError> {
+        block_on(super::AsyncAdapter::search(self, query, opts))
+    }
+
+    fn list_conditional(
+        &self,
+        filter: &ListFilter,
+        previous: Option<&Validator>,
+    ) -> Result<Revalidated<Vec<SpecDoc>>, AdapterError> {
+        block_on(super::AsyncAdapter::list_conditional(
+            self, filter, previous,
+        ))
+    }
+
+    fn get_conditional(
+        &self,
+        id: &str,
+        previous: Option<&Validator>,
+    ) -> Result<Revalidated<SpecDoc>, AdapterError> {
+        block_on(super::AsyncAdapter::get_conditional(self, id, previous))
+    }
+
+    fn list_comments(&self, id: &str) -> Result<Vec<Comment>, AdapterError> {
+        block_on(super::AsyncAdapter::list_comments(self, id))
+    }
+
+    fn add_comment(&self, id: &str, req: &CommentRequest) -> Result<Comment, AdapterError> {
+        block_on(super::AsyncAdapter::add_comment(self, id, req))
+    }
+
+    fn edit_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment, AdapterError> {
+        block_on(super::AsyncAdapter::edit_comment(
+            self, id, comment_id, body,
+        ))
+    }
+
+    fn resolve_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        resolved: bool,
+    ) -> Result<Comment, AdapterError> {
+        block_on(super::AsyncAdapter::resolve_comment(
+            self, id, comment_id, resolved,
+        ))
+    }
+}
+
+impl super::AsyncAdapter for GitHubAdapter {
+    fn capabilities(&self) -> &AdapterCapabilities {
+        &self.capabilities
+    }
+
+    fn schema(&self) -> &SpecSchema {
+        &self.schema
+    }
+
+    fn resolve_schema<'a>(&'a self, schema: &'a mut SpecSchema) -> AdapterFuture<'a, ()> {
+        Box::pin(async move {
+            let url = self.url(&format!(
+                "/repos/{}/{}/labels?per_page={}",
+                self.owner, self.repo, DEFAULT_PAGE_SIZE
+            ));
+            let mut tag_options: Vec<EnumOption> = Vec::new();
+            let mut priority_options: Vec<EnumOption> = Vec::new();
+            let mut next = Some(url);
+
+            while let Some(u) = next.take() {
+                let resp = send(self.request(Method::GET, &u)).await?;
+                let link_next = parse_next_link(resp.headers().get(LINK));
+                let value: Value = Self::parse_json(resp).await?;
+                for item in value.as_array().cloned().unwrap_or_default() {
+                    let Some(name) = item.get("name").and_then(|v| v.as_str()) else {
+                        continue;
+                    };
+                    let color = item
+                        .get("color")
+                        .and_then(|v| v.as_str())
+                        .map(|c| format!("#{c}"));
+                    let description = item
+                        .get("description")
+                        .and_then(|v| v.as_str())
+                        .map(String::from);
+
+                    if let Some(rest) = name.strip_prefix(PRIORITY_LABEL_PREFIX) {
+                        priority_options.push(EnumOption {
+                            value: rest.to_string(),
+                            label: rest.to_string(),
+                            color: color.clone(),
+                            icon: None,
+                            description: description.clone(),
+                        });
+                    } else {
+                        tag_options.push(EnumOption {
+                            value: name.to_string(),
+                            label: name.to_string(),
+                            color: color.clone(),
+                            icon: None,
+                            description: description.clone(),
+                        });
+                    }
                 }
+                next = link_next;
             }
-            next = link_next;
-        }
 
-        // GitHub does not guarantee label order. Sort by value so the resolved
-        // schema is deterministic — capabilities output and UI diffs stay
-        // stable across runs.
-        tag_options.sort_by(|a, b| a.value.cmp(&b.value));
-        priority_options.sort_by(|a, b| a.value.cmp(&b.value));
-
-        for f in schema.fields.iter_mut() {
-            if let FieldKind::Enum { options, .. } = &mut f.kind {
-                if f.key == field::TAGS {
-                    *options = tag_options.clone();
-                } else if f.key == field::PRIORITY {
-                    *options = priority_options.clone();
+            // GitHub does not guarantee label order. Sort by value so the resolved
+            // schema is deterministic — capabilities output and UI diffs stay
+            // stable across runs.
+            tag_options.sort_by(|a, b| a.value.cmp(&b.value));
+            priority_options.sort_by(|a, b| a.value.cmp(&b.value));
+
+            for f in schema.fields.iter_mut() {
+                if let FieldKind::Enum { options, .. } = &mut f.kind {
+                    if f.key == field::TAGS {
+                        *options = tag_options.clone();
+                    } else if f.key == field::PRIORITY {
+                        *options = priority_options.clone();
+                    }
                 }
             }
-        }
 
-        Ok(())
+            Ok(())
+        })
     }
 
-    fn list(&self, filter: &ListFilter) -> Result<Vec<SpecDoc>, AdapterError> {
-        let mut req = self.request(Method::GET, &self.url(&self.issues_path()));
-
-        let mut query: Vec<(String, String)> = Vec::new();
-        query.push(("per_page".into(), DEFAULT_PAGE_SIZE.to_string()));
-
-        // Status filter (open/closed/all).
-        let state = filter
-            .fields
-            .get(field::STATUS)
-            .and_then(|v| v.first())
-            .cloned()
-            .unwrap_or_else(|| {
-                if filter.include_archived {
-                    "all".into()
-                } else {
-                    "open".into()
+    fn list<'a>(&'a self, filter: &'a ListFilter) -> AdapterFuture<'a, Vec<SpecDoc>> {
+        Box::pin(async move {
+            let mut req = self.request(Method::GET, &self.url(&self.issues_path()));
+
+            let mut query: Vec<(String, String)> = Vec::new();
+            query.push(("per_page".into(), DEFAULT_PAGE_SIZE.to_string()));
+
+            // Status filter (open/closed/all).
+            let state = filter
+                .fields
+                .get(field::STATUS)
+                .and_then(|v| v.first())
+                .cloned()
+                .unwrap_or_else(|| {
+                    if filter.include_archived {
+                        "all".into()
+                    } else {
+                        "open".into()
+                    }
+                });
+            query.push(("state".into(), state));
+
+            if let Some(labels) = filter.fields.get(field::TAGS) {
+                if !labels.is_empty() {
+                    query.push(("labels".into(), labels.join(",")));
                 }
-            });
-        query.push(("state".into(), state));
-
-        if let Some(labels) = filter.fields.get(field::TAGS) {
-            if !labels.is_empty() {
-                query.push(("labels".into(), labels.join(",")));
             }
-        }
-        if let Some(assignees) = filter.fields.get(field::ASSIGNEE) {
-            if let Some(first) = assignees.first() {
-                query.push(("assignee".into(), first.clone()));
+            if let Some(assignees) = filter.fields.get(field::ASSIGNEE) {
+                if let Some(first) = assignees.first() {
+                    query.push(("assignee".into(), first.clone()));
+                }
             }
-        }
 
-        req = req.query(&query);
-        let url = req
-            .try_clone()
-            .and_then(|r| r.build().ok())
-            .map(|r| r.url().to_string())
-            .unwrap_or_else(|| self.url(&self.issues_path()));
-
-        let issues = self.paginate_issues(&url, DEFAULT_LIST_LIMIT)?;
-        let mut docs: Vec<SpecDoc> = issues.iter().map(issue_to_doc).collect();
-
-        // Free-text filter is applied client-side; GitHub's `q=` lives behind
-        // a different endpoint (`/search/issues`) which `search()` uses.
-        if let Some(ref text) = filter.text {
-            let needle = text.to_lowercase();
-            docs.retain(|d| {
-                d.title.to_lowercase().contains(&needle)
-                    || d.id.to_lowercase().contains(&needle)
-                    || d.fields
-                        .get(field::CONTENT)
-                        .and_then(|v| v.as_str())
-                        .map(|c| c.to_lowercase().contains(&needle))
-                        .unwrap_or(false)
-            });
-        }
+            req = req.query(&query);
+            let url = req
+                .try_clone()
+                .and_then(|r| r.build().ok())
+                .map(|r| r.url().to_string())
+                .unwrap_or_else(|| self.url(&self.issues_path()));
+
+            let issues = self.paginate_issues(&url, DEFAULT_LIST_LIMIT).await?;
+            let mut docs: Vec<SpecDoc> = issues.iter().map(issue_to_doc).collect();
+            self.attach_links(&mut docs).await?;
+
+            // Free-text filter is applied client-side; GitHub's `q=` lives behind
+            // a different endpoint (`/search/issues`) which `search()` uses.
+            if let Some(ref text) = filter.text {
+                let needle = text.to_lowercase();
+                docs.retain(|d| {
+                    d.title.to_lowercase().contains(&needle)
+                        || d.id.to_lowercase().contains(&needle)
+                        || d.fields
+                            .get(field::CONTENT)
+                            .and_then(|v| v.as_str())
+                            .map(|c| c.to_lowercase().contains(&needle))
+                            .unwrap_or(false)
+                });
+            }
 
-        Ok(docs)
+            Ok(docs)
+        })
     }
 
-    fn get(&self, id: &str) -> Result<SpecDoc, AdapterError> {
-        let url = self.url(&format!("{}/{}", self.issues_path(), id));
-        let resp = self
-            .send(self.request(Method::GET, &url))
-            .map_err(|e| with_not_found_id(e, id))?;
-        let value = Self::parse_json(resp)?;
-        Ok(issue_to_doc(&value))
+    fn get<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, SpecDoc> {
+        Box::pin(async move {
+            let url = self.url(&format!("{}/{}", self.issues_path(), id));
+            let resp = send(self.request(Method::GET, &url))
+                .await
+                .map_err(|e| with_not_found_id(e, id))?;
+            let value = Self::parse_json(resp).await?;
+            self.doc_with_links(&value).await
+        })
     }
 
-    fn create(&self, req: &CreateRequest) -> Result<SpecDoc, AdapterError> {
-        if let Some(ref id) = req.schema_id {
-            if id != SCHEMA_ID {
-                return Err(AdapterError::ConfigError(format!(
-                    "github adapter only supports schema '{}', got '{}'",
-                    SCHEMA_ID, id,
-                )));
+    fn list_conditional<'a>(
+        &'a self,
+        filter: &'a ListFilter,
+        previous: Option<&'a Validator>,
+    ) -> AdapterFuture<'a, Revalidated<Vec<SpecDoc>>> {
+        Box::pin(async move {
+            let probe = self
+                .request(Method::GET, &self.url(&self.issues_path()))
+                .query(&[
+                    ("state", "all"),
+                    ("sort", "updated"),
+                    ("direction", "desc"),
+                    ("per_page", "1"),
+                ]);
+            let previous_etag = previous.and_then(|v| v.etag.as_deref());
+            let etag = match send_conditional(probe, previous_etag).await? {
+                None => {
+                    return Ok(Revalidated::Unchanged(Validator::with_etag(
+                        previous_etag.map(str::to_string),
+                    )))
+                }
+                Some(resp) => etag_of(&resp),
+            };
+            let docs = super::AsyncAdapter::list(self, filter).await?;
+            Ok(Revalidated::Replaced(docs, Validator::with_etag(etag)))
+        })
+    }
+
+    fn get_conditional<'a>(
+        &'a self,
+        id: &'a str,
+        previous: Option<&'a Validator>,
+    ) -> AdapterFuture<'a, Revalidated<SpecDoc>> {
+        Box::pin(async move {
+            let url = self.url(&format!("{}/{}", self.issues_path(), id));
+            let previous_etag = previous.and_then(|v| v.etag.as_deref());
+            let resp = send_conditional(self.request(Method::GET, &url), previous_etag)
+                .await
+                .map_err(|e| with_not_found_id(e, id))?;
+            match resp {
+                None => Ok(Revalidated::Unchanged(Validator::with_etag(
+                    previous_etag.map(str::to_string),
+                ))),
+                Some(resp) => {
+                    let etag = etag_of(&resp);
+                    let value = Self::parse_json(resp).await?;
+                    Ok(Revalidated::Replaced(
+                        self.doc_with_links(&value).await?,
+                        Validator::with_etag(etag),
+                    ))
+                }
             }
-        }
+        })
+    }
 
-        let mut body = serde_json::Map::new();
-        body.insert("title".into(), Value::String(req.title.clone()));
-        if let Some(content) = req.fields.get(field::CONTENT).and_then(|v| v.as_str()) {
-            body.insert("body".into(), Value::String(content.into()));
-        }
+    fn create<'a>(&'a self, req: &'a CreateRequest) -> AdapterFuture<'a, SpecDoc> {
+        Box::pin(async move {
+            if let Some(ref id) = req.schema_id {
+                if id != SCHEMA_ID {
+                    return Err(AdapterError::ConfigError(format!(
+                        "github adapter only supports schema '{}', got '{}'",
+                        SCHEMA_ID, id,
+                    )));
+                }
+            }
 
-        let mut labels = req
-            .fields
-            .get(field::TAGS)
-            .and_then(|v| v.as_strings())
-            .map(|s| s.to_vec())
-            .unwrap_or_default();
-        if let Some(priority) = req.fields.get(field::PRIORITY).and_then(|v| v.as_str()) {
-            labels.push(format!("{PRIORITY_LABEL_PREFIX}{priority}"));
-        }
-        if !labels.is_empty() {
-            body.insert(
-                "labels".into(),
-                Value::Array(labels.into_iter().map(Value::String).collect()),
-            );
-        }
+            let link_plan = links::LinkPlan::new("", &req.links)?;
 
-        if let Some(assignee) = req.fields.get(field::ASSIGNEE).and_then(|v| v.as_str()) {
-            body.insert(
-                "assignees".into(),
-                Value::Array(vec![Value::String(assignee.into())]),
-            );
-        }
+            let mut body = serde_json::Map::new();
+            body.insert("title".into(), Value::String(req.title.clone()));
+            if let Some(content) = req.fields.get(field::CONTENT).and_then(|v| v.as_str()) {
+                body.insert("body".into(), Value::String(content.into()));
+            }
 
-        let url = self.url(&self.issues_path());
-        let resp = self.send(self.request(Method::POST, &url).json(&Value::Object(body)))?;
-        let value = Self::parse_json(resp)?;
-        Ok(issue_to_doc(&value))
-    }
+            let mut labels = req
+                .fields
+                .get(field::TAGS)
+                .and_then(|v| v.as_strings())
+                .map(|s| s.to_vec())
+                .unwrap_or_default();
+            if let Some(priority) = req.fields.get(field::PRIORITY).and_then(|v| v.as_str()) {
+                labels.push(format!("{PRIORITY_LABEL_PREFIX}{priority}"));
+            }
+            if !labels.is_empty() {
+                body.insert(
+                    "labels".into(),
+                    Value::Array(labels.into_iter().map(Value::String).collect()),
+                );
+            }
 
-    fn update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
-        reject_unknown_fields(&req.fields, &self.schema)?;
+            if let Some(assignee) = req.fields.get(field::ASSIGNEE).and_then(|v| v.as_str()) {
+                body.insert(
+                    "assignees".into(),
+                    Value::Array(vec![Value::String(assignee.into())]),
+                );
+            }
 
-        let mut body = serde_json::Map::new();
-        if let Some(ref title) = req.title {
-            body.insert("title".into(), Value::String(title.clone()));
-        }
-        if let Some(content) = req.fields.get(field::CONTENT).and_then(|v| v.as_str()) {
-            body.insert("body".into(), Value::String(content.into()));
-        }
-        if let Some(status) = req.fields.get(field::STATUS).and_then(|v| v.as_str()) {
-            match status {
-                "open" | "closed" => {
-                    body.insert("state".into(), Value::String(status.into()));
-                }
-                other => {
-                    return Err(AdapterError::InvalidField {
-                        adapter: ADAPTER_NAME.into(),
-                        reason: format!("status must be 'open' or 'closed', got '{other}'"),
-                    });
-                }
+            let url = self.url(&self.issues_path());
+            let resp = send(self.request(Method::POST, &url).json(&Value::Object(body))).await?;
+            let value = Self::parse_json(resp).await?;
+            let doc = issue_to_doc(&value);
+            if req.links.is_empty() {
+                return Ok(doc);
+            }
+            // The issue exists from here on; a failed link write is reported
+            // but does not undo the create.
+            self.apply_links(&doc.id, &link_plan).await?;
+            self.doc_with_links(&value).await
+        })
+    }
+
+    fn update<'a>(&'a self, id: &'a str, req: &'a UpdateRequest) -> AdapterFuture<'a, SpecDoc> {
+        Box::pin(async move {
+            reject_unknown_fields(&req.fields, &self.schema)?;
+            let link_plan = req
+                .replace_links
+                .as_deref()
+                .map(|l| links::LinkPlan::new(id, l))
+                .transpose()?;
+            if let Some(ref plan) = link_plan {
+                let body = match req.fields.get(field::CONTENT).and_then(|v| v.as_str()) {
+                    Some(content) => content.to_string(),
+                    None => self.issue_body(id).await?,
+                };
+                plan.check_body(id, &body)?;
+            }
+            if req.expected_version.is_some() {
+                // PATCH /issues ignores If-Match; re-read and compare.
+                crate::adapters::check_version(
+                    req.expected_version.as_deref(),
+                    &super::AsyncAdapter::get(self, id).await?,
+                )?;
             }
-        }
-        if let Some(assignee) = req.fields.get(field::ASSIGNEE).and_then(|v| v.as_str()) {
-            body.insert(
-                "assignees".into(),
-                Value::Array(vec![Value::String(assignee.into())]),
-            );
-        }
 
-        // Labels and priority both live in the same GitHub `labels` array,
-        // but `UpdateRequest` merge semantics say absent fields are kept.
-        // Compute the merged label set so a partial `tags` update doesn't
-        // wipe the priority label (and vice-versa), and per-field clears
-        // only remove the relevant slice of labels.
-        let clears_tags = req.clear.iter().any(|k| k == field::TAGS);
-        let clears_priority = req.clear.iter().any(|k| k == field::PRIORITY);
-        let touches_tags = req.fields.contains_key(field::TAGS);
-        let touches_priority = req.fields.contains_key(field::PRIORITY);
-
-        if touches_tags || touches_priority || clears_tags || clears_priority {
-            let current = self.fetch_current_labels(id)?;
-            let (mut keep_tags, mut keep_priority): (Vec<String>, Vec<String>) = current
-                .into_iter()
-                .partition(|l| !l.starts_with(PRIORITY_LABEL_PREFIX));
-
-            if touches_tags {
-                keep_tags = req
-                    .fields
-                    .get(field::TAGS)
-                    .and_then(|v| v.as_strings())
-                    .map(|s| s.to_vec())
-                    .unwrap_or_default();
+            let mut body = serde_json::Map::new();
+            if let Some(ref title) = req.title {
+                body.insert("title".into(), Value::String(title.clone()));
             }
-            if clears_tags {
-                keep_tags.clear();
+            if let Some(content) = req.fields.get(field::CONTENT).and_then(|v| v.as_str()) {
+                body.insert("body".into(), Value::String(content.into()));
             }
-            if touches_priority {
-                let p = req
-                    .fields
-                    .get(field::PRIORITY)
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("");
-                keep_priority = vec![format!("{PRIORITY_LABEL_PREFIX}{p}")];
+            if let Some(status) = req.fields.get(field::STATUS).and_then(|v| v.as_str()) {
+                match status {
+                    "open" | "closed" => {
+                        body.insert("state".into(), Value::String(status.into()));
+                    }
+                    other => {
+                        return Err(AdapterError::InvalidField {
+                            adapter: ADAPTER_NAME.into(),
+                            reason: format!("status must be 'open' or 'closed', got '{other}'"),
+                        });
+                    }
+                }
             }
-            if clears_priority {
-                keep_priority.clear();
+            if let Some(assignee) = req.fields.get(field::ASSIGNEE).and_then(|v| v.as_str()) {
+                body.insert(
+                    "assignees".into(),
+                    Value::Array(vec![Value::String(assignee.into())]),
+                );
             }
 
-            let mut merged = keep_tags;
-            merged.extend(keep_priority);
-            body.insert(
-                "labels".into(),
-                Value::Array(merged.into_iter().map(Value::String).collect()),
-            );
-        }
+            // Labels and priority both live in the same GitHub `labels` array,
+            // but `UpdateRequest` merge semantics say absent fields are kept.
+            // Compute the merged label set so a partial `tags` update doesn't
+            // wipe the priority label (and vice-versa), and per-field clears
+            // only remove the relevant slice of labels.
+            let clears_tags = req.clear.iter().any(|k| k == field::TAGS);
+            let clears_priority = req.clear.iter().any(|k| k == field::PRIORITY);
+            let touches_tags = req.fields.contains_key(field::TAGS);
+            let touches_priority = req.fields.contains_key(field::PRIORITY);
+
+            if touches_tags || touches_priority || clears_tags || clears_priority {
+                let current = self.fetch_current_labels(id).await?;
+                let (mut keep_tags, mut keep_priority): (Vec<String>, Vec<String>) = current
+                    .into_iter()
+                    .partition(|l| !l.starts_with(PRIORITY_LABEL_PREFIX));
+
+                if touches_tags {
+                    keep_tags = req
+                        .fields
+                        .get(field::TAGS)
+                        .and_then(|v| v.as_strings())
+                        .map(|s| s.to_vec())
+                        .unwrap_or_default();
+                }
+                if clears_tags {
+                    keep_tags.clear();
+                }
+                if touches_priority {
+                    let p = req
+                        .fields
+                        .get(field::PRIORITY)
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("");
+                    keep_priority = vec![format!("{PRIORITY_LABEL_PREFIX}{p}")];
+                }
+                if clears_priority {
+                    keep_priority.clear();
+                }
 
-        // Non-label clears (assignee). Label clears are handled above so they
-        // can be merged with `tags` / `priority` semantics in one PATCH.
-        for key in &req.clear {
-            if key.as_str() == field::ASSIGNEE {
-                body.insert("assignees".into(), Value::Array(vec![]));
+                let mut merged = keep_tags;
+                merged.extend(keep_priority);
+                body.insert(
+                    "labels".into(),
+                    Value::Array(merged.into_iter().map(Value::String).collect()),
+                );
             }
-        }
 
-        let url = self.url(&format!("{}/{}", self.issues_path(), id));
-        let resp = self
-            .send(self.request(Method::PATCH, &url).json(&Value::Object(body)))
-            .map_err(|e| with_not_found_id(e, id))?;
-        let value = Self::parse_json(resp)?;
-        Ok(issue_to_doc(&value))
+            // Non-label clears (assignee). Label clears are handled above so they
+            // can be merged with `tags` / `priority` semantics in one PATCH.
+            for key in &req.clear {
+                if key.as_str() == field::ASSIGNEE {
+                    body.insert("assignees".into(), Value::Array(vec![]));
+                }
+            }
+
+            let url = self.url(&format!("{}/{}", self.issues_path(), id));
+            let resp = send(self.request(Method::PATCH, &url).json(&Value::Object(body)))
+                .await
+                .map_err(|e| with_not_found_id(e, id))?;
+            let value = Self::parse_json(resp).await?;
+            if let Some(ref plan) = link_plan {
+                self.apply_links(id, plan).await?;
+            }
+            self.doc_with_links(&value).await
+        })
     }
 
-    fn delete(&self, id: &str) -> Result<(), AdapterError> {
-        // GitHub has no hard-delete for issues; closing matches the "archive"
-        // semantics used elsewhere.
-        let url = self.url(&format!("{}/{}", self.issues_path(), id));
-        let body = json!({ "state": "closed" });
-        self.send(self.request(Method::PATCH, &url).json(&body))
-            .map_err(|e| with_not_found_id(e, id))?;
-        Ok(())
+    fn delete<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, ()> {
+        Box::pin(async move {
+            // GitHub has no hard-delete for issues; closing matches the "archive"
+            // semantics used elsewhere.
+            let url = self.url(&format!("{}/{}", self.issues_path(), id));
+            let body = json!({ "state": "closed" });
+            send(self.request(Method::PATCH, &url).json(&body))
+                .await
+                .map_err(|e| with_not_found_id(e, id))?;
+            Ok(())
+        })
     }
 
-    fn search(&self, query: &str, opts: &SearchOptions) -> Result<Vec<SearchHit>, AdapterError> {
-        // GitHub's search endpoint requires repo qualifier in the query.
-        let q = format!("{query} repo:{}/{}", self.owner, self.repo);
-        let per_page = opts.limit.unwrap_or(30).min(100) as u32;
-        let url = self.url("/search/issues");
-
-        let resp = self.send(
-            self.request(Method::GET, &url)
-                .query(&[("q", q.as_str()), ("per_page", &per_page.to_string())]),
-        )?;
-        let value = Self::parse_json(resp)?;
-        let items = value
-            .get("items")
-            .and_then(|v| v.as_array())
-            .cloned()
-            .unwrap_or_default();
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        opts: &'a SearchOptions,
+    ) -> AdapterFuture<'a, Vec<SearchHit>> {
+        Box::pin(async move {
+            // GitHub's search endpoint requires repo qualifier in the query.
+            let q = format!("{query} repo:{}/{}", self.owner, self.repo);
+            let per_page = opts.limit.unwrap_or(30).min(100) as u32;
+            let url = self.url("/search/issues");
+
+            let resp = send(
+                self.request(Method::GET, &url)
+                    .query(&[("q", q.as_str()), ("per_page", &per_page.to_string())]),
+            )
+            .await?;
+            let value = Self::parse_json(resp).await?;
+            let items = value
+                .get("items")
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default();
+
+            let hits = items
+                .iter()
+                .filter(|item| item.get("pull_request").is_none())
+                .map(|item| {
+                    let id = item
+                        .get("number")
+                        .and_then(|v| v.as_i64())
+                        .map(|n| n.to_string())
+                        .unwrap_or_default();
+                    let score = item.get("score").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
+                    let snippet = if opts.include_body {
+                        item.get("body")
+                            .and_then(|v| v.as_str())
+                            .map(|s| s.chars().take(200).collect())
+                    } else {
+                        None
+                    };
+                    SearchHit { id, score, snippet }
+                })
+                .collect();
+            Ok(hits)
+        })
+    }
 
-        let hits = items
-            .iter()
-            .filter(|item| item.get("pull_request").is_none())
-            .map(|item| {
-                let id = item
-                    .get("number")
-                    .and_then(|v| v.as_i64())
-                    .map(|n| n.to_string())
-                    .unwrap_or_default();
-                let score = item.get("score").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
-                let snippet = if opts.include_body {
-                    item.get("body")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.chars().take(200).collect())
-                } else {
-                    None
-                };
-                SearchHit { id, score, snippet }
-            })
-            .collect();
-        Ok(hits)
+    fn list_comments<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, Vec<Comment>> {
+        Box::pin(self.list_issue_comments(id))
+    }
+
+    fn add_comment<'a>(
+        &'a self,
+        id: &'a str,
+        req: &'a CommentRequest,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(self.add_issue_comment(id, req))
+    }
+
+    fn edit_comment<'a>(
+        &'a self,
+        id: &'a str,
+        comment_id: &'a str,
+        body: &'a str,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(self.edit_issue_comment(id, comment_id, body))
+    }
+
+    fn resolve_comment<'a>(
+        &'a self,
+        id: &'a str,
+        comment_id: &'a str,
+        resolved: bool,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(self.resolve_issue_comment(id, comment_id, resolved))
     }
 }
 
@@ -748,6 +1124,13 @@ impl TokenValidation {
 pub fn validate_token(
     token: &str,
     base_url: Option<&str>,
+) -> Result<TokenValidation, AdapterError> {
+    block_on(validate_token_async(token, base_url))
+}
+
+async fn validate_token_async(
+    token: &str,
+    base_url: Option<&str>,
 ) -> Result<TokenValidation, AdapterError> {
     let base = base_url
         .unwrap_or("https://api.github.com")
@@ -777,6 +1160,7 @@ pub fn validate_token(
         .header(USER_AGENT, "leanspec-github-adapter")
         .header("X-GitHub-Api-Version", "2022-11-28")
         .send()
+        .await
         .map_err(|e| AdapterError::BackendError {
             adapter: ADAPTER_NAME.into(),
             reason: format!("network: {e}"),
@@ -785,7 +1169,7 @@ pub fn validate_token(
     let status = resp.status();
     if !status.is_success() {
         let headers = resp.headers().clone();
-        let body = resp.text().unwrap_or_default();
+        let body = resp.text().await.unwrap_or_default();
         return Err(map_error(status, &headers, &body));
     }
 
@@ -801,7 +1185,7 @@ pub fn validate_token(
         })
         .unwrap_or_default();
 
-    let body: Value = resp.json().map_err(|e| AdapterError::ParseError {
+    let body: Value = resp.json().await.map_err(|e| AdapterError::ParseError {
         path: "github /user response".into(),
         reason: e.to_string(),
     })?;
@@ -915,29 +1299,49 @@ pub(crate) fn issue_to_doc(issue: &Value) -> SpecDoc {
         .and_then(|v| v.as_str())
         .map(String::from);
 
+    let links = links::body_links(issue.get("body").and_then(|v| v.as_str()));
+
     SpecDoc {
         id: number,
         title,
         schema_id: SCHEMA_ID.into(),
         fields,
-        links: Vec::new(),
+        links,
         created_at,
         updated_at,
         url,
         raw: Some(issue.clone()),
+        version: issue_version(issue),
     }
 }
 
+/// An issue's version token: its `updated_at` timestamp, which GitHub bumps
+/// on every edit, comment, label or state change.
+fn issue_version(issue: &Value) -> Option<String> {
+    issue
+        .get("updated_at")
+        .and_then(|v| v.as_str())
+        .map(String::from)
+}
+
 impl GitHubAdapter {
+    /// Project an issue payload and add its native relationships.
+    async fn doc_with_links(&self, issue: &Value) -> Result<SpecDoc, AdapterError> {
+        let mut docs = [issue_to_doc(issue)];
+        self.attach_links(&mut docs).await?;
+        let [doc] = docs;
+        Ok(doc)
+    }
+
     /// Fetch the current labels for an issue, so per-field updates can merge
     /// rather than overwrite. Used by `update()` to honour
     /// [`UpdateRequest`]'s "absent keys are kept" contract.
-    fn fetch_current_labels(&self, id: &str) -> Result<Vec<String>, AdapterError> {
+    async fn fetch_current_labels(&self, id: &str) -> Result<Vec<String>, AdapterError> {
         let url = self.url(&format!("{}/{}", self.issues_path(), id));
-        let resp = self
-            .send(self.request(Method::GET, &url))
+        let resp = send(self.request(Method::GET, &url))
+            .await
             .map_err(|e| with_not_found_id(e, id))?;
-        let value = Self::parse_json(resp)?;
+        let value = Self::parse_json(resp).await?;
         Ok(value
             .get("labels")
             .and_then(|v| v.as_array())
@@ -1001,11 +1405,17 @@ pub(crate) fn map_error(status: StatusCode, headers: &HeaderMap, body: &str) ->
                 .and_then(|h| h.to_str().ok())
                 .and_then(|s| s.parse::<i64>().ok())
                 .and_then(|ts| Utc.timestamp_opt(ts, 0).single());
-
-            if status.as_u16() == 429 || remaining == Some(0) || reset.is_some() {
+            // Secondary rate limits only send `Retry-After`.
+            let retry_after = parse_retry_after(headers);
+
+            if status.as_u16() == 429
+                || remaining == Some(0)
+                || reset.is_some()
+                || retry_after.is_some()
+            {
                 AdapterError::RateLimit {
                     adapter: ADAPTER_NAME.into(),
-                    reset_at: reset,
+                    reset_at: reset.or(retry_after),
                 }
             } else {
                 AdapterError::AuthError {
@@ -1019,9 +1429,10 @@ pub(crate) fn map_error(status: StatusCode, headers: &HeaderMap, body: &str) ->
             adapter: ADAPTER_NAME.into(),
             reason: body.to_string(),
         },
-        s if (500..600).contains(&s) => AdapterError::BackendError {
+        s if s == 408 || (500..600).contains(&s) => AdapterError::Unavailable {
             adapter: ADAPTER_NAME.into(),
             reason: format!("HTTP {s}: {body}"),
+            retry_after: parse_retry_after(headers),
         },
         s => AdapterError::BackendError {
             adapter: ADAPTER_NAME.into(),
@@ -1057,9 +1468,59 @@ fn parse_next_link(header: Option<&HeaderValue>) -> Option<String> {
     None
 }
 
+/// Parse the `rel="last"` entry of an HTTP `Link` header into the page URL
+/// and its page number.
+fn parse_last_page(header: Option<&HeaderValue>) -> Option<(Url, u32)> {
+    let value = header?.to_str().ok()?;
+    let url = value.split(',').find_map(|part| {
+        let part = part.trim();
+        let close = part.find('>')?;
+        (part.starts_with('<') && part[close + 1..].contains("rel=\"last\""))
+            .then(|| part[1..close].to_string())
+    })?;
+    let url = Url::parse(&url).ok()?;
+    let last = url
+        .query_pairs()
+        .find(|(k, _)| k == "page")
+        .and_then(|(_, v)| v.parse().ok())?;
+    Some((url, last))
+}
+
+/// URLs for pages `2..=last`, trimmed to the pages needed to collect
+/// `remaining` more items. `None` when the link carries no `per_page`.
+fn page_urls(template: &Url, last: u32, remaining: usize) -> Option<Vec<String>> {
+    let per_page: usize = template
+        .query_pairs()
+        .find(|(k, _)| k == "per_page")
+        .and_then(|(_, v)| v.parse().ok())?;
+    let needed = remaining.div_ceil(per_page.max(1)) as u32;
+    let last = last.min(needed.saturating_add(1));
+    Some(
+        (2..=last)
+            .map(|page| {
+                let mut url = template.clone();
+                let pairs: Vec<(String, String)> = template
+                    .query_pairs()
+                    .map(|(k, v)| {
+                        let v = if k == "page" {
+                            page.to_string()
+                        } else {
+                            v.into_owned()
+                        };
+                        (k.into_owned(), v)
+                    })
+                    .collect();
+                url.query_pairs_mut().clear().extend_pairs(pairs);
+                url.to_string()
+            })
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::ItemLink;
     use mockito::Matcher;
     use serde_json::json;
 
@@ -1111,7 +1572,7 @@ mod tests {
             status_active: "open".into(),
             status_alt: "closed".into(),
             delete_is_archive: true,
-            supports_links: false,
+            supports_links: true,
             ..ComplianceOptions::default()
         };
         check_schema_consistency(&a, &opts);
@@ -1188,6 +1649,141 @@ mod tests {
         m.assert();
     }
 
+    #[test]
+    fn list_conditional_revalidates_with_etag() {
+        use crate::adapters::{CacheConfig, CachingAdapter};
+
+        let mut server = mockito::Server::new();
+        let probe_query = Matcher::UrlEncoded("per_page".into(), "1".into());
+        let first_probe = server
+            .mock("GET", "/repos/octo/demo/issues")
+            .match_query(probe_query.clone())
+            .match_header("if-none-match", Matcher::Missing)
+            .with_status(200)
+            .with_header("etag", "\"v1\"")
+            .with_body(json!([sample_issue(2)]).to_string())
+            .expect(1)
+            .create();
+        let not_modified = server
+            .mock("GET", "/repos/octo/demo/issues")
+            .match_query(probe_query)
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(304)
+            .expect(1)
+            .create();
+        let listing = server
+            .mock("GET", "/repos/octo/demo/issues")
+            .match_query(Matcher::UrlEncoded("per_page".into(), "100".into()))
+            .with_status(200)
+            .with_body(json!([sample_issue(1), sample_issue(2)]).to_string())
+            .expect(1)
+            .create();
+
+        let config = CacheConfig {
+            ttl: Duration::ZERO,
+            ..CacheConfig::default()
+        };
+        let cache = CachingAdapter::wrap(adapter(&server), config);
+        assert_eq!(cache.list(&ListFilter::default()).unwrap().len(), 2);
+        // Expired, but the probe comes back 304: no second full listing.
+        assert_eq!(cache.list(&ListFilter::default()).unwrap().len(), 2);
+
+        first_probe.assert();
+        not_modified.assert();
+        listing.assert();
+        let stats = cache.cache_stats().unwrap();
+        assert_eq!((stats.misses, stats.revalidated), (1, 1));
+    }
+
+    #[test]
+    fn get_conditional_maps_304_to_unchanged() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/repos/octo/demo/issues/7")
+            .match_header("if-none-match", "\"abc\"")
+            .with_status(304)
+            .create();
+
+        let previous = Validator::with_etag(Some("\"abc\"".into()));
+        let result = adapter(&server)
+            .get_conditional("7", Some(&previous))
+            .unwrap();
+        assert!(matches!(
+            result,
+            Revalidated::Unchanged(v) if v.etag.as_deref() == Some("\"abc\"")
+        ));
+    }
+
+    fn fast_retries() -> crate::adapters::RetryPolicy {
+        crate::adapters::RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            jitter: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn retrying_adapter_recovers_from_server_errors() {
+        let mut server = mockito::Server::new();
+        let failing = server
+            .mock("GET", "/repos/octo/demo/issues/7")
+            .with_status(502)
+            .expect(2)
+            .create();
+        let ok = server
+            .mock("GET", "/repos/octo/demo/issues/7")
+            .with_status(200)
+            .with_body(sample_issue(7).to_string())
+            .expect(1)
+            .create();
+
+        let a = crate::adapters::RetryingAdapter::new(adapter(&server), fast_retries());
+        assert_eq!(a.get("7").unwrap().id, "7");
+        failing.assert();
+        ok.assert();
+    }
+
+    #[test]
+    fn retrying_adapter_waits_out_secondary_rate_limit() {
+        let mut server = mockito::Server::new();
+        let limited = server
+            .mock("GET", "/repos/octo/demo/issues/7")
+            .with_status(403)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create();
+        server
+            .mock("GET", "/repos/octo/demo/issues/7")
+            .with_status(200)
+            .with_body(sample_issue(7).to_string())
+            .create();
+
+        let a = crate::adapters::RetryingAdapter::new(adapter(&server), fast_retries());
+        assert!(a.get("7").is_ok());
+        limited.assert();
+    }
+
+    #[test]
+    fn retrying_adapter_does_not_repeat_create() {
+        let mut server = mockito::Server::new();
+        let post = server
+            .mock("POST", "/repos/octo/demo/issues")
+            .with_status(503)
+            .expect(1)
+            .create();
+
+        let a = crate::adapters::RetryingAdapter::new(adapter(&server), fast_retries());
+        let req = CreateRequest {
+            title: "New".into(),
+            ..CreateRequest::default()
+        };
+        assert!(matches!(
+            a.create(&req),
+            Err(AdapterError::Unavailable { .. })
+        ));
+        post.assert();
+    }
+
     #[test]
     fn list_default_state_is_open() {
         let mut server = mockito::Server::new();
@@ -1254,6 +1850,297 @@ mod tests {
         }
     }
 
+    fn relations_response(number: u64, parent: Option<u64>, blocked_by: &[u64]) -> String {
+        let issue =
+            |n: u64| json!({ "number": n, "title": format!("Issue {n}"), "databaseId": n * 100 });
+        json!({
+            "data": { "repository": {
+                format!("i{number}"): {
+                    "parent": parent.map(issue),
+                    "subIssues": { "nodes": [] },
+                    "blockedBy": { "nodes": blocked_by.iter().map(|n| issue(*n)).collect::<Vec<_>>() }
+                }
+            }}
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn get_merges_native_relationships_with_body_links() {
+        let mut server = mockito::Server::new();
+        let mut issue = sample_issue(7);
+        issue["body"] = json!("Depends on #3 and #4");
+        server
+            .mock("GET", "/repos/octo/demo/issues/7")
+            .with_status(200)
+            .with_body(issue.to_string())
+            .create();
+        let graphql = server
+            .mock("POST", "/graphql")
+            .match_body(Matcher::PartialJson(json!({
+                "variables": { "owner": "octo", "name": "demo" }
+            })))
+            .with_status(200)
+            .with_body(relations_response(7, Some(9), &[4]))
+            .create();
+
+        let doc = adapter(&server).get("7").unwrap();
+        graphql.assert();
+        let links: Vec<(&str, &str, Option<&str>)> = doc
+            .links
+            .iter()
+            .map(|l| {
+                (
+                    l.link_type.as_str(),
+                    l.target_id.as_str(),
+                    l.target_title.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                (link::PARENT, "9", Some("Issue 9")),
+                (link::DEPENDS_ON, "4", Some("Issue 4")),
+                (link::DEPENDS_ON, "3", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn relationship_auth_errors_are_not_swallowed() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/repos/octo/demo/issues/7")
+            .with_status(200)
+            .with_body(sample_issue(7).to_string())
+            .create();
+        server
+            .mock("POST", "/graphql")
+            .with_status(401)
+            .with_body(r#"{"message":"Bad credentials"}"#)
+            .create();
+
+        let err = adapter(&server).get("7").unwrap_err();
+        assert!(matches!(err, AdapterError::AuthError { .. }), "{err:?}");
+    }
+
+    #[test]
+    fn update_replace_links_syncs_parent_and_dependencies() {
+        let mut server = mockito::Server::new();
+        let mut issue = sample_issue(42);
+        issue["id"] = json!(4200);
+        server
+            .mock("PATCH", "/repos/octo/demo/issues/42")
+            .with_status(200)
+            .with_body(issue.to_string())
+            .create();
+        server
+            .mock("GET", "/repos/octo/demo/issues/42")
+            .with_status(200)
+            .with_body(issue.to_string())
+            .create();
+        server
+            .mock("GET", "/repos/octo/demo/issues/5")
+            .with_status(200)
+            .with_body(json!({ "number": 5, "id": 500 }).to_string())
+            .create();
+        server
+            .mock("POST", "/graphql")
+            .with_status(200)
+            .with_body(relations_response(42, Some(9), &[4]))
+            .create();
+        let set_parent = server
+            .mock("POST", "/repos/octo/demo/issues/10/sub_issues")
+            .match_body(Matcher::Json(
+                json!({ "sub_issue_id": 4200, "replace_parent": true }),
+            ))
+            .with_status(201)
+            .with_body("{}")
+            .create();
+        let add_dependency = server
+            .mock("POST", "/repos/octo/demo/issues/42/dependencies/blocked_by")
+            .match_body(Matcher::Json(json!({ "issue_id": 500 })))
+            .with_status(201)
+            .with_body("{}")
+            .create();
+        let remove_dependency = server
+            .mock(
+                "DELETE",
+                "/repos/octo/demo/issues/42/dependencies/blocked_by/400",
+            )
+            .with_status(200)
+            .with_body("{}")
+            .create();
+
+        let link = |link_type: &str, target: &str| ItemLink {
+            link_type: link_type.into(),
+            target_id: target.into(),
+            target_title: None,
+        };
+        adapter(&server)
+            .update(
+                "42",
+                &UpdateRequest {
+                    replace_links: Some(vec![
+                        link(link::PARENT, "10"),
+                        link(link::DEPENDS_ON, "5"),
+                    ]),
+                    ..UpdateRequest::default()
+                },
+            )
+            .unwrap();
+        set_parent.assert();
+        add_dependency.assert();
+        remove_dependency.assert();
+    }
+
+    #[test]
+    fn update_replace_links_removes_dropped_sub_issues() {
+        let mut server = mockito::Server::new();
+        let mut issue = sample_issue(42);
+        issue["id"] = json!(4200);
+        for method in ["GET", "PATCH"] {
+            server
+                .mock(method, "/repos/octo/demo/issues/42")
+                .with_status(200)
+                .with_body(issue.to_string())
+                .create();
+        }
+        let sub_issue =
+            |n: u64| json!({ "number": n, "title": format!("Issue {n}"), "databaseId": n * 100 });
+        server
+            .mock("POST", "/graphql")
+            .with_status(200)
+            .with_body(
+                json!({ "data": { "repository": { "i42": {
+                    "parent": null,
+                    "subIssues": { "nodes": [sub_issue(7), sub_issue(8)] },
+                    "blockedBy": { "nodes": [] }
+                }}}})
+                .to_string(),
+            )
+            .create();
+        let remove_sub_issue = server
+            .mock("DELETE", "/repos/octo/demo/issues/42/sub_issue")
+            .match_body(Matcher::Json(json!({ "sub_issue_id": 800 })))
+            .with_status(200)
+            .with_body("{}")
+            .create();
+        let add_sub_issue = server
+            .mock("POST", "/repos/octo/demo/issues/42/sub_issues")
+            .expect(0)
+            .create();
+
+        let doc = adapter(&server)
+            .update(
+                "42",
+                &UpdateRequest {
+                    replace_links: Some(vec![ItemLink {
+                        link_type: link::CHILD.into(),
+                        target_id: "7".into(),
+                        target_title: None,
+                    }]),
+                    ..UpdateRequest::default()
+                },
+            )
+            .unwrap();
+        remove_sub_issue.assert();
+        add_sub_issue.assert();
+        assert!(doc
+            .links
+            .iter()
+            .any(|l| l.link_type == link::CHILD && l.target_id == "7"));
+    }
+
+    #[test]
+    fn update_rejects_dropping_a_body_dependency() {
+        let mut server = mockito::Server::new();
+        let mut issue = sample_issue(42);
+        issue["body"] = json!("Depends on #3 and #5");
+        server
+            .mock("GET", "/repos/octo/demo/issues/42")
+            .with_status(200)
+            .with_body(issue.to_string())
+            .create();
+        let patch = server
+            .mock("PATCH", "/repos/octo/demo/issues/42")
+            .expect(0)
+            .create();
+        let depends_on = |target: &str| ItemLink {
+            link_type: link::DEPENDS_ON.into(),
+            target_id: target.into(),
+            target_title: None,
+        };
+
+        let err = adapter(&server)
+            .update(
+                "42",
+                &UpdateRequest {
+                    replace_links: Some(vec![depends_on("3")]),
+                    ..UpdateRequest::default()
+                },
+            )
+            .unwrap_err();
+        match err {
+            AdapterError::InvalidField { reason, .. } => {
+                assert!(reason.contains("#5"), "{reason}");
+                assert!(!reason.contains("#3"), "{reason}");
+            }
+            other => panic!("expected InvalidField, got {other:?}"),
+        }
+        patch.assert();
+
+        // A body sent with the update is checked instead of the current one.
+        let mut fields = HashMap::new();
+        fields.insert(
+            field::CONTENT.to_string(),
+            FieldValue::String("Depends on #3".into()),
+        );
+        let err = adapter(&server)
+            .update(
+                "42",
+                &UpdateRequest {
+                    fields,
+                    replace_links: Some(vec![]),
+                    ..UpdateRequest::default()
+                },
+            )
+            .unwrap_err();
+        match err {
+            AdapterError::InvalidField { reason, .. } => {
+                assert!(reason.contains("#3"), "{reason}");
+                assert!(!reason.contains("#5"), "{reason}");
+            }
+            other => panic!("expected InvalidField, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn update_rejects_invalid_links_before_writing() {
+        let mut server = mockito::Server::new();
+        let patch = server
+            .mock("PATCH", "/repos/octo/demo/issues/42")
+            .expect(0)
+            .create();
+
+        let err = adapter(&server)
+            .update(
+                "42",
+                &UpdateRequest {
+                    replace_links: Some(vec![ItemLink {
+                        link_type: "relates_to".into(),
+                        target_id: "5".into(),
+                        target_title: None,
+                    }]),
+                    ..UpdateRequest::default()
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, AdapterError::InvalidField { .. }), "{err:?}");
+        patch.assert();
+    }
+
     #[test]
     fn invalid_token_fails_at_construction() {
         // \n is not a legal header byte; HeaderValue::from_str rejects it.
@@ -1394,6 +2281,7 @@ mod tests {
                 fields,
                 clear: vec![],
                 replace_links: None,
+                expected_version: None,
             },
         )
         .unwrap();
@@ -1414,6 +2302,7 @@ mod tests {
                     fields,
                     clear: vec![],
                     replace_links: None,
+                    expected_version: None,
                 },
             )
             .unwrap_err();
@@ -1434,6 +2323,7 @@ mod tests {
                     fields,
                     clear: vec![],
                     replace_links: None,
+                    expected_version: None,
                 },
             )
             .unwrap_err();
@@ -1455,6 +2345,118 @@ mod tests {
         m.assert();
     }
 
+    fn sample_comment(id: u64, body: &str) -> Value {
+        json!({
+            "id": id,
+            "node_id": format!("IC_{id}"),
+            "body": body,
+            "user": { "login": "octocat" },
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-01T00:00:00Z",
+            "html_url": format!("https://github.com/octo/demo/issues/42#issuecomment-{id}"),
+            "issue_url": "https://api.github.com/repos/octo/demo/issues/42",
+        })
+    }
+
+    #[test]
+    fn list_comments_reads_replies_and_resolved_state() {
+        let mut server = mockito::Server::new();
+        let _rest = server
+            .mock("GET", "/repos/octo/demo/issues/42/comments")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!([
+                    sample_comment(7, "Why not SQLite?"),
+                    sample_comment(8, "Fair.\n\n<!-- leanspec:in-reply-to 7 -->"),
+                ])
+                .to_string(),
+            )
+            .create();
+        let _graphql = server
+            .mock("POST", "/graphql")
+            .match_body(Matcher::PartialJson(
+                json!({ "variables": { "ids": ["IC_7", "IC_8"] } }),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({ "data": { "nodes": [
+                    { "id": "IC_7", "isMinimized": true, "minimizedReason": "RESOLVED" },
+                    { "id": "IC_8", "isMinimized": true, "minimizedReason": "OUTDATED" },
+                ] } })
+                .to_string(),
+            )
+            .create();
+
+        let comments = adapter(&server).list_comments("42").unwrap();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].id, "7");
+        assert_eq!(comments[0].author.as_deref(), Some("octocat"));
+        assert!(comments[0].resolved);
+        assert_eq!(comments[1].body, "Fair.");
+        assert_eq!(comments[1].in_reply_to.as_deref(), Some("7"));
+        assert!(!comments[1].resolved);
+    }
+
+    #[test]
+    fn add_reply_comment_embeds_marker() {
+        let mut server = mockito::Server::new();
+        let _parent = server
+            .mock("GET", "/repos/octo/demo/issues/comments/7")
+            .with_status(200)
+            .with_body(sample_comment(7, "Why not SQLite?").to_string())
+            .create();
+        let post = server
+            .mock("POST", "/repos/octo/demo/issues/42/comments")
+            .match_body(Matcher::Json(
+                json!({ "body": "Fair.\n\n<!-- leanspec:in-reply-to 7 -->" }),
+            ))
+            .with_status(201)
+            .with_body(sample_comment(8, "Fair.\n\n<!-- leanspec:in-reply-to 7 -->").to_string())
+            .create();
+
+        let comment = adapter(&server)
+            .add_comment(
+                "42",
+                &CommentRequest {
+                    body: "Fair.".into(),
+                    in_reply_to: Some("7".into()),
+                    author: None,
+                },
+            )
+            .unwrap();
+        post.assert();
+        assert_eq!(comment.id, "8");
+        assert_eq!(comment.body, "Fair.");
+    }
+
+    #[test]
+    fn resolve_comment_minimizes_it() {
+        let mut server = mockito::Server::new();
+        let _comment = server
+            .mock("GET", "/repos/octo/demo/issues/comments/7")
+            .with_status(200)
+            .with_body(sample_comment(7, "Why not SQLite?").to_string())
+            .create();
+        let graphql = server
+            .mock("POST", "/graphql")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex("minimizeComment".into()),
+                Matcher::PartialJson(json!({ "variables": { "id": "IC_7" } })),
+            ]))
+            .with_status(200)
+            .with_body(json!({ "data": { "minimizeComment": {} } }).to_string())
+            .create();
+
+        let a = adapter(&server);
+        assert!(a.resolve_comment("42", "7", true).unwrap().resolved);
+        graphql.assert();
+
+        // A comment from another issue is not addressable through this one.
+        let err = a.edit_comment("43", "7", "Moved").unwrap_err();
+        assert!(matches!(err, AdapterError::NotFound(_)));
+    }
+
     #[test]
     fn search_hits_search_endpoint_with_repo_qualifier() {
         let mut server = mockito::Server::new();
@@ -1593,12 +2595,46 @@ mod tests {
                 fields,
                 clear: vec![],
                 replace_links: None,
+                expected_version: None,
             },
         )
         .unwrap();
         m.assert();
     }
 
+    #[test]
+    fn update_with_stale_version_conflicts_without_writing() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/repos/octo/demo/issues/42")
+            .with_status(200)
+            .with_body(sample_issue(42).to_string())
+            .create();
+        let patch = server
+            .mock("PATCH", "/repos/octo/demo/issues/42")
+            .expect(0)
+            .create();
+
+        let a = adapter(&server);
+        let err = a
+            .update(
+                "42",
+                &UpdateRequest {
+                    title: Some("Renamed".into()),
+                    expected_version: Some("2026-01-01T00:00:00Z".into()),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        match err {
+            AdapterError::Conflict { current, .. } => {
+                assert_eq!(current.as_deref(), Some("2026-01-02T11:00:00Z"));
+            }
+            other => panic!("expected conflict, got {other:?}"),
+        }
+        patch.assert();
+    }
+
     #[test]
     fn update_tags_only_preserves_existing_priority() {
         let mut server = mockito::Server::new();
@@ -1635,6 +2671,7 @@ mod tests {
                 fields,
                 clear: vec![],
                 replace_links: None,
+                expected_version: None,
             },
         )
         .unwrap();
@@ -1672,6 +2709,7 @@ mod tests {
                 fields: HashMap::new(),
                 clear: vec![field::PRIORITY.into()],
                 replace_links: None,
+                expected_version: None,
             },
         )
         .unwrap();
@@ -1709,6 +2747,7 @@ mod tests {
                 fields: HashMap::new(),
                 clear: vec![field::TAGS.into()],
                 replace_links: None,
+                expected_version: None,
             },
         )
         .unwrap();
@@ -1816,6 +2855,69 @@ mod tests {
         assert!(ids.contains(&"2"));
     }
 
+    #[test]
+    fn pagination_fetches_remaining_pages_from_last_link() {
+        let mut server = mockito::Server::new();
+        let base = format!("{}/repos/octo/demo/issues", server.url());
+        let link = format!(
+            "<{base}?per_page=100&state=open&page=2>; rel=\"next\", <{base}?per_page=100&state=open&page=3>; rel=\"last\""
+        );
+
+        server
+            .mock("GET", "/repos/octo/demo/issues")
+            .match_query(Matcher::Exact("per_page=100&state=open".into()))
+            .with_status(200)
+            .with_header("link", &link)
+            .with_body(json!([sample_issue(1)]).to_string())
+            .create();
+        for page in [2, 3] {
+            server
+                .mock("GET", "/repos/octo/demo/issues")
+                .match_query(Matcher::UrlEncoded("page".into(), page.to_string()))
+                .with_status(200)
+                .with_body(json!([sample_issue(page)]).to_string())
+                .expect(1)
+                .create();
+        }
+
+        let a = adapter(&server);
+        let docs = a.list(&ListFilter::default()).unwrap();
+        let ids: Vec<&str> = docs.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn page_urls_stop_once_limit_is_covered() {
+        let template =
+            Url::parse("https://api.example.com/x?per_page=100&state=open&page=9").unwrap();
+        let urls = page_urls(&template, 9, 150).unwrap();
+        assert_eq!(
+            urls,
+            vec![
+                "https://api.example.com/x?per_page=100&state=open&page=2",
+                "https://api.example.com/x?per_page=100&state=open&page=3",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn async_adapter_lists_issues() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/repos/octo/demo/issues")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(json!([sample_issue(7)]).to_string())
+            .create_async()
+            .await;
+
+        let a: std::sync::Arc<dyn crate::adapters::AsyncAdapter> =
+            std::sync::Arc::new(adapter(&server));
+        let docs = a.list(&ListFilter::default()).await.unwrap();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].id, "7");
+    }
+
     #[test]
     fn parse_next_link_picks_only_next() {
         let v = HeaderValue::from_static(
@@ -1859,7 +2961,10 @@ mod integration {
             status_active: "open".into(),
             status_alt: "closed".into(),
             delete_is_archive: true,
-            supports_links: false,
+            supports_links: true,
+            // An existing issue in the test repository for the new issue
+            // to depend on.
+            link_target: std::env::var("TEST_GITHUB_LINK_TARGET").unwrap_or_else(|_| "1".into()),
             ..ComplianceOptions::default()
         }
     }
@@ -1910,6 +3015,7 @@ mod integration {
                 fields: update_fields,
                 clear: vec![],
                 replace_links: None,
+                expected_version: None,
             },
         )
         .expect("update");
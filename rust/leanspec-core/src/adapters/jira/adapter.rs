@@ -17,6 +17,31 @@
 //!   api_version: 3   # optional; 3 (Cloud, default) or 2 (Server / DC)
 //! ```
 //!
+//! ## Async
+//!
+//! Like the GitHub adapter, this one is natively async: [`AsyncAdapter`]
+//! calls share one pooled `reqwest` client and search pages after the first
+//! are fetched concurrently. The blocking [`Adapter`] impl drives the same
+//! code on a small internal runtime.
+//!
+//! [`AsyncAdapter`]: crate::adapters::AsyncAdapter
+//!
+//! ## Incremental refresh
+//!
+//! `list_conditional` turns the previous fetch time into an `updated >= -Nm`
+//! JQL window. One query finds every project issue touched since then; if
+//! none were, the cached listing is reported unchanged. Otherwise the
+//! listing's own JQL is rerun inside the window and the result returned as a
+//! delta: issues still matching are upserted, the rest removed. Issues
+//! deleted outright in Jira are not detected until the next full fetch.
+//!
+//! ## Links
+//!
+//! The `parent` field (epics and sub-task parents) maps to `parent` links and
+//! Jira issue links to the link keys configured in `link_types` — by default
+//! Blocks → `depends_on`, Relates → `relates_to` and Cloners → `clones`. See
+//! [`JiraLinkType`](super::JiraLinkType) for the setting's format.
+//!
 //! ## Delete semantics
 //!
 //! Jira's hard delete needs admin permission and is destructive.
@@ -28,18 +53,23 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
-use reqwest::blocking::{Client, RequestBuilder, Response};
 use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
-use reqwest::{Method, StatusCode};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
 use serde_json::{json, Value};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 use super::adf;
+use super::links::{self, JiraLinkType, LinkPlan};
+use crate::adapters::async_adapter::{block_on, AdapterFuture};
+use crate::adapters::retry::parse_retry_after;
 use crate::adapters::{
-    Adapter, AdapterCapabilities, AdapterError, ListFilter, SearchHit, SearchOptions,
+    Adapter, AdapterCapabilities, AdapterError, ListFilter, Revalidated, SearchHit, SearchOptions,
+    Validator,
 };
 use crate::model::{
-    semantic, CreateRequest, EnumOption, FieldDef, FieldDisplay, FieldKind, FieldValue,
-    LinkTypeDef, SpecDoc, SpecSchema, UpdateRequest,
+    semantic, Comment, CommentRequest, CreateRequest, EnumOption, FieldDef, FieldDisplay,
+    FieldKind, FieldValue, SpecDoc, SpecSchema, UpdateRequest,
 };
 
 /// Adapter name used in errors and capabilities.
@@ -53,7 +83,18 @@ const DEFAULT_PAGE_SIZE: u32 = 100;
 
 /// Default upper bound on items returned by `list` when pagination is not
 /// capped by the caller.
-const DEFAULT_LIST_LIMIT: usize = 1000;
+pub(super) const DEFAULT_LIST_LIMIT: usize = 1000;
+
+/// Maximum number of search pages fetched in parallel.
+const PAGE_CONCURRENCY: usize = 4;
+
+/// Listings older than this are refetched in full instead of patched with an
+/// `updated >=` delta.
+const MAX_DELTA_AGE: chrono::Duration = chrono::Duration::hours(24);
+
+/// Extra minutes added to the delta window. JQL `updated` comparisons have
+/// minute precision and server clocks drift.
+const DELTA_SLACK_MINUTES: i64 = 2;
 
 /// Schema id assigned to Story / Feature issue types.
 const SCHEMA_FEATURE: &str = "leanspec:feature";
@@ -74,7 +115,11 @@ pub mod field {
 
 /// Link type keys declared by the Jira adapter schema.
 pub mod link {
+    pub const PARENT: &str = "parent";
+    pub const CHILD: &str = "child";
     pub const DEPENDS_ON: &str = "depends_on";
+    pub const RELATES_TO: &str = "relates_to";
+    pub const CLONES: &str = "clones";
 }
 
 fn build_schema() -> SpecSchema {
@@ -171,12 +216,7 @@ fn build_schema() -> SpecSchema {
                 placeholder: None,
             },
         ],
-        link_types: vec![LinkTypeDef {
-            key: link::DEPENDS_ON.into(),
-            label: "Depends on".into(),
-            inverse_key: Some("blocked_by".into()),
-            inverse_label: Some("Blocked by".into()),
-        }],
+        link_types: links::link_type_defs(&JiraLinkType::defaults()),
     }
 }
 
@@ -189,7 +229,9 @@ fn build_capabilities() -> AdapterCapabilities {
         supports_delete: true,
         supports_search: true,
         supports_webhooks: false,
+        supports_comments: true,
         default_schema: SCHEMA_ID.into(),
+        cache: None,
     }
 }
 
@@ -203,13 +245,15 @@ pub struct JiraAdapter {
     project: String,
     email: String,
     token: String,
-    api_version: u8,
+    pub(super) api_version: u8,
     /// Base URL override — `https://{host}` in production, `server.url()` in
     /// tests. Used so the test suite can route traffic at a mock server.
-    base_url: String,
+    pub(super) base_url: String,
     client: Client,
     capabilities: AdapterCapabilities,
     schema: SpecSchema,
+    /// Jira issue link types reported as links.
+    pub(super) link_types: Vec<JiraLinkType>,
 }
 
 impl JiraAdapter {
@@ -298,20 +342,35 @@ impl JiraAdapter {
             client,
             capabilities: build_capabilities(),
             schema: build_schema(),
+            link_types: JiraLinkType::defaults(),
         })
     }
 
+    /// Replace the default Jira link type mapping (the `link_types`
+    /// setting). The schema's link types follow.
+    pub fn with_link_types(mut self, link_types: Vec<JiraLinkType>) -> Self {
+        self.schema.link_types = links::link_type_defs(&link_types);
+        self.link_types = link_types;
+        self
+    }
+
     /// Fetch project status / priority vocabularies and bake them into the
     /// adapter's own schema. Invoked by [`AdapterRegistry::create`] so callers
     /// that only call `adapter.schema()` see the resolved options.
     pub fn resolve_inline(&mut self) -> Result<(), AdapterError> {
+        block_on(self.resolve_inline_async())
+    }
+
+    /// Async form of [`Self::resolve_inline`], used by
+    /// [`AdapterRegistry::create_async`].
+    pub async fn resolve_inline_async(&mut self) -> Result<(), AdapterError> {
         let mut schema = std::mem::replace(&mut self.schema, build_schema());
-        self.resolve_schema(&mut schema)?;
+        let resolved = crate::adapters::AsyncAdapter::resolve_schema(&*self, &mut schema).await;
         self.schema = schema;
-        Ok(())
+        resolved
     }
 
-    fn url(&self, path: &str) -> String {
+    pub(super) fn url(&self, path: &str) -> String {
         format!(
             "{}/rest/api/{}{}",
             self.base_url.trim_end_matches('/'),
@@ -330,68 +389,74 @@ impl JiraAdapter {
         h
     }
 
-    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+    pub(super) fn request(&self, method: Method, url: &str) -> RequestBuilder {
         self.client
             .request(method, url)
             .headers(self.auth_headers())
             .basic_auth(&self.email, Some(&self.token))
     }
 
-    /// Send a request and map HTTP errors onto [`AdapterError`].
-    fn send(&self, req: RequestBuilder) -> Result<Response, AdapterError> {
-        let resp = req.send().map_err(|e| AdapterError::BackendError {
-            adapter: ADAPTER_NAME.into(),
-            reason: format!("network: {e}"),
-        })?;
-
-        let status = resp.status();
-        if status.is_success() {
-            return Ok(resp);
-        }
-
-        let headers = resp.headers().clone();
-        let body = resp.text().unwrap_or_default();
-        Err(map_error(status, &headers, &body))
-    }
-
-    fn parse_json(resp: Response) -> Result<Value, AdapterError> {
-        resp.json().map_err(|e| AdapterError::ParseError {
+    pub(super) async fn parse_json(resp: Response) -> Result<Value, AdapterError> {
+        resp.json().await.map_err(|e| AdapterError::ParseError {
             path: "jira response".into(),
             reason: e.to_string(),
         })
     }
 
+    fn search_request(&self, jql: &str, start_at: usize) -> RequestBuilder {
+        self.request(Method::GET, &self.url("/search")).query(&[
+            ("jql", jql),
+            ("maxResults", &DEFAULT_PAGE_SIZE.to_string()),
+            ("startAt", &start_at.to_string()),
+        ])
+    }
+
     /// Paginate `/search` results until all issues have been collected or the
     /// `limit` cap is reached.
-    fn paginate_search(&self, jql: &str, limit: usize) -> Result<Vec<Value>, AdapterError> {
-        let mut out: Vec<Value> = Vec::new();
-        let mut start_at = 0_u32;
-        loop {
-            let url = self.url("/search");
-            let resp = self.send(self.request(Method::GET, &url).query(&[
-                ("jql", jql),
-                ("maxResults", &DEFAULT_PAGE_SIZE.to_string()),
-                ("startAt", &start_at.to_string()),
-            ]))?;
-            let value = Self::parse_json(resp)?;
-            let issues = value
-                .get("issues")
-                .and_then(|v| v.as_array())
-                .cloned()
-                .unwrap_or_default();
-            let total = value.get("total").and_then(|v| v.as_u64()).unwrap_or(0);
-            let returned = issues.len();
-            for item in issues {
-                if out.len() >= limit {
-                    return Ok(out);
-                }
-                out.push(item);
-            }
-            start_at = start_at.saturating_add(returned as u32);
-            if returned == 0 || (start_at as u64) >= total {
-                break;
-            }
+    ///
+    /// The first page reports `total` and the page size the server actually
+    /// honoured, so the remaining `startAt` offsets are known up front and
+    /// fetched concurrently (at most [`PAGE_CONCURRENCY`] at a time).
+    pub(super) async fn paginate_search(
+        &self,
+        jql: &str,
+        limit: usize,
+    ) -> Result<Vec<Value>, AdapterError> {
+        let resp = send(self.search_request(jql, 0)).await?;
+        let first = Self::parse_json(resp).await?;
+        let total = first.get("total").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
+        let mut out = search_issues(first);
+        let stride = out.len();
+        if stride == 0 || out.len() >= limit.min(total) {
+            out.truncate(limit);
+            return Ok(out);
+        }
+
+        let permits = std::sync::Arc::new(Semaphore::new(PAGE_CONCURRENCY));
+        let mut tasks = JoinSet::new();
+        for (index, start_at) in (stride..total.min(limit)).step_by(stride).enumerate() {
+            let req = self.search_request(jql, start_at);
+            let permits = permits.clone();
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                let resp = send(req).await?;
+                Ok::<_, AdapterError>((index, Self::parse_json(resp).await?))
+            });
+        }
+
+        let mut pages: Vec<(usize, Value)> = Vec::with_capacity(tasks.len());
+        while let Some(joined) = tasks.join_next().await {
+            let page = joined.map_err(|e| AdapterError::BackendError {
+                adapter: ADAPTER_NAME.into(),
+                reason: format!("page fetch failed: {e}"),
+            })??;
+            pages.push(page);
+        }
+        pages.sort_by_key(|(index, _)| *index);
+        for (_, page) in pages {
+            out.extend(search_issues(page));
         }
+        out.truncate(limit);
         Ok(out)
     }
 
@@ -538,12 +603,12 @@ impl JiraAdapter {
     /// Run a status transition by finding the transition whose target status
     /// matches `name` (case-insensitive), or — if `name` is `None` — the
     /// first transition whose target `statusCategory.key == "done"`.
-    fn transition(&self, id: &str, target: TransitionTarget<'_>) -> Result<(), AdapterError> {
+    async fn transition(&self, id: &str, target: TransitionTarget<'_>) -> Result<(), AdapterError> {
         let list_url = self.url(&format!("/issue/{id}/transitions"));
-        let resp = self
-            .send(self.request(Method::GET, &list_url))
+        let resp = send(self.request(Method::GET, &list_url))
+            .await
             .map_err(|e| with_not_found_id(e, id))?;
-        let value = Self::parse_json(resp)?;
+        let value = Self::parse_json(resp).await?;
         let transitions = value
             .get("transitions")
             .and_then(|v| v.as_array())
@@ -565,14 +630,44 @@ impl JiraAdapter {
         })?;
 
         let url = self.url(&format!("/issue/{id}/transitions"));
-        self.send(
+        send(
             self.request(Method::POST, &url)
                 .json(&json!({ "transition": { "id": id_value } })),
-        )?;
+        )
+        .await?;
         Ok(())
     }
 }
 
+/// Send a request and map HTTP errors onto [`AdapterError`].
+pub(super) async fn send(req: RequestBuilder) -> Result<Response, AdapterError> {
+    let resp = req.send().await.map_err(|e| AdapterError::Unavailable {
+        adapter: ADAPTER_NAME.into(),
+        reason: format!("network: {e}"),
+        retry_after: None,
+    })?;
+
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+
+    let headers = resp.headers().clone();
+    let body = resp.text().await.unwrap_or_default();
+    Err(map_error(status, &headers, &body))
+}
+
+/// The `issues` array of a `/search` response page.
+fn search_issues(page: Value) -> Vec<Value> {
+    match page {
+        Value::Object(mut map) => match map.remove("issues") {
+            Some(Value::Array(issues)) => issues,
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
 /// What to look for when picking a transition.
 enum TransitionTarget<'a> {
     /// Transition whose target `statusCategory.key == "done"`.
@@ -592,29 +687,146 @@ impl Adapter for JiraAdapter {
     }
 
     fn resolve_schema(&self, schema: &mut SpecSchema) -> Result<(), AdapterError> {
-        // Project statuses live at /project/{key}/statuses and return an array
-        // of issue types, each with its own status list. We union them.
-        let status_url = self.url(&format!("/project/{}/statuses", self.project));
-        let resp = self.send(self.request(Method::GET, &status_url))?;
-        let value = Self::parse_json(resp)?;
-        let mut status_options: Vec<EnumOption> = Vec::new();
-        let mut seen_status: std::collections::HashSet<String> = std::collections::HashSet::new();
-        for issuetype in value.as_array().cloned().unwrap_or_default() {
-            for status in issuetype
-                .get("statuses")
-                .and_then(|v| v.as_array())
-                .cloned()
-                .unwrap_or_default()
-            {
-                if let Some(name) = status.get("name").and_then(|v| v.as_str()) {
-                    if seen_status.insert(name.to_string()) {
-                        let description = status
-                            .get("description")
-                            .and_then(|v| v.as_str())
-                            .filter(|s| !s.is_empty())
-                            .map(String::from);
-                        status_options.push(EnumOption {
-                            value: name.to_string(),
+        block_on(crate::adapters::AsyncAdapter::resolve_schema(self, schema))
+    }
+
+    fn list(&self, filter: &ListFilter) -> Result<Vec<SpecDoc>, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::list(self, filter))
+    }
+
+    fn get(&self, id: &str) -> Result<SpecDoc, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::get(self, id))
+    }
+
+    fn create(&self, req: &CreateRequest) -> Result<SpecDoc, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::create(self, req))
+    }
+
+    fn update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::update(self, id, req))
+    }
+
+    fn delete(&self, id: &str) -> Result<(), AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::delete(self, id))
+    }
+
+    fn search(&self, query: &str, opts: &SearchOptions) -> Result<Vec<SearchHit>, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::search(self, query, opts))
+    }
+
+    fn list_conditional(
+        &self,
+        filter: &ListFilter,
+        previous: Option<&Validator>,
+    ) -> Result<Revalidated<Vec<SpecDoc>>, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::list_conditional(
+            self, filter, previous,
+        ))
+    }
+
+    fn list_comments(&self, id: &str) -> Result<Vec<Comment>, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::list_comments(self, id))
+    }
+
+    fn add_comment(&self, id: &str, req: &CommentRequest) -> Result<Comment, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::add_comment(self, id, req))
+    }
+
+    fn edit_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::edit_comment(
+            self, id, comment_id, body,
+        ))
+    }
+
+    fn resolve_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        resolved: bool,
+    ) -> Result<Comment, AdapterError> {
+        block_on(crate::adapters::AsyncAdapter::resolve_comment(
+            self, id, comment_id, resolved,
+        ))
+    }
+}
+
+impl crate::adapters::AsyncAdapter for JiraAdapter {
+    fn capabilities(&self) -> &AdapterCapabilities {
+        &self.capabilities
+    }
+
+    fn schema(&self) -> &SpecSchema {
+        &self.schema
+    }
+
+    fn resolve_schema<'a>(&'a self, schema: &'a mut SpecSchema) -> AdapterFuture<'a, ()> {
+        Box::pin(async move {
+            // Project statuses live at /project/{key}/statuses and return an array
+            // of issue types, each with its own status list. We union them.
+            let status_url = self.url(&format!("/project/{}/statuses", self.project));
+            let resp = send(self.request(Method::GET, &status_url)).await?;
+            let value = Self::parse_json(resp).await?;
+            let mut status_options: Vec<EnumOption> = Vec::new();
+            let mut seen_status: std::collections::HashSet<String> =
+                std::collections::HashSet::new();
+            for issuetype in value.as_array().cloned().unwrap_or_default() {
+                for status in issuetype
+                    .get("statuses")
+                    .and_then(|v| v.as_array())
+                    .cloned()
+                    .unwrap_or_default()
+                {
+                    if let Some(name) = status.get("name").and_then(|v| v.as_str()) {
+                        if seen_status.insert(name.to_string()) {
+                            let description = status
+                                .get("description")
+                                .and_then(|v| v.as_str())
+                                .filter(|s| !s.is_empty())
+                                .map(String::from);
+                            status_options.push(EnumOption {
+                                value: name.to_string(),
+                                label: name.to_string(),
+                                color: None,
+                                icon: None,
+                                description,
+                            });
+                        }
+                    }
+                }
+            }
+            status_options.sort_by(|a, b| a.value.cmp(&b.value));
+
+            // Priorities are global. The endpoint returns an array of objects with
+            // at least { id, name, description }. Priority `value` is the
+            // normalized LeanSpec form (matching what `issue_to_doc` stores in
+            // documents); `label` keeps the live Jira name so writes can map
+            // back (see `normalized_priority_to_jira_name`).
+            let priority_url = self.url("/priority");
+            let resp = send(self.request(Method::GET, &priority_url)).await?;
+            let value = Self::parse_json(resp).await?;
+            let mut priority_options: Vec<EnumOption> = Vec::new();
+            let mut seen_priority: std::collections::HashSet<String> =
+                std::collections::HashSet::new();
+            for item in value.as_array().cloned().unwrap_or_default() {
+                if let Some(name) = item.get("name").and_then(|v| v.as_str()) {
+                    let description = item
+                        .get("description")
+                        .and_then(|v| v.as_str())
+                        .filter(|s| !s.is_empty())
+                        .map(String::from);
+                    let normalized = priority_name_to_value(name);
+                    // Two Jira priorities can collapse to the same normalized
+                    // value (e.g. "Highest" + "Critical" → "critical"). Keep the
+                    // first one we see — the write inverse mapping will surface
+                    // that label, which is the project's canonical name.
+                    if seen_priority.insert(normalized.clone()) {
+                        priority_options.push(EnumOption {
+                            value: normalized,
                             label: name.to_string(),
                             color: None,
                             icon: None,
@@ -623,215 +835,306 @@ impl Adapter for JiraAdapter {
                     }
                 }
             }
-        }
-        status_options.sort_by(|a, b| a.value.cmp(&b.value));
-
-        // Priorities are global. The endpoint returns an array of objects with
-        // at least { id, name, description }. Priority `value` is the
-        // normalized LeanSpec form (matching what `issue_to_doc` stores in
-        // documents); `label` keeps the live Jira name so writes can map
-        // back (see `normalized_priority_to_jira_name`).
-        let priority_url = self.url("/priority");
-        let resp = self.send(self.request(Method::GET, &priority_url))?;
-        let value = Self::parse_json(resp)?;
-        let mut priority_options: Vec<EnumOption> = Vec::new();
-        let mut seen_priority: std::collections::HashSet<String> = std::collections::HashSet::new();
-        for item in value.as_array().cloned().unwrap_or_default() {
-            if let Some(name) = item.get("name").and_then(|v| v.as_str()) {
-                let description = item
-                    .get("description")
-                    .and_then(|v| v.as_str())
-                    .filter(|s| !s.is_empty())
-                    .map(String::from);
-                let normalized = priority_name_to_value(name);
-                // Two Jira priorities can collapse to the same normalized
-                // value (e.g. "Highest" + "Critical" → "critical"). Keep the
-                // first one we see — the write inverse mapping will surface
-                // that label, which is the project's canonical name.
-                if seen_priority.insert(normalized.clone()) {
-                    priority_options.push(EnumOption {
-                        value: normalized,
-                        label: name.to_string(),
-                        color: None,
-                        icon: None,
-                        description,
-                    });
-                }
-            }
-        }
-        priority_options.sort_by(|a, b| a.value.cmp(&b.value));
-
-        for f in schema.fields.iter_mut() {
-            if let FieldKind::Enum { options, .. } = &mut f.kind {
-                if f.key == field::STATUS && !status_options.is_empty() {
-                    *options = status_options.clone();
-                } else if f.key == field::PRIORITY && !priority_options.is_empty() {
-                    *options = priority_options.clone();
+            priority_options.sort_by(|a, b| a.value.cmp(&b.value));
+
+            for f in schema.fields.iter_mut() {
+                if let FieldKind::Enum { options, .. } = &mut f.kind {
+                    if f.key == field::STATUS && !status_options.is_empty() {
+                        *options = status_options.clone();
+                    } else if f.key == field::PRIORITY && !priority_options.is_empty() {
+                        *options = priority_options.clone();
+                    }
                 }
             }
-        }
 
-        Ok(())
+            Ok(())
+        })
     }
 
-    fn list(&self, filter: &ListFilter) -> Result<Vec<SpecDoc>, AdapterError> {
-        let jql = self.list_jql(filter);
-        let issues = self.paginate_search(&jql, DEFAULT_LIST_LIMIT)?;
-        Ok(issues
-            .iter()
-            .map(|v| issue_to_doc(v, self.api_version))
-            .collect())
+    fn list<'a>(&'a self, filter: &'a ListFilter) -> AdapterFuture<'a, Vec<SpecDoc>> {
+        Box::pin(async move {
+            let jql = self.list_jql(filter);
+            let issues = self.paginate_search(&jql, DEFAULT_LIST_LIMIT).await?;
+            let mut docs: Vec<SpecDoc> = issues
+                .iter()
+                .map(|v| issue_to_doc(v, self.api_version, &self.link_types))
+                .collect();
+            self.resolve_titles(&mut docs).await?;
+            Ok(docs)
+        })
     }
 
-    fn get(&self, id: &str) -> Result<SpecDoc, AdapterError> {
-        let url = self.url(&format!("/issue/{id}"));
-        let resp = self
-            .send(self.request(Method::GET, &url))
-            .map_err(|e| with_not_found_id(e, id))?;
-        let value = Self::parse_json(resp)?;
-        Ok(issue_to_doc(&value, self.api_version))
+    fn list_conditional<'a>(
+        &'a self,
+        filter: &'a ListFilter,
+        previous: Option<&'a Validator>,
+    ) -> AdapterFuture<'a, Revalidated<Vec<SpecDoc>>> {
+        Box::pin(async move {
+            let age = previous.map(|v| Utc::now() - v.fetched_at);
+            let validator = Validator::now();
+            let Some(age) = age.filter(|age| *age < MAX_DELTA_AGE) else {
+                let docs = crate::adapters::AsyncAdapter::list(self, filter).await?;
+                return Ok(Revalidated::Replaced(docs, validator));
+            };
+
+            let window = format!(
+                "updated >= -{}m",
+                age.num_minutes().max(0) + DELTA_SLACK_MINUTES
+            );
+            let touched = self
+                .paginate_search(
+                    &format!("project = {} AND {window}", jql_quote(&self.project)),
+                    DEFAULT_LIST_LIMIT,
+                )
+                .await?;
+            if touched.is_empty() {
+                return Ok(Revalidated::Unchanged(validator));
+            }
+
+            let jql = format!("{} AND {window}", self.list_jql(filter));
+            let mut upserted: Vec<SpecDoc> = self
+                .paginate_search(&jql, DEFAULT_LIST_LIMIT)
+                .await?
+                .iter()
+                .map(|v| issue_to_doc(v, self.api_version, &self.link_types))
+                .collect();
+            self.resolve_titles(&mut upserted).await?;
+            let removed = touched
+                .iter()
+                .filter_map(|v| v.get("key").and_then(|k| k.as_str()))
+                .filter(|key| !upserted.iter().any(|d| d.id == *key))
+                .map(str::to_string)
+                .collect();
+            Ok(Revalidated::Changed {
+                upserted,
+                removed,
+                validator,
+            })
+        })
     }
 
-    fn create(&self, req: &CreateRequest) -> Result<SpecDoc, AdapterError> {
-        let issue_type = schema_id_to_issue_type(req.schema_id.as_deref());
-        let mut fields = self.fields_payload(Some(&req.title), &req.fields, Some(issue_type));
-        fields.insert("project".into(), json!({ "key": self.project }));
-
-        // Status on create: Jira ignores `status` in POST /issue. Apply via a
-        // follow-up transition if the caller asked for a non-default status.
-        let requested_status = req
-            .fields
-            .get(field::STATUS)
-            .and_then(|v| v.as_str())
-            .map(String::from);
+    fn get<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, SpecDoc> {
+        Box::pin(async move {
+            let url = self.url(&format!("/issue/{id}"));
+            let resp = send(self.request(Method::GET, &url))
+                .await
+                .map_err(|e| with_not_found_id(e, id))?;
+            let value = Self::parse_json(resp).await?;
+            let mut docs = [issue_to_doc(&value, self.api_version, &self.link_types)];
+            self.resolve_titles(&mut docs).await?;
+            let [doc] = docs;
+            Ok(doc)
+        })
+    }
 
-        // Strip the status from the create body — Jira rejects it.
-        fields.remove("status");
+    fn create<'a>(&'a self, req: &'a CreateRequest) -> AdapterFuture<'a, SpecDoc> {
+        Box::pin(async move {
+            let link_plan = LinkPlan::new("", &req.links, &self.link_types)?;
+            let issue_type = schema_id_to_issue_type(req.schema_id.as_deref());
+            let mut fields = self.fields_payload(Some(&req.title), &req.fields, Some(issue_type));
+            fields.insert("project".into(), json!({ "key": self.project }));
+
+            // Status on create: Jira ignores `status` in POST /issue. Apply via a
+            // follow-up transition if the caller asked for a non-default status.
+            let requested_status = req
+                .fields
+                .get(field::STATUS)
+                .and_then(|v| v.as_str())
+                .map(String::from);
+
+            // Strip the status from the create body — Jira rejects it.
+            fields.remove("status");
+
+            let url = self.url("/issue");
+            let resp = send(
+                self.request(Method::POST, &url)
+                    .json(&json!({ "fields": fields })),
+            )
+            .await?;
+            let value = Self::parse_json(resp).await?;
+
+            // POST /issue returns `{ id, key, self }` only. Fetch the full issue
+            // so callers get a complete SpecDoc (status, etc.).
+            let key = value
+                .get("key")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| AdapterError::ParseError {
+                    path: "jira POST /issue response".into(),
+                    reason: "missing 'key' field".into(),
+                })?
+                .to_string();
+
+            if let Some(target) = requested_status {
+                // Best-effort: a missing transition surfaces as InvalidField.
+                self.transition(&key, TransitionTarget::Named(&target))
+                    .await?;
+            }
 
-        let url = self.url("/issue");
-        let resp = self.send(
-            self.request(Method::POST, &url)
-                .json(&json!({ "fields": fields })),
-        )?;
-        let value = Self::parse_json(resp)?;
-
-        // POST /issue returns `{ id, key, self }` only. Fetch the full issue
-        // so callers get a complete SpecDoc (status, etc.).
-        let key = value
-            .get("key")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| AdapterError::ParseError {
-                path: "jira POST /issue response".into(),
-                reason: "missing 'key' field".into(),
-            })?
-            .to_string();
-
-        if let Some(target) = requested_status {
-            // Best-effort: a missing transition surfaces as InvalidField.
-            self.transition(&key, TransitionTarget::Named(&target))?;
-        }
+            if !link_plan.is_empty() {
+                self.apply_links(&key, &link_plan).await?;
+            }
 
-        self.get(&key)
+            crate::adapters::AsyncAdapter::get(self, &key).await
+        })
     }
 
-    fn update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
-        reject_unknown_fields(&req.fields, &self.schema)?;
+    fn update<'a>(&'a self, id: &'a str, req: &'a UpdateRequest) -> AdapterFuture<'a, SpecDoc> {
+        Box::pin(async move {
+            reject_unknown_fields(&req.fields, &self.schema)?;
+            let link_plan = req
+                .replace_links
+                .as_deref()
+                .map(|l| LinkPlan::new(id, l, &self.link_types))
+                .transpose()?;
+            if req.expected_version.is_some() {
+                // Jira has no conditional edit, so the check races with
+                // concurrent writers but still catches stale forms.
+                crate::adapters::check_version(
+                    req.expected_version.as_deref(),
+                    &crate::adapters::AsyncAdapter::get(self, id).await?,
+                )?;
+            }
 
-        // Status changes go through the transition API; everything else goes
-        // through PUT /issue/{id}.
-        let status_target = req
-            .fields
-            .get(field::STATUS)
-            .and_then(|v| v.as_str())
-            .map(String::from);
+            // Status changes go through the transition API; everything else goes
+            // through PUT /issue/{id}.
+            let status_target = req
+                .fields
+                .get(field::STATUS)
+                .and_then(|v| v.as_str())
+                .map(String::from);
+
+            let mut fields_no_status = req.fields.clone();
+            fields_no_status.remove(field::STATUS);
+
+            // Honour explicit clears by inserting an empty / null value for each.
+            let mut payload = self.fields_payload(req.title.as_deref(), &fields_no_status, None);
+            for key in &req.clear {
+                match key.as_str() {
+                    field::TAGS => {
+                        payload.insert("labels".into(), Value::Array(vec![]));
+                    }
+                    field::ASSIGNEE => {
+                        payload.insert("assignee".into(), Value::Null);
+                    }
+                    field::PRIORITY => {
+                        payload.insert("priority".into(), Value::Null);
+                    }
+                    field::DUE => {
+                        payload.insert("duedate".into(), Value::Null);
+                    }
+                    field::CONTENT => {
+                        payload.insert("description".into(), Value::Null);
+                    }
+                    _ => {}
+                }
+            }
 
-        let mut fields_no_status = req.fields.clone();
-        fields_no_status.remove(field::STATUS);
+            if !payload.is_empty() {
+                let url = self.url(&format!("/issue/{id}"));
+                send(
+                    self.request(Method::PUT, &url)
+                        .json(&json!({ "fields": payload })),
+                )
+                .await
+                .map_err(|e| with_not_found_id(e, id))?;
+            }
 
-        // Honour explicit clears by inserting an empty / null value for each.
-        let mut payload = self.fields_payload(req.title.as_deref(), &fields_no_status, None);
-        for key in &req.clear {
-            match key.as_str() {
-                field::TAGS => {
-                    payload.insert("labels".into(), Value::Array(vec![]));
-                }
-                field::ASSIGNEE => {
-                    payload.insert("assignee".into(), Value::Null);
-                }
-                field::PRIORITY => {
-                    payload.insert("priority".into(), Value::Null);
-                }
-                field::DUE => {
-                    payload.insert("duedate".into(), Value::Null);
-                }
-                field::CONTENT => {
-                    payload.insert("description".into(), Value::Null);
-                }
-                _ => {}
+            if let Some(plan) = &link_plan {
+                self.apply_links(id, plan).await?;
             }
-        }
 
-        if !payload.is_empty() {
-            let url = self.url(&format!("/issue/{id}"));
-            self.send(
-                self.request(Method::PUT, &url)
-                    .json(&json!({ "fields": payload })),
-            )
-            .map_err(|e| with_not_found_id(e, id))?;
-        }
+            if let Some(target) = status_target {
+                self.transition(id, TransitionTarget::Named(&target))
+                    .await?;
+            }
 
-        if let Some(target) = status_target {
-            self.transition(id, TransitionTarget::Named(&target))?;
-        }
+            crate::adapters::AsyncAdapter::get(self, id).await
+        })
+    }
+
+    fn delete<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, ()> {
+        Box::pin(async move {
+            // Jira hard delete is admin-only and destructive. Match the GitHub
+            // adapter's "archive on delete" semantics by transitioning to a done
+            // status. The compliance test asserts that get() still succeeds after
+            // delete and that list(default) excludes the item.
+            self.transition(id, TransitionTarget::ToDone).await
+        })
+    }
 
-        self.get(id)
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        opts: &'a SearchOptions,
+    ) -> AdapterFuture<'a, Vec<SearchHit>> {
+        Box::pin(async move {
+            let jql = format!(
+                "project = {} AND text ~ {}",
+                jql_quote(&self.project),
+                jql_quote(query)
+            );
+            let limit = opts.limit.unwrap_or(30).min(100);
+            let issues = self.paginate_search(&jql, limit).await?;
+            Ok(issues
+                .iter()
+                .map(|item| {
+                    let id = item
+                        .get("key")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    // Jira /search has no per-issue score; lean on order for now.
+                    let snippet = if opts.include_body {
+                        let desc = item.get("fields").and_then(|f| f.get("description"));
+                        desc.and_then(|d| {
+                            if d.is_object() {
+                                adf::to_markdown(d).ok()
+                            } else {
+                                d.as_str().map(String::from)
+                            }
+                        })
+                        .map(|s| s.chars().take(200).collect())
+                    } else {
+                        None
+                    };
+                    SearchHit {
+                        id,
+                        score: 0.0,
+                        snippet,
+                    }
+                })
+                .collect())
+        })
     }
 
-    fn delete(&self, id: &str) -> Result<(), AdapterError> {
-        // Jira hard delete is admin-only and destructive. Match the GitHub
-        // adapter's "archive on delete" semantics by transitioning to a done
-        // status. The compliance test asserts that get() still succeeds after
-        // delete and that list(default) excludes the item.
-        self.transition(id, TransitionTarget::ToDone)
+    fn list_comments<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, Vec<Comment>> {
+        Box::pin(self.list_issue_comments(id))
     }
 
-    fn search(&self, query: &str, opts: &SearchOptions) -> Result<Vec<SearchHit>, AdapterError> {
-        let jql = format!(
-            "project = {} AND text ~ {}",
-            jql_quote(&self.project),
-            jql_quote(query)
-        );
-        let limit = opts.limit.unwrap_or(30).min(100);
-        let issues = self.paginate_search(&jql, limit)?;
-        Ok(issues
-            .iter()
-            .map(|item| {
-                let id = item
-                    .get("key")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
-                    .to_string();
-                // Jira /search has no per-issue score; lean on order for now.
-                let snippet = if opts.include_body {
-                    let desc = item.get("fields").and_then(|f| f.get("description"));
-                    desc.and_then(|d| {
-                        if d.is_object() {
-                            adf::to_markdown(d).ok()
-                        } else {
-                            d.as_str().map(String::from)
-                        }
-                    })
-                    .map(|s| s.chars().take(200).collect())
-                } else {
-                    None
-                };
-                SearchHit {
-                    id,
-                    score: 0.0,
-                    snippet,
-                }
-            })
-            .collect())
+    fn add_comment<'a>(
+        &'a self,
+        id: &'a str,
+        req: &'a CommentRequest,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(self.add_issue_comment(id, req))
+    }
+
+    fn edit_comment<'a>(
+        &'a self,
+        id: &'a str,
+        comment_id: &'a str,
+        body: &'a str,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(self.edit_issue_comment(id, comment_id, body))
+    }
+
+    fn resolve_comment<'a>(
+        &'a self,
+        id: &'a str,
+        comment_id: &'a str,
+        resolved: bool,
+    ) -> AdapterFuture<'a, Comment> {
+        Box::pin(self.resolve_issue_comment(id, comment_id, resolved))
     }
 }
 
@@ -857,6 +1160,22 @@ pub fn validate_token(
     token: &str,
     api_version: u8,
     base_url: Option<&str>,
+) -> Result<TokenValidation, AdapterError> {
+    block_on(validate_token_async(
+        host,
+        email,
+        token,
+        api_version,
+        base_url,
+    ))
+}
+
+async fn validate_token_async(
+    host: &str,
+    email: &str,
+    token: &str,
+    api_version: u8,
+    base_url: Option<&str>,
 ) -> Result<TokenValidation, AdapterError> {
     if !(api_version == 2 || api_version == 3) {
         return Err(AdapterError::ConfigError(format!(
@@ -888,6 +1207,7 @@ pub fn validate_token(
         .header(ACCEPT, "application/json")
         .header(USER_AGENT, "leanspec-jira-adapter")
         .send()
+        .await
         .map_err(|e| AdapterError::BackendError {
             adapter: ADAPTER_NAME.into(),
             reason: format!("network: {e}"),
@@ -896,11 +1216,11 @@ pub fn validate_token(
     let status = resp.status();
     if !status.is_success() {
         let headers = resp.headers().clone();
-        let body = resp.text().unwrap_or_default();
+        let body = resp.text().await.unwrap_or_default();
         return Err(map_error(status, &headers, &body));
     }
 
-    let body: Value = resp.json().map_err(|e| AdapterError::ParseError {
+    let body: Value = resp.json().await.map_err(|e| AdapterError::ParseError {
         path: "jira /myself response".into(),
         reason: e.to_string(),
     })?;
@@ -925,8 +1245,9 @@ pub fn validate_token(
 }
 
 /// Project a Jira issue JSON payload onto a [`SpecDoc`]. `api_version` decides
-/// whether `description` is parsed as ADF or read as a plain string.
-pub(crate) fn issue_to_doc(issue: &Value, api_version: u8) -> SpecDoc {
+/// whether `description` is parsed as ADF or read as a plain string;
+/// `link_types` decides which issue links become [`SpecDoc::links`].
+pub(crate) fn issue_to_doc(issue: &Value, api_version: u8, link_types: &[JiraLinkType]) -> SpecDoc {
     let key = issue
         .get("key")
         .and_then(|v| v.as_str())
@@ -1041,11 +1362,15 @@ pub(crate) fn issue_to_doc(issue: &Value, api_version: u8) -> SpecDoc {
         title,
         schema_id,
         fields,
-        links: Vec::new(),
+        links: links::issue_links(issue_fields, link_types),
         created_at,
         updated_at,
         url,
         raw: Some(issue.clone()),
+        version: issue_fields
+            .and_then(|f| f.get("updated"))
+            .and_then(|v| v.as_str())
+            .map(String::from),
     }
 }
 
@@ -1123,7 +1448,7 @@ fn find_named_transition(transitions: &[Value], name: &str) -> Option<String> {
 /// Quote a string for inclusion in a JQL query — wrap in double quotes and
 /// escape internal quotes and backslashes. Unquoted bare identifiers are not
 /// safe across all values (statuses can contain spaces), so always quote.
-fn jql_quote(s: &str) -> String {
+pub(super) fn jql_quote(s: &str) -> String {
     let mut out = String::with_capacity(s.len() + 2);
     out.push('"');
     for c in s.chars() {
@@ -1158,7 +1483,7 @@ fn parse_reset_header(s: &str) -> Option<DateTime<Utc>> {
 
 /// Parse a Jira datetime — typically `"2026-01-02T11:00:00.000+0000"`. Falls
 /// back to RFC3339 when the offset is given as `±HH:MM`.
-fn parse_jira_datetime(s: &str) -> Option<DateTime<Utc>> {
+pub(super) fn parse_jira_datetime(s: &str) -> Option<DateTime<Utc>> {
     // Try RFC3339 first; Jira Cloud's timestamps usually parse directly once
     // a colon is inserted into the offset.
     if let Ok(d) = DateTime::parse_from_rfc3339(s) {
@@ -1181,7 +1506,7 @@ fn parse_jira_datetime(s: &str) -> Option<DateTime<Utc>> {
 
 /// Re-emit a generic `NotFound(message)` as `NotFound(id)` at the call site
 /// where `id` is known. Matches the GitHub adapter's behaviour.
-fn with_not_found_id(err: AdapterError, id: &str) -> AdapterError {
+pub(super) fn with_not_found_id(err: AdapterError, id: &str) -> AdapterError {
     match err {
         AdapterError::NotFound(_) => AdapterError::NotFound(id.to_string()),
         other => other,
@@ -1233,21 +1558,16 @@ pub(crate) fn map_error(status: StatusCode, headers: &HeaderMap, body: &str) ->
                 .get("x-ratelimit-reset")
                 .and_then(|h| h.to_str().ok())
                 .and_then(parse_reset_header)
-                .or_else(|| {
-                    headers
-                        .get("retry-after")
-                        .and_then(|h| h.to_str().ok())
-                        .and_then(|s| s.parse::<i64>().ok())
-                        .map(|secs| Utc::now() + chrono::Duration::seconds(secs))
-                });
+                .or_else(|| parse_retry_after(headers));
             AdapterError::RateLimit {
                 adapter: ADAPTER_NAME.into(),
                 reset_at: reset,
             }
         }
-        s if (500..600).contains(&s) => AdapterError::BackendError {
+        s if s == 408 || (500..600).contains(&s) => AdapterError::Unavailable {
             adapter: ADAPTER_NAME.into(),
             reason: format!("HTTP {s}: {body}"),
+            retry_after: parse_retry_after(headers),
         },
         s => AdapterError::BackendError {
             adapter: ADAPTER_NAME.into(),
@@ -1278,6 +1598,7 @@ use chrono::TimeZone;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::ItemLink;
     use mockito::Matcher;
     use serde_json::json;
 
@@ -1351,7 +1672,7 @@ mod tests {
             status_active: "To Do".into(),
             status_alt: "Done".into(),
             delete_is_archive: true,
-            supports_links: false,
+            supports_links: true,
             ..ComplianceOptions::default()
         };
         check_schema_consistency(&a, &opts);
@@ -1376,7 +1697,7 @@ mod tests {
     #[test]
     fn issue_to_doc_maps_all_fields() {
         let v = sample_issue("PROJ-42");
-        let doc = issue_to_doc(&v, 3);
+        let doc = issue_to_doc(&v, 3, &JiraLinkType::defaults());
         assert_eq!(doc.id, "PROJ-42");
         assert_eq!(doc.title, "Hello world");
         // Story → leanspec:feature
@@ -1405,7 +1726,7 @@ mod tests {
     fn issue_to_doc_v2_treats_description_as_plain_text() {
         let mut v = sample_issue("PROJ-1");
         v["fields"]["description"] = json!("Plain text body");
-        let doc = issue_to_doc(&v, 2);
+        let doc = issue_to_doc(&v, 2, &JiraLinkType::defaults());
         assert_eq!(doc.field_str(field::CONTENT), Some("Plain text body"));
     }
 
@@ -1413,11 +1734,20 @@ mod tests {
     fn issue_to_doc_maps_issue_type_to_schema() {
         let mut v = sample_issue("PROJ-1");
         v["fields"]["issuetype"]["name"] = json!("Bug");
-        assert_eq!(issue_to_doc(&v, 3).schema_id, SCHEMA_BUG);
+        assert_eq!(
+            issue_to_doc(&v, 3, &JiraLinkType::defaults()).schema_id,
+            SCHEMA_BUG
+        );
         v["fields"]["issuetype"]["name"] = json!("Epic");
-        assert_eq!(issue_to_doc(&v, 3).schema_id, SCHEMA_BASE);
+        assert_eq!(
+            issue_to_doc(&v, 3, &JiraLinkType::defaults()).schema_id,
+            SCHEMA_BASE
+        );
         v["fields"]["issuetype"]["name"] = json!("Feature");
-        assert_eq!(issue_to_doc(&v, 3).schema_id, SCHEMA_FEATURE);
+        assert_eq!(
+            issue_to_doc(&v, 3, &JiraLinkType::defaults()).schema_id,
+            SCHEMA_FEATURE
+        );
     }
 
     #[test]
@@ -1648,6 +1978,7 @@ mod tests {
                 fields,
                 clear: vec![],
                 replace_links: None,
+                expected_version: None,
             },
         )
         .unwrap();
@@ -1697,6 +2028,7 @@ mod tests {
                 fields,
                 clear: vec![],
                 replace_links: None,
+                expected_version: None,
             },
         )
         .unwrap();
@@ -1718,12 +2050,184 @@ mod tests {
                     fields,
                     clear: vec![],
                     replace_links: None,
+                    expected_version: None,
                 },
             )
             .unwrap_err();
         assert!(matches!(err, AdapterError::InvalidField { .. }));
     }
 
+    fn blocks(id: &str, side: &str, other: Value) -> Value {
+        let mut entry = json!({
+            "id": id,
+            "type": { "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+        });
+        entry[side] = other;
+        entry
+    }
+
+    #[test]
+    fn get_resolves_missing_link_titles_in_one_search() {
+        let mut server = mockito::Server::new();
+        let mut issue = sample_issue("PROJ-42");
+        issue["fields"]["parent"] = json!({ "key": "PROJ-1", "fields": { "summary": "Epic" } });
+        issue["fields"]["issuelinks"] = json!([
+            blocks("100", "inwardIssue", json!({ "key": "PROJ-7" })),
+            blocks("101", "inwardIssue", json!({ "key": "PROJ-8" })),
+        ]);
+        server
+            .mock("GET", "/rest/api/3/issue/PROJ-42")
+            .with_status(200)
+            .with_body(issue.to_string())
+            .create();
+        let search = server
+            .mock("GET", "/rest/api/3/search")
+            .match_query(Matcher::UrlEncoded(
+                "jql".into(),
+                r#"key in ("PROJ-7", "PROJ-8")"#.into(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "total": 2,
+                    "issues": [
+                        { "key": "PROJ-7", "fields": { "summary": "Schema" } },
+                        { "key": "PROJ-8", "fields": { "summary": "Storage" } }
+                    ]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+
+        let doc = adapter(&server).get("PROJ-42").unwrap();
+        let links: Vec<(&str, &str, Option<&str>)> = doc
+            .links
+            .iter()
+            .map(|l| {
+                (
+                    l.link_type.as_str(),
+                    l.target_id.as_str(),
+                    l.target_title.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                (link::PARENT, "PROJ-1", Some("Epic")),
+                (link::DEPENDS_ON, "PROJ-7", Some("Schema")),
+                (link::DEPENDS_ON, "PROJ-8", Some("Storage")),
+            ]
+        );
+        search.assert();
+    }
+
+    #[test]
+    fn update_replace_links_syncs_parent_and_issue_links() {
+        let mut server = mockito::Server::new();
+        let mut current = sample_issue("PROJ-42");
+        current["fields"]["parent"] = json!({ "key": "PROJ-1" });
+        current["fields"]["issuelinks"] = json!([
+            blocks("100", "inwardIssue", json!({ "key": "PROJ-2" })),
+            blocks("101", "outwardIssue", json!({ "key": "PROJ-3" })),
+            {
+                "id": "102",
+                "type": { "name": "Relates", "inward": "relates to", "outward": "relates to" },
+                "outwardIssue": { "key": "PROJ-4" }
+            },
+        ]);
+        server
+            .mock("GET", "/rest/api/3/issue/PROJ-42")
+            .match_query(Matcher::UrlEncoded(
+                "fields".into(),
+                "parent,issuelinks".into(),
+            ))
+            .with_status(200)
+            .with_body(current.to_string())
+            .create();
+        server
+            .mock("GET", "/rest/api/3/issue/PROJ-42")
+            .with_status(200)
+            .with_body(sample_issue("PROJ-42").to_string())
+            .create();
+        let set_parent = server
+            .mock("PUT", "/rest/api/3/issue/PROJ-42")
+            .match_body(Matcher::Json(
+                json!({ "fields": { "parent": { "key": "PROJ-5" } } }),
+            ))
+            .with_status(204)
+            .create();
+        // PROJ-2 is no longer a dependency; PROJ-3 is blocked by this issue,
+        // which is not a `depends_on` link and must survive.
+        let unlink = server
+            .mock("DELETE", "/rest/api/3/issueLink/100")
+            .with_status(204)
+            .create();
+        let untouched = server
+            .mock("DELETE", "/rest/api/3/issueLink/101")
+            .expect(0)
+            .create();
+        let link = server
+            .mock("POST", "/rest/api/3/issueLink")
+            .match_body(Matcher::Json(json!({
+                "type": { "name": "Blocks" },
+                "inwardIssue": { "key": "PROJ-6" },
+                "outwardIssue": { "key": "PROJ-42" }
+            })))
+            .with_status(201)
+            .expect(1)
+            .create();
+
+        let target = |link_type: &str, target_id: &str| ItemLink {
+            link_type: link_type.into(),
+            target_id: target_id.into(),
+            target_title: None,
+        };
+        adapter(&server)
+            .update(
+                "PROJ-42",
+                &UpdateRequest {
+                    replace_links: Some(vec![
+                        target(link::PARENT, "PROJ-5"),
+                        target(link::DEPENDS_ON, "PROJ-6"),
+                        target(link::RELATES_TO, "PROJ-4"),
+                    ]),
+                    ..UpdateRequest::default()
+                },
+            )
+            .unwrap();
+        set_parent.assert();
+        unlink.assert();
+        untouched.assert();
+        link.assert();
+    }
+
+    #[test]
+    fn update_rejects_invalid_links_before_writing() {
+        let mut server = mockito::Server::new();
+        let put = server
+            .mock("PUT", "/rest/api/3/issue/PROJ-42")
+            .expect(0)
+            .create();
+        let err = adapter(&server)
+            .update(
+                "PROJ-42",
+                &UpdateRequest {
+                    title: Some("Renamed".into()),
+                    replace_links: Some(vec![ItemLink {
+                        link_type: "duplicates".into(),
+                        target_id: "PROJ-1".into(),
+                        target_title: None,
+                    }]),
+                    ..UpdateRequest::default()
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(err, AdapterError::InvalidField { .. }));
+        put.assert();
+    }
+
     #[test]
     fn delete_transitions_to_done() {
         let mut server = mockito::Server::new();
@@ -1797,6 +2301,125 @@ mod tests {
         }
     }
 
+    // ─── comments ────────────────────────────────────────────────────────
+
+    fn sample_comment(id: &str, text: &str, thread: Option<Value>) -> Value {
+        let mut comment = json!({
+            "id": id,
+            "author": { "accountId": "bob-acc-id", "displayName": "Bob" },
+            "body": adf::from_markdown(text),
+            "created": "2025-01-01T10:00:00.000+0000",
+            "updated": "2025-01-01T10:00:00.000+0000",
+        });
+        if let Some(value) = thread {
+            comment["properties"] = json!([{ "key": "leanspec", "value": value }]);
+        }
+        comment
+    }
+
+    #[test]
+    fn list_comments_converts_adf_and_reads_thread_property() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/rest/api/3/issue/PROJ-1/comment")
+            .match_query(Matcher::UrlEncoded("expand".into(), "properties".into()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "comments": [
+                        sample_comment("100", "Why **not** SQLite?", Some(json!({ "resolved": true }))),
+                        sample_comment("101", "Fair.", Some(json!({ "in_reply_to": "100" }))),
+                    ],
+                    "total": 2,
+                    "startAt": 0,
+                })
+                .to_string(),
+            )
+            .create();
+
+        let comments = adapter(&server).list_comments("PROJ-1").unwrap();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].body, "Why **not** SQLite?");
+        assert_eq!(comments[0].author.as_deref(), Some("Bob"));
+        assert!(comments[0].resolved);
+        assert!(comments[0].created_at.is_some());
+        assert_eq!(comments[1].in_reply_to.as_deref(), Some("100"));
+        assert!(!comments[1].resolved);
+    }
+
+    #[test]
+    fn add_reply_stores_thread_property() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/rest/api/3/issue/PROJ-1/comment/100")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(sample_comment("100", "Why not SQLite?", None).to_string())
+            .create();
+        let post = server
+            .mock("POST", "/rest/api/3/issue/PROJ-1/comment")
+            .match_body(Matcher::PartialJson(json!({ "body": { "type": "doc" } })))
+            .with_status(201)
+            .with_body(sample_comment("101", "Fair.", None).to_string())
+            .create();
+        let property = server
+            .mock("PUT", "/rest/api/3/comment/101/properties/leanspec")
+            .match_body(Matcher::Json(
+                json!({ "resolved": false, "in_reply_to": "100" }),
+            ))
+            .with_status(200)
+            .create();
+
+        let comment = adapter(&server)
+            .add_comment(
+                "PROJ-1",
+                &CommentRequest {
+                    body: "Fair.".into(),
+                    in_reply_to: Some("100".into()),
+                    author: None,
+                },
+            )
+            .unwrap();
+        post.assert();
+        property.assert();
+        assert_eq!(comment.id, "101");
+        assert_eq!(comment.in_reply_to.as_deref(), Some("100"));
+    }
+
+    #[test]
+    fn resolve_comment_keeps_reply_target() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/rest/api/3/issue/PROJ-1/comment/101")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                sample_comment("101", "Fair.", Some(json!({ "in_reply_to": "100" }))).to_string(),
+            )
+            .create();
+        let property = server
+            .mock("PUT", "/rest/api/3/comment/101/properties/leanspec")
+            .match_body(Matcher::Json(
+                json!({ "resolved": true, "in_reply_to": "100" }),
+            ))
+            .with_status(200)
+            .create();
+        server
+            .mock("GET", "/rest/api/3/issue/PROJ-1/comment/999")
+            .match_query(Matcher::Any)
+            .with_status(404)
+            .create();
+
+        let a = adapter(&server);
+        let comment = a.resolve_comment("PROJ-1", "101", true).unwrap();
+        property.assert();
+        assert!(comment.resolved);
+        assert!(matches!(
+            a.edit_comment("PROJ-1", "999", "x"),
+            Err(AdapterError::NotFound(_))
+        ));
+    }
+
     // ─── search ──────────────────────────────────────────────────────────
 
     #[test]
@@ -2123,6 +2746,114 @@ mod tests {
         assert!(ids.contains(&"PROJ-2"));
     }
 
+    #[test]
+    fn paginate_search_fetches_remaining_offsets_in_order() {
+        let mut server = mockito::Server::new();
+        for start in 0..3 {
+            let page = json!({
+                "issues": [sample_issue(&format!("PROJ-{}", start + 1))],
+                "total": 3,
+                "startAt": start,
+                "maxResults": 1
+            });
+            server
+                .mock("GET", "/rest/api/3/search")
+                .match_query(Matcher::UrlEncoded("startAt".into(), start.to_string()))
+                .with_status(200)
+                .with_body(page.to_string())
+                .expect(1)
+                .create();
+        }
+
+        let a = adapter(&server);
+        let docs = a.list(&ListFilter::default()).unwrap();
+        let ids: Vec<&str> = docs.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, vec!["PROJ-1", "PROJ-2", "PROJ-3"]);
+    }
+
+    #[test]
+    fn cached_listing_applies_updated_delta() {
+        use crate::adapters::{CacheConfig, CachingAdapter};
+
+        let mut server = mockito::Server::new();
+        let search = |server: &mut mockito::ServerGuard, jql: &str, keys: &[&str]| {
+            let issues: Vec<Value> = keys.iter().map(|k| sample_issue(k)).collect();
+            server
+                .mock("GET", "/rest/api/3/search")
+                .match_query(Matcher::UrlEncoded("jql".into(), jql.into()))
+                .with_status(200)
+                .with_body(json!({ "issues": issues, "total": keys.len() }).to_string())
+                .expect(1)
+                .create()
+        };
+        let full = search(
+            &mut server,
+            "project = \"PROJ\" AND statusCategory != Done",
+            &["PROJ-1", "PROJ-2"],
+        );
+        // PROJ-1 was closed and PROJ-3 created since the first fetch.
+        let touched = search(
+            &mut server,
+            "project = \"PROJ\" AND updated >= -2m",
+            &["PROJ-1", "PROJ-3"],
+        );
+        let still_matching = search(
+            &mut server,
+            "project = \"PROJ\" AND statusCategory != Done AND updated >= -2m",
+            &["PROJ-3"],
+        );
+
+        let config = CacheConfig {
+            ttl: Duration::ZERO,
+            ..CacheConfig::default()
+        };
+        let cache = CachingAdapter::wrap(adapter(&server), config);
+        cache.list(&ListFilter::default()).unwrap();
+        let docs = cache.list(&ListFilter::default()).unwrap();
+
+        let ids: Vec<&str> = docs.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, vec!["PROJ-2", "PROJ-3"]);
+        full.assert();
+        touched.assert();
+        still_matching.assert();
+        assert_eq!(cache.cache_stats().unwrap().revalidated, 1);
+    }
+
+    #[tokio::test]
+    async fn retrying_adapter_retries_rate_limited_search_async() {
+        use crate::adapters::{AsyncAdapter, RetryPolicy, RetryingAdapter};
+
+        let mut server = mockito::Server::new_async().await;
+        let limited = server
+            .mock("GET", "/rest/api/3/search")
+            .match_query(Matcher::Any)
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/rest/api/3/search")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(json!({ "issues": [sample_issue("PROJ-1")], "total": 1 }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        };
+        let a = RetryingAdapter::new(adapter(&server), policy);
+        let docs = AsyncAdapter::list(&a, &ListFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(docs.len(), 1);
+        limited.assert_async().await;
+        ok.assert_async().await;
+    }
+
     // ─── helpers ─────────────────────────────────────────────────────────
 
     #[test]
@@ -2178,7 +2909,7 @@ mod tests {
                 ]}
             ]
         });
-        let doc = issue_to_doc(&v, 3);
+        let doc = issue_to_doc(&v, 3, &JiraLinkType::defaults());
         let content = doc.field_str(field::CONTENT).unwrap();
         assert!(content.contains("## Summary"));
         assert!(content.contains("- alpha"));
@@ -2216,7 +2947,15 @@ mod integration {
             status_alt: std::env::var("TEST_JIRA_STATUS_ALT")
                 .unwrap_or_else(|_| "In Progress".into()),
             delete_is_archive: true,
-            supports_links: false,
+            supports_links: true,
+            // An existing issue in the test project for the new issue to
+            // depend on.
+            link_target: std::env::var("TEST_JIRA_LINK_TARGET").unwrap_or_else(|_| {
+                format!(
+                    "{}-1",
+                    std::env::var("TEST_JIRA_PROJECT").unwrap_or_default()
+                )
+            }),
             ..ComplianceOptions::default()
         }
     }
@@ -0,0 +1,246 @@
+//! Issue comments as spec [`Comment`]s.
+//!
+//! Bodies are ADF on API v3 and wiki text on v2, converted the same way as
+//! issue descriptions. Jira comments are flat and have no resolved state, so
+//! the reply target and resolved flag live in a `leanspec` comment property,
+//! read back through `expand=properties`.
+
+use reqwest::Method;
+use serde_json::{json, Value};
+
+use super::adapter::{
+    parse_jira_datetime, send, with_not_found_id, JiraAdapter, ADAPTER_NAME, DEFAULT_LIST_LIMIT,
+};
+use super::adf;
+use crate::adapters::AdapterError;
+use crate::model::{Comment, CommentRequest};
+
+/// Comment property holding LeanSpec's thread state.
+const PROPERTY_KEY: &str = "leanspec";
+
+/// Comments requested per page.
+const PAGE_SIZE: usize = 100;
+
+/// The `leanspec` property value, when present.
+fn thread_property(value: &Value) -> Option<&Value> {
+    value
+        .get("properties")
+        .and_then(|p| p.as_array())?
+        .iter()
+        .find(|p| p.get("key").and_then(|k| k.as_str()) == Some(PROPERTY_KEY))
+        .and_then(|p| p.get("value"))
+}
+
+impl JiraAdapter {
+    fn comment_body(&self, value: &Value) -> String {
+        let body = match value.get("body") {
+            Some(body) if self.api_version == 3 && body.is_object() => {
+                adf::to_markdown(body).ok().unwrap_or_default()
+            }
+            Some(body) => body.as_str().unwrap_or("").to_string(),
+            None => String::new(),
+        };
+        body.trim_end_matches('\n').to_string()
+    }
+
+    fn body_value(&self, body: &str) -> Value {
+        if self.api_version == 3 {
+            adf::from_markdown(body)
+        } else {
+            Value::String(body.to_string())
+        }
+    }
+
+    /// Project a Jira comment onto a [`Comment`], taking the thread state
+    /// from `thread` (the `leanspec` property value).
+    fn comment_from_json(&self, key: &str, value: &Value, thread: Option<&Value>) -> Comment {
+        let id = value
+            .get("id")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+        let time = |field: &str| {
+            value
+                .get(field)
+                .and_then(|v| v.as_str())
+                .and_then(parse_jira_datetime)
+        };
+        let created_at = time("created");
+        let updated_at = time("updated").filter(|u| Some(*u) != created_at);
+        let author = value.get("author").and_then(|a| {
+            ["displayName", "accountId", "name"]
+                .iter()
+                .find_map(|k| a.get(*k).and_then(|v| v.as_str()))
+                .map(String::from)
+        });
+        let url = format!(
+            "{}/browse/{key}?focusedCommentId={id}",
+            self.base_url.trim_end_matches('/')
+        );
+        Comment {
+            author,
+            body: self.comment_body(value),
+            created_at,
+            updated_at,
+            resolved: thread
+                .and_then(|t| t.get("resolved"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            in_reply_to: thread
+                .and_then(|t| t.get("in_reply_to"))
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            url: Some(url),
+            id,
+        }
+    }
+
+    fn comment_url(&self, key: &str, comment_id: &str) -> String {
+        self.url(&format!("/issue/{key}/comment/{comment_id}"))
+    }
+
+    fn property_url(&self, comment_id: &str) -> String {
+        self.url(&format!("/comment/{comment_id}/properties/{PROPERTY_KEY}"))
+    }
+
+    /// Fetch comment `comment_id` of issue `key` with its properties.
+    async fn fetch_comment(&self, key: &str, comment_id: &str) -> Result<Value, AdapterError> {
+        let not_found = || AdapterError::NotFound(format!("comment {comment_id} on {key}"));
+        if comment_id.is_empty() || !comment_id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(not_found());
+        }
+        let resp = send(
+            self.request(Method::GET, &self.comment_url(key, comment_id))
+                .query(&[("expand", "properties")]),
+        )
+        .await
+        .map_err(|e| match e {
+            AdapterError::NotFound(_) => not_found(),
+            other => other,
+        })?;
+        Self::parse_json(resp).await
+    }
+
+    async fn put_thread(&self, comment_id: &str, thread: &Value) -> Result<(), AdapterError> {
+        send(
+            self.request(Method::PUT, &self.property_url(comment_id))
+                .json(thread),
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub(super) async fn list_issue_comments(
+        &self,
+        key: &str,
+    ) -> Result<Vec<Comment>, AdapterError> {
+        let url = self.url(&format!("/issue/{key}/comment"));
+        let mut out = Vec::new();
+        loop {
+            let resp = send(self.request(Method::GET, &url).query(&[
+                ("expand", "properties".to_string()),
+                ("startAt", out.len().to_string()),
+                ("maxResults", PAGE_SIZE.to_string()),
+            ]))
+            .await
+            .map_err(|e| with_not_found_id(e, key))?;
+            let page = Self::parse_json(resp).await?;
+            let comments = page
+                .get("comments")
+                .and_then(|c| c.as_array())
+                .cloned()
+                .unwrap_or_default();
+            let fetched = comments.len();
+            out.extend(
+                comments
+                    .iter()
+                    .map(|c| self.comment_from_json(key, c, thread_property(c))),
+            );
+            let total = page
+                .get("total")
+                .and_then(|v| v.as_u64())
+                .map_or(out.len(), |t| t as usize);
+            if fetched == 0 || out.len() >= total || out.len() >= DEFAULT_LIST_LIMIT {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    pub(super) async fn add_issue_comment(
+        &self,
+        key: &str,
+        req: &CommentRequest,
+    ) -> Result<Comment, AdapterError> {
+        check_body(&req.body)?;
+        if let Some(parent) = &req.in_reply_to {
+            self.fetch_comment(key, parent)
+                .await
+                .map_err(|_| invalid(format!("no comment '{parent}' to reply to on {key}")))?;
+        }
+        let url = self.url(&format!("/issue/{key}/comment"));
+        let resp = send(
+            self.request(Method::POST, &url)
+                .json(&json!({ "body": self.body_value(&req.body) })),
+        )
+        .await
+        .map_err(|e| with_not_found_id(e, key))?;
+        let value = Self::parse_json(resp).await?;
+        let thread = req
+            .in_reply_to
+            .as_ref()
+            .map(|parent| json!({ "resolved": false, "in_reply_to": parent }));
+        if let Some(thread) = &thread {
+            let id = value.get("id").and_then(|v| v.as_str()).unwrap_or("");
+            self.put_thread(id, thread).await?;
+        }
+        Ok(self.comment_from_json(key, &value, thread.as_ref()))
+    }
+
+    pub(super) async fn edit_issue_comment(
+        &self,
+        key: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment, AdapterError> {
+        check_body(body)?;
+        let current = self.fetch_comment(key, comment_id).await?;
+        let resp = send(
+            self.request(Method::PUT, &self.comment_url(key, comment_id))
+                .json(&json!({ "body": self.body_value(body) })),
+        )
+        .await?;
+        let value = Self::parse_json(resp).await?;
+        Ok(self.comment_from_json(key, &value, thread_property(&current)))
+    }
+
+    pub(super) async fn resolve_issue_comment(
+        &self,
+        key: &str,
+        comment_id: &str,
+        resolved: bool,
+    ) -> Result<Comment, AdapterError> {
+        let current = self.fetch_comment(key, comment_id).await?;
+        let mut thread = thread_property(&current)
+            .filter(|t| t.is_object())
+            .cloned()
+            .unwrap_or_else(|| json!({}));
+        thread["resolved"] = Value::Bool(resolved);
+        self.put_thread(comment_id, &thread).await?;
+        Ok(self.comment_from_json(key, &current, Some(&thread)))
+    }
+}
+
+fn invalid(reason: String) -> AdapterError {
+    AdapterError::InvalidField {
+        adapter: ADAPTER_NAME.into(),
+        reason,
+    }
+}
+
+fn check_body(body: &str) -> Result<(), AdapterError> {
+    if body.trim().is_empty() {
+        return Err(invalid("comment body must not be empty".into()));
+    }
+    Ok(())
+}
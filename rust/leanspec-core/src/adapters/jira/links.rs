@@ -0,0 +1,605 @@
+//! Issue links and hierarchy as [`ItemLink`]s.
+//!
+//! Jira issue links are typed ("Blocks", "Relates", "Cloners", …) and
+//! directed: an issue lists each of its links with the issue on the other
+//! end as either `inwardIssue` or `outwardIssue`, described by the link
+//! type's `inward` / `outward` phrase. A [`JiraLinkType`] maps one link type
+//! name, seen from one side, onto a LeanSpec link key:
+//!
+//! | Jira | Read on the issue as | Link |
+//! |------|----------------------|------|
+//! | `parent` field | child of X | `parent` X |
+//! | Blocks | "is blocked by" X | `depends_on` X |
+//! | Relates | "relates to" X | `relates_to` X |
+//! | Cloners | "clones" X | `clones` X |
+//!
+//! Types whose inward and outward phrases are the same ("relates to") match
+//! from either side. The mapping is configurable with the `link_types`
+//! adapter setting, which replaces the defaults above:
+//!
+//! ```yaml
+//! settings:
+//!   link_types:
+//!     Blocks: depends_on                            # inward side
+//!     Cloners: { key: clones, direction: outward }
+//! ```
+//!
+//! Link types that are not mapped are ignored on reads and left untouched on
+//! writes. Titles come from the summaries Jira embeds in links and in the
+//! `parent` field; targets without one are looked up with a single
+//! `key in (…)` search per batch.
+
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+
+use super::adapter::{
+    jql_quote, link, send, with_not_found_id, JiraAdapter, ADAPTER_NAME, DEFAULT_LIST_LIMIT,
+};
+use crate::adapters::AdapterError;
+use crate::model::{ItemLink, LinkTypeDef, SpecDoc};
+
+/// Issue keys per title lookup search.
+const TITLE_BATCH: usize = 100;
+
+/// Which side of a Jira link the document sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkDirection {
+    /// The document reads the link with the type's `inward` phrase
+    /// ("is blocked by"); the target is the link's inward issue.
+    Inward,
+    /// The document reads the link with the type's `outward` phrase
+    /// ("clones"); the target is the link's outward issue.
+    Outward,
+}
+
+/// Mapping of one Jira issue link type onto a LeanSpec link key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraLinkType {
+    /// Jira link type name, matched case-insensitively.
+    pub name: String,
+    /// Link key reported on documents.
+    pub key: String,
+    pub direction: LinkDirection,
+}
+
+impl JiraLinkType {
+    pub fn new(name: impl Into<String>, key: impl Into<String>, direction: LinkDirection) -> Self {
+        Self {
+            name: name.into(),
+            key: key.into(),
+            direction,
+        }
+    }
+
+    /// The built-in mapping: Blocks, Relates and Cloners.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new("Blocks", link::DEPENDS_ON, LinkDirection::Inward),
+            Self::new("Relates", link::RELATES_TO, LinkDirection::Inward),
+            Self::new("Cloners", link::CLONES, LinkDirection::Outward),
+        ]
+    }
+
+    /// Parse the `link_types` adapter setting: an object from Jira link type
+    /// name to either a link key (inward side) or
+    /// `{ key, direction: inward | outward }`.
+    pub fn from_settings(value: &Value) -> Result<Vec<Self>, AdapterError> {
+        let config_error =
+            |reason: String| AdapterError::ConfigError(format!("jira link_types: {reason}"));
+        let entries = value.as_object().ok_or_else(|| {
+            config_error("expected a map of Jira link type name to link key".into())
+        })?;
+
+        let mut out: Vec<Self> = Vec::with_capacity(entries.len());
+        for (name, entry) in entries {
+            let (key, direction) = match entry {
+                Value::String(key) => (key.as_str(), "inward"),
+                Value::Object(map) => (
+                    map.get("key").and_then(|v| v.as_str()).unwrap_or(""),
+                    map.get("direction")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("inward"),
+                ),
+                _ => {
+                    return Err(config_error(format!(
+                        "'{name}' must be a link key or an object"
+                    )))
+                }
+            };
+            let direction = match direction {
+                "inward" => LinkDirection::Inward,
+                "outward" => LinkDirection::Outward,
+                other => {
+                    return Err(config_error(format!(
+                        "'{name}' has direction '{other}' — expected inward or outward"
+                    )))
+                }
+            };
+            if key.is_empty() {
+                return Err(config_error(format!("'{name}' has no link key")));
+            }
+            if key == link::PARENT || key == link::CHILD {
+                return Err(config_error(format!(
+                    "'{key}' is reserved for the parent field"
+                )));
+            }
+            if out.iter().any(|t| t.key == key) {
+                return Err(config_error(format!(
+                    "link key '{key}' is mapped more than once"
+                )));
+            }
+            out.push(Self::new(name.as_str(), key, direction));
+        }
+        Ok(out)
+    }
+
+    fn matches_name(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+}
+
+/// Schema link types for the parent field and each mapped Jira link type.
+pub(super) fn link_type_defs(link_types: &[JiraLinkType]) -> Vec<LinkTypeDef> {
+    let def = |key: &str, label: &str, inverse: Option<(&str, &str)>| LinkTypeDef {
+        key: key.into(),
+        label: label.into(),
+        inverse_key: inverse.map(|(k, _)| k.into()),
+        inverse_label: inverse.map(|(_, l)| l.into()),
+    };
+    let mut defs = vec![
+        def(link::PARENT, "Parent", Some((link::CHILD, "Child issues"))),
+        def(link::CHILD, "Child issue", Some((link::PARENT, "Parent"))),
+    ];
+    for t in link_types {
+        defs.push(match t.key.as_str() {
+            link::DEPENDS_ON => def(
+                link::DEPENDS_ON,
+                "Depends on",
+                Some(("blocked_by", "Blocked by")),
+            ),
+            link::RELATES_TO => def(link::RELATES_TO, "Relates to", None),
+            link::CLONES => def(link::CLONES, "Clones", Some(("cloned_by", "Cloned by"))),
+            other => def(other, &humanize(other), None),
+        });
+    }
+    defs
+}
+
+/// `relates_to` → `Relates to`.
+fn humanize(key: &str) -> String {
+    let spaced = key.replace(['_', '-'], " ");
+    let mut chars = spaced.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// A mapped entry of an issue's `issuelinks`.
+#[derive(Debug, Clone)]
+struct MappedLink {
+    /// Jira issue link id, used to delete the link.
+    id: Option<String>,
+    link: ItemLink,
+}
+
+fn summary(issue: &Value) -> Option<String> {
+    issue
+        .pointer("/fields/summary")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+}
+
+/// Entries of `fields.issuelinks` whose type is mapped, seen from this issue.
+fn mapped_links(issue_fields: Option<&Value>, link_types: &[JiraLinkType]) -> Vec<MappedLink> {
+    let entries = issue_fields
+        .and_then(|f| f.get("issuelinks"))
+        .and_then(|v| v.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut out: Vec<MappedLink> = Vec::new();
+    for entry in entries {
+        let Some(ty) = entry.get("type") else {
+            continue;
+        };
+        let name = ty.get("name").and_then(|v| v.as_str()).unwrap_or("");
+        let Some(mapped) = link_types.iter().find(|t| t.matches_name(name)) else {
+            continue;
+        };
+        let symmetric = match (ty.get("inward"), ty.get("outward")) {
+            (Some(inward), Some(outward)) => inward == outward,
+            _ => false,
+        };
+        let (side, other) = match (entry.get("inwardIssue"), entry.get("outwardIssue")) {
+            (Some(other), _) => (LinkDirection::Inward, other),
+            (None, Some(other)) => (LinkDirection::Outward, other),
+            (None, None) => continue,
+        };
+        if !symmetric && side != mapped.direction {
+            continue;
+        }
+        let Some(target) = other.get("key").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        if out
+            .iter()
+            .any(|m| m.link.link_type == mapped.key && m.link.target_id == target)
+        {
+            continue;
+        }
+        out.push(MappedLink {
+            id: entry.get("id").and_then(|v| v.as_str()).map(String::from),
+            link: ItemLink {
+                link_type: mapped.key.clone(),
+                target_id: target.to_string(),
+                target_title: summary(other),
+            },
+        });
+    }
+    out
+}
+
+fn parent_key(issue_fields: Option<&Value>) -> Option<&str> {
+    issue_fields
+        .and_then(|f| f.get("parent"))
+        .and_then(|p| p.get("key"))
+        .and_then(|v| v.as_str())
+}
+
+/// Links of an issue: its parent, then mapped issue links.
+pub(super) fn issue_links(
+    issue_fields: Option<&Value>,
+    link_types: &[JiraLinkType],
+) -> Vec<ItemLink> {
+    let parent = issue_fields.and_then(|f| f.get("parent")).and_then(|p| {
+        Some(ItemLink {
+            link_type: link::PARENT.into(),
+            target_id: p.get("key")?.as_str()?.to_string(),
+            target_title: summary(p),
+        })
+    });
+    parent
+        .into_iter()
+        .chain(
+            mapped_links(issue_fields, link_types)
+                .into_iter()
+                .map(|m| m.link),
+        )
+        .collect()
+}
+
+fn invalid_link(reason: String) -> AdapterError {
+    AdapterError::InvalidField {
+        adapter: ADAPTER_NAME.into(),
+        reason,
+    }
+}
+
+/// Links requested for one issue, validated and grouped before anything is
+/// written.
+#[derive(Debug, Default)]
+pub(super) struct LinkPlan<'a> {
+    parent: Option<&'a str>,
+    children: Vec<&'a str>,
+    links: Vec<(&'a JiraLinkType, &'a str)>,
+}
+
+impl<'a> LinkPlan<'a> {
+    /// Validate `links` for issue `key` (empty when the issue is about to be
+    /// created).
+    pub(super) fn new(
+        key: &str,
+        links: &'a [ItemLink],
+        link_types: &'a [JiraLinkType],
+    ) -> Result<Self, AdapterError> {
+        let mut plan = LinkPlan::default();
+        for l in links {
+            let target = l.target_id.as_str();
+            if target.is_empty() || target.contains(char::is_whitespace) {
+                return Err(invalid_link(format!(
+                    "link target must be an issue key, got '{target}'"
+                )));
+            }
+            if target.eq_ignore_ascii_case(key) {
+                return Err(invalid_link(format!("issue {key} cannot link to itself")));
+            }
+            match l.link_type.as_str() {
+                link::PARENT => {
+                    if plan.parent.is_some_and(|p| !p.eq_ignore_ascii_case(target)) {
+                        return Err(invalid_link("an issue can only have one parent".into()));
+                    }
+                    plan.parent = Some(target);
+                }
+                link::CHILD => {
+                    if !plan.children.iter().any(|c| c.eq_ignore_ascii_case(target)) {
+                        plan.children.push(target);
+                    }
+                }
+                other => {
+                    let Some(ty) = link_types.iter().find(|t| t.key == other) else {
+                        let known: Vec<&str> = [link::PARENT, link::CHILD]
+                            .into_iter()
+                            .chain(link_types.iter().map(|t| t.key.as_str()))
+                            .collect();
+                        return Err(invalid_link(format!(
+                            "unknown link type '{other}' — expected one of {}",
+                            known.join(", ")
+                        )));
+                    };
+                    if !plan.links.iter().any(|(t, existing)| {
+                        t.key == ty.key && existing.eq_ignore_ascii_case(target)
+                    }) {
+                        plan.links.push((ty, target));
+                    }
+                }
+            }
+        }
+        Ok(plan)
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.parent.is_none() && self.children.is_empty() && self.links.is_empty()
+    }
+}
+
+/// Whether a failed title lookup should be ignored. Titles are cosmetic, and
+/// Jira rejects a whole `key in (…)` query when one key no longer exists.
+fn is_ignorable(err: &AdapterError) -> bool {
+    !matches!(
+        err,
+        AdapterError::AuthError { .. } | AdapterError::RateLimit { .. }
+    )
+}
+
+impl JiraAdapter {
+    /// Fill in `target_title` on links that came without one, first from
+    /// `docs` themselves and then with one search per [`TITLE_BATCH`] keys.
+    pub(super) async fn resolve_titles(&self, docs: &mut [SpecDoc]) -> Result<(), AdapterError> {
+        let mut titles: HashMap<String, String> = docs
+            .iter()
+            .map(|d| (d.id.to_ascii_uppercase(), d.title.clone()))
+            .collect();
+        let mut missing: Vec<String> = Vec::new();
+        for l in docs.iter().flat_map(|d| d.links.iter()) {
+            let key = l.target_id.to_ascii_uppercase();
+            if l.target_title.is_none() && !titles.contains_key(&key) && !missing.contains(&key) {
+                missing.push(key);
+            }
+        }
+
+        for batch in missing.chunks(TITLE_BATCH) {
+            let keys: Vec<String> = batch.iter().map(|k| jql_quote(k)).collect();
+            let jql = format!("key in ({})", keys.join(", "));
+            let issues = match self.paginate_search(&jql, batch.len()).await {
+                Ok(issues) => issues,
+                Err(e) if is_ignorable(&e) => continue,
+                Err(e) => return Err(e),
+            };
+            for issue in &issues {
+                if let (Some(key), Some(title)) =
+                    (issue.get("key").and_then(|v| v.as_str()), summary(issue))
+                {
+                    titles.insert(key.to_ascii_uppercase(), title);
+                }
+            }
+        }
+
+        for l in docs.iter_mut().flat_map(|d| d.links.iter_mut()) {
+            if l.target_title.is_none() {
+                l.target_title = titles.get(&l.target_id.to_ascii_uppercase()).cloned();
+            }
+        }
+        Ok(())
+    }
+
+    /// Make issue `key`'s parent and mapped issue links match `plan`.
+    ///
+    /// The parent and mapped links are replaced. `child` links make the
+    /// target a child of this issue; existing children are never detached
+    /// here because their `parent` link belongs to the child.
+    pub(super) async fn apply_links(
+        &self,
+        key: &str,
+        plan: &LinkPlan<'_>,
+    ) -> Result<(), AdapterError> {
+        let issue_url = |k: &str| self.url(&format!("/issue/{k}"));
+        let resp = send(
+            self.request(Method::GET, &issue_url(key))
+                .query(&[("fields", "parent,issuelinks")]),
+        )
+        .await
+        .map_err(|e| with_not_found_id(e, key))?;
+        let value = Self::parse_json(resp).await?;
+        let fields = value.get("fields");
+
+        let current_parent = parent_key(fields);
+        let parent_changed = match (current_parent, plan.parent) {
+            (Some(current), Some(wanted)) => !current.eq_ignore_ascii_case(wanted),
+            (current, wanted) => current.is_some() != wanted.is_some(),
+        };
+        if parent_changed {
+            let parent = plan.parent.map_or(Value::Null, |p| json!({ "key": p }));
+            send(
+                self.request(Method::PUT, &issue_url(key))
+                    .json(&json!({ "fields": { "parent": parent } })),
+            )
+            .await?;
+        }
+
+        if !plan.children.is_empty() {
+            let jql = format!("parent = {}", jql_quote(key));
+            let existing = self.paginate_search(&jql, DEFAULT_LIST_LIMIT).await?;
+            for child in &plan.children {
+                let attached = existing.iter().any(|issue| {
+                    issue
+                        .get("key")
+                        .and_then(|v| v.as_str())
+                        .is_some_and(|k| k.eq_ignore_ascii_case(child))
+                });
+                if attached {
+                    continue;
+                }
+                send(
+                    self.request(Method::PUT, &issue_url(child))
+                        .json(&json!({ "fields": { "parent": { "key": key } } })),
+                )
+                .await
+                .map_err(|e| with_not_found_id(e, child))?;
+            }
+        }
+
+        let current = mapped_links(fields, &self.link_types);
+        for m in &current {
+            let wanted = plan.links.iter().any(|(t, target)| {
+                t.key == m.link.link_type && target.eq_ignore_ascii_case(&m.link.target_id)
+            });
+            if wanted {
+                continue;
+            }
+            if let Some(id) = &m.id {
+                send(self.request(Method::DELETE, &self.url(&format!("/issueLink/{id}")))).await?;
+            }
+        }
+
+        for (ty, target) in &plan.links {
+            let exists = current.iter().any(|m| {
+                m.link.link_type == ty.key && m.link.target_id.eq_ignore_ascii_case(target)
+            });
+            if exists {
+                continue;
+            }
+            let (inward, outward) = match ty.direction {
+                LinkDirection::Inward => (*target, key),
+                LinkDirection::Outward => (key, *target),
+            };
+            let body = json!({
+                "type": { "name": ty.name },
+                "inwardIssue": { "key": inward },
+                "outwardIssue": { "key": outward },
+            });
+            send(
+                self.request(Method::POST, &self.url("/issueLink"))
+                    .json(&body),
+            )
+            .await
+            .map_err(|e| with_not_found_id(e, target))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_link(
+        id: &str,
+        name: &str,
+        inward: &str,
+        outward: &str,
+        side: &str,
+        other: Value,
+    ) -> Value {
+        let mut entry = json!({
+            "id": id,
+            "type": { "name": name, "inward": inward, "outward": outward },
+        });
+        entry[side] = other;
+        entry
+    }
+
+    #[test]
+    fn issue_links_map_parent_and_configured_types() {
+        let fields = json!({
+            "parent": { "key": "PROJ-1", "fields": { "summary": "Epic" } },
+            "issuelinks": [
+                issue_link("1", "Blocks", "is blocked by", "blocks",
+                    "inwardIssue", json!({ "key": "PROJ-2", "fields": { "summary": "Blocker" } })),
+                // This issue blocks PROJ-3 — the inverse, reported on PROJ-3.
+                issue_link("2", "Blocks", "is blocked by", "blocks",
+                    "outwardIssue", json!({ "key": "PROJ-3" })),
+                // Symmetric types match from either side.
+                issue_link("3", "Relates", "relates to", "relates to",
+                    "outwardIssue", json!({ "key": "PROJ-4" })),
+                issue_link("4", "Cloners", "is cloned by", "clones",
+                    "outwardIssue", json!({ "key": "PROJ-5" })),
+                issue_link("5", "Duplicate", "is duplicated by", "duplicates",
+                    "inwardIssue", json!({ "key": "PROJ-6" })),
+            ],
+        });
+        let links: Vec<(String, String, Option<String>)> =
+            issue_links(Some(&fields), &JiraLinkType::defaults())
+                .into_iter()
+                .map(|l| (l.link_type, l.target_id, l.target_title))
+                .collect();
+        assert_eq!(
+            links,
+            vec![
+                ("parent".into(), "PROJ-1".into(), Some("Epic".into())),
+                ("depends_on".into(), "PROJ-2".into(), Some("Blocker".into())),
+                ("relates_to".into(), "PROJ-4".into(), None),
+                ("clones".into(), "PROJ-5".into(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn link_types_parse_from_settings() {
+        let parsed = JiraLinkType::from_settings(&json!({
+            "Blocks": "depends_on",
+            "Duplicate": { "key": "duplicates", "direction": "outward" },
+        }))
+        .unwrap();
+        assert!(parsed.contains(&JiraLinkType::new(
+            "Blocks",
+            "depends_on",
+            LinkDirection::Inward
+        )));
+        assert!(parsed.contains(&JiraLinkType::new(
+            "Duplicate",
+            "duplicates",
+            LinkDirection::Outward
+        )));
+        assert_eq!(humanize("duplicates"), "Duplicates");
+
+        assert!(JiraLinkType::from_settings(&json!({ "Blocks": "parent" })).is_err());
+        assert!(JiraLinkType::from_settings(&json!({ "A": "x", "B": "x" })).is_err());
+        assert!(
+            JiraLinkType::from_settings(&json!({ "A": { "key": "x", "direction": "up" } }))
+                .is_err()
+        );
+        assert!(JiraLinkType::from_settings(&json!(["Blocks"])).is_err());
+    }
+
+    #[test]
+    fn link_plan_rejects_unknown_types_and_self_links() {
+        let types = JiraLinkType::defaults();
+        let link = |link_type: &str, target: &str| ItemLink {
+            link_type: link_type.into(),
+            target_id: target.into(),
+            target_title: None,
+        };
+        let ok = [
+            link("parent", "PROJ-1"),
+            link("depends_on", "PROJ-2"),
+            link("depends_on", "proj-2"),
+        ];
+        let plan = LinkPlan::new("PROJ-9", &ok, &types).unwrap();
+        assert_eq!(plan.parent, Some("PROJ-1"));
+        assert_eq!(plan.links.len(), 1);
+
+        assert!(LinkPlan::new("PROJ-9", &[link("duplicates", "PROJ-1")], &types).is_err());
+        assert!(LinkPlan::new("PROJ-9", &[link("depends_on", "proj-9")], &types).is_err());
+        assert!(LinkPlan::new("PROJ-9", &[link("depends_on", "two words")], &types).is_err());
+        assert!(LinkPlan::new(
+            "PROJ-9",
+            &[link("parent", "PROJ-1"), link("parent", "PROJ-2")],
+            &types
+        )
+        .is_err());
+    }
+}
@@ -11,14 +11,24 @@
 //! Each spec corresponds to one Jira issue: `SpecDoc::id` is the issue key
 //! (e.g. `PROJ-42`), `title` and `content` map to `summary` and `description`,
 //! and the metadata fields (`status`, `tags`, `assignee`, `priority`, `due`)
-//! are projected from the Jira issue fields.
+//! are projected from the Jira issue fields. The parent field and issue links
+//! become [`SpecDoc::links`](crate::model::SpecDoc::links).
 
 pub mod adf;
 
 #[cfg(feature = "jira")]
 mod adapter;
 
+#[cfg(feature = "jira")]
+mod comments;
+
+#[cfg(feature = "jira")]
+mod links;
+
 #[cfg(feature = "jira")]
 pub use adapter::{
     field, link, validate_token, JiraAdapter, TokenValidation, ADAPTER_NAME, SCHEMA_ID,
 };
+
+#[cfg(feature = "jira")]
+pub use links::{JiraLinkType, LinkDirection};
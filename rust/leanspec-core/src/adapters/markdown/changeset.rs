@@ -0,0 +1,789 @@
+//! Transactional multi-spec changes
+//!
+//! A [`Changeset`] stages file writes, frontmatter updates, archives and
+//! spec directory renames without touching the disk. [`Changeset::commit`]
+//! checks the relationships of every touched spec against the resulting
+//! state and then applies everything or nothing: when a step fails, the
+//! steps already taken are undone in reverse order.
+
+use super::link;
+use super::loader::{LoadError, SpecLoader};
+use super::types::SpecInfo;
+use crate::parsers::FrontmatterParser;
+use crate::relationships::{validate_spec_relationships, RelationshipError};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use thiserror::Error;
+use walkdir::WalkDir;
+
+/// Errors that can occur while staging or committing a changeset
+#[derive(Debug, Error)]
+pub enum ChangesetError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Spec not found: {0}")]
+    NotFound(String),
+
+    #[error("Load error: {0}")]
+    LoadError(#[from] LoadError),
+
+    #[error("Failed to update frontmatter of {path}: {reason}")]
+    Frontmatter { path: String, reason: String },
+
+    #[error("Cannot rename {from} to {to}: {reason}")]
+    InvalidRename {
+        from: String,
+        to: String,
+        reason: String,
+    },
+
+    #[error(transparent)]
+    Relationship(#[from] RelationshipError),
+
+    #[error("Failed to write {path}: {source}. All changes were rolled back")]
+    CommitFailed {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("Failed to write {path}: {source}. Rollback was incomplete: {rollback}")]
+    RollbackFailed {
+        path: String,
+        source: std::io::Error,
+        rollback: String,
+    },
+}
+
+/// What a successful [`Changeset::commit`] changed on disk
+#[derive(Debug, Clone, Default)]
+pub struct ChangesetSummary {
+    /// Files written, in commit order
+    pub written: Vec<PathBuf>,
+    /// Spec directories renamed, as `(from, to)` spec paths
+    pub renamed: Vec<(String, String)>,
+}
+
+/// One changed line in a staged file. Line numbers are 1-based; `None`
+/// on either side means the line is added or removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineChange {
+    pub line: usize,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Line-level changes a commit would make to one file
+#[derive(Debug, Clone)]
+pub struct FileDiff {
+    pub path: PathBuf,
+    /// True when the file does not exist yet
+    pub created: bool,
+    pub changes: Vec<LineChange>,
+}
+
+/// A staged set of spec changes that commits atomically.
+///
+/// Writes are keyed by their current on-disk path and applied before any
+/// directory rename, so callers never need to reason about paths that only
+/// exist after the commit.
+#[derive(Debug)]
+pub struct Changeset {
+    specs_dir: PathBuf,
+    writes: Vec<(PathBuf, String)>,
+    renames: Vec<(String, String)>,
+}
+
+/// How to undo one applied step
+enum Undo {
+    Restore {
+        path: PathBuf,
+        previous: Option<Vec<u8>>,
+    },
+    Rename {
+        from: PathBuf,
+        to: PathBuf,
+    },
+}
+
+impl Changeset {
+    /// Create an empty changeset for the given specs directory
+    pub fn new<P: AsRef<Path>>(specs_dir: P) -> Self {
+        Self {
+            specs_dir: specs_dir.as_ref().to_path_buf(),
+            writes: Vec::new(),
+            renames: Vec::new(),
+        }
+    }
+
+    /// True when nothing has been staged
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty() && self.renames.is_empty()
+    }
+
+    /// Staged file writes, in staging order
+    pub fn writes(&self) -> impl Iterator<Item = (&Path, &str)> {
+        self.writes.iter().map(|(p, c)| (p.as_path(), c.as_str()))
+    }
+
+    /// Staged directory renames as `(from, to)` spec paths
+    pub fn renames(&self) -> &[(String, String)] {
+        &self.renames
+    }
+
+    /// Contents of `path` as the commit would leave it: the staged write if
+    /// there is one, the file on disk otherwise.
+    pub fn read<P: AsRef<Path>>(&self, path: P) -> Result<String, ChangesetError> {
+        let path = self.resolve_path(path.as_ref());
+        match self.writes.iter().find(|(p, _)| *p == path) {
+            Some((_, content)) => Ok(content.clone()),
+            None => Ok(fs::read_to_string(&path)?),
+        }
+    }
+
+    /// Stage a full file write; a later write to the same path replaces
+    /// the earlier one.
+    pub fn write<P: AsRef<Path>>(&mut self, path: P, content: impl Into<String>) -> &mut Self {
+        let path = self.resolve_path(path.as_ref());
+        let content = content.into();
+        match self.writes.iter_mut().find(|(p, _)| *p == path) {
+            Some(entry) => entry.1 = content,
+            None => self.writes.push((path, content)),
+        }
+        self
+    }
+
+    /// Stage frontmatter updates for a spec's README, on top of any write
+    /// already staged for it. Timestamps and status transitions are kept
+    /// the same way as a direct `lean-spec update`.
+    pub fn update_frontmatter(
+        &mut self,
+        spec: &str,
+        updates: &HashMap<String, serde_yaml::Value>,
+    ) -> Result<&mut Self, ChangesetError> {
+        let info = self.resolve(spec)?;
+        let current = self.read(&info.file_path)?;
+        let updated = FrontmatterParser::new()
+            .update_frontmatter(&current, updates)
+            .map_err(|e| ChangesetError::Frontmatter {
+                path: info.path.clone(),
+                reason: e.to_string(),
+            })?;
+        Ok(self.write(&info.file_path, updated))
+    }
+
+    /// Stage archiving a spec (status only, like [`super::SpecArchiver`])
+    pub fn archive(&mut self, spec: &str) -> Result<&mut Self, ChangesetError> {
+        let mut updates = HashMap::new();
+        updates.insert(
+            "status".to_string(),
+            serde_yaml::Value::String("archived".to_string()),
+        );
+        self.update_frontmatter(spec, &updates)
+    }
+
+    /// Stage moving a spec directory to a new name next to it
+    pub fn rename(&mut self, spec: &str, new_name: &str) -> Result<&mut Self, ChangesetError> {
+        let info = self.resolve(spec)?;
+        let invalid = |reason: &str| ChangesetError::InvalidRename {
+            from: info.path.clone(),
+            to: new_name.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let mut components = Path::new(new_name).components();
+        if !matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        ) {
+            return Err(invalid("the new name must be a single directory name"));
+        }
+
+        let target = match Path::new(&info.path).parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => {
+                parent.join(new_name).to_string_lossy().to_string()
+            }
+            _ => new_name.to_string(),
+        };
+        if self.renames.iter().any(|(from, _)| *from == info.path) {
+            return Err(invalid("the spec is already being renamed"));
+        }
+        if self.renames.iter().any(|(_, to)| *to == target) || self.specs_dir.join(&target).exists()
+        {
+            return Err(invalid("the target already exists"));
+        }
+
+        self.renames.push((info.path, target));
+        Ok(self)
+    }
+
+    /// Stage moving a spec to `new_name` and rewriting every mention of its
+    /// old directory name — `depends_on`, `parent`, relative links and other
+    /// in-body references — in all markdown files under the specs directory.
+    /// When the number changes, number-only references such as
+    /// `depends_on: [042]` or `parent: 42` are renumbered as well.
+    pub fn move_spec(&mut self, spec: &str, new_name: &str) -> Result<&mut Self, ChangesetError> {
+        let info = self.resolve(spec)?;
+        let old_name = info
+            .path
+            .rsplit('/')
+            .next()
+            .unwrap_or(&info.path)
+            .to_string();
+        let renumber = match (info.number(), spec_number(new_name)) {
+            (Some(old), Some(new)) if old != new => Some((old, new)),
+            _ => None,
+        };
+
+        if let Some(number) = spec_number(new_name) {
+            let specs = SpecLoader::new(&self.specs_dir).load_all_metadata()?;
+            if let Some(other) = specs
+                .iter()
+                .find(|s| s.path != info.path && s.number() == Some(number))
+            {
+                return Err(ChangesetError::InvalidRename {
+                    from: info.path.clone(),
+                    to: new_name.to_string(),
+                    reason: format!("number {} is already used by {}", number, other.path),
+                });
+            }
+        }
+
+        self.rename(&info.path, new_name)?;
+
+        for entry in WalkDir::new(&self.specs_dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            if !entry.file_type().is_file()
+                || path.extension().and_then(|ext| ext.to_str()) != Some("md")
+            {
+                continue;
+            }
+            let content = self.read(path)?;
+            let mut rewritten = replace_spec_name(&content, &old_name, new_name);
+            if let Some((old, new)) = renumber {
+                rewritten = replace_spec_number(&rewritten, old, new);
+            }
+            if rewritten != content {
+                self.write(path, rewritten);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Line-level changes the staged writes would make, skipping files
+    /// whose content would not change
+    pub fn diff(&self) -> Vec<FileDiff> {
+        let mut diffs = Vec::new();
+        for (path, after) in &self.writes {
+            let before = fs::read_to_string(path).ok();
+            let before_lines: Vec<&str> = before.as_deref().unwrap_or("").lines().collect();
+            let after_lines: Vec<&str> = after.lines().collect();
+
+            let changes: Vec<LineChange> = (0..before_lines.len().max(after_lines.len()))
+                .filter_map(|i| {
+                    let old = before_lines.get(i).copied();
+                    let new = after_lines.get(i).copied();
+                    (old != new).then(|| LineChange {
+                        line: i + 1,
+                        before: old.map(String::from),
+                        after: new.map(String::from),
+                    })
+                })
+                .collect();
+
+            if !changes.is_empty() || before.is_none() {
+                diffs.push(FileDiff {
+                    path: path.clone(),
+                    created: before.is_none(),
+                    changes,
+                });
+            }
+        }
+        diffs
+    }
+
+    /// All specs as they will look after the commit
+    pub fn preview(&self) -> Result<Vec<SpecInfo>, ChangesetError> {
+        let parser = FrontmatterParser::new();
+        let mut specs = SpecLoader::new(&self.specs_dir).load_all()?;
+
+        for spec in &mut specs {
+            // A staged README without parseable frontmatter keeps the
+            // relationships it has on disk.
+            let staged = self.writes.iter().find(|(p, _)| *p == spec.file_path);
+            if let Some(Ok((frontmatter, body))) = staged.map(|(_, c)| parser.parse(c)) {
+                spec.frontmatter = frontmatter;
+                spec.content = body;
+            }
+            if let Some((from, to)) = self.renames.iter().find(|(from, _)| *from == spec.path) {
+                let old_dir = self.specs_dir.join(from);
+                let new_dir = self.specs_dir.join(to);
+                if let Ok(rest) = spec.file_path.strip_prefix(&old_dir) {
+                    spec.file_path = new_dir.join(rest);
+                }
+                spec.path = to.clone();
+            }
+        }
+
+        Ok(specs)
+    }
+
+    /// Check the relationships of every touched spec in the final state
+    pub fn validate(&self) -> Result<(), ChangesetError> {
+        let specs = self.preview()?;
+        let written: HashSet<&Path> = self.writes.iter().map(|(p, _)| p.as_path()).collect();
+        let renamed: HashSet<&str> = self.renames.iter().map(|(_, to)| to.as_str()).collect();
+
+        for spec in &specs {
+            let touched = renamed.contains(spec.path.as_str())
+                || written.contains(self.original_path(&spec.path, &spec.file_path).as_path());
+            if touched {
+                validate_spec_relationships(&spec.path, &specs)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate, then apply every staged change or none of them
+    pub fn commit(self) -> Result<ChangesetSummary, ChangesetError> {
+        self.validate()?;
+
+        let mut journal: Vec<Undo> = Vec::new();
+        let mut summary = ChangesetSummary::default();
+
+        let result = self.apply(&mut journal, &mut summary);
+        SpecLoader::invalidate_cached_specs_dir(&self.specs_dir);
+
+        match result {
+            Ok(()) => Ok(summary),
+            Err((path, source)) => {
+                let failures = rollback(journal);
+                SpecLoader::invalidate_cached_specs_dir(&self.specs_dir);
+                let path = path.display().to_string();
+                if failures.is_empty() {
+                    Err(ChangesetError::CommitFailed { path, source })
+                } else {
+                    Err(ChangesetError::RollbackFailed {
+                        path,
+                        source,
+                        rollback: failures.join("; "),
+                    })
+                }
+            }
+        }
+    }
+
+    fn apply(
+        &self,
+        journal: &mut Vec<Undo>,
+        summary: &mut ChangesetSummary,
+    ) -> Result<(), (PathBuf, std::io::Error)> {
+        for (path, content) in &self.writes {
+            let previous = match fs::read(path) {
+                Ok(bytes) => Some(bytes),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+                Err(e) => return Err((path.clone(), e)),
+            };
+            atomic_write(path, content).map_err(|e| (path.clone(), e))?;
+            journal.push(Undo::Restore {
+                path: path.clone(),
+                previous,
+            });
+            summary.written.push(path.clone());
+        }
+
+        for (from, to) in &self.renames {
+            let from_dir = self.specs_dir.join(from);
+            let to_dir = self.specs_dir.join(to);
+            fs::rename(&from_dir, &to_dir).map_err(|e| (from_dir.clone(), e))?;
+            journal.push(Undo::Rename {
+                from: from_dir,
+                to: to_dir,
+            });
+            summary.renamed.push((from.clone(), to.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Paths may be given relative to the specs directory or, like
+    /// [`SpecInfo::file_path`], already include it.
+    fn resolve_path(&self, path: &Path) -> PathBuf {
+        if path.starts_with(&self.specs_dir) {
+            path.to_path_buf()
+        } else {
+            self.specs_dir.join(path)
+        }
+    }
+
+    fn resolve(&self, spec: &str) -> Result<SpecInfo, ChangesetError> {
+        SpecLoader::new(&self.specs_dir)
+            .load(spec)?
+            .ok_or_else(|| ChangesetError::NotFound(spec.to_string()))
+    }
+
+    /// Map a post-rename file path back to where it lives before the commit
+    fn original_path(&self, spec_path: &str, file_path: &Path) -> PathBuf {
+        match self.renames.iter().find(|(_, to)| to == spec_path) {
+            Some((from, to)) => file_path
+                .strip_prefix(self.specs_dir.join(to))
+                .map(|rest| self.specs_dir.join(from).join(rest))
+                .unwrap_or_else(|_| file_path.to_path_buf()),
+            None => file_path.to_path_buf(),
+        }
+    }
+}
+
+/// Replace whole-name occurrences of `old` with `new`. A match must not be
+/// glued to other name characters, so "001-auth" leaves "001-auth-v2"
+/// alone.
+fn replace_spec_name(content: &str, old: &str, new: &str) -> String {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    let mut out = String::with_capacity(content.len());
+    let mut last = 0;
+    for (start, _) in content.match_indices(old) {
+        let end = start + old.len();
+        let before_ok = !content[..start]
+            .chars()
+            .next_back()
+            .is_some_and(is_name_char);
+        let after_ok = !content[end..].chars().next().is_some_and(is_name_char);
+        if before_ok && after_ok {
+            out.push_str(&content[last..start]);
+            out.push_str(new);
+            last = end;
+        }
+    }
+    out.push_str(&content[last..]);
+    out
+}
+
+/// Leading number of a spec directory name such as `042-search`.
+fn spec_number(name: &str) -> Option<u32> {
+    name.split('-').next()?.parse().ok()
+}
+
+/// Renumber number-only references to spec `old` in the relationship keys
+/// of a frontmatter block (`depends_on`, `parent` and the link lists),
+/// keeping the zero padding each reference was written with.
+fn replace_spec_number(content: &str, old: u32, new: u32) -> String {
+    let relationship_keys: Vec<&str> = [link::PARENT, link::DEPENDS_ON]
+        .into_iter()
+        .chain(link::FRONTMATTER)
+        .collect();
+
+    let mut lines = content.split_inclusive('\n');
+    let mut out = String::with_capacity(content.len());
+    match lines.next() {
+        Some(first) if first.trim_end() == "---" => out.push_str(first),
+        _ => return content.to_string(),
+    }
+
+    let mut in_relationship = false;
+    let mut closed = false;
+    for line in lines.by_ref() {
+        if line.trim_end() == "---" {
+            out.push_str(line);
+            closed = true;
+            break;
+        }
+        let value = if line.starts_with([' ', '\t', '-']) {
+            in_relationship.then_some(line)
+        } else {
+            let (key, value) = line.split_once(':').unwrap_or((line, ""));
+            in_relationship = relationship_keys.contains(&key.trim());
+            in_relationship.then_some(value)
+        };
+        match value {
+            Some(value) => {
+                let prefix = &line[..line.len() - value.len()];
+                out.push_str(prefix);
+                out.push_str(&renumber_tokens(value, old, new));
+            }
+            None => out.push_str(line),
+        }
+    }
+    if !closed {
+        return content.to_string();
+    }
+    out.extend(lines);
+    out
+}
+
+/// Replace digit runs equal to `old` that stand alone, i.e. are not part of
+/// a longer name such as `042-search` or a date.
+fn renumber_tokens(value: &str, old: u32, new: u32) -> String {
+    let is_glued = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.';
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let digits_len = rest[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - start);
+        let end = start + digits_len;
+        let digits = &rest[start..end];
+        let before = rest[..start]
+            .chars()
+            .next_back()
+            .or(out.chars().next_back());
+        let standalone =
+            !before.is_some_and(is_glued) && !rest[end..].chars().next().is_some_and(is_glued);
+
+        out.push_str(&rest[..start]);
+        if standalone && digits.parse::<u32>().ok() == Some(old) {
+            if digits.starts_with('0') {
+                out.push_str(&format!("{:0width$}", new, width = digits.len()));
+            } else {
+                out.push_str(&new.to_string());
+            }
+        } else {
+            out.push_str(digits);
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Write through a sibling temp file so readers never see a partial file
+pub fn atomic_write(path: &Path, content: &str) -> std::io::Result<()> {
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, content)?;
+    fs::rename(&temp_path, path)
+}
+
+/// Undo applied steps newest first; returns a message per step that failed
+fn rollback(journal: Vec<Undo>) -> Vec<String> {
+    let mut failures = Vec::new();
+    for undo in journal.into_iter().rev() {
+        let result = match &undo {
+            Undo::Restore {
+                path,
+                previous: Some(bytes),
+            } => fs::write(path, bytes),
+            Undo::Restore {
+                path,
+                previous: None,
+            } => fs::remove_file(path),
+            Undo::Rename { from, to } => fs::rename(to, from),
+        };
+        if let Err(e) = result {
+            let path = match &undo {
+                Undo::Restore { path, .. } => path,
+                Undo::Rename { to, .. } => to,
+            };
+            failures.push(format!("{}: {}", path.display(), e));
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_spec(dir: &Path, name: &str, extra: &str) -> PathBuf {
+        let spec_dir = dir.join(name);
+        fs::create_dir_all(&spec_dir).unwrap();
+        let readme = spec_dir.join("README.md");
+        fs::write(
+            &readme,
+            format!(
+                "---\nstatus: planned\ncreated: '2025-01-01'\n{extra}---\n\n# {name}\n\nBody.\n"
+            ),
+        )
+        .unwrap();
+        readme
+    }
+
+    fn parent_update(parent: &str) -> HashMap<String, serde_yaml::Value> {
+        let mut updates = HashMap::new();
+        updates.insert(
+            "parent".to_string(),
+            serde_yaml::Value::String(parent.to_string()),
+        );
+        updates
+    }
+
+    #[test]
+    fn commit_applies_writes_and_renames() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path();
+        write_spec(specs, "001-umbrella", "");
+        write_spec(specs, "002-child", "");
+        write_spec(specs, "003-old-name", "");
+
+        let mut changeset = Changeset::new(specs);
+        changeset
+            .update_frontmatter("002-child", &parent_update("001-umbrella"))
+            .unwrap();
+        changeset.archive("003-old-name").unwrap();
+        changeset.rename("003-old-name", "003-new-name").unwrap();
+        assert!(!specs.join("003-new-name").exists());
+
+        let summary = changeset.commit().unwrap();
+        assert_eq!(summary.written.len(), 2);
+        assert_eq!(
+            summary.renamed,
+            vec![("003-old-name".to_string(), "003-new-name".to_string())]
+        );
+
+        let loader = SpecLoader::new(specs);
+        let child = loader.load_exact("002-child").unwrap().unwrap();
+        assert_eq!(child.frontmatter.parent.as_deref(), Some("001-umbrella"));
+        let renamed = loader.load_exact("003-new-name").unwrap().unwrap();
+        assert_eq!(renamed.frontmatter.status.to_string(), "archived");
+        assert!(!specs.join("003-old-name").exists());
+    }
+
+    #[test]
+    fn commit_rejects_cycles_in_final_state() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path();
+        write_spec(specs, "001-a", "");
+        let b = write_spec(specs, "002-b", "depends_on:\n- 001-a\n");
+        let before = fs::read_to_string(&b).unwrap();
+
+        let mut changeset = Changeset::new(specs);
+        let mut updates = HashMap::new();
+        updates.insert(
+            "depends_on".to_string(),
+            serde_yaml::Value::Sequence(vec![serde_yaml::Value::String("002-b".into())]),
+        );
+        changeset.update_frontmatter("001-a", &updates).unwrap();
+
+        let err = changeset.commit().unwrap_err();
+        assert!(matches!(
+            err,
+            ChangesetError::Relationship(RelationshipError::DependencyCycle { .. })
+        ));
+        assert_eq!(fs::read_to_string(&b).unwrap(), before);
+    }
+
+    #[test]
+    fn failed_commit_rolls_back_earlier_steps() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path();
+        let readme = write_spec(specs, "001-a", "");
+        let before = fs::read_to_string(&readme).unwrap();
+
+        let mut changeset = Changeset::new(specs);
+        changeset.write("001-a/README.md", before.replace("Body.", "Changed."));
+        changeset.write("001-a/notes.md", "new sub-spec");
+        // The parent directory does not exist, so this write fails last.
+        changeset.write("missing-dir/README.md", "unreachable");
+
+        let err = changeset.commit().unwrap_err();
+        assert!(matches!(err, ChangesetError::CommitFailed { .. }));
+        assert_eq!(fs::read_to_string(&readme).unwrap(), before);
+        assert!(!specs.join("001-a/notes.md").exists());
+    }
+
+    #[test]
+    fn move_spec_rewrites_references() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path();
+        write_spec(specs, "001-auth", "");
+        write_spec(specs, "002-auth-v2", "parent: 001-auth\n");
+        let api = write_spec(specs, "003-api", "depends_on:\n- 001-auth\n- 002-auth-v2\n");
+        fs::write(
+            specs.join("003-api/DESIGN.md"),
+            "See [auth](../001-auth/README.md).\n",
+        )
+        .unwrap();
+
+        let mut changeset = Changeset::new(specs);
+        changeset.move_spec("001-auth", "004-login").unwrap();
+
+        // The moved spec's own heading, its child, and both files of 003.
+        let diff = changeset.diff();
+        assert_eq!(diff.len(), 4);
+        let api_diff = diff.iter().find(|d| d.path == api).unwrap();
+        assert_eq!(api_diff.changes.len(), 1);
+        assert_eq!(api_diff.changes[0].after.as_deref(), Some("- 004-login"));
+
+        changeset.commit().unwrap();
+        let loader = SpecLoader::new(specs);
+        let child = loader.load_exact("002-auth-v2").unwrap().unwrap();
+        assert_eq!(child.frontmatter.parent.as_deref(), Some("004-login"));
+        let api = loader.load_exact("003-api").unwrap().unwrap();
+        assert_eq!(api.frontmatter.depends_on, vec!["004-login", "002-auth-v2"]);
+        assert_eq!(
+            fs::read_to_string(specs.join("003-api/DESIGN.md")).unwrap(),
+            "See [auth](../004-login/README.md).\n"
+        );
+        assert!(loader.load_exact("004-login").unwrap().is_some());
+    }
+
+    #[test]
+    fn move_spec_renumbers_number_only_references() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path();
+        write_spec(specs, "042-x", "");
+        let child = write_spec(
+            specs,
+            "043-child",
+            "parent: '42'\ndepends_on:\n- '042'\n- 142\nrelates_to: [042, 7]\n",
+        );
+        fs::write(specs.join("043-child/DESIGN.md"), "Step 42 of 042-x.\n").unwrap();
+
+        let mut changeset = Changeset::new(specs);
+        changeset.move_spec("042-x", "057-x").unwrap();
+        changeset.commit().unwrap();
+
+        let content = fs::read_to_string(&child).unwrap();
+        assert!(content.contains("parent: '57'\n"), "{content}");
+        assert!(content.contains("- '057'\n- 142\n"), "{content}");
+        assert!(content.contains("relates_to: [057, 7]\n"), "{content}");
+        assert!(content.contains("created: '2025-01-01'"), "{content}");
+        // Only frontmatter relationships are renumbered
+        assert_eq!(
+            fs::read_to_string(specs.join("043-child/DESIGN.md")).unwrap(),
+            "Step 42 of 057-x.\n"
+        );
+
+        let loader = SpecLoader::new(specs);
+        let child = loader.load_exact("043-child").unwrap().unwrap();
+        assert_eq!(
+            loader
+                .load(child.frontmatter.parent.as_deref().unwrap())
+                .unwrap()
+                .unwrap()
+                .path,
+            "057-x"
+        );
+    }
+
+    #[test]
+    fn move_spec_rejects_taken_number() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path();
+        write_spec(specs, "001-a", "");
+        write_spec(specs, "002-b", "");
+
+        let err = Changeset::new(specs)
+            .move_spec("001-a", "002-a")
+            .unwrap_err();
+        assert!(err.to_string().contains("already used by 002-b"));
+    }
+
+    #[test]
+    fn rename_rejects_existing_target() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path();
+        write_spec(specs, "001-a", "");
+        write_spec(specs, "002-b", "");
+
+        let mut changeset = Changeset::new(specs);
+        let err = changeset.rename("001-a", "002-b").unwrap_err();
+        assert!(matches!(err, ChangesetError::InvalidRename { .. }));
+        let err = changeset.rename("001-a", "../escape").unwrap_err();
+        assert!(matches!(err, ChangesetError::InvalidRename { .. }));
+    }
+}
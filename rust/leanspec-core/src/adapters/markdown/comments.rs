@@ -0,0 +1,244 @@
+//! Spec discussion stored next to the spec.
+//!
+//! Comments live in a `comments.yaml` sidecar inside the spec directory, so
+//! they move with the spec through renames and archiving and show up in the
+//! same git history. Ids are sequential numbers local to the spec. Changes
+//! hold the spec's lock and replace the sidecar atomically.
+
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::changeset::atomic_write;
+use super::lock::with_spec_lock;
+use super::MarkdownAdapter;
+use crate::adapters::AdapterError;
+use crate::model::{Comment, CommentRequest};
+
+/// Sidecar file name inside a spec directory.
+pub const COMMENTS_FILE: &str = "comments.yaml";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CommentsFile {
+    #[serde(default)]
+    comments: Vec<Comment>,
+}
+
+fn read_comments(path: &Path) -> Result<Vec<Comment>, AdapterError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = std::fs::read_to_string(path)?;
+    let file: CommentsFile = serde_yaml::from_str(&text).map_err(|e| AdapterError::ParseError {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    Ok(file.comments)
+}
+
+fn write_comments(path: &Path, comments: Vec<Comment>) -> Result<(), AdapterError> {
+    let yaml = serde_yaml::to_string(&CommentsFile { comments }).map_err(|e| {
+        AdapterError::ParseError {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        }
+    })?;
+    atomic_write(path, &yaml)?;
+    Ok(())
+}
+
+fn invalid(reason: impl Into<String>) -> AdapterError {
+    AdapterError::InvalidField {
+        adapter: "markdown".into(),
+        reason: reason.into(),
+    }
+}
+
+fn check_body(body: &str) -> Result<(), AdapterError> {
+    if body.trim().is_empty() {
+        return Err(invalid("comment body must not be empty"));
+    }
+    Ok(())
+}
+
+impl MarkdownAdapter {
+    /// Sidecar path for spec `id`; the spec must exist.
+    fn comments_path(&self, id: &str) -> Result<PathBuf, AdapterError> {
+        Ok(self.spec_dir_of(id)?.join(COMMENTS_FILE))
+    }
+
+    pub(super) fn read_spec_comments(&self, id: &str) -> Result<Vec<Comment>, AdapterError> {
+        read_comments(&self.comments_path(id)?)
+    }
+
+    pub(super) fn append_comment(
+        &self,
+        id: &str,
+        req: &CommentRequest,
+    ) -> Result<Comment, AdapterError> {
+        check_body(&req.body)?;
+        let path = self.comments_path(id)?;
+        with_spec_lock(path.parent().unwrap_or(&self.specs_dir), || {
+            let mut comments = read_comments(&path)?;
+            if let Some(parent) = &req.in_reply_to {
+                if !comments.iter().any(|c| &c.id == parent) {
+                    return Err(invalid(format!("no comment '{parent}' to reply to")));
+                }
+            }
+            let next = comments
+                .iter()
+                .filter_map(|c| c.id.parse::<u64>().ok())
+                .max()
+                .unwrap_or(0)
+                + 1;
+            let comment = Comment {
+                id: next.to_string(),
+                author: req.author.clone().filter(|a| !a.trim().is_empty()),
+                body: req.body.clone(),
+                created_at: Some(Utc::now()),
+                updated_at: None,
+                resolved: false,
+                in_reply_to: req.in_reply_to.clone(),
+                url: None,
+            };
+            comments.push(comment.clone());
+            write_comments(&path, comments)?;
+            Ok(comment)
+        })
+    }
+
+    /// Apply `change` to comment `comment_id` of spec `id` and save.
+    pub(super) fn change_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        change: impl FnOnce(&mut Comment),
+    ) -> Result<Comment, AdapterError> {
+        let path = self.comments_path(id)?;
+        with_spec_lock(path.parent().unwrap_or(&self.specs_dir), || {
+            let mut comments = read_comments(&path)?;
+            let comment = comments
+                .iter_mut()
+                .find(|c| c.id == comment_id)
+                .ok_or_else(|| AdapterError::NotFound(format!("comment {comment_id} on {id}")))?;
+            change(comment);
+            let changed = comment.clone();
+            write_comments(&path, comments)?;
+            Ok(changed)
+        })
+    }
+
+    pub(super) fn edit_spec_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment, AdapterError> {
+        check_body(body)?;
+        self.change_comment(id, comment_id, |c| {
+            c.body = body.to_string();
+            c.updated_at = Some(Utc::now());
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::Adapter;
+    use tempfile::TempDir;
+
+    fn fixture() -> (TempDir, MarkdownAdapter) {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("001-first");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("README.md"),
+            "---\nstatus: planned\ncreated: '2025-01-01'\n---\n\n# First\n\nBody.\n",
+        )
+        .unwrap();
+        let adapter = MarkdownAdapter::new(tmp.path());
+        (tmp, adapter)
+    }
+
+    fn request(body: &str, in_reply_to: Option<&str>) -> CommentRequest {
+        CommentRequest {
+            body: body.into(),
+            in_reply_to: in_reply_to.map(String::from),
+            author: Some("alice".into()),
+        }
+    }
+
+    #[test]
+    fn comments_round_trip_through_the_sidecar() {
+        let (tmp, adapter) = fixture();
+        assert!(adapter.list_comments("001-first").unwrap().is_empty());
+
+        let first = adapter
+            .add_comment("001-first", &request("Looks good?", None))
+            .unwrap();
+        let reply = adapter
+            .add_comment("001-first", &request("Needs a test plan.", Some(&first.id)))
+            .unwrap();
+        assert_eq!((first.id.as_str(), reply.id.as_str()), ("1", "2"));
+
+        adapter
+            .edit_comment("001-first", "2", "Needs a **test plan**.")
+            .unwrap();
+        let resolved = adapter.resolve_comment("001-first", "1", true).unwrap();
+        assert!(resolved.resolved);
+
+        let comments = adapter.list_comments("001-first").unwrap();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[1].body, "Needs a **test plan**.");
+        assert_eq!(comments[1].in_reply_to.as_deref(), Some("1"));
+        assert_eq!(comments[1].author.as_deref(), Some("alice"));
+        assert!(comments[1].updated_at.is_some());
+        assert!(tmp.path().join("001-first").join(COMMENTS_FILE).exists());
+
+        // The sidecar does not change the spec itself.
+        assert_eq!(adapter.get("001-first").unwrap().title, "First");
+    }
+
+    #[test]
+    fn concurrent_comments_are_all_kept() {
+        let (_tmp, adapter) = fixture();
+        std::thread::scope(|scope| {
+            for n in 0..8 {
+                let adapter = &adapter;
+                scope.spawn(move || {
+                    adapter
+                        .add_comment("001-first", &request(&format!("Comment {n}"), None))
+                        .unwrap();
+                });
+            }
+        });
+
+        let comments = adapter.list_comments("001-first").unwrap();
+        let mut ids: Vec<u64> = comments.iter().map(|c| c.id.parse().unwrap()).collect();
+        ids.sort();
+        assert_eq!(ids, (1..=8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn comment_errors() {
+        let (_tmp, adapter) = fixture();
+        assert!(matches!(
+            adapter.add_comment("001-first", &request("  ", None)),
+            Err(AdapterError::InvalidField { .. })
+        ));
+        assert!(matches!(
+            adapter.add_comment("001-first", &request("Hi", Some("9"))),
+            Err(AdapterError::InvalidField { .. })
+        ));
+        assert!(matches!(
+            adapter.resolve_comment("001-first", "9", true),
+            Err(AdapterError::NotFound(_))
+        ));
+        assert!(matches!(
+            adapter.list_comments("999-missing"),
+            Err(AdapterError::NotFound(_))
+        ));
+    }
+}
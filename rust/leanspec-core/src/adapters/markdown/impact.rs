@@ -0,0 +1,277 @@
+//! Change impact analysis
+//!
+//! When the acceptance criteria or design of a spec change after work on
+//! the specs depending on it has started, that work may be building against
+//! outdated requirements. This module finds which of those sections changed
+//! between two versions of a spec, collects the started downstream specs
+//! and can flag them with a `needs-review` tag.
+
+use super::graph::DependencyGraph;
+use super::types::{SpecInfo, SpecStatus};
+use super::writer::{MetadataUpdate, SpecWriter, WriteError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Tag added to downstream specs that should be re-checked.
+pub const NEEDS_REVIEW_TAG: &str = "needs-review";
+
+/// `##` headings containing one of these (case-insensitive) are sections
+/// downstream work relies on.
+pub const IMPACT_SECTIONS: [&str; 2] = ["acceptance criteria", "design"];
+
+/// Downstream specs affected by a change to an upstream spec.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeImpact {
+    pub spec: String,
+    pub title: String,
+    /// Headings of the impact sections that were edited, added or removed
+    pub changed_sections: Vec<String>,
+    /// Downstream specs that are in progress or complete
+    pub affected: Vec<AffectedSpec>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AffectedSpec {
+    pub path: String,
+    pub title: String,
+    pub status: SpecStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+}
+
+/// Hashes of a spec's impact sections, enough to tell later which of them
+/// changed without keeping the content itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SectionDigest {
+    sections: Vec<(String, String)>,
+}
+
+impl SectionDigest {
+    pub fn new(content: &str) -> Self {
+        let sections = impact_sections(content)
+            .into_iter()
+            .map(|(heading, body)| (heading, crate::io::hash_content(&body)))
+            .collect();
+        Self { sections }
+    }
+
+    /// Headings of the impact sections that differ between `self` and the
+    /// later `after`, including sections added or removed.
+    pub fn changed_sections(&self, after: &SectionDigest) -> Vec<String> {
+        let (old, new) = (&self.sections, &after.sections);
+        let mut changed: Vec<String> = new
+            .iter()
+            .filter(|(heading, hash)| {
+                old.iter()
+                    .find(|(h, _)| h.eq_ignore_ascii_case(heading))
+                    .map_or(true, |(_, h)| h != hash)
+            })
+            .map(|(heading, _)| heading.clone())
+            .collect();
+        changed.extend(
+            old.iter()
+                .filter(|(heading, _)| !new.iter().any(|(h, _)| h.eq_ignore_ascii_case(heading)))
+                .map(|(heading, _)| heading.clone()),
+        );
+        changed
+    }
+}
+
+/// Headings of the impact sections that differ between two versions of a
+/// spec. Whitespace-only edits are ignored.
+pub fn changed_sections(before: &str, after: &str) -> Vec<String> {
+    SectionDigest::new(before).changed_sections(&SectionDigest::new(after))
+}
+
+/// Impact of `spec` having changed from the version digested in `before`
+/// to its current content: `None` when no impact section changed, otherwise
+/// the downstream specs (up to `max_depth` levels) that are in progress or
+/// complete.
+pub fn analyze_change(
+    graph: &DependencyGraph,
+    spec: &SpecInfo,
+    before: &SectionDigest,
+    max_depth: usize,
+) -> Option<ChangeImpact> {
+    let changed = before.changed_sections(&SectionDigest::new(&spec.content));
+    if changed.is_empty() {
+        return None;
+    }
+
+    let affected = graph
+        .get_impact_radius(&spec.path, max_depth)
+        .map(|radius| {
+            radius
+                .downstream
+                .into_iter()
+                .filter(|s| {
+                    matches!(
+                        s.frontmatter.status,
+                        SpecStatus::InProgress | SpecStatus::Complete
+                    )
+                })
+                .map(|s| AffectedSpec {
+                    status: s.frontmatter.status,
+                    assignee: s.frontmatter.assignee.clone(),
+                    path: s.path,
+                    title: s.title,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(ChangeImpact {
+        spec: spec.path.clone(),
+        title: spec.title.clone(),
+        changed_sections: changed,
+        affected,
+    })
+}
+
+/// Add the `needs-review` tag to every affected spec that doesn't carry it
+/// yet, returning the specs that were tagged.
+pub fn mark_needs_review<P: AsRef<Path>>(
+    specs_dir: P,
+    impact: &ChangeImpact,
+) -> Result<Vec<String>, WriteError> {
+    let writer = SpecWriter::new(specs_dir.as_ref());
+    let loader = super::SpecLoader::new(specs_dir.as_ref());
+    let mut marked = Vec::new();
+    for affected in &impact.affected {
+        let Some(spec) = loader.load(&affected.path)? else {
+            continue;
+        };
+        if spec.frontmatter.tags.iter().any(|t| t == NEEDS_REVIEW_TAG) {
+            continue;
+        }
+        let mut tags = spec.frontmatter.tags;
+        tags.push(NEEDS_REVIEW_TAG.to_string());
+        writer.update_metadata(&spec.path, MetadataUpdate::new().with_tags(tags))?;
+        marked.push(spec.path);
+    }
+    Ok(marked)
+}
+
+/// Impact sections of a markdown document as `(heading, normalized body)`,
+/// with the bodies of repeated headings joined.
+fn impact_sections(content: &str) -> Vec<(String, String)> {
+    let mut sections: Vec<(String, String)> = Vec::new();
+    let mut current: Option<usize> = None;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if let Some(heading) = trimmed.strip_prefix("## ") {
+            let heading = heading.trim();
+            let lower = heading.to_lowercase();
+            current = if IMPACT_SECTIONS.iter().any(|s| lower.contains(s)) {
+                // Repeated headings are compared as one section
+                match sections
+                    .iter()
+                    .position(|(h, _)| h.eq_ignore_ascii_case(heading))
+                {
+                    Some(index) => Some(index),
+                    None => {
+                        sections.push((heading.to_string(), String::new()));
+                        Some(sections.len() - 1)
+                    }
+                }
+            } else {
+                None
+            };
+        } else if trimmed.starts_with("# ") {
+            current = None;
+        } else if let Some(index) = current {
+            if !trimmed.is_empty() {
+                let body = &mut sections[index].1;
+                body.push_str(trimmed);
+                body.push('\n');
+            }
+        }
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::markdown::types::SpecFrontmatter;
+
+    const BEFORE: &str = "# Search\n\n## Overview\n\nFind specs.\n\n## Design\n\nUse an index.\n\n### Storage\n\nOn disk.\n\n## Acceptance Criteria\n\n- [ ] Fast\n";
+
+    fn spec(path: &str, status: SpecStatus, depends_on: &[&str], content: &str) -> SpecInfo {
+        SpecInfo {
+            path: path.to_string(),
+            title: path.to_string(),
+            frontmatter: SpecFrontmatter {
+                status,
+                created: "2025-01-01".to_string(),
+                priority: None,
+                tags: Vec::new(),
+                depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+                parent: None,
+                assignee: None,
+                reviewer: None,
+                issue: None,
+                pr: None,
+                epic: None,
+                breaking: None,
+                due: None,
+                updated: None,
+                completed: None,
+                created_at: None,
+                updated_at: None,
+                completed_at: None,
+                transitions: Vec::new(),
+                custom: Default::default(),
+            },
+            content: content.to_string(),
+            file_path: Default::default(),
+            is_sub_spec: false,
+            parent_spec: None,
+        }
+    }
+
+    #[test]
+    fn detects_changed_impact_sections() {
+        let reformatted = BEFORE.replace("Use an index.", "  Use an index.  \n");
+        assert!(changed_sections(BEFORE, &reformatted).is_empty());
+
+        let overview_only = BEFORE.replace("Find specs.", "Find all specs.");
+        assert!(changed_sections(BEFORE, &overview_only).is_empty());
+
+        let subsection = BEFORE.replace("On disk.", "In memory.");
+        assert_eq!(changed_sections(BEFORE, &subsection), vec!["Design"]);
+
+        let criteria = BEFORE.replace("- [ ] Fast", "- [ ] Fast\n- [ ] Fuzzy");
+        assert_eq!(
+            changed_sections(BEFORE, &criteria),
+            vec!["Acceptance Criteria"]
+        );
+
+        let removed = BEFORE.replace("## Design", "## Notes");
+        assert_eq!(changed_sections(BEFORE, &removed), vec!["Design"]);
+    }
+
+    #[test]
+    fn affects_only_started_downstream_specs() {
+        let changed = BEFORE.replace("- [ ] Fast", "- [ ] Instant");
+        let specs = vec![
+            spec("001-search", SpecStatus::Planned, &[], &changed),
+            spec("002-api", SpecStatus::InProgress, &["001-search"], ""),
+            spec("003-ui", SpecStatus::Planned, &["001-search"], ""),
+            spec("004-docs", SpecStatus::Complete, &["002-api"], ""),
+        ];
+        let graph = DependencyGraph::new(&specs);
+
+        let before = SectionDigest::new(BEFORE);
+        let impact = analyze_change(&graph, &specs[0], &before, 3).unwrap();
+        assert_eq!(impact.changed_sections, vec!["Acceptance Criteria"]);
+        let mut affected: Vec<&str> = impact.affected.iter().map(|a| a.path.as_str()).collect();
+        affected.sort();
+        assert_eq!(affected, vec!["002-api", "004-docs"]);
+
+        let current = SectionDigest::new(&changed);
+        assert!(analyze_change(&graph, &specs[0], &current, 3).is_none());
+    }
+}
@@ -0,0 +1,27 @@
+//! Per-spec write locks
+//!
+//! Read-modify-write cycles on one spec, such as a version check followed
+//! by the update it guards, hold the spec's lock so concurrent writers in
+//! the same process (the HTTP server) apply one after the other.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+static SPEC_LOCKS: OnceLock<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
+
+/// Run `f` while holding the lock for the spec stored in `spec_dir`.
+pub(super) fn with_spec_lock<T>(spec_dir: &Path, f: impl FnOnce() -> T) -> T {
+    let key = spec_dir
+        .canonicalize()
+        .unwrap_or_else(|_| spec_dir.to_path_buf());
+    let lock = SPEC_LOCKS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(key)
+        .or_default()
+        .clone();
+    let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+    f()
+}
@@ -2,18 +2,32 @@
 //!
 //! Wraps the markdown-specific loader, writer, and archiver to speak the
 //! [`Adapter`] trait, mapping each spec's YAML frontmatter and body content
-//! onto the [`SpecDoc`] shape with a declared [`SpecSchema`].
+//! onto the [`SpecDoc`] shape with a declared [`SpecSchema`]. Comments are
+//! kept in a per-spec sidecar file (see [`COMMENTS_FILE`]).
 
 mod archiver;
+mod changeset;
+mod comments;
 mod graph;
+mod impact;
 mod loader;
+mod lock;
+mod review;
 mod writer;
 
 pub mod content;
 pub mod types;
 
 pub use graph::{CompleteDependencyGraph, DependencyGraph, ImpactRadius};
+pub use impact::{
+    analyze_change, changed_sections, mark_needs_review, AffectedSpec, ChangeImpact, SectionDigest,
+    IMPACT_SECTIONS, NEEDS_REVIEW_TAG,
+};
 pub use loader::SpecHierarchyNode;
+pub use review::{
+    approval_blocker, load_review, record_review, request_review, ReviewDecision, ReviewError,
+    ReviewRecord, ReviewRequest, ReviewState, ReviewerStatus, SpecReview, REVIEW_FILE,
+};
 pub use types::{
     SpecFilterOptions, SpecFrontmatter, SpecInfo, SpecPriority, SpecStatus, StatusTransition,
 };
@@ -24,11 +38,15 @@ use std::str::FromStr;
 
 use super::{Adapter, AdapterCapabilities, AdapterError, ListFilter, SearchHit, SearchOptions};
 use crate::model::{
-    semantic, CreateRequest, EnumOption, FieldDef, FieldDisplay, FieldKind, FieldValue, ItemLink,
-    LinkTypeDef, SpecDoc, SpecSchema, UpdateRequest,
+    semantic, Comment, CommentRequest, CreateRequest, EnumOption, FieldDef, FieldDisplay,
+    FieldKind, FieldValue, ItemLink, LinkTypeDef, SpecDoc, SpecSchema, UpdateRequest,
 };
 use crate::search::{search_specs_with_options, SearchOptions as LegacySearchOptions};
 use archiver::SpecArchiver;
+pub use changeset::{
+    atomic_write, Changeset, ChangesetError, ChangesetSummary, FileDiff, LineChange,
+};
+pub use comments::COMMENTS_FILE;
 use loader::SpecLoader;
 use writer::{MetadataUpdate, SpecWriter};
 
@@ -53,6 +71,14 @@ pub mod link {
     pub const PARENT: &str = "parent";
     pub const CHILD: &str = "child";
     pub const DEPENDS_ON: &str = "depends_on";
+    pub const RELATES_TO: &str = "relates_to";
+    pub const SUPERSEDES: &str = "supersedes";
+    pub const DUPLICATES: &str = "duplicates";
+    pub const IMPLEMENTS: &str = "implements";
+
+    /// Link types stored as a list under their own frontmatter key.
+    /// `parent` and `depends_on` have dedicated frontmatter fields.
+    pub const FRONTMATTER: [&str; 4] = [RELATES_TO, SUPERSEDES, DUPLICATES, IMPLEMENTS];
 }
 
 /// Stable schema id for the markdown adapter.
@@ -302,6 +328,30 @@ fn build_schema() -> SpecSchema {
                 inverse_key: Some("blocked_by".into()),
                 inverse_label: Some("Blocked by".into()),
             },
+            LinkTypeDef {
+                key: link::RELATES_TO.into(),
+                label: "Relates to".into(),
+                inverse_key: None,
+                inverse_label: None,
+            },
+            LinkTypeDef {
+                key: link::SUPERSEDES.into(),
+                label: "Supersedes".into(),
+                inverse_key: Some("superseded_by".into()),
+                inverse_label: Some("Superseded by".into()),
+            },
+            LinkTypeDef {
+                key: link::DUPLICATES.into(),
+                label: "Duplicates".into(),
+                inverse_key: Some("duplicated_by".into()),
+                inverse_label: Some("Duplicated by".into()),
+            },
+            LinkTypeDef {
+                key: link::IMPLEMENTS.into(),
+                label: "Implements".into(),
+                inverse_key: Some("implemented_by".into()),
+                inverse_label: Some("Implemented by".into()),
+            },
         ],
     }
 }
@@ -314,7 +364,9 @@ fn build_capabilities() -> AdapterCapabilities {
         supports_delete: true,
         supports_search: true,
         supports_webhooks: false,
+        supports_comments: true,
         default_schema: SCHEMA_ID.into(),
+        cache: None,
     }
 }
 
@@ -346,6 +398,40 @@ impl MarkdownAdapter {
         SpecLoader::invalidate_cached_path(path);
     }
 
+    /// Spec `id` as [`SpecInfo`], with its file path and full frontmatter,
+    /// for markdown-only features that need more than a [`SpecDoc`].
+    pub fn load_spec_info(&self, id: &str) -> Result<SpecInfo, AdapterError> {
+        SpecLoader::new(&self.specs_dir)
+            .load(id)
+            .map_err(|e| AdapterError::ParseError {
+                path: id.to_string(),
+                reason: e.to_string(),
+            })?
+            .ok_or_else(|| AdapterError::NotFound(id.to_string()))
+    }
+
+    /// Run `f` with the directory of spec `id` while holding the spec's
+    /// write lock, so raw file edits made in `f` cannot interleave with
+    /// [`Adapter::update`] or another locked edit of the same spec.
+    pub fn with_spec_lock<T>(
+        &self,
+        id: &str,
+        f: impl FnOnce(&Path) -> T,
+    ) -> Result<T, AdapterError> {
+        let spec_dir = self.spec_dir_of(id)?;
+        Ok(lock::with_spec_lock(&spec_dir, || f(&spec_dir)))
+    }
+
+    /// Every spec, sub-specs included, as [`SpecInfo`].
+    pub fn load_spec_infos(&self) -> Result<Vec<SpecInfo>, AdapterError> {
+        SpecLoader::new(&self.specs_dir)
+            .load_all()
+            .map_err(|e| AdapterError::ParseError {
+                path: self.specs_dir.display().to_string(),
+                reason: e.to_string(),
+            })
+    }
+
     fn next_spec_number(&self) -> Result<u32, AdapterError> {
         let loader = SpecLoader::new(&self.specs_dir);
         let specs = loader
@@ -421,6 +507,13 @@ pub fn spec_info_to_doc(info: &SpecInfo) -> SpecDoc {
             target_title: None,
         });
     }
+    for key in link::FRONTMATTER {
+        links.extend(fm.link_targets(key).into_iter().map(|target| ItemLink {
+            link_type: key.into(),
+            target_id: target,
+            target_title: None,
+        }));
+    }
 
     SpecDoc {
         id: info.path.clone(),
@@ -432,7 +525,28 @@ pub fn spec_info_to_doc(info: &SpecInfo) -> SpecDoc {
         updated_at: fm.updated_at,
         url: None,
         raw: None,
+        version: spec_version(info),
+    }
+}
+
+/// Version token for a markdown spec: a hash over its parsed frontmatter and
+/// body, so any edit (through LeanSpec or by hand) changes it.
+///
+/// `None` for metadata-only loads, which carry no body to hash.
+pub fn spec_version(info: &SpecInfo) -> Option<String> {
+    if info.content.is_empty() {
+        return None;
     }
+    let mut frontmatter = info.frontmatter.clone();
+    // `custom` is a HashMap; hash its entries in key order.
+    let mut custom: Vec<_> = std::mem::take(&mut frontmatter.custom)
+        .into_iter()
+        .collect();
+    custom.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut canonical = serde_json::to_string(&frontmatter).ok()?;
+    canonical.push_str(&serde_json::to_string(&custom).ok()?);
+    canonical.push_str(&info.content);
+    Some(crate::io::hash_content(&canonical))
 }
 
 /// Build a markdown [`SpecInfo`] from a [`SpecDoc`] for code that still needs
@@ -440,7 +554,11 @@ pub fn spec_info_to_doc(info: &SpecInfo) -> SpecDoc {
 ///
 /// `body_override` lets callers supply the on-disk body when the doc was loaded
 /// without it; if `None` the body comes from the doc's content field.
-pub fn doc_to_spec_info(doc: &SpecDoc, file_path: PathBuf, body_override: Option<String>) -> SpecInfo {
+pub fn doc_to_spec_info(
+    doc: &SpecDoc,
+    file_path: PathBuf,
+    body_override: Option<String>,
+) -> SpecInfo {
     use crate::adapters::markdown::types::SpecFrontmatter;
     use std::str::FromStr;
 
@@ -545,7 +663,7 @@ pub fn doc_to_spec_info(doc: &SpecDoc, file_path: PathBuf, body_override: Option
             updated_at: doc.updated_at,
             completed_at: None,
             transitions: Vec::new(),
-            custom: std::collections::HashMap::new(),
+            custom: frontmatter_links(&doc.links),
         },
         content,
         file_path,
@@ -637,6 +755,28 @@ pub fn umbrella_completion_for_docs(
     }
 }
 
+/// Targets of `key` among `links`, in order.
+fn targets_of(links: &[ItemLink], key: &str) -> Vec<String> {
+    links
+        .iter()
+        .filter(|l| l.link_type == key)
+        .map(|l| l.target_id.clone())
+        .collect()
+}
+
+/// Custom frontmatter entries for the link types kept under their own key.
+fn frontmatter_links(links: &[ItemLink]) -> HashMap<String, serde_yaml::Value> {
+    let mut frontmatter = HashMap::new();
+    for key in link::FRONTMATTER {
+        let targets = targets_of(links, key);
+        if !targets.is_empty() {
+            let items = targets.into_iter().map(serde_yaml::Value::String).collect();
+            frontmatter.insert(key.to_string(), serde_yaml::Value::Sequence(items));
+        }
+    }
+    frontmatter
+}
+
 fn fields_to_frontmatter(
     fields: &HashMap<String, FieldValue>,
     links: &[ItemLink],
@@ -728,7 +868,7 @@ fn fields_to_frontmatter(
         updated_at: None,
         completed_at: None,
         transitions: Vec::new(),
-        custom: std::collections::HashMap::new(),
+        custom: frontmatter_links(links),
     })
 }
 
@@ -779,77 +919,24 @@ fn reject_unknown_fields(
     Ok(())
 }
 
-impl Adapter for MarkdownAdapter {
-    fn capabilities(&self) -> &AdapterCapabilities {
-        &self.capabilities
-    }
-
-    fn schema(&self) -> &SpecSchema {
-        &self.schema
-    }
-
-    fn list(&self, filter: &ListFilter) -> Result<Vec<SpecDoc>, AdapterError> {
-        let loader = SpecLoader::new(&self.specs_dir);
-        let specs = loader.load_all().map_err(|e| AdapterError::ParseError {
-            path: self.specs_dir.display().to_string(),
-            reason: e.to_string(),
-        })?;
-        let docs = specs.iter().map(spec_info_to_doc).collect::<Vec<_>>();
-        Ok(apply_list_filter(docs, filter))
-    }
-
-    fn get(&self, id: &str) -> Result<SpecDoc, AdapterError> {
-        let loader = SpecLoader::new(&self.specs_dir);
-        let info = loader
+impl MarkdownAdapter {
+    /// Directory holding spec `id`; the spec must exist.
+    fn spec_dir_of(&self, id: &str) -> Result<PathBuf, AdapterError> {
+        let info = SpecLoader::new(&self.specs_dir)
             .load(id)
             .map_err(|e| AdapterError::ParseError {
                 path: id.to_string(),
                 reason: e.to_string(),
             })?
             .ok_or_else(|| AdapterError::NotFound(id.to_string()))?;
-        Ok(spec_info_to_doc(&info))
+        Ok(info
+            .file_path
+            .parent()
+            .unwrap_or(&self.specs_dir)
+            .to_path_buf())
     }
 
-    fn create(&self, req: &CreateRequest) -> Result<SpecDoc, AdapterError> {
-        if let Some(ref id) = req.schema_id {
-            if id != SCHEMA_ID {
-                return Err(AdapterError::ConfigError(format!(
-                    "markdown adapter only supports schema '{}', got '{}'",
-                    SCHEMA_ID, id,
-                )));
-            }
-        }
-
-        let slug = req.slug.as_deref().unwrap_or(&req.title);
-        let slug = slug_sanitize(slug);
-        let number = self.next_spec_number()?;
-        let dir_name = format!("{:03}-{}", number, slug);
-
-        let frontmatter = fields_to_frontmatter(&req.fields, &req.links)?;
-        let fm_yaml =
-            serde_yaml::to_string(&frontmatter).map_err(|e| AdapterError::ParseError {
-                path: dir_name.clone(),
-                reason: e.to_string(),
-            })?;
-
-        let body = req
-            .fields
-            .get(field::CONTENT)
-            .and_then(|v| v.as_str())
-            .unwrap_or("## Overview\n\n## Design\n\n## Plan\n\n## Test\n");
-
-        let file_content = format!("---\n{}---\n\n# {}\n\n{}", fm_yaml, req.title, body);
-
-        let loader = SpecLoader::new(&self.specs_dir);
-        let info = loader
-            .create_spec(&dir_name, &req.title, &file_content)
-            .map_err(|e| AdapterError::IoError(std::io::Error::other(e.to_string())))?;
-        Ok(spec_info_to_doc(&info))
-    }
-
-    fn update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
-        reject_unknown_fields(&req.fields, &self.schema)?;
-
+    fn apply_update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
         let writer = SpecWriter::new(&self.specs_dir);
         let mut meta_update = fields_to_metadata_update(&req.fields)?;
 
@@ -868,6 +955,10 @@ impl Adapter for MarkdownAdapter {
                 .find(|l| l.link_type == link::PARENT)
                 .map(|l| l.target_id.clone());
             meta_update = meta_update.with_parent(parent);
+
+            for key in link::FRONTMATTER {
+                meta_update = meta_update.with_links(key, targets_of(links, key));
+            }
         }
 
         writer
@@ -970,6 +1061,86 @@ impl Adapter for MarkdownAdapter {
 
         self.get(id)
     }
+}
+
+impl Adapter for MarkdownAdapter {
+    fn capabilities(&self) -> &AdapterCapabilities {
+        &self.capabilities
+    }
+
+    fn schema(&self) -> &SpecSchema {
+        &self.schema
+    }
+
+    fn list(&self, filter: &ListFilter) -> Result<Vec<SpecDoc>, AdapterError> {
+        let loader = SpecLoader::new(&self.specs_dir);
+        let specs = loader.load_all().map_err(|e| AdapterError::ParseError {
+            path: self.specs_dir.display().to_string(),
+            reason: e.to_string(),
+        })?;
+        let docs = specs.iter().map(spec_info_to_doc).collect::<Vec<_>>();
+        Ok(apply_list_filter(docs, filter))
+    }
+
+    fn get(&self, id: &str) -> Result<SpecDoc, AdapterError> {
+        let loader = SpecLoader::new(&self.specs_dir);
+        let info = loader
+            .load(id)
+            .map_err(|e| AdapterError::ParseError {
+                path: id.to_string(),
+                reason: e.to_string(),
+            })?
+            .ok_or_else(|| AdapterError::NotFound(id.to_string()))?;
+        Ok(spec_info_to_doc(&info))
+    }
+
+    fn create(&self, req: &CreateRequest) -> Result<SpecDoc, AdapterError> {
+        if let Some(ref id) = req.schema_id {
+            if id != SCHEMA_ID {
+                return Err(AdapterError::ConfigError(format!(
+                    "markdown adapter only supports schema '{}', got '{}'",
+                    SCHEMA_ID, id,
+                )));
+            }
+        }
+
+        let slug = req.slug.as_deref().unwrap_or(&req.title);
+        let slug = slug_sanitize(slug);
+        let number = self.next_spec_number()?;
+        let dir_name = format!("{:03}-{}", number, slug);
+
+        let frontmatter = fields_to_frontmatter(&req.fields, &req.links)?;
+        let fm_yaml =
+            serde_yaml::to_string(&frontmatter).map_err(|e| AdapterError::ParseError {
+                path: dir_name.clone(),
+                reason: e.to_string(),
+            })?;
+
+        let body = match req.fields.get(field::CONTENT).and_then(|v| v.as_str()) {
+            Some(b) => strip_leading_title(b, &req.title),
+            None => "## Overview\n\n## Design\n\n## Plan\n\n## Test\n".to_string(),
+        };
+
+        let file_content = format!("---\n{}---\n\n# {}\n\n{}", fm_yaml, req.title, body);
+
+        let loader = SpecLoader::new(&self.specs_dir);
+        let info = loader
+            .create_spec(&dir_name, &req.title, &file_content)
+            .map_err(|e| AdapterError::IoError(std::io::Error::other(e.to_string())))?;
+        Ok(spec_info_to_doc(&info))
+    }
+
+    fn update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
+        reject_unknown_fields(&req.fields, &self.schema)?;
+        // Hold the spec's lock from the version check through the write, so
+        // two writers carrying the same version cannot both pass the check.
+        lock::with_spec_lock(&self.spec_dir_of(id)?, || {
+            if req.expected_version.is_some() {
+                super::check_version(req.expected_version.as_deref(), &self.get(id)?)?;
+            }
+            self.apply_update(id, req)
+        })
+    }
 
     fn delete(&self, id: &str) -> Result<(), AdapterError> {
         SpecArchiver::new(&self.specs_dir)
@@ -1000,6 +1171,32 @@ impl Adapter for MarkdownAdapter {
             })
             .collect())
     }
+
+    fn list_comments(&self, id: &str) -> Result<Vec<Comment>, AdapterError> {
+        self.read_spec_comments(id)
+    }
+
+    fn add_comment(&self, id: &str, req: &CommentRequest) -> Result<Comment, AdapterError> {
+        self.append_comment(id, req)
+    }
+
+    fn edit_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment, AdapterError> {
+        self.edit_spec_comment(id, comment_id, body)
+    }
+
+    fn resolve_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        resolved: bool,
+    ) -> Result<Comment, AdapterError> {
+        self.change_comment(id, comment_id, |c| c.resolved = resolved)
+    }
 }
 
 fn apply_list_filter(docs: Vec<SpecDoc>, filter: &ListFilter) -> Vec<SpecDoc> {
@@ -1204,6 +1401,71 @@ mod tests {
             .any(|l| { l.link_type == link::DEPENDS_ON && l.target_id == "001-foundation" }));
     }
 
+    #[test]
+    fn frontmatter_link_types_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path().join("specs");
+        std::fs::create_dir_all(&specs).unwrap();
+        let adapter = MarkdownAdapter::new(&specs);
+        let item_link = |link_type: &str, target: &str| ItemLink {
+            link_type: link_type.into(),
+            target_id: target.into(),
+            target_title: None,
+        };
+
+        let doc = adapter
+            .create(&CreateRequest {
+                title: "New Auth".into(),
+                links: vec![
+                    item_link(link::SUPERSEDES, "001-old-auth"),
+                    item_link(link::RELATES_TO, "003-sessions"),
+                ],
+                ..Default::default()
+            })
+            .unwrap();
+        let readme = std::fs::read_to_string(specs.join(&doc.id).join("README.md")).unwrap();
+        assert!(readme.contains("supersedes:\n- 001-old-auth"), "{readme}");
+
+        let doc = adapter
+            .update(
+                &doc.id,
+                &UpdateRequest {
+                    replace_links: Some(vec![
+                        item_link(link::DEPENDS_ON, "003-sessions"),
+                        item_link(link::IMPLEMENTS, "004-rfc"),
+                        item_link(link::IMPLEMENTS, "005-rfc-2"),
+                    ]),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let mut links: Vec<_> = doc
+            .links
+            .iter()
+            .map(|l| (l.link_type.as_str(), l.target_id.as_str()))
+            .collect();
+        links.sort();
+        assert_eq!(
+            links,
+            [
+                (link::DEPENDS_ON, "003-sessions"),
+                (link::IMPLEMENTS, "004-rfc"),
+                (link::IMPLEMENTS, "005-rfc-2"),
+            ]
+        );
+
+        // Hand-written single values and numbers are accepted too.
+        let path = specs.join(&doc.id).join("README.md");
+        let readme = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, readme.replace("status:", "duplicates: 42\nstatus:")).unwrap();
+        SpecLoader::invalidate_cached_path(&path);
+        let doc = adapter.get(&doc.id).unwrap();
+        assert!(doc
+            .links
+            .iter()
+            .any(|l| l.link_type == link::DUPLICATES && l.target_id == "42"));
+    }
+
     #[test]
     fn create_rejects_wrong_schema_id() {
         let tmp = TempDir::new().unwrap();
@@ -1275,6 +1537,71 @@ mod tests {
         assert!(matches!(err, AdapterError::InvalidField { .. }));
     }
 
+    #[test]
+    fn update_rejects_stale_version() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path().join("specs");
+        std::fs::create_dir_all(&specs).unwrap();
+        write_spec(&specs, "001-test", "planned", None);
+
+        let adapter = MarkdownAdapter::new(&specs);
+        let read = adapter.get("001-test").unwrap().version.unwrap();
+        let mut fields = HashMap::new();
+        fields.insert(field::PRIORITY.into(), FieldValue::from("high"));
+        let req = UpdateRequest {
+            fields,
+            expected_version: Some(read.clone()),
+            ..Default::default()
+        };
+        let updated = adapter.update("001-test", &req).unwrap();
+        assert_ne!(updated.version.as_deref(), Some(read.as_str()));
+
+        // Replaying the same request now targets a stale version.
+        let err = adapter.update("001-test", &req).unwrap_err();
+        match err {
+            AdapterError::Conflict { id, current } => {
+                assert_eq!(id, "001-test");
+                assert_eq!(current, updated.version);
+            }
+            other => panic!("expected conflict, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn concurrent_updates_with_the_same_version_conflict() {
+        let tmp = TempDir::new().unwrap();
+        let specs = tmp.path().join("specs");
+        std::fs::create_dir_all(&specs).unwrap();
+        write_spec(&specs, "001-test", "planned", None);
+
+        let adapter = MarkdownAdapter::new(&specs);
+        let read = adapter.get("001-test").unwrap().version.unwrap();
+        let results: Vec<_> = std::thread::scope(|scope| {
+            ["high", "low"]
+                .map(|priority| {
+                    let (adapter, read) = (&adapter, &read);
+                    scope.spawn(move || {
+                        let mut fields = HashMap::new();
+                        fields.insert(field::PRIORITY.into(), FieldValue::from(priority));
+                        let req = UpdateRequest {
+                            fields,
+                            expected_version: Some(read.clone()),
+                            ..Default::default()
+                        };
+                        adapter.update("001-test", &req)
+                    })
+                })
+                .map(|handle| handle.join().unwrap())
+                .into_iter()
+                .collect()
+        });
+
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        assert!(results
+            .iter()
+            .any(|r| matches!(r, Err(AdapterError::Conflict { .. }))));
+    }
+
     #[test]
     fn update_writes_extended_frontmatter_fields() {
         let tmp = TempDir::new().unwrap();
@@ -0,0 +1,522 @@
+//! Spec review workflow
+//!
+//! Review requests and decisions live in a `review.yaml` sidecar inside the
+//! spec directory, next to `comments.yaml`. Every decision records the hash
+//! of the spec body it was made against, so editing the spec makes earlier
+//! approvals stale without rewriting the review history. Frontmatter edits,
+//! such as status changes, do not affect the hash. Only the frontmatter
+//! `reviewer` and requested reviewers count toward the review state; anyone
+//! else's decision is kept in the history but cannot approve a spec.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::changeset::atomic_write;
+use super::lock::with_spec_lock;
+use super::types::SpecInfo;
+use crate::types::ReviewConfig;
+
+/// Sidecar file name inside a spec directory.
+pub const REVIEW_FILE: &str = "review.yaml";
+
+/// A reviewer's verdict on a spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "kebab-case")]
+pub enum ReviewDecision {
+    Approved,
+    ChangesRequested,
+}
+
+impl fmt::Display for ReviewDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReviewDecision::Approved => write!(f, "approved"),
+            ReviewDecision::ChangesRequested => write!(f, "changes-requested"),
+        }
+    }
+}
+
+/// Overall review state of a spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "kebab-case")]
+pub enum ReviewState {
+    /// No reviewer assigned or requested
+    NotRequested,
+    /// Some assigned reviewers have not approved the current content yet
+    Pending,
+    /// Every assigned reviewer approved the current content
+    Approved,
+    /// An assigned reviewer requested changes to the current content
+    ChangesRequested,
+}
+
+impl fmt::Display for ReviewState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReviewState::NotRequested => write!(f, "not-requested"),
+            ReviewState::Pending => write!(f, "pending"),
+            ReviewState::Approved => write!(f, "approved"),
+            ReviewState::ChangesRequested => write!(f, "changes-requested"),
+        }
+    }
+}
+
+/// A request for someone to review a spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewRequest {
+    pub reviewer: String,
+    pub requested_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_by: Option<String>,
+}
+
+/// A recorded review decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewRecord {
+    pub reviewer: String,
+    pub decision: ReviewDecision,
+    pub at: DateTime<Utc>,
+    /// Hash of the spec body the decision was made against
+    pub content_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Where one reviewer stands on the current content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewerStatus {
+    pub reviewer: String,
+    /// Explicitly requested, as opposed to the frontmatter `reviewer` or
+    /// someone who reviewed unasked
+    pub requested: bool,
+    /// The frontmatter `reviewer` or explicitly requested, so their decision
+    /// counts toward the review state
+    pub assigned: bool,
+    /// Latest decision, current or not
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<ReviewDecision>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decided_at: Option<DateTime<Utc>>,
+    /// The latest decision was made against different content
+    pub stale: bool,
+}
+
+impl ReviewerStatus {
+    /// Latest decision if it still applies to the current content.
+    pub fn current_decision(&self) -> Option<ReviewDecision> {
+        self.decision.filter(|_| !self.stale)
+    }
+}
+
+/// Review state of a spec, computed against its current content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct SpecReview {
+    pub spec: String,
+    pub state: ReviewState,
+    pub content_hash: String,
+    /// Assigned reviewers whose latest decision approves the current content
+    pub approvals: usize,
+    pub reviewers: Vec<ReviewerStatus>,
+    pub requests: Vec<ReviewRequest>,
+    /// Every decision, oldest first
+    pub history: Vec<ReviewRecord>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReviewError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid review file {path}: {reason}")]
+    Parse { path: String, reason: String },
+
+    #[error("Reviewer name must not be empty")]
+    EmptyReviewer,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ReviewFile {
+    #[serde(default)]
+    requests: Vec<ReviewRequest>,
+    #[serde(default)]
+    decisions: Vec<ReviewRecord>,
+}
+
+fn review_path(spec: &SpecInfo) -> PathBuf {
+    spec.file_path
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join(REVIEW_FILE)
+}
+
+fn read_review_file(path: &Path) -> Result<ReviewFile, ReviewError> {
+    if !path.exists() {
+        return Ok(ReviewFile::default());
+    }
+    let text = std::fs::read_to_string(path)?;
+    serde_yaml::from_str(&text).map_err(|e| ReviewError::Parse {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })
+}
+
+fn write_review_file(path: &Path, file: &ReviewFile) -> Result<(), ReviewError> {
+    let yaml = serde_yaml::to_string(file).map_err(|e| ReviewError::Parse {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    atomic_write(path, &yaml)?;
+    Ok(())
+}
+
+/// Read, change and write back the review file of `spec` under its lock.
+fn change_review_file(
+    spec: &SpecInfo,
+    change: impl FnOnce(&mut ReviewFile) -> Result<(), ReviewError>,
+) -> Result<ReviewFile, ReviewError> {
+    let path = review_path(spec);
+    with_spec_lock(path.parent().unwrap_or(Path::new(".")), || {
+        let mut file = read_review_file(&path)?;
+        change(&mut file)?;
+        write_review_file(&path, &file)?;
+        Ok(file)
+    })
+}
+
+fn reviewer_name(name: &str) -> Result<String, ReviewError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ReviewError::EmptyReviewer);
+    }
+    Ok(name.to_string())
+}
+
+/// Review state of `spec`, which must be loaded with its content.
+pub fn load_review(spec: &SpecInfo) -> Result<SpecReview, ReviewError> {
+    let file = read_review_file(&review_path(spec))?;
+    Ok(summarize(spec, file))
+}
+
+/// Ask `reviewers` to review `spec`. Re-requesting a reviewer refreshes the
+/// request time.
+pub fn request_review(
+    spec: &SpecInfo,
+    reviewers: &[String],
+    requested_by: Option<&str>,
+) -> Result<SpecReview, ReviewError> {
+    let now = Utc::now();
+    let requested_by = requested_by
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(String::from);
+    let file = change_review_file(spec, |file| {
+        for reviewer in reviewers {
+            let reviewer = reviewer_name(reviewer)?;
+            file.requests.retain(|r| r.reviewer != reviewer);
+            file.requests.push(ReviewRequest {
+                reviewer,
+                requested_at: now,
+                requested_by: requested_by.clone(),
+            });
+        }
+        Ok(())
+    })?;
+    Ok(summarize(spec, file))
+}
+
+/// Record `reviewer`'s decision on the current content of `spec`.
+pub fn record_review(
+    spec: &SpecInfo,
+    reviewer: &str,
+    decision: ReviewDecision,
+    comment: Option<String>,
+) -> Result<SpecReview, ReviewError> {
+    let record = ReviewRecord {
+        reviewer: reviewer_name(reviewer)?,
+        decision,
+        at: Utc::now(),
+        content_hash: crate::hash_content(&spec.content),
+        comment: comment.filter(|c| !c.trim().is_empty()),
+    };
+    let file = change_review_file(spec, |file| {
+        file.decisions.push(record);
+        Ok(())
+    })?;
+    Ok(summarize(spec, file))
+}
+
+/// Why `review` does not allow work to start under `config`, if it does not.
+pub fn approval_blocker(review: &SpecReview, config: &ReviewConfig) -> Option<String> {
+    if !config.require_approval {
+        return None;
+    }
+    match review.state {
+        ReviewState::NotRequested => Some("no review has been requested".to_string()),
+        ReviewState::ChangesRequested => {
+            let by: Vec<&str> = review
+                .reviewers
+                .iter()
+                .filter(|r| {
+                    r.assigned && r.current_decision() == Some(ReviewDecision::ChangesRequested)
+                })
+                .map(|r| r.reviewer.as_str())
+                .collect();
+            Some(format!("changes requested by {}", by.join(", ")))
+        }
+        ReviewState::Pending => {
+            let waiting: Vec<&str> = review
+                .reviewers
+                .iter()
+                .filter(|r| r.assigned && r.current_decision().is_none())
+                .map(|r| r.reviewer.as_str())
+                .collect();
+            Some(format!("waiting for approval from {}", waiting.join(", ")))
+        }
+        ReviewState::Approved if review.approvals < config.min_approvals => Some(format!(
+            "{} of {} required approvals",
+            review.approvals, config.min_approvals
+        )),
+        ReviewState::Approved => None,
+    }
+}
+
+fn summarize(spec: &SpecInfo, file: ReviewFile) -> SpecReview {
+    let content_hash = crate::hash_content(&spec.content);
+
+    // Frontmatter reviewer first, then requests, then anyone who reviewed
+    let mut names: Vec<String> = Vec::new();
+    let candidates = spec
+        .frontmatter
+        .reviewer
+        .iter()
+        .map(|r| r.trim().to_string())
+        .chain(file.requests.iter().map(|r| r.reviewer.clone()))
+        .chain(file.decisions.iter().map(|d| d.reviewer.clone()));
+    for name in candidates {
+        if !name.is_empty() && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let frontmatter_reviewer = spec.frontmatter.reviewer.as_deref().map(str::trim);
+    let reviewers: Vec<ReviewerStatus> = names
+        .into_iter()
+        .map(|reviewer| {
+            let latest = file.decisions.iter().rev().find(|d| d.reviewer == reviewer);
+            let requested = file.requests.iter().any(|r| r.reviewer == reviewer);
+            ReviewerStatus {
+                requested,
+                assigned: requested || frontmatter_reviewer == Some(reviewer.as_str()),
+                decision: latest.map(|d| d.decision),
+                decided_at: latest.map(|d| d.at),
+                stale: latest.is_some_and(|d| d.content_hash != content_hash),
+                reviewer,
+            }
+        })
+        .collect();
+
+    let current: Vec<Option<ReviewDecision>> = reviewers
+        .iter()
+        .filter(|r| r.assigned)
+        .map(|r| r.current_decision())
+        .collect();
+    let approvals = current
+        .iter()
+        .filter(|d| **d == Some(ReviewDecision::Approved))
+        .count();
+    let state = if current.is_empty() {
+        ReviewState::NotRequested
+    } else if current.contains(&Some(ReviewDecision::ChangesRequested)) {
+        ReviewState::ChangesRequested
+    } else if approvals == current.len() {
+        ReviewState::Approved
+    } else {
+        ReviewState::Pending
+    };
+
+    SpecReview {
+        spec: spec.path.clone(),
+        state,
+        content_hash,
+        approvals,
+        reviewers,
+        requests: file.requests,
+        history: file.decisions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::markdown::SpecLoader;
+    use tempfile::TempDir;
+
+    fn fixture(frontmatter: &str) -> (TempDir, SpecInfo) {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("001-first");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("README.md"),
+            format!(
+                "---\nstatus: planned\ncreated: '2025-01-01'\n{frontmatter}---\n\n# First\n\nBody.\n"
+            ),
+        )
+        .unwrap();
+        let spec = SpecLoader::new(tmp.path()).load("001").unwrap().unwrap();
+        (tmp, spec)
+    }
+
+    fn edit_body(spec: &mut SpecInfo) {
+        spec.content.push_str("\nMore detail.\n");
+    }
+
+    #[test]
+    fn approvals_follow_the_reviewers() {
+        let (tmp, spec) = fixture("reviewer: alice\n");
+        let review = load_review(&spec).unwrap();
+        assert_eq!(review.state, ReviewState::Pending);
+        assert_eq!(review.reviewers[0].reviewer, "alice");
+        assert!(!review.reviewers[0].requested);
+
+        let review = request_review(&spec, &["bob".to_string()], Some("carol")).unwrap();
+        assert_eq!(review.reviewers.len(), 2);
+        assert_eq!(review.requests[0].requested_by.as_deref(), Some("carol"));
+
+        record_review(&spec, "alice", ReviewDecision::Approved, None).unwrap();
+        let review = record_review(
+            &spec,
+            "bob",
+            ReviewDecision::ChangesRequested,
+            Some("Needs a rollback plan".into()),
+        )
+        .unwrap();
+        assert_eq!(review.state, ReviewState::ChangesRequested);
+        assert_eq!(review.approvals, 1);
+
+        let review = record_review(&spec, "bob", ReviewDecision::Approved, None).unwrap();
+        assert_eq!(review.state, ReviewState::Approved);
+        assert_eq!(review.approvals, 2);
+        assert_eq!(review.history.len(), 3);
+        assert!(tmp.path().join("001-first").join(REVIEW_FILE).exists());
+    }
+
+    #[test]
+    fn edits_make_approvals_stale() {
+        let (_tmp, mut spec) = fixture("");
+        assert_eq!(load_review(&spec).unwrap().state, ReviewState::NotRequested);
+
+        request_review(&spec, &["alice".to_string()], None).unwrap();
+        record_review(&spec, "alice", ReviewDecision::Approved, None).unwrap();
+        assert_eq!(load_review(&spec).unwrap().state, ReviewState::Approved);
+
+        edit_body(&mut spec);
+        let review = load_review(&spec).unwrap();
+        assert_eq!(review.state, ReviewState::Pending);
+        assert_eq!(review.approvals, 0);
+        assert!(review.reviewers[0].stale);
+        assert_eq!(review.reviewers[0].current_decision(), None);
+    }
+
+    #[test]
+    fn blocker_respects_the_config() {
+        let (_tmp, spec) = fixture("reviewer: alice\n");
+        let mut config = ReviewConfig::default();
+        let review = load_review(&spec).unwrap();
+        assert_eq!(approval_blocker(&review, &config), None);
+
+        config.require_approval = true;
+        assert_eq!(
+            approval_blocker(&review, &config).as_deref(),
+            Some("waiting for approval from alice")
+        );
+
+        let review = record_review(&spec, "alice", ReviewDecision::Approved, None).unwrap();
+        assert_eq!(approval_blocker(&review, &config), None);
+
+        config.min_approvals = 2;
+        assert_eq!(
+            approval_blocker(&review, &config).as_deref(),
+            Some("1 of 2 required approvals")
+        );
+    }
+
+    #[test]
+    fn unassigned_decisions_do_not_count() {
+        let (_tmp, spec) = fixture("reviewer: alice\n");
+        let config = ReviewConfig {
+            require_approval: true,
+            min_approvals: 1,
+        };
+
+        let review = record_review(&spec, "mallory", ReviewDecision::Approved, None).unwrap();
+        assert_eq!(review.state, ReviewState::Pending);
+        assert_eq!(review.approvals, 0);
+        assert!(!review.reviewers[1].assigned);
+        assert_eq!(
+            approval_blocker(&review, &config).as_deref(),
+            Some("waiting for approval from alice")
+        );
+
+        let review =
+            record_review(&spec, "mallory", ReviewDecision::ChangesRequested, None).unwrap();
+        assert_eq!(review.state, ReviewState::Pending);
+
+        let review = record_review(&spec, "alice", ReviewDecision::Approved, None).unwrap();
+        assert_eq!(review.state, ReviewState::Approved);
+        assert_eq!(approval_blocker(&review, &config), None);
+
+        let (_tmp, spec) = fixture("");
+        let review = record_review(&spec, "author", ReviewDecision::Approved, None).unwrap();
+        assert_eq!(review.state, ReviewState::NotRequested);
+        assert_eq!(
+            approval_blocker(&review, &config).as_deref(),
+            Some("no review has been requested")
+        );
+    }
+
+    #[test]
+    fn concurrent_decisions_are_all_kept() {
+        let (_tmp, spec) = fixture("");
+        let reviewers: Vec<String> = (0..8).map(|n| format!("r{n}")).collect();
+        request_review(&spec, &reviewers, None).unwrap();
+        std::thread::scope(|scope| {
+            for n in 0..8 {
+                let spec = &spec;
+                scope.spawn(move || {
+                    record_review(spec, &format!("r{n}"), ReviewDecision::Approved, None).unwrap();
+                });
+            }
+        });
+
+        let review = load_review(&spec).unwrap();
+        assert_eq!(review.history.len(), 8);
+        assert_eq!(review.approvals, 8);
+    }
+
+    #[test]
+    fn empty_reviewer_is_rejected() {
+        let (_tmp, spec) = fixture("");
+        assert!(matches!(
+            record_review(&spec, " ", ReviewDecision::Approved, None),
+            Err(ReviewError::EmptyReviewer)
+        ));
+        assert!(matches!(
+            request_review(&spec, &[String::new()], None),
+            Err(ReviewError::EmptyReviewer)
+        ));
+    }
+}
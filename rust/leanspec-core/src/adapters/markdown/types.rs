@@ -179,6 +179,35 @@ impl SpecFrontmatter {
             SpecStatus::Archived => "Archived",
         }
     }
+
+    /// Targets of a link type kept under its own frontmatter key
+    /// (e.g. `supersedes: [042-old-auth]`). Accepts a single value or a list.
+    pub fn link_targets(&self, key: &str) -> Vec<String> {
+        fn scalar(value: &serde_yaml::Value) -> Option<String> {
+            match value {
+                serde_yaml::Value::String(s) => Some(s.trim().to_string()),
+                serde_yaml::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            }
+        }
+        let targets = match self.custom.get(key) {
+            Some(serde_yaml::Value::Sequence(items)) => items.iter().filter_map(scalar).collect(),
+            Some(value) => scalar(value).into_iter().collect(),
+            None => Vec::new(),
+        };
+        targets.into_iter().filter(|t| !t.is_empty()).collect()
+    }
+
+    /// Replace the targets stored under `key`; an empty list removes the key.
+    pub fn set_link_targets(&mut self, key: &str, targets: Vec<String>) {
+        if targets.is_empty() {
+            self.custom.remove(key);
+        } else {
+            let items = targets.into_iter().map(serde_yaml::Value::String).collect();
+            self.custom
+                .insert(key.to_string(), serde_yaml::Value::Sequence(items));
+        }
+    }
 }
 
 /// Complete spec information including parsed content
@@ -219,6 +248,25 @@ impl SpecInfo {
             .map(|(_, name)| name)
             .unwrap_or(&self.path)
     }
+
+    /// Directory name for a move to `name` and/or `number`. A `name`
+    /// without a number prefix keeps the requested or current number
+    /// (e.g., "170-new-name" for name "new-name" from "170-cli-mcp").
+    pub fn moved_name(&self, name: Option<&str>, number: Option<u32>) -> String {
+        let (name_number, slug) = match name {
+            Some(name) => match name.split_once('-') {
+                Some((prefix, rest)) if prefix.parse::<u32>().is_ok() => {
+                    (prefix.parse().ok(), rest)
+                }
+                _ => (None, name),
+            },
+            None => (None, self.name()),
+        };
+        match number.or(name_number).or_else(|| self.number()) {
+            Some(number) => format!("{:03}-{}", number, slug),
+            None => slug.to_string(),
+        }
+    }
 }
 
 /// Filter options for listing specs
@@ -320,6 +368,23 @@ mod tests {
         assert!("invalid".parse::<SpecPriority>().is_err());
     }
 
+    #[test]
+    fn test_moved_name() {
+        let spec = SpecInfo {
+            path: "170-cli-mcp".to_string(),
+            title: "CLI".to_string(),
+            frontmatter: serde_yaml::from_str("status: planned\ncreated: '2025-01-01'").unwrap(),
+            content: String::new(),
+            file_path: "170-cli-mcp/README.md".into(),
+            is_sub_spec: false,
+            parent_spec: None,
+        };
+        assert_eq!(spec.moved_name(Some("new-name"), None), "170-new-name");
+        assert_eq!(spec.moved_name(Some("042-new-name"), None), "042-new-name");
+        assert_eq!(spec.moved_name(None, Some(7)), "007-cli-mcp");
+        assert_eq!(spec.moved_name(Some("new-name"), Some(7)), "007-new-name");
+    }
+
     #[test]
     fn test_status_display() {
         assert_eq!(SpecStatus::Draft.to_string(), "draft");
@@ -5,6 +5,7 @@
 use super::loader::{LoadError, SpecLoader};
 use super::types::{SpecFrontmatter, SpecPriority, SpecStatus};
 use chrono::Utc;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -37,6 +38,8 @@ pub struct MetadataUpdate {
     pub assignee: Option<String>,
     pub depends_on: Option<Vec<String>>,
     pub parent: Option<Option<String>>,
+    /// Link targets per frontmatter link key; keys not present are untouched.
+    pub links: HashMap<String, Vec<String>>,
 }
 
 impl MetadataUpdate {
@@ -73,6 +76,11 @@ impl MetadataUpdate {
         self.parent = Some(parent);
         self
     }
+
+    pub fn with_links(mut self, key: &str, targets: Vec<String>) -> Self {
+        self.links.insert(key.to_string(), targets);
+        self
+    }
 }
 
 /// Spec writer for updating spec metadata
@@ -131,6 +139,10 @@ impl SpecWriter {
             frontmatter.parent = parent.filter(|value| !value.trim().is_empty());
         }
 
+        for (key, targets) in updates.links {
+            frontmatter.set_link_targets(&key, targets);
+        }
+
         // Update timestamp
         frontmatter.updated_at = Some(Utc::now());
 
@@ -13,12 +13,25 @@
 //! - [`AdapterCapabilities`] declares operational support flags and the default
 //!   schema id for this adapter.
 //! - [`Adapter`] is the trait each backend implements.
-
+//! - `AsyncAdapter` (behind the `async` feature) is its non-blocking twin for
+//!   async callers; remote backends implement it natively and blocking
+//!   adapters are lifted into it with `BlockingBridge`.
+//! - [`CachingAdapter`] wraps any adapter with a TTL cache that revalidates
+//!   through the backend's conditional-fetch hooks.
+//! - [`RetryingAdapter`] retries rate-limited and transiently failing calls
+//!   with exponential backoff.
+//! - [`Archive`] and [`ImportPlan`] move a whole project between adapters.
+
+#[cfg(feature = "async")]
+pub mod async_adapter;
+pub mod cache;
 #[cfg(feature = "github")]
 pub mod github;
 pub mod jira;
 pub mod markdown;
 pub mod registry;
+pub mod retry;
+pub mod transfer;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_harness;
@@ -33,8 +46,9 @@ use ts_rs::TS;
 // Re-export model types so callers can import them from either
 // `leanspec_core::model` or `leanspec_core::adapters`.
 pub use crate::model::{
-    semantic, CompletableItem, CreateRequest, EnumOption, FieldDef, FieldDisplay, FieldKind,
-    FieldValue, ItemLink, LinkTypeDef, Reference, SpecDoc, SpecSchema, UpdateRequest,
+    semantic, Comment, CommentRequest, CompletableItem, CreateRequest, EnumOption, FieldDef,
+    FieldDisplay, FieldKind, FieldValue, ItemLink, LinkTypeDef, Reference, SpecDoc, SpecSchema,
+    UpdateRequest,
 };
 
 /// Errors returned by adapter operations.
@@ -67,14 +81,30 @@ pub enum AdapterError {
     /// The backend rejected the request because a rate limit has been hit.
     ///
     /// `reset_at` is the moment the limit window resets. `None` if the backend
-    /// did not advertise a reset time. Adapters do not retry internally — the
-    /// caller decides how to back off.
+    /// did not advertise a reset time. Adapters do not retry internally; wrap
+    /// them in a [`RetryingAdapter`] to back off and retry.
     #[error("Rate limit hit for {adapter}; resets at {}", reset_at.map(|t| t.to_rfc3339()).unwrap_or_else(|| "unknown".into()))]
     RateLimit {
         adapter: String,
         reset_at: Option<DateTime<Utc>>,
     },
 
+    /// The backend could not be reached or failed transiently (network
+    /// error, `408`, `5xx`). The request may or may not have been applied.
+    ///
+    /// `retry_after` carries the backend's `Retry-After` hint, if any.
+    #[error("Backend unavailable for {adapter}: {reason}")]
+    Unavailable {
+        adapter: String,
+        reason: String,
+        retry_after: Option<DateTime<Utc>>,
+    },
+
+    /// An update carried an [`UpdateRequest::expected_version`] that no
+    /// longer matches the document. `current` is the version found.
+    #[error("Version conflict on {id}: the document changed since it was read")]
+    Conflict { id: String, current: Option<String> },
+
     /// A local I/O error (for file-backed adapters).
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
@@ -95,8 +125,15 @@ pub struct AdapterCapabilities {
     pub supports_delete: bool,
     pub supports_search: bool,
     pub supports_webhooks: bool,
+    /// Whether the comment operations ([`Adapter::list_comments`] and
+    /// friends) are implemented.
+    #[serde(default)]
+    pub supports_comments: bool,
     /// The schema id documents returned by this adapter conform to by default.
     pub default_schema: String,
+    /// Hit/miss statistics when the adapter is wrapped in a [`CachingAdapter`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CacheStats>,
 }
 
 /// Filters passed to [`Adapter::list`].
@@ -182,6 +219,91 @@ pub trait Adapter: Send + Sync {
     fn get_links(&self, id: &str) -> Result<Vec<ItemLink>, AdapterError> {
         Ok(self.get(id)?.links)
     }
+
+    /// Conditional form of [`list`](Self::list) used by [`CachingAdapter`].
+    ///
+    /// `previous` is the validator captured by the last fetch with the same
+    /// filter. Backends that can revalidate cheaply (ETags, `updated >=`
+    /// queries) override this; the default always refetches.
+    fn list_conditional(
+        &self,
+        filter: &ListFilter,
+        _previous: Option<&Validator>,
+    ) -> Result<Revalidated<Vec<SpecDoc>>, AdapterError> {
+        Ok(Revalidated::Replaced(self.list(filter)?, Validator::now()))
+    }
+
+    /// Conditional form of [`get`](Self::get). See
+    /// [`list_conditional`](Self::list_conditional).
+    fn get_conditional(
+        &self,
+        id: &str,
+        _previous: Option<&Validator>,
+    ) -> Result<Revalidated<SpecDoc>, AdapterError> {
+        Ok(Revalidated::Replaced(self.get(id)?, Validator::now()))
+    }
+
+    /// Cache statistics, reported by [`CachingAdapter`]. `None` otherwise.
+    fn cache_stats(&self) -> Option<CacheStats> {
+        None
+    }
+
+    /// Comments on document `id`, oldest first. Optional: adapters that set
+    /// [`AdapterCapabilities::supports_comments`] override the comment
+    /// operations; the defaults return [`AdapterError::NotSupported`].
+    fn list_comments(&self, _id: &str) -> Result<Vec<Comment>, AdapterError> {
+        Err(comments_not_supported(self.capabilities(), "list_comments"))
+    }
+
+    fn add_comment(&self, _id: &str, _req: &CommentRequest) -> Result<Comment, AdapterError> {
+        Err(comments_not_supported(self.capabilities(), "add_comment"))
+    }
+
+    /// Replace the body of comment `comment_id` on document `id`.
+    fn edit_comment(
+        &self,
+        _id: &str,
+        _comment_id: &str,
+        _body: &str,
+    ) -> Result<Comment, AdapterError> {
+        Err(comments_not_supported(self.capabilities(), "edit_comment"))
+    }
+
+    /// Mark comment `comment_id` resolved, or reopen it with `resolved: false`.
+    fn resolve_comment(
+        &self,
+        _id: &str,
+        _comment_id: &str,
+        _resolved: bool,
+    ) -> Result<Comment, AdapterError> {
+        Err(comments_not_supported(
+            self.capabilities(),
+            "resolve_comment",
+        ))
+    }
+}
+
+/// [`AdapterError::NotSupported`] for a comment operation.
+pub(crate) fn comments_not_supported(caps: &AdapterCapabilities, operation: &str) -> AdapterError {
+    AdapterError::NotSupported {
+        adapter: caps.name.clone(),
+        operation: operation.into(),
+    }
+}
+
+/// Reject a write whose expected version (see
+/// [`UpdateRequest::expected_version`]) no longer matches `current`.
+/// Adapters call this right before writing.
+pub fn check_version(expected: Option<&str>, current: &SpecDoc) -> Result<(), AdapterError> {
+    match expected {
+        Some(expected) if current.version.as_deref() != Some(expected) => {
+            Err(AdapterError::Conflict {
+                id: current.id.clone(),
+                current: current.version.clone(),
+            })
+        }
+        _ => Ok(()),
+    }
 }
 
 impl fmt::Debug for dyn Adapter {
@@ -190,7 +312,12 @@ impl fmt::Debug for dyn Adapter {
     }
 }
 
+#[cfg(feature = "async")]
+pub use async_adapter::{AdapterFuture, AsyncAdapter, BlockingBridge};
+pub use cache::{CacheConfig, CacheStats, CachingAdapter, Revalidated, Validator};
 pub use registry::AdapterRegistry;
+pub use retry::{Operation, RetryPolicy, RetryingAdapter};
+pub use transfer::{Archive, ImportPlan, ImportReport, TransferError, ValueMapping};
 
 #[cfg(test)]
 mod tests {
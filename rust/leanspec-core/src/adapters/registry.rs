@@ -6,19 +6,26 @@
 //! [`AdapterRegistry`] unchanged.
 
 use std::path::Path;
+#[cfg(feature = "async")]
+use std::sync::Arc;
 
 #[cfg(feature = "github")]
 use super::github::GitHubAdapter;
 #[cfg(feature = "jira")]
-use super::jira::JiraAdapter;
+use super::jira::{JiraAdapter, JiraLinkType};
 use super::markdown::MarkdownAdapter;
 use super::{Adapter, AdapterConfig, AdapterError};
+#[cfg(feature = "async")]
+use super::{AsyncAdapter, BlockingBridge};
 
 /// Factory for [`Adapter`] instances.
 pub struct AdapterRegistry;
 
 impl AdapterRegistry {
     /// Instantiate an adapter from the provided configuration.
+    ///
+    /// Remote adapters are wrapped in a [`RetryingAdapter`](super::RetryingAdapter)
+    /// unless the config sets `retry: false`.
     pub fn create(config: &AdapterConfig) -> Result<Box<dyn Adapter>, AdapterError> {
         match config.adapter.as_str() {
             "markdown" => {
@@ -31,39 +38,13 @@ impl AdapterRegistry {
             }
             #[cfg(feature = "github")]
             "github" => {
-                let owner = config
-                    .settings
-                    .get("owner")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| {
-                        AdapterError::ConfigError(
-                            "github adapter requires 'owner' in settings".into(),
-                        )
-                    })?;
-                let repo = config
-                    .settings
-                    .get("repo")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| {
-                        AdapterError::ConfigError(
-                            "github adapter requires 'repo' in settings".into(),
-                        )
-                    })?;
-                let token_env = config
-                    .settings
-                    .get("token_env")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("GITHUB_TOKEN");
-                let mut adapter = match config.settings.get("base_url").and_then(|v| v.as_str()) {
-                    Some(base) => GitHubAdapter::with_base_url(owner, repo, token_env, base)?,
-                    None => GitHubAdapter::new(owner, repo, token_env)?,
-                };
+                let mut adapter = github_from_settings(&config.settings)?;
                 // Bake the project's actual labels into the adapter's schema
                 // so `leanspec capabilities` shows them rather than empty
                 // dynamic enum slots. Transient failures here are fatal: an
                 // unreachable GitHub means the adapter is unusable anyway.
                 adapter.resolve_inline()?;
-                Ok(Box::new(adapter))
+                with_retry(adapter, &config.settings)
             }
             #[cfg(not(feature = "github"))]
             "github" => Err(AdapterError::ConfigError(
@@ -73,60 +54,13 @@ impl AdapterRegistry {
             )),
             #[cfg(feature = "jira")]
             "jira" => {
-                let host = config
-                    .settings
-                    .get("host")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| {
-                        AdapterError::ConfigError("jira adapter requires 'host' in settings".into())
-                    })?;
-                let project = config
-                    .settings
-                    .get("project")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| {
-                        AdapterError::ConfigError(
-                            "jira adapter requires 'project' in settings".into(),
-                        )
-                    })?;
-                let email = config
-                    .settings
-                    .get("email")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| {
-                        AdapterError::ConfigError(
-                            "jira adapter requires 'email' in settings".into(),
-                        )
-                    })?;
-                let token_env = config
-                    .settings
-                    .get("token_env")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("JIRA_TOKEN");
-                let api_version = config
-                    .settings
-                    .get("api_version")
-                    .and_then(|v| v.as_u64())
-                    .unwrap_or(3) as u8;
-                let base_url = config
-                    .settings
-                    .get("base_url")
-                    .and_then(|v| v.as_str())
-                    .map(String::from);
-                let mut adapter = JiraAdapter::with_settings(
-                    host,
-                    project,
-                    email,
-                    token_env,
-                    api_version,
-                    base_url,
-                )?;
+                let mut adapter = jira_from_settings(&config.settings)?;
                 // Pre-fetch the project's status / priority vocabulary so
                 // `leanspec capabilities` reports the real names. Failures
                 // here are fatal: an unreachable Jira means the adapter is
                 // unusable.
                 adapter.resolve_inline()?;
-                Ok(Box::new(adapter))
+                with_retry(adapter, &config.settings)
             }
             #[cfg(not(feature = "jira"))]
             "jira" => Err(AdapterError::ConfigError(
@@ -141,6 +75,35 @@ impl AdapterRegistry {
         }
     }
 
+    /// Async counterpart of [`Self::create`] for callers running on a Tokio
+    /// runtime, such as the HTTP server.
+    ///
+    /// Remote adapters are natively async and resolve their schema without
+    /// blocking; they are wrapped in a [`CachingAdapter`](super::CachingAdapter)
+    /// and a [`RetryingAdapter`](super::RetryingAdapter) unless the config sets
+    /// `cache: false` / `retry: false`. Every other adapter is built with
+    /// [`Self::create`] and wrapped in a [`BlockingBridge`].
+    #[cfg(feature = "async")]
+    pub async fn create_async(
+        config: &AdapterConfig,
+    ) -> Result<Arc<dyn AsyncAdapter>, AdapterError> {
+        match config.adapter.as_str() {
+            #[cfg(feature = "github")]
+            "github" => {
+                let mut adapter = github_from_settings(&config.settings)?;
+                adapter.resolve_inline_async().await?;
+                with_cache(adapter, &config.settings)
+            }
+            #[cfg(feature = "jira")]
+            "jira" => {
+                let mut adapter = jira_from_settings(&config.settings)?;
+                adapter.resolve_inline_async().await?;
+                with_cache(adapter, &config.settings)
+            }
+            _ => Ok(Arc::new(BlockingBridge::from_boxed(Self::create(config)?))),
+        }
+    }
+
     /// The built-in default: markdown adapter rooted at `specs/`.
     pub fn default_adapter() -> Box<dyn Adapter> {
         Box::new(MarkdownAdapter::new("specs"))
@@ -227,6 +190,109 @@ impl AdapterRegistry {
     }
 }
 
+/// Put a [`CachingAdapter`](super::CachingAdapter) in front of `adapter`
+/// per the `cache:` setting, and a [`RetryingAdapter`](super::RetryingAdapter)
+/// between the two per `retry:`, so cache hits skip the retry loop.
+#[cfg(any(feature = "github", feature = "jira"))]
+fn with_cache<A: AsyncAdapter + 'static>(
+    adapter: A,
+    settings: &serde_json::Value,
+) -> Result<Arc<dyn AsyncAdapter>, AdapterError> {
+    let cache = super::CacheConfig::from_settings(settings)?;
+    Ok(
+        match (super::RetryPolicy::from_settings(settings)?, cache) {
+            (Some(policy), Some(config)) => Arc::new(super::CachingAdapter::wrap_async(
+                super::RetryingAdapter::new(adapter, policy),
+                config,
+            )),
+            (Some(policy), None) => Arc::new(super::RetryingAdapter::new(adapter, policy)),
+            (None, Some(config)) => Arc::new(super::CachingAdapter::wrap_async(adapter, config)),
+            (None, None) => Arc::new(adapter),
+        },
+    )
+}
+
+/// Put a [`RetryingAdapter`](super::RetryingAdapter) in front of `adapter`
+/// per the `retry:` setting.
+#[cfg(any(feature = "github", feature = "jira"))]
+fn with_retry<A: Adapter + 'static>(
+    adapter: A,
+    settings: &serde_json::Value,
+) -> Result<Box<dyn Adapter>, AdapterError> {
+    Ok(match super::RetryPolicy::from_settings(settings)? {
+        Some(policy) => Box::new(super::RetryingAdapter::new(adapter, policy)),
+        None => Box::new(adapter),
+    })
+}
+
+/// Build a [`GitHubAdapter`] from registry settings without touching the
+/// network.
+#[cfg(feature = "github")]
+fn github_from_settings(settings: &serde_json::Value) -> Result<GitHubAdapter, AdapterError> {
+    let owner = settings
+        .get("owner")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            AdapterError::ConfigError("github adapter requires 'owner' in settings".into())
+        })?;
+    let repo = settings
+        .get("repo")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            AdapterError::ConfigError("github adapter requires 'repo' in settings".into())
+        })?;
+    let token_env = settings
+        .get("token_env")
+        .and_then(|v| v.as_str())
+        .unwrap_or("GITHUB_TOKEN");
+    match settings.get("base_url").and_then(|v| v.as_str()) {
+        Some(base) => GitHubAdapter::with_base_url(owner, repo, token_env, base),
+        None => GitHubAdapter::new(owner, repo, token_env),
+    }
+}
+
+/// Build a [`JiraAdapter`] from registry settings without touching the
+/// network.
+#[cfg(feature = "jira")]
+fn jira_from_settings(settings: &serde_json::Value) -> Result<JiraAdapter, AdapterError> {
+    let host = settings
+        .get("host")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            AdapterError::ConfigError("jira adapter requires 'host' in settings".into())
+        })?;
+    let project = settings
+        .get("project")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            AdapterError::ConfigError("jira adapter requires 'project' in settings".into())
+        })?;
+    let email = settings
+        .get("email")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            AdapterError::ConfigError("jira adapter requires 'email' in settings".into())
+        })?;
+    let token_env = settings
+        .get("token_env")
+        .and_then(|v| v.as_str())
+        .unwrap_or("JIRA_TOKEN");
+    let api_version = settings
+        .get("api_version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(3) as u8;
+    let base_url = settings
+        .get("base_url")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+    let adapter =
+        JiraAdapter::with_settings(host, project, email, token_env, api_version, base_url)?;
+    match settings.get("link_types") {
+        Some(link_types) => Ok(adapter.with_link_types(JiraLinkType::from_settings(link_types)?)),
+        None => Ok(adapter),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,6 +320,23 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn create_async_bridges_markdown_adapter() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = AdapterConfig {
+            adapter: "markdown".into(),
+            settings: serde_json::json!({ "directory": tmp.path() }),
+        };
+        let adapter = AdapterRegistry::create_async(&cfg).await.unwrap();
+        assert_eq!(adapter.capabilities().name, "markdown");
+        assert!(adapter
+            .list(&super::super::ListFilter::default())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn default_is_markdown() {
         let adapter = AdapterRegistry::default_adapter();
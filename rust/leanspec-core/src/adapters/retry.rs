@@ -0,0 +1,651 @@
+//! Retry wrapper
+//!
+//! Adapters surface [`AdapterError::RateLimit`] and
+//! [`AdapterError::Unavailable`] without retrying. [`RetryingAdapter`] wraps
+//! an [`Adapter`] (or, with the `async` feature, an
+//! [`AsyncAdapter`](super::AsyncAdapter)) and retries those failures
+//! according to a [`RetryPolicy`]:
+//!
+//! - Exponential backoff from `base_delay`, capped at `max_delay`, with
+//!   jitter so concurrent clients do not retry in lockstep.
+//! - When the backend says when to come back (`reset_at` on a rate limit,
+//!   `Retry-After` on a `503`), that time is used instead. If it is further
+//!   away than `max_wait`, the error is returned immediately.
+//! - Reads, `update` and `delete` are idempotent and retried on both
+//!   errors. `create` is never retried: backends may create in several
+//!   requests (Jira creates, then transitions), so even a rate limit can
+//!   follow a successful insert, and a repeat would file a duplicate.
+//! - An `update` carrying an `expected_version` is not retried either. If
+//!   the write lands and a later request in the same update fails, the
+//!   repeat would fail its own version check with a spurious conflict.
+//!
+//! Configure it under the `retry:` key of `leanspec.adapter.yaml`:
+//!
+//! ```yaml
+//! adapter: jira
+//! retry:
+//!   max_attempts: 4
+//!   base_delay_ms: 500
+//!   max_delay_ms: 30000
+//!   max_wait_secs: 60
+//!   jitter: true
+//! ```
+//!
+//! `retry: false` disables the wrapper.
+
+use std::time::Duration;
+
+use chrono::Utc;
+
+use super::{
+    Adapter, AdapterCapabilities, AdapterError, CacheStats, Comment, CommentRequest, CreateRequest,
+    ItemLink, ListFilter, Revalidated, SearchHit, SearchOptions, SpecDoc, SpecSchema,
+    UpdateRequest, Validator,
+};
+
+/// Kind of adapter call, used to decide whether a failure is safe to retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Read,
+    Create,
+    Update,
+    /// An update guarded by [`UpdateRequest::expected_version`].
+    ConditionalUpdate,
+    Delete,
+}
+
+impl Operation {
+    /// The kind of call `req` makes.
+    pub fn for_update(req: &UpdateRequest) -> Self {
+        if req.expected_version.is_some() {
+            Operation::ConditionalUpdate
+        } else {
+            Operation::Update
+        }
+    }
+
+    /// Whether repeating the call after an ambiguous failure is harmless.
+    pub fn is_idempotent(self) -> bool {
+        !matches!(self, Operation::Create | Operation::ConditionalUpdate)
+    }
+}
+
+/// When and how long to wait between attempts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    /// Backoff before the first retry; doubled for each retry after that.
+    pub base_delay: Duration,
+    /// Upper bound on a single backoff.
+    pub max_delay: Duration,
+    /// Longest wait accepted from a backend hint (`reset_at`, `Retry-After`).
+    pub max_wait: Duration,
+    /// Randomise each backoff between half and all of its nominal length.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_wait: Duration::from_secs(60),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Parse the `retry:` key of an adapter's settings.
+    ///
+    /// Returns `None` when retries are disabled with `retry: false`. A missing
+    /// key or `retry: true` yields the defaults.
+    pub fn from_settings(settings: &serde_json::Value) -> Result<Option<Self>, AdapterError> {
+        let Some(value) = settings.get("retry") else {
+            return Ok(Some(Self::default()));
+        };
+        let map = match value {
+            serde_json::Value::Bool(false) => return Ok(None),
+            serde_json::Value::Bool(true) | serde_json::Value::Null => {
+                return Ok(Some(Self::default()))
+            }
+            serde_json::Value::Object(map) => map,
+            _ => {
+                return Err(AdapterError::ConfigError(
+                    "retry must be a boolean or a mapping".into(),
+                ))
+            }
+        };
+
+        let number = |key: &str| match map.get(key) {
+            None => Ok(None),
+            Some(v) => v.as_u64().map(Some).ok_or_else(|| {
+                AdapterError::ConfigError(format!("retry.{key} must be a non-negative integer"))
+            }),
+        };
+        let defaults = Self::default();
+        let max_attempts = match number("max_attempts")? {
+            None => defaults.max_attempts,
+            Some(0) => {
+                return Err(AdapterError::ConfigError(
+                    "retry.max_attempts must be at least 1".into(),
+                ))
+            }
+            Some(n) => u32::try_from(n).unwrap_or(u32::MAX),
+        };
+        let jitter = match map.get("jitter") {
+            None => defaults.jitter,
+            Some(v) => v.as_bool().ok_or_else(|| {
+                AdapterError::ConfigError("retry.jitter must be a boolean".into())
+            })?,
+        };
+        Ok(Some(Self {
+            max_attempts,
+            base_delay: number("base_delay_ms")?
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.base_delay),
+            max_delay: number("max_delay_ms")?
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_delay),
+            max_wait: number("max_wait_secs")?
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.max_wait),
+            jitter,
+        }))
+    }
+
+    /// How long to wait before retrying `op` after `attempt` attempts ended
+    /// in `err`, or `None` if the error should be returned.
+    pub fn delay_for(&self, op: Operation, err: &AdapterError, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let hint = match err {
+            _ if !op.is_idempotent() => return None,
+            AdapterError::RateLimit { reset_at, .. } => *reset_at,
+            AdapterError::Unavailable { retry_after, .. } => *retry_after,
+            _ => return None,
+        };
+        let delay = match hint {
+            Some(at) => (at - Utc::now()).to_std().unwrap_or(Duration::ZERO),
+            None => self.backoff(attempt),
+        };
+        (delay <= self.max_wait).then_some(delay)
+    }
+
+    /// Exponential backoff before retry number `attempt` (1-based).
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let nominal = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if self.jitter {
+            nominal / 2 + nominal.mul_f64(random_fraction() / 2.0)
+        } else {
+            nominal
+        }
+    }
+}
+
+/// A value in `[0, 1)`. `RandomState` is seeded per instance, which is
+/// plenty for spreading out retries without pulling in an RNG crate.
+fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Retries rate-limited and transiently failing calls on another adapter.
+/// See the [module docs](self).
+pub struct RetryingAdapter<A> {
+    inner: A,
+    policy: RetryPolicy,
+}
+
+impl<A> RetryingAdapter<A> {
+    pub fn new(inner: A, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    /// The wrapped adapter.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    fn run<T>(
+        &self,
+        op: Operation,
+        mut call: impl FnMut() -> Result<T, AdapterError>,
+    ) -> Result<T, AdapterError> {
+        let mut attempt = 1;
+        loop {
+            match call() {
+                Err(err) => match self.policy.delay_for(op, &err, attempt) {
+                    Some(delay) => std::thread::sleep(delay),
+                    None => return Err(err),
+                },
+                ok => return ok,
+            }
+            attempt += 1;
+        }
+    }
+}
+
+impl<A: Adapter> Adapter for RetryingAdapter<A> {
+    fn capabilities(&self) -> &AdapterCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn schema(&self) -> &SpecSchema {
+        self.inner.schema()
+    }
+
+    fn resolve_schema(&self, schema: &mut SpecSchema) -> Result<(), AdapterError> {
+        self.run(Operation::Read, || self.inner.resolve_schema(schema))
+    }
+
+    fn list(&self, filter: &ListFilter) -> Result<Vec<SpecDoc>, AdapterError> {
+        self.run(Operation::Read, || self.inner.list(filter))
+    }
+
+    fn get(&self, id: &str) -> Result<SpecDoc, AdapterError> {
+        self.run(Operation::Read, || self.inner.get(id))
+    }
+
+    fn create(&self, req: &CreateRequest) -> Result<SpecDoc, AdapterError> {
+        self.run(Operation::Create, || self.inner.create(req))
+    }
+
+    fn update(&self, id: &str, req: &UpdateRequest) -> Result<SpecDoc, AdapterError> {
+        self.run(Operation::for_update(req), || self.inner.update(id, req))
+    }
+
+    fn delete(&self, id: &str) -> Result<(), AdapterError> {
+        self.run(Operation::Delete, || self.inner.delete(id))
+    }
+
+    fn search(&self, query: &str, opts: &SearchOptions) -> Result<Vec<SearchHit>, AdapterError> {
+        self.run(Operation::Read, || self.inner.search(query, opts))
+    }
+
+    fn get_links(&self, id: &str) -> Result<Vec<ItemLink>, AdapterError> {
+        self.run(Operation::Read, || self.inner.get_links(id))
+    }
+
+    fn list_conditional(
+        &self,
+        filter: &ListFilter,
+        previous: Option<&Validator>,
+    ) -> Result<Revalidated<Vec<SpecDoc>>, AdapterError> {
+        self.run(Operation::Read, || {
+            self.inner.list_conditional(filter, previous)
+        })
+    }
+
+    fn get_conditional(
+        &self,
+        id: &str,
+        previous: Option<&Validator>,
+    ) -> Result<Revalidated<SpecDoc>, AdapterError> {
+        self.run(Operation::Read, || self.inner.get_conditional(id, previous))
+    }
+
+    fn cache_stats(&self) -> Option<CacheStats> {
+        self.inner.cache_stats()
+    }
+
+    fn list_comments(&self, id: &str) -> Result<Vec<Comment>, AdapterError> {
+        self.run(Operation::Read, || self.inner.list_comments(id))
+    }
+
+    fn add_comment(&self, id: &str, req: &CommentRequest) -> Result<Comment, AdapterError> {
+        self.run(Operation::Create, || self.inner.add_comment(id, req))
+    }
+
+    fn edit_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        body: &str,
+    ) -> Result<Comment, AdapterError> {
+        self.run(Operation::Update, || {
+            self.inner.edit_comment(id, comment_id, body)
+        })
+    }
+
+    fn resolve_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        resolved: bool,
+    ) -> Result<Comment, AdapterError> {
+        self.run(Operation::Update, || {
+            self.inner.resolve_comment(id, comment_id, resolved)
+        })
+    }
+}
+
+/// `Retry-After` in its delay-seconds form, as an absolute time, for the
+/// `retry_after` of [`AdapterError::Unavailable`] and rate limits.
+#[cfg(any(feature = "github", feature = "jira"))]
+pub(crate) fn parse_retry_after(
+    headers: &reqwest::header::HeaderMap,
+) -> Option<chrono::DateTime<Utc>> {
+    headers
+        .get("retry-after")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.trim().parse::<i64>().ok())
+        .map(|secs| Utc::now() + chrono::Duration::seconds(secs))
+}
+
+#[cfg(feature = "async")]
+mod async_impl {
+    use super::*;
+    use crate::adapters::{AdapterFuture, AsyncAdapter};
+
+    impl<A: AsyncAdapter> RetryingAdapter<A> {
+        async fn run_async<'a, T>(
+            &'a self,
+            op: Operation,
+            mut call: impl FnMut() -> AdapterFuture<'a, T> + Send + 'a,
+        ) -> Result<T, AdapterError> {
+            let mut attempt = 1;
+            loop {
+                match call().await {
+                    Err(err) => match self.policy.delay_for(op, &err, attempt) {
+                        Some(delay) => tokio::time::sleep(delay).await,
+                        None => return Err(err),
+                    },
+                    ok => return ok,
+                }
+                attempt += 1;
+            }
+        }
+    }
+
+    impl<A: AsyncAdapter> AsyncAdapter for RetryingAdapter<A> {
+        fn capabilities(&self) -> &AdapterCapabilities {
+            self.inner.capabilities()
+        }
+
+        fn schema(&self) -> &SpecSchema {
+            self.inner.schema()
+        }
+
+        fn resolve_schema<'a>(&'a self, schema: &'a mut SpecSchema) -> AdapterFuture<'a, ()> {
+            Box::pin(async move {
+                let mut attempt = 1;
+                loop {
+                    match self.inner.resolve_schema(schema).await {
+                        Err(err) => match self.policy.delay_for(Operation::Read, &err, attempt) {
+                            Some(delay) => tokio::time::sleep(delay).await,
+                            None => return Err(err),
+                        },
+                        ok => return ok,
+                    }
+                    attempt += 1;
+                }
+            })
+        }
+
+        fn list<'a>(&'a self, filter: &'a ListFilter) -> AdapterFuture<'a, Vec<SpecDoc>> {
+            Box::pin(self.run_async(Operation::Read, move || self.inner.list(filter)))
+        }
+
+        fn get<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, SpecDoc> {
+            Box::pin(self.run_async(Operation::Read, move || self.inner.get(id)))
+        }
+
+        fn create<'a>(&'a self, req: &'a CreateRequest) -> AdapterFuture<'a, SpecDoc> {
+            Box::pin(self.run_async(Operation::Create, move || self.inner.create(req)))
+        }
+
+        fn update<'a>(&'a self, id: &'a str, req: &'a UpdateRequest) -> AdapterFuture<'a, SpecDoc> {
+            Box::pin(self.run_async(Operation::for_update(req), move || {
+                self.inner.update(id, req)
+            }))
+        }
+
+        fn delete<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, ()> {
+            Box::pin(self.run_async(Operation::Delete, move || self.inner.delete(id)))
+        }
+
+        fn search<'a>(
+            &'a self,
+            query: &'a str,
+            opts: &'a SearchOptions,
+        ) -> AdapterFuture<'a, Vec<SearchHit>> {
+            Box::pin(self.run_async(Operation::Read, move || self.inner.search(query, opts)))
+        }
+
+        fn get_links<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, Vec<ItemLink>> {
+            Box::pin(self.run_async(Operation::Read, move || self.inner.get_links(id)))
+        }
+
+        fn list_conditional<'a>(
+            &'a self,
+            filter: &'a ListFilter,
+            previous: Option<&'a Validator>,
+        ) -> AdapterFuture<'a, Revalidated<Vec<SpecDoc>>> {
+            Box::pin(self.run_async(Operation::Read, move || {
+                self.inner.list_conditional(filter, previous)
+            }))
+        }
+
+        fn get_conditional<'a>(
+            &'a self,
+            id: &'a str,
+            previous: Option<&'a Validator>,
+        ) -> AdapterFuture<'a, Revalidated<SpecDoc>> {
+            Box::pin(self.run_async(Operation::Read, move || {
+                self.inner.get_conditional(id, previous)
+            }))
+        }
+
+        fn cache_stats(&self) -> Option<CacheStats> {
+            self.inner.cache_stats()
+        }
+
+        fn list_comments<'a>(&'a self, id: &'a str) -> AdapterFuture<'a, Vec<Comment>> {
+            Box::pin(self.run_async(Operation::Read, move || self.inner.list_comments(id)))
+        }
+
+        fn add_comment<'a>(
+            &'a self,
+            id: &'a str,
+            req: &'a CommentRequest,
+        ) -> AdapterFuture<'a, Comment> {
+            Box::pin(self.run_async(Operation::Create, move || self.inner.add_comment(id, req)))
+        }
+
+        fn edit_comment<'a>(
+            &'a self,
+            id: &'a str,
+            comment_id: &'a str,
+            body: &'a str,
+        ) -> AdapterFuture<'a, Comment> {
+            Box::pin(self.run_async(Operation::Update, move || {
+                self.inner.edit_comment(id, comment_id, body)
+            }))
+        }
+
+        fn resolve_comment<'a>(
+            &'a self,
+            id: &'a str,
+            comment_id: &'a str,
+            resolved: bool,
+        ) -> AdapterFuture<'a, Comment> {
+            Box::pin(self.run_async(Operation::Update, move || {
+                self.inner.resolve_comment(id, comment_id, resolved)
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unavailable() -> AdapterError {
+        AdapterError::Unavailable {
+            adapter: "test".into(),
+            reason: "HTTP 503".into(),
+            retry_after: None,
+        }
+    }
+
+    fn rate_limited(in_secs: i64) -> AdapterError {
+        AdapterError::RateLimit {
+            adapter: "test".into(),
+            reset_at: Some(Utc::now() + chrono::Duration::seconds(in_secs)),
+        }
+    }
+
+    fn fixed() -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            jitter: false,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            max_delay: Duration::from_millis(350),
+            ..fixed()
+        };
+        let delays: Vec<_> = (1..5)
+            .map(|n| {
+                policy
+                    .delay_for(Operation::Read, &unavailable(), n)
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(
+            delays,
+            [100, 200, 350, 350].map(Duration::from_millis).to_vec()
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_half_and_full_delay() {
+        let policy = RetryPolicy {
+            jitter: true,
+            ..fixed()
+        };
+        for _ in 0..20 {
+            let delay = policy
+                .delay_for(Operation::Read, &unavailable(), 2)
+                .unwrap();
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let policy = fixed();
+        assert!(policy
+            .delay_for(Operation::Read, &unavailable(), 3)
+            .is_some());
+        assert!(policy
+            .delay_for(Operation::Read, &unavailable(), 4)
+            .is_none());
+    }
+
+    #[test]
+    fn rate_limit_reset_is_honoured_within_max_wait() {
+        let policy = fixed();
+        let delay = policy
+            .delay_for(Operation::Update, &rate_limited(5), 1)
+            .unwrap();
+        assert!(delay > Duration::from_secs(3) && delay <= Duration::from_secs(5));
+        assert!(policy
+            .delay_for(Operation::Read, &rate_limited(3600), 1)
+            .is_none());
+    }
+
+    #[test]
+    fn create_is_never_retried() {
+        let policy = fixed();
+        assert!(policy
+            .delay_for(Operation::Create, &unavailable(), 1)
+            .is_none());
+        assert!(policy
+            .delay_for(Operation::Create, &rate_limited(0), 1)
+            .is_none());
+        assert!(policy
+            .delay_for(Operation::Delete, &unavailable(), 1)
+            .is_some());
+    }
+
+    #[test]
+    fn conditional_updates_are_not_retried() {
+        let policy = fixed();
+        let mut req = UpdateRequest::default();
+        assert!(policy
+            .delay_for(Operation::for_update(&req), &rate_limited(0), 1)
+            .is_some());
+        req.expected_version = Some("v1".into());
+        assert!(policy
+            .delay_for(Operation::for_update(&req), &rate_limited(0), 1)
+            .is_none());
+    }
+
+    #[test]
+    fn permanent_errors_are_not_retried() {
+        let err = AdapterError::NotFound("1".into());
+        assert!(fixed().delay_for(Operation::Read, &err, 1).is_none());
+    }
+
+    #[test]
+    fn config_parses_settings() {
+        let parse = |v| RetryPolicy::from_settings(&serde_json::json!({ "retry": v }));
+        assert_eq!(parse(serde_json::json!(false)).unwrap(), None);
+        assert_eq!(
+            parse(serde_json::json!({ "max_attempts": 2, "jitter": false })).unwrap(),
+            Some(RetryPolicy {
+                max_attempts: 2,
+                jitter: false,
+                ..RetryPolicy::default()
+            })
+        );
+        assert!(parse(serde_json::json!({ "max_attempts": 0 })).is_err());
+        assert!(parse(serde_json::json!(3)).is_err());
+    }
+
+    #[cfg(any(feature = "github", feature = "jira"))]
+    #[test]
+    fn retry_after_reads_delay_seconds() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        assert!(parse_retry_after(&headers).is_none());
+
+        headers.insert("retry-after", HeaderValue::from_static(" 30 "));
+        let at = parse_retry_after(&headers).unwrap();
+        let secs = (at - Utc::now()).num_seconds();
+        assert!((28..=30).contains(&secs), "{secs}");
+
+        // The HTTP-date form is not supported
+        headers.insert(
+            "retry-after",
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert!(parse_retry_after(&headers).is_none());
+    }
+}
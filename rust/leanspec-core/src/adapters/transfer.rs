@@ -0,0 +1,804 @@
+//! Portable project archives
+//!
+//! An [`Archive`] is every [`SpecDoc`] of a project together with the
+//! [`SpecSchema`] it was written against, so a project can move between
+//! adapters (markdown → GitHub, Jira → markdown) without scripts.
+//!
+//! Archives are written as one JSON document or as NDJSON: a header line
+//! carrying the version, source adapter and schema, then one line per spec.
+//!
+//! ```text
+//! {"type":"header","version":1,"adapter":"markdown","exported_at":"…","schema":{…}}
+//! {"type":"spec","id":"001-auth","title":"Auth","schema_id":"…","fields":{…},"links":[…]}
+//! ```
+//!
+//! Importing goes through an [`ImportPlan`]: fields are matched to the
+//! target schema by semantic, then by key; enum values are matched to the
+//! target's options by value or label, with a [`ValueMapping`] for values
+//! that have no obvious counterpart. Nothing is written until every value
+//! maps. [`ImportPlan::apply`] then creates the specs and, once every new
+//! id is known, rewrites the links between them.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{
+    Adapter, AdapterError, CreateRequest, FieldDef, FieldKind, FieldValue, ItemLink, ListFilter,
+    SpecDoc, SpecSchema, UpdateRequest,
+};
+
+/// Archive format version written by this build.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// Errors reading, mapping or importing an archive.
+#[derive(Debug, Error)]
+pub enum TransferError {
+    #[error("Invalid archive at line {line}: {reason}")]
+    Parse { line: usize, reason: String },
+
+    #[error("Unsupported archive version {found} (expected {expected})")]
+    Version { found: u32, expected: u32 },
+
+    #[error("Invalid mapping file: {0}")]
+    Mapping(String),
+
+    /// Enum values with no counterpart in the target schema, one
+    /// `field: value, value` entry per field.
+    #[error("Values with no equivalent in the target schema (add them to a mapping file):\n  {}", .0.join("\n  "))]
+    Unmapped(Vec<String>),
+
+    /// Creating a spec failed; the specs created before it are kept.
+    #[error("Importing {id} failed after {imported} of {total} specs were created: {source}")]
+    Import {
+        id: String,
+        imported: usize,
+        total: usize,
+        source: AdapterError,
+    },
+
+    /// Rewriting the links of an imported spec failed.
+    #[error("Linking {id} failed: {source}")]
+    Link { id: String, source: AdapterError },
+
+    #[error(transparent)]
+    Adapter(#[from] AdapterError),
+}
+
+/// Every spec of a project plus the schema they conform to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Archive {
+    pub version: u32,
+    /// Name of the adapter the specs were exported from.
+    pub adapter: String,
+    pub exported_at: DateTime<Utc>,
+    pub schema: SpecSchema,
+    pub specs: Vec<SpecDoc>,
+}
+
+/// One NDJSON line.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Record {
+    Header {
+        version: u32,
+        adapter: String,
+        exported_at: DateTime<Utc>,
+        schema: SpecSchema,
+    },
+    Spec(SpecDoc),
+}
+
+impl Archive {
+    /// Read every spec, archived ones included, from `adapter`.
+    pub fn export(adapter: &dyn Adapter) -> Result<Self, AdapterError> {
+        let filter = ListFilter {
+            include_archived: true,
+            ..Default::default()
+        };
+        Ok(Self {
+            version: ARCHIVE_VERSION,
+            adapter: adapter.capabilities().name.clone(),
+            exported_at: Utc::now(),
+            schema: adapter.schema().clone(),
+            specs: adapter.list(&filter)?,
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn to_ndjson(&self) -> Result<String, serde_json::Error> {
+        let header = Record::Header {
+            version: self.version,
+            adapter: self.adapter.clone(),
+            exported_at: self.exported_at,
+            schema: self.schema.clone(),
+        };
+        let mut out = serde_json::to_string(&header)?;
+        out.push('\n');
+        for doc in &self.specs {
+            out.push_str(&serde_json::to_string(&Record::Spec(doc.clone()))?);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Parse an archive written by [`to_json`](Self::to_json) or
+    /// [`to_ndjson`](Self::to_ndjson).
+    pub fn parse(input: &str) -> Result<Self, TransferError> {
+        let first = input.lines().find(|line| !line.trim().is_empty());
+        let archive = match first.map(serde_json::from_str::<Record>) {
+            Some(Ok(Record::Header { .. })) => Self::parse_ndjson(input)?,
+            _ => serde_json::from_str::<Archive>(input).map_err(|e| TransferError::Parse {
+                line: e.line(),
+                reason: e.to_string(),
+            })?,
+        };
+        if archive.version != ARCHIVE_VERSION {
+            return Err(TransferError::Version {
+                found: archive.version,
+                expected: ARCHIVE_VERSION,
+            });
+        }
+        Ok(archive)
+    }
+
+    fn parse_ndjson(input: &str) -> Result<Self, TransferError> {
+        let mut archive: Option<Archive> = None;
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let parse_error = |reason: String| TransferError::Parse {
+                line: index + 1,
+                reason,
+            };
+            let record: Record =
+                serde_json::from_str(line).map_err(|e| parse_error(e.to_string()))?;
+            match (record, archive.as_mut()) {
+                (
+                    Record::Header {
+                        version,
+                        adapter,
+                        exported_at,
+                        schema,
+                    },
+                    None,
+                ) => {
+                    archive = Some(Archive {
+                        version,
+                        adapter,
+                        exported_at,
+                        schema,
+                        specs: Vec::new(),
+                    })
+                }
+                (Record::Header { .. }, Some(_)) => {
+                    return Err(parse_error("unexpected second header".into()))
+                }
+                (Record::Spec(doc), Some(archive)) => archive.specs.push(doc),
+                (Record::Spec(_), None) => {
+                    return Err(parse_error("spec before the header line".into()))
+                }
+            }
+        }
+        archive.ok_or_else(|| TransferError::Parse {
+            line: 1,
+            reason: "missing header line".into(),
+        })
+    }
+}
+
+/// Enum value translations for an import, keyed by target field key or
+/// semantic, then by source value:
+///
+/// ```yaml
+/// status:
+///   open: planned
+///   closed: complete
+/// priority:
+///   P1: high
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(transparent)]
+pub struct ValueMapping(HashMap<String, HashMap<String, String>>);
+
+impl ValueMapping {
+    pub fn from_yaml(yaml: &str) -> Result<Self, TransferError> {
+        serde_yaml::from_str(yaml).map_err(|e| TransferError::Mapping(e.to_string()))
+    }
+
+    /// Translation of `value` under the first of `keys` that maps it;
+    /// source values match case-insensitively.
+    fn lookup(&self, keys: &[&str], value: &str) -> Option<&str> {
+        keys.iter()
+            .filter_map(|key| self.0.get(*key))
+            .find_map(|values| {
+                values.get(value).or_else(|| {
+                    values
+                        .iter()
+                        .find(|(from, _)| from.eq_ignore_ascii_case(value))
+                        .map(|(_, to)| to)
+                })
+            })
+            .map(String::as_str)
+    }
+}
+
+/// A spec to create, with its links still pointing at archive ids.
+#[derive(Debug, Clone)]
+pub struct PlannedSpec {
+    /// Id of the spec in the archive.
+    pub source_id: String,
+    pub request: CreateRequest,
+    pub links: Vec<ItemLink>,
+}
+
+/// An archive translated to a target schema, ready to [`apply`](Self::apply).
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    pub specs: Vec<PlannedSpec>,
+    /// Fields and links that could not be carried over.
+    pub warnings: Vec<String>,
+}
+
+/// Archive id → id assigned by the target adapter.
+#[derive(Debug, Clone, Serialize)]
+pub struct IdMapping {
+    pub from: String,
+    pub to: String,
+}
+
+/// Outcome of [`ImportPlan::apply`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    pub ids: Vec<IdMapping>,
+    /// Number of links written.
+    pub links: usize,
+}
+
+enum Conversion {
+    /// Enum values with no counterpart among the target's options.
+    Unmapped(Vec<String>),
+    /// The value's shape cannot be stored in the target field.
+    Incompatible,
+}
+
+impl ImportPlan {
+    /// Translate `archive` to the `target` schema. Fails with
+    /// [`TransferError::Unmapped`] listing every enum value that neither
+    /// matches a target option nor has an entry in `mapping`.
+    pub fn new(
+        archive: &Archive,
+        target: &SpecSchema,
+        mapping: &ValueMapping,
+    ) -> Result<Self, TransferError> {
+        let index: HashMap<&str, usize> = archive
+            .specs
+            .iter()
+            .enumerate()
+            .map(|(i, doc)| (doc.id.as_str(), i))
+            .collect();
+
+        let mut unmapped: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        let mut dropped_fields = BTreeSet::new();
+        let mut incompatible_fields = BTreeSet::new();
+        let mut dropped_links = BTreeSet::new();
+        let mut external_links = 0;
+        let mut flipped: Vec<(usize, ItemLink)> = Vec::new();
+        let mut specs = Vec::with_capacity(archive.specs.len());
+
+        for doc in &archive.specs {
+            let mut fields = HashMap::new();
+            for (key, value) in &doc.fields {
+                let source_def = archive.schema.field(key);
+                let Some(target_def) = target_field(source_def, key, target) else {
+                    dropped_fields.insert(key.clone());
+                    continue;
+                };
+                match convert(value, source_def, target_def, mapping) {
+                    Ok(Some(value)) => {
+                        fields.insert(target_def.key.clone(), value);
+                    }
+                    Ok(None) => {}
+                    Err(Conversion::Unmapped(values)) => unmapped
+                        .entry(target_def.key.clone())
+                        .or_default()
+                        .extend(values),
+                    Err(Conversion::Incompatible) => {
+                        incompatible_fields.insert(key.clone());
+                    }
+                }
+            }
+
+            let mut links = Vec::new();
+            for link in &doc.links {
+                let Some(&target_index) = index.get(link.target_id.as_str()) else {
+                    external_links += 1;
+                    continue;
+                };
+                match link_direction(&link.link_type, &archive.schema, target) {
+                    Some(Direction::Keep) => links.push(link.clone()),
+                    Some(Direction::Inverse(inverse)) => flipped.push((
+                        target_index,
+                        ItemLink {
+                            link_type: inverse,
+                            target_id: doc.id.clone(),
+                            target_title: Some(doc.title.clone()),
+                        },
+                    )),
+                    None => {
+                        dropped_links.insert(link.link_type.clone());
+                    }
+                }
+            }
+
+            specs.push(PlannedSpec {
+                source_id: doc.id.clone(),
+                request: CreateRequest {
+                    slug: slug_hint(&doc.id),
+                    title: doc.title.clone(),
+                    schema_id: None,
+                    fields,
+                    links: Vec::new(),
+                },
+                links,
+            });
+        }
+
+        if !unmapped.is_empty() {
+            return Err(TransferError::Unmapped(
+                unmapped
+                    .into_iter()
+                    .map(|(key, values)| {
+                        format!(
+                            "{}: {}",
+                            key,
+                            values.into_iter().collect::<Vec<_>>().join(", ")
+                        )
+                    })
+                    .collect(),
+            ));
+        }
+
+        for (target_index, link) in flipped {
+            specs[target_index].links.push(link);
+        }
+        dedupe_links(&mut specs);
+
+        let mut warnings = Vec::new();
+        for key in dropped_fields {
+            warnings.push(format!(
+                "Field '{}' has no equivalent in the target schema and was dropped",
+                key
+            ));
+        }
+        for key in incompatible_fields {
+            warnings.push(format!(
+                "Field '{}' could not be converted to the target field type and was dropped",
+                key
+            ));
+        }
+        for link_type in dropped_links {
+            warnings.push(format!(
+                "Link type '{}' has no equivalent in the target schema and was dropped",
+                link_type
+            ));
+        }
+        if external_links > 0 {
+            warnings.push(format!(
+                "{} link(s) point to specs outside the archive and were dropped",
+                external_links
+            ));
+        }
+
+        Ok(Self { specs, warnings })
+    }
+
+    /// Create every planned spec in `adapter`, then write the links with the
+    /// ids the adapter assigned.
+    pub fn apply(&self, adapter: &dyn Adapter) -> Result<ImportReport, TransferError> {
+        let total = self.specs.len();
+        let mut ids: HashMap<&str, String> = HashMap::new();
+        let mut report = ImportReport::default();
+
+        for spec in &self.specs {
+            let created =
+                adapter
+                    .create(&spec.request)
+                    .map_err(|source| TransferError::Import {
+                        id: spec.source_id.clone(),
+                        imported: ids.len(),
+                        total,
+                        source,
+                    })?;
+            report.ids.push(IdMapping {
+                from: spec.source_id.clone(),
+                to: created.id.clone(),
+            });
+            ids.insert(&spec.source_id, created.id);
+        }
+
+        for spec in &self.specs {
+            if spec.links.is_empty() {
+                continue;
+            }
+            let links: Vec<ItemLink> = spec
+                .links
+                .iter()
+                .map(|link| ItemLink {
+                    link_type: link.link_type.clone(),
+                    target_id: ids[link.target_id.as_str()].clone(),
+                    target_title: link.target_title.clone(),
+                })
+                .collect();
+            let id = &ids[spec.source_id.as_str()];
+            report.links += links.len();
+            let update = UpdateRequest {
+                replace_links: Some(links),
+                ..Default::default()
+            };
+            adapter
+                .update(id, &update)
+                .map_err(|source| TransferError::Link {
+                    id: id.clone(),
+                    source,
+                })?;
+        }
+
+        Ok(report)
+    }
+}
+
+/// The target field for a source field: same semantic first, then same key.
+fn target_field<'t>(
+    source: Option<&FieldDef>,
+    key: &str,
+    target: &'t SpecSchema,
+) -> Option<&'t FieldDef> {
+    source
+        .and_then(|def| def.semantic.as_deref())
+        .and_then(|semantic| target.field_with_semantic(semantic))
+        .or_else(|| target.field(key))
+}
+
+fn convert(
+    value: &FieldValue,
+    source: Option<&FieldDef>,
+    target: &FieldDef,
+    mapping: &ValueMapping,
+) -> Result<Option<FieldValue>, Conversion> {
+    let converted = match (&target.kind, value) {
+        (
+            FieldKind::Enum {
+                options,
+                multi,
+                allow_custom,
+                dynamic,
+            },
+            FieldValue::String(_) | FieldValue::Strings(_),
+        ) => {
+            let keys: Vec<&str> = [
+                Some(target.key.as_str()),
+                target.semantic.as_deref(),
+                source.map(|def| def.key.as_str()),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            let mut values = Vec::new();
+            let mut missing = Vec::new();
+            for value in strings(value) {
+                let candidate = mapping.lookup(&keys, value).unwrap_or(value);
+                let option = options.iter().find(|o| {
+                    o.value.eq_ignore_ascii_case(candidate)
+                        || o.label.eq_ignore_ascii_case(candidate)
+                });
+                match option {
+                    Some(option) => values.push(option.value.clone()),
+                    None if *allow_custom || *dynamic || options.is_empty() => {
+                        values.push(candidate.to_string())
+                    }
+                    None => missing.push(value.to_string()),
+                }
+            }
+            if !missing.is_empty() {
+                return Err(Conversion::Unmapped(missing));
+            }
+            if *multi {
+                FieldValue::Strings(values)
+            } else {
+                match values.into_iter().next() {
+                    Some(value) => FieldValue::String(value),
+                    None => return Ok(None),
+                }
+            }
+        }
+        (FieldKind::Text | FieldKind::LongText, value) => FieldValue::String(match value {
+            FieldValue::String(s) => s.clone(),
+            FieldValue::Strings(values) => values.join(", "),
+            FieldValue::Number(n) => n.to_string(),
+            FieldValue::Bool(b) => b.to_string(),
+            FieldValue::Timestamp(t) => t.format("%Y-%m-%d").to_string(),
+            _ => return Err(Conversion::Incompatible),
+        }),
+        (FieldKind::Number, FieldValue::Number(n)) => FieldValue::Number(*n),
+        (FieldKind::Number, FieldValue::String(s)) => {
+            FieldValue::Number(s.trim().parse().map_err(|_| Conversion::Incompatible)?)
+        }
+        (FieldKind::Bool, FieldValue::Bool(b)) => FieldValue::Bool(*b),
+        (FieldKind::Timestamp, FieldValue::Timestamp(t)) => FieldValue::Timestamp(*t),
+        (FieldKind::Timestamp, FieldValue::String(s)) => {
+            FieldValue::Timestamp(parse_timestamp(s).ok_or(Conversion::Incompatible)?)
+        }
+        (FieldKind::Checklist { .. }, FieldValue::Checklist(_))
+        | (FieldKind::References { .. }, FieldValue::References(_)) => value.clone(),
+        _ => return Err(Conversion::Incompatible),
+    };
+    Ok(Some(converted))
+}
+
+fn strings(value: &FieldValue) -> Vec<&str> {
+    match value {
+        FieldValue::String(s) => vec![s.as_str()],
+        FieldValue::Strings(values) => values.iter().map(String::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|t| t.and_utc())
+        })
+}
+
+/// `"012-api-auth"` → `"api-auth"`, so numbering targets don't stack a new
+/// number in front of the old one.
+fn slug_hint(id: &str) -> Option<String> {
+    let (number, rest) = id.split_once('-')?;
+    (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) && !rest.is_empty())
+        .then(|| rest.to_string())
+}
+
+enum Direction {
+    Keep,
+    /// Store the link on the other spec under this inverse type.
+    Inverse(String),
+}
+
+/// How to store a link of `link_type` in the target. A relationship is kept
+/// in the direction the target schema declares first, so `A child B`
+/// becomes `B parent A` when `parent` comes first (markdown only persists
+/// `parent`) or when the target has no `child` type at all.
+fn link_direction(link_type: &str, source: &SpecSchema, target: &SpecSchema) -> Option<Direction> {
+    let position = |key: &str| target.link_types.iter().position(|t| t.key == key);
+    let inverse = source
+        .link_types
+        .iter()
+        .chain(&target.link_types)
+        .find(|t| t.key == link_type)
+        .and_then(|t| t.inverse_key.as_deref());
+    match (position(link_type), inverse.and_then(position)) {
+        (Some(own), Some(other)) if other < own => inverse.map(|i| Direction::Inverse(i.into())),
+        (Some(_), _) => Some(Direction::Keep),
+        (None, Some(_)) => inverse.map(|i| Direction::Inverse(i.into())),
+        (None, None) => None,
+    }
+}
+
+/// Drop links recorded twice, e.g. `A parent B` from A and, flipped,
+/// from `B child A`.
+fn dedupe_links(specs: &mut [PlannedSpec]) {
+    for spec in specs.iter_mut() {
+        let mut seen = HashSet::new();
+        spec.links
+            .retain(|link| seen.insert((link.link_type.clone(), link.target_id.clone())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::markdown::MarkdownAdapter;
+    use crate::model::{EnumOption, FieldDisplay, LinkTypeDef};
+    use tempfile::TempDir;
+
+    fn enum_field(key: &str, values: &[&str]) -> FieldDef {
+        FieldDef {
+            key: key.into(),
+            label: key.into(),
+            kind: FieldKind::Enum {
+                options: values
+                    .iter()
+                    .map(|v| EnumOption {
+                        value: v.to_string(),
+                        label: v.to_string(),
+                        color: None,
+                        icon: None,
+                        description: None,
+                    })
+                    .collect(),
+                multi: false,
+                allow_custom: false,
+                dynamic: false,
+            },
+            display: FieldDisplay::Inline,
+            required: false,
+            semantic: Some(key.into()),
+            ai_hint: None,
+            placeholder: None,
+        }
+    }
+
+    fn issue_archive() -> Archive {
+        let schema = SpecSchema {
+            id: "test:issue".into(),
+            name: "Issue".into(),
+            extends: None,
+            fields: vec![enum_field("status", &["open", "closed"])],
+            link_types: vec![
+                LinkTypeDef {
+                    key: "child".into(),
+                    label: "Child".into(),
+                    inverse_key: Some("parent".into()),
+                    inverse_label: Some("Parent".into()),
+                },
+                LinkTypeDef {
+                    key: "depends_on".into(),
+                    label: "Depends on".into(),
+                    inverse_key: Some("blocked_by".into()),
+                    inverse_label: Some("Blocked by".into()),
+                },
+            ],
+        };
+        let doc = |id: &str, status: &str, links: Vec<ItemLink>| SpecDoc {
+            id: id.into(),
+            title: format!("Issue {}", id),
+            schema_id: schema.id.clone(),
+            fields: HashMap::from([
+                ("status".to_string(), FieldValue::from(status)),
+                ("estimate".to_string(), FieldValue::Number(3.0)),
+            ]),
+            links,
+            created_at: None,
+            updated_at: None,
+            url: None,
+            raw: None,
+            version: None,
+        };
+        let link = |link_type: &str, target: &str| ItemLink {
+            link_type: link_type.into(),
+            target_id: target.into(),
+            target_title: None,
+        };
+        Archive {
+            version: ARCHIVE_VERSION,
+            adapter: "test".into(),
+            exported_at: Utc::now(),
+            specs: vec![
+                doc("PROJ-1", "open", vec![link("child", "PROJ-2")]),
+                doc(
+                    "PROJ-2",
+                    "closed",
+                    vec![link("depends_on", "PROJ-1"), link("depends_on", "PROJ-9")],
+                ),
+            ],
+            schema,
+        }
+    }
+
+    #[test]
+    fn ndjson_round_trip() {
+        let archive = issue_archive();
+        let ndjson = archive.to_ndjson().unwrap();
+        assert_eq!(ndjson.lines().count(), 3);
+        assert!(ndjson.starts_with("{\"type\":\"header\""));
+
+        let parsed = Archive::parse(&ndjson).unwrap();
+        assert_eq!(parsed.specs.len(), 2);
+        assert_eq!(parsed.schema.id, "test:issue");
+        assert_eq!(parsed.specs[1].links.len(), 2);
+
+        let json = Archive::parse(&archive.to_json().unwrap()).unwrap();
+        assert_eq!(json.specs[0].id, "PROJ-1");
+
+        let headless = ndjson.lines().skip(1).collect::<Vec<_>>().join("\n");
+        assert!(matches!(
+            Archive::parse(&headless),
+            Err(TransferError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn plan_requires_mapping_for_unknown_values() {
+        let archive = issue_archive();
+        let tmp = TempDir::new().unwrap();
+        let target = MarkdownAdapter::new(tmp.path());
+
+        let err = ImportPlan::new(&archive, target.schema(), &ValueMapping::default()).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("status: closed, open"), "{}", message);
+
+        let mapping =
+            ValueMapping::from_yaml("status:\n  Open: planned\n  closed: complete\n").unwrap();
+        let plan = ImportPlan::new(&archive, target.schema(), &mapping).unwrap();
+        assert_eq!(
+            plan.specs[0].request.fields["status"].as_str(),
+            Some("planned")
+        );
+        assert!(plan.warnings.iter().any(|w| w.contains("'estimate'")));
+        assert!(plan.warnings.iter().any(|w| w.contains("1 link(s)")));
+
+        // PROJ-1 child PROJ-2 becomes PROJ-2 parent PROJ-1.
+        let links = &plan.specs[1].links;
+        assert_eq!(links.len(), 2);
+        assert!(links
+            .iter()
+            .any(|l| l.link_type == "parent" && l.target_id == "PROJ-1"));
+        assert!(plan.specs[0].links.is_empty());
+    }
+
+    #[test]
+    fn apply_remaps_link_ids() {
+        let source_dir = TempDir::new().unwrap();
+        let source = MarkdownAdapter::new(source_dir.path());
+        let base = source
+            .create(&CreateRequest {
+                slug: Some("base".into()),
+                title: "Base".into(),
+                fields: HashMap::from([("status".to_string(), FieldValue::from("complete"))]),
+                ..Default::default()
+            })
+            .unwrap();
+        source
+            .create(&CreateRequest {
+                slug: Some("feature".into()),
+                title: "Feature".into(),
+                fields: HashMap::from([("status".to_string(), FieldValue::from("planned"))]),
+                links: vec![ItemLink {
+                    link_type: "depends_on".into(),
+                    target_id: base.id.clone(),
+                    target_title: None,
+                }],
+                ..Default::default()
+            })
+            .unwrap();
+        let archive =
+            Archive::parse(&Archive::export(&source).unwrap().to_ndjson().unwrap()).unwrap();
+
+        // Shift numbering in the target so every id changes.
+        let target_dir = TempDir::new().unwrap();
+        let target = MarkdownAdapter::new(target_dir.path());
+        target
+            .create(&CreateRequest {
+                title: "Existing".into(),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let plan = ImportPlan::new(&archive, target.schema(), &ValueMapping::default()).unwrap();
+        let report = plan.apply(&target).unwrap();
+        assert_eq!(report.links, 1);
+        assert_eq!(report.ids[0].from, "001-base");
+        assert_eq!(report.ids[0].to, "002-base");
+
+        let feature = target.get("003-feature").unwrap();
+        assert_eq!(feature.title, "Feature");
+        assert_eq!(feature.links[0].target_id, "002-base");
+        assert_eq!(
+            target.get("002-base").unwrap().fields["status"].as_str(),
+            Some("complete")
+        );
+    }
+}
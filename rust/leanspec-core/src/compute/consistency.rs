@@ -0,0 +1,505 @@
+//! Project-wide consistency analysis
+//!
+//! Per-spec validators only see one spec at a time, and relationship checks
+//! only run when a link is written. This module looks across the whole
+//! project for states that are individually valid but contradictory together:
+//! active specs linked to archived ones, completed work resting on unfinished
+//! dependencies, duplicate titles, and spec references in body text that the
+//! frontmatter doesn't back up.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::adapters::markdown::types::{SpecInfo, SpecStatus};
+use crate::types::{ErrorSeverity, ValidationError};
+
+/// Matches in-body references such as `spec 042`, `Spec #42` or `specs 12`.
+static SPEC_REFERENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bspecs?\s+#?(\d{1,4})\b").unwrap());
+
+static FENCE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(`{3,}|~{3,})").unwrap());
+
+/// Kind of cross-spec inconsistency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsistencyIssueKind {
+    /// A non-archived spec depends on, or is parented by, an archived spec
+    ArchivedLink,
+    /// A complete spec depends on a spec that isn't complete
+    CompleteDependsOnIncomplete,
+    /// An umbrella spec is complete while some children are still open
+    UmbrellaOpenChildren,
+    /// An in-progress spec depends on specs that haven't started
+    InProgressDependsOnPlanned,
+    /// Two or more specs share the same title
+    DuplicateTitle,
+    /// Body text references a spec number that doesn't exist
+    DanglingBodyReference,
+    /// Body text references a spec with no matching frontmatter relationship
+    UnlinkedBodyReference,
+}
+
+impl ConsistencyIssueKind {
+    /// Stable kebab-case identifier, used in reports
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConsistencyIssueKind::ArchivedLink => "archived-link",
+            ConsistencyIssueKind::CompleteDependsOnIncomplete => "complete-depends-on-incomplete",
+            ConsistencyIssueKind::UmbrellaOpenChildren => "umbrella-open-children",
+            ConsistencyIssueKind::InProgressDependsOnPlanned => "in-progress-depends-on-planned",
+            ConsistencyIssueKind::DuplicateTitle => "duplicate-title",
+            ConsistencyIssueKind::DanglingBodyReference => "dangling-body-reference",
+            ConsistencyIssueKind::UnlinkedBodyReference => "unlinked-body-reference",
+        }
+    }
+}
+
+impl std::fmt::Display for ConsistencyIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A single inconsistency found between specs
+#[derive(Debug, Clone)]
+pub struct ConsistencyIssue {
+    pub kind: ConsistencyIssueKind,
+    pub severity: ErrorSeverity,
+    /// The spec the issue is reported against
+    pub spec: String,
+    /// Other specs involved
+    pub related: Vec<String>,
+    pub message: String,
+    /// Body-relative line number, for issues found in body text
+    pub line: Option<usize>,
+}
+
+impl ConsistencyIssue {
+    /// Convert to a validation error under the `consistency` category
+    pub fn to_validation_error(&self) -> ValidationError {
+        ValidationError {
+            severity: self.severity,
+            message: self.message.clone(),
+            line: self.line,
+            category: "consistency".to_string(),
+            suggestion: None,
+        }
+    }
+}
+
+/// Analyze all specs in a project for cross-spec inconsistencies.
+///
+/// `specs` should contain every spec, archived ones included, so links to
+/// archived specs can be told apart from links to missing ones.
+pub fn check_consistency(specs: &[SpecInfo]) -> Vec<ConsistencyIssue> {
+    let by_path: HashMap<&str, &SpecInfo> = specs.iter().map(|s| (s.path.as_str(), s)).collect();
+    let mut issues = Vec::new();
+
+    check_archived_links(specs, &by_path, &mut issues);
+    check_complete_dependencies(specs, &by_path, &mut issues);
+    check_umbrella_children(specs, &mut issues);
+    check_in_progress_dependencies(specs, &by_path, &mut issues);
+    check_duplicate_titles(specs, &mut issues);
+    check_body_references(specs, &mut issues);
+
+    issues
+}
+
+fn is_closed(status: SpecStatus) -> bool {
+    matches!(status, SpecStatus::Complete | SpecStatus::Archived)
+}
+
+fn check_archived_links(
+    specs: &[SpecInfo],
+    by_path: &HashMap<&str, &SpecInfo>,
+    issues: &mut Vec<ConsistencyIssue>,
+) {
+    for spec in specs
+        .iter()
+        .filter(|s| s.frontmatter.status != SpecStatus::Archived)
+    {
+        let targets = spec
+            .frontmatter
+            .depends_on
+            .iter()
+            .map(|d| ("depends on", d.as_str()))
+            .chain(
+                spec.frontmatter
+                    .parent
+                    .as_deref()
+                    .map(|p| ("has parent", p)),
+            );
+
+        for (relation, target) in targets {
+            let archived = by_path
+                .get(target)
+                .is_some_and(|t| t.frontmatter.status == SpecStatus::Archived);
+            if archived {
+                issues.push(ConsistencyIssue {
+                    kind: ConsistencyIssueKind::ArchivedLink,
+                    severity: ErrorSeverity::Warning,
+                    spec: spec.path.clone(),
+                    related: vec![target.to_string()],
+                    message: format!("Active spec {} archived spec {}", relation, target),
+                    line: None,
+                });
+            }
+        }
+    }
+}
+
+fn check_complete_dependencies(
+    specs: &[SpecInfo],
+    by_path: &HashMap<&str, &SpecInfo>,
+    issues: &mut Vec<ConsistencyIssue>,
+) {
+    for spec in specs
+        .iter()
+        .filter(|s| s.frontmatter.status == SpecStatus::Complete)
+    {
+        let incomplete: Vec<String> = spec
+            .frontmatter
+            .depends_on
+            .iter()
+            .filter(|d| {
+                by_path
+                    .get(d.as_str())
+                    .is_some_and(|t| !is_closed(t.frontmatter.status))
+            })
+            .cloned()
+            .collect();
+
+        if !incomplete.is_empty() {
+            issues.push(ConsistencyIssue {
+                kind: ConsistencyIssueKind::CompleteDependsOnIncomplete,
+                severity: ErrorSeverity::Warning,
+                spec: spec.path.clone(),
+                message: format!(
+                    "Spec is complete but depends on incomplete spec(s): {}",
+                    incomplete.join(", ")
+                ),
+                related: incomplete,
+                line: None,
+            });
+        }
+    }
+}
+
+fn check_umbrella_children(specs: &[SpecInfo], issues: &mut Vec<ConsistencyIssue>) {
+    let mut open_children: HashMap<&str, Vec<String>> = HashMap::new();
+    for spec in specs {
+        if let Some(parent) = spec.frontmatter.parent.as_deref() {
+            if !is_closed(spec.frontmatter.status) {
+                open_children
+                    .entry(parent)
+                    .or_default()
+                    .push(spec.path.clone());
+            }
+        }
+    }
+
+    for spec in specs
+        .iter()
+        .filter(|s| s.frontmatter.status == SpecStatus::Complete)
+    {
+        if let Some(children) = open_children.get(spec.path.as_str()) {
+            issues.push(ConsistencyIssue {
+                kind: ConsistencyIssueKind::UmbrellaOpenChildren,
+                severity: ErrorSeverity::Warning,
+                spec: spec.path.clone(),
+                message: format!(
+                    "Umbrella spec is complete but has {} open child spec(s): {}",
+                    children.len(),
+                    children.join(", ")
+                ),
+                related: children.clone(),
+                line: None,
+            });
+        }
+    }
+}
+
+fn check_in_progress_dependencies(
+    specs: &[SpecInfo],
+    by_path: &HashMap<&str, &SpecInfo>,
+    issues: &mut Vec<ConsistencyIssue>,
+) {
+    for spec in specs
+        .iter()
+        .filter(|s| s.frontmatter.status == SpecStatus::InProgress)
+    {
+        let not_started: Vec<String> = spec
+            .frontmatter
+            .depends_on
+            .iter()
+            .filter(|d| {
+                by_path.get(d.as_str()).is_some_and(|t| {
+                    matches!(
+                        t.frontmatter.status,
+                        SpecStatus::Draft | SpecStatus::Planned
+                    )
+                })
+            })
+            .cloned()
+            .collect();
+
+        if !not_started.is_empty() {
+            issues.push(ConsistencyIssue {
+                kind: ConsistencyIssueKind::InProgressDependsOnPlanned,
+                severity: ErrorSeverity::Warning,
+                spec: spec.path.clone(),
+                message: format!(
+                    "Spec is in progress but its dependencies haven't started: {}",
+                    not_started.join(", ")
+                ),
+                related: not_started,
+                line: None,
+            });
+        }
+    }
+}
+
+fn check_duplicate_titles(specs: &[SpecInfo], issues: &mut Vec<ConsistencyIssue>) {
+    let mut by_title: HashMap<String, Vec<&SpecInfo>> = HashMap::new();
+    for spec in specs
+        .iter()
+        .filter(|s| s.frontmatter.status != SpecStatus::Archived)
+    {
+        let title = spec.title.trim().to_lowercase();
+        if !title.is_empty() {
+            by_title.entry(title).or_default().push(spec);
+        }
+    }
+
+    for group in by_title.values().filter(|g| g.len() > 1) {
+        for spec in group {
+            let others: Vec<String> = group
+                .iter()
+                .filter(|o| o.path != spec.path)
+                .map(|o| o.path.clone())
+                .collect();
+            issues.push(ConsistencyIssue {
+                kind: ConsistencyIssueKind::DuplicateTitle,
+                severity: ErrorSeverity::Warning,
+                spec: spec.path.clone(),
+                message: format!(
+                    "Title '{}' is also used by: {}",
+                    spec.title.trim(),
+                    others.join(", ")
+                ),
+                related: others,
+                line: None,
+            });
+        }
+    }
+}
+
+fn check_body_references(specs: &[SpecInfo], issues: &mut Vec<ConsistencyIssue>) {
+    let by_number: HashMap<u32, &SpecInfo> = specs
+        .iter()
+        .filter(|s| !s.is_sub_spec)
+        .filter_map(|s| s.number().map(|n| (n, s)))
+        .collect();
+
+    let mut children: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for spec in specs {
+        if let Some(parent) = spec.frontmatter.parent.as_deref() {
+            children
+                .entry(parent)
+                .or_default()
+                .insert(spec.path.as_str());
+        }
+    }
+
+    for spec in specs {
+        let own_number = spec.number();
+        let mut linked: HashSet<&str> = spec
+            .frontmatter
+            .depends_on
+            .iter()
+            .map(String::as_str)
+            .collect();
+        linked.extend(spec.frontmatter.parent.as_deref());
+        if let Some(kids) = children.get(spec.path.as_str()) {
+            linked.extend(kids.iter().copied());
+        }
+        // Specs that depend on this one are linked too, just from the other side.
+        linked.extend(
+            specs
+                .iter()
+                .filter(|s| s.frontmatter.depends_on.contains(&spec.path))
+                .map(|s| s.path.as_str()),
+        );
+
+        let mut reported: HashSet<u32> = HashSet::new();
+        for (line_number, number) in body_references(&spec.content) {
+            if Some(number) == own_number || !reported.insert(number) {
+                continue;
+            }
+
+            match by_number.get(&number) {
+                None => issues.push(ConsistencyIssue {
+                    kind: ConsistencyIssueKind::DanglingBodyReference,
+                    severity: ErrorSeverity::Warning,
+                    spec: spec.path.clone(),
+                    related: Vec::new(),
+                    message: format!("Body references spec {:03}, which doesn't exist", number),
+                    line: Some(line_number),
+                }),
+                Some(target) if !linked.contains(target.path.as_str()) => {
+                    issues.push(ConsistencyIssue {
+                        kind: ConsistencyIssueKind::UnlinkedBodyReference,
+                        severity: ErrorSeverity::Info,
+                        spec: spec.path.clone(),
+                        related: vec![target.path.clone()],
+                        message: format!(
+                        "Body references {} but frontmatter has no depends_on or parent link to it",
+                        target.path
+                    ),
+                        line: Some(line_number),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+/// Find `spec NNN` references outside fenced code blocks, with 1-indexed lines.
+fn body_references(content: &str) -> Vec<(usize, u32)> {
+    let mut refs = Vec::new();
+    let mut in_fence = false;
+
+    for (index, line) in content.lines().enumerate() {
+        if FENCE.is_match(line) {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        for cap in SPEC_REFERENCE.captures_iter(line) {
+            if let Ok(number) = cap[1].parse::<u32>() {
+                refs.push((index + 1, number));
+            }
+        }
+    }
+
+    refs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::markdown::types::SpecFrontmatter;
+    use std::path::PathBuf;
+
+    fn make_spec(path: &str, status: SpecStatus) -> SpecInfo {
+        SpecInfo {
+            path: path.to_string(),
+            title: path.to_string(),
+            frontmatter: SpecFrontmatter {
+                status,
+                created: "2025-01-01".to_string(),
+                priority: None,
+                tags: Vec::new(),
+                depends_on: Vec::new(),
+                parent: None,
+                assignee: None,
+                reviewer: None,
+                issue: None,
+                pr: None,
+                epic: None,
+                breaking: None,
+                due: None,
+                updated: None,
+                completed: None,
+                created_at: None,
+                updated_at: None,
+                completed_at: None,
+                transitions: Vec::new(),
+                custom: std::collections::HashMap::new(),
+            },
+            content: String::new(),
+            file_path: PathBuf::from(format!("specs/{}/README.md", path)),
+            is_sub_spec: false,
+            parent_spec: None,
+        }
+    }
+
+    fn kinds(issues: &[ConsistencyIssue]) -> Vec<ConsistencyIssueKind> {
+        issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn detects_links_to_archived_specs() {
+        let old = make_spec("001-old", SpecStatus::Archived);
+        let mut active = make_spec("002-active", SpecStatus::Planned);
+        active.frontmatter.depends_on = vec!["001-old".into()];
+
+        let issues = check_consistency(&[old, active]);
+        assert_eq!(kinds(&issues), vec![ConsistencyIssueKind::ArchivedLink]);
+        assert_eq!(issues[0].spec, "002-active");
+    }
+
+    #[test]
+    fn detects_status_contradictions() {
+        let planned = make_spec("001-base", SpecStatus::Planned);
+        let mut done = make_spec("002-done", SpecStatus::Complete);
+        done.frontmatter.depends_on = vec!["001-base".into()];
+        let mut working = make_spec("003-working", SpecStatus::InProgress);
+        working.frontmatter.depends_on = vec!["001-base".into()];
+        let mut umbrella = make_spec("004-umbrella", SpecStatus::Complete);
+        umbrella.title = "Umbrella".into();
+        let mut child = make_spec("005-child", SpecStatus::InProgress);
+        child.frontmatter.parent = Some("004-umbrella".into());
+
+        let issues = check_consistency(&[planned, done, working, umbrella, child]);
+        let kinds = kinds(&issues);
+        assert!(kinds.contains(&ConsistencyIssueKind::CompleteDependsOnIncomplete));
+        assert!(kinds.contains(&ConsistencyIssueKind::InProgressDependsOnPlanned));
+        assert!(kinds.contains(&ConsistencyIssueKind::UmbrellaOpenChildren));
+    }
+
+    #[test]
+    fn detects_duplicate_titles() {
+        let mut a = make_spec("001-auth", SpecStatus::Planned);
+        a.title = "User Auth".into();
+        let mut b = make_spec("002-auth-again", SpecStatus::Draft);
+        b.title = "user auth ".into();
+
+        let issues = check_consistency(&[a, b]);
+        assert_eq!(issues.len(), 2);
+        assert!(issues
+            .iter()
+            .all(|i| i.kind == ConsistencyIssueKind::DuplicateTitle));
+    }
+
+    #[test]
+    fn checks_body_references_against_frontmatter() {
+        let base = make_spec("042-base", SpecStatus::Planned);
+        let other = make_spec("043-other", SpecStatus::Planned);
+        let mut spec = make_spec("044-feature", SpecStatus::Planned);
+        spec.frontmatter.depends_on = vec!["042-base".into()];
+        spec.content =
+            "# Feature\n\nBuilds on spec 042 and Spec #43.\n\n```\nspec 777\n```\nSee spec 099.\n"
+                .into();
+
+        let issues = check_consistency(&[base, other, spec]);
+        let unlinked: Vec<_> = issues
+            .iter()
+            .filter(|i| i.kind == ConsistencyIssueKind::UnlinkedBodyReference)
+            .collect();
+        assert_eq!(unlinked.len(), 1);
+        assert_eq!(unlinked[0].related, vec!["043-other".to_string()]);
+        assert_eq!(unlinked[0].line, Some(3));
+
+        let dangling: Vec<_> = issues
+            .iter()
+            .filter(|i| i.kind == ConsistencyIssueKind::DanglingBodyReference)
+            .collect();
+        assert_eq!(dangling.len(), 1);
+        assert!(dangling[0].message.contains("099"));
+    }
+}
@@ -1,7 +1,8 @@
 //! Insights generation for spec analysis
 
-use super::SpecStats;
+use super::{check_consistency, ConsistencyIssueKind, SpecStats, WorkloadReport};
 use crate::adapters::markdown::types::{SpecInfo, SpecPriority, SpecStatus};
+use crate::types::{ErrorSeverity, LeanSpecConfig, WorkloadConfig};
 
 /// Generated insights about specs
 #[derive(Debug, Clone, Default)]
@@ -41,6 +42,16 @@ impl std::fmt::Display for InsightSeverity {
 impl Insights {
     /// Generate insights from specs and stats
     pub fn generate(specs: &[SpecInfo], stats: &SpecStats) -> Self {
+        Self::generate_with_config(specs, stats, &LeanSpecConfig::default())
+    }
+
+    /// Generate insights, including those that depend on project settings
+    /// such as WIP limits
+    pub fn generate_with_config(
+        specs: &[SpecInfo],
+        stats: &SpecStats,
+        config: &LeanSpecConfig,
+    ) -> Self {
         let mut insights = Self::default();
 
         // Check for high priority specs that are still planned
@@ -49,6 +60,9 @@ impl Insights {
         // Check for in-progress specs without assignees
         insights.check_in_progress_no_assignee(specs);
 
+        // Check for assignees over their WIP limit
+        insights.check_over_capacity(specs, &config.workload);
+
         // Check for old planned specs
         insights.check_stale_planned(specs);
 
@@ -61,6 +75,9 @@ impl Insights {
         // Check for large specs
         insights.check_large_specs(specs);
 
+        // Check for contradictions between specs
+        insights.check_consistency(specs);
+
         insights
     }
 
@@ -110,6 +127,43 @@ impl Insights {
         }
     }
 
+    fn check_over_capacity(&mut self, specs: &[SpecInfo], config: &WorkloadConfig) {
+        if config.default_wip_limit.is_none() && config.wip_limits.is_empty() {
+            return;
+        }
+        let report = WorkloadReport::compute(specs, config, chrono::Utc::now().date_naive());
+        let over = report.over_capacity();
+        if over.is_empty() {
+            return;
+        }
+
+        let people: Vec<String> = over
+            .iter()
+            .map(|a| {
+                format!(
+                    "{} ({}/{})",
+                    a.assignee,
+                    a.in_progress,
+                    a.wip_limit.unwrap_or_default()
+                )
+            })
+            .collect();
+        self.messages.push(InsightMessage {
+            severity: InsightSeverity::Warning,
+            message: format!(
+                "{} assignee(s) over their WIP limit: {}",
+                over.len(),
+                people.join(", ")
+            ),
+            related_specs: over
+                .iter()
+                .flat_map(|a| &a.specs)
+                .filter(|s| s.status == SpecStatus::InProgress)
+                .map(|s| s.path.clone())
+                .collect(),
+        });
+    }
+
     fn check_stale_planned(&mut self, specs: &[SpecInfo]) {
         use chrono::{NaiveDate, Utc};
 
@@ -235,6 +289,76 @@ impl Insights {
         }
     }
 
+    fn check_consistency(&mut self, specs: &[SpecInfo]) {
+        let issues = check_consistency(specs);
+
+        // One insight per kind, in a fixed order so output is stable.
+        let kinds = [
+            ConsistencyIssueKind::ArchivedLink,
+            ConsistencyIssueKind::CompleteDependsOnIncomplete,
+            ConsistencyIssueKind::UmbrellaOpenChildren,
+            ConsistencyIssueKind::InProgressDependsOnPlanned,
+            ConsistencyIssueKind::DuplicateTitle,
+            ConsistencyIssueKind::DanglingBodyReference,
+            ConsistencyIssueKind::UnlinkedBodyReference,
+        ];
+
+        for kind in kinds {
+            let matching: Vec<_> = issues.iter().filter(|i| i.kind == kind).collect();
+            let Some(first) = matching.first() else {
+                continue;
+            };
+
+            let mut related_specs: Vec<String> = Vec::new();
+            for issue in &matching {
+                if !related_specs.contains(&issue.spec) {
+                    related_specs.push(issue.spec.clone());
+                }
+            }
+
+            let count = related_specs.len();
+            let message = match kind {
+                ConsistencyIssueKind::ArchivedLink => {
+                    format!("{} active spec(s) link to archived specs", count)
+                }
+                ConsistencyIssueKind::CompleteDependsOnIncomplete => {
+                    format!("{} complete spec(s) depend on incomplete specs", count)
+                }
+                ConsistencyIssueKind::UmbrellaOpenChildren => {
+                    format!(
+                        "{} umbrella spec(s) marked complete with open children",
+                        count
+                    )
+                }
+                ConsistencyIssueKind::InProgressDependsOnPlanned => format!(
+                    "{} in-progress spec(s) depend on specs that haven't started",
+                    count
+                ),
+                ConsistencyIssueKind::DuplicateTitle => {
+                    format!("{} spec(s) share a title with another spec", count)
+                }
+                ConsistencyIssueKind::DanglingBodyReference => {
+                    format!("{} spec(s) mention spec numbers that don't exist", count)
+                }
+                ConsistencyIssueKind::UnlinkedBodyReference => format!(
+                    "{} spec(s) mention other specs without a frontmatter link",
+                    count
+                ),
+            };
+
+            let severity = match first.severity {
+                ErrorSeverity::Error | ErrorSeverity::Warning => InsightSeverity::Warning,
+                ErrorSeverity::Info => InsightSeverity::Suggestion,
+            };
+
+            self.messages.push(InsightMessage {
+                severity,
+                message,
+                related_specs,
+            });
+        }
+    }
+
     /// Get critical insights
     pub fn critical(&self) -> Vec<&InsightMessage> {
         self.messages
@@ -338,4 +462,49 @@ mod tests {
             .iter()
             .any(|m| m.message.contains("assignee")));
     }
+
+    #[test]
+    fn test_consistency_insights() {
+        let archived = create_spec("001-old", SpecStatus::Archived, None, None);
+        let mut active = create_spec("002-new", SpecStatus::Planned, None, None);
+        active.frontmatter.depends_on = vec!["001-old".to_string()];
+        let specs = vec![archived, active];
+
+        let stats = SpecStats::compute(&specs);
+        let insights = Insights::generate(&specs, &stats);
+
+        let message = insights
+            .messages
+            .iter()
+            .find(|m| m.message.contains("archived"))
+            .expect("archived link insight");
+        assert_eq!(message.severity, InsightSeverity::Warning);
+        assert_eq!(message.related_specs, vec!["002-new".to_string()]);
+    }
+
+    #[test]
+    fn test_over_capacity() {
+        let specs = vec![
+            create_spec("001-a", SpecStatus::InProgress, None, Some("alice")),
+            create_spec("002-b", SpecStatus::InProgress, None, Some("alice")),
+            create_spec("003-c", SpecStatus::Planned, None, Some("alice")),
+        ];
+        let stats = SpecStats::compute(&specs);
+        assert!(!Insights::generate(&specs, &stats)
+            .messages
+            .iter()
+            .any(|m| m.message.contains("WIP limit")));
+
+        let mut config = LeanSpecConfig::default();
+        config.workload.wip_limits.insert("alice".to_string(), 1);
+        let insights = Insights::generate_with_config(&specs, &stats, &config);
+        let message = insights
+            .messages
+            .iter()
+            .find(|m| m.message.contains("WIP limit"))
+            .expect("over capacity insight");
+        assert_eq!(message.severity, InsightSeverity::Warning);
+        assert!(message.message.contains("alice (2/1)"));
+        assert_eq!(message.related_specs, vec!["001-a", "002-b"]);
+    }
 }
@@ -1,7 +1,11 @@
+pub mod consistency;
 pub mod insights;
 pub mod stats;
 pub mod tokens;
+pub mod workload;
 
+pub use consistency::*;
 pub use insights::*;
 pub use stats::*;
 pub use tokens::*;
+pub use workload::*;
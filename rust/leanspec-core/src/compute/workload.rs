@@ -0,0 +1,388 @@
+//! Workload per assignee
+//!
+//! Groups open specs (planned or in progress) by assignee with their size in
+//! tokens and how close they are to their due dates, and compares each
+//! person's in-progress count with the WIP limits from the project config.
+//!
+//! Markdown specs are read directly; documents from any adapter are read
+//! through their schema's status, assignee and due date semantics.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::global_token_counter;
+use crate::adapters::markdown::types::{SpecInfo, SpecStatus};
+use crate::model::{semantic, SpecDoc, SpecSchema};
+use crate::types::WorkloadConfig;
+
+/// Open work per assignee.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadReport {
+    /// One entry per assignee with open specs or a configured WIP limit,
+    /// sorted by name
+    pub assignees: Vec<AssigneeWorkload>,
+    /// Open specs without an assignee
+    pub unassigned: Vec<WorkloadSpec>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct AssigneeWorkload {
+    pub assignee: String,
+    pub in_progress: usize,
+    pub planned: usize,
+    /// Total tokens of the open specs, a rough measure of their size
+    pub tokens: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wip_limit: Option<usize>,
+    /// More specs in progress than the WIP limit allows
+    pub over_capacity: bool,
+    /// Open specs past their due date
+    pub overdue: usize,
+    /// Open specs due within `due_soon_days`
+    pub due_soon: usize,
+    /// In-progress specs first, then by due date
+    pub specs: Vec<WorkloadSpec>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadSpec {
+    pub path: String,
+    pub title: String,
+    pub status: SpecStatus,
+    pub tokens: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// Days until the due date, negative when overdue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<i64>,
+}
+
+impl WorkloadSpec {
+    pub fn is_overdue(&self) -> bool {
+        self.days_until_due.is_some_and(|days| days < 0)
+    }
+
+    pub fn is_due_within(&self, days: i64) -> bool {
+        self.days_until_due.is_some_and(|d| (0..=days).contains(&d))
+    }
+}
+
+impl WorkloadReport {
+    /// Compute the workload of `specs` as of `today`.
+    pub fn compute(specs: &[SpecInfo], config: &WorkloadConfig, today: NaiveDate) -> Self {
+        let open = specs.iter().filter(|spec| !spec.is_sub_spec).map(|spec| {
+            let fm = &spec.frontmatter;
+            OpenSpec {
+                path: &spec.path,
+                title: &spec.title,
+                status: fm.status,
+                assignee: fm.assignee.as_deref(),
+                due: fm.due.as_deref(),
+                content: &spec.content,
+            }
+        });
+        Self::collect(open, config, today)
+    }
+
+    /// Compute the workload of adapter documents as of `today`.
+    ///
+    /// Markdown statuses are used as they are. Other workflow states count
+    /// as in progress when named so ("In Progress") and as planned
+    /// otherwise, since adapters leave closed and done items out of a
+    /// default listing.
+    pub fn from_docs(
+        docs: &[SpecDoc],
+        schema: &SpecSchema,
+        config: &WorkloadConfig,
+        today: NaiveDate,
+    ) -> Self {
+        let open = docs.iter().map(|doc| OpenSpec {
+            path: &doc.id,
+            title: &doc.title,
+            status: semantic_str(doc, schema, semantic::STATUS)
+                .map_or(SpecStatus::Planned, doc_status),
+            assignee: semantic_str(doc, schema, semantic::ASSIGNEE),
+            due: semantic_str(doc, schema, semantic::DUE_DATE),
+            content: doc.field_str("content").unwrap_or(""),
+        });
+        Self::collect(open, config, today)
+    }
+
+    fn collect<'a>(
+        specs: impl Iterator<Item = OpenSpec<'a>>,
+        config: &WorkloadConfig,
+        today: NaiveDate,
+    ) -> Self {
+        let mut report = Self::default();
+        let counter = global_token_counter();
+
+        for spec in specs {
+            let status = spec.status;
+            if !matches!(status, SpecStatus::InProgress | SpecStatus::Planned) {
+                continue;
+            }
+            let item = WorkloadSpec {
+                path: spec.path.to_string(),
+                title: spec.title.to_string(),
+                status,
+                tokens: counter.count(spec.content),
+                days_until_due: spec
+                    .due
+                    .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+                    .map(|d| (d - today).num_days()),
+                due: spec.due.map(String::from),
+            };
+
+            let assignee = spec.assignee.map(str::trim).filter(|a| !a.is_empty());
+            match assignee {
+                Some(name) => report.entry(name).specs.push(item),
+                None => report.unassigned.push(item),
+            }
+        }
+
+        for name in config.wip_limits.keys() {
+            report.entry(name);
+        }
+
+        for workload in &mut report.assignees {
+            let specs = &mut workload.specs;
+            specs.sort_by(|a, b| {
+                (a.status != SpecStatus::InProgress)
+                    .cmp(&(b.status != SpecStatus::InProgress))
+                    .then_with(|| due_order(a).cmp(&due_order(b)))
+                    .then_with(|| a.path.cmp(&b.path))
+            });
+            workload.in_progress = specs
+                .iter()
+                .filter(|s| s.status == SpecStatus::InProgress)
+                .count();
+            workload.planned = specs.len() - workload.in_progress;
+            workload.tokens = specs.iter().map(|s| s.tokens).sum();
+            workload.overdue = specs.iter().filter(|s| s.is_overdue()).count();
+            workload.due_soon = specs
+                .iter()
+                .filter(|s| s.is_due_within(config.due_soon_days))
+                .count();
+            workload.wip_limit = config.wip_limit(&workload.assignee);
+            workload.over_capacity = workload
+                .wip_limit
+                .is_some_and(|limit| workload.in_progress > limit);
+        }
+        report.assignees.sort_by(|a, b| a.assignee.cmp(&b.assignee));
+        report.unassigned.sort_by(|a, b| a.path.cmp(&b.path));
+        report
+    }
+
+    /// Assignees with more specs in progress than their WIP limit.
+    pub fn over_capacity(&self) -> Vec<&AssigneeWorkload> {
+        self.assignees.iter().filter(|a| a.over_capacity).collect()
+    }
+
+    fn entry(&mut self, assignee: &str) -> &mut AssigneeWorkload {
+        let index = match self.assignees.iter().position(|a| a.assignee == assignee) {
+            Some(index) => index,
+            None => {
+                self.assignees.push(AssigneeWorkload {
+                    assignee: assignee.to_string(),
+                    in_progress: 0,
+                    planned: 0,
+                    tokens: 0,
+                    wip_limit: None,
+                    over_capacity: false,
+                    overdue: 0,
+                    due_soon: 0,
+                    specs: Vec::new(),
+                });
+                self.assignees.len() - 1
+            }
+        };
+        &mut self.assignees[index]
+    }
+}
+
+/// The fields of a spec or document that workload looks at.
+struct OpenSpec<'a> {
+    path: &'a str,
+    title: &'a str,
+    status: SpecStatus,
+    assignee: Option<&'a str>,
+    due: Option<&'a str>,
+    content: &'a str,
+}
+
+fn semantic_str<'a>(doc: &'a SpecDoc, schema: &SpecSchema, sem: &str) -> Option<&'a str> {
+    doc.field_str(schema.key_for_semantic(sem)?)
+}
+
+/// Map an adapter status value onto the markdown lifecycle.
+fn doc_status(value: &str) -> SpecStatus {
+    value
+        .trim()
+        .replace(' ', "-")
+        .parse()
+        .unwrap_or(SpecStatus::Planned)
+}
+
+/// Specs with a due date sort before those without, earliest first.
+fn due_order(spec: &WorkloadSpec) -> (bool, i64) {
+    (
+        spec.days_until_due.is_none(),
+        spec.days_until_due.unwrap_or(0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::markdown::types::SpecFrontmatter;
+    use std::path::PathBuf;
+
+    fn spec(path: &str, status: &str, assignee: Option<&str>, due: Option<&str>) -> SpecInfo {
+        let mut yaml = format!("status: {status}\ncreated: '2025-01-01'\n");
+        if let Some(assignee) = assignee {
+            yaml.push_str(&format!("assignee: {assignee}\n"));
+        }
+        if let Some(due) = due {
+            yaml.push_str(&format!("due: '{due}'\n"));
+        }
+        let frontmatter: SpecFrontmatter = serde_yaml::from_str(&yaml).unwrap();
+        SpecInfo {
+            path: path.to_string(),
+            title: path.to_string(),
+            frontmatter,
+            content: format!("# {path}\n\nSome content to count."),
+            file_path: PathBuf::from(format!("specs/{path}/README.md")),
+            is_sub_spec: false,
+            parent_spec: None,
+        }
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 6, 10).unwrap()
+    }
+
+    #[test]
+    fn groups_open_specs_by_assignee() {
+        let specs = vec![
+            spec("001-a", "in-progress", Some("alice"), Some("2025-06-01")),
+            spec("002-b", "planned", Some("alice"), Some("2025-06-12")),
+            spec("003-c", "in-progress", Some("alice"), None),
+            spec("004-d", "complete", Some("alice"), None),
+            spec("005-e", "planned", None, None),
+            spec("006-f", "draft", Some("bob"), None),
+        ];
+        let report = WorkloadReport::compute(&specs, &WorkloadConfig::default(), today());
+
+        assert_eq!(report.assignees.len(), 1);
+        let alice = &report.assignees[0];
+        assert_eq!((alice.in_progress, alice.planned), (2, 1));
+        assert_eq!((alice.overdue, alice.due_soon), (1, 1));
+        assert!(alice.tokens > 0);
+        assert!(!alice.over_capacity);
+        let order: Vec<_> = alice.specs.iter().map(|s| s.path.as_str()).collect();
+        assert_eq!(order, vec!["001-a", "003-c", "002-b"]);
+        assert_eq!(alice.specs[0].days_until_due, Some(-9));
+        assert_eq!(report.unassigned.len(), 1);
+    }
+
+    #[test]
+    fn compares_in_progress_with_wip_limits() {
+        let specs = vec![
+            spec("001-a", "in-progress", Some("alice"), None),
+            spec("002-b", "in-progress", Some("alice"), None),
+            spec("003-c", "in-progress", Some("bob"), None),
+        ];
+        let config = WorkloadConfig {
+            default_wip_limit: Some(1),
+            wip_limits: [("carol".to_string(), 2)].into_iter().collect(),
+            ..Default::default()
+        };
+        let report = WorkloadReport::compute(&specs, &config, today());
+
+        let names: Vec<_> = report
+            .assignees
+            .iter()
+            .map(|a| a.assignee.as_str())
+            .collect();
+        assert_eq!(names, vec!["alice", "bob", "carol"]);
+        assert_eq!(report.assignees[2].wip_limit, Some(2));
+        assert_eq!(report.assignees[2].in_progress, 0);
+        let over: Vec<_> = report
+            .over_capacity()
+            .iter()
+            .map(|a| a.assignee.as_str())
+            .collect();
+        assert_eq!(over, vec!["alice"]);
+    }
+
+    #[test]
+    fn reads_docs_through_the_schema() {
+        use crate::model::{FieldDef, FieldDisplay, FieldKind, FieldValue};
+
+        let text_field = |key: &str, sem: &str| FieldDef {
+            key: key.into(),
+            label: key.into(),
+            kind: FieldKind::Text,
+            display: FieldDisplay::Inline,
+            required: false,
+            semantic: Some(sem.into()),
+            ai_hint: None,
+            placeholder: None,
+        };
+        let schema = SpecSchema {
+            id: "test:issue".into(),
+            name: "Issue".into(),
+            extends: None,
+            fields: vec![
+                text_field("state", semantic::STATUS),
+                text_field("owner", semantic::ASSIGNEE),
+                text_field("deadline", semantic::DUE_DATE),
+            ],
+            link_types: Vec::new(),
+        };
+        let doc = |id: &str, state: &str, owner: Option<&str>, deadline: Option<&str>| {
+            let mut fields = std::collections::HashMap::new();
+            fields.insert("state".to_string(), FieldValue::String(state.into()));
+            if let Some(owner) = owner {
+                fields.insert("owner".to_string(), FieldValue::String(owner.into()));
+            }
+            if let Some(deadline) = deadline {
+                fields.insert("deadline".to_string(), FieldValue::String(deadline.into()));
+            }
+            fields.insert("content".to_string(), FieldValue::String("Body".into()));
+            SpecDoc {
+                id: id.into(),
+                title: id.into(),
+                schema_id: schema.id.clone(),
+                fields,
+                links: Vec::new(),
+                created_at: None,
+                updated_at: None,
+                url: None,
+                raw: None,
+                version: None,
+            }
+        };
+        let docs = vec![
+            doc("PROJ-1", "In Progress", Some("alice"), Some("2025-06-01")),
+            doc("PROJ-2", "To Do", Some("alice"), None),
+            doc("PROJ-3", "complete", Some("alice"), None),
+            doc("PROJ-4", "open", None, None),
+        ];
+        let report = WorkloadReport::from_docs(&docs, &schema, &WorkloadConfig::default(), today());
+
+        let alice = &report.assignees[0];
+        assert_eq!((alice.in_progress, alice.planned), (1, 1));
+        assert_eq!(alice.overdue, 1);
+        assert!(alice.tokens > 0);
+        assert_eq!(report.unassigned[0].path, "PROJ-4");
+        assert_eq!(report.unassigned[0].status, SpecStatus::Planned);
+    }
+}
@@ -26,6 +26,7 @@ pub enum ErrorCode {
 
     // Auth errors
     Unauthorized,
+    Forbidden,
 
     // Tool errors
     ToolNotFound,
@@ -56,6 +57,7 @@ impl ErrorCode {
             Self::TokenLimitExceeded => "TOKEN_LIMIT_EXCEEDED",
             Self::CircularDependency => "CIRCULAR_DEPENDENCY",
             Self::Unauthorized => "UNAUTHORIZED",
+            Self::Forbidden => "FORBIDDEN",
             Self::ToolNotFound => "TOOL_NOT_FOUND",
             Self::ToolError => "TOOL_ERROR",
             Self::IoError => "IO_ERROR",
@@ -1,16 +1,26 @@
-//! Clone manager — handles shallow sparse clones, pull, commit, push.
+//! Clone manager — handles shallow sparse clones, pull, commit, push, and
+//! branch-per-spec workflows.
 
 use crate::error::{CoreError, CoreResult};
 use crate::parsers::FrontmatterParser;
 
 use super::operations::{run_git, run_git_in};
+use super::pending::PendingQueue;
 use super::types::*;
 
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
 
 /// Candidate directories where specs might live inside a repo.
 const SPECS_DIR_CANDIDATES: &[&str] = &["specs", ".lean-spec/specs", "doc/specs", "docs/specs"];
 
+/// Prefix for per-spec working branches (`spec/042-search`).
+pub const SPEC_BRANCH_PREFIX: &str = "spec/";
+
+/// One lock per clone directory; see [`clone_lock`].
+static CLONE_LOCKS: OnceLock<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
+
 pub struct CloneManager;
 
 impl CloneManager {
@@ -48,16 +58,47 @@ impl CloneManager {
     }
 
     /// Pull latest changes from the remote.
+    ///
+    /// Branches without local commits are hard-reset to the remote (shallow
+    /// fetch). Branches carrying unpushed commits — e.g. queued pending
+    /// changes — are rebased instead so local work is never discarded.
+    /// Local-only branches have nothing to pull.
     pub fn pull(clone_dir: &Path) -> CoreResult<PullResult> {
+        let lock = clone_lock(clone_dir);
+        let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
         let old_sha = run_git(&["rev-parse", "--short", "HEAD"], clone_dir)?;
+        let branch = current_branch(clone_dir)?;
+        let remote_ref = format!("origin/{}", branch);
 
-        run_git(&["fetch", "--depth=1", "origin"], clone_dir)?;
+        let has_local_commits = ref_exists(clone_dir, &format!("refs/remotes/{}", remote_ref))
+            && ahead_behind(clone_dir, &branch, &remote_ref)?.0 > 0;
 
-        // Get the current branch
-        let branch = run_git(&["rev-parse", "--abbrev-ref", "HEAD"], clone_dir)?;
-        let remote_ref = format!("origin/{}", branch);
+        if has_local_commits {
+            ensure_full_history(clone_dir)?;
+        }
+        if !fetch_branch(clone_dir, &branch, !has_local_commits)? {
+            return Ok(PullResult {
+                updated: false,
+                head_sha: old_sha,
+            });
+        }
 
-        run_git(&["reset", "--hard", &remote_ref], clone_dir)?;
+        if has_local_commits {
+            if let Err(e) = run_git(&["rebase", &remote_ref], clone_dir) {
+                let conflicts = conflicted_files(clone_dir);
+                let _ = run_git(&["rebase", "--abort"], clone_dir);
+                return Err(CoreError::Other(if conflicts.is_empty() {
+                    e.to_string()
+                } else {
+                    format!(
+                        "Pull stopped on conflicts with local changes: {}",
+                        conflicts.join(", ")
+                    )
+                }));
+            }
+        } else {
+            run_git(&["reset", "--hard", &remote_ref], clone_dir)?;
+        }
 
         let new_sha = run_git(&["rev-parse", "--short", "HEAD"], clone_dir)?;
 
@@ -121,11 +162,16 @@ impl CloneManager {
     }
 
     /// Stage, commit, and push changes in the specs directory.
+    ///
+    /// If the push fails the commit stays local and is added to the
+    /// pending-changes queue; see [`CloneManager::flush_pending`].
     pub fn commit_and_push(
         clone_dir: &Path,
         specs_path: &str,
         message: &str,
     ) -> CoreResult<PushResult> {
+        let lock = clone_lock(clone_dir);
+        let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
         // Stage all changes in the specs directory
         run_git(&["add", specs_path], clone_dir)?;
 
@@ -138,15 +184,65 @@ impl CloneManager {
         run_git(&["commit", "-m", message], clone_dir)?;
 
         let sha = run_git(&["rev-parse", "--short", "HEAD"], clone_dir)?;
+        let branch = current_branch(clone_dir)?;
+        let mut queue = PendingQueue::load(clone_dir)?;
+
+        match push_branch(clone_dir, &branch) {
+            Ok(()) => {
+                // Earlier queued commits on this branch went out too.
+                queue.remove_branch(&branch)?;
+                Ok(PushResult {
+                    commit_sha: sha,
+                    pushed: true,
+                    queued_error: None,
+                })
+            }
+            Err(e) => {
+                queue.enqueue(PendingChange {
+                    branch,
+                    commit_sha: sha.clone(),
+                    message: message.to_string(),
+                    queued_at: chrono::Utc::now(),
+                    attempts: 1,
+                    last_error: Some(e.to_string()),
+                })?;
+                Ok(PushResult {
+                    commit_sha: sha,
+                    pushed: false,
+                    queued_error: Some(e.to_string()),
+                })
+            }
+        }
+    }
+
+    /// Commits waiting in the pending-changes queue.
+    pub fn pending_changes(clone_dir: &Path) -> CoreResult<Vec<PendingChange>> {
+        Ok(PendingQueue::load(clone_dir)?.changes().to_vec())
+    }
 
-        run_git(&["push", "origin", "HEAD"], clone_dir)?;
+    /// Retry pushing every branch with queued commits.
+    pub fn flush_pending(clone_dir: &Path) -> CoreResult<FlushResult> {
+        let lock = clone_lock(clone_dir);
+        let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut queue = PendingQueue::load(clone_dir)?;
+        let mut pushed = Vec::new();
+
+        for branch in queue.branches() {
+            match push_branch(clone_dir, &branch) {
+                Ok(()) => pushed.extend(queue.remove_branch(&branch)?),
+                Err(e) => queue.record_failure(&branch, &e.to_string())?,
+            }
+        }
 
-        Ok(PushResult { commit_sha: sha })
+        Ok(FlushResult {
+            pushed,
+            remaining: queue.changes().to_vec(),
+        })
     }
 
     /// Get the status of the working tree.
     pub fn status(clone_dir: &Path) -> CoreResult<GitStatus> {
-        let branch = run_git(&["rev-parse", "--abbrev-ref", "HEAD"], clone_dir)?;
+        let branch = current_branch(clone_dir)?;
         let remote_url = run_git(&["remote", "get-url", "origin"], clone_dir)?;
         let porcelain = run_git(&["status", "--porcelain"], clone_dir)?;
 
@@ -156,11 +252,180 @@ impl CloneManager {
             .map(|l| l[3..].to_string()) // skip status columns "XY "
             .collect();
 
+        let remote_ref = format!("origin/{}", branch);
+        let (ahead, behind) = if ref_exists(clone_dir, &format!("refs/remotes/{}", remote_ref)) {
+            ahead_behind(clone_dir, &branch, &remote_ref)?
+        } else {
+            (0, 0)
+        };
+
         Ok(GitStatus {
             has_changes: !changed_files.is_empty(),
             changed_files,
             branch,
             remote_url,
+            ahead,
+            behind,
+            conflicted_files: conflicted_files(clone_dir),
+            pending_changes: PendingQueue::load(clone_dir)?.len(),
+        })
+    }
+
+    /// Name of the working branch for a spec.
+    pub fn spec_branch_name(spec_id: &str) -> String {
+        let slug: String = spec_id
+            .trim()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        format!("{}{}", SPEC_BRANCH_PREFIX, slug.trim_matches('-'))
+    }
+
+    /// Check out the working branch for `spec_id`, creating it from
+    /// `base` (preferring `origin/<base>`) if it doesn't exist yet.
+    pub fn checkout_spec_branch(
+        clone_dir: &Path,
+        spec_id: &str,
+        base: &str,
+    ) -> CoreResult<BranchInfo> {
+        let lock = clone_lock(clone_dir);
+        let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let name = Self::spec_branch_name(spec_id);
+        if name == SPEC_BRANCH_PREFIX {
+            return Err(CoreError::ValidationError(
+                "Spec id is required".to_string(),
+            ));
+        }
+
+        validate_branch_name(clone_dir, base)?;
+        if ref_exists(clone_dir, &format!("refs/heads/{}", name)) {
+            run_git(&["checkout", &name, "--"], clone_dir)?;
+        } else {
+            let remote_base = format!("origin/{}", base);
+            let start = if ref_exists(clone_dir, &format!("refs/remotes/{}", remote_base)) {
+                remote_base
+            } else {
+                base.to_string()
+            };
+            run_git(&["checkout", "-b", &name, &start, "--"], clone_dir)?;
+        }
+
+        Self::list_branches(clone_dir, base)?
+            .into_iter()
+            .find(|b| b.name == name)
+            .ok_or_else(|| CoreError::Other(format!("Branch '{}' not found", name)))
+    }
+
+    /// Switch to an existing local branch.
+    pub fn checkout_branch(clone_dir: &Path, branch: &str) -> CoreResult<()> {
+        let lock = clone_lock(clone_dir);
+        let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        checkout_existing(clone_dir, branch)
+    }
+
+    /// List local branches with ahead/behind counts.
+    ///
+    /// Each branch is compared to its own `origin/<name>` when it has been
+    /// pushed, otherwise to `origin/<base>`. Uses only local refs, so call
+    /// [`CloneManager::pull`] or [`CloneManager::integrate`] first for fresh
+    /// numbers.
+    pub fn list_branches(clone_dir: &Path, base: &str) -> CoreResult<Vec<BranchInfo>> {
+        let current = current_branch(clone_dir)?;
+        let names = run_git(
+            &["for-each-ref", "--format=%(refname:short)", "refs/heads"],
+            clone_dir,
+        )?;
+        let base_ref = format!("origin/{}", base);
+        let has_base = ref_exists(clone_dir, &format!("refs/remotes/{}", base_ref));
+
+        let mut branches = Vec::new();
+        for name in names.lines().filter(|l| !l.is_empty()) {
+            let own_ref = format!("origin/{}", name);
+            let compared_to = if ref_exists(clone_dir, &format!("refs/remotes/{}", own_ref)) {
+                Some(own_ref)
+            } else if has_base {
+                Some(base_ref.clone())
+            } else {
+                None
+            };
+            let (ahead, behind) = match &compared_to {
+                Some(target) => ahead_behind(clone_dir, name, target)?,
+                None => (0, 0),
+            };
+
+            branches.push(BranchInfo {
+                name: name.to_string(),
+                current: name == current,
+                compared_to,
+                ahead,
+                behind,
+                spec: name.strip_prefix(SPEC_BRANCH_PREFIX).map(str::to_string),
+            });
+        }
+
+        Ok(branches)
+    }
+
+    /// Rebase or merge `branch` onto `origin/<onto>`; `None` integrates the
+    /// current branch.
+    ///
+    /// On conflicts the operation is aborted so the clone stays usable, and
+    /// the conflicting files are reported grouped by spec.
+    pub fn integrate(
+        clone_dir: &Path,
+        specs_path: &str,
+        branch: Option<&str>,
+        onto: &str,
+        strategy: IntegrationStrategy,
+    ) -> CoreResult<IntegrationResult> {
+        let lock = clone_lock(clone_dir);
+        let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let branch = match branch {
+            Some(branch) => branch.to_string(),
+            None => current_branch(clone_dir)?,
+        };
+        validate_branch_name(clone_dir, onto)?;
+        ensure_full_history(clone_dir)?;
+        if !fetch_branch(clone_dir, onto, false)? {
+            return Err(CoreError::NotFound(format!("Remote branch '{}'", onto)));
+        }
+        checkout_existing(clone_dir, &branch)?;
+
+        let target = format!("origin/{}", onto);
+        let attempt = match strategy {
+            IntegrationStrategy::Rebase => run_git(&["rebase", &target], clone_dir),
+            IntegrationStrategy::Merge => run_git(&["merge", "--no-edit", &target], clone_dir),
+        };
+
+        let conflicts = match attempt {
+            Ok(_) => Vec::new(),
+            Err(e) => {
+                let files = conflicted_files(clone_dir);
+                let abort = match strategy {
+                    IntegrationStrategy::Rebase => "rebase",
+                    IntegrationStrategy::Merge => "merge",
+                };
+                let _ = run_git(&[abort, "--abort"], clone_dir);
+                if files.is_empty() {
+                    return Err(e);
+                }
+                group_conflicts(&files, specs_path)
+            }
+        };
+
+        Ok(IntegrationResult {
+            branch,
+            onto: onto.to_string(),
+            strategy,
+            success: conflicts.is_empty(),
+            head_sha: run_git(&["rev-parse", "--short", "HEAD"], clone_dir)?,
+            conflicts,
         })
     }
 
@@ -170,6 +435,144 @@ impl CloneManager {
     }
 }
 
+/// Lock serializing branch switches, commits and pushes on the clone in
+/// `dir`, so concurrent requests never commit onto a branch another one just
+/// checked out.
+fn clone_lock(dir: &Path) -> Arc<Mutex<()>> {
+    let key = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    CLONE_LOCKS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(key)
+        .or_default()
+        .clone()
+}
+
+/// Reject branch names git would read as options or that are not valid refs.
+fn validate_branch_name(dir: &Path, name: &str) -> CoreResult<()> {
+    if name.is_empty()
+        || name.starts_with('-')
+        || run_git(&["check-ref-format", "--branch", name], dir).is_err()
+    {
+        return Err(CoreError::ValidationError(format!(
+            "Invalid branch name: '{}'",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Switch to the existing local `branch`; callers hold the clone lock.
+fn checkout_existing(dir: &Path, branch: &str) -> CoreResult<()> {
+    validate_branch_name(dir, branch)?;
+    if !ref_exists(dir, &format!("refs/heads/{}", branch)) {
+        return Err(CoreError::NotFound(format!("Branch '{}'", branch)));
+    }
+    run_git(&["checkout", branch, "--"], dir)?;
+    Ok(())
+}
+
+fn current_branch(dir: &Path) -> CoreResult<String> {
+    run_git(&["rev-parse", "--abbrev-ref", "HEAD"], dir)
+}
+
+fn ref_exists(dir: &Path, full_ref: &str) -> bool {
+    run_git(&["rev-parse", "--verify", "--quiet", full_ref], dir).is_ok()
+}
+
+/// Commits on `branch` missing from `target`, and vice versa.
+fn ahead_behind(dir: &Path, branch: &str, target: &str) -> CoreResult<(usize, usize)> {
+    let range = format!("{}...{}", branch, target);
+    let counts = run_git(&["rev-list", "--left-right", "--count", &range], dir)?;
+    let mut parts = counts.split_whitespace().map(|n| n.parse().unwrap_or(0));
+    Ok((parts.next().unwrap_or(0), parts.next().unwrap_or(0)))
+}
+
+/// Fetch `branch` into `origin/<branch>`. Returns `false` when the remote
+/// has no such branch.
+///
+/// Uses an explicit refspec because shallow clones are single-branch.
+fn fetch_branch(dir: &Path, branch: &str, shallow: bool) -> CoreResult<bool> {
+    let refspec = format!("+refs/heads/{0}:refs/remotes/origin/{0}", branch);
+    let mut args = vec!["fetch"];
+    if shallow {
+        args.push("--depth=1");
+    }
+    args.extend(["origin", refspec.as_str()]);
+    match run_git(&args, dir) {
+        Ok(_) => Ok(true),
+        Err(e) if e.to_string().contains("couldn't find remote ref") => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Push `branch` and record it as `origin/<branch>`.
+fn push_branch(dir: &Path, branch: &str) -> CoreResult<()> {
+    let refspec = format!("refs/heads/{0}:refs/heads/{0}", branch);
+    run_git(&["push", "origin", &refspec], dir)?;
+    run_git(
+        &[
+            "update-ref",
+            &format!("refs/remotes/origin/{}", branch),
+            &format!("refs/heads/{}", branch),
+        ],
+        dir,
+    )?;
+    Ok(())
+}
+
+/// Rebase and merge need a common ancestor, which shallow clones may lack.
+fn ensure_full_history(dir: &Path) -> CoreResult<()> {
+    if run_git(&["rev-parse", "--is-shallow-repository"], dir)? == "true" {
+        run_git(&["fetch", "--unshallow", "origin"], dir)?;
+    }
+    Ok(())
+}
+
+fn conflicted_files(dir: &Path) -> Vec<String> {
+    run_git(&["diff", "--name-only", "--diff-filter=U"], dir)
+        .map(|out| {
+            out.lines()
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Group repo-relative file paths by the spec directory they live in.
+fn group_conflicts(files: &[String], specs_path: &str) -> Vec<SpecConflict> {
+    let mut grouped: BTreeMap<Option<String>, Vec<String>> = BTreeMap::new();
+    for file in files {
+        grouped
+            .entry(spec_for_path(file, specs_path))
+            .or_default()
+            .push(file.clone());
+    }
+    grouped
+        .into_iter()
+        .map(|(spec, files)| SpecConflict { spec, files })
+        .collect()
+}
+
+fn spec_for_path(file: &str, specs_path: &str) -> Option<String> {
+    let relative = Path::new(file).strip_prefix(specs_path).ok()?;
+    let mut parts = relative.components().filter_map(|c| match c {
+        Component::Normal(name) => name.to_str(),
+        _ => None,
+    });
+    let first = parts.next()?;
+    let spec = if first == "archived" {
+        parts.next()?
+    } else {
+        first
+    };
+    // Files directly inside the specs directory don't belong to a spec.
+    parts.next()?;
+    Some(spec.to_string())
+}
+
 /// Scan a cloned directory for spec directories.
 fn scan_specs_in_dir(
     dir: &Path,
@@ -260,3 +663,260 @@ fn extract_spec_metadata(
 
     (title, status, priority)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    struct Fixture {
+        _tmp: TempDir,
+        remote: PathBuf,
+        seed: PathBuf,
+        clone: PathBuf,
+    }
+
+    fn identify(dir: &Path) {
+        run_git(&["config", "user.email", "dev@example.com"], dir).unwrap();
+        run_git(&["config", "user.name", "Dev"], dir).unwrap();
+    }
+
+    fn write_spec(repo: &Path, spec: &str, body: &str) {
+        let dir = repo.join("specs").join(spec);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("README.md"), body).unwrap();
+    }
+
+    fn commit_all(repo: &Path, message: &str) {
+        run_git(&["add", "-A"], repo).unwrap();
+        run_git(&["commit", "-q", "-m", message], repo).unwrap();
+    }
+
+    /// Bare remote with a `main` branch, a seed working copy that pushes to
+    /// it, and a clone managed by `CloneManager`.
+    fn fixture() -> Option<Fixture> {
+        if !super::super::operations::git_available() {
+            return None;
+        }
+        let tmp = TempDir::new().unwrap();
+        let remote = tmp.path().join("remote.git");
+        let seed = tmp.path().join("seed");
+        let clone = tmp.path().join("clone");
+
+        run_git(&["init", "-q", "--bare", "remote.git"], tmp.path()).unwrap();
+        fs::create_dir_all(&seed).unwrap();
+        run_git(&["init", "-q"], &seed).unwrap();
+        identify(&seed);
+        write_spec(&seed, "001-alpha", "# Alpha\n\nline one\n");
+        write_spec(&seed, "002-beta", "# Beta\n\nline one\n");
+        commit_all(&seed, "initial");
+        run_git(&["branch", "-M", "main"], &seed).unwrap();
+        run_git(
+            &["remote", "add", "origin", remote.to_str().unwrap()],
+            &seed,
+        )
+        .unwrap();
+        run_git(&["push", "-q", "origin", "main"], &seed).unwrap();
+
+        CloneManager::clone_repo(&CloneConfig {
+            remote_url: remote.to_string_lossy().to_string(),
+            branch: Some("main".to_string()),
+            specs_path: Some("specs".to_string()),
+            clone_dir: clone.clone(),
+        })
+        .unwrap();
+        identify(&clone);
+
+        Some(Fixture {
+            _tmp: tmp,
+            remote,
+            seed,
+            clone,
+        })
+    }
+
+    #[test]
+    fn spec_branch_is_created_listed_and_pushed() {
+        let Some(fx) = fixture() else { return };
+
+        let info = CloneManager::checkout_spec_branch(&fx.clone, "001-alpha", "main").unwrap();
+        assert_eq!(info.name, "spec/001-alpha");
+        assert_eq!(info.spec.as_deref(), Some("001-alpha"));
+        assert!(info.current);
+        assert_eq!(info.compared_to.as_deref(), Some("origin/main"));
+
+        write_spec(&fx.clone, "001-alpha", "# Alpha\n\nedited\n");
+        let result = CloneManager::commit_and_push(&fx.clone, "specs", "edit alpha").unwrap();
+        assert!(result.pushed);
+        assert!(run_git(
+            &["rev-parse", "--verify", "refs/heads/spec/001-alpha"],
+            &fx.remote
+        )
+        .is_ok());
+
+        let branches = CloneManager::list_branches(&fx.clone, "main").unwrap();
+        let spec_branch = branches
+            .iter()
+            .find(|b| b.name == "spec/001-alpha")
+            .unwrap();
+        assert_eq!(
+            spec_branch.compared_to.as_deref(),
+            Some("origin/spec/001-alpha")
+        );
+        assert_eq!((spec_branch.ahead, spec_branch.behind), (0, 0));
+        let main = branches.iter().find(|b| b.name == "main").unwrap();
+        assert!(!main.current);
+        assert!(main.spec.is_none());
+
+        // Re-checking out an existing spec branch keeps its commits
+        CloneManager::checkout_branch(&fx.clone, "main").unwrap();
+        CloneManager::checkout_spec_branch(&fx.clone, "001-alpha", "main").unwrap();
+        let content = fs::read_to_string(fx.clone.join("specs/001-alpha/README.md")).unwrap();
+        assert!(content.contains("edited"));
+    }
+
+    #[test]
+    fn branch_names_that_look_like_options_are_rejected() {
+        let Some(fx) = fixture() else { return };
+        for base in ["--orphan", "-f", "main..x", ""] {
+            let err = CloneManager::checkout_spec_branch(&fx.clone, "001-alpha", base);
+            assert!(matches!(err, Err(CoreError::ValidationError(_))), "{base}");
+        }
+        assert!(matches!(
+            CloneManager::checkout_branch(&fx.clone, "--detach"),
+            Err(CoreError::ValidationError(_))
+        ));
+        assert_eq!(CloneManager::status(&fx.clone).unwrap().branch, "main");
+    }
+
+    #[test]
+    fn failed_pushes_are_queued_and_flushed() {
+        let Some(fx) = fixture() else { return };
+        let offline = fx.remote.with_extension("offline");
+        fs::rename(&fx.remote, &offline).unwrap();
+
+        write_spec(&fx.clone, "002-beta", "# Beta\n\nwritten offline\n");
+        let result = CloneManager::commit_and_push(&fx.clone, "specs", "offline edit").unwrap();
+        assert!(!result.pushed);
+        assert!(result.queued_error.is_some());
+
+        // The queue is persisted, so a fresh load (e.g. after restart) sees it
+        let pending = CloneManager::pending_changes(&fx.clone).unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].branch, "main");
+        assert_eq!(pending[0].message, "offline edit");
+
+        let status = CloneManager::status(&fx.clone).unwrap();
+        assert_eq!(status.pending_changes, 1);
+        assert_eq!(status.ahead, 1);
+
+        let still_offline = CloneManager::flush_pending(&fx.clone).unwrap();
+        assert!(still_offline.pushed.is_empty());
+        assert_eq!(still_offline.remaining[0].attempts, 2);
+
+        fs::rename(&offline, &fx.remote).unwrap();
+        let flushed = CloneManager::flush_pending(&fx.clone).unwrap();
+        assert_eq!(flushed.pushed.len(), 1);
+        assert!(flushed.remaining.is_empty());
+        assert_eq!(CloneManager::status(&fx.clone).unwrap().ahead, 0);
+    }
+
+    #[test]
+    fn pull_rebases_instead_of_discarding_local_commits() {
+        let Some(fx) = fixture() else { return };
+        let offline = fx.remote.with_extension("offline");
+        fs::rename(&fx.remote, &offline).unwrap();
+        write_spec(&fx.clone, "002-beta", "# Beta\n\nlocal\n");
+        CloneManager::commit_and_push(&fx.clone, "specs", "local edit").unwrap();
+        fs::rename(&offline, &fx.remote).unwrap();
+
+        write_spec(&fx.seed, "001-alpha", "# Alpha\n\nremote\n");
+        commit_all(&fx.seed, "remote edit");
+        run_git(&["push", "-q", "origin", "main"], &fx.seed).unwrap();
+
+        let result = CloneManager::pull(&fx.clone).unwrap();
+        assert!(result.updated);
+        let beta = fs::read_to_string(fx.clone.join("specs/002-beta/README.md")).unwrap();
+        let alpha = fs::read_to_string(fx.clone.join("specs/001-alpha/README.md")).unwrap();
+        assert!(beta.contains("local"));
+        assert!(alpha.contains("remote"));
+    }
+
+    #[test]
+    fn integrate_reports_conflicts_per_spec() {
+        let Some(fx) = fixture() else { return };
+        CloneManager::checkout_spec_branch(&fx.clone, "001-alpha", "main").unwrap();
+        write_spec(&fx.clone, "001-alpha", "# Alpha\n\nbranch version\n");
+        commit_all(&fx.clone, "branch edit");
+
+        write_spec(&fx.seed, "001-alpha", "# Alpha\n\nmain version\n");
+        commit_all(&fx.seed, "main edit");
+        run_git(&["push", "-q", "origin", "main"], &fx.seed).unwrap();
+
+        let result = CloneManager::integrate(
+            &fx.clone,
+            "specs",
+            Some("spec/001-alpha"),
+            "main",
+            IntegrationStrategy::Rebase,
+        )
+        .unwrap();
+        assert!(!result.success);
+        assert_eq!(
+            result.conflicts,
+            vec![SpecConflict {
+                spec: Some("001-alpha".to_string()),
+                files: vec!["specs/001-alpha/README.md".to_string()],
+            }]
+        );
+        // The rebase was aborted, leaving a clean tree on the spec branch
+        let status = CloneManager::status(&fx.clone).unwrap();
+        assert_eq!(status.branch, "spec/001-alpha");
+        assert!(status.conflicted_files.is_empty());
+    }
+
+    #[test]
+    fn integrate_merges_non_conflicting_changes() {
+        let Some(fx) = fixture() else { return };
+        CloneManager::checkout_spec_branch(&fx.clone, "001-alpha", "main").unwrap();
+        write_spec(&fx.clone, "001-alpha", "# Alpha\n\nbranch version\n");
+        commit_all(&fx.clone, "branch edit");
+
+        write_spec(&fx.seed, "002-beta", "# Beta\n\nmain version\n");
+        commit_all(&fx.seed, "main edit");
+        run_git(&["push", "-q", "origin", "main"], &fx.seed).unwrap();
+
+        let result = CloneManager::integrate(
+            &fx.clone,
+            "specs",
+            Some("spec/001-alpha"),
+            "main",
+            IntegrationStrategy::Merge,
+        )
+        .unwrap();
+        assert!(result.success);
+        assert!(result.conflicts.is_empty());
+        let beta = fs::read_to_string(fx.clone.join("specs/002-beta/README.md")).unwrap();
+        assert!(beta.contains("main version"));
+    }
+
+    #[test]
+    fn spec_for_path_handles_archived_and_top_level_files() {
+        assert_eq!(
+            spec_for_path("specs/001-alpha/README.md", "specs").as_deref(),
+            Some("001-alpha")
+        );
+        assert_eq!(
+            spec_for_path("specs/archived/002-old/DESIGN.md", "specs").as_deref(),
+            Some("002-old")
+        );
+        assert_eq!(spec_for_path("specs/README.md", "specs"), None);
+        assert_eq!(spec_for_path("src/main.rs", "specs"), None);
+        assert_eq!(
+            CloneManager::spec_branch_name("042 search/api"),
+            "spec/042-search-api"
+        );
+    }
+}
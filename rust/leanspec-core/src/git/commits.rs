@@ -0,0 +1,249 @@
+//! Commit-to-spec linking
+//!
+//! Commits reference the specs they implement with a `spec:` trailer in the
+//! commit message, e.g. `spec: 042` or `Specs: 042, 043-search-api`. This
+//! module scans `git log` for those trailers so spec views can list the
+//! implementing commits. As with `git interpret-trailers`, only the last
+//! paragraph of the message counts, and only when it is made of trailers.
+
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::operations::run_git;
+use crate::error::CoreResult;
+
+/// Trailer line: `spec: 042`, `Specs: 042, 043-search-api`.
+static SPEC_TRAILER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^specs?[ \t]*:[ \t]*(.+?)[ \t]*$").unwrap());
+
+/// Any `Key: value` trailer line, e.g. `Signed-off-by: Dev <dev@example.com>`.
+static TRAILER_LINE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Za-z0-9][A-Za-z0-9-]*[ \t]*:[ \t]*\S").unwrap());
+
+/// Field separator used in the `git log` format string.
+const FIELD_SEP: char = '\u{1f}';
+/// Record separator used in the `git log` format string.
+const RECORD_SEP: char = '\u{1e}';
+
+/// A commit that references one or more specs via trailers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecCommit {
+    pub sha: String,
+    pub short_sha: String,
+    pub author: String,
+    /// Author date, ISO 8601
+    pub date: String,
+    pub subject: String,
+    /// Spec references exactly as written in the trailers
+    pub specs: Vec<String>,
+}
+
+impl SpecCommit {
+    /// Whether any of this commit's trailers refer to `spec_path`.
+    pub fn references(&self, spec_path: &str) -> bool {
+        self.specs.iter().any(|r| spec_ref_matches(r, spec_path))
+    }
+}
+
+/// Extract spec references from `spec:` trailers in a commit message.
+///
+/// Values may be comma- or space-separated and may carry a leading `#`.
+/// Lines in the subject or body that merely start with `spec:` are not
+/// trailers and are ignored.
+pub fn parse_spec_trailers(message: &str) -> Vec<String> {
+    let mut refs = Vec::new();
+    for caps in trailer_block(message)
+        .into_iter()
+        .filter_map(|line| SPEC_TRAILER.captures(line))
+    {
+        for value in caps[1].split(|c: char| c == ',' || c.is_whitespace()) {
+            let value = value.trim().trim_start_matches('#');
+            if !value.is_empty() && !refs.iter().any(|r| r == value) {
+                refs.push(value.to_string());
+            }
+        }
+    }
+    refs
+}
+
+/// Lines of the trailer block: the last paragraph of `message`, if it is not
+/// the subject and every line is a `Key: value` trailer or a continuation.
+fn trailer_block(message: &str) -> Vec<&str> {
+    let mut paragraphs: Vec<Vec<&str>> = Vec::new();
+    let mut current = Vec::new();
+    for line in message.lines().map(str::trim_end) {
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    if paragraphs.len() < 2 {
+        return Vec::new();
+    }
+    let block = paragraphs.pop().unwrap_or_default();
+    let is_trailers = block
+        .iter()
+        .enumerate()
+        .all(|(i, line)| TRAILER_LINE.is_match(line) || (i > 0 && line.starts_with([' ', '\t'])));
+    if is_trailers {
+        block
+    } else {
+        Vec::new()
+    }
+}
+
+/// Whether a trailer reference such as `42`, `042` or `042-search-api`
+/// points at `spec_path`.
+pub fn spec_ref_matches(reference: &str, spec_path: &str) -> bool {
+    if reference == spec_path {
+        return true;
+    }
+    let spec_number = spec_path
+        .split('-')
+        .next()
+        .and_then(|n| n.parse::<u32>().ok());
+    match (reference.parse::<u32>().ok(), spec_number) {
+        (Some(r), Some(n)) => r == n,
+        _ => false,
+    }
+}
+
+/// Scan the most recent `limit` commits carrying a `spec:` trailer.
+pub fn scan_spec_commits(repo: &Path, limit: usize) -> CoreResult<Vec<SpecCommit>> {
+    let max_count = format!("--max-count={}", limit);
+    let format = format!(
+        "--format=%H{sep}%h{sep}%an{sep}%aI{sep}%s{sep}%B{rec}",
+        sep = "%x1f",
+        rec = "%x1e"
+    );
+    let output = run_git(
+        &[
+            "log",
+            &max_count,
+            "--regexp-ignore-case",
+            "--extended-regexp",
+            "--grep=^[[:space:]]*specs?[[:space:]]*:",
+            &format,
+        ],
+        repo,
+    )?;
+
+    Ok(output
+        .split(RECORD_SEP)
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').splitn(6, FIELD_SEP);
+            let sha = fields.next()?.trim();
+            if sha.is_empty() {
+                return None;
+            }
+            let short_sha = fields.next()?;
+            let author = fields.next()?;
+            let date = fields.next()?;
+            let subject = fields.next()?;
+            let body = fields.next().unwrap_or("");
+            let specs = parse_spec_trailers(body);
+            (!specs.is_empty()).then(|| SpecCommit {
+                sha: sha.to_string(),
+                short_sha: short_sha.to_string(),
+                author: author.to_string(),
+                date: date.to_string(),
+                subject: subject.to_string(),
+                specs,
+            })
+        })
+        .collect())
+}
+
+/// Commits from `scan_spec_commits` that reference `spec_path`, newest first.
+pub fn commits_for_spec(repo: &Path, spec_path: &str, limit: usize) -> Vec<SpecCommit> {
+    scan_spec_commits(repo, limit)
+        .map(|commits| {
+            commits
+                .into_iter()
+                .filter(|c| c.references(spec_path))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_trailers() {
+        let message = "feat: add search\n\nLonger body.\n\nspec: 042\nSpecs: 043-search-api, #44\n";
+        assert_eq!(
+            parse_spec_trailers(message),
+            vec!["042", "043-search-api", "44"]
+        );
+        assert!(parse_spec_trailers("fix: nothing to see\n\nno trailer").is_empty());
+    }
+
+    #[test]
+    fn ignores_spec_lines_outside_the_trailer_block() {
+        assert!(parse_spec_trailers("Spec: add auth flow\n\nWires up login.\n").is_empty());
+        assert!(parse_spec_trailers("Spec: add auth flow").is_empty());
+        assert!(parse_spec_trailers("feat: x\n\nspec: 042 is next\nmore prose\n").is_empty());
+        assert_eq!(
+            parse_spec_trailers(
+                "Spec: add auth flow\n\nspecs: follow-up\n\nspec: 042\nSigned-off-by: Dev <dev@example.com>\n"
+            ),
+            vec!["042"]
+        );
+    }
+
+    #[test]
+    fn matches_references_by_number_or_path() {
+        assert!(spec_ref_matches("042", "042-search"));
+        assert!(spec_ref_matches("42", "042-search"));
+        assert!(spec_ref_matches("042-search", "042-search"));
+        assert!(!spec_ref_matches("043", "042-search"));
+        assert!(!spec_ref_matches("search", "042-search"));
+    }
+
+    #[test]
+    fn scans_git_log_for_trailers() {
+        if !super::super::operations::git_available() {
+            return;
+        }
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path();
+        run_git(&["init", "-q"], repo).unwrap();
+        run_git(&["config", "user.email", "dev@example.com"], repo).unwrap();
+        run_git(&["config", "user.name", "Dev"], repo).unwrap();
+
+        let commit = |file: &str, message: &str| {
+            fs::write(repo.join(file), file).unwrap();
+            run_git(&["add", "."], repo).unwrap();
+            run_git(&["commit", "-q", "-m", message], repo).unwrap();
+        };
+        commit("a", "feat: first\n\nspec: 001");
+        commit("b", "chore: unrelated");
+        commit("c", "fix: second\n\nspec: 002-other, 001");
+
+        let commits = scan_spec_commits(repo, 100).unwrap();
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].subject, "fix: second");
+        assert_eq!(commits[0].specs, vec!["002-other", "001"]);
+        assert_eq!(commits[0].author, "Dev");
+
+        let linked = commits_for_spec(repo, "001-first", 100);
+        assert_eq!(linked.len(), 2);
+        assert_eq!(commits_for_spec(repo, "002-other", 100).len(), 1);
+        assert!(commits_for_spec(repo, "003-none", 100).is_empty());
+    }
+}
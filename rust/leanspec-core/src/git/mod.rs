@@ -1,12 +1,20 @@
 //! Git integration for LeanSpec
 //!
-//! Clone, pull, and push specs from any Git remote.
+//! Clone, pull, and push specs from any Git remote, work on per-spec
+//! branches with an offline pending-changes queue, and link commits to the
+//! specs they implement via `spec:` trailers.
 //! Uses the system `git` binary — supports any host (GitHub, GitLab, Gitea, SSH, etc.)
 //! and delegates authentication to the user's existing Git credentials.
 
 pub mod clone_manager;
+pub mod commits;
 pub mod operations;
+pub mod pending;
 pub mod types;
 
-pub use clone_manager::CloneManager;
+pub use clone_manager::{CloneManager, SPEC_BRANCH_PREFIX};
+pub use commits::{
+    commits_for_spec, parse_spec_trailers, scan_spec_commits, spec_ref_matches, SpecCommit,
+};
+pub use pending::PendingQueue;
 pub use types::*;
@@ -72,6 +72,21 @@ pub fn run_git_in(args: &[&str], cwd: &Path) -> CoreResult<String> {
     }
 }
 
+/// Contents of `file` as of revision `rev`, or `None` when the file did not
+/// exist there. Fails when `rev` is not a commit of the repository holding
+/// `file`.
+pub fn file_at_revision(file: &Path, rev: &str) -> CoreResult<Option<String>> {
+    let dir = file.parent().unwrap_or(Path::new("."));
+    let name = file
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| CoreError::Other(format!("Invalid path: {}", file.display())))?;
+    let commit = format!("{}^{{commit}}", rev);
+    run_git(&["rev-parse", "--verify", "--quiet", &commit], dir)
+        .map_err(|e| CoreError::Other(format!("Cannot resolve revision {}: {}", rev, e)))?;
+    Ok(run_git(&["show", &format!("{}:./{}", rev, name)], dir).ok())
+}
+
 /// Check if `git` is available on PATH.
 pub fn git_available() -> bool {
     Command::new("git")
@@ -0,0 +1,98 @@
+//! Pending-changes queue
+//!
+//! Commits whose push failed (offline, auth expired, remote rejected) are
+//! recorded here and retried later. The queue lives inside the clone's git
+//! directory so it survives server restarts without ever being committed.
+
+use crate::error::{CoreError, CoreResult};
+
+use super::operations::run_git;
+use super::types::PendingChange;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const QUEUE_FILE: &str = "leanspec-pending.json";
+
+/// Persistent queue of unpushed commits for one clone.
+#[derive(Debug)]
+pub struct PendingQueue {
+    path: PathBuf,
+    changes: Vec<PendingChange>,
+}
+
+impl PendingQueue {
+    /// Load the queue for `clone_dir`, starting empty if none was saved.
+    pub fn load(clone_dir: &Path) -> CoreResult<Self> {
+        let git_dir = PathBuf::from(run_git(&["rev-parse", "--git-dir"], clone_dir)?);
+        let git_dir = if git_dir.is_absolute() {
+            git_dir
+        } else {
+            clone_dir.join(git_dir)
+        };
+        let path = git_dir.join(QUEUE_FILE);
+
+        let changes = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| CoreError::Other(format!("Corrupt pending-changes queue: {}", e)))?,
+            Err(_) => Vec::new(),
+        };
+
+        Ok(Self { path, changes })
+    }
+
+    pub fn changes(&self) -> &[PendingChange] {
+        &self.changes
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Branches with queued commits, in queue order.
+    pub fn branches(&self) -> Vec<String> {
+        let mut branches: Vec<String> = Vec::new();
+        for change in &self.changes {
+            if !branches.contains(&change.branch) {
+                branches.push(change.branch.clone());
+            }
+        }
+        branches
+    }
+
+    /// Append a change and persist the queue.
+    pub fn enqueue(&mut self, change: PendingChange) -> CoreResult<()> {
+        self.changes.push(change);
+        self.save()
+    }
+
+    /// Drop every change queued for `branch` and persist the queue.
+    pub fn remove_branch(&mut self, branch: &str) -> CoreResult<Vec<PendingChange>> {
+        let (removed, kept) = std::mem::take(&mut self.changes)
+            .into_iter()
+            .partition(|c| c.branch == branch);
+        self.changes = kept;
+        self.save()?;
+        Ok(removed)
+    }
+
+    /// Record a failed retry for every change queued for `branch`.
+    pub fn record_failure(&mut self, branch: &str, error: &str) -> CoreResult<()> {
+        for change in self.changes.iter_mut().filter(|c| c.branch == branch) {
+            change.attempts += 1;
+            change.last_error = Some(error.to_string());
+        }
+        self.save()
+    }
+
+    fn save(&self) -> CoreResult<()> {
+        let json = serde_json::to_string_pretty(&self.changes)
+            .map_err(|e| CoreError::Other(format!("Failed to serialize queue: {}", e)))?;
+        fs::write(&self.path, json)
+            .map_err(|e| CoreError::Other(format!("Failed to write pending queue: {}", e)))
+    }
+}
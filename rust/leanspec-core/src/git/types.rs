@@ -123,8 +123,12 @@ pub struct PullResult {
 /// Result of a push operation.
 #[derive(Debug, Clone)]
 pub struct PushResult {
-    /// Commit SHA that was pushed
+    /// Commit SHA that was created
     pub commit_sha: String,
+    /// Whether the push reached the remote
+    pub pushed: bool,
+    /// Set when the push failed and the commit was queued for retry
+    pub queued_error: Option<String>,
 }
 
 /// Status of a git working tree.
@@ -135,6 +139,99 @@ pub struct GitStatus {
     pub changed_files: Vec<String>,
     pub branch: String,
     pub remote_url: String,
+    /// Commits on `branch` not yet on its remote counterpart
+    #[serde(default)]
+    pub ahead: usize,
+    /// Commits on the remote counterpart not yet on `branch`
+    #[serde(default)]
+    pub behind: usize,
+    /// Files with unresolved merge conflicts
+    #[serde(default)]
+    pub conflicted_files: Vec<String>,
+    /// Commits waiting in the pending-changes queue
+    #[serde(default)]
+    pub pending_changes: usize,
+}
+
+/// A local branch with its position relative to the remote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchInfo {
+    pub name: String,
+    pub current: bool,
+    /// Remote ref used for ahead/behind (own upstream, else the base branch)
+    pub compared_to: Option<String>,
+    pub ahead: usize,
+    pub behind: usize,
+    /// Spec id for `spec/<id>` working branches
+    pub spec: Option<String>,
+}
+
+/// How to bring a branch up to date with another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum IntegrationStrategy {
+    #[default]
+    Rebase,
+    Merge,
+}
+
+impl std::str::FromStr for IntegrationStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "rebase" => Ok(Self::Rebase),
+            "merge" => Ok(Self::Merge),
+            other => Err(format!(
+                "Unknown integration strategy '{}'. Use rebase or merge.",
+                other
+            )),
+        }
+    }
+}
+
+/// Conflicting files grouped by the spec they belong to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecConflict {
+    /// Spec id, or `None` for files outside the specs directory
+    pub spec: Option<String>,
+    pub files: Vec<String>,
+}
+
+/// Outcome of a rebase or merge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationResult {
+    pub branch: String,
+    pub onto: String,
+    pub strategy: IntegrationStrategy,
+    /// `false` when conflicts stopped the operation; the branch is left as
+    /// it was before the attempt.
+    pub success: bool,
+    pub head_sha: String,
+    pub conflicts: Vec<SpecConflict>,
+}
+
+/// A local commit whose push failed and is waiting to be retried.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingChange {
+    pub branch: String,
+    pub commit_sha: String,
+    pub message: String,
+    pub queued_at: chrono::DateTime<chrono::Utc>,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+}
+
+/// Outcome of retrying queued pushes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlushResult {
+    pub pushed: Vec<PendingChange>,
+    pub remaining: Vec<PendingChange>,
 }
 
 #[cfg(test)]
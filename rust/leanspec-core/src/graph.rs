@@ -0,0 +1,592 @@
+//! Relationship graph over [`SpecDoc`] links
+//!
+//! [`SpecGraph`] indexes the links of a document set by the link types an
+//! adapter declares in [`SpecSchema::link_types`](crate::SpecSchema). A link
+//! can be stored on either end: `parent` on the child and `child` on the
+//! parent describe the same edge, because each type's `inverse_key` names it
+//! from the other side. Every edge is kept once under its canonical type —
+//! the first declared of an inverse pair — and can be queried under either
+//! key.
+//!
+//! Directed types support upstream/downstream traversal, cycle detection and
+//! topological ordering. Types without an `inverse_key` are undirected: their
+//! edges read the same from both ends and never form cycles. Link keys found
+//! on documents but not declared are treated as directed types without an
+//! inverse. Links to documents outside the set, and links from a document to
+//! itself, are ignored.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::model::{LinkTypeDef, SpecDoc};
+
+/// One canonical link type and its adjacency lists (document indices).
+struct EdgeType {
+    key: String,
+    label: String,
+    inverse_key: Option<String>,
+    inverse_label: Option<String>,
+    directed: bool,
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl EdgeType {
+    fn new(def: &LinkTypeDef, nodes: usize) -> Self {
+        let inverse_key = def.inverse_key.clone().filter(|k| *k != def.key);
+        Self {
+            key: def.key.clone(),
+            label: def.label.clone(),
+            directed: inverse_key.is_some(),
+            inverse_label: inverse_key.as_ref().and(def.inverse_label.clone()),
+            inverse_key,
+            outgoing: vec![Vec::new(); nodes],
+            incoming: vec![Vec::new(); nodes],
+        }
+    }
+
+    /// Neighbours of `node`, following edges backwards when `reversed`.
+    fn neighbours(&self, node: usize, reversed: bool) -> &[usize] {
+        if reversed {
+            &self.incoming[node]
+        } else {
+            &self.outgoing[node]
+        }
+    }
+}
+
+/// A relationship of one document, as seen from that document.
+#[derive(Debug, Clone)]
+pub struct GraphLink<'a> {
+    /// Link key from this document's side: the type key for outgoing edges,
+    /// the inverse key for incoming ones.
+    pub key: &'a str,
+    pub label: &'a str,
+    /// Canonical type the edge is stored under.
+    pub link_type: &'a str,
+    /// Whether this document is the edge's source under [`Self::link_type`].
+    /// Always `true` for undirected types.
+    pub outgoing: bool,
+    pub target: &'a SpecDoc,
+}
+
+/// A document reached by a traversal, with its distance from the start.
+#[derive(Debug, Clone)]
+pub struct Reached<'a> {
+    pub doc: &'a SpecDoc,
+    pub depth: usize,
+}
+
+/// Everything a document reaches along a link type, in both directions.
+#[derive(Debug, Clone)]
+pub struct Impact<'a> {
+    pub doc: &'a SpecDoc,
+    /// Reached by following the link key (what the document depends on,
+    /// for `depends_on`).
+    pub upstream: Vec<Reached<'a>>,
+    /// Reached by following it backwards (what depends on the document).
+    pub downstream: Vec<Reached<'a>>,
+}
+
+/// Typed relationship graph over a set of documents.
+pub struct SpecGraph {
+    docs: Vec<SpecDoc>,
+    index: HashMap<String, usize>,
+    types: Vec<EdgeType>,
+    /// Link key → canonical type index, and whether the key names the edge
+    /// from its target's side.
+    keys: HashMap<String, (usize, bool)>,
+}
+
+impl SpecGraph {
+    /// Build the graph for `docs` using the declared `link_types`.
+    pub fn new(docs: Vec<SpecDoc>, link_types: &[LinkTypeDef]) -> Self {
+        let index: HashMap<String, usize> = docs
+            .iter()
+            .enumerate()
+            .map(|(i, doc)| (doc.id.clone(), i))
+            .collect();
+        let mut graph = Self {
+            types: Vec::new(),
+            keys: HashMap::new(),
+            index,
+            docs,
+        };
+        for def in link_types {
+            graph.declare(def);
+        }
+
+        for from in 0..graph.docs.len() {
+            for link in graph.docs[from].links.clone() {
+                let Some(&to) = graph.index.get(&link.target_id) else {
+                    continue;
+                };
+                if to == from {
+                    continue;
+                }
+                let (t, reversed) = match graph.keys.get(&link.link_type) {
+                    Some(&resolved) => resolved,
+                    None => {
+                        let resolved = graph.declare(&LinkTypeDef {
+                            key: link.link_type.clone(),
+                            label: link.link_type.clone(),
+                            inverse_key: None,
+                            inverse_label: None,
+                        });
+                        graph.types[resolved.0].directed = true;
+                        resolved
+                    }
+                };
+                let (source, target) = if reversed { (to, from) } else { (from, to) };
+                let edge_type = &mut graph.types[t];
+                edge_type.outgoing[source].push(target);
+                edge_type.incoming[target].push(source);
+                if !edge_type.directed {
+                    edge_type.outgoing[target].push(source);
+                    edge_type.incoming[source].push(target);
+                }
+            }
+        }
+
+        for edge_type in &mut graph.types {
+            for list in edge_type
+                .outgoing
+                .iter_mut()
+                .chain(edge_type.incoming.iter_mut())
+            {
+                list.sort_unstable();
+                list.dedup();
+            }
+        }
+        graph
+    }
+
+    /// Register `def` unless its key is already known (possibly as the
+    /// inverse of an earlier type). Returns how the key resolves.
+    fn declare(&mut self, def: &LinkTypeDef) -> (usize, bool) {
+        if let Some(&resolved) = self.keys.get(&def.key) {
+            return resolved;
+        }
+        let t = self.types.len();
+        let edge_type = EdgeType::new(def, self.docs.len());
+        self.keys.insert(def.key.clone(), (t, false));
+        if let Some(inverse) = &edge_type.inverse_key {
+            self.keys.entry(inverse.clone()).or_insert((t, true));
+        }
+        self.types.push(edge_type);
+        (t, false)
+    }
+
+    fn resolve(&self, key: &str) -> Option<(&EdgeType, bool)> {
+        self.keys
+            .get(key)
+            .map(|&(t, reversed)| (&self.types[t], reversed))
+    }
+
+    /// All documents, in the order given to [`Self::new`].
+    pub fn docs(&self) -> &[SpecDoc] {
+        &self.docs
+    }
+
+    pub fn get(&self, id: &str) -> Option<&SpecDoc> {
+        self.index.get(id).map(|&i| &self.docs[i])
+    }
+
+    /// Canonical link types: the declared ones that are not the inverse of
+    /// an earlier type, then undeclared keys found on documents.
+    pub fn link_types(&self) -> Vec<LinkTypeDef> {
+        self.types
+            .iter()
+            .map(|t| LinkTypeDef {
+                key: t.key.clone(),
+                label: t.label.clone(),
+                inverse_key: t.inverse_key.clone(),
+                inverse_label: t.inverse_label.clone(),
+            })
+            .collect()
+    }
+
+    /// Whether `key` names a directed link type (either side of it).
+    pub fn is_directed(&self, key: &str) -> bool {
+        self.resolve(key).is_some_and(|(t, _)| t.directed)
+    }
+
+    /// Documents directly linked from `id` under `key`, as seen from `id`:
+    /// `related(a, "depends_on")` are a's dependencies and
+    /// `related(b, "blocked_by")` the documents depending on b.
+    pub fn related(&self, id: &str, key: &str) -> Vec<&SpecDoc> {
+        let (Some(&node), Some((edge_type, reversed))) = (self.index.get(id), self.resolve(key))
+        else {
+            return Vec::new();
+        };
+        edge_type
+            .neighbours(node, reversed)
+            .iter()
+            .map(|&i| &self.docs[i])
+            .collect()
+    }
+
+    /// Every relationship of `id`, in both directions and across all types.
+    pub fn links(&self, id: &str) -> Vec<GraphLink<'_>> {
+        let Some(&node) = self.index.get(id) else {
+            return Vec::new();
+        };
+        let mut out = Vec::new();
+        for edge_type in &self.types {
+            for &target in &edge_type.outgoing[node] {
+                out.push(GraphLink {
+                    key: &edge_type.key,
+                    label: &edge_type.label,
+                    link_type: &edge_type.key,
+                    outgoing: true,
+                    target: &self.docs[target],
+                });
+            }
+            if !edge_type.directed {
+                continue;
+            }
+            for &source in &edge_type.incoming[node] {
+                out.push(GraphLink {
+                    key: edge_type.inverse_key.as_deref().unwrap_or(&edge_type.key),
+                    label: edge_type
+                        .inverse_label
+                        .as_deref()
+                        .unwrap_or(&edge_type.label),
+                    link_type: &edge_type.key,
+                    outgoing: false,
+                    target: &self.docs[source],
+                });
+            }
+        }
+        out
+    }
+
+    /// Edges of `key` as `(from, to)` pairs in that key's direction. For an
+    /// undirected type each edge appears once.
+    pub fn edges(&self, key: &str) -> Vec<(&SpecDoc, &SpecDoc)> {
+        let Some((edge_type, reversed)) = self.resolve(key) else {
+            return Vec::new();
+        };
+        let mut out = Vec::new();
+        for (from, targets) in edge_type.outgoing.iter().enumerate() {
+            for &to in targets {
+                if !edge_type.directed && to < from {
+                    continue;
+                }
+                let (a, b) = if reversed { (to, from) } else { (from, to) };
+                out.push((&self.docs[a], &self.docs[b]));
+            }
+        }
+        out
+    }
+
+    /// Documents reachable from `id` by following `key` up to `max_depth`
+    /// hops, nearest first. The start document is never included.
+    pub fn reachable(&self, id: &str, key: &str, max_depth: usize) -> Vec<Reached<'_>> {
+        match (self.index.get(id), self.resolve(key)) {
+            (Some(&node), Some((edge_type, reversed))) => {
+                self.walk(node, edge_type, reversed, max_depth)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn walk(
+        &self,
+        start: usize,
+        edge_type: &EdgeType,
+        reversed: bool,
+        max_depth: usize,
+    ) -> Vec<Reached<'_>> {
+        let mut seen = HashSet::from([start]);
+        let mut frontier = vec![start];
+        let mut out = Vec::new();
+        for depth in 1..=max_depth {
+            let mut next = Vec::new();
+            for node in frontier {
+                for &neighbour in edge_type.neighbours(node, reversed) {
+                    if seen.insert(neighbour) {
+                        out.push(Reached {
+                            doc: &self.docs[neighbour],
+                            depth,
+                        });
+                        next.push(neighbour);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        out
+    }
+
+    /// What `id` reaches along `key` and what reaches it, up to `max_depth`
+    /// hops each way. For undirected types both lists are the same.
+    pub fn impact(&self, id: &str, key: &str, max_depth: usize) -> Option<Impact<'_>> {
+        let node = *self.index.get(id)?;
+        let (edge_type, reversed) = self.resolve(key)?;
+        Some(Impact {
+            doc: &self.docs[node],
+            upstream: self.walk(node, edge_type, reversed, max_depth),
+            downstream: self.walk(node, edge_type, reversed != edge_type.directed, max_depth),
+        })
+    }
+
+    /// Cycles among the edges of `key`, each as the ids along the cycle.
+    /// Undirected types have none.
+    pub fn cycles(&self, key: &str) -> Vec<Vec<String>> {
+        let Some((edge_type, reversed)) = self.resolve(key) else {
+            return Vec::new();
+        };
+        if !edge_type.directed {
+            return Vec::new();
+        }
+
+        let mut cycles = Vec::new();
+        let mut visited = vec![false; self.docs.len()];
+        let mut on_stack = vec![false; self.docs.len()];
+        let mut path = Vec::new();
+        for start in 0..self.docs.len() {
+            if !visited[start] {
+                self.find_cycles(
+                    start,
+                    edge_type,
+                    reversed,
+                    &mut visited,
+                    &mut on_stack,
+                    &mut path,
+                    &mut cycles,
+                );
+            }
+        }
+        cycles
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn find_cycles(
+        &self,
+        node: usize,
+        edge_type: &EdgeType,
+        reversed: bool,
+        visited: &mut [bool],
+        on_stack: &mut [bool],
+        path: &mut Vec<usize>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        visited[node] = true;
+        on_stack[node] = true;
+        path.push(node);
+        for &next in edge_type.neighbours(node, reversed) {
+            if !visited[next] {
+                self.find_cycles(next, edge_type, reversed, visited, on_stack, path, cycles);
+            } else if on_stack[next] {
+                if let Some(start) = path.iter().position(|&n| n == next) {
+                    cycles.push(
+                        path[start..]
+                            .iter()
+                            .map(|&n| self.docs[n].id.clone())
+                            .collect(),
+                    );
+                }
+            }
+        }
+        path.pop();
+        on_stack[node] = false;
+    }
+
+    /// All documents ordered so that each comes after the documents it
+    /// links to under `key` — dependencies first for `depends_on`, parents
+    /// first for `parent`. Ties keep input order. Fails with one of the
+    /// cycles when there is no such order.
+    pub fn topological_order(&self, key: &str) -> Result<Vec<&SpecDoc>, Vec<String>> {
+        let Some((edge_type, reversed)) = self.resolve(key).filter(|(t, _)| t.directed) else {
+            return Ok(self.docs.iter().collect());
+        };
+
+        let mut pending: Vec<usize> = (0..self.docs.len())
+            .map(|node| edge_type.neighbours(node, reversed).len())
+            .collect();
+        let mut ready: BTreeSet<usize> = (0..self.docs.len())
+            .filter(|&node| pending[node] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.docs.len());
+        while let Some(node) = ready.pop_first() {
+            order.push(&self.docs[node]);
+            for &dependent in edge_type.neighbours(node, !reversed) {
+                pending[dependent] -= 1;
+                if pending[dependent] == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+
+        if order.len() < self.docs.len() {
+            return Err(self.cycles(key).into_iter().next().unwrap_or_default());
+        }
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ItemLink;
+
+    fn def(key: &str, inverse: Option<&str>) -> LinkTypeDef {
+        LinkTypeDef {
+            key: key.into(),
+            label: key.into(),
+            inverse_key: inverse.map(String::from),
+            inverse_label: inverse.map(String::from),
+        }
+    }
+
+    /// The markdown/GitHub shape: parent and child declared as inverses of
+    /// each other, depends_on with an undeclared inverse.
+    fn link_types() -> Vec<LinkTypeDef> {
+        vec![
+            def("parent", Some("child")),
+            def("child", Some("parent")),
+            def("depends_on", Some("blocked_by")),
+            def("relates_to", None),
+        ]
+    }
+
+    fn doc(id: &str, links: &[(&str, &str)]) -> SpecDoc {
+        SpecDoc {
+            id: id.into(),
+            title: id.into(),
+            schema_id: "test".into(),
+            fields: HashMap::new(),
+            links: links
+                .iter()
+                .map(|(link_type, target)| ItemLink {
+                    link_type: link_type.to_string(),
+                    target_id: target.to_string(),
+                    target_title: None,
+                })
+                .collect(),
+            created_at: None,
+            updated_at: None,
+            url: None,
+            raw: None,
+            version: None,
+        }
+    }
+
+    fn ids(docs: &[&SpecDoc]) -> Vec<String> {
+        docs.iter().map(|d| d.id.clone()).collect()
+    }
+
+    #[test]
+    fn links_stored_on_either_end_are_one_edge() {
+        let graph = SpecGraph::new(
+            vec![
+                doc("epic", &[("child", "a")]),
+                doc("a", &[("parent", "epic")]),
+                doc(
+                    "b",
+                    &[("parent", "epic"), ("parent", "missing"), ("parent", "b")],
+                ),
+            ],
+            &link_types(),
+        );
+
+        assert_eq!(ids(&graph.related("epic", "child")), ["a", "b"]);
+        assert_eq!(ids(&graph.related("a", "parent")), ["epic"]);
+        assert_eq!(graph.edges("parent").len(), 2);
+        assert_eq!(graph.edges("child")[0].0.id, "epic");
+
+        let keys: Vec<_> = graph.links("epic").iter().map(|l| l.key).collect();
+        assert_eq!(keys, ["child", "child"]);
+        assert_eq!(
+            graph
+                .link_types()
+                .iter()
+                .map(|t| t.key.as_str())
+                .collect::<Vec<_>>(),
+            ["parent", "depends_on", "relates_to"]
+        );
+    }
+
+    #[test]
+    fn inverse_keys_and_undirected_types() {
+        let graph = SpecGraph::new(
+            vec![
+                doc("api", &[("depends_on", "db"), ("relates_to", "ui")]),
+                doc("db", &[]),
+                doc("ui", &[("blocked_by", "api"), ("mentions", "api")]),
+            ],
+            &link_types(),
+        );
+
+        // `blocked_by` names a `depends_on` edge from its target's side.
+        assert_eq!(ids(&graph.related("api", "depends_on")), ["db", "ui"]);
+        assert_eq!(ids(&graph.related("db", "blocked_by")), ["api"]);
+        assert_eq!(ids(&graph.related("ui", "relates_to")), ["api"]);
+        assert!(!graph.is_directed("relates_to"));
+        assert_eq!(graph.edges("relates_to").len(), 1);
+
+        // Undeclared keys are directed without an inverse key.
+        assert!(graph.is_directed("mentions"));
+        let incoming: Vec<_> = graph
+            .links("api")
+            .into_iter()
+            .filter(|l| l.link_type == "mentions")
+            .collect();
+        assert_eq!((incoming[0].key, incoming[0].outgoing), ("mentions", false));
+    }
+
+    #[test]
+    fn impact_walks_both_directions_nearest_first() {
+        let graph = SpecGraph::new(
+            vec![
+                doc("a", &[("depends_on", "b")]),
+                doc("b", &[("depends_on", "c")]),
+                doc("c", &[]),
+                doc("d", &[("depends_on", "a")]),
+            ],
+            &link_types(),
+        );
+
+        let impact = graph.impact("b", "depends_on", 5).unwrap();
+        let up: Vec<_> = impact
+            .upstream
+            .iter()
+            .map(|r| (r.doc.id.as_str(), r.depth))
+            .collect();
+        let down: Vec<_> = impact
+            .downstream
+            .iter()
+            .map(|r| (r.doc.id.as_str(), r.depth))
+            .collect();
+        assert_eq!(up, [("c", 1)]);
+        assert_eq!(down, [("a", 1), ("d", 2)]);
+        assert_eq!(graph.reachable("b", "blocked_by", 1).len(), 1);
+        assert!(graph.impact("zzz", "depends_on", 5).is_none());
+    }
+
+    #[test]
+    fn cycles_and_topological_order_are_per_link_type() {
+        let graph = SpecGraph::new(
+            vec![
+                doc("a", &[("depends_on", "b"), ("parent", "c")]),
+                doc("b", &[("depends_on", "c")]),
+                doc("c", &[("parent", "a")]),
+            ],
+            &link_types(),
+        );
+
+        assert!(graph.cycles("depends_on").is_empty());
+        let order = graph.topological_order("depends_on").unwrap();
+        assert_eq!(ids(&order), ["c", "b", "a"]);
+
+        let cycles = graph.cycles("parent");
+        assert_eq!(cycles.len(), 1);
+        let mut cycle = cycles[0].clone();
+        cycle.sort();
+        assert_eq!(cycle, ["a", "c"]);
+        assert!(graph.topological_order("child").is_err());
+    }
+}
@@ -29,6 +29,7 @@
 pub mod adapters;
 pub mod compute;
 pub mod error;
+pub mod graph;
 pub mod io;
 pub mod model;
 pub mod parsers;
@@ -45,9 +46,11 @@ pub mod git;
 
 // Re-exports for convenience
 pub use compute::{
-    global_token_counter, Insights, SpecStats, TokenCount, TokenCounter, TokenStatus,
+    global_token_counter, AssigneeWorkload, Insights, SpecStats, TokenCount, TokenCounter,
+    TokenStatus, WorkloadReport, WorkloadSpec,
 };
 pub use error::{CoreError, CoreResult, ErrorCode, StructuredError};
+pub use graph::{GraphLink, Impact, Reached, SpecGraph};
 pub use io::{
     hash_content, DiscoveredProject, DiscoveryError, ProjectDiscovery, TemplateError,
     TemplateLoader,
@@ -55,11 +58,12 @@ pub use io::{
 pub use parsers::FrontmatterParser;
 pub use relationships::{
     validate_dependency_addition, validate_parent_assignment,
-    validate_parent_assignment_with_index, RelationshipError,
+    validate_parent_assignment_with_index, validate_spec_relationships, RelationshipError,
 };
 pub use search::{
     find_content_snippet, parse_query, parse_query_terms, search_specs, search_specs_with_options,
-    validate_search_query, SearchOptions, SearchQueryError, SearchResult,
+    validate_search_query, DuplicateCluster, SearchOptions, SearchQueryError, SearchResult,
+    SimilarPair, SimilarSpec, SimilarityIndex, SimilarityInput, DEFAULT_SIMILARITY_THRESHOLD,
 };
 // Pure string utilities used by HTTP handlers in the fetch-transform-push
 // pattern. They live inside the markdown adapter module but operate purely
@@ -74,14 +78,16 @@ pub use types::{
     Progress, UmbrellaVerificationResult, ValidationError, ValidationResult,
 };
 pub use validators::{
-    global_frontmatter_validator, global_structure_validator, global_token_count_validator,
-    CompletionVerifier, FrontmatterValidator, StructureValidator, TokenCountValidator,
+    body_line_offset, global_frontmatter_validator, global_structure_validator,
+    global_token_count_validator, CompletionVerifier, FrontmatterValidator, ReportFormat,
+    StructureValidator, TokenCountValidator, ValidationReport,
 };
 
 // Model layer — the new schema-driven public abstraction.
 pub use model::{
-    semantic, CompletableItem, CreateRequest, EnumOption, FieldDef, FieldDisplay, FieldKind,
-    FieldValue, ItemLink, LinkTypeDef, Reference, SpecDoc, SpecSchema, UpdateRequest,
+    semantic, Comment, CommentRequest, CompletableItem, CreateRequest, EnumOption, FieldDef,
+    FieldDisplay, FieldKind, FieldValue, ItemLink, LinkTypeDef, Reference, SpecDoc, SpecSchema,
+    UpdateRequest,
 };
 
 // Adapter layer — the backend abstraction.
@@ -93,3 +99,5 @@ pub use adapters::{
     Adapter, AdapterCapabilities, AdapterConfig, AdapterError, AdapterRegistry, ListFilter,
     SearchHit, SearchOptions as AdapterSearchOptions,
 };
+#[cfg(feature = "async")]
+pub use adapters::{AsyncAdapter, BlockingBridge};
@@ -15,6 +15,7 @@
 //!          ↓ values validated against
 //! ┌─ Document layer ──────────────────────────────────────────────────┐
 //! │  SpecDoc · ItemLink · UpdateRequest · CreateRequest               │
+//! │  Comment · CommentRequest                                         │
 //! └───────────────────────────────────────────────────────────────────┘
 //! ```
 
@@ -388,6 +389,11 @@ pub struct SpecDoc {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[ts(type = "unknown | null")]
     pub raw: Option<serde_json::Value>,
+    /// Opaque concurrency token that changes whenever the document does.
+    /// Pass it back as [`UpdateRequest::expected_version`] to reject updates
+    /// based on a stale read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 }
 
 impl SpecDoc {
@@ -425,6 +431,46 @@ pub struct UpdateRequest {
     pub clear: Vec<String>,
     /// If `Some`, replaces all links; `None` leaves links untouched.
     pub replace_links: Option<Vec<ItemLink>>,
+    /// If `Some`, the update only applies while the document's
+    /// [`SpecDoc::version`] still matches; otherwise the adapter returns
+    /// [`AdapterError::Conflict`](crate::adapters::AdapterError::Conflict).
+    pub expected_version: Option<String>,
+}
+
+/// A comment in a spec document's discussion.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../packages/ui/src/types/generated/")]
+pub struct Comment {
+    /// Adapter-native comment id.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Comment body in markdown.
+    pub body: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Resolved comments are kept but no longer need attention.
+    #[serde(default)]
+    pub resolved: bool,
+    /// Id of the comment this one replies to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_reply_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Request to add a comment to a spec document.
+#[derive(Debug, Clone, Default)]
+pub struct CommentRequest {
+    /// Comment body in markdown.
+    pub body: String,
+    /// Reply to an existing comment.
+    pub in_reply_to: Option<String>,
+    /// Author recorded by adapters without an authenticated user (markdown).
+    /// Remote backends attribute comments to the token's owner.
+    pub author: Option<String>,
 }
 
 #[cfg(test)]
@@ -508,6 +554,7 @@ mod tests {
             updated_at: None,
             url: None,
             raw: None,
+            version: None,
         };
         assert_eq!(doc.field_str("status"), Some("planned"));
         assert!(doc.field("missing").is_none());
@@ -313,6 +313,9 @@ impl FrontmatterParser {
                 }
                 "assignee" => frontmatter.assignee = value.as_str().map(String::from),
                 "reviewer" => frontmatter.reviewer = value.as_str().map(String::from),
+                _ if value.is_null() => {
+                    frontmatter.custom.remove(key);
+                }
                 _ => {
                     frontmatter.custom.insert(key.clone(), value.clone());
                 }
@@ -1,15 +1,41 @@
 //! Relationship validation utilities
 
-use crate::adapters::markdown::types::SpecInfo;
+use crate::adapters::markdown::link;
+use crate::adapters::markdown::types::{SpecInfo, SpecStatus};
 use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RelationshipError {
-    ParentCycle { path: Vec<String> },
-    DependencyCycle { path: Vec<String> },
-    SelfDependency { spec: String },
-    DependsOnParent { spec: String, parent: String },
-    DependsOnChild { spec: String, child: String },
+    ParentCycle {
+        path: Vec<String>,
+    },
+    DependencyCycle {
+        path: Vec<String>,
+    },
+    SelfDependency {
+        spec: String,
+    },
+    DependsOnParent {
+        spec: String,
+        parent: String,
+    },
+    DependsOnChild {
+        spec: String,
+        child: String,
+    },
+    SelfLink {
+        spec: String,
+        link_type: String,
+    },
+    LinkCycle {
+        link_type: String,
+        path: Vec<String>,
+    },
+    SupersedesActive {
+        spec: String,
+        target: String,
+        status: SpecStatus,
+    },
 }
 
 impl RelationshipError {
@@ -46,6 +72,26 @@ impl std::fmt::Display for RelationshipError {
                 "Cannot add dependency - target is a child of this spec:\n  {} is parent of {}, cannot depend on its own child",
                 spec, child
             ),
+            RelationshipError::SelfLink { spec, link_type } => write!(
+                f,
+                "Cannot add {} link - spec cannot link to itself: {}",
+                link_type, spec
+            ),
+            RelationshipError::LinkCycle { link_type, path } => write!(
+                f,
+                "Cannot add {} link - would create cycle: {}",
+                link_type,
+                Self::format_path(path)
+            ),
+            RelationshipError::SupersedesActive {
+                spec,
+                target,
+                status,
+            } => write!(
+                f,
+                "Cannot add supersedes link - {} is still {}:\n  Archive {} before {} supersedes it.",
+                target, status, target, spec
+            ),
         }
     }
 }
@@ -172,6 +218,81 @@ pub fn validate_dependency_addition(
     Ok(())
 }
 
+/// Validate adding a link of one of the frontmatter link types
+/// ([`link::FRONTMATTER`]) from `spec` to `target`.
+///
+/// No type may link a spec to itself, directed types may not form cycles,
+/// and a spec can only supersede specs that are already archived.
+pub fn validate_link_addition(
+    spec: &str,
+    link_type: &str,
+    target: &str,
+    specs: &[SpecInfo],
+) -> Result<(), RelationshipError> {
+    if spec == target {
+        return Err(RelationshipError::SelfLink {
+            spec: spec.to_string(),
+            link_type: link_type.to_string(),
+        });
+    }
+
+    if link_type == link::SUPERSEDES {
+        if let Some(target_info) = specs.iter().find(|s| s.path == target) {
+            if target_info.frontmatter.status != SpecStatus::Archived {
+                return Err(RelationshipError::SupersedesActive {
+                    spec: spec.to_string(),
+                    target: target.to_string(),
+                    status: target_info.frontmatter.status,
+                });
+            }
+        }
+    }
+
+    if link_type != link::RELATES_TO {
+        let link_map: HashMap<String, Vec<String>> = specs
+            .iter()
+            .map(|s| (s.path.clone(), s.frontmatter.link_targets(link_type)))
+            .collect();
+        if let Some(path) = find_dependency_path(target, spec, &link_map) {
+            let mut cycle_path = Vec::with_capacity(path.len() + 1);
+            cycle_path.push(spec.to_string());
+            cycle_path.extend(path);
+            return Err(RelationshipError::LinkCycle {
+                link_type: link_type.to_string(),
+                path: cycle_path,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate the relationships a spec already declares — its `parent`, every
+/// `depends_on` entry and its frontmatter links — against the rest of the
+/// project.
+pub fn validate_spec_relationships(
+    spec: &str,
+    specs: &[SpecInfo],
+) -> Result<(), RelationshipError> {
+    let Some(spec_info) = specs.iter().find(|s| s.path == spec) else {
+        return Ok(());
+    };
+
+    if let Some(parent) = &spec_info.frontmatter.parent {
+        validate_parent_assignment(spec, parent, specs)?;
+    }
+    for dep in &spec_info.frontmatter.depends_on {
+        validate_dependency_addition(spec, dep, specs)?;
+    }
+    for link_type in link::FRONTMATTER {
+        for target in spec_info.frontmatter.link_targets(link_type) {
+            validate_link_addition(spec, link_type, &target, specs)?;
+        }
+    }
+
+    Ok(())
+}
+
 fn build_dependency_map(specs: &[SpecInfo]) -> HashMap<String, Vec<String>> {
     specs
         .iter()
@@ -315,4 +436,56 @@ mod tests {
         let err = validate_dependency_addition("B", "A", &specs).unwrap_err();
         assert!(matches!(err, RelationshipError::DependsOnParent { .. }));
     }
+
+    #[test]
+    fn validates_declared_relationships() {
+        let specs = vec![
+            make_spec("A", None, vec!["C"]),
+            make_spec("B", None, vec!["A"]),
+            make_spec("C", None, vec!["B"]),
+        ];
+
+        let err = validate_spec_relationships("A", &specs).unwrap_err();
+        assert!(matches!(err, RelationshipError::DependencyCycle { .. }));
+        assert!(validate_spec_relationships("missing", &specs).is_ok());
+    }
+
+    #[test]
+    fn validates_frontmatter_links() {
+        let mut old = make_spec("A", None, vec![]);
+        let mut new = make_spec("B", None, vec![]);
+        new.frontmatter
+            .set_link_targets(link::SUPERSEDES, vec!["A".into()]);
+        let specs = vec![old.clone(), new.clone()];
+
+        let err = validate_spec_relationships("B", &specs).unwrap_err();
+        assert!(matches!(
+            err,
+            RelationshipError::SupersedesActive {
+                status: SpecStatus::Planned,
+                ..
+            }
+        ));
+
+        old.frontmatter.status = SpecStatus::Archived;
+        old.frontmatter
+            .set_link_targets(link::DUPLICATES, vec!["B".into()]);
+        new.frontmatter
+            .set_link_targets(link::DUPLICATES, vec!["A".into()]);
+        let specs = vec![old, new];
+        let err = validate_spec_relationships("B", &specs).unwrap_err();
+        match err {
+            RelationshipError::LinkCycle { link_type, path } => {
+                assert_eq!(link_type, link::DUPLICATES);
+                assert_eq!(path, vec!["B", "A", "B"]);
+            }
+            other => panic!("Unexpected error: {other}"),
+        }
+
+        assert!(validate_link_addition("A", link::RELATES_TO, "B", &specs).is_ok());
+        assert!(matches!(
+            validate_link_addition("A", link::IMPLEMENTS, "A", &specs),
+            Err(RelationshipError::SelfLink { .. })
+        ));
+    }
 }
@@ -45,7 +45,7 @@ pub fn best_match_distance_in_text(text: &str, term: &str) -> Option<usize> {
     best
 }
 
-fn tokenize(text: &str) -> Vec<String> {
+pub(super) fn tokenize(text: &str) -> Vec<String> {
     text.to_ascii_lowercase()
         .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '-')
         .filter(|t| !t.is_empty())
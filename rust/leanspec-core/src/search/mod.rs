@@ -1,18 +1,24 @@
 //! Search module for spec discovery.
 //!
 //! Supports boolean operators, field filters, date ranges, quoted phrases,
-//! fuzzy matching, and weighted relevance scoring.
+//! fuzzy matching, and weighted relevance scoring. Also hosts duplicate
+//! detection via TF-IDF similarity.
 
 mod filters;
 mod fuzzy;
 mod query;
 mod scorer;
+mod similarity;
 
 use crate::adapters::markdown::types::SpecInfo;
 use query::ParsedQuery;
 pub use query::{parse_query, parse_query_terms, validate_search_query, SearchQueryError};
 use scorer::{matches_query, score_spec};
 use serde::Serialize;
+pub use similarity::{
+    DuplicateCluster, SimilarPair, SimilarSpec, SimilarityIndex, SimilarityInput,
+    DEFAULT_SIMILARITY_THRESHOLD,
+};
 
 /// A search result with relevance score.
 #[derive(Debug, Clone, Serialize)]
@@ -0,0 +1,400 @@
+//! Duplicate and near-duplicate spec detection.
+//!
+//! Scores pairs of specs by blending TF-IDF cosine similarity over title and
+//! section text with a Levenshtein ratio over the titles. Template
+//! boilerplate (headings, HTML comments, placeholder checklists) is stripped
+//! before tokenizing so freshly scaffolded specs don't all look alike.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::Serialize;
+
+use super::fuzzy::{levenshtein_distance, tokenize};
+use crate::adapters::markdown::types::SpecInfo;
+
+/// Default score at or above which two specs are reported as similar.
+pub const DEFAULT_SIMILARITY_THRESHOLD: f64 = 0.5;
+
+/// Title tokens count this many times in the term vector.
+const TITLE_WEIGHT: usize = 3;
+/// Share of the final score taken by the title Levenshtein ratio.
+const TITLE_RATIO_WEIGHT: f64 = 0.3;
+
+static HTML_COMMENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<!--.*?-->").unwrap());
+
+const STOPWORDS: &[&str] = &[
+    "about", "after", "all", "also", "and", "any", "are", "but", "can", "criteria", "design",
+    "does", "each", "for", "from", "has", "have", "how", "into", "its", "not", "notes", "now",
+    "overview", "plan", "priority", "should", "spec", "specs", "status", "task", "test", "that",
+    "the", "their", "then", "there", "these", "this", "use", "when", "which", "will", "with",
+    "would", "you",
+];
+
+/// A document to index for similarity, independent of adapter.
+#[derive(Debug, Clone)]
+pub struct SimilarityInput<'a> {
+    pub id: &'a str,
+    pub title: &'a str,
+    pub body: &'a str,
+}
+
+/// An existing spec that resembles a candidate.
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarSpec {
+    pub id: String,
+    pub title: String,
+    /// Similarity score in `0.0..=1.0`
+    pub score: f64,
+}
+
+/// A pair of specs inside a duplicate cluster.
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarPair {
+    pub left: String,
+    pub right: String,
+    pub score: f64,
+}
+
+/// A group of specs connected by pairwise similarity above the threshold.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateCluster {
+    /// Member spec ids, sorted
+    pub specs: Vec<String>,
+    /// Highest pairwise score in the cluster
+    pub score: f64,
+    /// Every pair above the threshold, highest first
+    pub pairs: Vec<SimilarPair>,
+}
+
+#[derive(Debug)]
+struct IndexedDoc {
+    id: String,
+    title: String,
+    normalized_title: String,
+    terms: HashMap<String, usize>,
+}
+
+/// TF-IDF index over a set of specs.
+#[derive(Debug)]
+pub struct SimilarityIndex {
+    docs: Vec<IndexedDoc>,
+    document_frequency: HashMap<String, usize>,
+}
+
+impl SimilarityIndex {
+    /// Build an index from adapter-neutral inputs.
+    pub fn new<'a>(inputs: impl IntoIterator<Item = SimilarityInput<'a>>) -> Self {
+        let docs: Vec<IndexedDoc> = inputs
+            .into_iter()
+            .map(|input| IndexedDoc {
+                id: input.id.to_string(),
+                title: input.title.to_string(),
+                normalized_title: normalize_title(input.title),
+                terms: term_counts(input.title, input.body),
+            })
+            .collect();
+
+        let mut document_frequency: HashMap<String, usize> = HashMap::new();
+        for doc in &docs {
+            for term in doc.terms.keys() {
+                *document_frequency.entry(term.clone()).or_insert(0) += 1;
+            }
+        }
+
+        Self {
+            docs,
+            document_frequency,
+        }
+    }
+
+    /// Build an index over markdown specs.
+    pub fn from_specs(specs: &[SpecInfo]) -> Self {
+        Self::new(specs.iter().map(|s| SimilarityInput {
+            id: &s.path,
+            title: &s.title,
+            body: &s.content,
+        }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.docs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.docs.is_empty()
+    }
+
+    /// Find indexed specs resembling a candidate title and body, best first.
+    ///
+    /// Specs whose id is in `exclude` are skipped, so a freshly created spec
+    /// can be checked against the rest of the project.
+    pub fn find_similar(
+        &self,
+        title: &str,
+        body: &str,
+        threshold: f64,
+        exclude: &[&str],
+    ) -> Vec<SimilarSpec> {
+        let terms = term_counts(title, body);
+        let vector = self.weights(&terms);
+        let normalized_title = normalize_title(title);
+
+        let mut matches: Vec<SimilarSpec> = self
+            .docs
+            .iter()
+            .filter(|doc| !exclude.contains(&doc.id.as_str()))
+            .filter_map(|doc| {
+                let score = combined_score(
+                    &vector,
+                    &self.weights(&doc.terms),
+                    &normalized_title,
+                    &doc.normalized_title,
+                );
+                (score >= threshold).then(|| SimilarSpec {
+                    id: doc.id.clone(),
+                    title: doc.title.clone(),
+                    score,
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches
+    }
+
+    /// Group indexed specs into clusters of likely duplicates, highest
+    /// scoring cluster first.
+    pub fn duplicate_clusters(&self, threshold: f64) -> Vec<DuplicateCluster> {
+        let vectors: Vec<HashMap<&str, f64>> =
+            self.docs.iter().map(|d| self.weights(&d.terms)).collect();
+
+        let mut pairs: Vec<(usize, usize, f64)> = Vec::new();
+        for i in 0..self.docs.len() {
+            for j in (i + 1)..self.docs.len() {
+                let score = combined_score(
+                    &vectors[i],
+                    &vectors[j],
+                    &self.docs[i].normalized_title,
+                    &self.docs[j].normalized_title,
+                );
+                if score >= threshold {
+                    pairs.push((i, j, score));
+                }
+            }
+        }
+
+        // Union-find over above-threshold pairs.
+        let mut parent: Vec<usize> = (0..self.docs.len()).collect();
+        fn find(parent: &mut [usize], i: usize) -> usize {
+            let mut root = i;
+            while parent[root] != root {
+                root = parent[root];
+            }
+            parent[i] = root;
+            root
+        }
+        for &(i, j, _) in &pairs {
+            let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+            if a != b {
+                parent[b] = a;
+            }
+        }
+
+        let mut groups: HashMap<usize, Vec<(usize, usize, f64)>> = HashMap::new();
+        for &(i, j, score) in &pairs {
+            let root = find(&mut parent, i);
+            groups.entry(root).or_default().push((i, j, score));
+        }
+
+        let mut clusters: Vec<DuplicateCluster> = groups
+            .into_values()
+            .map(|mut group| {
+                group.sort_by(|a, b| b.2.total_cmp(&a.2));
+                let mut members: Vec<String> = group
+                    .iter()
+                    .flat_map(|&(i, j, _)| [self.docs[i].id.clone(), self.docs[j].id.clone()])
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect();
+                members.sort();
+                DuplicateCluster {
+                    specs: members,
+                    score: group[0].2,
+                    pairs: group
+                        .iter()
+                        .map(|&(i, j, score)| SimilarPair {
+                            left: self.docs[i].id.clone(),
+                            right: self.docs[j].id.clone(),
+                            score,
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        clusters.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.specs.cmp(&b.specs)));
+        clusters
+    }
+
+    fn weights<'a>(&self, terms: &'a HashMap<String, usize>) -> HashMap<&'a str, f64> {
+        let total_docs = self.docs.len() as f64;
+        terms
+            .iter()
+            .map(|(term, count)| {
+                let df = self.document_frequency.get(term).copied().unwrap_or(0) as f64;
+                let idf = ((total_docs + 1.0) / (df + 1.0)).ln() + 1.0;
+                (term.as_str(), *count as f64 * idf)
+            })
+            .collect()
+    }
+}
+
+fn combined_score(
+    left: &HashMap<&str, f64>,
+    right: &HashMap<&str, f64>,
+    left_title: &str,
+    right_title: &str,
+) -> f64 {
+    let cosine = cosine_similarity(left, right);
+    let title = title_ratio(left_title, right_title);
+    (1.0 - TITLE_RATIO_WEIGHT) * cosine + TITLE_RATIO_WEIGHT * title
+}
+
+fn cosine_similarity(left: &HashMap<&str, f64>, right: &HashMap<&str, f64>) -> f64 {
+    let dot: f64 = left
+        .iter()
+        .filter_map(|(term, w)| right.get(term).map(|r| w * r))
+        .sum();
+    let norm = |v: &HashMap<&str, f64>| v.values().map(|w| w * w).sum::<f64>().sqrt();
+    let denominator = norm(left) * norm(right);
+    if denominator == 0.0 {
+        0.0
+    } else {
+        dot / denominator
+    }
+}
+
+/// `1 - levenshtein / max_len` over normalized titles.
+fn title_ratio(left: &str, right: &str) -> f64 {
+    let max_len = left.chars().count().max(right.chars().count());
+    if max_len == 0 {
+        return 0.0;
+    }
+    1.0 - levenshtein_distance(left, right) as f64 / max_len as f64
+}
+
+fn normalize_title(title: &str) -> String {
+    tokenize(title).join(" ")
+}
+
+fn term_counts(title: &str, body: &str) -> HashMap<String, usize> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for term in significant_terms(title) {
+        *counts.entry(term).or_insert(0) += TITLE_WEIGHT;
+    }
+    for term in significant_terms(&strip_boilerplate(body)) {
+        *counts.entry(term).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn significant_terms(text: &str) -> impl Iterator<Item = String> {
+    tokenize(text).into_iter().filter(|t| {
+        t.len() > 2 && !t.chars().all(|c| c.is_ascii_digit()) && !STOPWORDS.contains(&t.as_str())
+    })
+}
+
+/// Drop headings, HTML comments, and status blockquotes.
+fn strip_boilerplate(body: &str) -> String {
+    let without_comments = HTML_COMMENT.replace_all(body, "");
+    without_comments
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#') && !line.starts_with("> **Status**"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input<'a>(id: &'a str, title: &'a str, body: &'a str) -> SimilarityInput<'a> {
+        SimilarityInput { id, title, body }
+    }
+
+    fn index() -> SimilarityIndex {
+        SimilarityIndex::new(vec![
+            input(
+                "001-user-auth",
+                "User Authentication",
+                "## Overview\n\nLogin with OAuth tokens and refresh sessions for users.",
+            ),
+            input(
+                "002-user-authentication",
+                "User Authentication Flow",
+                "## Overview\n\nUsers login via OAuth; tokens refresh sessions automatically.",
+            ),
+            input(
+                "003-billing",
+                "Billing Invoices",
+                "## Overview\n\nGenerate monthly invoices and charge credit cards.",
+            ),
+            input(
+                "004-dark-mode",
+                "Dark Mode Theme",
+                "## Overview\n\nAdd a dark colour palette to the web UI.",
+            ),
+        ])
+    }
+
+    #[test]
+    fn finds_similar_specs_for_candidate() {
+        let matches = index().find_similar(
+            "OAuth user login",
+            "Let users login with OAuth and refresh tokens.",
+            0.3,
+            &[],
+        );
+        assert!(!matches.is_empty());
+        assert!(matches[0].id.starts_with("00"));
+        assert!(matches
+            .iter()
+            .all(|m| m.id == "001-user-auth" || m.id == "002-user-authentication"));
+    }
+
+    #[test]
+    fn excludes_requested_ids() {
+        let matches = index().find_similar(
+            "User Authentication",
+            "Login with OAuth tokens and refresh sessions for users.",
+            DEFAULT_SIMILARITY_THRESHOLD,
+            &["001-user-auth"],
+        );
+        assert!(matches.iter().all(|m| m.id != "001-user-auth"));
+        assert_eq!(matches[0].id, "002-user-authentication");
+    }
+
+    #[test]
+    fn clusters_near_duplicates() {
+        let clusters = index().duplicate_clusters(DEFAULT_SIMILARITY_THRESHOLD);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(
+            clusters[0].specs,
+            vec!["001-user-auth", "002-user-authentication"]
+        );
+        assert!(clusters[0].score >= DEFAULT_SIMILARITY_THRESHOLD);
+    }
+
+    #[test]
+    fn template_boilerplate_is_ignored() {
+        let template = "## Overview\n\n<!-- What are we solving? Why now? -->\n\n## Plan\n\n- [ ] Task 1\n- [ ] Task 2\n";
+        let index = SimilarityIndex::new(vec![
+            input("001-payments", "Payments Gateway", template),
+            input("002-search", "Search Indexing", template),
+        ]);
+        assert!(index.duplicate_clusters(0.3).is_empty());
+    }
+}
@@ -5,9 +5,10 @@
 #![cfg(feature = "storage")]
 
 use crate::error::{CoreError, CoreResult};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Server configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -36,6 +37,11 @@ pub struct ServerConfig {
     /// Security settings
     #[serde(default)]
     pub security: SecuritySettings,
+
+    /// File this configuration was loaded from, if any. Changes made at
+    /// runtime (API tokens) are written back here.
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
 }
 
 /// Server-specific settings
@@ -228,6 +234,64 @@ pub struct SecuritySettings {
     /// Read-only mode (prevent modifications)
     #[serde(default)]
     pub readonly: bool,
+
+    /// Named API tokens. Once any exist, every `/api` request must carry
+    /// one of them as a bearer token.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tokens: Vec<ApiToken>,
+}
+
+/// A named API token. Only the SHA-256 hash of the secret is stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiToken {
+    pub name: String,
+    /// Hex-encoded SHA-256 of the token secret
+    pub token_hash: String,
+    pub role: TokenRole,
+    /// Project ids the token is limited to; empty means every project
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// What an API token may do, each role including the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenRole {
+    /// Read specs and project data
+    Viewer,
+    /// Also edit specs and push them with git
+    Editor,
+    /// Also manage projects and tokens
+    Admin,
+}
+
+impl TokenRole {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Viewer => "viewer",
+            Self::Editor => "editor",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+impl std::str::FromStr for TokenRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "viewer" => Ok(Self::Viewer),
+            "editor" => Ok(Self::Editor),
+            "admin" => Ok(Self::Admin),
+            _ => Err(format!(
+                "Invalid role '{}' (expected viewer, editor or admin)",
+                s
+            )),
+        }
+    }
 }
 
 impl Default for SyncSettings {
@@ -382,19 +446,28 @@ pub fn load_config_from_path(path: &PathBuf) -> CoreResult<ServerConfig> {
         }
 
         // Return defaults
-        return Ok(ServerConfig::default());
+        return Ok(ServerConfig {
+            source: Some(path.clone()),
+            ..ServerConfig::default()
+        });
     }
 
     let content = fs::read_to_string(path)
         .map_err(|e| CoreError::ConfigError(format!("Failed to read config: {}", e)))?;
 
-    serde_json::from_str(&content)
-        .map_err(|e| CoreError::ConfigError(format!("Failed to parse config: {}", e)))
+    let mut config: ServerConfig = serde_json::from_str(&content)
+        .map_err(|e| CoreError::ConfigError(format!("Failed to parse config: {}", e)))?;
+    config.source = Some(path.clone());
+    Ok(config)
 }
 
 /// Save configuration to disk
 pub fn save_config(config: &ServerConfig) -> CoreResult<()> {
-    let path = config_path();
+    save_config_to_path(config, &config_path())
+}
+
+/// Save configuration to a custom path
+pub fn save_config_to_path(config: &ServerConfig, path: &Path) -> CoreResult<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
             .map_err(|e| CoreError::ConfigError(format!("Failed to create config dir: {}", e)))?;
@@ -402,7 +475,7 @@ pub fn save_config(config: &ServerConfig) -> CoreResult<()> {
 
     let content = serde_json::to_string_pretty(config)
         .map_err(|e| CoreError::ConfigError(format!("Failed to serialize config: {}", e)))?;
-    fs::write(&path, content)
+    fs::write(path, content)
         .map_err(|e| CoreError::ConfigError(format!("Failed to write config: {}", e)))?;
 
     Ok(())
@@ -417,10 +490,11 @@ fn migrate_yaml_config(yaml_path: &PathBuf) -> CoreResult<ServerConfig> {
 
     // Try to parse YAML directly into our config struct
     // This handles fields that match between YAML and JSON formats
-    let config = serde_yaml::from_str::<ServerConfig>(&content).unwrap_or_else(|e| {
+    let mut config = serde_yaml::from_str::<ServerConfig>(&content).unwrap_or_else(|e| {
         eprintln!("Could not fully parse YAML config, using defaults: {}", e);
         ServerConfig::default()
     });
+    config.source = Some(config_path());
 
     // Save as JSON for future use
     if let Err(e) = save_config(&config) {
@@ -7,6 +7,6 @@ pub mod project_registry;
 
 pub use config::{
     config_dir, config_path, load_config, load_config_from_path, projects_path, save_config,
-    ServerConfig,
+    save_config_to_path, ApiToken, ServerConfig, TokenRole,
 };
 pub use project_registry::{Project, ProjectOptions, ProjectRegistry, ProjectUpdate};
@@ -1,6 +1,7 @@
 //! Configuration types for LeanSpec
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// LeanSpec project configuration.
@@ -30,6 +31,14 @@ pub struct LeanSpecConfig {
     /// Supports a `{specs}` placeholder. If absent, specs are appended.
     #[serde(default)]
     pub session_prompt_template: Option<String>,
+
+    /// Spec review configuration.
+    #[serde(default)]
+    pub review: ReviewConfig,
+
+    /// Assignee capacity configuration.
+    #[serde(default)]
+    pub workload: WorkloadConfig,
 }
 
 impl Default for LeanSpecConfig {
@@ -41,6 +50,8 @@ impl Default for LeanSpecConfig {
             schema: SchemaConfig::default(),
             validation: ValidationConfig::default(),
             session_prompt_template: None,
+            review: ReviewConfig::default(),
+            workload: WorkloadConfig::default(),
         }
     }
 }
@@ -121,6 +132,79 @@ impl Default for ValidationConfig {
     }
 }
 
+/// Spec review configuration.
+///
+/// Field names also accept the camelCase spelling used by the legacy
+/// `config.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewConfig {
+    /// Require an approved review before a spec moves from planned to
+    /// in-progress (default: false).
+    #[serde(default, alias = "requireApproval")]
+    pub require_approval: bool,
+
+    /// Minimum number of current approvals (default: 1).
+    #[serde(default = "default_min_approvals", alias = "minApprovals")]
+    pub min_approvals: usize,
+}
+
+fn default_min_approvals() -> usize {
+    1
+}
+
+impl Default for ReviewConfig {
+    fn default() -> Self {
+        Self {
+            require_approval: false,
+            min_approvals: default_min_approvals(),
+        }
+    }
+}
+
+/// Assignee capacity configuration.
+///
+/// Field names also accept the camelCase spelling used by the legacy
+/// `config.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadConfig {
+    /// Maximum in-progress specs for anyone without an entry in
+    /// `wip_limits` (default: no limit).
+    #[serde(default, alias = "defaultWipLimit")]
+    pub default_wip_limit: Option<usize>,
+
+    /// Maximum in-progress specs per assignee.
+    #[serde(default, alias = "wipLimits")]
+    pub wip_limits: HashMap<String, usize>,
+
+    /// Days before its due date at which a spec counts as due soon (default: 7).
+    #[serde(default = "default_due_soon_days", alias = "dueSoonDays")]
+    pub due_soon_days: i64,
+}
+
+fn default_due_soon_days() -> i64 {
+    7
+}
+
+impl Default for WorkloadConfig {
+    fn default() -> Self {
+        Self {
+            default_wip_limit: None,
+            wip_limits: HashMap::new(),
+            due_soon_days: default_due_soon_days(),
+        }
+    }
+}
+
+impl WorkloadConfig {
+    /// WIP limit that applies to `assignee`, if any.
+    pub fn wip_limit(&self, assignee: &str) -> Option<usize> {
+        self.wip_limits
+            .get(assignee)
+            .copied()
+            .or(self.default_wip_limit)
+    }
+}
+
 impl LeanSpecConfig {
     /// Load configuration from a YAML file.
     pub fn load(path: &std::path::Path) -> Result<Self, ConfigError> {
@@ -137,6 +221,22 @@ impl LeanSpecConfig {
             Ok(Self::default())
         }
     }
+
+    /// Load the configuration of the project rooted at `root`, trying
+    /// `.lean-spec/config.yaml` and then the legacy `config.json`. Missing or
+    /// unreadable files fall back to the defaults.
+    pub fn load_project(root: &std::path::Path) -> Self {
+        let dir = root.join(".lean-spec");
+        if let Ok(content) = std::fs::read_to_string(dir.join("config.yaml")) {
+            if let Ok(config) = serde_yaml::from_str(&content) {
+                return config;
+            }
+        }
+        std::fs::read_to_string(dir.join("config.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
 }
 
 /// Configuration error types.
@@ -232,4 +332,46 @@ frontmatter:
         );
         assert_eq!(config.schema.bundles, vec!["acme:epic"]);
     }
+
+    #[test]
+    fn test_parse_review_config() {
+        assert!(!LeanSpecConfig::default().review.require_approval);
+
+        let yaml = r#"
+review:
+  require_approval: true
+  min_approvals: 2
+"#;
+        let config: LeanSpecConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.review.require_approval);
+        assert_eq!(config.review.min_approvals, 2);
+
+        let json = r#"{ "specsDir": "specs", "review": { "requireApproval": true } }"#;
+        let config: LeanSpecConfig = serde_json::from_str(json).unwrap();
+        assert!(config.review.require_approval);
+        assert_eq!(config.review.min_approvals, 1);
+    }
+
+    #[test]
+    fn test_parse_workload_config() {
+        let config = LeanSpecConfig::default();
+        assert_eq!(config.workload.wip_limit("alice"), None);
+        assert_eq!(config.workload.due_soon_days, 7);
+
+        let yaml = r#"
+workload:
+  default_wip_limit: 3
+  wip_limits:
+    alice: 1
+"#;
+        let config: LeanSpecConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.workload.wip_limit("alice"), Some(1));
+        assert_eq!(config.workload.wip_limit("bob"), Some(3));
+
+        let json = r#"{ "workload": { "wipLimits": { "bob": 2 }, "dueSoonDays": 3 } }"#;
+        let config: LeanSpecConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.workload.wip_limit("bob"), Some(2));
+        assert_eq!(config.workload.wip_limit("alice"), None);
+        assert_eq!(config.workload.due_soon_days, 3);
+    }
 }
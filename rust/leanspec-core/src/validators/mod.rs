@@ -4,11 +4,13 @@ use std::sync::LazyLock;
 
 mod completion;
 mod frontmatter;
+mod report;
 mod structure;
 mod token_count;
 
 pub use completion::CompletionVerifier;
 pub use frontmatter::FrontmatterValidator;
+pub use report::{body_line_offset, ReportEntry, ReportFormat, ValidationReport};
 pub use structure::StructureValidator;
 pub use token_count::TokenCountValidator;
 
@@ -0,0 +1,391 @@
+//! Machine-readable validation reports (SARIF and JUnit)
+//!
+//! Turns [`ValidationResult`]s into formats that CI tooling already knows how
+//! to display: SARIF 2.1.0 for code-scanning annotations and JUnit XML for
+//! test-report panels. Each validated spec file becomes one SARIF artifact and
+//! one JUnit test case.
+
+use std::collections::BTreeSet;
+
+use serde_json::{json, Value};
+
+use crate::types::{ErrorSeverity, ValidationError, ValidationResult};
+
+const TOOL_NAME: &str = "lean-spec";
+const TOOL_URI: &str = "https://lean-spec.dev";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Output format of a [`ValidationReport`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// SARIF 2.1.0 JSON, for code-scanning tools
+    Sarif,
+    /// JUnit XML, for test-report tools
+    Junit,
+}
+
+impl ReportFormat {
+    /// MIME type to serve the rendered report with
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ReportFormat::Sarif => "application/sarif+json",
+            ReportFormat::Junit => "application/xml",
+        }
+    }
+}
+
+impl std::str::FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sarif" => Ok(ReportFormat::Sarif),
+            "junit" => Ok(ReportFormat::Junit),
+            _ => Err(format!(
+                "Invalid report format: {}. Valid values: sarif, junit",
+                s
+            )),
+        }
+    }
+}
+
+/// A validated spec file included in a report
+#[derive(Debug, Clone)]
+pub struct ReportEntry {
+    /// Validation result for the spec
+    pub result: ValidationResult,
+
+    /// Path to the spec file, relative to the repository root
+    pub file: String,
+
+    /// Number of lines preceding the markdown body (the frontmatter block).
+    /// Validators report body-relative line numbers; this shifts them to
+    /// file-relative ones.
+    pub line_offset: usize,
+}
+
+/// Validation results for a set of specs, renderable as SARIF or JUnit
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub entries: Vec<ReportEntry>,
+}
+
+impl ValidationReport {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a spec's result to the report
+    pub fn push(&mut self, result: ValidationResult, file: impl Into<String>, line_offset: usize) {
+        self.entries.push(ReportEntry {
+            result,
+            file: normalize_path(&file.into()),
+            line_offset,
+        });
+    }
+
+    /// Render the report in the given format
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Sarif => {
+                serde_json::to_string_pretty(&self.to_sarif()).unwrap_or_default()
+            }
+            ReportFormat::Junit => self.to_junit(),
+        }
+    }
+
+    /// Build a SARIF 2.1.0 log with one run
+    pub fn to_sarif(&self) -> Value {
+        // One rule per validation category, in a stable order so rule indices
+        // don't shift between runs.
+        let rule_ids: Vec<&str> = self
+            .entries
+            .iter()
+            .flat_map(|e| &e.result.errors)
+            .map(|e| e.category.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let results: Vec<Value> = self
+            .entries
+            .iter()
+            .flat_map(|entry| {
+                let rule_ids = &rule_ids;
+                entry.result.errors.iter().map(move |error| {
+                    let rule_index = rule_ids
+                        .iter()
+                        .position(|id| *id == error.category)
+                        .unwrap_or(0);
+                    sarif_result(entry, error, rule_index)
+                })
+            })
+            .collect();
+
+        let rules: Vec<Value> = rule_ids
+            .iter()
+            .map(|id| {
+                json!({
+                    "id": rule_id(id),
+                    "name": id,
+                    "shortDescription": { "text": format!("Spec {} check", id) },
+                })
+            })
+            .collect();
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": TOOL_NAME,
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": TOOL_URI,
+                        "rules": rules,
+                    }
+                },
+                "artifacts": self
+                    .entries
+                    .iter()
+                    .map(|e| json!({ "location": { "uri": e.file } }))
+                    .collect::<Vec<_>>(),
+                "results": results,
+            }]
+        })
+    }
+
+    /// Build a JUnit XML document with one test case per spec.
+    ///
+    /// Error-level issues become `<failure>` elements; warnings and info
+    /// messages are listed in the test case's `<system-out>` so they stay
+    /// visible without failing the build.
+    pub fn to_junit(&self) -> String {
+        let tests = self.entries.len();
+        let failures = self
+            .entries
+            .iter()
+            .filter(|e| e.result.has_errors())
+            .count();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+            TOOL_NAME, tests, failures
+        ));
+        xml.push_str(&format!(
+            "  <testsuite name=\"{} validate\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\">\n",
+            TOOL_NAME, tests, failures
+        ));
+
+        for entry in &self.entries {
+            let name = xml_escape(&entry.result.spec_path);
+            let file = xml_escape(&entry.file);
+            let failures: Vec<&ValidationError> = entry.result.errors().collect();
+            let notes: Vec<&ValidationError> = entry
+                .result
+                .errors
+                .iter()
+                .filter(|e| e.severity != ErrorSeverity::Error)
+                .collect();
+
+            if failures.is_empty() && notes.is_empty() {
+                xml.push_str(&format!(
+                    "    <testcase classname=\"specs\" name=\"{}\" file=\"{}\"/>\n",
+                    name, file
+                ));
+                continue;
+            }
+
+            xml.push_str(&format!(
+                "    <testcase classname=\"specs\" name=\"{}\" file=\"{}\">\n",
+                name, file
+            ));
+            for error in failures {
+                xml.push_str(&format!(
+                    "      <failure message=\"{}\" type=\"{}\">{}</failure>\n",
+                    xml_escape(&error.message),
+                    xml_escape(&error.category),
+                    xml_escape(&describe(entry, error))
+                ));
+            }
+            if !notes.is_empty() {
+                let text: Vec<String> = notes.iter().map(|e| describe(entry, e)).collect();
+                xml.push_str(&format!(
+                    "      <system-out>{}</system-out>\n",
+                    xml_escape(&text.join("\n"))
+                ));
+            }
+            xml.push_str("    </testcase>\n");
+        }
+
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+}
+
+/// Count the lines in `raw` that precede `body`.
+///
+/// `body` is the markdown content the validators saw (frontmatter stripped);
+/// returns 0 when it can't be located in `raw`.
+pub fn body_line_offset(raw: &str, body: &str) -> usize {
+    if body.is_empty() || !raw.ends_with(body) {
+        return 0;
+    }
+    raw[..raw.len() - body.len()].matches('\n').count()
+}
+
+fn sarif_result(entry: &ReportEntry, error: &ValidationError, rule_index: usize) -> Value {
+    let level = match error.severity {
+        ErrorSeverity::Error => "error",
+        ErrorSeverity::Warning => "warning",
+        ErrorSeverity::Info => "note",
+    };
+    // SARIF regions require a line; issues without one point at the top of
+    // the file, where the frontmatter lives.
+    let start_line = error.line.map(|l| l + entry.line_offset).unwrap_or(1);
+
+    let mut result = json!({
+        "ruleId": rule_id(&error.category),
+        "ruleIndex": rule_index,
+        "level": level,
+        "message": { "text": error.message },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": { "uri": entry.file },
+                "region": { "startLine": start_line },
+            }
+        }],
+        "partialFingerprints": {
+            "leanspec/v1": format!("{}:{}:{}", entry.result.spec_path, error.category, error.message),
+        },
+    });
+    if let Some(suggestion) = &error.suggestion {
+        result["properties"] = json!({ "suggestion": suggestion });
+    }
+    result
+}
+
+fn rule_id(category: &str) -> String {
+    format!("leanspec/{}", category)
+}
+
+fn describe(entry: &ReportEntry, error: &ValidationError) -> String {
+    let location = match error.line {
+        Some(line) => format!("{}:{}", entry.file, line + entry.line_offset),
+        None => entry.file.clone(),
+    };
+    let mut text = format!(
+        "{} [{}] {}: {}",
+        error.severity, error.category, location, error.message
+    );
+    if let Some(suggestion) = &error.suggestion {
+        text.push_str(&format!(" (suggestion: {})", suggestion));
+    }
+    text
+}
+
+fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_report() -> ValidationReport {
+        let mut failing = ValidationResult::new("001-auth");
+        failing.add_error("frontmatter", "Missing required field: status");
+        failing.add(ValidationError {
+            severity: ErrorSeverity::Warning,
+            message: "h3 'Notes' not under an h2 section".to_string(),
+            line: Some(3),
+            category: "structure".to_string(),
+            suggestion: Some("Add a parent h2".to_string()),
+        });
+
+        let mut report = ValidationReport::new();
+        report.push(failing, "specs/001-auth/README.md", 5);
+        report.push(
+            ValidationResult::new("002-billing"),
+            "specs\\002-billing\\README.md",
+            5,
+        );
+        report
+    }
+
+    #[test]
+    fn test_sarif_maps_severity_path_and_line() {
+        let sarif = sample_report().to_sarif();
+        assert_eq!(sarif["version"], "2.1.0");
+
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "lean-spec");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            run["artifacts"][1]["location"]["uri"],
+            "specs/002-billing/README.md"
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["ruleId"], "leanspec/frontmatter");
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            1
+        );
+
+        assert_eq!(results[1]["level"], "warning");
+        assert_eq!(
+            results[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "specs/001-auth/README.md"
+        );
+        assert_eq!(
+            results[1]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            8
+        );
+        assert_eq!(results[1]["properties"]["suggestion"], "Add a parent h2");
+    }
+
+    #[test]
+    fn test_junit_has_case_per_spec() {
+        let xml = sample_report().to_junit();
+        assert!(xml.contains("tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase classname=\"specs\" name=\"002-billing\""));
+        assert!(xml
+            .contains("<failure message=\"Missing required field: status\" type=\"frontmatter\">"));
+        assert!(xml.contains("h3 &apos;Notes&apos; not under an h2 section"));
+        assert!(xml.contains("specs/001-auth/README.md:8"));
+    }
+
+    #[test]
+    fn test_body_line_offset() {
+        let raw = "---\nstatus: planned\ncreated: '2025-01-01'\n---\n\n# Title\n";
+        assert_eq!(body_line_offset(raw, "# Title\n"), 5);
+        assert_eq!(body_line_offset(raw, "missing"), 0);
+    }
+
+    #[test]
+    fn test_report_format_from_str() {
+        assert_eq!("SARIF".parse::<ReportFormat>(), Ok(ReportFormat::Sarif));
+        assert_eq!("junit".parse::<ReportFormat>(), Ok(ReportFormat::Junit));
+        assert!("xml".parse::<ReportFormat>().is_err());
+    }
+}
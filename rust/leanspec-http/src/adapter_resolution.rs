@@ -5,9 +5,12 @@
 //! normalisation. Keeping this in one place stops watcher activation from
 //! drifting away from request-time adapter resolution.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use leanspec_core::adapters::{Adapter, AdapterConfig, AdapterError, AdapterRegistry};
+use leanspec_core::adapters::{AdapterConfig, AdapterError, AdapterRegistry, AsyncAdapter};
+use tokio::sync::RwLock;
 
 /// Candidate file paths (relative to the project root) for adapter config,
 /// in priority order. The legacy `provider:` files are honoured so existing
@@ -50,13 +53,46 @@ pub fn load_adapter_config(
     Ok(config)
 }
 
-/// Resolve and instantiate the active adapter for a project.
-pub fn resolve_adapter(
-    project_root: &Path,
-    specs_dir: &Path,
-) -> Result<Box<dyn Adapter>, AdapterError> {
-    let config = load_adapter_config(project_root, specs_dir)?;
-    AdapterRegistry::create(&config)
+/// Resolved adapters keyed by project root.
+///
+/// Remote adapters hold a pooled HTTP client and a schema resolved against
+/// the backend at construction, so rebuilding them per request would pay for
+/// a fresh TLS handshake and schema fetch every time. Entries are rebuilt when
+/// the project's adapter config changes.
+#[derive(Default)]
+pub struct AdapterCache {
+    entries: RwLock<HashMap<PathBuf, CachedAdapter>>,
+}
+
+/// An adapter plus the config it was built from.
+type CachedAdapter = (AdapterConfig, Arc<dyn AsyncAdapter>);
+
+impl AdapterCache {
+    /// Return the cached adapter for a project, building it on first use or
+    /// when its config no longer matches.
+    pub async fn resolve(
+        &self,
+        project_root: &Path,
+        specs_dir: &Path,
+    ) -> Result<Arc<dyn AsyncAdapter>, AdapterError> {
+        let config = load_adapter_config(project_root, specs_dir)?;
+        if let Some((cached, adapter)) = self.entries.read().await.get(project_root) {
+            if same_config(cached, &config) {
+                return Ok(adapter.clone());
+            }
+        }
+
+        let adapter = AdapterRegistry::create_async(&config).await?;
+        self.entries
+            .write()
+            .await
+            .insert(project_root.to_path_buf(), (config, adapter.clone()));
+        Ok(adapter)
+    }
+}
+
+fn same_config(a: &AdapterConfig, b: &AdapterConfig) -> bool {
+    a.adapter == b.adapter && a.settings == b.settings
 }
 
 /// When the active adapter is markdown, rewrite a relative `settings.directory`
@@ -146,6 +182,27 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn cache_reuses_adapter_until_config_changes() {
+        let tmp = TempDir::new().unwrap();
+        let project = tmp.path();
+        let specs = project.join("specs");
+        fs::create_dir_all(&specs).unwrap();
+
+        let cache = AdapterCache::default();
+        let first = cache.resolve(project, &specs).await.unwrap();
+        let second = cache.resolve(project, &specs).await.unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        fs::write(
+            project.join("leanspec.adapter.yaml"),
+            "adapter: markdown\ndirectory: docs/specs\n",
+        )
+        .unwrap();
+        let third = cache.resolve(project, &specs).await.unwrap();
+        assert!(!Arc::ptr_eq(&first, &third));
+    }
+
     #[test]
     fn non_markdown_adapter_left_alone() {
         let tmp = TempDir::new().unwrap();
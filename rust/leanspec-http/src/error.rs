@@ -82,6 +82,7 @@ pub fn error_code_to_status(code: ErrorCode) -> StatusCode {
             StatusCode::UNPROCESSABLE_ENTITY
         }
         ErrorCode::Unauthorized => StatusCode::UNAUTHORIZED,
+        ErrorCode::Forbidden => StatusCode::FORBIDDEN,
         ErrorCode::ToolNotFound => StatusCode::NOT_FOUND,
         ErrorCode::ToolError => StatusCode::BAD_REQUEST,
         ErrorCode::ModelNotAvailable => StatusCode::SERVICE_UNAVAILABLE,
@@ -155,6 +156,10 @@ impl ApiError {
         Self::new("UNAUTHORIZED", reason)
     }
 
+    pub fn forbidden(reason: &str) -> Self {
+        Self::new("FORBIDDEN", reason)
+    }
+
     pub fn internal_error(reason: &str) -> Self {
         Self::new("INTERNAL_ERROR", reason)
     }
@@ -256,6 +261,7 @@ fn map_error_code_to_status(code: &str) -> StatusCode {
         "NO_PROJECT" => error_code_to_status(ErrorCode::NoProject),
         "INVALID_REQUEST" => error_code_to_status(ErrorCode::InvalidRequest),
         "UNAUTHORIZED" => error_code_to_status(ErrorCode::Unauthorized),
+        "FORBIDDEN" => error_code_to_status(ErrorCode::Forbidden),
         "VALIDATION_FAILED" => error_code_to_status(ErrorCode::ValidationFailed),
         "DATABASE_ERROR" => error_code_to_status(ErrorCode::DatabaseError),
         "CONFIG_ERROR" => error_code_to_status(ErrorCode::ConfigError),
@@ -12,7 +12,6 @@ use axum::Json;
 use leanspec_core::adapters::AdapterCapabilities;
 use leanspec_core::SpecSchema;
 
-use crate::adapter_resolution::resolve_adapter;
 use crate::error::ApiError;
 use crate::state::AppState;
 use crate::utils::resolve_project;
@@ -25,13 +24,21 @@ fn api_error(err: leanspec_core::AdapterError) -> (StatusCode, Json<ApiError>) {
 }
 
 /// GET /api/projects/{id}/adapter
+///
+/// When the adapter is cached, `cache` carries its live hit/miss counters.
 pub async fn get_project_adapter_capabilities(
     State(state): State<AppState>,
     Path(project_id): Path<String>,
 ) -> Result<Json<AdapterCapabilities>, (StatusCode, Json<ApiError>)> {
     let project = resolve_project(&state, &project_id).await?;
-    let adapter = resolve_adapter(&project.path, &project.specs_dir).map_err(api_error)?;
-    Ok(Json(adapter.capabilities().clone()))
+    let adapter = state
+        .adapters
+        .resolve(&project.path, &project.specs_dir)
+        .await
+        .map_err(api_error)?;
+    let mut capabilities = adapter.capabilities().clone();
+    capabilities.cache = adapter.cache_stats();
+    Ok(Json(capabilities))
 }
 
 /// GET /api/projects/{id}/schema
@@ -43,6 +50,10 @@ pub async fn get_project_schema(
     Path(project_id): Path<String>,
 ) -> Result<Json<SpecSchema>, (StatusCode, Json<ApiError>)> {
     let project = resolve_project(&state, &project_id).await?;
-    let adapter = resolve_adapter(&project.path, &project.specs_dir).map_err(api_error)?;
+    let adapter = state
+        .adapters
+        .resolve(&project.path, &project.specs_dir)
+        .await
+        .map_err(api_error)?;
     Ok(Json(adapter.schema().clone()))
 }
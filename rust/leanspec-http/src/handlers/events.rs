@@ -1,18 +1,29 @@
 //! SSE event handlers
 
+use std::path::PathBuf;
+
 use axum::body::{Body, Bytes};
 use axum::extract::State;
 use axum::http::{header, StatusCode};
 use axum::response::Response;
+use axum::Extension;
 use serde_json::json;
 use tokio::sync::broadcast;
 
 use crate::error::{ApiError, ApiResult};
+use crate::middleware::Principal;
 use crate::state::AppState;
-use crate::watcher::{sse_keepalive_interval, sse_min_interval, SpecChangeEvent};
+use crate::watcher::{sse_keepalive_interval, sse_min_interval, WatchEvent};
 
-/// GET /api/events/specs - server-sent events for spec changes
-pub async fn spec_events(State(state): State<AppState>) -> ApiResult<Response> {
+/// GET /api/events/specs - server-sent events for spec changes and their
+/// downstream impact
+///
+/// Callers authenticated with a project-scoped token only receive events
+/// from those projects.
+pub async fn spec_events(
+    State(state): State<AppState>,
+    principal: Option<Extension<Principal>>,
+) -> ApiResult<Response> {
     let watcher = state.file_watcher.clone().ok_or_else(|| {
         (
             StatusCode::SERVICE_UNAVAILABLE,
@@ -34,6 +45,21 @@ pub async fn spec_events(State(state): State<AppState>) -> ApiResult<Response> {
             )
         })?;
 
+    let visible_dirs: Option<Vec<PathBuf>> = match principal {
+        Some(Extension(who)) if !who.projects.is_empty() => {
+            let registry = state.registry.read().await;
+            Some(
+                registry
+                    .all()
+                    .into_iter()
+                    .filter(|project| who.can_access(&project.id))
+                    .map(|project| project.specs_dir.clone())
+                    .collect(),
+            )
+        }
+        _ => None,
+    };
+
     let mut rx = watcher.subscribe();
     let keepalive_interval = sse_keepalive_interval();
     let min_interval = sse_min_interval();
@@ -51,6 +77,9 @@ pub async fn spec_events(State(state): State<AppState>) -> ApiResult<Response> {
                 result = rx.recv() => {
                     match result {
                         Ok(event) => {
+                            if !is_visible(&event, visible_dirs.as_deref()) {
+                                continue;
+                            }
                             let elapsed = last_sent.elapsed();
                             if elapsed < min_interval {
                                 tokio::time::sleep(min_interval - elapsed).await;
@@ -86,7 +115,41 @@ pub async fn spec_events(State(state): State<AppState>) -> ApiResult<Response> {
     Ok(response)
 }
 
-fn to_sse_payload(event: &SpecChangeEvent) -> String {
-    let data = serde_json::to_string(event).unwrap_or_else(|_| json!({}).to_string());
-    format!("data: {}\n\n", data)
+/// Whether `event` comes from one of `visible_dirs`; `None` allows all.
+fn is_visible(event: &WatchEvent, visible_dirs: Option<&[PathBuf]>) -> bool {
+    visible_dirs.map_or(true, |dirs| dirs.iter().any(|dir| dir == event.specs_dir()))
+}
+
+/// File changes go out as unnamed `message` events; impact reports use the
+/// `impact` event name so existing listeners don't see them.
+fn to_sse_payload(event: &WatchEvent) -> String {
+    let (name, data) = match event {
+        WatchEvent::Change(change) => (None, serde_json::to_string(change)),
+        WatchEvent::Impact(impact) => (Some("impact"), serde_json::to_string(impact)),
+    };
+    let data = data.unwrap_or_else(|_| json!({}).to_string());
+    match name {
+        Some(name) => format!("event: {}\ndata: {}\n\n", name, data),
+        None => format!("data: {}\n\n", data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watcher::{SpecChangeEvent, SpecChangeType};
+
+    #[test]
+    fn scoped_callers_only_see_their_projects() {
+        let event = WatchEvent::Change(SpecChangeEvent {
+            change_type: SpecChangeType::Modified,
+            path: "001-a/README.md".into(),
+            specs_dir: PathBuf::from("/p1/specs"),
+        });
+        assert!(is_visible(&event, None));
+        assert!(is_visible(&event, Some(&[PathBuf::from("/p1/specs")])));
+        assert!(!is_visible(&event, Some(&[PathBuf::from("/p2/specs")])));
+        assert!(!is_visible(&event, Some(&[])));
+        assert!(!to_sse_payload(&event).contains("/p1/specs"));
+    }
 }
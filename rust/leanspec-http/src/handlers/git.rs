@@ -1,7 +1,9 @@
 //! Git repository integration API handlers
 //!
 //! Uses the system `git` binary for clone/pull/push — works with any
-//! Git host (GitHub, GitLab, Gitea, self-hosted, SSH).
+//! Git host (GitHub, GitLab, Gitea, self-hosted, SSH). Git-sourced projects
+//! can edit specs on per-spec branches; pushes that fail are queued and
+//! retried via the pending endpoints.
 
 use axum::extract::State;
 use axum::http::StatusCode;
@@ -10,7 +12,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::state::AppState;
 
-use leanspec_core::git::{CloneManager, RemoteRef, SpecDetectionResult};
+use crate::project_registry::GitConfig;
+use leanspec_core::git::{
+    BranchInfo, CloneManager, FlushResult, GitStatus, IntegrationResult, IntegrationStrategy,
+    PendingChange, RemoteRef, SpecDetectionResult,
+};
+use std::path::PathBuf;
 
 /// Compute a deterministic clone directory for a remote URL.
 fn clone_dir_for(remote_url: &str) -> std::path::PathBuf {
@@ -30,6 +37,43 @@ fn clone_dir_for(remote_url: &str) -> std::path::PathBuf {
         .join(slug)
 }
 
+/// Look up a git-sourced project's clone directory and git config.
+async fn git_project(
+    state: &AppState,
+    project_id: &str,
+) -> Result<(PathBuf, GitConfig), (StatusCode, String)> {
+    let registry = state.registry.read().await;
+    let project = registry
+        .get(project_id)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "Project not found".to_string()))?;
+    let git_config = project.git.clone().ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            "Project is not a git-sourced project".to_string(),
+        )
+    })?;
+    Ok((project.path.clone(), git_config))
+}
+
+/// Run a blocking git operation off the async runtime.
+async fn run_blocking<T, F>(f: F, failure: StatusCode) -> Result<T, (StatusCode, String)>
+where
+    T: Send + 'static,
+    F: FnOnce() -> leanspec_core::CoreResult<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map_err(|e| {
+            let status = match e {
+                leanspec_core::CoreError::NotFound(_) => StatusCode::NOT_FOUND,
+                leanspec_core::CoreError::ValidationError(_) => StatusCode::BAD_REQUEST,
+                _ => failure,
+            };
+            (status, e.to_string())
+        })
+}
+
 /// POST /api/git/detect — Detect specs in a remote Git repository.
 ///
 /// Clones into a temp directory, scans for specs, then cleans up.
@@ -216,6 +260,9 @@ pub async fn git_sync_project(
 }
 
 /// POST /api/git/push/{id} — Commit and push local spec changes.
+///
+/// When the push fails the commit is kept locally and queued; the response
+/// reports `pushed: false` with the push error.
 pub async fn git_push_project(
     State(state): State<AppState>,
     axum::extract::Path(project_id): axum::extract::Path<String>,
@@ -252,6 +299,8 @@ pub async fn git_push_project(
     Ok(Json(PushResponse {
         project_id,
         commit_sha: result.commit_sha,
+        pushed: result.pushed,
+        queued_error: result.queued_error,
     }))
 }
 
@@ -283,6 +332,136 @@ pub async fn git_status_project(
     Ok(Json(status))
 }
 
+/// GET /api/git/branches/{id} — List local branches with ahead/behind counts.
+pub async fn git_list_branches(
+    State(state): State<AppState>,
+    axum::extract::Path(project_id): axum::extract::Path<String>,
+) -> Result<Json<BranchesResponse>, (StatusCode, String)> {
+    let (clone_dir, git_config) = git_project(&state, &project_id).await?;
+    let base = git_config.branch.clone();
+
+    let branches = run_blocking(
+        move || CloneManager::list_branches(&clone_dir, &base),
+        StatusCode::BAD_GATEWAY,
+    )
+    .await?;
+
+    Ok(Json(BranchesResponse {
+        project_id,
+        base: git_config.branch,
+        branches,
+    }))
+}
+
+/// POST /api/git/branches/{id} — Check out (creating if needed) the working
+/// branch for a spec, based on the project's tracked branch.
+pub async fn git_create_spec_branch(
+    State(state): State<AppState>,
+    axum::extract::Path(project_id): axum::extract::Path<String>,
+    Json(body): Json<SpecBranchRequest>,
+) -> Result<Json<BranchInfo>, (StatusCode, String)> {
+    let (clone_dir, git_config) = git_project(&state, &project_id).await?;
+    let base = body.base.unwrap_or(git_config.branch);
+
+    let branch = run_blocking(
+        move || CloneManager::checkout_spec_branch(&clone_dir, &body.spec, &base),
+        StatusCode::BAD_REQUEST,
+    )
+    .await?;
+
+    Ok(Json(branch))
+}
+
+/// POST /api/git/checkout/{id} — Switch the clone to an existing branch.
+pub async fn git_checkout_branch(
+    State(state): State<AppState>,
+    axum::extract::Path(project_id): axum::extract::Path<String>,
+    Json(body): Json<CheckoutRequest>,
+) -> Result<Json<GitStatus>, (StatusCode, String)> {
+    let (clone_dir, _) = git_project(&state, &project_id).await?;
+
+    let status = run_blocking(
+        move || {
+            CloneManager::checkout_branch(&clone_dir, &body.branch)?;
+            CloneManager::status(&clone_dir)
+        },
+        StatusCode::BAD_REQUEST,
+    )
+    .await?;
+
+    Ok(Json(status))
+}
+
+/// POST /api/git/integrate/{id} — Rebase or merge a branch onto the remote.
+///
+/// Responds 409 with per-spec conflicts when the operation can't complete;
+/// the branch is left as it was.
+pub async fn git_integrate_branch(
+    State(state): State<AppState>,
+    axum::extract::Path(project_id): axum::extract::Path<String>,
+    Json(body): Json<IntegrateRequest>,
+) -> Result<(StatusCode, Json<IntegrationResult>), (StatusCode, String)> {
+    let (clone_dir, git_config) = git_project(&state, &project_id).await?;
+    let onto = body.onto.unwrap_or_else(|| git_config.branch.clone());
+    let specs_path = git_config.specs_path;
+
+    let result = run_blocking(
+        move || {
+            CloneManager::integrate(
+                &clone_dir,
+                &specs_path,
+                body.branch.as_deref(),
+                &onto,
+                body.strategy,
+            )
+        },
+        StatusCode::BAD_GATEWAY,
+    )
+    .await?;
+
+    let status = if result.success {
+        StatusCode::OK
+    } else {
+        StatusCode::CONFLICT
+    };
+    Ok((status, Json(result)))
+}
+
+/// GET /api/git/pending/{id} — List commits waiting to be pushed.
+pub async fn git_pending_changes(
+    State(state): State<AppState>,
+    axum::extract::Path(project_id): axum::extract::Path<String>,
+) -> Result<Json<PendingResponse>, (StatusCode, String)> {
+    let (clone_dir, _) = git_project(&state, &project_id).await?;
+
+    let changes = run_blocking(
+        move || CloneManager::pending_changes(&clone_dir),
+        StatusCode::INTERNAL_SERVER_ERROR,
+    )
+    .await?;
+
+    Ok(Json(PendingResponse {
+        project_id,
+        changes,
+    }))
+}
+
+/// POST /api/git/pending/{id}/flush — Retry pushing queued commits.
+pub async fn git_flush_pending(
+    State(state): State<AppState>,
+    axum::extract::Path(project_id): axum::extract::Path<String>,
+) -> Result<Json<FlushResult>, (StatusCode, String)> {
+    let (clone_dir, _) = git_project(&state, &project_id).await?;
+
+    let result = run_blocking(
+        move || CloneManager::flush_pending(&clone_dir),
+        StatusCode::INTERNAL_SERVER_ERROR,
+    )
+    .await?;
+
+    Ok(Json(result))
+}
+
 // ── Request/Response types ───────────────────────────────────────────
 
 #[derive(Debug, Deserialize)]
@@ -341,4 +520,48 @@ pub struct PushRequest {
 pub struct PushResponse {
     pub project_id: String,
     pub commit_sha: String,
+    pub pushed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queued_error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchesResponse {
+    pub project_id: String,
+    pub base: String,
+    pub branches: Vec<BranchInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpecBranchRequest {
+    /// Spec id the branch is for (e.g. `042-search`).
+    pub spec: String,
+    /// Branch to start from (defaults to the project's tracked branch).
+    #[serde(default)]
+    pub base: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutRequest {
+    pub branch: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IntegrateRequest {
+    /// Branch to update (defaults to the current branch).
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// Remote branch to integrate (defaults to the project's tracked branch).
+    #[serde(default)]
+    pub onto: Option<String>,
+    #[serde(default)]
+    pub strategy: IntegrationStrategy,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingResponse {
+    pub project_id: String,
+    pub changes: Vec<PendingChange>,
 }
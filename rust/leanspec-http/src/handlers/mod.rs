@@ -11,6 +11,7 @@ mod health;
 mod local_projects;
 mod projects;
 mod specs;
+mod tokens;
 
 pub use adapter::*;
 pub use capabilities::*;
@@ -21,3 +22,4 @@ pub use health::{health_check, health_live, health_ready};
 pub use local_projects::*;
 pub use projects::*;
 pub use specs::*;
+pub use tokens::*;
@@ -1,13 +1,16 @@
 //! Project management handlers
 #![allow(clippy::result_large_err)]
 
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
-use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+use leanspec_core::ReportFormat;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::error::{ApiError, ApiResult};
+use crate::middleware::Principal;
 use crate::project_registry::{GitConfig, Project, ProjectSource, ProjectUpdate};
 use crate::state::AppState;
 
@@ -64,11 +67,36 @@ impl From<&Project> for ProjectResponse {
 }
 
 /// GET /api/projects - List all projects
-pub async fn list_projects(State(state): State<AppState>) -> Json<ProjectsListResponse> {
+///
+/// Callers authenticated with a project-scoped token only see those projects.
+pub async fn list_projects(
+    State(state): State<AppState>,
+    principal: Option<Extension<Principal>>,
+) -> Json<ProjectsListResponse> {
+    let visible = |p: &&Project| principal.as_ref().map_or(true, |who| who.can_access(&p.id));
     let registry = state.registry.read().await;
-    let projects: Vec<ProjectResponse> = registry.all().iter().map(|p| (*p).into()).collect();
-    let recent_projects = Some(registry.recent(5).iter().map(|p| p.id.clone()).collect());
-    let favorite_projects = Some(registry.favorites().iter().map(|p| p.id.clone()).collect());
+    let projects: Vec<ProjectResponse> = registry
+        .all()
+        .into_iter()
+        .filter(visible)
+        .map(|p| p.into())
+        .collect();
+    let recent_projects = Some(
+        registry
+            .recent(5)
+            .into_iter()
+            .filter(visible)
+            .map(|p| p.id.clone())
+            .collect(),
+    );
+    let favorite_projects = Some(
+        registry
+            .favorites()
+            .into_iter()
+            .filter(visible)
+            .map(|p| p.id.clone())
+            .collect(),
+    );
 
     Json(ProjectsListResponse {
         projects,
@@ -181,10 +209,26 @@ pub async fn refresh_projects(State(state): State<AppState>) -> Json<serde_json:
 }
 
 /// POST /api/projects/:projectId/validate - Validate a project
+///
+/// With `?format=sarif` or `?format=junit`, validates every spec instead and
+/// returns the results as a SARIF log or JUnit XML document.
 pub async fn validate_project(
     State(state): State<AppState>,
     Path(project_id): Path<String>,
-) -> ApiResult<Json<crate::types::ProjectValidationResponse>> {
+    Query(query): Query<crate::types::ValidateProjectQuery>,
+) -> ApiResult<Response> {
+    if let Some(format) = query.format.as_deref() {
+        let format = format
+            .parse::<ReportFormat>()
+            .map_err(|e| (StatusCode::BAD_REQUEST, Json(ApiError::invalid_request(&e))))?;
+        let report = super::build_project_validation_report(&state, &project_id).await?;
+        return Ok((
+            [(header::CONTENT_TYPE, format.content_type())],
+            report.render(format),
+        )
+            .into_response());
+    }
+
     let registry = state.registry.read().await;
     let project = registry.get(&project_id).ok_or_else(|| {
         (
@@ -210,7 +254,8 @@ pub async fn validate_project(
         project_id: project.id.clone(),
         path: project.path.to_string_lossy().to_string(),
         validation,
-    }))
+    })
+    .into_response())
 }
 
 /// GET /api/projects/:projectId/context - Get project context (agent instructions, config, docs)
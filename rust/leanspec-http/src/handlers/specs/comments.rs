@@ -0,0 +1,124 @@
+//! Spec comment handlers
+//!
+//! Thin wrappers over the adapter comment operations. Adapters without
+//! comment support answer `422 ADAPTER_NOT_SUPPORTED`. With API tokens, only
+//! a comment's author or an admin may change its body. Authorship is only
+//! known for markdown projects, where the author is the token name; trackers
+//! record their own login instead, so there only admins edit bodies.
+
+#![allow(clippy::result_large_err)]
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+
+use leanspec_core::adapters::{Comment, CommentRequest};
+
+use crate::config::TokenRole;
+use crate::error::{ApiError, ApiResult};
+use crate::middleware::Principal;
+use crate::state::AppState;
+use crate::types::{AddCommentRequest, UpdateCommentRequest};
+
+use super::helpers::{adapter_error, get_adapter_and_project};
+
+/// GET /api/projects/:projectId/specs/:spec/comments - List spec comments
+pub async fn list_spec_comments(
+    State(state): State<AppState>,
+    Path((project_id, spec_id)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<Comment>>> {
+    let (adapter, _project) = get_adapter_and_project(&state, &project_id).await?;
+    let comments = adapter
+        .list_comments(&spec_id)
+        .await
+        .map_err(adapter_error)?;
+    Ok(Json(comments))
+}
+
+/// POST /api/projects/:projectId/specs/:spec/comments - Add a comment
+///
+/// The author is the name of the API token used, when there is one.
+pub async fn add_spec_comment(
+    State(state): State<AppState>,
+    Path((project_id, spec_id)): Path<(String, String)>,
+    principal: Option<Extension<Principal>>,
+    Json(request): Json<AddCommentRequest>,
+) -> ApiResult<(StatusCode, Json<Comment>)> {
+    let (adapter, _project) = get_adapter_and_project(&state, &project_id).await?;
+    let req = CommentRequest {
+        body: request.body,
+        in_reply_to: request.in_reply_to,
+        author: principal.map(|Extension(p)| p.name),
+    };
+    let comment = adapter
+        .add_comment(&spec_id, &req)
+        .await
+        .map_err(adapter_error)?;
+    Ok((StatusCode::CREATED, Json(comment)))
+}
+
+/// PATCH /api/projects/:projectId/specs/:spec/comments/:comment - Edit or
+/// resolve a comment
+pub async fn update_spec_comment(
+    State(state): State<AppState>,
+    Path((project_id, spec_id, comment_id)): Path<(String, String, String)>,
+    principal: Option<Extension<Principal>>,
+    Json(request): Json<UpdateCommentRequest>,
+) -> ApiResult<Json<Comment>> {
+    if request.body.is_none() && request.resolved.is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::invalid_request(
+                "Provide a new body, a resolved state, or both",
+            )),
+        ));
+    }
+    let (adapter, _project) = get_adapter_and_project(&state, &project_id).await?;
+
+    if let (Some(_), Some(Extension(who))) = (&request.body, &principal) {
+        if who.role < TokenRole::Admin {
+            if adapter.capabilities().name != "markdown" {
+                return Err((
+                    StatusCode::FORBIDDEN,
+                    Json(ApiError::forbidden(&format!(
+                        "Token '{}' needs the admin role to edit comments in this project",
+                        who.name
+                    ))),
+                ));
+            }
+            let comments = adapter
+                .list_comments(&spec_id)
+                .await
+                .map_err(adapter_error)?;
+            let existing = comments.iter().find(|c| c.id == comment_id);
+            if existing.is_some_and(|c| c.author.as_deref() != Some(who.name.as_str())) {
+                return Err((
+                    StatusCode::FORBIDDEN,
+                    Json(ApiError::forbidden(&format!(
+                        "Token '{}' may only edit its own comments",
+                        who.name
+                    ))),
+                ));
+            }
+        }
+    }
+
+    let mut comment = None;
+    if let Some(body) = &request.body {
+        comment = Some(
+            adapter
+                .edit_comment(&spec_id, &comment_id, body)
+                .await
+                .map_err(adapter_error)?,
+        );
+    }
+    if let Some(resolved) = request.resolved {
+        comment = Some(
+            adapter
+                .resolve_comment(&spec_id, &comment_id, resolved)
+                .await
+                .map_err(adapter_error)?,
+        );
+    }
+    Ok(Json(comment.expect("body or resolved was checked above")))
+}
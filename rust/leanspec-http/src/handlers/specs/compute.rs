@@ -1,4 +1,4 @@
-//! Spec compute handlers: stats, dependencies, tokens, validation
+//! Spec compute handlers: stats, workload, dependencies, tokens, validation
 
 #![allow(clippy::result_large_err)]
 
@@ -10,11 +10,13 @@ use axum::http::StatusCode;
 use axum::Json;
 
 use leanspec_core::adapters::markdown::doc_to_spec_info;
+use leanspec_core::adapters::markdown::SpecInfo;
 use leanspec_core::adapters::ListFilter;
 use leanspec_core::{
-    global_frontmatter_validator, global_structure_validator, global_token_count_validator,
-    global_token_counter, semantic, FieldValue, FrontmatterParser, SpecDoc, SpecSchema,
-    ValidationResult,
+    body_line_offset, global_frontmatter_validator, global_structure_validator,
+    global_token_count_validator, global_token_counter, semantic, FieldValue, FrontmatterParser,
+    SimilarityIndex, SimilarityInput, SpecDoc, SpecGraph, SpecSchema, ValidationReport,
+    ValidationResult, WorkloadReport, DEFAULT_SIMILARITY_THRESHOLD,
 };
 
 use crate::error::{ApiError, ApiResult};
@@ -22,13 +24,14 @@ use crate::state::AppState;
 
 use crate::types::{
     DependencyEdge, DependencyGraphResponse, DependencyNode, DetailedBreakdown, PriorityCountItem,
-    SectionTokenCount, SpecTokenResponse, SpecValidationError, SpecValidationResponse,
-    StatsResponse, StatusCountItem, TokenBreakdown,
+    SectionTokenCount, SimilarSpecItem, SimilarSpecsRequest, SimilarSpecsResponse,
+    SpecTokenResponse, SpecValidationError, SpecValidationResponse, StatsResponse, StatusCountItem,
+    TokenBreakdown,
 };
 
 use super::helpers::{
-    adapter_error, get_adapter_and_project, require_markdown_adapter, resolve_markdown_spec_path,
-    token_status_label, validation_status_label,
+    adapter_error, get_adapter_and_project, load_project_config, require_markdown_adapter,
+    resolve_markdown_spec_path, run_blocking, token_status_label, validation_status_label,
 };
 
 fn doc_content(doc: &SpecDoc) -> &str {
@@ -91,7 +94,7 @@ pub async fn get_project_spec_tokens(
     // projects we read the file; for other adapters we fall back to the body
     // content the adapter returned.
     let content = if adapter.capabilities().name == "markdown" {
-        adapter.get(&spec_id).map_err(adapter_error)?;
+        adapter.get(&spec_id).await.map_err(adapter_error)?;
         let file_path =
             resolve_markdown_spec_path(&project.specs_dir, &spec_id).ok_or_else(|| {
                 (
@@ -106,7 +109,7 @@ pub async fn get_project_spec_tokens(
             )
         })?
     } else {
-        let doc = adapter.get(&spec_id).map_err(adapter_error)?;
+        let doc = adapter.get(&spec_id).await.map_err(adapter_error)?;
         doc_content(&doc).to_string()
     };
 
@@ -148,7 +151,7 @@ pub async fn get_project_spec_validation(
     let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
     require_markdown_adapter(adapter.as_ref())?;
 
-    let doc = adapter.get(&spec_id).map_err(adapter_error)?;
+    let doc = adapter.get(&spec_id).await.map_err(adapter_error)?;
     let file_path = resolve_markdown_spec_path(&project.specs_dir, &spec_id).ok_or_else(|| {
         (
             StatusCode::NOT_FOUND,
@@ -157,15 +160,7 @@ pub async fn get_project_spec_validation(
     })?;
 
     let spec_info = doc_to_spec_info_from_disk(&doc, file_path)?;
-
-    let fm_validator = global_frontmatter_validator();
-    let struct_validator = global_structure_validator();
-    let token_validator = global_token_count_validator();
-
-    let mut result = ValidationResult::new(&spec_info.path);
-    result.merge(fm_validator.validate(&spec_info));
-    result.merge(struct_validator.validate(&spec_info));
-    result.merge(token_validator.validate(&spec_info));
+    let result = run_spec_validators(&spec_info);
 
     let errors = result
         .errors
@@ -185,6 +180,50 @@ pub async fn get_project_spec_validation(
     }))
 }
 
+/// Run the frontmatter, structure, and token validators over one spec.
+fn run_spec_validators(spec_info: &SpecInfo) -> ValidationResult {
+    let mut result = ValidationResult::new(&spec_info.path);
+    result.merge(global_frontmatter_validator().validate(spec_info));
+    result.merge(global_structure_validator().validate(spec_info));
+    result.merge(global_token_count_validator().validate(spec_info));
+    result
+}
+
+/// Validate every spec in a project and collect the results into a
+/// [`ValidationReport`], with file paths relative to the project root.
+///
+/// Markdown-only. Returns HTTP 422 for other adapters.
+pub(crate) async fn build_project_validation_report(
+    state: &AppState,
+    project_id: &str,
+) -> Result<ValidationReport, (StatusCode, Json<ApiError>)> {
+    let (adapter, project) = get_adapter_and_project(state, project_id).await?;
+    require_markdown_adapter(adapter.as_ref())?;
+
+    let docs = adapter
+        .list(&ListFilter::default())
+        .await
+        .map_err(adapter_error)?;
+
+    let mut report = ValidationReport::new();
+    for doc in &docs {
+        let Some(file_path) = resolve_markdown_spec_path(&project.specs_dir, &doc.id) else {
+            continue;
+        };
+        let raw = fs::read_to_string(&file_path).unwrap_or_default();
+        let spec_info = doc_to_spec_info_from_disk(doc, file_path.clone())?;
+        let line_offset = body_line_offset(&raw, &spec_info.content);
+        let relative = file_path
+            .strip_prefix(&project.path)
+            .unwrap_or(&file_path)
+            .to_string_lossy()
+            .to_string();
+        report.push(run_spec_validators(&spec_info), relative, line_offset);
+    }
+
+    Ok(report)
+}
+
 /// GET /api/projects/:projectId/stats - Project statistics
 pub async fn get_project_stats(
     State(state): State<AppState>,
@@ -198,6 +237,7 @@ pub async fn get_project_stats(
             include_archived: true,
             ..Default::default()
         })
+        .await
         .map_err(adapter_error)?;
 
     let specs_by_status = build_status_counts(&docs, schema);
@@ -214,6 +254,33 @@ pub async fn get_project_stats(
     }))
 }
 
+/// GET /api/projects/:projectId/workload - Open specs per assignee against
+/// the configured WIP limits
+pub async fn get_project_workload(
+    State(state): State<AppState>,
+    Path(project_id): Path<String>,
+) -> ApiResult<Json<WorkloadReport>> {
+    let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
+    let docs = adapter
+        .list(&ListFilter::default())
+        .await
+        .map_err(adapter_error)?;
+    let project_path = project.path.clone();
+    let config = run_blocking(move || {
+        Ok(load_project_config(&project_path)
+            .map(|c| c.workload)
+            .unwrap_or_default())
+    })
+    .await?;
+
+    Ok(Json(WorkloadReport::from_docs(
+        &docs,
+        adapter.schema(),
+        &config,
+        chrono::Utc::now().date_naive(),
+    )))
+}
+
 fn build_status_counts(docs: &[SpecDoc], schema: &SpecSchema) -> Vec<StatusCountItem> {
     // Keep the canonical markdown status ordering at minimum, then append any
     // adapter-specific values seen in the data.
@@ -281,15 +348,75 @@ fn completion_rate(counts: &[StatusCountItem]) -> f64 {
     (complete as f64 / total as f64) * 100.0
 }
 
+/// POST /api/projects/:projectId/specs/similar - Find specs resembling a draft
+///
+/// Works with any adapter: compares the draft title and body against the
+/// title and `content` field of every active spec.
+pub async fn find_similar_specs(
+    State(state): State<AppState>,
+    Path(project_id): Path<String>,
+    Json(req): Json<SimilarSpecsRequest>,
+) -> ApiResult<Json<SimilarSpecsResponse>> {
+    let threshold = req.threshold.unwrap_or(DEFAULT_SIMILARITY_THRESHOLD);
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::invalid_request(
+                "threshold must be between 0.0 and 1.0",
+            )),
+        ));
+    }
+    if req.title.trim().is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::invalid_request("title is required")),
+        ));
+    }
+
+    let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
+    let docs = adapter
+        .list(&ListFilter::default())
+        .await
+        .map_err(adapter_error)?;
+
+    let index = SimilarityIndex::new(docs.iter().map(|doc| SimilarityInput {
+        id: &doc.id,
+        title: &doc.title,
+        body: doc_content(doc),
+    }));
+    let exclude: Vec<&str> = req.exclude.as_deref().into_iter().collect();
+    let mut results: Vec<SimilarSpecItem> = index
+        .find_similar(
+            &req.title,
+            req.content.as_deref().unwrap_or(""),
+            threshold,
+            &exclude,
+        )
+        .into_iter()
+        .map(|m| SimilarSpecItem {
+            id: m.id,
+            title: m.title,
+            score: m.score,
+        })
+        .collect();
+    results.truncate(req.limit.unwrap_or(10));
+
+    Ok(Json(SimilarSpecsResponse {
+        results,
+        threshold,
+        project_id: Some(project.id),
+    }))
+}
+
 /// GET /api/projects/:projectId/dependencies - Dependency graph for a project
 ///
-/// Markdown-only. Returns HTTP 422 for other adapters.
+/// Edges come from `depends_on` links, whichever end of the link the adapter
+/// stores them on.
 pub async fn get_project_dependencies(
     State(state): State<AppState>,
     Path(project_id): Path<String>,
 ) -> ApiResult<Json<DependencyGraphResponse>> {
     let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
-    require_markdown_adapter(adapter.as_ref())?;
 
     let schema = adapter.schema();
     let docs = adapter
@@ -297,14 +424,14 @@ pub async fn get_project_dependencies(
             include_archived: true,
             ..Default::default()
         })
+        .await
         .map_err(adapter_error)?;
+    let graph = SpecGraph::new(docs, &schema.link_types);
 
-    let known_ids: std::collections::HashSet<String> = docs.iter().map(|d| d.id.clone()).collect();
-    let mut nodes = Vec::with_capacity(docs.len());
-    let mut edges = Vec::new();
-
-    for doc in &docs {
-        nodes.push(DependencyNode {
+    let nodes = graph
+        .docs()
+        .iter()
+        .map(|doc| DependencyNode {
             id: doc.id.clone(),
             name: if !doc.title.is_empty() && doc.title != doc.id {
                 doc.title.clone()
@@ -324,19 +451,19 @@ pub async fn get_project_dependencies(
                 .unwrap_or("medium")
                 .to_string(),
             tags: doc_semantic_strings(doc, schema, semantic::TAGS),
-        });
-
-        for link in &doc.links {
-            if link.link_type == "depends_on" && known_ids.contains(&link.target_id) {
-                edges.push(DependencyEdge {
-                    // Edge direction: dependency -> dependent
-                    source: link.target_id.clone(),
-                    target: doc.id.clone(),
-                    r#type: Some("dependsOn".to_string()),
-                });
-            }
-        }
-    }
+        })
+        .collect();
+
+    let edges = graph
+        .edges("depends_on")
+        .into_iter()
+        .map(|(dependent, dependency)| DependencyEdge {
+            // Edge direction: dependency -> dependent
+            source: dependency.id.clone(),
+            target: dependent.id.clone(),
+            r#type: Some("dependsOn".to_string()),
+        })
+        .collect();
 
     Ok(Json(DependencyGraphResponse {
         project_id: Some(project.id),
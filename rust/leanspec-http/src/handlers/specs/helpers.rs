@@ -2,15 +2,16 @@
 
 #![allow(clippy::result_large_err)]
 
-use axum::http::StatusCode;
+use axum::http::{header::IF_MATCH, HeaderMap, StatusCode};
 use axum::Json;
 use sha2::{Digest, Sha256};
 use std::path::{Component, Path as FsPath, PathBuf};
+use std::sync::Arc;
 
-use leanspec_core::adapters::{Adapter, AdapterError};
+use leanspec_core::adapters::markdown::ChangesetError;
+use leanspec_core::adapters::{AdapterError, AsyncAdapter};
 use leanspec_core::{LeanSpecConfig, TokenStatus, ValidationResult};
 
-use crate::adapter_resolution::resolve_adapter;
 use crate::error::ApiError;
 use crate::project_registry::Project;
 use crate::state::AppState;
@@ -22,13 +23,18 @@ use crate::types::SubSpec;
 ///
 /// Adapter config is read from the project root using the same lookup order
 /// as the CLI. When no config file is present we fall back to the markdown
-/// adapter pointed at the project's declared `specs_dir`.
+/// adapter pointed at the project's declared `specs_dir`. Adapters are reused
+/// across requests via [`AppState::adapters`].
 pub(super) async fn get_adapter_and_project(
     state: &AppState,
     project_id: &str,
-) -> Result<(Box<dyn Adapter>, Project), (StatusCode, Json<ApiError>)> {
+) -> Result<(Arc<dyn AsyncAdapter>, Project), (StatusCode, Json<ApiError>)> {
     let project = resolve_project(state, project_id).await?;
-    let adapter = resolve_adapter(&project.path, &project.specs_dir).map_err(adapter_init_error)?;
+    let adapter = state
+        .adapters
+        .resolve(&project.path, &project.specs_dir)
+        .await
+        .map_err(adapter_init_error)?;
     Ok((adapter, project))
 }
 
@@ -39,6 +45,44 @@ fn adapter_init_error(err: AdapterError) -> (StatusCode, Json<ApiError>) {
     )
 }
 
+/// Run blocking file I/O off the async runtime.
+pub(super) async fn run_blocking<T, F>(f: F) -> Result<T, (StatusCode, Json<ApiError>)>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, (StatusCode, Json<ApiError>)> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::internal_error(&e.to_string())),
+        )
+    })?
+}
+
+/// The version token carried by an `If-Match` header.
+///
+/// Quotes and a weak `W/` prefix are stripped; `*` matches any version and
+/// is treated as if no precondition was sent.
+pub(super) fn if_match(headers: &HeaderMap) -> Option<String> {
+    let raw = headers.get(IF_MATCH)?.to_str().ok()?.trim();
+    if raw.is_empty() || raw == "*" {
+        return None;
+    }
+    let raw = raw.strip_prefix("W/").unwrap_or(raw);
+    Some(raw.trim_matches('"').to_string())
+}
+
+/// 409 response for a write against a stale version.
+fn version_conflict(message: &str, current: Option<String>) -> (StatusCode, Json<ApiError>) {
+    (
+        StatusCode::CONFLICT,
+        Json(
+            ApiError::new("VERSION_CONFLICT", message)
+                .with_details(serde_json::json!({ "currentVersion": current })),
+        ),
+    )
+}
+
 /// Map any [`AdapterError`] to an HTTP error response.
 pub(super) fn adapter_error(err: AdapterError) -> (StatusCode, Json<ApiError>) {
     match err {
@@ -63,6 +107,13 @@ pub(super) fn adapter_error(err: AdapterError) -> (StatusCode, Json<ApiError>) {
             StatusCode::BAD_GATEWAY,
             Json(ApiError::internal_error(&err.to_string())),
         ),
+        AdapterError::Unavailable { .. } => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiError::internal_error(&err.to_string())),
+        ),
+        AdapterError::Conflict { ref current, .. } => {
+            version_conflict(&err.to_string(), current.clone())
+        }
         AdapterError::IoError(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ApiError::internal_error(&e.to_string())),
@@ -70,10 +121,31 @@ pub(super) fn adapter_error(err: AdapterError) -> (StatusCode, Json<ApiError>) {
     }
 }
 
+/// Map a [`ChangesetError`] to an HTTP error response.
+pub(super) fn changeset_error(err: ChangesetError) -> (StatusCode, Json<ApiError>) {
+    match err {
+        ChangesetError::NotFound(id) => {
+            (StatusCode::NOT_FOUND, Json(ApiError::spec_not_found(&id)))
+        }
+        ChangesetError::InvalidRename { .. } => (
+            StatusCode::CONFLICT,
+            Json(ApiError::invalid_request(&err.to_string())),
+        ),
+        ChangesetError::Relationship(_) | ChangesetError::Frontmatter { .. } => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiError::invalid_request(&err.to_string())),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::internal_error(&err.to_string())),
+        ),
+    }
+}
+
 /// Guard a handler so it only runs against the markdown adapter, with a
 /// consistent 422 error otherwise.
 pub(super) fn require_markdown_adapter(
-    adapter: &dyn Adapter,
+    adapter: &dyn AsyncAdapter,
 ) -> Result<(), (StatusCode, Json<ApiError>)> {
     if adapter.capabilities().name == "markdown" {
         Ok(())
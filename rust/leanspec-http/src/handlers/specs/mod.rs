@@ -1,8 +1,12 @@
+mod comments;
 mod compute;
 mod helpers;
 mod read;
+mod review;
 mod write;
 
+pub use comments::*;
 pub use compute::*;
 pub use read::*;
+pub use review::*;
 pub use write::*;
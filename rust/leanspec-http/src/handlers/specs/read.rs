@@ -9,8 +9,9 @@ use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
 
-use leanspec_core::adapters::ListFilter;
-use leanspec_core::{semantic, SpecDoc, SpecSchema};
+use leanspec_core::adapters::{AsyncAdapter, ListFilter};
+use leanspec_core::git::commits_for_spec;
+use leanspec_core::{semantic, SpecDoc, SpecGraph, SpecSchema};
 
 use crate::error::{ApiError, ApiResult};
 use crate::state::AppState;
@@ -22,11 +23,13 @@ use crate::types::{
 
 use super::helpers::{
     adapter_error, detect_sub_specs, get_adapter_and_project, hash_raw_content,
-    require_markdown_adapter, resolve_markdown_spec_path,
+    require_markdown_adapter, resolve_markdown_spec_path, run_blocking,
 };
 
 const LINK_PARENT: &str = "parent";
 const LINK_DEPENDS_ON: &str = "depends_on";
+/// How many trailer-carrying commits to scan for linked commits.
+const COMMIT_SCAN_LIMIT: usize = 1000;
 
 fn parse_csv_filter(value: &Option<String>) -> Option<Vec<String>> {
     value.as_ref().map(|s| {
@@ -122,42 +125,35 @@ fn next_cursor(total: usize, offset: usize, limit: Option<usize>) -> Option<Stri
     }
 }
 
-/// Build child→parent and parent→children maps from the document set's links.
-fn build_relationship_index(docs: &[SpecDoc]) -> RelationshipIndex {
+/// Build child→parent, parent→children and dependency→dependents maps from
+/// the document graph. Links count whichever end of the relationship they
+/// are stored on.
+fn build_relationship_index(graph: &SpecGraph) -> RelationshipIndex {
     let mut parent_by_child: HashMap<String, String> = HashMap::new();
     let mut children_by_parent: HashMap<String, Vec<String>> = HashMap::new();
     let mut required_by: HashMap<String, Vec<String>> = HashMap::new();
 
-    for doc in docs {
-        for link in &doc.links {
-            match link.link_type.as_str() {
-                LINK_PARENT => {
-                    parent_by_child.insert(doc.id.clone(), link.target_id.clone());
-                    children_by_parent
-                        .entry(link.target_id.clone())
-                        .or_default()
-                        .push(doc.id.clone());
-                }
-                LINK_DEPENDS_ON => {
-                    if link.target_id != doc.id {
-                        required_by
-                            .entry(link.target_id.clone())
-                            .or_default()
-                            .push(doc.id.clone());
-                    }
-                }
-                _ => {}
-            }
+    for doc in graph.docs() {
+        if let Some(parent) = graph.related(&doc.id, LINK_PARENT).first() {
+            parent_by_child.insert(doc.id.clone(), parent.id.clone());
+            children_by_parent
+                .entry(parent.id.clone())
+                .or_default()
+                .push(doc.id.clone());
+        }
+        for dependency in graph.related(&doc.id, LINK_DEPENDS_ON) {
+            required_by
+                .entry(dependency.id.clone())
+                .or_default()
+                .push(doc.id.clone());
         }
     }
 
     for list in children_by_parent.values_mut() {
         list.sort();
-        list.dedup();
     }
     for list in required_by.values_mut() {
         list.sort();
-        list.dedup();
     }
 
     RelationshipIndex {
@@ -268,7 +264,7 @@ fn build_hierarchy(summaries: &[SpecSummary], index: &RelationshipIndex) -> Vec<
 fn populate_file_path(
     summary_file_path: &mut String,
     doc: &SpecDoc,
-    adapter: &dyn leanspec_core::Adapter,
+    adapter: &dyn AsyncAdapter,
     specs_dir: &std::path::Path,
 ) {
     if let Some(url) = doc.url.as_ref() {
@@ -313,11 +309,13 @@ pub async fn list_project_specs(
     let schema = adapter.schema();
 
     let filter = build_list_filter(&query, schema);
-    let docs = adapter.list(&filter).map_err(adapter_error)?;
+    let docs = adapter.list(&filter).await.map_err(adapter_error)?;
 
-    let index = build_relationship_index(&docs);
+    let graph = SpecGraph::new(docs, &schema.link_types);
+    let index = build_relationship_index(&graph);
 
-    let mut filtered_specs: Vec<SpecSummary> = docs
+    let mut filtered_specs: Vec<SpecSummary> = graph
+        .docs()
         .iter()
         .map(|doc| {
             let mut summary = SpecSummary::from_doc(doc, schema).with_project_id(&project.id);
@@ -374,7 +372,7 @@ pub async fn get_project_spec(
     let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
     let schema = adapter.schema();
 
-    let doc = adapter.get(&spec_id).map_err(adapter_error)?;
+    let doc = adapter.get(&spec_id).await.map_err(adapter_error)?;
 
     let mut detail = SpecDetail::from_doc(&doc, schema).with_project_id(project.id.clone());
 
@@ -384,8 +382,11 @@ pub async fn get_project_spec(
             include_archived: true,
             ..Default::default()
         })
+        .await
         .map_err(adapter_error)?;
-    let index = build_relationship_index(&all_docs);
+    let graph = SpecGraph::new(all_docs, &schema.link_types);
+    let index = build_relationship_index(&graph);
+    detail.links = graph.links(&doc.id).into_iter().map(Into::into).collect();
 
     let required_by = index.required_by.get(&doc.id).cloned().unwrap_or_default();
     detail.required_by = required_by.clone();
@@ -408,6 +409,15 @@ pub async fn get_project_spec(
                 detail.sub_specs = Some(sub_specs);
             }
         }
+
+        let specs_dir = project.specs_dir.clone();
+        let spec_path = doc.id.clone();
+        let commits =
+            run_blocking(move || Ok(commits_for_spec(&specs_dir, &spec_path, COMMIT_SCAN_LIMIT)))
+                .await?;
+        if !commits.is_empty() {
+            detail.commits = Some(commits.into_iter().map(Into::into).collect());
+        }
     }
 
     Ok(Json(detail))
@@ -422,7 +432,7 @@ pub async fn get_project_spec_raw(
     require_markdown_adapter(adapter.as_ref())?;
 
     // Surface a 404 if the spec doesn't exist at all.
-    adapter.get(&spec_id).map_err(adapter_error)?;
+    adapter.get(&spec_id).await.map_err(adapter_error)?;
 
     let file_path = resolve_markdown_spec_path(&project.specs_dir, &spec_id).ok_or_else(|| {
         (
@@ -461,7 +471,7 @@ pub async fn get_project_subspec_raw(
     let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
     require_markdown_adapter(adapter.as_ref())?;
 
-    adapter.get(&spec_id).map_err(adapter_error)?;
+    adapter.get(&spec_id).await.map_err(adapter_error)?;
 
     let spec_readme =
         resolve_markdown_spec_path(&project.specs_dir, &spec_id).ok_or_else(|| {
@@ -544,7 +554,7 @@ pub async fn search_project_specs(
         raw: None,
     };
 
-    let docs = adapter.list(&filter).map_err(adapter_error)?;
+    let docs = adapter.list(&filter).await.map_err(adapter_error)?;
 
     let results: Vec<SpecSummary> = docs
         .iter()
@@ -574,7 +584,7 @@ pub async fn search_project_specs(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use leanspec_core::ItemLink;
+    use leanspec_core::{ItemLink, LinkTypeDef};
 
     fn dummy_summary(id: &str) -> SpecSummary {
         SpecSummary {
@@ -600,6 +610,7 @@ mod tests {
             token_status: None,
             validation_status: None,
             relationships: None,
+            version: None,
         }
     }
 
@@ -622,6 +633,7 @@ mod tests {
             updated_at: None,
             url: None,
             raw: None,
+            version: None,
         }
     }
 
@@ -634,7 +646,13 @@ mod tests {
             dummy_doc("001-a", Some("002-b")),
             dummy_doc("002-b", Some("001-a")),
         ];
-        let index = build_relationship_index(&docs);
+        let link_types = [LinkTypeDef {
+            key: "parent".into(),
+            label: "Parent".into(),
+            inverse_key: Some("child".into()),
+            inverse_label: Some("Children".into()),
+        }];
+        let index = build_relationship_index(&SpecGraph::new(docs, &link_types));
 
         let summaries = vec![dummy_summary("001-a"), dummy_summary("002-b")];
         let hierarchy = build_hierarchy(&summaries, &index);
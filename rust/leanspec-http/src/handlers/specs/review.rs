@@ -0,0 +1,193 @@
+//! Spec review handlers
+//!
+//! Reviews live in the markdown `review.yaml` sidecar, so these endpoints
+//! require a markdown adapter. The reviewer is the name of the API token
+//! used, when there is one.
+
+#![allow(clippy::result_large_err)]
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+
+use leanspec_core::adapters::markdown::{
+    approval_blocker, load_review, record_review, request_review, MarkdownAdapter, ReviewError,
+    SpecInfo, SpecReview, SpecStatus,
+};
+use leanspec_core::types::ReviewConfig;
+use leanspec_core::FrontmatterParser;
+
+use crate::error::{ApiError, ApiResult};
+use crate::middleware::Principal;
+use crate::project_registry::Project;
+use crate::state::AppState;
+use crate::types::{RequestReviewRequest, SubmitReviewRequest};
+
+use super::helpers::{
+    adapter_error, get_adapter_and_project, invalid_spec_id, load_project_config,
+    require_markdown_adapter, run_blocking,
+};
+
+/// GET /api/projects/:projectId/specs/:spec/review - Review state of a spec
+pub async fn get_spec_review(
+    State(state): State<AppState>,
+    Path((project_id, spec_id)): Path<(String, String)>,
+) -> ApiResult<Json<SpecReview>> {
+    let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
+    require_markdown_adapter(adapter.as_ref())?;
+    with_spec(&project, &spec_id, load_review).await.map(Json)
+}
+
+/// POST /api/projects/:projectId/specs/:spec/review/request - Ask reviewers
+pub async fn request_spec_review(
+    State(state): State<AppState>,
+    Path((project_id, spec_id)): Path<(String, String)>,
+    principal: Option<Extension<Principal>>,
+    Json(request): Json<RequestReviewRequest>,
+) -> ApiResult<Json<SpecReview>> {
+    if request.reviewers.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::invalid_request(
+                "At least one reviewer is required",
+            )),
+        ));
+    }
+    let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
+    require_markdown_adapter(adapter.as_ref())?;
+    let requested_by = principal.map(|Extension(p)| p.name);
+    with_spec(&project, &spec_id, move |spec| {
+        request_review(spec, &request.reviewers, requested_by.as_deref())
+    })
+    .await
+    .map(Json)
+}
+
+/// POST /api/projects/:projectId/specs/:spec/review - Approve or request
+/// changes
+pub async fn submit_spec_review(
+    State(state): State<AppState>,
+    Path((project_id, spec_id)): Path<(String, String)>,
+    principal: Option<Extension<Principal>>,
+    Json(request): Json<SubmitReviewRequest>,
+) -> ApiResult<Json<SpecReview>> {
+    let reviewer = principal
+        .map(|Extension(p)| p.name)
+        .or(request.reviewer)
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::invalid_request("reviewer is required")),
+            )
+        })?;
+    let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
+    require_markdown_adapter(adapter.as_ref())?;
+    with_spec(&project, &spec_id, move |spec| {
+        record_review(spec, &reviewer, request.decision, request.comment)
+    })
+    .await
+    .map(Json)
+}
+
+/// Refuse to start `spec_id` when the project requires an approved review
+/// and it does not have one.
+pub(super) async fn check_review_gate(
+    project: &Project,
+    spec_id: &str,
+) -> Result<(), (StatusCode, Json<ApiError>)> {
+    let project_path = project.path.clone();
+    let config = run_blocking(move || {
+        Ok(load_project_config(&project_path)
+            .map(|c| c.review)
+            .unwrap_or_default())
+    })
+    .await?;
+    if !config.require_approval {
+        return Ok(());
+    }
+    let review = with_spec(project, spec_id, load_review).await?;
+    match approval_blocker(&review, &config) {
+        None => Ok(()),
+        Some(reason) => Err(review_gate_error(&reason, "Use force to override.")),
+    }
+}
+
+/// Refuse a raw write of `content` over the README of `spec` that starts it,
+/// by setting the frontmatter `status` to in-progress, without an approved
+/// review of the new body. Blocking; call it while holding the spec lock.
+pub(super) fn check_raw_review_gate(
+    spec: &SpecInfo,
+    content: &str,
+    config: &ReviewConfig,
+) -> Result<(), (StatusCode, Json<ApiError>)> {
+    if !config.require_approval || spec.frontmatter.status == SpecStatus::InProgress {
+        return Ok(());
+    }
+    let Ok((frontmatter, body)) = FrontmatterParser::new().parse(content) else {
+        return Ok(());
+    };
+    if frontmatter.status != SpecStatus::InProgress {
+        return Ok(());
+    }
+    let updated = SpecInfo {
+        frontmatter,
+        content: body,
+        ..spec.clone()
+    };
+    let review = load_review(&updated).map_err(review_error)?;
+    match approval_blocker(&review, config) {
+        None => Ok(()),
+        Some(reason) => Err(review_gate_error(
+            &reason,
+            "Use the status endpoint with force to override.",
+        )),
+    }
+}
+
+fn review_gate_error(reason: &str, hint: &str) -> (StatusCode, Json<ApiError>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ApiError::invalid_request(&format!(
+            "Cannot start before the review is approved: {reason}. {hint}"
+        ))),
+    )
+}
+
+/// Load `spec_id` and run `f` on it, both on the blocking pool since the
+/// review sidecar is read and written with blocking file I/O.
+async fn with_spec<T, F>(
+    project: &Project,
+    spec_id: &str,
+    f: F,
+) -> Result<T, (StatusCode, Json<ApiError>)>
+where
+    T: Send + 'static,
+    F: FnOnce(&SpecInfo) -> Result<T, ReviewError> + Send + 'static,
+{
+    if invalid_spec_id(spec_id) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::invalid_request("Invalid spec name")),
+        ));
+    }
+    let adapter = MarkdownAdapter::new(&project.specs_dir);
+    let spec_id = spec_id.to_string();
+    run_blocking(move || {
+        let spec = adapter.load_spec_info(&spec_id).map_err(adapter_error)?;
+        f(&spec).map_err(review_error)
+    })
+    .await
+}
+
+fn review_error(err: ReviewError) -> (StatusCode, Json<ApiError>) {
+    match err {
+        ReviewError::EmptyReviewer => (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::invalid_request(&err.to_string())),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::internal_error(&err.to_string())),
+        ),
+    }
+}
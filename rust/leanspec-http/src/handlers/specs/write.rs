@@ -16,14 +16,15 @@ use std::path::Path as FsPath;
 use std::sync::{LazyLock, RwLock};
 
 use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, StatusCode};
 use axum::Json;
 
 use leanspec_core::adapters::markdown::{
-    doc_to_spec_info, umbrella_completion_for_docs, MarkdownAdapter,
+    atomic_write, doc_to_spec_info, umbrella_completion_for_docs, Changeset, MarkdownAdapter,
 };
-use leanspec_core::adapters::ListFilter;
+use leanspec_core::adapters::{check_version, Adapter, AsyncAdapter, ListFilter};
 use leanspec_core::io::hash_content;
+use leanspec_core::types::ReviewConfig;
 use leanspec_core::{
     apply_checklist_toggles, global_frontmatter_validator, global_structure_validator,
     global_token_count_validator, global_token_counter, rebuild_content, semantic,
@@ -36,14 +37,17 @@ use crate::state::AppState;
 
 use crate::types::{
     BatchMetadataRequest, BatchMetadataResponse, ChecklistToggleRequest, ChecklistToggleResponse,
-    ChecklistToggledResult, CreateSpecRequest, FrontmatterResponse, MetadataUpdate, SpecDetail,
-    SpecMetadata, SpecRawResponse, SpecRawUpdateRequest, UpdateMetadataResponse,
+    ChecklistToggledResult, CreateSpecRequest, FrontmatterResponse, MetadataUpdate,
+    MoveSpecRequest, MoveSpecResponse, MovedFile, MovedLine, SpecDetail, SpecMetadata,
+    SpecRawResponse, SpecRawUpdateRequest, UpdateMetadataResponse,
 };
 
 use super::helpers::{
-    adapter_error, get_adapter_and_project, hash_raw_content, invalid_spec_id, load_project_config,
-    require_markdown_adapter, resolve_markdown_spec_path,
+    adapter_error, changeset_error, get_adapter_and_project, hash_raw_content, if_match,
+    invalid_spec_id, load_project_config, require_markdown_adapter, resolve_markdown_spec_path,
+    run_blocking,
 };
+use super::review::{check_raw_review_gate, check_review_gate};
 
 // In-process cache for expensive batch metadata computation.
 static BATCH_METADATA_CACHE: LazyLock<RwLock<HashMap<String, (String, SpecMetadata)>>> =
@@ -243,7 +247,7 @@ pub async fn create_project_spec(
         )
     })?;
 
-    let doc = adapter.get(spec_name).map_err(adapter_error)?;
+    let doc = adapter.get(spec_name).await.map_err(adapter_error)?;
     let mut detail =
         SpecDetail::from_doc(&doc, adapter.schema()).with_project_id(project.id.clone());
     if let Some(path) = resolve_markdown_spec_path(&project.specs_dir, spec_name) {
@@ -256,54 +260,99 @@ pub async fn create_project_spec(
 pub async fn update_project_spec_raw(
     State(state): State<AppState>,
     Path((project_id, spec_id)): Path<(String, String)>,
+    headers: HeaderMap,
     Json(request): Json<SpecRawUpdateRequest>,
 ) -> ApiResult<Json<SpecRawResponse>> {
     let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
     require_markdown_adapter(adapter.as_ref())?;
 
-    adapter.get(&spec_id).map_err(adapter_error)?;
-
-    let file_path = resolve_markdown_spec_path(&project.specs_dir, &spec_id).ok_or_else(|| {
-        (
-            StatusCode::NOT_FOUND,
-            Json(ApiError::spec_not_found(&spec_id)),
-        )
-    })?;
-
-    let current = fs::read_to_string(&file_path).map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiError::internal_error(&e.to_string())),
-        )
-    })?;
-    let current_hash = hash_raw_content(&current);
-
-    if let Some(expected) = &request.expected_content_hash {
-        if expected != &current_hash {
-            return Err((
-                StatusCode::CONFLICT,
-                Json(ApiError::invalid_request("Content hash mismatch").with_details(current_hash)),
-            ));
-        }
-    }
-
-    fs::write(&file_path, &request.content).map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiError::internal_error(&e.to_string())),
+    let expected_version = if_match(&headers);
+    let specs_dir = project.specs_dir.clone();
+    let project_path = project.path.clone();
+    let content = request.content.clone();
+    let file_path = run_blocking(move || {
+        let review = load_project_config(&project_path)
+            .map(|c| c.review)
+            .unwrap_or_default();
+        write_raw_locked(
+            &specs_dir,
+            &spec_id,
+            "README.md",
+            expected_version.as_deref(),
+            Some(&review),
+            &request,
         )
-    })?;
-
-    invalidate_markdown_cache(&project.specs_dir, &file_path);
+    })
+    .await?;
 
-    let new_hash = hash_raw_content(&request.content);
+    let new_hash = hash_raw_content(&content);
     Ok(Json(SpecRawResponse {
-        content: request.content,
+        content,
         content_hash: new_hash,
         file_path: file_path.to_string_lossy().to_string(),
     }))
 }
 
+/// Replace `file` in the directory of `spec_id` with `request.content` while
+/// holding the spec's lock, so the `If-Match` version, content-hash and
+/// `review` gate checks and the write happen as one step.
+fn write_raw_locked(
+    specs_dir: &FsPath,
+    spec_id: &str,
+    file: &str,
+    expected_version: Option<&str>,
+    review: Option<&ReviewConfig>,
+    request: &SpecRawUpdateRequest,
+) -> Result<std::path::PathBuf, (StatusCode, Json<ApiError>)> {
+    let markdown = MarkdownAdapter::new(specs_dir);
+    markdown
+        .with_spec_lock(spec_id, |spec_dir| {
+            let doc = Adapter::get(&markdown, spec_id).map_err(adapter_error)?;
+            check_version(expected_version, &doc).map_err(adapter_error)?;
+            if let Some(config) = review {
+                let spec = markdown.load_spec_info(spec_id).map_err(adapter_error)?;
+                check_raw_review_gate(&spec, &request.content, config)?;
+            }
+
+            let file_path = spec_dir.join(file);
+            if !file_path.exists() {
+                return Err((
+                    StatusCode::NOT_FOUND,
+                    Json(ApiError::invalid_request("Sub-spec not found")),
+                ));
+            }
+            let current = fs::read_to_string(&file_path).map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::internal_error(&e.to_string())),
+                )
+            })?;
+            let current_hash = hash_raw_content(&current);
+
+            if let Some(expected) = &request.expected_content_hash {
+                if expected != &current_hash {
+                    return Err((
+                        StatusCode::CONFLICT,
+                        Json(
+                            ApiError::invalid_request("Content hash mismatch")
+                                .with_details(current_hash),
+                        ),
+                    ));
+                }
+            }
+
+            atomic_write(&file_path, &request.content).map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::internal_error(&e.to_string())),
+                )
+            })?;
+            markdown.invalidate_path(&file_path);
+            Ok(file_path)
+        })
+        .map_err(adapter_error)?
+}
+
 /// POST /api/projects/:projectId/specs/:spec/checklist-toggle - Toggle checklist items
 ///
 /// Main-spec toggles run the fetch-transform-push pattern through the adapter
@@ -314,10 +363,11 @@ pub async fn update_project_spec_raw(
 ///
 /// Sub-spec toggles fall back to direct file I/O and require the markdown
 /// adapter — sub-specs are extra files inside the spec directory and aren't
-/// modelled by the adapter API.
+/// modelled by the adapter API. `If-Match` only guards main-spec toggles.
 pub async fn toggle_project_spec_checklist(
     State(state): State<AppState>,
     Path((project_id, spec_id)): Path<(String, String)>,
+    headers: HeaderMap,
     Json(request): Json<ChecklistToggleRequest>,
 ) -> ApiResult<Json<ChecklistToggleResponse>> {
     let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
@@ -342,11 +392,12 @@ pub async fn toggle_project_spec_checklist(
             subspec_file,
             request.expected_content_hash.as_deref(),
             &toggles,
-        );
+        )
+        .await;
     }
 
     // Main-spec path: fetch-transform-push through the adapter.
-    let doc = adapter.get(&spec_id).map_err(adapter_error)?;
+    let doc = adapter.get(&spec_id).await.map_err(adapter_error)?;
     let body = doc
         .fields
         .get("content")
@@ -371,9 +422,13 @@ pub async fn toggle_project_spec_checklist(
     req_fields.insert("content".into(), FieldValue::String(updated_body.clone()));
     let update = UpdateRequest {
         fields: req_fields,
+        expected_version: if_match(&headers),
         ..Default::default()
     };
-    adapter.update(&spec_id, &update).map_err(adapter_error)?;
+    adapter
+        .update(&spec_id, &update)
+        .await
+        .map_err(adapter_error)?;
 
     let new_hash = hash_content(&updated_body);
 
@@ -392,8 +447,8 @@ pub async fn toggle_project_spec_checklist(
 }
 
 /// Sub-spec checklist toggle — markdown-only direct file I/O.
-fn toggle_subspec_checklist(
-    adapter: &dyn leanspec_core::adapters::Adapter,
+async fn toggle_subspec_checklist(
+    adapter: &dyn AsyncAdapter,
     specs_dir: &FsPath,
     spec_id: &str,
     subspec_file: &str,
@@ -407,7 +462,7 @@ fn toggle_subspec_checklist(
         ));
     }
     // Adapter.get confirms the parent spec exists.
-    adapter.get(spec_id).map_err(adapter_error)?;
+    adapter.get(spec_id).await.map_err(adapter_error)?;
 
     let spec_readme = resolve_markdown_spec_path(specs_dir, spec_id).ok_or_else(|| {
         (
@@ -477,6 +532,7 @@ fn toggle_subspec_checklist(
 pub async fn update_project_subspec_raw(
     State(state): State<AppState>,
     Path((project_id, spec_id, file)): Path<(String, String, String)>,
+    headers: HeaderMap,
     Json(request): Json<SpecRawUpdateRequest>,
 ) -> ApiResult<Json<SpecRawResponse>> {
     if file.contains('/') || file.contains('\\') {
@@ -489,61 +545,105 @@ pub async fn update_project_subspec_raw(
     let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
     require_markdown_adapter(adapter.as_ref())?;
 
-    adapter.get(&spec_id).map_err(adapter_error)?;
-
-    let spec_readme =
-        resolve_markdown_spec_path(&project.specs_dir, &spec_id).ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ApiError::spec_not_found(&spec_id)),
-            )
-        })?;
-    let parent_dir = spec_readme.parent().ok_or_else(|| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiError::internal_error("Missing spec directory")),
+    let expected_version = if_match(&headers);
+    let specs_dir = project.specs_dir.clone();
+    let content = request.content.clone();
+    let file_path = run_blocking(move || {
+        write_raw_locked(
+            &specs_dir,
+            &spec_id,
+            &file,
+            expected_version.as_deref(),
+            None,
+            &request,
         )
-    })?;
-    let file_path = parent_dir.join(&file);
+    })
+    .await?;
 
-    if !file_path.exists() {
+    let new_hash = hash_raw_content(&content);
+    Ok(Json(SpecRawResponse {
+        content,
+        content_hash: new_hash,
+        file_path: file_path.to_string_lossy().to_string(),
+    }))
+}
+
+/// POST /api/projects/:projectId/specs/:spec/move - Rename or renumber a spec
+///
+/// Renames the spec directory and rewrites references to it in every
+/// markdown file of the project, all-or-nothing. With `dryRun` the
+/// rewritten lines are returned without touching the disk.
+pub async fn move_project_spec(
+    State(state): State<AppState>,
+    Path((project_id, spec_id)): Path<(String, String)>,
+    Json(request): Json<MoveSpecRequest>,
+) -> ApiResult<Json<MoveSpecResponse>> {
+    let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
+    require_markdown_adapter(adapter.as_ref())?;
+
+    if request.new_name.is_none() && request.number.is_none() {
         return Err((
-            StatusCode::NOT_FOUND,
-            Json(ApiError::invalid_request("Sub-spec not found")),
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::invalid_request(
+                "Either newName or number is required",
+            )),
         ));
     }
-
-    let current = fs::read_to_string(&file_path).map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiError::internal_error(&e.to_string())),
-        )
-    })?;
-    let current_hash = hash_raw_content(&current);
-
-    if let Some(expected) = &request.expected_content_hash {
-        if expected != &current_hash {
-            return Err((
-                StatusCode::CONFLICT,
-                Json(ApiError::invalid_request("Content hash mismatch").with_details(current_hash)),
-            ));
-        }
+    if invalid_spec_id(&spec_id) || request.new_name.as_deref().is_some_and(invalid_spec_id) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::invalid_request("Invalid spec name")),
+        ));
     }
 
-    fs::write(&file_path, &request.content).map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiError::internal_error(&e.to_string())),
-        )
-    })?;
-
-    invalidate_markdown_cache(&project.specs_dir, &file_path);
+    let doc = adapter.get(&spec_id).await.map_err(adapter_error)?;
+    let file_path = project.specs_dir.join(&doc.id).join("README.md");
+    let info = doc_to_spec_info(&doc, file_path, None);
+    let target = info.moved_name(request.new_name.as_deref(), request.number);
+
+    // Rewriting references reads and writes every markdown file of the
+    // project, so the changeset runs on the blocking pool.
+    let specs_dir = project.specs_dir.clone();
+    let (from, to) = (info.path.clone(), target.clone());
+    let dry_run = request.dry_run;
+    let files = run_blocking(move || {
+        let mut changeset = Changeset::new(&specs_dir);
+        changeset.move_spec(&from, &to).map_err(changeset_error)?;
+
+        let files = changeset
+            .diff()
+            .into_iter()
+            .map(|diff| MovedFile {
+                path: diff
+                    .path
+                    .strip_prefix(&specs_dir)
+                    .unwrap_or(&diff.path)
+                    .to_string_lossy()
+                    .to_string(),
+                changes: diff
+                    .changes
+                    .into_iter()
+                    .map(|c| MovedLine {
+                        line: c.line,
+                        before: c.before,
+                        after: c.after,
+                    })
+                    .collect(),
+            })
+            .collect();
 
-    let new_hash = hash_raw_content(&request.content);
-    Ok(Json(SpecRawResponse {
-        content: request.content,
-        content_hash: new_hash,
-        file_path: file_path.to_string_lossy().to_string(),
+        if !dry_run {
+            changeset.commit().map_err(changeset_error)?;
+        }
+        Ok(files)
+    })
+    .await?;
+
+    Ok(Json(MoveSpecResponse {
+        from: info.path,
+        to: target,
+        dry_run: request.dry_run,
+        files,
     }))
 }
 
@@ -551,12 +651,18 @@ pub async fn update_project_subspec_raw(
 pub async fn update_project_metadata(
     State(state): State<AppState>,
     Path((project_id, spec_id)): Path<(String, String)>,
+    headers: HeaderMap,
     Json(updates): Json<MetadataUpdate>,
 ) -> ApiResult<Json<UpdateMetadataResponse>> {
-    let (adapter, _project) = get_adapter_and_project(&state, &project_id).await?;
+    let (adapter, project) = get_adapter_and_project(&state, &project_id).await?;
     let schema = adapter.schema();
 
-    let current_doc = adapter.get(&spec_id).map_err(adapter_error)?;
+    let current_doc = adapter.get(&spec_id).await.map_err(adapter_error)?;
+
+    // The header wins over the body field; both carry the `version` token
+    // returned by the read endpoints.
+    let expected_version = if_match(&headers).or_else(|| updates.expected_version.clone());
+    check_version(expected_version.as_deref(), &current_doc).map_err(adapter_error)?;
 
     if let Some(expected_hash) = &updates.expected_content_hash {
         let content = current_doc
@@ -597,12 +703,20 @@ pub async fn update_project_metadata(
                     ));
                 }
 
+                if current_status_str == "planned"
+                    && status_str == "in-progress"
+                    && !updates.force.unwrap_or(false)
+                {
+                    check_review_gate(&project, &spec_id).await?;
+                }
+
                 if status_str == "complete" && !updates.force.unwrap_or(false) {
                     let all_docs = adapter
                         .list(&ListFilter {
                             include_archived: true,
                             ..Default::default()
                         })
+                        .await
                         .map_err(adapter_error)?;
                     let umbrella = umbrella_completion_for_docs(&spec_id, &all_docs);
                     if !umbrella.is_complete {
@@ -718,14 +832,19 @@ pub async fn update_project_metadata(
         fields: req_fields,
         clear: Vec::new(),
         replace_links,
+        expected_version,
     };
 
-    let updated = adapter.update(&spec_id, &update).map_err(adapter_error)?;
+    let updated = adapter
+        .update(&spec_id, &update)
+        .await
+        .map_err(adapter_error)?;
 
     Ok(Json(UpdateMetadataResponse {
         success: true,
         spec_id: spec_id.clone(),
         frontmatter: FrontmatterResponse::from_doc(&updated, schema),
+        version: updated.version.clone(),
     }))
 }
 
@@ -742,6 +861,7 @@ pub async fn batch_spec_metadata(
             include_archived: true,
             ..Default::default()
         })
+        .await
         .map_err(adapter_error)?;
     let doc_map: HashMap<String, &SpecDoc> = docs.iter().map(|d| (d.id.clone(), d)).collect();
 
@@ -767,7 +887,11 @@ pub async fn batch_spec_metadata(
         if let Ok(cache) = BATCH_METADATA_CACHE.read() {
             if let Some((cached_hash, cached_metadata)) = cache.get(&cache_key) {
                 if cached_hash == &content_hash {
-                    result.insert(spec_name.clone(), cached_metadata.clone());
+                    // The cache is keyed on the body; the version also covers
+                    // frontmatter, so always take it from the fresh doc.
+                    let mut metadata = cached_metadata.clone();
+                    metadata.version = doc.version.clone();
+                    result.insert(spec_name.clone(), metadata);
                     continue;
                 }
             }
@@ -815,6 +939,7 @@ pub async fn batch_spec_metadata(
             token_count: total,
             token_status: token_status_str.to_string(),
             validation_status: validation_status_str.to_string(),
+            version: doc.version.clone(),
         };
 
         result.insert(spec_name.clone(), metadata.clone());
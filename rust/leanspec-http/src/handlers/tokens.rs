@@ -0,0 +1,85 @@
+//! API token management handlers
+#![allow(clippy::result_large_err)]
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+
+use crate::config::TokenRole;
+use crate::error::{ApiError, ApiResult, ServerError};
+use crate::state::AppState;
+use crate::types::{ApiTokenInfo, CreateTokenRequest, CreateTokenResponse};
+
+/// GET /api/tokens - List API tokens (admin only)
+pub async fn list_tokens(State(state): State<AppState>) -> Json<Vec<ApiTokenInfo>> {
+    let tokens = state.tokens.list().await;
+    Json(tokens.into_iter().map(ApiTokenInfo::from).collect())
+}
+
+/// POST /api/tokens - Create an API token (admin only)
+///
+/// The plaintext token is only returned by this call; the server keeps its
+/// hash.
+pub async fn create_token(
+    State(state): State<AppState>,
+    Json(req): Json<CreateTokenRequest>,
+) -> ApiResult<(StatusCode, Json<CreateTokenResponse>)> {
+    let role: TokenRole = req
+        .role
+        .parse()
+        .map_err(|e: String| (StatusCode::BAD_REQUEST, Json(ApiError::invalid_request(&e))))?;
+    {
+        let registry = state.registry.read().await;
+        if let Some(unknown) = req.projects.iter().find(|id| registry.get(id).is_none()) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::project_not_found(unknown)),
+            ));
+        }
+    }
+
+    let (info, token) = state
+        .tokens
+        .create(&req.name, role, req.projects)
+        .await
+        .map_err(|e| match e {
+            ServerError::ValidationError(message) => (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::invalid_request(&message)),
+            ),
+            e => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::internal_error(&e.to_string())),
+            ),
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateTokenResponse {
+            token,
+            info: info.into(),
+        }),
+    ))
+}
+
+/// DELETE /api/tokens/:name - Revoke an API token (admin only)
+pub async fn revoke_token(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> ApiResult<StatusCode> {
+    let revoked = state.tokens.revoke(&name).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::internal_error(&e.to_string())),
+        )
+    })?;
+
+    if revoked {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError::not_found(&format!("Token '{}'", name))),
+        ))
+    }
+}
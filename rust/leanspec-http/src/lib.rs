@@ -30,6 +30,7 @@ pub mod middleware;
 pub mod project_registry;
 pub mod routes;
 pub mod state;
+pub mod tokens;
 pub mod types;
 pub mod utils;
 pub mod watcher;
@@ -2,7 +2,9 @@
 //!
 //! Command-line binary for running the HTTP server.
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use leanspec_http::config::{config_path, save_config_to_path, TokenRole};
+use leanspec_http::tokens::{create_token, revoke_token};
 use leanspec_http::{
     load_config, load_config_from_path, start_server_with_config, ProjectRegistry, ServerConfig,
 };
@@ -17,6 +19,9 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 #[command(about = "HTTP server for LeanSpec web UI")]
 #[command(version)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<ServerCommand>,
+
     /// Host to bind to
     #[arg(short = 'H', long, default_value = "127.0.0.1", env = "LEANSPEC_HOST")]
     host: String,
@@ -82,10 +87,47 @@ struct Args {
     locale: Option<String>,
 }
 
+#[derive(Subcommand, Debug)]
+enum ServerCommand {
+    /// Manage named API tokens in the config file
+    Tokens {
+        #[command(subcommand)]
+        action: TokensCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum TokensCommand {
+    /// Create a token and print its secret (shown only once)
+    Create {
+        /// Token name
+        name: String,
+
+        /// Role: viewer, editor or admin
+        #[arg(long, default_value = "viewer")]
+        role: TokenRole,
+
+        /// Limit the token to a project id (repeatable; default: all projects)
+        #[arg(long = "project")]
+        projects: Vec<String>,
+    },
+    /// List configured tokens
+    List,
+    /// Revoke a token by name
+    Revoke {
+        /// Token name
+        name: String,
+    },
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
+    if let Some(ServerCommand::Tokens { action }) = args.command {
+        return run_tokens_command(args.config.unwrap_or_else(config_path), action);
+    }
+
     // Initialize tracing with improved configuration for dev experience
     let is_dev_mode = std::env::var("LEANSPEC_DEV_MODE").is_ok();
     let is_debug_mode = std::env::var("LEANSPEC_DEBUG").is_ok();
@@ -244,6 +286,61 @@ fn load_server_config(args: &Args) -> ServerConfig {
     load_config().unwrap_or_default()
 }
 
+/// Edit `security.tokens` in the config file. A running server picks up
+/// changes on restart; tokens created through `/api/tokens` apply at once.
+fn run_tokens_command(
+    path: PathBuf,
+    action: TokensCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = load_config_from_path(&path)?;
+
+    match action {
+        TokensCommand::Create {
+            name,
+            role,
+            projects,
+        } => {
+            let token = create_token(&mut config.security.tokens, &name, role, projects)?;
+            save_config_to_path(&config, &path)?;
+            println!("Created token '{}' in {}", name, path.display());
+            println!("{}", token);
+            println!("Store it now; it cannot be shown again.");
+        }
+        TokensCommand::List => {
+            if config.security.tokens.is_empty() {
+                println!("No API tokens configured in {}", path.display());
+            }
+            for token in &config.security.tokens {
+                let scope = if token.projects.is_empty() {
+                    "all projects".to_string()
+                } else {
+                    token.projects.join(", ")
+                };
+                let created = token
+                    .created_at
+                    .map(|at| at.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default();
+                println!(
+                    "{:<24} {:<8} {:<32} {}",
+                    token.name,
+                    token.role.as_str(),
+                    scope,
+                    created
+                );
+            }
+        }
+        TokensCommand::Revoke { name } => {
+            if !revoke_token(&mut config.security.tokens, &name) {
+                return Err(format!("No token named '{}'", name).into());
+            }
+            save_config_to_path(&config, &path)?;
+            println!("Revoked token '{}'", name);
+        }
+    }
+
+    Ok(())
+}
+
 fn open_browser(url: &str, browser: Option<&str>) {
     if let Some(browser) = browser {
         let browser = browser.trim();
@@ -1,27 +1,190 @@
-//! API key authentication middleware
+//! API authentication and authorization middleware
 //!
-//! When `LEANSPEC_API_KEY` is set, all `/api/*` requests must include
-//! a matching `Authorization: Bearer <token>` header. Health endpoints
-//! are always exempt so orchestrators can probe liveness/readiness.
+//! `/api/*` requests must include an `Authorization: Bearer <token>` header
+//! once either `LEANSPEC_API_KEY` is set or named tokens are configured in
+//! `security.tokens`. The legacy key acts as an unscoped admin token. Named
+//! tokens carry a role and optional project scopes, checked against the
+//! kind of route being called. Health endpoints are always exempt so
+//! orchestrators can probe liveness/readiness.
 
 use axum::{
     body::Body,
-    http::{Request, StatusCode},
+    extract::State,
+    http::{Method, Request, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
 
+use crate::config::{ApiToken, TokenRole};
+use crate::state::AppState;
+
 /// Paths that bypass authentication (health probes).
 const AUTH_EXEMPT_PREFIXES: &[&str] = &["/health"];
 
-/// Middleware that enforces bearer-token authentication when `LEANSPEC_API_KEY`
-/// is configured. Uses constant-time comparison to prevent timing attacks.
-pub async fn api_key_auth(req: Request<Body>, next: Next) -> Response {
-    let expected = match std::env::var("LEANSPEC_API_KEY") {
-        Ok(key) if !key.is_empty() => key,
-        _ => return next.run(req).await, // No key configured — pass through
+/// POST endpoints under a project that only read data
+const READ_ONLY_POSTS: &[&str] = &[
+    "specs/batch-metadata",
+    "specs/similar",
+    "search",
+    "validate",
+];
+
+/// What a route does, from least to most privileged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    /// Read specs and project data
+    Read,
+    /// Create or edit specs
+    Write,
+    /// Push commits to a git remote
+    GitPush,
+    /// Add, update or remove projects
+    ManageProjects,
+    /// Create, list or revoke API tokens
+    ManageTokens,
+}
+
+impl Access {
+    /// Least privileged role allowed to perform this access
+    pub fn min_role(self) -> TokenRole {
+        match self {
+            Access::Read => TokenRole::Viewer,
+            Access::Write | Access::GitPush => TokenRole::Editor,
+            Access::ManageProjects | Access::ManageTokens => TokenRole::Admin,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Access::Read => "read",
+            Access::Write => "modify specs",
+            Access::GitPush => "push to git",
+            Access::ManageProjects => "manage projects",
+            Access::ManageTokens => "manage API tokens",
+        }
+    }
+}
+
+/// The access a request needs and the project it targets, if any
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteAccess {
+    pub access: Access,
+    pub project: Option<String>,
+}
+
+/// Classify an `/api` request by method and path
+pub fn classify(method: &Method, path: &str) -> RouteAccess {
+    let segments: Vec<&str> = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+    let safe = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+
+    let (access, project) = match segments.as_slice() {
+        ["api", "tokens", ..] => (Access::ManageTokens, None),
+        ["api", "projects", "refresh"] | ["api", "projects"] if !safe => {
+            (Access::ManageProjects, None)
+        }
+        ["api", "projects", id, rest @ ..] => {
+            let access = if safe || READ_ONLY_POSTS.contains(&rest.join("/").as_str()) {
+                Access::Read
+            } else if rest.is_empty() || rest == ["favorite"] {
+                Access::ManageProjects
+            } else {
+                Access::Write
+            };
+            (access, Some(id.to_string()))
+        }
+        ["api", "git", "detect" | "import"] => (Access::ManageProjects, None),
+        ["api", "git", op, id, rest @ ..] => {
+            let access = match (*op, rest) {
+                _ if safe => Access::Read,
+                ("push" | "integrate", []) | ("pending", ["flush"]) => Access::GitPush,
+                _ => Access::Write,
+            };
+            (access, Some(id.to_string()))
+        }
+        // Browses the server's filesystem
+        ["api", "local-projects", ..] => (Access::ManageProjects, None),
+        _ if safe => (Access::Read, None),
+        _ => (Access::Write, None),
     };
 
+    RouteAccess { access, project }
+}
+
+/// The authenticated caller, available to handlers as a request extension
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    pub name: String,
+    pub role: TokenRole,
+    /// Projects the caller may access; empty means every project
+    pub projects: Vec<String>,
+}
+
+impl Principal {
+    /// Principal for the legacy `LEANSPEC_API_KEY`
+    fn api_key() -> Self {
+        Self {
+            name: "LEANSPEC_API_KEY".to_string(),
+            role: TokenRole::Admin,
+            projects: Vec::new(),
+        }
+    }
+
+    /// Whether the caller may see the given project
+    pub fn can_access(&self, project_id: &str) -> bool {
+        self.projects.is_empty() || self.projects.iter().any(|p| p == project_id)
+    }
+
+    /// Check a classified request, returning the reason it is refused
+    pub fn authorize(&self, route: &RouteAccess) -> Result<(), String> {
+        if self.role < route.access.min_role() {
+            return Err(format!(
+                "Token '{}' ({}) is not allowed to {}",
+                self.name,
+                self.role.as_str(),
+                route.access.describe()
+            ));
+        }
+        match &route.project {
+            Some(id) if !self.can_access(id) => Err(format!(
+                "Token '{}' is not allowed to access project '{}'",
+                self.name, id
+            )),
+            None if !self.projects.is_empty()
+                && matches!(route.access, Access::ManageProjects | Access::ManageTokens) =>
+            {
+                Err(format!(
+                    "Token '{}' is limited to specific projects and cannot {}",
+                    self.name,
+                    route.access.describe()
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<ApiToken> for Principal {
+    fn from(token: ApiToken) -> Self {
+        Self {
+            name: token.name,
+            role: token.role,
+            projects: token.projects,
+        }
+    }
+}
+
+/// Middleware that enforces bearer-token authentication when
+/// `LEANSPEC_API_KEY` or named tokens are configured, then checks the
+/// token's role and project scopes against the route.
+pub async fn api_key_auth(
+    State(state): State<AppState>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
     let path = req.uri().path();
 
     // Skip auth for health endpoints
@@ -37,24 +200,42 @@ pub async fn api_key_auth(req: Request<Body>, next: Next) -> Response {
         return next.run(req).await;
     }
 
+    let api_key = std::env::var("LEANSPEC_API_KEY")
+        .ok()
+        .filter(|key| !key.is_empty());
+    if api_key.is_none() && state.tokens.is_empty().await {
+        return next.run(req).await; // Nothing configured — pass through
+    }
+
     // Extract bearer token
     let token = req
         .headers()
         .get("authorization")
         .and_then(|v| v.to_str().ok())
         .and_then(|v| v.strip_prefix("Bearer "));
+    let Some(token) = token else {
+        return (StatusCode::UNAUTHORIZED, "Missing Authorization header").into_response();
+    };
 
-    match token {
-        Some(token) if constant_time_eq(token.as_bytes(), expected.as_bytes()) => {
-            next.run(req).await
-        }
-        Some(_) => (StatusCode::UNAUTHORIZED, "Invalid API key").into_response(),
-        None => (StatusCode::UNAUTHORIZED, "Missing Authorization header").into_response(),
+    let principal = match api_key {
+        Some(key) if constant_time_eq(token.as_bytes(), key.as_bytes()) => Principal::api_key(),
+        _ => match state.tokens.authenticate(token).await {
+            Some(token) => token.into(),
+            None => return (StatusCode::UNAUTHORIZED, "Invalid API key").into_response(),
+        },
+    };
+
+    let route = classify(req.method(), path);
+    if let Err(reason) = principal.authorize(&route) {
+        return (StatusCode::FORBIDDEN, reason).into_response();
     }
+
+    req.extensions_mut().insert(principal);
+    next.run(req).await
 }
 
 /// Constant-time byte comparison to prevent timing attacks.
-fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
     if a.len() != b.len() {
         return false;
     }
@@ -69,6 +250,125 @@ fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
 mod tests {
     use super::*;
 
+    fn principal(role: TokenRole, projects: &[&str]) -> Principal {
+        Principal {
+            name: "test".to_string(),
+            role,
+            projects: projects.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    fn route(method: Method, path: &str) -> RouteAccess {
+        classify(&method, path)
+    }
+
+    #[test]
+    fn classify_routes() {
+        let cases = [
+            (Method::GET, "/api/projects", Access::Read, None),
+            (Method::POST, "/api/projects", Access::ManageProjects, None),
+            (
+                Method::POST,
+                "/api/projects/refresh",
+                Access::ManageProjects,
+                None,
+            ),
+            (
+                Method::DELETE,
+                "/api/projects/p1",
+                Access::ManageProjects,
+                Some("p1"),
+            ),
+            (
+                Method::POST,
+                "/api/projects/p1/specs",
+                Access::Write,
+                Some("p1"),
+            ),
+            (
+                Method::POST,
+                "/api/projects/p1/search",
+                Access::Read,
+                Some("p1"),
+            ),
+            (
+                Method::POST,
+                "/api/projects/p1/specs/similar",
+                Access::Read,
+                Some("p1"),
+            ),
+            (Method::GET, "/api/git/status/p1", Access::Read, Some("p1")),
+            (Method::POST, "/api/git/sync/p1", Access::Write, Some("p1")),
+            (
+                Method::POST,
+                "/api/git/push/p1",
+                Access::GitPush,
+                Some("p1"),
+            ),
+            (
+                Method::POST,
+                "/api/git/pending/p1/flush",
+                Access::GitPush,
+                Some("p1"),
+            ),
+            (
+                Method::POST,
+                "/api/git/import",
+                Access::ManageProjects,
+                None,
+            ),
+            (Method::GET, "/api/tokens", Access::ManageTokens, None),
+            (
+                Method::POST,
+                "/api/local-projects/discover",
+                Access::ManageProjects,
+                None,
+            ),
+        ];
+        for (method, path, access, project) in cases {
+            let expected = RouteAccess {
+                access,
+                project: project.map(str::to_string),
+            };
+            assert_eq!(route(method, path), expected, "{}", path);
+        }
+    }
+
+    #[test]
+    fn roles_build_on_each_other() {
+        let write = route(Method::PATCH, "/api/projects/p1/specs/001/raw");
+        let push = route(Method::POST, "/api/git/push/p1");
+        let manage = route(Method::DELETE, "/api/projects/p1");
+
+        assert!(principal(TokenRole::Viewer, &[]).authorize(&write).is_err());
+        assert!(principal(TokenRole::Editor, &[]).authorize(&write).is_ok());
+        assert!(principal(TokenRole::Editor, &[]).authorize(&push).is_ok());
+        assert!(principal(TokenRole::Editor, &[])
+            .authorize(&manage)
+            .is_err());
+        assert!(principal(TokenRole::Admin, &[]).authorize(&manage).is_ok());
+    }
+
+    #[test]
+    fn project_scopes_are_enforced() {
+        let scoped = principal(TokenRole::Admin, &["p1"]);
+        assert!(scoped
+            .authorize(&route(Method::GET, "/api/projects/p1/specs"))
+            .is_ok());
+        assert!(scoped
+            .authorize(&route(Method::GET, "/api/projects/p2/specs"))
+            .is_err());
+        assert!(scoped
+            .authorize(&route(Method::POST, "/api/projects"))
+            .is_err());
+        assert!(scoped
+            .authorize(&route(Method::GET, "/api/tokens"))
+            .is_err());
+        assert!(scoped
+            .authorize(&route(Method::GET, "/api/projects"))
+            .is_ok());
+    }
+
     #[test]
     fn constant_time_eq_matches() {
         assert!(constant_time_eq(b"secret", b"secret"));
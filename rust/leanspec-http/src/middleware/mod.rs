@@ -2,4 +2,4 @@
 
 pub mod auth;
 
-pub use auth::api_key_auth;
+pub use auth::{api_key_auth, Principal};
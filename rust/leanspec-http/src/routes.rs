@@ -105,6 +105,10 @@ pub fn create_router(state: AppState) -> Router {
             "/api/projects/{id}/specs/batch-metadata",
             post(handlers::batch_spec_metadata),
         )
+        .route(
+            "/api/projects/{id}/specs/similar",
+            post(handlers::find_similar_specs),
+        )
         .route(
             "/api/projects/{id}/specs/{spec}/raw",
             get(handlers::get_project_spec_raw),
@@ -117,6 +121,10 @@ pub fn create_router(state: AppState) -> Router {
             "/api/projects/{id}/specs/{spec}/checklist-toggle",
             post(handlers::toggle_project_spec_checklist),
         )
+        .route(
+            "/api/projects/{id}/specs/{spec}/move",
+            post(handlers::move_project_spec),
+        )
         .route(
             "/api/projects/{id}/specs/{spec}/subspecs/{file}/raw",
             get(handlers::get_project_subspec_raw),
@@ -130,6 +138,10 @@ pub fn create_router(state: AppState) -> Router {
             get(handlers::get_project_dependencies),
         )
         .route("/api/projects/{id}/stats", get(handlers::get_project_stats))
+        .route(
+            "/api/projects/{id}/workload",
+            get(handlers::get_project_workload),
+        )
         .route(
             "/api/projects/{id}/validate",
             post(handlers::validate_project),
@@ -146,6 +158,26 @@ pub fn create_router(state: AppState) -> Router {
             "/api/projects/{id}/specs/{spec}/metadata",
             patch(handlers::update_project_metadata),
         )
+        .route(
+            "/api/projects/{id}/specs/{spec}/comments",
+            get(handlers::list_spec_comments),
+        )
+        .route(
+            "/api/projects/{id}/specs/{spec}/comments",
+            post(handlers::add_spec_comment),
+        )
+        .route(
+            "/api/projects/{id}/specs/{spec}/comments/{comment}",
+            patch(handlers::update_spec_comment),
+        )
+        .route(
+            "/api/projects/{id}/specs/{spec}/review",
+            get(handlers::get_spec_review).post(handlers::submit_spec_review),
+        )
+        .route(
+            "/api/projects/{id}/specs/{spec}/review/request",
+            post(handlers::request_spec_review),
+        )
         // File browsing routes (codebase viewer)
         .route(
             "/api/projects/{id}/files",
@@ -164,6 +196,29 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/git/sync/{id}", post(handlers::git_sync_project))
         .route("/api/git/push/{id}", post(handlers::git_push_project))
         .route("/api/git/status/{id}", get(handlers::git_status_project))
+        .route(
+            "/api/git/branches/{id}",
+            get(handlers::git_list_branches).post(handlers::git_create_spec_branch),
+        )
+        .route(
+            "/api/git/checkout/{id}",
+            post(handlers::git_checkout_branch),
+        )
+        .route(
+            "/api/git/integrate/{id}",
+            post(handlers::git_integrate_branch),
+        )
+        .route("/api/git/pending/{id}", get(handlers::git_pending_changes))
+        .route(
+            "/api/git/pending/{id}/flush",
+            post(handlers::git_flush_pending),
+        )
+        // API token management
+        .route(
+            "/api/tokens",
+            get(handlers::list_tokens).post(handlers::create_token),
+        )
+        .route("/api/tokens/{name}", delete(handlers::revoke_token))
         // Local project routes
         .route(
             "/api/local-projects/discover",
@@ -220,7 +275,10 @@ pub fn create_router(state: AppState) -> Router {
                     },
                 ),
         )
-        .layer(axum_mw::from_fn(middleware::api_key_auth))
+        .layer(axum_mw::from_fn_with_state(
+            state.clone(),
+            middleware::api_key_auth,
+        ))
         .layer(axum_mw::from_fn_with_state(state, readonly_guard))
         .layer(axum_mw::from_fn(log_error_body))
 }
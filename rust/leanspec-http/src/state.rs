@@ -2,12 +2,14 @@
 //!
 //! Shared state for the HTTP server using Arc for thread-safety.
 
-use crate::adapter_resolution::find_adapter_config;
+use crate::adapter_resolution::{find_adapter_config, AdapterCache};
 use crate::config::ServerConfig;
 use crate::error::ServerError;
 use crate::project_registry::{Project, ProjectRegistry};
+use crate::tokens::TokenStore;
 use crate::watcher::{
-    sse_connection_limit, watch_debounce, watch_enabled, FileWatcher, MarkdownWatchTarget,
+    impact_mark_review, sse_connection_limit, watch_debounce, watch_enabled, FileWatcher,
+    MarkdownWatchTarget,
 };
 use leanspec_core::adapters::AdapterRegistry;
 use std::path::{Path, PathBuf};
@@ -28,6 +30,12 @@ pub struct AppState {
 
     /// SSE connection limiter
     pub sse_connections: Arc<Semaphore>,
+
+    /// Resolved adapters, reused across requests
+    pub adapters: Arc<AdapterCache>,
+
+    /// Named API tokens from `security.tokens`
+    pub tokens: Arc<TokenStore>,
 }
 
 impl AppState {
@@ -51,10 +59,12 @@ impl AppState {
         let sse_connections = Arc::new(Semaphore::new(sse_connection_limit()));
 
         Ok(Self {
+            tokens: Arc::new(token_store(&config)),
             config: Arc::new(config),
             registry: Arc::new(RwLock::new(registry)),
             file_watcher,
             sse_connections,
+            adapters: Arc::new(AdapterCache::default()),
         })
     }
 
@@ -63,14 +73,22 @@ impl AppState {
         let file_watcher = build_file_watcher(&registry);
         let sse_connections = Arc::new(Semaphore::new(sse_connection_limit()));
         Self {
+            tokens: Arc::new(token_store(&config)),
             config: Arc::new(config),
             registry: Arc::new(RwLock::new(registry)),
             file_watcher,
             sse_connections,
+            adapters: Arc::new(AdapterCache::default()),
         }
     }
 }
 
+/// Seed the token store from the config, persisting changes to the file it
+/// was loaded from.
+fn token_store(config: &ServerConfig) -> TokenStore {
+    TokenStore::new(config.security.tokens.clone(), config.source.clone())
+}
+
 /// Build a file watcher restricted to projects whose adapter is markdown.
 fn build_file_watcher(registry: &ProjectRegistry) -> Option<Arc<FileWatcher>> {
     if !watch_enabled() {
@@ -88,7 +106,7 @@ fn build_file_watcher(registry: &ProjectRegistry) -> Option<Arc<FileWatcher>> {
         return None;
     }
 
-    match FileWatcher::new(targets, watch_debounce()) {
+    match FileWatcher::new(targets, watch_debounce(), impact_mark_review()) {
         Ok(watcher) => Some(Arc::new(watcher)),
         Err(err) => {
             tracing::warn!("Failed to initialize spec watcher: {}", err);
@@ -0,0 +1,180 @@
+//! Named API tokens
+//!
+//! Tokens live in the server config under `security.tokens`. Only the
+//! SHA-256 hash of each secret is stored; the plaintext is shown once when
+//! the token is created. The store keeps the in-memory list used by the
+//! auth middleware and writes changes back to the config file the server
+//! was started with.
+
+use crate::config::{load_config_from_path, save_config_to_path, ApiToken, TokenRole};
+use crate::error::ServerError;
+use crate::middleware::auth::constant_time_eq;
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+/// Prefix that makes leaked tokens easy to recognise in logs and scanners
+const TOKEN_PREFIX: &str = "lst_";
+
+/// Generate a new random token secret
+pub fn generate_token() -> String {
+    format!(
+        "{}{}{}",
+        TOKEN_PREFIX,
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// Hex-encoded SHA-256 of a token secret, as stored in the config
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Add a token to `tokens` and return its plaintext secret
+pub fn create_token(
+    tokens: &mut Vec<ApiToken>,
+    name: &str,
+    role: TokenRole,
+    projects: Vec<String>,
+) -> Result<String, ServerError> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err(ServerError::ValidationError(format!(
+            "Invalid token name '{}' (use letters, digits, '-', '_' or '.')",
+            name
+        )));
+    }
+    if tokens.iter().any(|token| token.name == name) {
+        return Err(ServerError::ValidationError(format!(
+            "A token named '{}' already exists",
+            name
+        )));
+    }
+
+    let secret = generate_token();
+    tokens.push(ApiToken {
+        name: name.to_string(),
+        token_hash: hash_token(&secret),
+        role,
+        projects,
+        created_at: Some(Utc::now()),
+    });
+    Ok(secret)
+}
+
+/// Remove the token called `name`, returning whether it existed
+pub fn revoke_token(tokens: &mut Vec<ApiToken>, name: &str) -> bool {
+    let before = tokens.len();
+    tokens.retain(|token| token.name != name);
+    tokens.len() != before
+}
+
+/// Runtime view of the configured API tokens
+pub struct TokenStore {
+    tokens: RwLock<Vec<ApiToken>>,
+    /// Config file that token changes are persisted to; `None` keeps them
+    /// in memory only (e.g. `--no-config`)
+    path: Option<PathBuf>,
+}
+
+impl TokenStore {
+    pub fn new(tokens: Vec<ApiToken>, path: Option<PathBuf>) -> Self {
+        Self {
+            tokens: RwLock::new(tokens),
+            path,
+        }
+    }
+
+    /// Whether any named tokens are configured
+    pub async fn is_empty(&self) -> bool {
+        self.tokens.read().await.is_empty()
+    }
+
+    /// Find the token whose hash matches `secret`
+    pub async fn authenticate(&self, secret: &str) -> Option<ApiToken> {
+        let hash = hash_token(secret);
+        self.tokens
+            .read()
+            .await
+            .iter()
+            .find(|token| constant_time_eq(token.token_hash.as_bytes(), hash.as_bytes()))
+            .cloned()
+    }
+
+    pub async fn list(&self) -> Vec<ApiToken> {
+        self.tokens.read().await.clone()
+    }
+
+    /// Create a token and persist it, returning the stored entry and the
+    /// plaintext secret
+    pub async fn create(
+        &self,
+        name: &str,
+        role: TokenRole,
+        projects: Vec<String>,
+    ) -> Result<(ApiToken, String), ServerError> {
+        let mut tokens = self.tokens.write().await;
+        let mut updated = tokens.clone();
+        let secret = create_token(&mut updated, name, role, projects)?;
+        self.persist(&updated)?;
+        let token = updated.last().cloned().expect("token was just added");
+        *tokens = updated;
+        Ok((token, secret))
+    }
+
+    /// Revoke a token and persist the change, returning whether it existed
+    pub async fn revoke(&self, name: &str) -> Result<bool, ServerError> {
+        let mut tokens = self.tokens.write().await;
+        let mut updated = tokens.clone();
+        if !revoke_token(&mut updated, name) {
+            return Ok(false);
+        }
+        self.persist(&updated)?;
+        *tokens = updated;
+        Ok(true)
+    }
+
+    /// Write the token list into the config file, re-reading it first so
+    /// settings edited on disk since startup are kept
+    fn persist(&self, tokens: &[ApiToken]) -> Result<(), ServerError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut config = load_config_from_path(path)?;
+        config.security.tokens = tokens.to_vec();
+        save_config_to_path(&config, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_token_stores_only_the_hash() {
+        let mut tokens = Vec::new();
+        let secret = create_token(&mut tokens, "ci", TokenRole::Editor, vec![]).unwrap();
+        assert!(secret.starts_with(TOKEN_PREFIX));
+        assert_eq!(tokens[0].token_hash, hash_token(&secret));
+        assert_ne!(tokens[0].token_hash, secret);
+    }
+
+    #[test]
+    fn create_token_rejects_duplicate_and_invalid_names() {
+        let mut tokens = Vec::new();
+        create_token(&mut tokens, "ci", TokenRole::Viewer, vec![]).unwrap();
+        assert!(create_token(&mut tokens, "ci", TokenRole::Viewer, vec![]).is_err());
+        assert!(create_token(&mut tokens, "team a", TokenRole::Viewer, vec![]).is_err());
+        assert!(revoke_token(&mut tokens, "ci"));
+        assert!(!revoke_token(&mut tokens, "ci"));
+    }
+}
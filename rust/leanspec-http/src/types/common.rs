@@ -1,5 +1,6 @@
-//! Common API types: configuration, health, context
+//! Common API types: configuration, health, context, API tokens
 
+use crate::config::ApiToken;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -98,3 +99,49 @@ pub struct ProjectContextResponse {
     pub total_tokens: usize,
     pub project_root: String,
 }
+
+/// API token as listed by `/api/tokens` (never includes the secret)
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTokenInfo {
+    pub name: String,
+    /// viewer, editor or admin
+    pub role: String,
+    /// Project ids the token is limited to; empty means every project
+    pub projects: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiToken> for ApiTokenInfo {
+    fn from(token: ApiToken) -> Self {
+        Self {
+            name: token.name,
+            role: token.role.as_str().to_string(),
+            projects: token.projects,
+            created_at: token.created_at,
+        }
+    }
+}
+
+/// Request body for creating an API token
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTokenRequest {
+    pub name: String,
+    /// viewer, editor or admin
+    pub role: String,
+    #[serde(default)]
+    pub projects: Vec<String>,
+}
+
+/// Response for a newly created API token. `token` is only returned here.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTokenResponse {
+    pub token: String,
+    pub info: ApiTokenInfo,
+}
@@ -1,8 +1,12 @@
 //! Spec-related API types for request/response serialization
 
 use chrono::{DateTime, Utc};
+use leanspec_core::adapters::markdown::ReviewDecision;
+use leanspec_core::git::SpecCommit;
 use leanspec_core::io::hash_content;
-use leanspec_core::{global_token_counter, semantic, FieldValue, SpecDoc, SpecSchema, TokenStatus};
+use leanspec_core::{
+    global_token_counter, semantic, FieldValue, GraphLink, SpecDoc, SpecSchema, TokenStatus,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use ts_rs::TS;
@@ -84,6 +88,9 @@ pub struct SpecSummary {
     pub required_by: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_hash: Option<String>,
+    /// Opaque version token to send back as `If-Match` on writes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token_count: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -120,6 +127,7 @@ impl SpecSummary {
             children: Vec::new(),
             required_by: Vec::new(),
             content_hash: Some(hash_content(content)),
+            version: doc.version.clone(),
             token_count: None,
             token_status: None,
             validation_status: None,
@@ -190,9 +198,14 @@ pub struct SpecDetail {
     pub children: Vec<String>,
     #[serde(default)]
     pub required_by: Vec<String>,
+    /// Every typed link of the spec, declared on it or on the other end
+    #[serde(default)]
+    pub links: Vec<SpecLink>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_hash: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub token_count: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token_status: Option<String>,
@@ -202,6 +215,9 @@ pub struct SpecDetail {
     pub relationships: Option<SpecRelationships>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_specs: Option<Vec<SubSpec>>,
+    /// Commits linked to this spec via `spec:` trailers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commits: Option<Vec<SpecCommitInfo>>,
 }
 
 impl SpecDetail {
@@ -231,12 +247,15 @@ impl SpecDetail {
             parent: parent_link(doc),
             children: Vec::new(),
             required_by: Vec::new(),
+            links: Vec::new(),
             content_hash: Some(hash_content(&content)),
+            version: doc.version.clone(),
             token_count: Some(token_result.total),
             token_status: Some(token_status_str(token_result.status).to_string()),
             validation_status: None,
             relationships: None,
             sub_specs: None,
+            commits: None,
         }
     }
 
@@ -364,6 +383,51 @@ pub struct ChecklistToggledResult {
     pub line: usize,
 }
 
+/// Request to rename or renumber a spec
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct MoveSpecRequest {
+    /// New name, with or without a number prefix
+    pub new_name: Option<String>,
+    pub number: Option<u32>,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Response from a spec move (or its dry-run preview)
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct MoveSpecResponse {
+    pub from: String,
+    pub to: String,
+    pub dry_run: bool,
+    pub files: Vec<MovedFile>,
+}
+
+/// A file whose references a move rewrites
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct MovedFile {
+    /// Path relative to the specs directory
+    pub path: String,
+    pub changes: Vec<MovedLine>,
+}
+
+/// One rewritten line in a moved file
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct MovedLine {
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
 /// Create spec request
 #[derive(Debug, Clone, Deserialize, TS)]
 #[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
@@ -400,6 +464,53 @@ pub struct SubSpec {
     pub content: String,
 }
 
+/// A typed link as seen from one spec
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct SpecLink {
+    /// Link key from this spec's side, e.g. `supersedes` or `superseded_by`
+    pub link_type: String,
+    pub label: String,
+    pub target_id: String,
+    pub target_title: String,
+}
+
+impl From<GraphLink<'_>> for SpecLink {
+    fn from(link: GraphLink<'_>) -> Self {
+        Self {
+            link_type: link.key.to_string(),
+            label: link.label.to_string(),
+            target_id: link.target.id.clone(),
+            target_title: link.target.title.clone(),
+        }
+    }
+}
+
+/// A commit linked to a spec via a `spec:` trailer
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct SpecCommitInfo {
+    pub sha: String,
+    pub short_sha: String,
+    pub author: String,
+    pub date: String,
+    pub subject: String,
+}
+
+impl From<SpecCommit> for SpecCommitInfo {
+    fn from(commit: SpecCommit) -> Self {
+        Self {
+            sha: commit.sha,
+            short_sha: commit.short_sha,
+            author: commit.author,
+            date: commit.date,
+            subject: commit.subject,
+        }
+    }
+}
+
 /// Response for list specs endpoint
 #[derive(Debug, Clone, Serialize, TS)]
 #[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
@@ -478,6 +589,44 @@ pub struct SearchFilters {
     pub tags: Option<Vec<String>>,
 }
 
+/// Request body for finding specs similar to a draft
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarSpecsRequest {
+    pub title: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub threshold: Option<f64>,
+    /// Spec id to leave out of the comparison (e.g. the spec being edited)
+    #[serde(default)]
+    pub exclude: Option<String>,
+}
+
+/// A spec that resembles the candidate
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarSpecItem {
+    pub id: String,
+    pub title: String,
+    pub score: f64,
+}
+
+/// Response for similar spec lookup
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarSpecsResponse {
+    pub results: Vec<SimilarSpecItem>,
+    pub threshold: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
 /// Statistics response
 #[derive(Debug, Clone, Serialize, TS)]
 #[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
@@ -654,6 +803,8 @@ pub struct SpecMetadata {
     pub token_count: usize,
     pub token_status: String,
     pub validation_status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 }
 
 /// Project validation summary
@@ -677,6 +828,16 @@ pub struct ProjectValidationSummary {
     pub specs_dir: Option<String>,
 }
 
+/// Query parameters for project validation
+#[derive(Debug, Clone, Deserialize, Default, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateProjectQuery {
+    /// Report format for spec validation results: `sarif` or `junit`.
+    /// When omitted, only the project structure is checked.
+    pub format: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, TS)]
 #[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
 #[serde(rename_all = "camelCase")]
@@ -712,11 +873,58 @@ pub struct MetadataUpdate {
     pub remove_depends_on: Option<Vec<String>>,
     pub parent: Option<Option<String>>,
     pub expected_content_hash: Option<String>,
+    /// Version token from a prior read; `If-Match` takes precedence
+    #[serde(default)]
+    pub expected_version: Option<String>,
     /// Skip completion verification when setting status to complete
     #[serde(default)]
     pub force: Option<bool>,
 }
 
+/// New comment on a spec
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct AddCommentRequest {
+    pub body: String,
+    /// Id of the comment this one replies to
+    #[serde(default)]
+    pub in_reply_to: Option<String>,
+}
+
+/// Comment edit: new body text, resolved state, or both
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCommentRequest {
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub resolved: Option<bool>,
+}
+
+/// Reviewers to ask for a review of a spec
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct RequestReviewRequest {
+    pub reviewers: Vec<String>,
+}
+
+/// A review decision on the current content of a spec
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitReviewRequest {
+    pub decision: ReviewDecision,
+    /// Reviewer name; ignored when the request is made with an API token,
+    /// whose name is used instead
+    #[serde(default)]
+    pub reviewer: Option<String>,
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
 /// Metadata update response
 #[derive(Debug, Clone, Serialize, TS)]
 #[ts(export, export_to = "../../../../packages/ui/src/types/generated/")]
@@ -725,6 +933,8 @@ pub struct UpdateMetadataResponse {
     pub success: bool,
     pub spec_id: String,
     pub frontmatter: FrontmatterResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 }
 
 /// Frontmatter response for API
@@ -4,14 +4,22 @@
 //! roots and invalidates the markdown adapter's cache when files change.
 //! For non-markdown adapters file watching is a no-op (the project root is
 //! simply not registered with the watcher).
+//!
+//! The watcher also keeps a digest of every spec README's acceptance
+//! criteria and design so that an edit to them can be reported, with the
+//! started specs depending on it, as an impact event. Loading specs for
+//! that runs on the blocking thread pool.
 
 use crate::error::ServerError;
-use leanspec_core::adapters::markdown::MarkdownAdapter;
+use leanspec_core::adapters::markdown::{
+    analyze_change, mark_needs_review, ChangeImpact, DependencyGraph, MarkdownAdapter,
+    SectionDigest,
+};
 use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::Serialize;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tokio::runtime::Handle;
 use tokio::sync::{broadcast, mpsc};
@@ -29,8 +37,45 @@ pub enum SpecChangeType {
 pub struct SpecChangeEvent {
     pub change_type: SpecChangeType,
     pub path: String,
+    /// Watched specs directory the path is relative to
+    #[serde(skip)]
+    pub specs_dir: PathBuf,
+}
+
+/// Downstream specs affected by an edit to an upstream spec's acceptance
+/// criteria or design.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecImpactEvent {
+    #[serde(flatten)]
+    pub impact: ChangeImpact,
+    /// Affected specs that were tagged `needs-review` by this change
+    pub marked: Vec<String>,
+    /// Watched specs directory of the changed spec
+    #[serde(skip)]
+    pub specs_dir: PathBuf,
+}
+
+/// Event broadcast to watcher subscribers.
+#[derive(Debug, Clone)]
+pub enum WatchEvent {
+    Change(SpecChangeEvent),
+    Impact(SpecImpactEvent),
+}
+
+impl WatchEvent {
+    /// Specs directory the event comes from.
+    pub fn specs_dir(&self) -> &Path {
+        match self {
+            WatchEvent::Change(change) => &change.specs_dir,
+            WatchEvent::Impact(impact) => &impact.specs_dir,
+        }
+    }
 }
 
+/// Maximum depth of downstream specs checked for impact.
+const IMPACT_DEPTH: usize = 3;
+
 /// One watched markdown root with the typed adapter handle for cache
 /// invalidation.
 pub struct MarkdownWatchTarget {
@@ -47,12 +92,16 @@ impl MarkdownWatchTarget {
 
 pub struct FileWatcher {
     _watcher: RecommendedWatcher,
-    tx: broadcast::Sender<SpecChangeEvent>,
+    tx: broadcast::Sender<WatchEvent>,
     roots: Vec<PathBuf>,
 }
 
 impl FileWatcher {
-    pub fn new(targets: Vec<MarkdownWatchTarget>, debounce: Duration) -> Result<Self, ServerError> {
+    pub fn new(
+        targets: Vec<MarkdownWatchTarget>,
+        debounce: Duration,
+        mark_needs_review: bool,
+    ) -> Result<Self, ServerError> {
         let (tx, _) = broadcast::channel(200);
         let (raw_tx, mut raw_rx) = mpsc::unbounded_channel::<Event>();
 
@@ -90,6 +139,16 @@ impl FileWatcher {
         })?;
 
         handle.spawn(async move {
+            let startup_targets = targets_for_loop.clone();
+            let impacts = tokio::task::spawn_blocking(move || {
+                ImpactTracker::new(&startup_targets, mark_needs_review)
+            })
+            .await
+            .unwrap_or_else(|err| {
+                tracing::warn!("Failed to snapshot specs for impact tracking: {}", err);
+                ImpactTracker::empty(mark_needs_review)
+            });
+            let impacts = Arc::new(Mutex::new(impacts));
             let mut pending: HashMap<PathBuf, (SpecChangeType, Instant)> = HashMap::new();
             let mut ticker = tokio::time::interval(debounce_interval);
             ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
@@ -123,11 +182,34 @@ impl FileWatcher {
                         let mut drained = HashMap::new();
                         std::mem::swap(&mut drained, &mut pending);
 
-                        for (path, (kind, _)) in drained {
-                            if let Some(event) = to_spec_event(&roots_for_loop, path, kind) {
-                                let _ = tx_clone.send(event);
+                        let changes: Vec<(PathBuf, SpecChangeType)> = drained
+                            .into_iter()
+                            .map(|(path, (kind, _))| (path, kind))
+                            .collect();
+                        for (path, kind) in &changes {
+                            if let Some(event) = to_spec_event(&roots_for_loop, path.clone(), *kind) {
+                                let _ = tx_clone.send(WatchEvent::Change(event));
                             }
                         }
+
+                        let impacts = impacts.clone();
+                        let targets = targets_for_loop.clone();
+                        let checked = tokio::task::spawn_blocking(move || {
+                            let mut impacts = impacts.lock().unwrap_or_else(|e| e.into_inner());
+                            changes
+                                .iter()
+                                .filter_map(|(path, kind)| impacts.check(&targets, path, *kind))
+                                .collect::<Vec<_>>()
+                        })
+                        .await;
+                        match checked {
+                            Ok(events) => {
+                                for impact in events {
+                                    let _ = tx_clone.send(WatchEvent::Impact(impact));
+                                }
+                            }
+                            Err(err) => tracing::warn!("Impact check failed: {}", err),
+                        }
                     }
                 }
             }
@@ -140,7 +222,7 @@ impl FileWatcher {
         })
     }
 
-    pub fn subscribe(&self) -> broadcast::Receiver<SpecChangeEvent> {
+    pub fn subscribe(&self) -> broadcast::Receiver<WatchEvent> {
         self.tx.subscribe()
     }
 
@@ -157,6 +239,11 @@ pub fn watch_debounce() -> Duration {
     Duration::from_millis(env_u64("FILE_WATCH_DEBOUNCE_MS", 300))
 }
 
+/// Whether impact events also tag the affected specs `needs-review`.
+pub fn impact_mark_review() -> bool {
+    env_bool("IMPACT_MARK_NEEDS_REVIEW", false)
+}
+
 pub fn sse_keepalive_interval() -> Duration {
     Duration::from_secs(env_u64("SSE_KEEPALIVE_SEC", 15))
 }
@@ -169,6 +256,82 @@ pub fn sse_min_interval() -> Duration {
     Duration::from_millis(env_u64("SSE_MIN_INTERVAL_MS", 100))
 }
 
+/// Impact section digests of each README as last seen, to diff edits
+/// against. Its methods load specs from disk; call them off the runtime.
+struct ImpactTracker {
+    snapshots: HashMap<PathBuf, SectionDigest>,
+    mark_needs_review: bool,
+}
+
+impl ImpactTracker {
+    fn new(targets: &[MarkdownWatchTarget], mark_needs_review: bool) -> Self {
+        let snapshots = targets
+            .iter()
+            .filter_map(|target| target.adapter.load_spec_infos().ok())
+            .flatten()
+            .filter(|spec| !spec.is_sub_spec)
+            .map(|spec| (spec.file_path, SectionDigest::new(&spec.content)))
+            .collect();
+        Self {
+            snapshots,
+            mark_needs_review,
+        }
+    }
+
+    fn empty(mark_needs_review: bool) -> Self {
+        Self {
+            snapshots: HashMap::new(),
+            mark_needs_review,
+        }
+    }
+
+    /// Record the new state of `path` and return the impact of the edit
+    /// when it changed an impact section of a spec that started specs
+    /// depend on.
+    fn check(
+        &mut self,
+        targets: &[MarkdownWatchTarget],
+        path: &Path,
+        kind: SpecChangeType,
+    ) -> Option<SpecImpactEvent> {
+        if path.file_name().and_then(|n| n.to_str()) != Some("README.md") {
+            return None;
+        }
+        if kind == SpecChangeType::Deleted {
+            self.snapshots.remove(path);
+            return None;
+        }
+
+        let target = targets.iter().find(|t| path.starts_with(&t.specs_dir))?;
+        let name = path.parent()?.file_name()?.to_str()?;
+        let spec = target.adapter.load_spec_info(name).ok()?;
+        let before = self
+            .snapshots
+            .insert(path.to_path_buf(), SectionDigest::new(&spec.content))?;
+
+        let all_specs = target.adapter.load_spec_infos().ok()?;
+        let graph = DependencyGraph::new(&all_specs);
+        let impact = analyze_change(&graph, &spec, &before, IMPACT_DEPTH)?;
+        if impact.affected.is_empty() {
+            return None;
+        }
+
+        let marked = if self.mark_needs_review {
+            mark_needs_review(&target.specs_dir, &impact).unwrap_or_else(|err| {
+                tracing::warn!("Failed to tag specs affected by {}: {}", impact.spec, err);
+                Vec::new()
+            })
+        } else {
+            Vec::new()
+        };
+        Some(SpecImpactEvent {
+            impact,
+            marked,
+            specs_dir: target.specs_dir.clone(),
+        })
+    }
+}
+
 fn env_bool(key: &str, default: bool) -> bool {
     std::env::var(key)
         .ok()
@@ -236,10 +399,10 @@ fn to_spec_event(
         return None;
     }
 
-    let relative = roots.iter().find_map(|root| {
+    let (root, relative) = roots.iter().find_map(|root| {
         path.strip_prefix(root)
             .ok()
-            .map(|relative| relative.to_path_buf())
+            .map(|relative| (root, relative.to_path_buf()))
     })?;
 
     let relative_str = relative.to_string_lossy().replace('\\', "/");
@@ -247,5 +410,73 @@ fn to_spec_event(
     Some(SpecChangeEvent {
         change_type,
         path: relative_str,
+        specs_dir: root.clone(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_spec(specs_dir: &Path, name: &str, status: &str, depends_on: &str, body: &str) {
+        let dir = specs_dir.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("README.md"),
+            format!(
+                "---\nstatus: {status}\ncreated: '2025-01-01'\ndepends_on: [{depends_on}]\n---\n\n# {name}\n\n{body}"
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn reports_impact_of_design_edits() {
+        let dir = tempfile::tempdir().unwrap();
+        let specs_dir = dir.path().to_path_buf();
+        write_spec(
+            &specs_dir,
+            "001-db",
+            "planned",
+            "",
+            "## Design\n\nPostgres\n",
+        );
+        write_spec(&specs_dir, "002-api", "in-progress", "001-db", "");
+        write_spec(&specs_dir, "003-ui", "planned", "001-db", "");
+
+        let targets = vec![MarkdownWatchTarget::new(specs_dir.clone())];
+        let mut tracker = ImpactTracker::new(&targets, true);
+        let readme = specs_dir.join("001-db").join("README.md");
+
+        write_spec(
+            &specs_dir,
+            "001-db",
+            "in-progress",
+            "",
+            "## Design\n\nPostgres\n",
+        );
+        assert!(tracker
+            .check(&targets, &readme, SpecChangeType::Modified)
+            .is_none());
+
+        write_spec(
+            &specs_dir,
+            "001-db",
+            "in-progress",
+            "",
+            "## Design\n\nSQLite\n",
+        );
+        let event = tracker
+            .check(&targets, &readme, SpecChangeType::Modified)
+            .unwrap();
+        assert_eq!(event.impact.changed_sections, vec!["Design"]);
+        assert_eq!(event.marked, vec!["002-api"]);
+        let api = fs::read_to_string(specs_dir.join("002-api").join("README.md")).unwrap();
+        assert!(api.contains("needs-review"));
+
+        let payload = serde_json::to_value(&event).unwrap();
+        assert_eq!(payload["spec"], "001-db");
+        assert_eq!(payload["affected"][0]["path"], "002-api");
+    }
+}
@@ -0,0 +1,133 @@
+//! Integration tests for API token authentication and authorization
+
+mod common;
+
+use axum::http::StatusCode;
+use leanspec_http::config::TokenRole;
+use leanspec_http::create_router;
+use leanspec_http::tokens::{create_token, TokenStore};
+use serde_json::Value;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+use common::*;
+
+async fn call(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: &str,
+    token: &str,
+) -> (StatusCode, String) {
+    let auth = format!("Bearer {}", token);
+    make_json_request_with_headers(
+        app.clone(),
+        method,
+        uri,
+        body,
+        &[("authorization", auth.as_str())],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_token_roles_and_project_scopes() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_file = temp_dir.path().join("config.json");
+    let mut state = create_test_state(&temp_dir).await;
+    let project_id = state.registry.read().await.all()[0].id.clone();
+
+    let mut tokens = Vec::new();
+    let admin = create_token(&mut tokens, "admin", TokenRole::Admin, vec![]).unwrap();
+    state.tokens = Arc::new(TokenStore::new(tokens, Some(config_file.clone())));
+    let app = create_router(state);
+
+    let (status, _) = make_request(app.clone(), "GET", "/api/projects").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = call(&app, "GET", "/api/projects", "", "lst_wrong").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // Admin creates a viewer token scoped to the project
+    let body = format!(
+        r#"{{"name":"team-a","role":"viewer","projects":["{}"]}}"#,
+        project_id
+    );
+    let (status, response) = call(&app, "POST", "/api/tokens", &body, &admin).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", response);
+    let created: Value = serde_json::from_str(&response).unwrap();
+    let viewer = created["token"].as_str().unwrap().to_string();
+    assert_eq!(created["info"]["role"], "viewer");
+
+    let saved = std::fs::read_to_string(&config_file).unwrap();
+    assert!(saved.contains("\"tokenHash\""));
+    assert!(!saved.contains(&viewer));
+
+    // Viewer can read its project but not write, manage, or see others
+    let (status, response) = call(&app, "GET", "/api/projects", "", &viewer).await;
+    assert_eq!(status, StatusCode::OK);
+    let projects: Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(projects["projects"].as_array().unwrap().len(), 1);
+
+    let specs = format!("/api/projects/{}/specs", project_id);
+    let (status, _) = call(&app, "GET", &specs, "", &viewer).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = call(&app, "POST", &specs, r#"{"name":"x"}"#, &viewer).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = call(&app, "GET", "/api/projects/other/specs", "", &viewer).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = call(&app, "GET", "/api/tokens", "", &viewer).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // Listing never exposes secrets or hashes
+    let (status, response) = call(&app, "GET", "/api/tokens", "", &admin).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(response.contains("team-a") && !response.contains("tokenHash"));
+
+    let (status, _) = call(&app, "DELETE", "/api/tokens/team-a", "", &admin).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = call(&app, "GET", &specs, "", &viewer).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = call(&app, "DELETE", "/api/tokens/team-a", "", &admin).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_only_authors_and_admins_edit_comment_bodies() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut state = create_test_state(&temp_dir).await;
+    let project_id = state.registry.read().await.all()[0].id.clone();
+
+    let mut tokens = Vec::new();
+    let admin = create_token(&mut tokens, "admin", TokenRole::Admin, vec![]).unwrap();
+    let alice = create_token(&mut tokens, "alice", TokenRole::Editor, vec![]).unwrap();
+    let bob = create_token(&mut tokens, "bob", TokenRole::Editor, vec![]).unwrap();
+    state.tokens = Arc::new(TokenStore::new(tokens, None));
+    let app = create_router(state);
+
+    let comments = format!("/api/projects/{}/specs/001-first-spec/comments", project_id);
+    let (status, response) = call(&app, "POST", &comments, r#"{"body":"Mine"}"#, &alice).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", response);
+    let comment = format!("{}/1", comments);
+
+    let (status, _) = call(&app, "PATCH", &comment, r#"{"body":"Hijacked"}"#, &bob).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = call(&app, "PATCH", &comment, r#"{"resolved":true}"#, &bob).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = call(&app, "PATCH", &comment, r#"{"body":"Still mine"}"#, &alice).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, response) = call(&app, "PATCH", &comment, r#"{"body":"Moderated"}"#, &admin).await;
+    assert_eq!(status, StatusCode::OK);
+    let edited: Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(edited["body"], "Moderated");
+    assert_eq!(edited["author"], "alice");
+}
+
+#[tokio::test]
+async fn test_open_server_without_tokens() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state);
+
+    let (status, _) = make_request(app, "GET", "/api/projects").await;
+    assert_eq!(status, StatusCode::OK);
+}
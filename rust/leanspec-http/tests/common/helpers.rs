@@ -47,3 +47,30 @@ pub async fn make_json_request(
 
     (status, body_str)
 }
+
+/// Helper to make JSON requests with extra headers (e.g. `If-Match`)
+pub async fn make_json_request_with_headers(
+    app: axum::Router,
+    method: &str,
+    uri: &str,
+    body: &str,
+    headers: &[(&str, &str)],
+) -> (StatusCode, String) {
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json");
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    let request = builder.body(Body::from(body.to_string())).unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body_str = String::from_utf8_lossy(&body).to_string();
+
+    (status, body_str)
+}
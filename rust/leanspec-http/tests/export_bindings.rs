@@ -1,16 +1,23 @@
-use leanspec_core::adapters::markdown::{SpecPriority, SpecStatus, StatusTransition};
+use leanspec_core::adapters::markdown::{
+    ReviewDecision, ReviewRecord, ReviewRequest, ReviewState, ReviewerStatus, SpecPriority,
+    SpecReview, SpecStatus, StatusTransition,
+};
+use leanspec_core::{AssigneeWorkload, WorkloadReport, WorkloadSpec};
 use leanspec_http::types::{
-    BatchMetadataRequest, BatchMetadataResponse, ChecklistToggleItem, ChecklistToggleRequest,
-    ChecklistToggleResponse, ChecklistToggledResult, ConfigFeatures, ConfigStructure, ContextFile,
-    CreateSpecRequest, DependencyEdge, DependencyGraphResponse, DependencyNode, DependencyResponse,
-    DetailedBreakdown, DraftStatusConfig, FrontmatterResponse, HealthResponse, HierarchyNode,
-    LeanSpecConfig, ListSpecsQuery, ListSpecsResponse, MetadataUpdate, PriorityCountItem,
+    AddCommentRequest, ApiTokenInfo, BatchMetadataRequest, BatchMetadataResponse,
+    ChecklistToggleItem, ChecklistToggleRequest, ChecklistToggleResponse, ChecklistToggledResult,
+    ConfigFeatures, ConfigStructure, ContextFile, CreateSpecRequest, CreateTokenRequest,
+    CreateTokenResponse, DependencyEdge, DependencyGraphResponse, DependencyNode,
+    DependencyResponse, DetailedBreakdown, DraftStatusConfig, FrontmatterResponse, HealthResponse,
+    HierarchyNode, LeanSpecConfig, ListSpecsQuery, ListSpecsResponse, MetadataUpdate,
+    MoveSpecRequest, MoveSpecResponse, MovedFile, MovedLine, PriorityCountItem,
     ProjectConfigResponse, ProjectContextResponse, ProjectValidationResponse,
-    ProjectValidationSummary, SearchFilters, SearchRequest, SearchResponse, SectionTokenCount,
-    SpecDetail, SpecMetadata, SpecRawResponse, SpecRawUpdateRequest, SpecRelationships,
+    ProjectValidationSummary, RequestReviewRequest, SearchFilters, SearchRequest, SearchResponse,
+    SectionTokenCount, SimilarSpecItem, SimilarSpecsRequest, SimilarSpecsResponse, SpecCommitInfo,
+    SpecDetail, SpecLink, SpecMetadata, SpecRawResponse, SpecRawUpdateRequest, SpecRelationships,
     SpecSummary, SpecTokenResponse, SpecValidationError, SpecValidationResponse, StatsResponse,
-    StatusCountItem, SubSpec, TokenBreakdown, UpdateMetadataResponse, ValidationError,
-    ValidationResponse,
+    StatusCountItem, SubSpec, SubmitReviewRequest, TokenBreakdown, UpdateCommentRequest,
+    UpdateMetadataResponse, ValidateProjectQuery, ValidationError, ValidationResponse,
 };
 use std::fs;
 use std::path::PathBuf;
@@ -43,6 +50,8 @@ fn export_bindings() {
     write_binding::<SpecDetail>();
     write_binding::<SpecRelationships>();
     write_binding::<SubSpec>();
+    write_binding::<SpecCommitInfo>();
+    write_binding::<SpecLink>();
     write_binding::<SpecRawResponse>();
     write_binding::<SpecRawUpdateRequest>();
 
@@ -56,6 +65,31 @@ fn export_bindings() {
     write_binding::<CreateSpecRequest>();
     write_binding::<MetadataUpdate>();
     write_binding::<UpdateMetadataResponse>();
+
+    // Comments
+    write_binding::<AddCommentRequest>();
+    write_binding::<UpdateCommentRequest>();
+
+    // Workload
+    write_binding::<WorkloadReport>();
+    write_binding::<AssigneeWorkload>();
+    write_binding::<WorkloadSpec>();
+
+    // Review
+    write_binding::<SpecReview>();
+    write_binding::<ReviewerStatus>();
+    write_binding::<ReviewRecord>();
+    write_binding::<ReviewRequest>();
+    write_binding::<ReviewDecision>();
+    write_binding::<ReviewState>();
+    write_binding::<RequestReviewRequest>();
+    write_binding::<SubmitReviewRequest>();
+
+    // Move
+    write_binding::<MoveSpecRequest>();
+    write_binding::<MoveSpecResponse>();
+    write_binding::<MovedFile>();
+    write_binding::<MovedLine>();
     write_binding::<FrontmatterResponse>();
 
     // List / Hierarchy
@@ -67,6 +101,9 @@ fn export_bindings() {
     write_binding::<SearchResponse>();
     write_binding::<SearchRequest>();
     write_binding::<SearchFilters>();
+    write_binding::<SimilarSpecsRequest>();
+    write_binding::<SimilarSpecItem>();
+    write_binding::<SimilarSpecsResponse>();
 
     // Stats
     write_binding::<StatsResponse>();
@@ -97,6 +134,7 @@ fn export_bindings() {
     // Project
     write_binding::<ProjectValidationResponse>();
     write_binding::<ProjectValidationSummary>();
+    write_binding::<ValidateProjectQuery>();
     write_binding::<ProjectConfigResponse>();
     write_binding::<ProjectContextResponse>();
 
@@ -110,6 +148,11 @@ fn export_bindings() {
     write_binding::<HealthResponse>();
     write_binding::<ContextFile>();
 
+    // API tokens
+    write_binding::<ApiTokenInfo>();
+    write_binding::<CreateTokenRequest>();
+    write_binding::<CreateTokenResponse>();
+
     // Core domain types
     write_binding::<SpecStatus>();
     write_binding::<SpecPriority>();
@@ -0,0 +1,290 @@
+//! Integration tests for git branch-per-spec endpoints
+//!
+//! Every test runs against a local bare repository, so no network is needed.
+
+mod common;
+
+use axum::http::StatusCode;
+use leanspec_http::{create_router, AppState, ProjectRegistry, ServerConfig};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+use common::*;
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("Failed to execute git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn write_spec(repo: &Path, spec: &str, body: &str) {
+    let dir = repo.join("specs").join(spec);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("README.md"),
+        format!(
+            "---\nstatus: planned\ncreated: '2025-01-01'\n---\n\n# {}\n\n{}\n",
+            spec, body
+        ),
+    )
+    .unwrap();
+}
+
+fn commit_all(repo: &Path, message: &str) {
+    git(repo, &["add", "-A"]);
+    git(repo, &["commit", "-q", "-m", message]);
+}
+
+struct GitFixture {
+    temp_dir: TempDir,
+    remote: PathBuf,
+    seed: PathBuf,
+    clone: PathBuf,
+}
+
+/// A bare remote with a `main` branch, a seed working copy, and a clone of
+/// the remote registered as a git-sourced project.
+async fn setup() -> (GitFixture, AppState, String) {
+    let temp_dir = TempDir::new().unwrap();
+    let remote = temp_dir.path().join("remote.git");
+    let seed = temp_dir.path().join("seed");
+    let clone = temp_dir.path().join("clone");
+
+    git(temp_dir.path(), &["init", "-q", "--bare", "remote.git"]);
+    fs::create_dir_all(&seed).unwrap();
+    git(&seed, &["init", "-q"]);
+    git(&seed, &["config", "user.email", "dev@example.com"]);
+    git(&seed, &["config", "user.name", "Dev"]);
+    write_spec(&seed, "001-alpha", "line one");
+    write_spec(&seed, "002-beta", "line one");
+    commit_all(&seed, "initial");
+    git(&seed, &["branch", "-M", "main"]);
+    git(
+        &seed,
+        &["remote", "add", "origin", remote.to_str().unwrap()],
+    );
+    git(&seed, &["push", "-q", "origin", "main"]);
+
+    git(
+        temp_dir.path(),
+        &["clone", "-q", "--branch", "main", "remote.git", "clone"],
+    );
+    git(&clone, &["config", "user.email", "dev@example.com"]);
+    git(&clone, &["config", "user.name", "Dev"]);
+
+    let registry_file = temp_dir
+        .path()
+        .join(".lean-spec-test")
+        .join("projects.json");
+    let registry = ProjectRegistry::new_with_file_path(registry_file).unwrap();
+    let state = AppState::with_registry(ServerConfig::default(), registry).await;
+    let project_id = {
+        let mut reg = state.registry.write().await;
+        reg.add_git(
+            remote.to_str().unwrap(),
+            "main",
+            "specs",
+            &clone,
+            Some("git-project"),
+        )
+        .unwrap()
+        .id
+    };
+
+    (
+        GitFixture {
+            temp_dir,
+            remote,
+            seed,
+            clone,
+        },
+        state,
+        project_id,
+    )
+}
+
+#[tokio::test]
+async fn test_spec_branch_lifecycle() {
+    let (fx, state, project_id) = setup().await;
+
+    let (status, body) = make_json_request(
+        create_router(state.clone()),
+        "POST",
+        &format!("/api/git/branches/{}", project_id),
+        r#"{"spec": "001-alpha"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let branch: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(branch["name"], "spec/001-alpha");
+    assert_eq!(branch["spec"], "001-alpha");
+    assert_eq!(branch["current"], true);
+
+    write_spec(&fx.clone, "001-alpha", "edited on branch");
+    let (status, body) = make_json_request(
+        create_router(state.clone()),
+        "POST",
+        &format!("/api/git/push/{}", project_id),
+        r#"{"message": "edit alpha"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let push: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(push["pushed"], true);
+
+    let (status, body) = make_request(
+        create_router(state.clone()),
+        "GET",
+        &format!("/api/git/branches/{}", project_id),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let list: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(list["base"], "main");
+    let names: Vec<&str> = list["branches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["name"].as_str().unwrap())
+        .collect();
+    assert!(names.contains(&"main"));
+    assert!(names.contains(&"spec/001-alpha"));
+
+    let (status, body) = make_json_request(
+        create_router(state),
+        "POST",
+        &format!("/api/git/checkout/{}", project_id),
+        r#"{"branch": "main"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let git_status: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(git_status["branch"], "main");
+    assert_eq!(git_status["ahead"], 0);
+    assert_eq!(git_status["pendingChanges"], 0);
+}
+
+#[tokio::test]
+async fn test_checkout_unknown_branch() {
+    let (_fx, state, project_id) = setup().await;
+
+    let (status, _) = make_json_request(
+        create_router(state),
+        "POST",
+        &format!("/api/git/checkout/{}", project_id),
+        r#"{"branch": "does-not-exist"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_failed_push_is_queued_and_flushed() {
+    let (fx, state, project_id) = setup().await;
+    let offline = fx.temp_dir.path().join("offline.git");
+    fs::rename(&fx.remote, &offline).unwrap();
+
+    write_spec(&fx.clone, "002-beta", "written offline");
+    let (status, body) = make_json_request(
+        create_router(state.clone()),
+        "POST",
+        &format!("/api/git/push/{}", project_id),
+        r#"{"message": "offline edit"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let push: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(push["pushed"], false);
+    assert!(push["queuedError"].is_string());
+
+    let (status, body) = make_request(
+        create_router(state.clone()),
+        "GET",
+        &format!("/api/git/pending/{}", project_id),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let pending: Value = serde_json::from_str(&body).unwrap();
+    let changes = pending["changes"].as_array().unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0]["message"], "offline edit");
+    assert_eq!(changes[0]["branch"], "main");
+
+    fs::rename(&offline, &fx.remote).unwrap();
+    let (status, body) = make_request(
+        create_router(state),
+        "POST",
+        &format!("/api/git/pending/{}/flush", project_id),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let flushed: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(flushed["pushed"].as_array().unwrap().len(), 1);
+    assert!(flushed["remaining"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_integrate_reports_conflicts() {
+    let (fx, state, project_id) = setup().await;
+
+    let (status, _) = make_json_request(
+        create_router(state.clone()),
+        "POST",
+        &format!("/api/git/branches/{}", project_id),
+        r#"{"spec": "001-alpha"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    write_spec(&fx.clone, "001-alpha", "branch version");
+    commit_all(&fx.clone, "branch edit");
+
+    write_spec(&fx.seed, "001-alpha", "main version");
+    commit_all(&fx.seed, "main edit");
+    git(&fx.seed, &["push", "-q", "origin", "main"]);
+
+    let (status, body) = make_json_request(
+        create_router(state),
+        "POST",
+        &format!("/api/git/integrate/{}", project_id),
+        r#"{"strategy": "rebase"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", body);
+    let result: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(result["success"], false);
+    assert_eq!(result["branch"], "spec/001-alpha");
+    assert_eq!(result["conflicts"][0]["spec"], "001-alpha");
+    assert_eq!(
+        result["conflicts"][0]["files"][0],
+        "specs/001-alpha/README.md"
+    );
+}
+
+#[tokio::test]
+async fn test_branch_endpoints_reject_local_projects() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+
+    let (status, _) = make_request(
+        create_router(state),
+        "GET",
+        &format!("/api/git/branches/{}", project_id),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -124,3 +124,78 @@ async fn test_search_empty_results() {
     let results_array = results["results"].as_array().unwrap();
     assert_eq!(results_array.len(), 0);
 }
+
+#[tokio::test]
+async fn test_similar_specs() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+
+    let (status, body) = make_json_request(
+        app,
+        "POST",
+        &format!("/api/projects/{}/specs/similar", project_id),
+        r#"{"title": "Second Spec", "content": "This spec depends on the first spec.", "threshold": 0.4}"#,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let response: Value = serde_json::from_str(&body).unwrap();
+    let results = response["results"].as_array().unwrap();
+    assert!(!results.is_empty());
+    assert_eq!(results[0]["id"], "002-second-spec");
+    assert!(results[0]["score"].as_f64().unwrap() >= 0.4);
+    assert_eq!(response["threshold"], 0.4);
+}
+
+#[tokio::test]
+async fn test_similar_specs_respects_exclude() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+
+    let (status, body) = make_json_request(
+        app,
+        "POST",
+        &format!("/api/projects/{}/specs/similar", project_id),
+        r#"{"title": "Second Spec", "content": "This spec depends on the first spec.", "exclude": "002-second-spec"}"#,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let response: Value = serde_json::from_str(&body).unwrap();
+    let results = response["results"].as_array().unwrap();
+    assert!(results.iter().all(|r| r["id"] != "002-second-spec"));
+}
+
+#[tokio::test]
+async fn test_similar_specs_rejects_invalid_threshold() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+
+    let (status, _body) = make_json_request(
+        app,
+        "POST",
+        &format!("/api/projects/{}/specs/similar", project_id),
+        r#"{"title": "Anything", "threshold": 2.0}"#,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
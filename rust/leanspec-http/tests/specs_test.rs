@@ -101,6 +101,54 @@ async fn test_get_spec_detail() {
     assert!(body.contains("contentMd"));
 }
 
+#[tokio::test]
+async fn test_spec_detail_includes_linked_commits() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(temp_dir.path())
+            .output()
+            .expect("Failed to execute git");
+        assert!(status.status.success(), "git {:?} failed", args);
+    };
+    git(&["init", "-q"]);
+    git(&["config", "user.email", "dev@example.com"]);
+    git(&["config", "user.name", "Dev"]);
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "feat: first spec work\n\nspec: 001"]);
+
+    let (status, body) = make_request(
+        app.clone(),
+        "GET",
+        &format!("/api/projects/{}/specs/001-first-spec", project_id),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let detail: Value = serde_json::from_str(&body).unwrap();
+    let commits = detail["commits"].as_array().unwrap();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0]["subject"], "feat: first spec work");
+    assert_eq!(commits[0]["author"], "Dev");
+
+    let (_, body) = make_request(
+        app,
+        "GET",
+        &format!("/api/projects/{}/specs/002-second-spec", project_id),
+    )
+    .await;
+    let detail: Value = serde_json::from_str(&body).unwrap();
+    assert!(detail.get("commits").is_none());
+}
+
 #[tokio::test]
 async fn test_spec_required_by_computation() {
     let temp_dir = TempDir::new().unwrap();
@@ -264,6 +312,108 @@ async fn test_update_spec_metadata_not_implemented() {
     );
 }
 
+#[tokio::test]
+async fn test_update_metadata_with_stale_if_match_returns_409() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        let projects = reg.all();
+        projects.first().unwrap().id.clone()
+    };
+    let spec_uri = format!("/api/projects/{}/specs/001-first-spec", project_id);
+
+    let (status, body) = make_request(app.clone(), "GET", &spec_uri).await;
+    assert_eq!(status, StatusCode::OK);
+    let detail: Value = serde_json::from_str(&body).unwrap();
+    let read_version = detail["version"].as_str().unwrap().to_string();
+    let if_match = format!("\"{}\"", read_version);
+
+    let metadata_uri = format!("{}/metadata", spec_uri);
+    let (status, body) = make_json_request_with_headers(
+        app.clone(),
+        "PATCH",
+        &metadata_uri,
+        &serde_json::json!({ "priority": "low" }).to_string(),
+        &[("if-match", &if_match)],
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let updated: Value = serde_json::from_str(&body).unwrap();
+    let new_version = updated["version"].as_str().unwrap();
+    assert_ne!(new_version, read_version);
+
+    // A second writer still holding the old version is rejected.
+    let (status, body) = make_json_request_with_headers(
+        app,
+        "PATCH",
+        &metadata_uri,
+        &serde_json::json!({ "priority": "critical" }).to_string(),
+        &[("if-match", &if_match)],
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", body);
+    let error: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(error["code"], "VERSION_CONFLICT");
+    assert_eq!(error["details"]["currentVersion"], new_version);
+}
+
+#[tokio::test]
+async fn test_raw_updates_with_the_same_if_match_conflict() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+    let spec_uri = format!("/api/projects/{}/specs/001-first-spec", project_id);
+    let (_, body) = make_request(app.clone(), "GET", &spec_uri).await;
+    let detail: Value = serde_json::from_str(&body).unwrap();
+    let if_match = format!("\"{}\"", detail["version"].as_str().unwrap());
+    let (_, raw) = make_request(app.clone(), "GET", &format!("{}/raw", spec_uri)).await;
+    let raw: Value = serde_json::from_str(&raw).unwrap();
+    let content = raw["content"].as_str().unwrap().to_string();
+
+    let patch = |body: String| {
+        let app = app.clone();
+        let uri = format!("{}/raw", spec_uri);
+        let if_match = if_match.clone();
+        async move {
+            let request = serde_json::json!({ "content": body }).to_string();
+            make_json_request_with_headers(app, "PATCH", &uri, &request, &[("if-match", &if_match)])
+                .await
+                .0
+        }
+    };
+    let (first, second) = tokio::join!(
+        patch(format!("{}\nFirst writer.\n", content)),
+        patch(format!("{}\nSecond writer.\n", content)),
+    );
+    let mut statuses = [first, second];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::OK, StatusCode::CONFLICT]);
+
+    // Sub-spec writes check the spec's version too
+    std::fs::write(
+        temp_dir.path().join("specs/001-first-spec/DESIGN.md"),
+        "# Design\n",
+    )
+    .unwrap();
+    let (status, body) = make_json_request_with_headers(
+        app,
+        "PATCH",
+        &format!("{}/subspecs/DESIGN.md/raw", spec_uri),
+        &serde_json::json!({ "content": "# Design\n\nStale.\n" }).to_string(),
+        &[("if-match", &if_match)],
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", body);
+}
+
 #[tokio::test]
 async fn test_invalid_query_parameters() {
     let temp_dir = TempDir::new().unwrap();
@@ -417,3 +567,231 @@ async fn test_umbrella_completion_blocks_when_children_incomplete() {
         body
     );
 }
+
+#[tokio::test]
+async fn test_move_spec_rewrites_references() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        let projects = reg.all();
+        projects.first().unwrap().id.clone()
+    };
+    let move_uri = format!("/api/projects/{}/specs/001-first-spec/move", project_id);
+    let specs_dir = temp_dir.path().join("specs");
+
+    let (status, body) = make_json_request(
+        app.clone(),
+        "POST",
+        &move_uri,
+        &serde_json::json!({ "newName": "renamed-spec", "dryRun": true }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let preview: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(preview["to"], "001-renamed-spec");
+    let files = preview["files"].as_array().unwrap();
+    assert!(files
+        .iter()
+        .any(|f| f["path"] == "002-second-spec/README.md"));
+    assert!(specs_dir.join("001-first-spec").exists());
+
+    // Taking another spec's number is rejected before anything is written.
+    let (status, _body) = make_json_request(
+        app.clone(),
+        "POST",
+        &move_uri,
+        &serde_json::json!({ "number": 2 }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, body) = make_json_request(
+        app,
+        "POST",
+        &move_uri,
+        &serde_json::json!({ "newName": "renamed-spec" }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert!(!specs_dir.join("001-first-spec").exists());
+    assert!(specs_dir
+        .join("001-renamed-spec")
+        .join("README.md")
+        .exists());
+    let dependent =
+        std::fs::read_to_string(specs_dir.join("002-second-spec").join("README.md")).unwrap();
+    assert!(dependent.contains("- 001-renamed-spec"));
+}
+
+#[tokio::test]
+async fn test_spec_comment_endpoints() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+    let comments_uri = format!("/api/projects/{}/specs/001-first-spec/comments", project_id);
+
+    let (status, body) = make_json_request(
+        app.clone(),
+        "POST",
+        &comments_uri,
+        &serde_json::json!({ "body": "Why not SQLite?" }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let first: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(first["id"], "1");
+
+    let (status, body) = make_json_request(
+        app.clone(),
+        "POST",
+        &comments_uri,
+        &serde_json::json!({ "body": "It is.", "inReplyTo": "1" }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+
+    let (status, body) = make_json_request(
+        app.clone(),
+        "PATCH",
+        &format!("{}/1", comments_uri),
+        &serde_json::json!({ "body": "Why not *SQLite*?", "resolved": true }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    let (status, body) = make_request(app.clone(), "GET", &comments_uri).await;
+    assert_eq!(status, StatusCode::OK);
+    let comments: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(comments[0]["body"], "Why not *SQLite*?");
+    assert_eq!(comments[0]["resolved"], true);
+    assert_eq!(comments[1]["in_reply_to"], "1");
+
+    let (status, _body) = make_json_request(
+        app.clone(),
+        "PATCH",
+        &format!("{}/9", comments_uri),
+        &serde_json::json!({ "resolved": true }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _body) = make_json_request(
+        app,
+        "PATCH",
+        &format!("{}/1", comments_uri),
+        &serde_json::json!({}).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_spec_review_endpoints_and_start_gate() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+    let config_dir = temp_dir.path().join(".lean-spec");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.json"),
+        r#"{ "review": { "requireApproval": true } }"#,
+    )
+    .unwrap();
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+    let spec_uri = format!("/api/projects/{}/specs/001-first-spec", project_id);
+    let start = serde_json::json!({ "status": "in-progress" }).to_string();
+
+    let (status, body) = make_json_request(
+        app.clone(),
+        "PATCH",
+        &format!("{}/metadata", spec_uri),
+        &start,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    assert!(body.contains("no review has been requested"), "{}", body);
+
+    // Raw edits that flip the frontmatter status are gated too
+    let (_, raw) = make_request(app.clone(), "GET", &format!("{}/raw", spec_uri)).await;
+    let raw: Value = serde_json::from_str(&raw).unwrap();
+    let started = raw["content"]
+        .as_str()
+        .unwrap()
+        .replace("status: planned", "status: in-progress");
+    let raw_start = serde_json::json!({ "content": started }).to_string();
+    let (status, body) = make_json_request(
+        app.clone(),
+        "PATCH",
+        &format!("{}/raw", spec_uri),
+        &raw_start,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    assert!(body.contains("no review has been requested"), "{}", body);
+
+    // Approving unasked does not count
+    let (status, body) = make_json_request(
+        app.clone(),
+        "POST",
+        &format!("{}/review", spec_uri),
+        &serde_json::json!({ "decision": "approved", "reviewer": "author" }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let review: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(review["state"], "not-requested");
+    assert_eq!(review["approvals"], 0);
+
+    let (status, body) = make_json_request(
+        app.clone(),
+        "POST",
+        &format!("{}/review/request", spec_uri),
+        &serde_json::json!({ "reviewers": ["alice"] }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let review: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(review["state"], "pending");
+
+    let (status, _body) = make_json_request(
+        app.clone(),
+        "POST",
+        &format!("{}/review", spec_uri),
+        &serde_json::json!({ "decision": "approved" }).to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = make_json_request(
+        app.clone(),
+        "POST",
+        &format!("{}/review", spec_uri),
+        &serde_json::json!({ "decision": "approved", "reviewer": "alice", "comment": "LGTM" })
+            .to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    let (status, body) = make_request(app.clone(), "GET", &format!("{}/review", spec_uri)).await;
+    assert_eq!(status, StatusCode::OK);
+    let review: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(review["state"], "approved");
+    assert_eq!(review["approvals"], 1);
+    assert_eq!(review["history"][1]["comment"], "LGTM");
+
+    let (status, body) =
+        make_json_request(app, "PATCH", &format!("{}/metadata", spec_uri), &start).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+}
@@ -113,3 +113,49 @@ async fn test_empty_project_stats() {
     let by_status = stats["specsByStatus"].as_array().unwrap();
     assert!(by_status.iter().all(|v| v["count"] == 0));
 }
+
+#[tokio::test]
+async fn test_project_workload() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let readme = temp_dir
+        .path()
+        .join("specs")
+        .join("002-second-spec")
+        .join("README.md");
+    let content = std::fs::read_to_string(&readme).unwrap();
+    std::fs::write(
+        &readme,
+        content.replacen("status:", "assignee: alice\nstatus:", 1),
+    )
+    .unwrap();
+    let config_dir = temp_dir.path().join(".lean-spec");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.json"),
+        r#"{ "workload": { "defaultWipLimit": 0 } }"#,
+    )
+    .unwrap();
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+    let (status, body) = make_request(
+        app,
+        "GET",
+        &format!("/api/projects/{}/workload", project_id),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+
+    let report: Value = serde_json::from_str(&body).unwrap();
+    let alice = &report["assignees"][0];
+    assert_eq!(alice["assignee"], "alice");
+    assert_eq!(alice["inProgress"], 1);
+    assert_eq!(alice["wipLimit"], 0);
+    assert_eq!(alice["overCapacity"], true);
+    assert_eq!(report["unassigned"][0]["path"], "001-first-spec");
+}
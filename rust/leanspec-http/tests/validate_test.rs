@@ -123,3 +123,79 @@ async fn test_validate_nonexistent_spec() {
 
     assert_eq!(status, StatusCode::NOT_FOUND);
 }
+
+#[tokio::test]
+async fn test_validate_sarif_report() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+
+    let (status, body) = make_json_request(
+        app,
+        "POST",
+        &format!("/api/projects/{}/validate?format=sarif", project_id),
+        "{}",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let sarif: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let artifacts = sarif["runs"][0]["artifacts"].as_array().unwrap();
+    assert!(!artifacts.is_empty());
+    assert!(artifacts
+        .iter()
+        .all(|a| a["location"]["uri"].as_str().unwrap().starts_with("specs/")));
+}
+
+#[tokio::test]
+async fn test_validate_junit_report() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+
+    let (status, body) = make_json_request(
+        app,
+        "POST",
+        &format!("/api/projects/{}/validate?format=junit", project_id),
+        "{}",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.starts_with("<?xml"));
+    assert!(body.contains("<testsuite name=\"lean-spec validate\""));
+    assert!(body.contains("<testcase classname=\"specs\""));
+}
+
+#[tokio::test]
+async fn test_validate_unknown_report_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let state = create_test_state(&temp_dir).await;
+    let app = create_router(state.clone());
+
+    let project_id = {
+        let reg = state.registry.read().await;
+        reg.all().first().unwrap().id.clone()
+    };
+
+    let (status, _body) = make_json_request(
+        app,
+        "POST",
+        &format!("/api/projects/{}/validate?format=html", project_id),
+        "{}",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}